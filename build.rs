@@ -0,0 +1,42 @@
+//! Exposes build provenance to `sysg version --verbose` as compile-time
+//! environment variables.
+
+use std::{env, path::Path, process::Command};
+
+fn main() {
+    let commit = command_output("git", &["rev-parse", "--short=12", "HEAD"]);
+    println!(
+        "cargo:rustc-env=SYSG_GIT_COMMIT={}",
+        commit.as_deref().unwrap_or("unknown")
+    );
+
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".into());
+    let rustc_version = command_output(&rustc, &["--version"]);
+    println!(
+        "cargo:rustc-env=SYSG_RUSTC_VERSION={}",
+        rustc_version.as_deref().unwrap_or("unknown")
+    );
+
+    let target = env::var("TARGET").unwrap_or_else(|_| "unknown".into());
+    println!("cargo:rustc-env=SYSG_BUILD_TARGET={target}");
+
+    // Only a new commit should re-run the script; a source snapshot without
+    // `.git` builds once and reports `unknown`.
+    for path in [".git/HEAD", ".git/refs"] {
+        if Path::new(path).exists() {
+            println!("cargo:rerun-if-changed={path}");
+        }
+    }
+    println!("cargo:rerun-if-changed=build.rs");
+}
+
+/// First line of a successful command's stdout, if any.
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    let line = stdout.lines().next()?.trim();
+    (!line.is_empty()).then(|| line.to_string())
+}
@@ -0,0 +1,101 @@
+//! Stdin pipes the supervisor holds for `interactive` services.
+//!
+//! An interactive service is launched with its stdin connected to a pipe whose
+//! write end stays with the supervisor. `sysg attach` relays the client's input
+//! into that pipe while following the service's output, and detaching only
+//! stops the relay: the pipe stays open, so the service never sees end-of-file
+//! and the next attach picks up where the last one left off.
+
+use std::{
+    collections::BTreeMap,
+    io::{self, Read, Write},
+    process::ChildStdin,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+/// A service's stdin, shared by every client attached to it.
+type Pipe = Arc<Mutex<ChildStdin>>;
+
+/// Stdin pipes of running interactive services, keyed by project id and
+/// service name. A relaunch replaces the entry with the new process's pipe.
+static PIPES: Mutex<BTreeMap<(String, String), Pipe>> = Mutex::new(BTreeMap::new());
+
+/// Records `stdin` as the pipe `sysg attach` writes to for `service`.
+pub(crate) fn register(project: &str, service: &str, stdin: ChildStdin) {
+    PIPES.lock().unwrap_or_else(PoisonError::into_inner).insert(
+        (project.to_string(), service.to_string()),
+        Arc::new(Mutex::new(stdin)),
+    );
+}
+
+/// Returns whether `service` of `project` has a stdin pipe to attach to.
+pub fn is_attachable(project: &str, service: &str) -> bool {
+    PIPES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .contains_key(&(project.to_string(), service.to_string()))
+}
+
+/// Copies `input` into the stdin of `service` until `input` ends. Writes from
+/// concurrent attaches never interleave within one read chunk.
+pub(crate) fn relay(
+    project: &str,
+    service: &str,
+    mut input: impl Read,
+) -> io::Result<()> {
+    let pipe = PIPES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&(project.to_string(), service.to_string()))
+        .cloned()
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("service '{service}' has no stdin to attach to"),
+            )
+        })?;
+    let mut buf = [0_u8; 4096];
+    loop {
+        let read = match input.read(&mut buf) {
+            Ok(0) => return Ok(()),
+            Ok(read) => read,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+        let mut stdin = pipe.lock().unwrap_or_else(PoisonError::into_inner);
+        stdin.write_all(&buf[..read])?;
+        stdin.flush()?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process::{Command, Stdio};
+
+    use super::*;
+
+    #[test]
+    fn relay_feeds_input_to_the_registered_pipe() {
+        let mut child = Command::new("cat")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        register("attach-test", "repl", child.stdin.take().unwrap());
+        assert!(is_attachable("attach-test", "repl"));
+        assert!(!is_attachable("attach-test", "other"));
+
+        relay("attach-test", "repl", &b"one\n"[..]).unwrap();
+        relay("attach-test", "repl", &b"two\n"[..]).unwrap();
+        let err = relay("attach-test", "other", &b"lost\n"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+
+        // Dropping the last handle closes the pipe, so `cat` sees end-of-file.
+        PIPES
+            .lock()
+            .unwrap()
+            .remove(&("attach-test".into(), "repl".into()));
+        let output = child.wait_with_output().unwrap();
+        assert_eq!(output.stdout, b"one\ntwo\n");
+    }
+}
@@ -1,7 +1,6 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     error::Error,
-    ffi::CString,
     fs, io,
     io::{IsTerminal, Write},
     os::unix::io::IntoRawFd,
@@ -32,19 +31,35 @@ use sysinfo::{
 };
 use systemg::{
     charting::{self, ChartConfig, parse_stream_duration},
-    cli::{Cli, Commands, OutputFormat, parse_args},
-    config::{Config, EffectiveLogsConfig, load_config},
-    constants::{PROCESS_CHECK_INTERVAL, SERVICE_POLL_INTERVAL},
-    cron::{CronExecutionStatus, CronStateFile},
-    daemon::{Daemon, ServiceLifecycleStatus},
-    ipc::{self, ControlCommand, ControlError, ControlResponse, InspectPayload},
+    cli::{
+        Cli, ColorChoice, Commands, CompleteTarget, ConfigCommand, CronCommand,
+        OutputFormat, StatusOutput, StatusSort, parse_args,
+    },
+    config::{Config, EffectiveLogsConfig, HealthPolicy, load_config},
+    config_test::{self, CheckKind, ConfigTestReport, Decision},
+    constants::{
+        CRON_WATCH_POLL_INTERVAL, PROCESS_CHECK_INTERVAL, SERVICE_POLL_INTERVAL,
+        SNAPSHOT_LOG_CAP,
+    },
+    cron::{
+        CronExecutionRecord, CronExecutionStatus, CronHistoryCursor, CronRunEvent,
+        CronStateFile,
+    },
+    daemon::{Daemon, ServiceLifecycleStatus, ServiceStateFile},
+    diff::DiffReport,
+    ipc::{
+        self, BulkAction, BulkOutcome, ControlCommand, ControlError, ControlResponse,
+        InspectPayload,
+    },
     logs::{
-        LogFilter, LogFormat, LogManager, LogSection, LogWriter, RotatingLogWriter,
-        get_service_log_path, prune_logs, resolve_log_path, supervisor_log_path,
-        write_log_section_header,
+        FollowBuffer, LogExportSummary, LogFilter, LogFormat, LogManager, LogSection,
+        LogWriter, RotatingLogWriter, get_service_log_path, prune_logs, resolve_log_path,
+        supervisor_log_path, write_log_section_header,
     },
     metrics::MetricSample,
+    notify,
     runtime::{self, RuntimeMode},
+    snapshot::{Redactor, Snapshot, tail_capped},
     spawn::{SpawnedChild, SpawnedChildKind, SpawnedExit},
     state_store::StateStore,
     status::{
@@ -52,7 +67,7 @@ use systemg::{
         ProjectRunMode, SpawnedProcessNode, StatusSnapshot, UnitHealth, UnitIntent,
         UnitKind, UnitMetricsSummary, UnitState, UnitStatus, UptimeInfo,
         collect_disk_snapshot, compute_overall_health, explain_unit_health,
-        format_elapsed,
+        format_elapsed, trace_unit_health,
     },
     supervisor::{Supervisor, SupervisorError},
     validate::{self, ValidationReport},
@@ -88,6 +103,8 @@ const SUPERVISOR_RUNTIME_TIMEOUT: Duration = Duration::from_secs(5);
 const SUPERVISOR_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
 /// Interval between foreground attachment and reconnect checks.
 const FOREGROUND_POLL_INTERVAL: Duration = Duration::from_millis(250);
+/// Interval between status readings while `sysg wait` blocks.
+const WAIT_POLL_INTERVAL: Duration = Duration::from_millis(250);
 /// Interval between visible boot progress probes.
 const BOOT_PROGRESS_INTERVAL: Duration = Duration::from_millis(400);
 /// Prefix used for foreground boot progress.
@@ -361,6 +378,33 @@ fn agent_mode() -> bool {
     set("SYSTEMG_AGENT") || set("NO_COLOR")
 }
 
+/// Whether this invocation may emit ANSI colors, resolved once from `--color`.
+///
+/// Left unset outside `run` (unit tests), where each renderer's own `no_color`
+/// argument alone decides.
+static COLOR_ENABLED: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+
+/// Resolves the global `--color` choice. `auto` colors only an interactive
+/// stdout outside agent mode, which also covers `NO_COLOR`.
+fn apply_color_choice(choice: ColorChoice) {
+    let enabled = match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => stdout_is_tty() && !agent_mode(),
+    };
+    let _ = COLOR_ENABLED.set(enabled);
+}
+
+/// Returns whether the global `--color` choice permits colored output.
+fn color_enabled() -> bool {
+    COLOR_ENABLED.get().copied().unwrap_or(true)
+}
+
+/// Folds a command's own `--no-color` flag into the global color choice.
+fn color_disabled(no_color: bool) -> bool {
+    no_color || !color_enabled()
+}
+
 thread_local! {
     /// The subcommand currently being dispatched, so the top-level catch-all can
     /// attach help for THAT command instead of a fixed `sysg logs` suggestion.
@@ -382,6 +426,80 @@ fn apply_plain_mode(plain: bool) {
     }
 }
 
+/// Routes a log clear through a serving supervisor so its in-memory live-log
+/// buffer is dropped with the files. Returns `false` when no supervisor is up
+/// and the caller should clear the files itself.
+fn clear_logs_via_supervisor(
+    service: Option<String>,
+    project: Option<String>,
+    keep_history: bool,
+) -> Result<bool, Box<dyn Error>> {
+    if !supervisor_running() {
+        return Ok(false);
+    }
+    match ipc::send_command(&ControlCommand::ClearLogs {
+        service,
+        project,
+        keep_history,
+    }) {
+        Ok(ControlResponse::Message(message)) => {
+            println!("{message}");
+            Ok(true)
+        }
+        Ok(ControlResponse::Ok) => Ok(true),
+        Ok(ControlResponse::Error(message)) => Err(ControlError::Server(message).into()),
+        Ok(other) => Err(io::Error::other(format!(
+            "unexpected supervisor response: {other:?}"
+        ))
+        .into()),
+        Err(ControlError::NotAvailable) => Ok(false),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Asks on the terminal before `logs --clear` empties `subject`. Without an
+/// interactive stdin (or in agent mode) there is no one to ask, so the clear
+/// is refused with SG0024 instead of proceeding silently.
+fn confirm_log_clear(subject: &str) -> Result<bool, Box<dyn Error>> {
+    if !io::stdin().is_terminal() || agent_mode() {
+        return Err(Box::new(DiagError(Box::new(
+            systemg::logs_cmd::clear_unconfirmed(subject),
+        ))));
+    }
+    eprint!("Clear {subject}? Rotated backups are kept. [y/N] ");
+    io::stderr().flush()?;
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer)?;
+    Ok(matches!(
+        answer.trim().to_ascii_lowercase().as_str(),
+        "y" | "yes"
+    ))
+}
+
+/// Resolves the runtime namespace from `--namespace` or `SYSTEMG_NAMESPACE` and
+/// exports it, so the re-executed supervisor, its services, and any `sysg` they
+/// run all address the same isolated runtime.
+fn resolve_namespace(flag: Option<String>) -> Result<Option<String>, Box<dyn Error>> {
+    let namespace = flag.or_else(|| {
+        std::env::var(runtime::NAMESPACE_ENV)
+            .ok()
+            .filter(|value| !value.is_empty())
+    });
+    let Some(namespace) = namespace else {
+        return Ok(None);
+    };
+    runtime::validate_namespace(&namespace).map_err(|err| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{}: {err}", runtime::NAMESPACE_ENV),
+        )
+    })?;
+    unsafe {
+        std::env::set_var(runtime::NAMESPACE_ENV, &namespace);
+    }
+    Ok(Some(namespace))
+}
+
 /// Decides whether to follow given explicit flags and the environment.
 ///
 /// Explicit flags win; otherwise systemg follows only on an interactive stdout
@@ -655,6 +773,8 @@ fn run() -> Result<(), Box<dyn Error>> {
     let args = parse_args();
     set_current_command(&args.command);
     apply_plain_mode(args.plain);
+    apply_color_choice(args.color);
+    let namespace = resolve_namespace(args.namespace.clone())?;
     let euid = Uid::effective();
     let drop_privileges_effective =
         args.drop_privileges && drop_privileges_applies_to_command(&args.command);
@@ -672,6 +792,7 @@ fn run() -> Result<(), Box<dyn Error>> {
         RuntimeMode::User
     };
 
+    runtime::set_namespace(namespace.as_deref());
     runtime::init(runtime_mode);
     runtime::set_drop_privileges(drop_privileges_effective);
     runtime::capture_socket_activation();
@@ -722,6 +843,9 @@ fn run() -> Result<(), Box<dyn Error>> {
             parent_pid,
             child,
             stderr,
+            env,
+            parallel,
+            follow_exit,
             command,
         } => {
             if let Some(child_start) = resolve_child_start(
@@ -736,6 +860,23 @@ fn run() -> Result<(), Box<dyn Error>> {
                 return Ok(());
             }
 
+            if service.len() > 1 {
+                if follow_exit || !env.is_empty() || !command.is_empty() {
+                    use systemg::diag::{Diagnostic, SgCode};
+                    return Err(Box::new(DiagError(Box::new(
+                        Diagnostic::error(
+                            SgCode::ConflictingSelectors,
+                            "several --service targets cannot be combined with \
+                             --follow-exit, --env or an ad-hoc command",
+                        )
+                        .note("those options apply to a single started unit")
+                        .help_cmd("start one service", "sysg start -s web --follow-exit")
+                        .help_docs(),
+                    ))));
+                }
+                return dispatch_bulk(BulkAction::Start, service, project, &config);
+            }
+            let service = service.into_iter().next();
             let start_target =
                 resolve_start_target(&config, service.clone(), name.as_deref(), command)?;
             let plan = systemg::start::resolve_plan(
@@ -751,92 +892,164 @@ fn run() -> Result<(), Box<dyn Error>> {
                 )))
             })?;
 
+            let env: BTreeMap<String, String> = env.into_iter().collect();
             if daemonize {
-                dispatch_start_daemonize(plan, stderr, verbose, args.drop_privileges)?;
+                dispatch_start_daemonize(
+                    plan,
+                    stderr,
+                    verbose,
+                    args.drop_privileges,
+                    &env,
+                    parallel,
+                )?;
+            } else if follow_exit {
+                let exit_code = dispatch_start_follow_exit(plan, stderr, &env, parallel)?;
+                process::exit(exit_code);
             } else {
-                dispatch_start_foreground(plan, stderr)?;
+                dispatch_start_foreground(plan, stderr, &env, parallel)?;
             }
         }
         Commands::Stop {
+            services,
             service,
             project,
             config,
             supervisor,
+            orphans,
+            timeout,
         } => {
+            let services = merge_service_targets(services, service);
             let config_path =
-                resolve_config_path(&config).unwrap_or_else(|_| config.into());
+                resolve_config_path(&config).unwrap_or_else(|_| config.clone().into());
+            // The first target resolves the plan, so several targets get the same
+            // selector and `--timeout` validation as one.
             let plan = systemg::stop::resolve_plan(
                 config_path,
-                service.as_deref(),
+                services.first().map(String::as_str),
                 project.as_deref(),
                 supervisor,
+                orphans,
+                timeout.as_deref(),
             )
             .map_err(stop_plan_diag)?;
-            dispatch_stop(plan)?;
+            match plan {
+                systemg::stop::StopPlan::Service { grace, .. } if services.len() > 1 => {
+                    let action = BulkAction::Stop {
+                        timeout_ms: grace.map(|grace| grace.as_millis() as u64),
+                    };
+                    dispatch_bulk(action, services, project, &config)?;
+                }
+                plan => dispatch_stop(plan)?,
+            }
         }
         Commands::Restart {
             config,
+            services,
             service,
+            with_dependents,
+            build_first,
+            all_failed,
             project,
             daemonize,
+            wait,
         } => {
             if args.drop_privileges && supervisor_running() {
                 warn!(
                     "--drop-privileges is managed by the running supervisor and has no effect for this restart request"
                 );
             }
-            let config_path =
-                resolve_config_path(&config).unwrap_or_else(|_| config.clone().into());
-            let plan = systemg::restart::resolve_plan(
-                config_path,
-                service.as_deref(),
-                project.as_deref(),
-            )
-            .map_err(|mismatch| {
-                DiagError(Box::new(systemg::start::project_mismatch(
-                    &mismatch.flag,
-                    &mismatch.selector,
-                )))
-            })?;
-
-            let world = systemg::restart::World {
-                supervisor_running: supervisor_running(),
-                version_drifted: matches!(
-                    daemon_version_drift(),
-                    VersionDrift::Drifted(_) | VersionDrift::PreVersionDaemon
-                ),
-            };
-            match systemg::restart::preflight(plan, world) {
-                systemg::restart::Preflight::Refused(diag) => {
-                    return Err(Box::new(DiagError(diag)));
-                }
-                systemg::restart::Preflight::Ready(plan) => {
-                    dispatch_restart(plan, daemonize, verbose)?;
+            let services = merge_service_targets(services, service);
+            if all_failed {
+                dispatch_restart_failed(project)?;
+            } else if services.len() > 1 {
+                if daemonize {
+                    warn!(
+                        "--daemonize has no effect when restarting several services; they are restarted through the running supervisor"
+                    );
                 }
+                let config_path = resolve_config_path(&config)
+                    .unwrap_or_else(|_| config.clone().into());
+                let action = BulkAction::Restart {
+                    config: restart_scoped_config(&config_path),
+                    with_dependents,
+                    build_first,
+                    wait,
+                };
+                dispatch_bulk(action, services, project, &config)?;
+            } else {
+                run_restart(
+                    &config,
+                    services.first().map(String::as_str),
+                    with_dependents,
+                    build_first,
+                    project.as_deref(),
+                    daemonize,
+                    wait,
+                    verbose,
+                )?;
+            }
+        }
+        Commands::Reload {
+            config,
+            project,
+            dry_run,
+            no_color,
+        } => {
+            if dry_run {
+                dispatch_reload_preview(&config, project.as_deref(), no_color)?;
+            } else {
+                run_restart(
+                    &config,
+                    None,
+                    false,
+                    false,
+                    project.as_deref(),
+                    false,
+                    false,
+                    verbose,
+                )?;
             }
         }
         Commands::Status {
             config,
             service,
+            unit,
             project,
             all,
             format,
+            output,
             no_color,
             full_cmd,
             live,
             stream,
+            summary,
+            json,
+            filter,
+            sort,
+            limit,
+            explain,
+            no_children,
         } => {
+            let service = service.or(unit).or_else(|| explain.clone());
             let target_project =
                 resolve_status_project_filter(config.as_deref(), project.clone())?;
             let render_config = config.as_deref().unwrap_or(DEFAULT_CONFIG_PATH);
 
+            let format = match output {
+                Some(StatusOutput::Json) => Some(OutputFormat::Json),
+                _ => format,
+            };
             let mut render_opts = StatusRenderOptions {
                 format,
-                no_color: no_color || agent_mode(),
+                output,
+                no_color: color_disabled(no_color),
                 full_cmd,
                 include_orphans: all,
                 service_filter: service.as_deref(),
                 project_filter: target_project.as_deref(),
+                name_glob: filter.as_deref(),
+                sort,
+                limit,
                 offline: false,
             };
 
@@ -853,10 +1066,11 @@ fn run() -> Result<(), Box<dyn Error>> {
                 };
                 let sleep_interval = Duration::from_secs(stream_seconds);
                 loop {
-                    match fetch_status_reading(config.as_deref(), live) {
+                    match fetch_status_reading(config.as_deref(), live, !no_children) {
                         Ok(reading) => {
                             print!("\x1B[2J\x1B[H");
                             print_presence_banner(reading.presence);
+                            print_paused_banner(&reading.snapshot);
                             render_opts.offline =
                                 reading.presence != SupervisorPresence::Live;
                             if let Err(e) = render_status(
@@ -873,8 +1087,12 @@ fn run() -> Result<(), Box<dyn Error>> {
                         Err(_) => {
                             print!("\x1B[2J\x1B[H");
                             println!(
-                                "{}Warn: Supervisor has been shut down{}",
-                                YELLOW, RESET
+                                "{}",
+                                colorize(
+                                    "Warn: Supervisor has been shut down",
+                                    YELLOW,
+                                    false
+                                )
                             );
                             println!("\nWaiting for supervisor to restart...");
                             println!("Press Ctrl+C to exit stream mode.");
@@ -883,9 +1101,22 @@ fn run() -> Result<(), Box<dyn Error>> {
                     thread::sleep(sleep_interval);
                 }
             } else {
-                let reading = with_progress_spinner("Computing", || {
-                    fetch_status_reading(config.as_deref(), live)
-                })?;
+                let reading = match with_progress_spinner("Computing", || {
+                    fetch_status_reading(config.as_deref(), live, !no_children)
+                }) {
+                    Ok(reading) => reading,
+                    Err(err) => match err.downcast_ref::<NoStatusSource>() {
+                        // With no supervisor and no config to read disk state
+                        // from, a check must still print its verdict rather
+                        // than an error.
+                        Some(NoStatusSource(presence)) if summary => {
+                            let (line, exit_code) = status_check_line(*presence, &[]);
+                            println!("{line}");
+                            process::exit(exit_code);
+                        }
+                        _ => return Err(err),
+                    },
+                };
 
                 if let Some(diag) = status_ambiguous_service(
                     &reading.snapshot,
@@ -896,7 +1127,78 @@ fn run() -> Result<(), Box<dyn Error>> {
                     process::exit(2);
                 }
 
+                if let Some(selector) = explain.as_deref() {
+                    let Some(document) = reading.snapshot.unit_status(|unit| {
+                        (all || unit.kind != UnitKind::Orphaned)
+                            && status_unit_matches_selector(
+                                unit,
+                                Some(selector),
+                                target_project.as_deref(),
+                            )
+                    }) else {
+                        return Err(Box::new(DiagError(Box::new(
+                            systemg::status::diagnostics::unit_not_found(selector),
+                        ))));
+                    };
+                    print_presence_banner(reading.presence);
+                    let policy = unit_health_policy(render_config, &document.unit);
+                    print!(
+                        "{}",
+                        render_health_trace(
+                            &document.unit,
+                            policy.as_ref(),
+                            render_config,
+                            render_opts.no_color,
+                        )
+                    );
+                    let health =
+                        compute_overall_health(std::slice::from_ref(&document.unit));
+                    process::exit(status_exit_code(reading.presence, health));
+                }
+
+                if json {
+                    let selector = service.as_deref().unwrap_or_default();
+                    let Some(document) = reading.snapshot.unit_status(|unit| {
+                        (all || unit.kind != UnitKind::Orphaned)
+                            && status_unit_matches_selector(
+                                unit,
+                                Some(selector),
+                                target_project.as_deref(),
+                            )
+                    }) else {
+                        return Err(Box::new(DiagError(Box::new(
+                            systemg::status::diagnostics::unit_not_found(selector),
+                        ))));
+                    };
+                    print_presence_banner(reading.presence);
+                    println!("{}", serde_json::to_string_pretty(&document)?);
+                    let health =
+                        compute_overall_health(std::slice::from_ref(&document.unit));
+                    process::exit(status_exit_code(reading.presence, health));
+                }
+
+                if summary {
+                    let mut units: Vec<UnitStatus> = reading
+                        .snapshot
+                        .units
+                        .into_iter()
+                        .filter(|unit| all || unit.kind != UnitKind::Orphaned)
+                        .filter(|unit| {
+                            status_unit_matches_selector(
+                                unit,
+                                service.as_deref(),
+                                target_project.as_deref(),
+                            )
+                        })
+                        .collect();
+                    shape_status_units(&mut units, filter.as_deref(), None);
+                    let (line, exit_code) = status_check_line(reading.presence, &units);
+                    println!("{line}");
+                    process::exit(exit_code);
+                }
+
                 print_presence_banner(reading.presence);
+                print_paused_banner(&reading.snapshot);
                 render_opts.offline = reading.presence != SupervisorPresence::Live;
                 let health =
                     render_status(&reading.snapshot, &render_opts, false, render_config)?;
@@ -905,6 +1207,55 @@ fn run() -> Result<(), Box<dyn Error>> {
                 process::exit(exit_code);
             }
         }
+        Commands::Tree {
+            config,
+            project,
+            all,
+            no_color,
+        } => {
+            let target_project =
+                resolve_status_project_filter(config.as_deref(), project)?;
+            let reading = with_progress_spinner("Computing", || {
+                fetch_status_reading(config.as_deref(), false, true)
+            })?;
+            print_presence_banner(reading.presence);
+
+            let units: Vec<UnitStatus> = reading
+                .snapshot
+                .units
+                .into_iter()
+                .filter(|unit| all || unit.kind != UnitKind::Orphaned)
+                .filter(|unit| {
+                    status_unit_matches_selector(unit, None, target_project.as_deref())
+                })
+                .collect();
+            let supervisor_pid = if reading.presence == SupervisorPresence::Live {
+                ipc::read_supervisor_pid().ok().flatten()
+            } else {
+                None
+            };
+            for line in
+                render_process_tree(&units, supervisor_pid, color_disabled(no_color))
+            {
+                println!("{line}");
+            }
+        }
+        Commands::Wait {
+            service,
+            config,
+            project,
+            condition,
+            timeout,
+        } => {
+            let plan = systemg::wait::resolve_plan(
+                &service,
+                project.as_deref(),
+                condition,
+                timeout.as_deref(),
+            )
+            .map_err(|err| wait_plan_diag(err, &service))?;
+            dispatch_wait(plan, config.as_deref())?;
+        }
         Commands::Inspect {
             config,
             service,
@@ -977,7 +1328,7 @@ fn run() -> Result<(), Box<dyn Error>> {
 
             let render_opts = InspectRenderOptions {
                 format,
-                no_color: no_color || agent_mode(),
+                no_color: color_disabled(no_color),
                 window_seconds: stream_seconds,
                 window_desc: format!("last {}s", stream_seconds),
                 samples_limit,
@@ -1112,6 +1463,9 @@ fn run() -> Result<(), Box<dyn Error>> {
         Commands::Logs {
             config,
             purge,
+            clear,
+            clear_all,
+            yes,
             prune,
             max_size,
             max_age,
@@ -1122,6 +1476,8 @@ fn run() -> Result<(), Box<dyn Error>> {
             supervisor,
             follow,
             no_follow,
+            max_rate,
+            max_lines,
             since,
             until,
             grep,
@@ -1129,14 +1485,20 @@ fn run() -> Result<(), Box<dyn Error>> {
             path,
             format,
             raw,
+            merge_streams,
             strip_ansi,
             no_strip_ansi,
+            color_by_level,
             stream,
+            output_file,
         } => {
+            let clearing = clear.is_some() || clear_all;
+            let service = service.or(clear);
             let logs_modes = systemg::logs_cmd::Modes {
                 path,
                 purge,
                 prune,
+                clear: clearing,
                 follow,
                 supervisor,
             };
@@ -1290,38 +1652,66 @@ fn run() -> Result<(), Box<dyn Error>> {
                 // reader replays from, so clearing files CLI-side would leave it
                 // showing "purged" lines. Route the clear through the supervisor
                 // when one is up; fall back to a local file clear when it is not.
-                if supervisor_running() {
-                    match ipc::send_command(&ControlCommand::ClearLogs {
-                        service: service.clone(),
-                        project: target_project.clone(),
-                    }) {
-                        Ok(ControlResponse::Message(message)) => {
-                            println!("{message}");
-                            return Ok(());
-                        }
-                        Ok(ControlResponse::Ok) => return Ok(()),
-                        Ok(ControlResponse::Error(message)) => {
-                            return Err(ControlError::Server(message).into());
-                        }
-                        Ok(other) => {
-                            return Err(io::Error::other(format!(
-                                "unexpected supervisor response: {other:?}"
-                            ))
-                            .into());
-                        }
-                        Err(ControlError::NotAvailable) => {}
-                        Err(err) => return Err(err.into()),
-                    }
+                if clear_logs_via_supervisor(
+                    service.clone(),
+                    target_project.clone(),
+                    false,
+                )? {
+                    return Ok(());
                 }
                 match service.as_deref() {
                     Some(service_name) => {
                         info!("Purging logs for service: {service_name}");
                         manager.clear_service_logs(&log_project_id, service_name)?;
                     }
-                    None => {
-                        info!("Purging logs for all services");
-                        manager.clear_all_logs()?;
+                    None => match target_project.as_deref() {
+                        Some(project) => {
+                            info!("Purging logs for project: {project}");
+                            manager.clear_project_logs(project)?;
+                        }
+                        None => {
+                            info!("Purging logs for all services");
+                            manager.clear_all_logs()?;
+                        }
+                    },
+                }
+                return Ok(());
+            }
+
+            if clearing {
+                let subject = match (service.as_deref(), target_project.as_deref()) {
+                    (Some(service_name), _) => format!("the logs of '{service_name}'"),
+                    (None, Some(project)) => {
+                        format!("the logs of every service in project '{project}'")
+                    }
+                    (None, None) => "the logs of every service".to_string(),
+                };
+                if !yes && !confirm_log_clear(&subject)? {
+                    println!("Left {subject} untouched");
+                    return Ok(());
+                }
+                if clear_logs_via_supervisor(
+                    service.clone(),
+                    target_project.clone(),
+                    true,
+                )? {
+                    return Ok(());
+                }
+                match service.as_deref() {
+                    Some(service_name) => {
+                        manager.truncate_service_logs(&log_project_id, service_name)?;
+                        println!("Cleared logs for '{service_name}'");
                     }
+                    None => match target_project.as_deref() {
+                        Some(project) => {
+                            manager.truncate_project_service_logs(project)?;
+                            println!("Cleared logs for project '{project}'");
+                        }
+                        None => {
+                            manager.truncate_all_service_logs()?;
+                            println!("Cleared logs for all services");
+                        }
+                    },
                 }
                 return Ok(());
             }
@@ -1341,24 +1731,56 @@ fn run() -> Result<(), Box<dyn Error>> {
                         systemg::logs_cmd::unsupported_format("xml"),
                     ))));
                 }
+                None if merge_streams => LogFormat::Merged,
                 None if raw => LogFormat::Raw,
                 None => LogFormat::Text,
             };
             let strip_ansi_output = if no_strip_ansi {
                 false
             } else {
-                strip_ansi
-                    || !matches!(log_format, LogFormat::Text)
-                    || !stdout_is_tty()
-                    || agent_mode()
+                strip_ansi || !matches!(log_format, LogFormat::Text) || !color_enabled()
             };
+            let level_colors = color_by_level && color_enabled();
             // Whether output must pass through the reformatting LogWriter at all.
-            let machine_output =
-                !matches!(log_format, LogFormat::Text) || strip_ansi_output;
-            // Structured formats (json/raw) intentionally drop banners and read
+            let machine_output = !matches!(log_format, LogFormat::Text)
+                || strip_ansi_output
+                || level_colors;
+            // Structured formats (json/raw/merged) intentionally drop banners and read
             // straight from captured bytes; plain text keeps its service header.
             let structured_output = !matches!(log_format, LogFormat::Text);
 
+            if let Some(output_file) = output_file.as_deref()
+                && let Some(selector) = service.as_deref()
+            {
+                let service_name = service_selector_name(selector);
+                let bytes = manager.collect_service_log(
+                    &log_project_id,
+                    service_name,
+                    lines,
+                    kind.as_ref().map(|kind| kind.as_str()),
+                    &log_filter,
+                )?;
+                let summary = LogExportSummary::from_bytes(&bytes);
+                let label = if log_project_id == systemg::state_store::LOOSE_PROJECT_ID {
+                    service_name.to_string()
+                } else {
+                    format!("{log_project_id}/{service_name}")
+                };
+                let mut file = io::BufWriter::new(fs::File::create(output_file)?);
+                writeln!(file, "{}", summary.header(&label))?;
+                // A file is never a terminal, so escapes are dropped unless asked for.
+                let mut writer = LogWriter::new(
+                    file,
+                    log_format,
+                    !no_strip_ansi,
+                    Some(service_name.to_string()),
+                );
+                writer.write_all(&bytes)?;
+                writer.flush()?;
+                println!("Wrote {} lines to {output_file}", summary.lines);
+                return Ok(());
+            }
+
             let make_log_writer = || {
                 LogWriter::new(
                     io::stdout(),
@@ -1366,6 +1788,21 @@ fn run() -> Result<(), Box<dyn Error>> {
                     strip_ansi_output,
                     service.clone(),
                 )
+                .with_level_colors(level_colors)
+            };
+
+            // A service follow without time bounds goes through the supervisor's
+            // log writer as framed lines instead of a supervisor-side file tail.
+            let make_log_follow_command = || {
+                (service.is_some() && since.is_none() && until.is_none() && !all).then(
+                    || ControlCommand::LogFollow {
+                        service: service.clone().unwrap_or_default(),
+                        project: target_project.clone(),
+                        lines,
+                        kind: kind.as_ref().map(|kind| kind.as_str().to_string()),
+                        grep: grep.clone(),
+                    },
+                )
             };
 
             let stream_logs_via_supervisor =
@@ -1382,6 +1819,20 @@ fn run() -> Result<(), Box<dyn Error>> {
                         all,
                         structured: structured_output,
                     };
+                    if follow {
+                        // The buffer keeps the socket drained however slow the
+                        // terminal is, trading dropped lines for bounded memory.
+                        let mut buffer =
+                            FollowBuffer::new(make_log_writer(), max_lines, max_rate)?;
+                        let outcome = match make_log_follow_command() {
+                            Some(follow_command) => {
+                                ipc::stream_log_follow(&follow_command, &mut buffer, None)
+                            }
+                            None => ipc::stream_command_output(&command, &mut buffer),
+                        };
+                        buffer.finish()?;
+                        return outcome.map_err(|err| Box::new(err) as Box<dyn Error>);
+                    }
                     let mut writer = make_log_writer();
                     ipc::stream_command_output(&command, &mut writer)
                         .map_err(|err| Box::new(err) as Box<dyn Error>)?;
@@ -1404,10 +1855,11 @@ fn run() -> Result<(), Box<dyn Error>> {
                     captured_at: chrono::Utc::now(),
                     overall_health: systemg::status::OverallHealth::Warn,
                     units: Vec::new(),
+                    paused: false,
                 });
 
                 match service.as_ref() {
-                    Some(service_name) if structured_output => {
+                    Some(service_name) if structured_output || level_colors => {
                         info!("Fetching logs for service: {service_name}");
                         let service_project = snapshot
                             .units
@@ -1501,7 +1953,19 @@ fn run() -> Result<(), Box<dyn Error>> {
                             .map_err(|err| Box::new(err) as Box<dyn Error>)
                         {
                             Ok(()) => {
-                                if logs_stream_tty {
+                                if logs_stream_tty && level_colors {
+                                    let mut tinted = Vec::new();
+                                    let mut writer = LogWriter::new(
+                                        &mut tinted,
+                                        log_format,
+                                        strip_ansi_output,
+                                        service.clone(),
+                                    )
+                                    .with_level_colors(true);
+                                    writer.write_all(&output)?;
+                                    writer.flush()?;
+                                    write_logs_stream_frame(&tinted)?;
+                                } else if logs_stream_tty {
                                     write_logs_stream_frame(&output)?;
                                 } else if machine_output {
                                     let mut writer = make_log_writer();
@@ -1546,7 +2010,7 @@ fn run() -> Result<(), Box<dyn Error>> {
                 }
                 stream_result?;
             } else {
-                let follow_logs = resolve_logs_follow(follow, no_follow);
+                let follow_logs = resolve_logs_follow(follow, no_follow || merge_streams);
                 // A follow in a real terminal must exit on Esc/Ctrl-C. The socket
                 // stream blocks, so run it on a background thread and poll keys on
                 // the main thread; a key press restores the terminal and returns.
@@ -1568,6 +2032,7 @@ fn run() -> Result<(), Box<dyn Error>> {
                         all,
                         structured: structured_output,
                     };
+                    let follow_cmd = make_log_follow_command();
                     let log_format_owned = log_format;
                     let strip_ansi_owned = strip_ansi_output;
                     let service_owned = service.clone();
@@ -1577,21 +2042,39 @@ fn run() -> Result<(), Box<dyn Error>> {
                             .name(LOG_STREAM_THREAD.into())
                             .spawn(move || {
                                 let output = CrlfWriter::new(io::stdout());
-                                let mut writer = LogWriter::new(
+                                let writer = LogWriter::new(
                                     output,
                                     log_format_owned,
                                     strip_ansi_owned,
                                     service_owned,
-                                );
-                                let outcome =
-                                    ipc::stream_command_output(&stream_cmd, &mut writer);
-                                let _ = writer.flush();
+                                )
+                                .with_level_colors(level_colors);
+                                let mut buffer =
+                                    FollowBuffer::new(writer, max_lines, max_rate)?;
+                                let outcome = match &follow_cmd {
+                                    Some(command) => {
+                                        ipc::stream_log_follow(command, &mut buffer, None)
+                                    }
+                                    None => ipc::stream_command_output(
+                                        &stream_cmd,
+                                        &mut buffer,
+                                    ),
+                                };
+                                let _ = buffer.finish();
                                 outcome
                             })?;
 
                         loop {
                             if stream_thread.is_finished() {
-                                return Ok(());
+                                // Only a refusal from the supervisor (an error
+                                // frame) is worth reporting; a dropped connection
+                                // ends the follow quietly as before.
+                                return match stream_thread.join() {
+                                    Ok(Err(err @ ControlError::Server(_))) => {
+                                        Err(Box::new(err))
+                                    }
+                                    _ => Ok(()),
+                                };
                             }
                             if event::poll(PROCESS_CHECK_INTERVAL)?
                                 && matches!(
@@ -1631,7 +2114,7 @@ fn run() -> Result<(), Box<dyn Error>> {
             no_color,
         } => {
             let (report, content) = validate::validate(&config);
-            let use_color = !(no_color || agent_mode());
+            let use_color = !color_disabled(no_color);
             match format {
                 Some(fmt) => {
                     println!("{}", serialize_machine_output(&report, fmt)?);
@@ -1642,6 +2125,75 @@ fn run() -> Result<(), Box<dyn Error>> {
             }
             process::exit(if report.valid { 0 } else { 1 });
         }
+        Commands::Diff {
+            config,
+            format,
+            no_color,
+        } => {
+            let report = systemg::diff::diff(&config)?;
+            match format {
+                Some(fmt) => println!("{}", serialize_machine_output(&report, fmt)?),
+                None => render_diff_report(&report, !color_disabled(no_color)),
+            }
+            process::exit(if report.in_sync() { 0 } else { 1 });
+        }
+        Commands::Config {
+            command:
+                ConfigCommand::Test {
+                    config,
+                    service,
+                    url,
+                    format,
+                    no_color,
+                },
+        } => {
+            let daemon = build_daemon(&config)?;
+            let report = config_test::run(&daemon, &service, url.as_deref()).map_err(
+                |err| -> Box<dyn Error> {
+                    match err {
+                        systemg::error::ProcessManagerError::Diag(diag) => {
+                            Box::new(DiagError(diag))
+                        }
+                        other => Box::new(other),
+                    }
+                },
+            )?;
+            match format {
+                Some(fmt) => println!("{}", serialize_machine_output(&report, fmt)?),
+                None => render_config_test_report(&report, !color_disabled(no_color)),
+            }
+            process::exit(if report.passed() { 0 } else { 1 });
+        }
+        Commands::Cron {
+            command:
+                CronCommand::Watch {
+                    config,
+                    service,
+                    lines,
+                    no_color,
+                },
+        } => watch_cron_job(&config, &service, lines, no_color)?,
+        Commands::Cron {
+            command: CronCommand::Run { config, service },
+        } => dispatch_cron_run(&config, &service)?,
+        Commands::Attach { service, project } => attach_to_service(&service, project)?,
+        Commands::Disable {
+            service,
+            config,
+            project,
+        } => set_service_disabled(&config, &service, project, true)?,
+        Commands::Enable {
+            service,
+            config,
+            project,
+        } => set_service_disabled(&config, &service, project, false)?,
+        Commands::Pause => dispatch_pause_toggle("Pausing", ControlCommand::Pause)?,
+        Commands::Resume { restart } => dispatch_pause_toggle(
+            "Resuming",
+            ControlCommand::Resume {
+                restart_crashed: restart,
+            },
+        )?,
         Commands::Migrate { config, in_place } => {
             let content = fs::read_to_string(&config)
                 .map_err(|e| io::Error::other(format!("failed to read {config}: {e}")))?;
@@ -1659,6 +2211,66 @@ fn run() -> Result<(), Box<dyn Error>> {
                 print!("{converted}");
             }
         }
+        Commands::Import {
+            from,
+            format,
+            output,
+        } => {
+            let content = fs::read_to_string(&from)
+                .map_err(|e| io::Error::other(format!("failed to read {from}: {e}")))?;
+            let format = format
+                .unwrap_or_else(|| systemg::import::detect_format(Path::new(&from)));
+            let imported = systemg::import::import(&content, format)?;
+            for warning in &imported.warnings {
+                eprintln!("warning: {warning}");
+            }
+            let manifest = systemg::import::to_manifest(&imported.config)?;
+            match output {
+                Some(path) => {
+                    fs::OpenOptions::new()
+                        .write(true)
+                        .create_new(true)
+                        .open(&path)
+                        .and_then(|mut file| file.write_all(manifest.as_bytes()))
+                        .map_err(|e| {
+                            io::Error::other(format!("failed to write {path}: {e}"))
+                        })?;
+                    println!(
+                        "Imported {} service(s) from {from} into {path}",
+                        imported.config.services.len()
+                    );
+                }
+                None => print!("{manifest}"),
+            }
+        }
+        Commands::Version => {
+            for line in version_report(verbose) {
+                println!("{line}");
+            }
+        }
+        Commands::Snapshot { config, out } => {
+            let files = write_snapshot(&config, &out)?;
+            println!("Wrote {out} ({} files, secrets masked)", files.len());
+            for file in files {
+                println!("  {file}");
+            }
+        }
+        Commands::Completion { shell } => {
+            print!("{}", systemg::completion::script(shell));
+        }
+        Commands::Complete { target, config } => match target {
+            CompleteTarget::Services => {
+                for name in systemg::completion::service_names(config.as_deref()) {
+                    println!("{name}");
+                }
+            }
+        },
+        Commands::Schema => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&systemg::config::manifest_schema())?
+            );
+        }
         Commands::Purge {
             config,
             project,
@@ -1672,6 +2284,9 @@ fn run() -> Result<(), Box<dyn Error>> {
                 serde_json::to_string(&systemg::upgrade::LiveUpgradeInfo::current())?
             );
         }
+        Commands::SupervisorReexec => {
+            with_progress_message("Re-executing", request_supervisor_reexec)?;
+        }
         Commands::UpgradeSupervisor { binary } => {
             with_progress_message("Updating", || request_live_upgrade(binary))?;
         }
@@ -1682,6 +2297,8 @@ fn run() -> Result<(), Box<dyn Error>> {
             verbose: _,
             foreground,
             handoff,
+            env,
+            parallel,
         } => {
             let mode = if foreground {
                 ProjectRunMode::Foreground
@@ -1694,6 +2311,8 @@ fn run() -> Result<(), Box<dyn Error>> {
                 pipe_stderr,
                 mode,
                 handoff.map(PathBuf::from),
+                &env.into_iter().collect(),
+                parallel,
             );
         }
         Commands::Spawn {
@@ -1804,23 +2423,391 @@ fn render_validation_report(
     println!();
 }
 
-/// Handles system mode state detected.
-fn system_mode_state_detected() -> bool {
-    let state_dir = PathBuf::from("/var/lib/systemg");
-    state_dir.join("sysg.pid").exists() || state_dir.join("control.sock").exists()
-}
-
-#[cfg(test)]
-mod tests {
-    use std::time::SystemTime;
-
-    use systemg::{spawn::SpawnedChild, status::SpawnedProcessNode};
-
-    use super::*;
+/// Prints `sysg diff` as one line per difference, grouped by kind.
+fn render_diff_report(report: &DiffReport, use_color: bool) {
+    let paint = |code: &str, text: &str| {
+        if use_color {
+            format!("{code}{text}{RESET}")
+        } else {
+            text.to_string()
+        }
+    };
 
-    #[test]
-    fn logs_follow_flag_forces_follow() {
-        assert!(logs_follow_decision(true, false, false, true));
+    if report.in_sync() {
+        println!(
+            "{}  {}",
+            paint(GREEN_BOLD, "✓ in sync"),
+            paint(GRAY, &format!("what is running matches {}", report.config))
+        );
+        return;
+    }
+
+    let groups = [
+        ("-", RED, "not running", &report.not_running),
+        ("+", YELLOW, "orphan", &report.orphans),
+        ("~", CYAN, "drifted", &report.drifted),
+    ];
+    for (marker, color, label, names) in groups {
+        for name in names {
+            println!(
+                "{} {:<24} {}",
+                paint(color, marker),
+                paint(BRIGHT_WHITE, name),
+                paint(GRAY, label)
+            );
+        }
+    }
+    let count = report.not_running.len() + report.orphans.len() + report.drifted.len();
+    let noun = if count == 1 {
+        "difference"
+    } else {
+        "differences"
+    };
+    println!(
+        "{}",
+        paint(GRAY, &format!("{count} {noun} from {}", report.config))
+    );
+}
+
+/// Looks up a cron service in `config_path`, failing with a diagnostic when the
+/// service is missing or has no schedule.
+fn load_cron_service(config_path: &str, service: &str) -> Result<Config, Box<dyn Error>> {
+    let config = load_config(Some(config_path))?;
+    let Some(service_config) = config.services.get(service) else {
+        return Err(Box::new(DiagError(Box::new(
+            systemg::inspect::service_not_found(service),
+        ))));
+    };
+    if service_config.cron.is_none() {
+        let diag = systemg::diag::Diagnostic::error(
+            systemg::diag::SgCode::Catchall,
+            format!("service `{service}` is not a cron job"),
+        )
+        .note("only services with a `cron` schedule record run history")
+        .help_cmd(
+            "follow its output instead",
+            format!("sysg logs -s {service}"),
+        )
+        .help_docs();
+        return Err(Box::new(DiagError(Box::new(diag))));
+    }
+    Ok(config)
+}
+
+/// Handles `sysg disable` and `sysg enable`. A running supervisor applies the
+/// change (stopping a disabled service); otherwise the flag is written straight
+/// to the project's state file for the next start to honor.
+fn set_service_disabled(
+    config_path: &str,
+    service: &str,
+    project: Option<String>,
+    disabled: bool,
+) -> Result<(), Box<dyn Error>> {
+    match supervisor_health() {
+        SupervisorHealth::Serving => {
+            let message = if disabled {
+                send_control_message(ControlCommand::Disable {
+                    service: service.to_string(),
+                    project,
+                })?
+            } else {
+                send_control_message(ControlCommand::Enable {
+                    service: service.to_string(),
+                    project,
+                })?
+            };
+            println!("{message}");
+            return Ok(());
+        }
+        SupervisorHealth::Dying => {
+            return Err(Box::new(DiagError(Box::new(
+                supervisor_not_responding_diag(),
+            ))));
+        }
+        SupervisorHealth::Down => {}
+    }
+
+    let config = load_config(Some(config_path))?;
+    let (qualifier, service) = match systemg::selector::split_selector(service) {
+        Some((project, service)) => (Some(project), service),
+        None => (None, service),
+    };
+    // With no supervisor there is only the loaded config to write to, so a
+    // `-p` or `project/` prefix naming anything else must not land in it.
+    for requested in [project.as_deref(), qualifier].into_iter().flatten() {
+        if requested != config.project.id {
+            return Err(Box::new(DiagError(Box::new(
+                systemg::start::project_mismatch(requested, &config.project.id),
+            ))));
+        }
+    }
+    if !config.services.contains_key(service) {
+        return Err(Box::new(DiagError(Box::new(
+            systemg::inspect::service_not_found(service),
+        ))));
+    }
+    let mut state = ServiceStateFile::load(StateStore::for_project(&config.project.id))?;
+    state.set_disabled(&config.state_key(service), disabled)?;
+    if disabled {
+        println!("Service '{service}' disabled");
+    } else {
+        println!("Service '{service}' enabled");
+    }
+    Ok(())
+}
+
+/// Asks the resident supervisor to run a cron job immediately. Cron jobs only
+/// run under a supervisor, so there is no local fallback.
+fn dispatch_cron_run(config_path: &str, service: &str) -> Result<(), Box<dyn Error>> {
+    let config = load_cron_service(config_path, service)?;
+    match supervisor_health() {
+        SupervisorHealth::Serving => {}
+        SupervisorHealth::Dying => {
+            return Err(Box::new(DiagError(Box::new(
+                supervisor_not_responding_diag(),
+            ))));
+        }
+        SupervisorHealth::Down => {
+            return Err(Box::new(DiagError(Box::new(
+                systemg::status::diagnostics::supervisor_offline(),
+            ))));
+        }
+    }
+    let message = send_control_message(ControlCommand::RunCron {
+        hash: config.state_key(service),
+    })?;
+    println!("{message}");
+    Ok(())
+}
+
+/// Relays this terminal's stdin to an `interactive` service and prints its
+/// output until Ctrl-C. The stdin pipe lives in the supervisor, so there is no
+/// local fallback.
+fn attach_to_service(
+    service: &str,
+    project: Option<String>,
+) -> Result<(), Box<dyn Error>> {
+    match supervisor_health() {
+        SupervisorHealth::Serving => {}
+        SupervisorHealth::Dying => {
+            return Err(Box::new(DiagError(Box::new(
+                supervisor_not_responding_diag(),
+            ))));
+        }
+        SupervisorHealth::Down => {
+            return Err(Box::new(DiagError(Box::new(
+                systemg::status::diagnostics::supervisor_offline(),
+            ))));
+        }
+    }
+    let command = ControlCommand::Attach {
+        service: service.to_string(),
+        project,
+    };
+    let output = LogWriter::new(
+        io::stdout(),
+        LogFormat::Raw,
+        false,
+        Some(service.to_string()),
+    );
+    ipc::stream_attach(&command, output, io::stdin())?;
+    Ok(())
+}
+
+/// Prints a cron job's last `lines` runs, then polls its persisted history and
+/// prints each start and finish until interrupted.
+fn watch_cron_job(
+    config_path: &str,
+    service: &str,
+    lines: usize,
+    no_color: bool,
+) -> Result<(), Box<dyn Error>> {
+    let config = load_cron_service(config_path, service)?;
+    let store = StateStore::for_project(&config.project.id);
+    let key = config.state_key(service);
+    let read_history = || -> Vec<CronExecutionRecord> {
+        CronStateFile::load(store.clone())
+            .ok()
+            .and_then(|state| state.jobs().get(&key).cloned())
+            .map(|job| job.execution_history.into_iter().collect())
+            .unwrap_or_default()
+    };
+
+    let history = read_history();
+    let mut cursor = CronHistoryCursor::primed(&history);
+    let skip = history.len().saturating_sub(lines);
+    for record in history.iter().skip(skip) {
+        println!("{}", format_cron_watch_record(record, no_color));
+        print_cron_run_output(record, no_color);
+    }
+    println!(
+        "{}",
+        colorize(
+            &format!("Watching cron job '{service}' (Ctrl-C to stop)"),
+            GRAY,
+            no_color
+        )
+    );
+
+    loop {
+        thread::sleep(CRON_WATCH_POLL_INTERVAL);
+        for event in cursor.advance(&read_history()) {
+            println!("{}", format_cron_run_event(&event, no_color));
+            if let CronRunEvent::Finished(record) = &event {
+                print_cron_run_output(record, no_color);
+            }
+        }
+    }
+}
+
+/// Prints the log lines captured for a finished run beneath its status line.
+fn print_cron_run_output(record: &CronExecutionRecord, no_color: bool) {
+    for line in &record.output {
+        println!("    {}", colorize(&format!("│ {line}"), GRAY, no_color));
+    }
+}
+
+/// Formats a history row shown before `sysg cron watch` starts following.
+fn format_cron_watch_record(record: &CronExecutionRecord, no_color: bool) -> String {
+    let started: DateTime<Local> = record.started_at.into();
+    format!(
+        "{}  {}{}",
+        started.format("%Y-%m-%d %H:%M:%S"),
+        format_inspect_cron_status(record.status.as_ref(), no_color),
+        format_cron_run_suffix(record)
+    )
+}
+
+/// Formats one live start or finish line for `sysg cron watch`.
+fn format_cron_run_event(event: &CronRunEvent, no_color: bool) -> String {
+    match event {
+        CronRunEvent::Started(record) => {
+            let started: DateTime<Local> = record.started_at.into();
+            let pid = record
+                .pid
+                .map(|pid| format!(" (pid {pid})"))
+                .unwrap_or_default();
+            let manual = if record.manual { " (manual)" } else { "" };
+            format!(
+                "{}  {}{pid}{manual}",
+                started.format("%Y-%m-%d %H:%M:%S"),
+                colorize("started", LIGHT_BLUE, no_color)
+            )
+        }
+        CronRunEvent::Finished(record) => {
+            let finished: DateTime<Local> =
+                record.completed_at.unwrap_or_else(SystemTime::now).into();
+            format!(
+                "{}  {}{}",
+                finished.format("%Y-%m-%d %H:%M:%S"),
+                format_inspect_cron_status(record.status.as_ref(), no_color),
+                format_cron_run_suffix(record)
+            )
+        }
+    }
+}
+
+/// Returns ` (exit N)` for successful runs, since failure reasons already name
+/// their exit code or signal, followed by ` (manual)` for `sysg cron run` runs.
+fn format_cron_run_suffix(record: &CronExecutionRecord) -> String {
+    let exit = match (&record.status, record.exit_code) {
+        (Some(CronExecutionStatus::Success), Some(code)) => format!(" (exit {code})"),
+        _ => String::new(),
+    };
+    let manual = if record.manual { " (manual)" } else { "" };
+    format!("{exit}{manual}")
+}
+
+/// Renders the result of `sysg config test` for a terminal.
+fn render_config_test_report(report: &ConfigTestReport, use_color: bool) {
+    let paint = |code: &str, text: &str| {
+        if use_color {
+            format!("{code}{text}{RESET}")
+        } else {
+            text.to_string()
+        }
+    };
+
+    println!();
+    let instance = match report.running_pid {
+        Some(pid) => format!("· running as pid {pid}"),
+        None => "· not running".to_string(),
+    };
+    println!(
+        "  {}  {}",
+        paint(
+            BRIGHT_WHITE,
+            &format!("{}/{}", report.project, report.service)
+        ),
+        paint(GRAY, &instance)
+    );
+    if report.checks.is_empty() {
+        println!(
+            "  {}",
+            paint(GRAY, "No skip, condition or health check is configured.")
+        );
+    }
+
+    for check in &report.checks {
+        println!();
+        let (color, label) = match check.decision {
+            Decision::Skip => (YELLOW, "skip"),
+            Decision::Start => (GREEN, "start"),
+            Decision::Healthy => (GREEN_BOLD, "✓ healthy"),
+            Decision::Unhealthy => (RED_BOLD, "✗ unhealthy"),
+            Decision::Error => (RED_BOLD, "✗ error"),
+        };
+        let kind = match check.kind {
+            CheckKind::Skip => "skip",
+            CheckKind::Condition => "condition",
+            CheckKind::Health => "health",
+        };
+        println!(
+            "  {:<10} {}  {}",
+            paint(WHITE, kind),
+            paint(color, label),
+            paint(GRAY, &format!("{}ms", check.elapsed_ms))
+        );
+        println!("     {}", paint(GRAY, &check.target));
+        if let Some(code) = check.exit_code {
+            println!("     {} {code}", paint(CYAN, "exit  "));
+        }
+        if let Some(status) = check.http_status {
+            println!("     {} {status}", paint(CYAN, "status"));
+        }
+        for line in check.stdout.lines() {
+            println!("     {} {line}", paint(GRAY, "stdout"));
+        }
+        for line in check.stderr.lines() {
+            println!("     {} {line}", paint(YELLOW, "stderr"));
+        }
+        if let Some(detail) = &check.detail {
+            println!("     {} {detail}", paint(YELLOW, "why   "));
+        }
+    }
+    println!();
+}
+
+/// Handles system mode state detected.
+fn system_mode_state_detected() -> bool {
+    let state_dir = match runtime::namespace() {
+        Some(namespace) => PathBuf::from(format!("/var/lib/systemg-{namespace}")),
+        None => PathBuf::from("/var/lib/systemg"),
+    };
+    state_dir.join("sysg.pid").exists() || state_dir.join("control.sock").exists()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use systemg::{spawn::SpawnedChild, status::SpawnedProcessNode};
+
+    use super::*;
+
+    #[test]
+    fn logs_follow_flag_forces_follow() {
+        assert!(logs_follow_decision(true, false, false, true));
     }
 
     #[test]
@@ -1868,6 +2855,97 @@ mod tests {
         assert!(!status_restart_blocked_for_cron(UnitKind::Orphaned));
     }
 
+    #[test]
+    fn render_process_tree_nests_children_under_their_service() {
+        let child = |name: &str, pid: u32, last_exit: Option<SpawnedExit>| SpawnedChild {
+            name: name.into(),
+            pid,
+            parent_pid: 100,
+            command: name.into(),
+            started_at: SystemTime::now(),
+            ttl: None,
+            depth: 1,
+            cpu_percent: None,
+            rss_bytes: None,
+            last_exit,
+            user: None,
+            kind: SpawnedChildKind::Spawned,
+        };
+        let unit = |name: &str, pid: Option<u32>, children| UnitStatus {
+            name: name.to_string(),
+            hash: format!("hash-{name}"),
+            project: None,
+            kind: UnitKind::Service,
+            lifecycle: None,
+            state: if pid.is_some() {
+                UnitState::Running
+            } else {
+                UnitState::Stopped
+            },
+            intent: UnitIntent::Serve,
+            health: UnitHealth::Healthy,
+            process: pid.map(|pid| systemg::status::ProcessRuntime {
+                pid,
+                state: systemg::status::ProcessState::Running,
+                user: None,
+            }),
+            uptime: None,
+            last_exit: None,
+            cron: None,
+            metrics: None,
+            command: None,
+            runtime_command: None,
+            spawned_children: children,
+            drifted: false,
+            restarts: 0,
+            gave_up_after: None,
+        };
+        let units = vec![
+            unit(
+                "api",
+                Some(100),
+                vec![
+                    SpawnedProcessNode::new(
+                        child("worker", 200, None),
+                        vec![SpawnedProcessNode::new(
+                            child("helper", 300, None),
+                            Vec::new(),
+                        )],
+                    ),
+                    SpawnedProcessNode::new(
+                        child(
+                            "migrate",
+                            201,
+                            Some(SpawnedExit {
+                                exit_code: Some(1),
+                                signal: None,
+                                finished_at: None,
+                            }),
+                        ),
+                        Vec::new(),
+                    ),
+                ],
+            ),
+            unit("web", None, Vec::new()),
+        ];
+
+        assert_eq!(
+            render_process_tree(&units, Some(42), true),
+            vec![
+                "sysg supervisor pid 42",
+                "├─ api pid 100 Running Healthy",
+                "│  ├─ worker pid 200 Running",
+                "│  │  └─ helper pid 300 Running",
+                "│  └─ migrate pid 201 Failed",
+                "└─ web pid - Stopped Healthy",
+            ]
+        );
+        assert_eq!(
+            render_process_tree(&[], None, true),
+            vec!["sysg supervisor not running", "└─ (no units)"]
+        );
+    }
+
     #[test]
     fn visit_spawn_tree_renders_nested_children() {
         let nodes = vec![SpawnedProcessNode::new(
@@ -2036,6 +3114,9 @@ mod tests {
             command: None,
             runtime_command: None,
             spawned_children: vec![],
+            drifted: false,
+            restarts: 0,
+            gave_up_after: None,
         };
         let unit_row = format_unit_row_focus(&unit, &columns, true, None);
         assert!(unit_row.contains("srvc"));
@@ -2066,6 +3147,68 @@ mod tests {
         assert!(child_row.contains("rashad"));
     }
 
+    #[test]
+    fn drifted_unit_state_notes_pending_restart() {
+        let mut unit = UnitStatus {
+            name: "api".to_string(),
+            hash: "v2:none:api".to_string(),
+            project: None,
+            kind: UnitKind::Service,
+            lifecycle: Some(ServiceLifecycleStatus::Running),
+            state: UnitState::Running,
+            intent: UnitIntent::Serve,
+            health: UnitHealth::Healthy,
+            process: None,
+            uptime: None,
+            last_exit: None,
+            cron: None,
+            metrics: None,
+            command: None,
+            runtime_command: None,
+            spawned_children: vec![],
+            drifted: false,
+            restarts: 0,
+            gave_up_after: None,
+        };
+        assert_eq!(unit_state_label(&unit, true), "Running");
+
+        unit.drifted = true;
+        assert_eq!(
+            unit_state_label(&unit, true),
+            "Running (config changed, restart to apply)"
+        );
+    }
+
+    #[test]
+    fn bulk_report_lists_each_service_then_a_summary() {
+        let targets = merge_service_targets(
+            vec!["web".to_string(), "worker".to_string()],
+            vec!["worker".to_string(), "db".to_string()],
+        );
+        assert_eq!(targets, vec!["web", "worker", "db"]);
+
+        let outcomes = vec![
+            BulkOutcome {
+                service: "web".to_string(),
+                error: None,
+            },
+            BulkOutcome {
+                service: "worker".to_string(),
+                error: Some("service 'worker' not found".to_string()),
+            },
+        ];
+        let lines =
+            format_bulk_report(&BulkAction::Stop { timeout_ms: None }, &outcomes, false);
+        assert_eq!(
+            lines,
+            vec![
+                "  ✓ web     stopped",
+                "  ✗ worker  service 'worker' not found",
+                "  1 of 2 services stopped · 1 failed",
+            ]
+        );
+    }
+
     #[test]
     fn status_overview_uses_rail_layout_and_large_bullets() {
         let columns = vec![
@@ -2143,6 +3286,9 @@ mod tests {
                 command: None,
                 runtime_command: None,
                 spawned_children: vec![],
+                drifted: false,
+                restarts: 0,
+                gave_up_after: None,
             },
             UnitStatus {
                 name: "worker".to_string(),
@@ -2161,6 +3307,9 @@ mod tests {
                 command: None,
                 runtime_command: None,
                 spawned_children: vec![],
+                drifted: false,
+                restarts: 0,
+                gave_up_after: None,
             },
         ];
 
@@ -2186,6 +3335,205 @@ mod tests {
         assert!(!offline.contains("Status: WARN"));
     }
 
+    #[test]
+    fn version_report_is_one_line_unless_verbose() {
+        assert_eq!(
+            version_report(false),
+            vec![format!("systemg {}", env!("CARGO_PKG_VERSION"))]
+        );
+    }
+
+    #[test]
+    fn status_glob_matches_wildcards() {
+        assert!(status_glob_matches("worker-*", "worker-12"));
+        assert!(status_glob_matches("worker-?", "worker-a"));
+        assert!(!status_glob_matches("worker-?", "worker-ab"));
+        assert!(status_glob_matches("*-api", "billing-api"));
+        assert!(status_glob_matches("w*r*2", "worker-12"));
+        assert!(!status_glob_matches("api", "api-v2"));
+        assert!(status_glob_matches("*", ""));
+    }
+
+    #[test]
+    fn shape_status_units_filters_then_sorts() {
+        let unit =
+            |name: &str, health, cpu: Option<f32>, uptime: Option<u64>| UnitStatus {
+                name: name.to_string(),
+                hash: name.to_string(),
+                project: None,
+                kind: UnitKind::Service,
+                lifecycle: None,
+                state: UnitState::Running,
+                intent: UnitIntent::Serve,
+                health,
+                process: None,
+                uptime: uptime.map(|seconds| UptimeInfo {
+                    seconds,
+                    human: String::new(),
+                    started_at: None,
+                }),
+                last_exit: None,
+                cron: None,
+                metrics: cpu.map(|cpu| UnitMetricsSummary {
+                    latest_cpu_percent: cpu,
+                    average_cpu_percent: cpu,
+                    max_cpu_percent: cpu,
+                    p50_cpu_percent: cpu,
+                    p95_cpu_percent: cpu,
+                    p99_cpu_percent: cpu,
+                    latest_rss_bytes: 0,
+                    latest_io_read_bytes: 0,
+                    latest_io_write_bytes: 0,
+                    average_io_read_bytes_per_sec: 0.0,
+                    average_io_write_bytes_per_sec: 0.0,
+                    samples: 1,
+                }),
+                command: None,
+                runtime_command: None,
+                spawned_children: vec![],
+                drifted: false,
+                restarts: 0,
+                gave_up_after: None,
+            };
+        let fleet = || {
+            vec![
+                unit("worker-b", UnitHealth::Healthy, Some(90.0), Some(10)),
+                unit("api", UnitHealth::Failing, Some(99.0), None),
+                unit("worker-a", UnitHealth::Warn, None, Some(500)),
+                unit("worker-c", UnitHealth::Healthy, Some(5.0), Some(60)),
+            ]
+        };
+        let names = |units: &[UnitStatus]| {
+            units
+                .iter()
+                .map(|unit| unit.name.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let mut units = fleet();
+        shape_status_units(&mut units, Some("worker-*"), Some(StatusSort::Cpu));
+        assert_eq!(names(&units), ["worker-b", "worker-c", "worker-a"]);
+
+        let mut units = fleet();
+        shape_status_units(&mut units, None, Some(StatusSort::Health));
+        assert_eq!(names(&units), ["api", "worker-a", "worker-b", "worker-c"]);
+
+        let mut units = fleet();
+        shape_status_units(&mut units, None, Some(StatusSort::Uptime));
+        assert_eq!(names(&units), ["worker-a", "worker-c", "worker-b", "api"]);
+
+        let mut units = fleet();
+        shape_status_units(&mut units, None, Some(StatusSort::Name));
+        assert_eq!(names(&units), ["api", "worker-a", "worker-b", "worker-c"]);
+    }
+
+    #[test]
+    fn health_trace_flags_a_verdict_the_supervisor_disagrees_with() {
+        let unit = UnitStatus {
+            name: "api".to_string(),
+            hash: "api".to_string(),
+            project: None,
+            kind: UnitKind::Service,
+            lifecycle: Some(ServiceLifecycleStatus::Stopped),
+            state: UnitState::Stopped,
+            intent: UnitIntent::Serve,
+            health: UnitHealth::Idle,
+            process: None,
+            uptime: None,
+            last_exit: None,
+            cron: None,
+            metrics: None,
+            command: None,
+            runtime_command: None,
+            spawned_children: vec![],
+            drifted: false,
+            restarts: 0,
+            gave_up_after: None,
+        };
+
+        let policy = HealthPolicy {
+            stopped: Some(UnitHealth::Idle),
+            ..HealthPolicy::default()
+        };
+        let agreed = render_health_trace(&unit, Some(&policy), "sysg.yaml", true);
+        assert!(agreed.starts_with("api: Idle\n\nInputs:\n"), "{agreed}");
+        assert!(
+            agreed.contains("  3. health policy `stopped` reclassifies warn as idle\n")
+        );
+        assert!(!agreed.contains("The supervisor reports"));
+
+        let assumed = render_health_trace(&unit, None, "sysg.yaml", true);
+        assert!(assumed.starts_with("api: Warn\n"), "{assumed}");
+        assert!(assumed.contains("assuming defaults"));
+        assert!(assumed.contains("The supervisor reports Idle"));
+    }
+
+    #[test]
+    fn status_check_line_names_offending_units() {
+        let unit = |name: &str, health| UnitStatus {
+            name: name.to_string(),
+            hash: name.to_string(),
+            project: None,
+            kind: UnitKind::Service,
+            lifecycle: None,
+            state: UnitState::Running,
+            intent: UnitIntent::Serve,
+            health,
+            process: None,
+            uptime: None,
+            last_exit: None,
+            cron: None,
+            metrics: None,
+            command: None,
+            runtime_command: None,
+            spawned_children: vec![],
+            drifted: false,
+            restarts: 0,
+            gave_up_after: None,
+        };
+        let live = SupervisorPresence::Live;
+
+        let healthy = vec![
+            unit("web", UnitHealth::Healthy),
+            unit("job", UnitHealth::Idle),
+        ];
+        assert_eq!(
+            status_check_line(live, &healthy),
+            ("OK 2 services, 0 failing".to_string(), 0)
+        );
+
+        let degraded = vec![
+            unit("web", UnitHealth::Healthy),
+            unit("api", UnitHealth::Warn),
+        ];
+        assert_eq!(
+            status_check_line(live, &degraded),
+            ("WARN api degraded".to_string(), 1)
+        );
+
+        let failing = vec![
+            unit("api", UnitHealth::Warn),
+            unit("web", UnitHealth::Failing),
+        ];
+        assert_eq!(
+            status_check_line(live, &failing),
+            ("CRIT web failing, api degraded".to_string(), 2)
+        );
+
+        let mut nightly = unit("nightly", UnitHealth::Idle);
+        nightly.kind = UnitKind::Cron;
+        let with_cron = vec![unit("web", UnitHealth::Healthy), nightly];
+        assert_eq!(
+            status_check_line(live, &with_cron),
+            ("OK 1 service, 1 cron job, 0 failing".to_string(), 0)
+        );
+
+        assert_eq!(
+            status_check_line(SupervisorPresence::Offline, &healthy),
+            ("CRIT supervisor not running".to_string(), 2)
+        );
+    }
+
     #[test]
     fn inspect_overview_renders_state_under_kind() {
         let unit = UnitStatus {
@@ -2209,6 +3557,9 @@ mod tests {
             command: None,
             runtime_command: None,
             spawned_children: vec![],
+            drifted: false,
+            restarts: 0,
+            gave_up_after: None,
         };
         let payload = InspectPayload {
             unit: Some(unit),
@@ -2230,11 +3581,89 @@ mod tests {
             .expect("kind line");
 
         assert!(
-            lines
-                .get(kind_index + 1)
-                .is_some_and(|line| line.contains("State: Running")),
-            "expected State row immediately under Kind row:\n{}",
-            lines.join("\n")
+            lines
+                .get(kind_index + 1)
+                .is_some_and(|line| line.contains("State: Running")),
+            "expected State row immediately under Kind row:\n{}",
+            lines.join("\n")
+        );
+    }
+
+    #[test]
+    fn status_table_lists_one_compact_row_per_unit() {
+        let unit = |name: &str, project: &str, pid: Option<u32>, restarts| UnitStatus {
+            name: name.to_string(),
+            hash: format!("{project}-{name}"),
+            project: Some(systemg::status::ProjectStatus {
+                id: project.to_string(),
+                name: project.to_string(),
+                mode: ProjectRunMode::Daemon,
+                config_path: None,
+                boot: None,
+            }),
+            kind: UnitKind::Service,
+            lifecycle: None,
+            state: UnitState::Running,
+            intent: UnitIntent::Serve,
+            health: UnitHealth::Healthy,
+            process: pid.map(|pid| systemg::status::ProcessRuntime {
+                pid,
+                state: systemg::status::ProcessState::Running,
+                user: None,
+            }),
+            uptime: None,
+            last_exit: Some(ExitMetadata {
+                exit_code: Some(1),
+                signal: None,
+                at: None,
+            }),
+            cron: None,
+            metrics: None,
+            command: Some("python -m http.server 8080 --bind 127.0.0.1".to_string()),
+            runtime_command: None,
+            spawned_children: vec![],
+            drifted: false,
+            restarts,
+            gave_up_after: None,
+        };
+        let units = vec![
+            unit("api", "shop", Some(4242), 3),
+            unit("worker", "shop", None, 0),
+        ];
+
+        let table = status_table_lines(&units, false, None, true);
+        assert_eq!(
+            table,
+            [
+                "NAME    HEALTH    PID  CPU%  MEM  UPTIME  RESTARTS",
+                "api     Healthy  4242     -    -  -              3",
+                "worker  Healthy     -     -    -  -              0",
+            ]
+        );
+
+        let wide = status_table_lines(&units, true, None, true);
+        assert!(
+            wide[0].ends_with("RESTARTS  STATE    LAST_EXIT  CMD"),
+            "{}",
+            wide[0]
+        );
+        assert!(wide[1].ends_with("python -m http.server 8080 --bind 127.0.0.1"));
+        let fitted = status_table_lines(&units, true, Some(90), true);
+        assert!(fitted.iter().all(|line| visible_length(line) <= 90));
+        assert!(fitted[1].ends_with("..."), "{}", fitted[1]);
+
+        let mut mixed = units.clone();
+        mixed.push(unit("api", "blog", Some(7), 0));
+        let qualified = status_table_lines(&mixed, false, None, true);
+        assert!(qualified[1].starts_with("shop/api "));
+        assert!(qualified[3].starts_with("blog/api "));
+
+        let detail = status_detail_lines(&units[0], true);
+        assert_eq!(detail[0], "● api  Healthy");
+        assert!(detail.contains(&"  Restarts: 3".to_string()), "{detail:#?}");
+        assert!(
+            detail.contains(&"       PID: 4242".to_string()),
+            "{detail:#?}"
         );
     }
 
@@ -2264,6 +3693,9 @@ mod tests {
                 command: None,
                 runtime_command: None,
                 spawned_children: vec![],
+                drifted: false,
+                restarts: 0,
+                gave_up_after: None,
             },
             UnitStatus {
                 name: "api".to_string(),
@@ -2288,6 +3720,9 @@ mod tests {
                 command: None,
                 runtime_command: None,
                 spawned_children: vec![],
+                drifted: false,
+                restarts: 0,
+                gave_up_after: None,
             },
         ];
 
@@ -2523,6 +3958,9 @@ mod tests {
             command: None,
             runtime_command: None,
             spawned_children: vec![],
+            drifted: false,
+            restarts: 0,
+            gave_up_after: None,
         };
 
         assert_eq!(
@@ -2777,31 +4215,48 @@ mod tests {
         assert!(drop_privileges_applies_to_command(&Commands::Start {
             config: "systemg.yaml".to_string(),
             daemonize: false,
-            service: None,
+            service: Vec::new(),
             project: None,
             name: None,
             ttl: None,
             parent_pid: None,
             child: false,
             stderr: false,
+            env: vec![],
+            parallel: false,
+            follow_exit: false,
             command: vec![],
         }));
         assert!(drop_privileges_applies_to_command(&Commands::Restart {
             config: "systemg.yaml".to_string(),
-            service: None,
+            services: Vec::new(),
+            service: Vec::new(),
+            with_dependents: false,
+            build_first: false,
+            all_failed: false,
             project: None,
             daemonize: false,
+            wait: false,
         }));
         assert!(!drop_privileges_applies_to_command(&Commands::Status {
             config: None,
             service: None,
+            unit: None,
             project: None,
             all: false,
             format: None,
+            output: None,
             no_color: false,
             full_cmd: false,
             stream: None,
             live: false,
+            summary: false,
+            json: false,
+            filter: None,
+            sort: None,
+            limit: None,
+            explain: None,
+            no_children: false,
         }));
     }
 
@@ -2895,6 +4350,9 @@ mod tests {
             command: Some("sh hello-world.sh".to_string()),
             runtime_command: None,
             spawned_children: vec![],
+            drifted: false,
+            restarts: 0,
+            gave_up_after: None,
         };
         let widths = compute_status_preferred_widths(&[unit], true);
         let mut fitted = widths;
@@ -3110,6 +4568,38 @@ mod tests {
         assert_eq!(success, "success");
     }
 
+    #[test]
+    fn cron_run_events_show_pid_on_start_and_exit_on_finish() {
+        let mut record = CronExecutionRecord {
+            started_at: SystemTime::now(),
+            completed_at: None,
+            status: None,
+            exit_code: None,
+            pid: Some(4242),
+            process_start: None,
+            user: None,
+            command: None,
+            metrics: vec![],
+            manual: false,
+            output: vec![],
+        };
+        let started = format_cron_run_event(&CronRunEvent::Started(record.clone()), true);
+        assert!(started.ends_with("started (pid 4242)"));
+
+        record.completed_at = Some(SystemTime::now());
+        record.status = Some(CronExecutionStatus::Failed(
+            "Process exited with code 3".into(),
+        ));
+        record.exit_code = Some(3);
+        let failed = format_cron_run_event(&CronRunEvent::Finished(record.clone()), true);
+        assert!(failed.ends_with("failed: Process exited with code 3"));
+
+        record.status = Some(CronExecutionStatus::Success);
+        record.exit_code = Some(0);
+        let succeeded = format_cron_run_event(&CronRunEvent::Finished(record), true);
+        assert!(succeeded.ends_with("success (exit 0)"));
+    }
+
     #[test]
     fn wrap_paragraph_respects_width_and_keeps_words_whole() {
         let text = "the quick brown fox jumps over the lazy dog";
@@ -3138,6 +4628,9 @@ mod tests {
             command: None,
             runtime_command: None,
             spawned_children: Vec::new(),
+            drifted: false,
+            restarts: 0,
+            gave_up_after: None,
         };
         unit.intent = UnitIntent::Serve;
 
@@ -3181,20 +4674,30 @@ mod tests {
         let exit_zero = Some(ExitMetadata {
             exit_code: Some(0),
             signal: None,
+            at: None,
         });
         let exit_one = Some(ExitMetadata {
             exit_code: Some(1),
             signal: None,
+            at: None,
         });
         let signal_kill = Some(ExitMetadata {
             exit_code: None,
             signal: Some(9),
+            at: None,
         });
 
         assert_eq!(format_last_exit(exit_zero.as_ref(), None), "exit 0");
         assert_eq!(format_last_exit(exit_one.as_ref(), None), "exit 1");
         assert_eq!(format_last_exit(signal_kill.as_ref(), None), "exit ?");
         assert_eq!(format_last_exit(None, None), "-");
+
+        let crashed = ExitMetadata {
+            exit_code: Some(1),
+            signal: None,
+            at: Some(Utc::now() - chrono::Duration::hours(3)),
+        };
+        assert_eq!(format_last_exit(Some(&crashed), None), "exit 1; 3h");
     }
 
     #[test]
@@ -3202,14 +4705,17 @@ mod tests {
         let success = ExitMetadata {
             exit_code: Some(0),
             signal: None,
+            at: None,
         };
         let failure = ExitMetadata {
             exit_code: Some(2),
             signal: None,
+            at: None,
         };
         let signaled = ExitMetadata {
             exit_code: None,
             signal: Some(9),
+            at: None,
         };
 
         assert_eq!(last_exit_color(Some(&success), None), Some(GREEN_BOLD));
@@ -3492,7 +4998,7 @@ fn dispatch_purge(
 fn purge_world(force: bool) -> systemg::purge::World {
     match supervisor_health() {
         SupervisorHealth::Serving => {
-            let managed_units = try_live_status(true)
+            let managed_units = try_live_status(true, true)
                 .map(|reading| {
                     reading
                         .snapshot
@@ -3949,7 +5455,10 @@ fn start_foreground(
     config_path: PathBuf,
     service: Option<String>,
     pipe_stderr: bool,
-) -> Result<(), Box<dyn Error>> {
+    env: &BTreeMap<String, String>,
+    parallel: bool,
+    follow: Option<&FollowedService>,
+) -> Result<Option<i32>, Box<dyn Error>> {
     let config = load_config(Some(config_path.to_string_lossy().as_ref()))?;
     let project_id = config.project.id.clone();
     let attach_config = config_path.clone();
@@ -3971,6 +5480,8 @@ fn start_foreground(
             pipe_stderr,
             false,
             ProjectRunMode::Foreground,
+            env,
+            parallel,
         );
         run_supervisor_in_process(
             config_path,
@@ -3978,6 +5489,8 @@ fn start_foreground(
             pipe_stderr,
             ProjectRunMode::Foreground,
             None,
+            env,
+            parallel,
         );
     }
 
@@ -3988,7 +5501,8 @@ fn start_foreground(
             libc::waitpid(child_pid, std::ptr::null_mut(), libc::WNOHANG);
         }
         if supervisor_running() {
-            return start_foreground_attached(attach_config, attach_service);
+            return start_foreground_attached(attach_config, attach_service, env)
+                .map(|()| None);
         }
         return Err(err);
     }
@@ -4048,11 +5562,23 @@ fn start_foreground(
             project: project_id,
         });
         let _ = follow_handle.join();
+        // A followed one-shot that fails fast never reaches the wait loop; its
+        // exit code is already recorded, and that is what the caller asked for.
+        // One that never launched has no exit from this run, so the boot
+        // failure is the answer.
+        if let Some(exit_code) = follow.and_then(FollowedService::exit_code) {
+            eprintln!("{}", diag.render_for_terminal());
+            return Ok(Some(exit_code));
+        }
         return Err(Box::new(DiagError(Box::new(diag))));
     }
 
-    let result =
-        wait_for_foreground_attachment(project_id, streaming.clone(), shutdown.clone());
+    let result = wait_for_foreground_attachment(
+        project_id,
+        streaming.clone(),
+        shutdown.clone(),
+        follow,
+    );
     streaming.store(false, Ordering::SeqCst);
     let _ = follow_handle.join();
     result
@@ -4062,6 +5588,7 @@ fn start_foreground(
 fn start_foreground_attached(
     config_path: PathBuf,
     service: Option<String>,
+    env: &BTreeMap<String, String>,
 ) -> Result<(), Box<dyn Error>> {
     let config = load_config(Some(config_path.to_string_lossy().as_ref()))?;
     let project_id = config.project.id.clone();
@@ -4069,6 +5596,7 @@ fn start_foreground_attached(
         config: config_path.to_string_lossy().to_string(),
         service,
         mode: ProjectRunMode::Foreground,
+        env: env.clone(),
     };
     let ctrlc = foreground_ctrlc()?;
     // NO spinner here. A foreground start's whole purpose is to show the
@@ -4148,12 +5676,13 @@ fn start_foreground_attached(
         streaming.clone(),
         shutdown.clone(),
         ctrlc,
+        None,
     );
     // The wait already cleared the flag and force-closed the stream on its exit
     // path; join the (now unblocked) follow thread.
     streaming.store(false, Ordering::SeqCst);
     let _ = follow_handle.join();
-    result
+    result.map(|_| ())
 }
 
 /// A running boot-progress reporter; call `stop()` to end it.
@@ -4547,9 +6076,12 @@ fn wait_for_foreground_attachment(
     project_id: String,
     streaming: Arc<AtomicBool>,
     shutdown: Arc<std::sync::Mutex<Option<std::os::unix::net::UnixStream>>>,
-) -> Result<(), Box<dyn Error>> {
+    follow: Option<&FollowedService>,
+) -> Result<Option<i32>, Box<dyn Error>> {
     let ctrlc = foreground_ctrlc()?;
-    wait_for_foreground_attachment_with_ctrlc(project_id, streaming, shutdown, ctrlc)
+    wait_for_foreground_attachment_with_ctrlc(
+        project_id, streaming, shutdown, ctrlc, follow,
+    )
 }
 
 /// Installs the foreground Ctrl-C handler and returns its notification channel.
@@ -4566,7 +6098,8 @@ fn wait_for_foreground_attachment_with_ctrlc(
     streaming: Arc<AtomicBool>,
     shutdown: Arc<std::sync::Mutex<Option<std::os::unix::net::UnixStream>>>,
     ctrlc: mpsc::Receiver<()>,
-) -> Result<(), Box<dyn Error>> {
+    follow: Option<&FollowedService>,
+) -> Result<Option<i32>, Box<dyn Error>> {
     // Wake on Ctrl-C, the supervisor disappearing, OR this project being stopped
     // out from under us. Blocking only on Ctrl-C wedged the foreground forever
     // when a `stop --supervisor`/crash tore the supervisor down, OR when a
@@ -4581,6 +6114,16 @@ fn wait_for_foreground_attachment_with_ctrlc(
     // left to receive Ctrl-C. Only trust "gone" once it has actually been seen.
     let mut project_ever_seen = false;
     let startup_grace = Instant::now() + systemg::constants::FOREGROUND_ATTACH_GRACE;
+    // Both callers get here only once the boot report came back clean, so this
+    // is the moment a `Type=notify` unit becomes active. Heartbeats follow the
+    // liveness checks below: if the supervisor wedges, they stop and the
+    // manager's watchdog takes over.
+    let mut notifier = notify::Notifier::from_env();
+    if let Some(notifier) = notifier.as_mut()
+        && let Err(err) = notifier.ready()
+    {
+        warn!("Failed to notify the service manager of readiness: {err}");
+    }
     loop {
         if ctrlc.recv_timeout(FOREGROUND_POLL_INTERVAL).is_ok() {
             break;
@@ -4588,19 +6131,34 @@ fn wait_for_foreground_attachment_with_ctrlc(
         if !supervisor_running() {
             info!("Foreground supervisor is no longer running; detaching.");
             stop_foreground_follow(&streaming, &shutdown);
-            return Ok(());
+            return Ok(None);
         }
         if project_loaded_in_supervisor(&project_id) {
             project_ever_seen = true;
         } else if project_ever_seen || Instant::now() >= startup_grace {
             info!("Foreground project '{project_id}' was stopped elsewhere; detaching.");
             stop_foreground_follow(&streaming, &shutdown);
-            return Ok(());
+            return Ok(None);
+        }
+        if let Some(exit_code) = follow.and_then(FollowedService::exit_code) {
+            // Give the log follow a beat to drain the service's final lines.
+            thread::sleep(FOREGROUND_POLL_INTERVAL);
+            stop_foreground_follow(&streaming, &shutdown);
+            stop_foreground_project(&project_id)?;
+            return Ok(Some(exit_code));
+        }
+        if let Some(notifier) = notifier.as_mut()
+            && let Err(err) = notifier.heartbeat()
+        {
+            warn!("Failed to send a watchdog heartbeat: {err}");
         }
     }
 
     // Ctrl-C: stop the console stream at once, then tear down the project.
     stop_foreground_follow(&streaming, &shutdown);
+    if let Some(notifier) = notifier.as_ref() {
+        let _ = notifier.stopping();
+    }
 
     stop_foreground_project(&project_id)?;
 
@@ -4610,7 +6168,7 @@ fn wait_for_foreground_attachment_with_ctrlc(
     // stays running, idle and ready for the next `sysg start`. It ends ONLY on an
     // explicit `sysg stop --supervisor` (or `purge`).
 
-    Ok(())
+    Ok(None)
 }
 
 fn stop_foreground_project(project_id: &str) -> Result<(), Box<dyn Error>> {
@@ -4668,7 +6226,10 @@ fn wait_for_supervisor_ready(child_pid: libc::pid_t) -> Result<(), Box<dyn Error
 /// Dispatches a resolved (preflight-cleared) restart plan.
 fn dispatch_restart(
     plan: systemg::restart::RestartPlan,
+    with_dependents: bool,
+    build_first: bool,
     daemonize: bool,
+    wait: bool,
     verbose: bool,
 ) -> Result<(), Box<dyn Error>> {
     use systemg::restart::RestartPlan;
@@ -4681,7 +6242,32 @@ fn dispatch_restart(
 
     if !supervisor_running() {
         if daemonize {
-            return start_supervisor_daemon(config_path, None, false, verbose);
+            start_supervisor_daemon(
+                config_path,
+                None,
+                false,
+                verbose,
+                &BTreeMap::new(),
+                false,
+            )?;
+            if !wait {
+                return Ok(());
+            }
+            // A fresh supervisor booted everything, so there is nothing left to
+            // restart; hold it to the same post-restart check instead.
+            let (service, project) = match plan {
+                RestartPlan::Service {
+                    service, project, ..
+                } => (Some(service), project),
+                RestartPlan::Project { project, .. } => (None, Some(project)),
+                _ => (None, None),
+            };
+            let verify = ControlCommand::VerifyRestart {
+                service,
+                project,
+                with_dependents,
+            };
+            return with_progress_message("Verifying", || send_restart_and_wait(verify));
         }
         warn!(
             "No running supervisor detected; executing restart in local one-shot mode. \
@@ -4701,11 +6287,17 @@ Use --daemonize in deployment scripts to ensure daemonized supervision is restor
             config: restart_scoped_config(&config),
             service: None,
             project: None,
+            with_dependents: false,
+            build_first: false,
+            wait,
         },
         RestartPlan::Project { config, project } => ControlCommand::Restart {
             config: restart_scoped_config(&config),
             service: None,
             project: Some(project),
+            with_dependents: false,
+            build_first: false,
+            wait,
         },
         RestartPlan::Service {
             config,
@@ -4718,16 +6310,68 @@ Use --daemonize in deployment scripts to ensure daemonized supervision is restor
             config: restart_scoped_config(&config),
             service: Some(service),
             project,
+            with_dependents,
+            build_first,
+            wait,
         },
     };
 
-    if daemonize {
+    if wait {
+        // Same drift check `restart_daemonized` runs, ahead of the wait.
+        if daemonize {
+            check_restart_version_drift(false);
+        }
+        with_progress_message("Restarting", || send_restart_and_wait(command))
+    } else if daemonize {
         restart_daemonized(command, config_path, false)
     } else {
         with_progress_message("Restarting", || send_control_message(command))
     }
 }
 
+/// Asks the resident supervisor to restart only its failing services. Failure
+/// is a supervised notion, so there is no local one-shot fallback.
+fn dispatch_restart_failed(project: Option<String>) -> Result<(), Box<dyn Error>> {
+    match supervisor_health() {
+        SupervisorHealth::Serving => {}
+        SupervisorHealth::Dying => {
+            return Err(Box::new(DiagError(Box::new(
+                supervisor_not_responding_diag(),
+            ))));
+        }
+        SupervisorHealth::Down => {
+            return Err(Box::new(DiagError(Box::new(
+                systemg::status::diagnostics::supervisor_offline(),
+            ))));
+        }
+    }
+    with_progress_message("Restarting", || {
+        send_control_message(ControlCommand::RestartFailed { project })
+    })
+}
+
+/// Sends `sysg pause`/`sysg resume` to the resident supervisor. Pausing is a
+/// property of the running monitor, so there is nothing to do without one.
+fn dispatch_pause_toggle(
+    label: &'static str,
+    command: ControlCommand,
+) -> Result<(), Box<dyn Error>> {
+    match supervisor_health() {
+        SupervisorHealth::Serving => {}
+        SupervisorHealth::Dying => {
+            return Err(Box::new(DiagError(Box::new(
+                supervisor_not_responding_diag(),
+            ))));
+        }
+        SupervisorHealth::Down => {
+            return Err(Box::new(DiagError(Box::new(
+                systemg::status::diagnostics::supervisor_offline(),
+            ))));
+        }
+    }
+    with_progress_message(label, || send_control_message(command))
+}
+
 /// The config path a restart plan carries (for the not-running fork/one-shot).
 fn restart_plan_config(plan: &systemg::restart::RestartPlan) -> PathBuf {
     use systemg::restart::RestartPlan;
@@ -4767,6 +6411,24 @@ fn stop_plan_diag(err: systemg::stop::StopPlanError) -> DiagError {
         )
         .note("--supervisor shuts the whole supervisor down; drop -s/-p to use it")
         .help_docs(),
+        StopPlanError::OrphansWithService => systemg::diag::Diagnostic::error(
+            systemg::diag::SgCode::ConflictingSelectors,
+            "--orphans cannot be combined with a service selector",
+        )
+        .note("orphaned units are, by definition, not named in the config; drop -s")
+        .help_docs(),
+        StopPlanError::InvalidTimeout { value, reason } => {
+            systemg::diag::Diagnostic::error(
+                systemg::diag::SgCode::Catchall,
+                format!("`--timeout` value `{value}` is not a valid duration"),
+            )
+            .note(reason)
+            .help_cmd(
+                "use milliseconds or a unit",
+                "sysg stop -s web --timeout 10s",
+            )
+            .help_docs()
+        }
     };
     DiagError(Box::new(diag))
 }
@@ -4798,20 +6460,50 @@ fn dispatch_stop(plan: systemg::stop::StopPlan) -> Result<(), Box<dyn Error>> {
         ))));
     }
 
+    if let StopPlan::Orphans { config, project } = plan {
+        if health == SupervisorHealth::Serving {
+            return with_progress_message("Stopping", || {
+                send_control_message(ControlCommand::StopOrphans { project })
+            });
+        }
+        // Without a supervisor, sweep the on-disk state of the local config.
+        let daemon = build_daemon(&config.to_string_lossy())?;
+        let cleared = daemon.stop_orphans()?;
+        if cleared.is_empty() {
+            println!("No orphaned units");
+        } else {
+            println!(
+                "Cleared {} orphaned unit(s): {}",
+                cleared.len(),
+                cleared.join(", ")
+            );
+        }
+        return Ok(());
+    }
+
     if health == SupervisorHealth::Serving {
         let command = match plan {
-            StopPlan::Supervisor => unreachable!("handled above"),
+            StopPlan::Supervisor | StopPlan::Orphans { .. } => {
+                unreachable!("handled above")
+            }
             StopPlan::Everything { .. } => ControlCommand::Stop {
                 service: None,
                 project: None,
+                timeout_ms: None,
             },
             StopPlan::Project { project } => ControlCommand::Stop {
                 service: None,
                 project: Some(project),
+                timeout_ms: None,
             },
-            StopPlan::Service { service, project } => ControlCommand::Stop {
+            StopPlan::Service {
+                service,
+                project,
+                grace,
+            } => ControlCommand::Stop {
                 service: Some(service),
                 project,
+                timeout_ms: grace.map(|grace| grace.as_millis() as u64),
             },
         };
         return with_progress_message("Stopping", || send_control_message(command));
@@ -4835,13 +6527,19 @@ fn dispatch_stop(plan: systemg::stop::StopPlan) -> Result<(), Box<dyn Error>> {
         && project_loaded_in_supervisor(&project)
     {
         let command = match plan {
-            StopPlan::Service { service, project } => ControlCommand::Stop {
+            StopPlan::Service {
+                service,
+                project,
+                grace,
+            } => ControlCommand::Stop {
                 service: Some(service),
                 project,
+                timeout_ms: grace.map(|grace| grace.as_millis() as u64),
             },
             _ => ControlCommand::Stop {
                 service: None,
                 project: Some(project),
+                timeout_ms: None,
             },
         };
         return with_progress_message("Stopping", || send_control_message(command));
@@ -4872,22 +6570,425 @@ fn dispatch_stop(plan: systemg::stop::StopPlan) -> Result<(), Box<dyn Error>> {
         ))));
     }
 
-    // No supervisor context for the target: run a one-shot local stop from the
-    // config on disk.
-    let config = match &plan {
-        StopPlan::Everything { config } => config.to_string_lossy().to_string(),
-        _ => resolve_config_path(DEFAULT_CONFIG_PATH)
-            .map(|p| p.to_string_lossy().to_string())
-            .unwrap_or_else(|_| DEFAULT_CONFIG_PATH.to_string()),
-    };
-    let daemon = build_daemon(&config)?;
-    match plan {
-        StopPlan::Service { service, .. } => daemon.stop_service(&service)?,
-        _ => daemon.stop_services()?,
+    // No supervisor context for the target: run a one-shot local stop from the
+    // config on disk.
+    let config = match &plan {
+        StopPlan::Everything { config } => config.to_string_lossy().to_string(),
+        _ => resolve_config_path(DEFAULT_CONFIG_PATH)
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_else(|_| DEFAULT_CONFIG_PATH.to_string()),
+    };
+    let daemon = build_daemon(&config)?;
+    match plan {
+        StopPlan::Service { service, grace, .. } => {
+            let grace = grace.unwrap_or_else(|| daemon.stop_grace(&service));
+            let outcome = daemon.stop_service_within(&service, grace)?;
+            println!("Service '{service}' {outcome}");
+        }
+        _ => daemon.stop_services()?,
+    }
+    Ok(())
+}
+
+/// Merges positional service names with repeated `--service` flags, keeping
+/// first-seen order and dropping repeats.
+fn merge_service_targets(positional: Vec<String>, flagged: Vec<String>) -> Vec<String> {
+    let mut merged: Vec<String> = Vec::with_capacity(positional.len() + flagged.len());
+    for service in positional.into_iter().chain(flagged) {
+        if !merged.contains(&service) {
+            merged.push(service);
+        }
+    }
+    merged
+}
+
+/// Applies `action` to several services and reports how each one fared.
+///
+/// A serving supervisor gets the whole list in one [`ControlCommand::Bulk`], so
+/// the batch runs back to back on its owner thread. With no supervisor only a
+/// stop can run, one-shot from the config on disk; there is nothing to start or
+/// restart against.
+fn dispatch_bulk(
+    action: BulkAction,
+    services: Vec<String>,
+    project: Option<String>,
+    config: &str,
+) -> Result<(), Box<dyn Error>> {
+    use systemg::diag::{Diagnostic, SgCode};
+
+    let outcomes = match supervisor_health() {
+        SupervisorHealth::Serving => {
+            let command = ControlCommand::Bulk {
+                action: action.clone(),
+                services,
+                project,
+            };
+            let label = match action {
+                BulkAction::Start => "Starting",
+                BulkAction::Stop { .. } => "Stopping",
+                BulkAction::Restart { .. } => "Restarting",
+            };
+            with_progress_spinner(label, || match ipc::send_command_and_wait(&command) {
+                Ok(ControlResponse::Bulk(outcomes)) => Ok(outcomes),
+                Ok(ControlResponse::Error(message)) => {
+                    Err(ControlError::Server(message).into())
+                }
+                Ok(ControlResponse::Diag(diag)) => {
+                    Err(Box::new(DiagError(diag)) as Box<dyn Error>)
+                }
+                Ok(other) => Err(io::Error::other(format!(
+                    "unexpected supervisor response: {other:?}"
+                ))
+                .into()),
+                Err(err) => Err(err.into()),
+            })?
+        }
+        SupervisorHealth::Dying => {
+            return Err(Box::new(DiagError(Box::new(
+                supervisor_not_responding_diag(),
+            ))));
+        }
+        SupervisorHealth::Down => {
+            let BulkAction::Stop { timeout_ms } = action else {
+                return Err(Box::new(DiagError(Box::new(
+                    Diagnostic::error(
+                        SgCode::SupervisorOffline,
+                        "no supervisor is running to apply the command to several services",
+                    )
+                    .note(format!(
+                        "services are {} through the running supervisor",
+                        action.past_tense()
+                    ))
+                    .help_cmd("start the supervisor first", "sysg start --daemonize")
+                    .help_docs(),
+                ))));
+            };
+            cleanup_stopped_runtime();
+            let config = resolve_config_path(config)
+                .map(|path| path.to_string_lossy().to_string())
+                .unwrap_or_else(|_| config.to_string());
+            let daemon = build_daemon(&config)?;
+            let grace = timeout_ms.map(Duration::from_millis);
+            services
+                .into_iter()
+                .map(|service| {
+                    let name = service_selector_name(&service);
+                    let grace = grace.unwrap_or_else(|| daemon.stop_grace(name));
+                    let error = daemon
+                        .stop_service_within(name, grace)
+                        .err()
+                        .map(|err| err.to_string());
+                    BulkOutcome { service, error }
+                })
+                .collect()
+        }
+    };
+
+    for line in format_bulk_report(&action, &outcomes, color_enabled()) {
+        println!("{line}");
+    }
+    let failed: Vec<&str> = outcomes
+        .iter()
+        .filter(|outcome| outcome.error.is_some())
+        .map(|outcome| outcome.service.as_str())
+        .collect();
+    if failed.is_empty() {
+        return Ok(());
+    }
+    let mut diag = Diagnostic::error(
+        SgCode::Catchall,
+        format!(
+            "{} of {} services could not be {}",
+            failed.len(),
+            outcomes.len(),
+            action.past_tense()
+        ),
+    )
+    .note(format!("failed: {}", failed.join(", ")));
+    for service in failed.iter().take(3) {
+        diag = diag.help_cmd(
+            format!("why '{service}' failed"),
+            format!("sysg logs -s {service}"),
+        );
+    }
+    Err(Box::new(DiagError(Box::new(
+        diag.help_cmd("check status", "sysg status").help_docs(),
+    ))))
+}
+
+/// One line per service of a bulk operation, then a one-line summary.
+fn format_bulk_report(
+    action: &BulkAction,
+    outcomes: &[BulkOutcome],
+    use_color: bool,
+) -> Vec<String> {
+    let paint = |code: &str, text: &str| {
+        if use_color {
+            format!("{code}{text}{RESET}")
+        } else {
+            text.to_string()
+        }
+    };
+    let width = outcomes
+        .iter()
+        .map(|outcome| outcome.service.len())
+        .max()
+        .unwrap_or(0);
+    let mut lines: Vec<String> = outcomes
+        .iter()
+        .map(|outcome| match &outcome.error {
+            None => format!(
+                "  {} {:<width$}  {}",
+                paint(GREEN_BOLD, "✓"),
+                outcome.service,
+                action.past_tense()
+            ),
+            Some(error) => format!(
+                "  {} {:<width$}  {}",
+                paint(RED_BOLD, "✗"),
+                outcome.service,
+                paint(RED, error)
+            ),
+        })
+        .collect();
+    let failed = outcomes
+        .iter()
+        .filter(|outcome| outcome.error.is_some())
+        .count();
+    let summary = format!(
+        "{} of {} services {}",
+        outcomes.len() - failed,
+        outcomes.len(),
+        action.past_tense()
+    );
+    lines.push(if failed == 0 {
+        format!("  {}", paint(GRAY, &summary))
+    } else {
+        format!("  {}", paint(GRAY, &format!("{summary} · {failed} failed")))
+    });
+    lines
+}
+
+/// Renders a wait-plan resolution failure as a typed diagnostic.
+fn wait_plan_diag(err: systemg::wait::WaitPlanError, service: &str) -> DiagError {
+    use systemg::wait::WaitPlanError;
+    let diag = match err {
+        WaitPlanError::Mismatch(mismatch) => {
+            systemg::start::project_mismatch(&mismatch.flag, &mismatch.selector)
+        }
+        WaitPlanError::NotAService => systemg::inspect::service_not_found(service),
+        WaitPlanError::InvalidTimeout { value, reason } => {
+            systemg::diag::Diagnostic::error(
+                systemg::diag::SgCode::Catchall,
+                format!("`--timeout` value `{value}` is not a valid duration"),
+            )
+            .note(reason)
+            .help_cmd("use seconds or a unit", "sysg wait web --timeout 30s")
+            .help_docs()
+        }
+    };
+    DiagError(Box::new(diag))
+}
+
+/// Polls status readings until the planned service reaches its condition.
+///
+/// A failed reading (no supervisor yet, or one that is mid-restart) counts as
+/// pending rather than fatal, so `sysg start --daemonize && sysg wait web` works
+/// even while the supervisor is still coming up. Only the deadline or a unit
+/// that can no longer reach the condition ends the wait with an error.
+fn dispatch_wait(
+    plan: systemg::wait::WaitPlan,
+    config: Option<&str>,
+) -> Result<(), Box<dyn Error>> {
+    use systemg::wait::WaitVerdict;
+
+    let deadline = Instant::now() + plan.timeout;
+    loop {
+        let last_seen = match fetch_status_snapshot(config, false) {
+            Ok(snapshot) => {
+                if let Some(diag) = status_ambiguous_service(
+                    &snapshot,
+                    Some(&plan.service),
+                    plan.project.as_deref(),
+                ) {
+                    return Err(Box::new(DiagError(Box::new(diag))));
+                }
+                let unit = snapshot.units.iter().find(|unit| {
+                    unit.kind != UnitKind::Orphaned
+                        && status_unit_matches_selector(
+                            unit,
+                            Some(&plan.service),
+                            plan.project.as_deref(),
+                        )
+                });
+                match systemg::wait::evaluate(plan.condition, unit) {
+                    WaitVerdict::Met => return Ok(()),
+                    WaitVerdict::Pending => {}
+                    WaitVerdict::Unreachable(reason) => {
+                        return Err(Box::new(DiagError(Box::new(
+                            systemg::wait::condition_unreachable(
+                                &plan.service,
+                                plan.condition,
+                                &reason,
+                            ),
+                        ))));
+                    }
+                }
+                unit.map(|unit| unit_state_plain_label(unit.state))
+                    .unwrap_or("not registered with the supervisor")
+                    .to_string()
+            }
+            Err(err) => err.to_string(),
+        };
+        if Instant::now() >= deadline {
+            return Err(Box::new(DiagError(Box::new(systemg::wait::timed_out(
+                &plan.service,
+                plan.condition,
+                plan.timeout,
+                &last_seen,
+            )))));
+        }
+        thread::sleep(WAIT_POLL_INTERVAL);
+    }
+}
+
+/// Resolves and runs a `restart`. `reload` shares this path with no service
+/// selector, since applying a reload is a whole-project restart.
+#[allow(clippy::too_many_arguments)]
+fn run_restart(
+    config: &str,
+    service: Option<&str>,
+    with_dependents: bool,
+    build_first: bool,
+    project: Option<&str>,
+    daemonize: bool,
+    wait: bool,
+    verbose: bool,
+) -> Result<(), Box<dyn Error>> {
+    let config_path = resolve_config_path(config).unwrap_or_else(|_| config.into());
+    let plan = systemg::restart::resolve_plan(config_path, service, project).map_err(
+        |mismatch| {
+            DiagError(Box::new(systemg::start::project_mismatch(
+                &mismatch.flag,
+                &mismatch.selector,
+            )))
+        },
+    )?;
+    if build_first && !matches!(plan, systemg::restart::RestartPlan::Service { .. }) {
+        return Err(Box::new(DiagError(Box::new(
+            systemg::restart::build_first_needs_service(),
+        ))));
+    }
+
+    let world = systemg::restart::World {
+        supervisor_running: supervisor_running(),
+        version_drifted: matches!(
+            daemon_version_drift(),
+            VersionDrift::Drifted(_) | VersionDrift::PreVersionDaemon
+        ),
+    };
+    match systemg::restart::preflight(plan, world) {
+        systemg::restart::Preflight::Refused(diag) => Err(Box::new(DiagError(diag))),
+        systemg::restart::Preflight::Ready(plan) => {
+            dispatch_restart(plan, with_dependents, build_first, daemonize, wait, verbose)
+        }
+    }
+}
+
+/// Prints what `sysg reload` would change, comparing the on-disk manifest
+/// against the field hashes the running supervisor reports.
+fn dispatch_reload_preview(
+    config: &str,
+    project: Option<&str>,
+    no_color: bool,
+) -> Result<(), Box<dyn Error>> {
+    if !supervisor_running() {
+        return Err(Box::new(DiagError(Box::new(
+            systemg::status::diagnostics::supervisor_offline(),
+        ))));
+    }
+    let config_path = resolve_config_path(config)?;
+    let projects = systemg::config::load_projects_from_file(
+        fs::File::open(&config_path)?,
+        &config_path,
+    )?;
+    let hashes = match ipc::send_command(&ControlCommand::ServiceHashes {
+        project: project.map(str::to_string),
+    })? {
+        ControlResponse::ServiceHashes(hashes) => hashes,
+        ControlResponse::Error(message) => {
+            return Err(ControlError::Server(message).into());
+        }
+        other => {
+            return Err(io::Error::other(format!(
+                "unexpected supervisor response: {other:?}"
+            ))
+            .into());
+        }
+    };
+
+    let selected = projects
+        .iter()
+        .filter(|config| project.is_none_or(|id| id == config.project.id))
+        .collect::<Vec<_>>();
+    if selected.is_empty() {
+        return Err(io::Error::other(format!(
+            "project `{}` is not declared in {}",
+            project.unwrap_or_default(),
+            config_path.display()
+        ))
+        .into());
+    }
+
+    let use_color = !color_disabled(no_color);
+    for config in selected {
+        let preview = systemg::reload::preview(config, hashes.get(&config.project.id));
+        render_reload_preview(&preview, use_color);
     }
     Ok(())
 }
 
+/// Renders one project's reload preview as a `+`/`-`/`~` diff.
+fn render_reload_preview(preview: &systemg::reload::ReloadPreview, use_color: bool) {
+    use systemg::reload::ReloadChange;
+
+    let paint = |code: &str, text: &str| {
+        if use_color {
+            format!("{code}{text}{RESET}")
+        } else {
+            text.to_string()
+        }
+    };
+
+    println!();
+    let summary = if !preview.loaded {
+        "not running · reload would start it"
+    } else if preview.is_noop() {
+        "no changes"
+    } else {
+        "changes pending"
+    };
+    println!(
+        "  {}  {}",
+        paint(BRIGHT_WHITE, &preview.project),
+        paint(GRAY, &format!("· {summary}"))
+    );
+    for (name, change) in &preview.services {
+        let line = match change {
+            ReloadChange::Add => paint(GREEN, &format!("+ {name}")),
+            ReloadChange::Remove => paint(RED, &format!("- {name}")),
+            ReloadChange::Restart { fields } => format!(
+                "{} {}",
+                paint(YELLOW, &format!("~ {name}")),
+                paint(GRAY, &format!("({})", fields.join(", ")))
+            ),
+            ReloadChange::Unchanged => paint(GRAY, &format!("  {name}")),
+        };
+        println!("    {line}");
+    }
+    println!();
+}
+
 /// Dispatches a `--daemonize` start plan: routes to the resident supervisor
 /// when one is running, otherwise forks a fresh supervisor from the plan's
 /// config.
@@ -4896,6 +6997,8 @@ fn dispatch_start_daemonize(
     stderr: bool,
     verbose: bool,
     drop_privileges: bool,
+    env: &BTreeMap<String, String>,
+    parallel: bool,
 ) -> Result<(), Box<dyn Error>> {
     match supervisor_health() {
         SupervisorHealth::Serving => {
@@ -4904,7 +7007,12 @@ fn dispatch_start_daemonize(
                     "--drop-privileges is managed by the running supervisor and has no effect for this start request"
                 );
             }
-            match dispatch_start_resident(plan.clone()) {
+            if parallel {
+                warn!(
+                    "--parallel is managed by the running supervisor and has no effect for this start request"
+                );
+            }
+            match dispatch_start_resident(plan.clone(), env) {
                 Ok(()) => return Ok(()),
                 Err(err) if error_is_supervisor_shutting_down(err.as_ref()) => {
                     wait_for_runtime_cleared(SUPERVISOR_RUNTIME_TIMEOUT);
@@ -4957,7 +7065,7 @@ fn dispatch_start_daemonize(
     }
     let config = plan_config(plan);
     info!("Starting systemg supervisor with config {:?}", config);
-    start_supervisor_daemon(config, service, stderr, verbose)
+    start_supervisor_daemon(config, service, stderr, verbose, env, parallel)
 }
 
 /// The config path a plan carries.
@@ -4974,9 +7082,31 @@ fn plan_config(plan: systemg::start::StartPlan) -> PathBuf {
 /// Sends a resident supervisor the control command for `plan`.
 fn dispatch_start_resident(
     plan: systemg::start::StartPlan,
+    env: &BTreeMap<String, String>,
 ) -> Result<(), Box<dyn Error>> {
     use systemg::start::StartPlan;
 
+    // Overrides are applied while a manifest is loaded. A `-p`/`-s` start only
+    // asks the supervisor to start what it already holds, so there is nothing
+    // to apply them to.
+    if !env.is_empty()
+        && matches!(plan, StartPlan::Project { .. } | StartPlan::Service { .. })
+    {
+        use systemg::diag::{Diagnostic, SgCode};
+        return Err(Box::new(DiagError(Box::new(
+            Diagnostic::error(
+                SgCode::Catchall,
+                "--env cannot target a project the running supervisor already holds",
+            )
+            .note("overrides are applied when `sysg start` loads a manifest")
+            .help_cmd(
+                "start the whole config with the overrides",
+                "sysg start --daemonize --env KEY=VALUE",
+            )
+            .help_docs(),
+        ))));
+    }
+
     let command = match plan {
         StartPlan::StageAdHoc { config } => {
             info!(
@@ -4993,6 +7123,7 @@ fn dispatch_start_resident(
             config: config.to_string_lossy().to_string(),
             service: None,
             mode: ProjectRunMode::Daemon,
+            env: env.clone(),
         },
         StartPlan::Project { project, .. } => ControlCommand::Start {
             service: None,
@@ -5217,7 +7348,10 @@ fn await_queued_boot(project: &str) -> Result<QueuedBoot, ControlError> {
 /// Cron units are excluded because they never indicate whether services booted.
 fn project_service_units(project: &str) -> Result<ProjectUnits, ControlError> {
     let snapshot = match ipc::send_command_with_timeout(
-        &ControlCommand::Status { live: false },
+        &ControlCommand::Status {
+            live: false,
+            no_children: false,
+        },
         SUPERVISOR_REQUEST_TIMEOUT,
     )? {
         ipc::CommandAck::Response(ControlResponse::Status(snapshot)) => snapshot,
@@ -5269,26 +7403,181 @@ fn error_is_supervisor_shutting_down(err: &(dyn Error + 'static)) -> bool {
 fn dispatch_start_foreground(
     plan: systemg::start::StartPlan,
     stderr: bool,
+    env: &BTreeMap<String, String>,
+    parallel: bool,
 ) -> Result<(), Box<dyn Error>> {
     use systemg::start::StartPlan;
 
     if supervisor_running() {
+        if parallel {
+            warn!(
+                "--parallel is managed by the running supervisor and has no effect for this start request"
+            );
+        }
         // A targeted service/project routes to the resident supervisor; a whole
         // foreground config attaches to it and owns the terminal lifetime.
         return match plan {
-            StartPlan::WholeConfig { config } => start_foreground_attached(config, None),
-            other => dispatch_start_resident(other),
+            StartPlan::WholeConfig { config } => {
+                start_foreground_attached(config, None, env)
+            }
+            other => dispatch_start_resident(other, env),
         };
     }
 
     match plan {
         StartPlan::StageAdHoc { config }
         | StartPlan::WholeConfig { config }
-        | StartPlan::Project { config, .. } => start_foreground(config, None, stderr),
+        | StartPlan::Project { config, .. } => {
+            start_foreground(config, None, stderr, env, parallel, None)
+        }
         StartPlan::Service {
             config, service, ..
-        } => start_foreground(config, Some(service), stderr),
+        } => start_foreground(config, Some(service), stderr, env, parallel, None),
+    }
+    .map(|_| ())
+}
+
+/// The one-shot service a `sysg start --follow-exit` run waits on.
+struct FollowedService {
+    /// State directory of the service's project.
+    store: StateStore,
+    /// The service's persistent state key.
+    key: String,
+    /// When this run was dispatched. An exit recorded for a launch before it
+    /// belongs to an earlier run and is ignored.
+    since: SystemTime,
+}
+
+impl FollowedService {
+    /// Follows `key` from now on. State timestamps are stored in whole
+    /// seconds, so `since` is truncated to match.
+    fn new(store: StateStore, key: String) -> Self {
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default();
+        Self {
+            store,
+            key,
+            since: SystemTime::UNIX_EPOCH + Duration::from_secs(now.as_secs()),
+        }
+    }
+
+    /// The service's exit code once this run's launch has exited, mapping a
+    /// fatal signal to the shell's `128 + signal` convention.
+    fn exit_code(&self) -> Option<i32> {
+        let state = ServiceStateFile::load(self.store.clone()).ok()?;
+        let entry = state
+            .get(&self.key)
+            .filter(|entry| entry.started_at.is_some_and(|at| at >= self.since))?;
+        match entry.status {
+            ServiceLifecycleStatus::ExitedSuccessfully => Some(0),
+            ServiceLifecycleStatus::ExitedWithError => Some(
+                entry
+                    .exit_code
+                    .or(entry.signal.map(|signal| 128 + signal))
+                    .unwrap_or(1),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// Runs a single one-shot service in the foreground and returns its exit code,
+/// so `sysg start --follow-exit -s migrate` can gate a CI step on it.
+fn dispatch_start_follow_exit(
+    plan: systemg::start::StartPlan,
+    stderr: bool,
+    env: &BTreeMap<String, String>,
+    parallel: bool,
+) -> Result<i32, Box<dyn Error>> {
+    use systemg::{
+        diag::{Diagnostic, SgCode},
+        start::StartPlan,
+    };
+
+    let StartPlan::Service {
+        config: config_path,
+        service,
+        project,
+    } = plan
+    else {
+        let diag = Diagnostic::error(SgCode::Catchall, "--follow-exit needs one service")
+            .help_cmd("name the service", "sysg start --follow-exit -s migrate")
+            .help_docs();
+        return Err(Box::new(DiagError(Box::new(diag))));
+    };
+    let config = load_config(Some(config_path.to_string_lossy().as_ref()))?;
+    let Some(service_config) = config.services.get(&service) else {
+        return Err(Box::new(DiagError(Box::new(
+            systemg::inspect::service_not_found(&service),
+        ))));
+    };
+    if service_config.restarts_after_failure() {
+        let diag = Diagnostic::error(
+            SgCode::Catchall,
+            format!("service `{service}` restarts after failure"),
+        )
+        .note("--follow-exit waits for a one-shot service's own exit")
+        .help_cmd(
+            "make it one-shot",
+            format!("set `restart_policy: never` on `{service}`"),
+        )
+        .help_docs();
+        return Err(Box::new(DiagError(Box::new(diag))));
     }
+    let follow = FollowedService::new(
+        StateStore::for_project(&config.project.id),
+        config.state_key(&service),
+    );
+
+    let exit_code = if supervisor_running() {
+        // The resident supervisor owns the service; start it there and poll
+        // its recorded state until the run finishes.
+        let plan = StartPlan::Service {
+            config: config_path,
+            service: service.clone(),
+            project,
+        };
+        if let Err(err) = dispatch_start_resident(plan, env) {
+            let Some(exit_code) = follow.exit_code() else {
+                return Err(err);
+            };
+            match err.downcast_ref::<DiagError>() {
+                Some(diag) => eprintln!("{}", diag.0.render_for_terminal()),
+                None => eprintln!("{err}"),
+            }
+            return Ok(exit_code);
+        }
+        loop {
+            if let Some(exit_code) = follow.exit_code() {
+                break Some(exit_code);
+            }
+            if !supervisor_running() {
+                break None;
+            }
+            thread::sleep(FOREGROUND_POLL_INTERVAL);
+        }
+    } else {
+        start_foreground(
+            config_path,
+            Some(service.clone()),
+            stderr,
+            env,
+            parallel,
+            Some(&follow),
+        )?
+        .or_else(|| follow.exit_code())
+    };
+
+    exit_code.ok_or_else(|| {
+        let diag = Diagnostic::error(
+            SgCode::Catchall,
+            format!("service `{service}` was stopped before it exited"),
+        )
+        .help_cmd("check status", format!("sysg status -s {service}"))
+        .help_docs();
+        Box::new(DiagError(Box::new(diag))) as Box<dyn Error>
+    })
 }
 
 /// The service name a plan targets, if it targets a single service.
@@ -5305,6 +7594,8 @@ fn start_supervisor_daemon(
     service: Option<String>,
     pipe_stderr: bool,
     verbose: bool,
+    env: &BTreeMap<String, String>,
+    parallel: bool,
 ) -> Result<(), Box<dyn Error>> {
     let child_pid = unsafe { libc::fork() };
     if child_pid < 0 {
@@ -5319,6 +7610,8 @@ fn start_supervisor_daemon(
             pipe_stderr,
             verbose,
             ProjectRunMode::Daemon,
+            env,
+            parallel,
         );
         run_supervisor_in_process(
             config_path,
@@ -5326,6 +7619,8 @@ fn start_supervisor_daemon(
             pipe_stderr,
             ProjectRunMode::Daemon,
             None,
+            env,
+            parallel,
         );
     }
 
@@ -5340,6 +7635,7 @@ fn start_supervisor_daemon(
                 config: config_path.to_string_lossy().to_string(),
                 service,
                 mode: ProjectRunMode::Daemon,
+                env: env.clone(),
             });
         }
         use systemg::diag::{Diagnostic, SgCode};
@@ -5366,7 +7662,7 @@ fn start_supervisor_daemon(
 /// forever. A recycle forks the daemon *after* `stop_supervisors` and the
 /// version probe have already spun up IPC / sysinfo / spinner threads, so the
 /// forked daemon inherited those poisoned locks and wedged — silent, and
-/// suppressing its own services' restarts. `execv` replaces the image wholesale,
+/// suppressing its own services' restarts. `exec` replaces the image wholesale,
 /// dropping every inherited thread and lock, so the daemon always boots pristine.
 /// On success this never returns; on failure it falls through to the in-process
 /// boot so a daemon still comes up.
@@ -5376,34 +7672,40 @@ fn reexec_supervisor(
     pipe_stderr: bool,
     verbose: bool,
     mode: ProjectRunMode,
+    env: &BTreeMap<String, String>,
+    parallel: bool,
 ) {
     let Ok(exe) = std::env::current_exe() else {
         return;
     };
-    let mut args: Vec<CString> = Vec::new();
-    let push = |args: &mut Vec<CString>, s: &str| {
-        if let Ok(c) = CString::new(s) {
-            args.push(c);
-        }
-    };
-    push(&mut args, &exe.to_string_lossy());
-    push(&mut args, "supervise");
-    push(&mut args, "--config");
-    push(&mut args, &config.to_string_lossy());
+    let mut command = process::Command::new(exe);
+    command.arg("supervise").arg("--config").arg(config);
     if let Some(service) = service {
-        push(&mut args, "--service");
-        push(&mut args, service);
+        command.arg("--service").arg(service);
     }
     if pipe_stderr {
-        push(&mut args, "--pipe-stderr");
+        command.arg("--pipe-stderr");
     }
     if verbose {
-        push(&mut args, "--verbose");
+        command.arg("--verbose");
     }
     if mode == ProjectRunMode::Foreground {
-        push(&mut args, "--foreground");
+        command.arg("--foreground");
     }
-    let _ = nix::unistd::execv(&args[0], &args);
+    for (key, value) in env {
+        command.arg("--env").arg(format!("{key}={value}"));
+    }
+    if parallel {
+        command.arg("--parallel");
+    }
+    // Readiness belongs to the foreground `sysg start`, which is what an init
+    // system tracks as the main pid; the supervisor and its services must not
+    // inherit the notify socket. Dropping the vars on the exec'd command keeps
+    // the forked child from mutating its own environment.
+    for var in notify::NOTIFY_ENV_VARS {
+        command.env_remove(var);
+    }
+    let _ = std::os::unix::process::CommandExt::exec(&mut command);
 }
 
 /// Boots the supervisor in the current process and exits. Reached post-`execv`
@@ -5414,13 +7716,15 @@ fn run_supervisor_in_process(
     pipe_stderr: bool,
     mode: ProjectRunMode,
     handoff: Option<PathBuf>,
+    env: &BTreeMap<String, String>,
+    parallel: bool,
 ) -> ! {
     install_supervisor_panic_hook();
     let handed_off = handoff.is_some();
     let handoff_path = handoff.clone();
     let supervisor = match handoff {
         Some(path) => Supervisor::from_handoff(path),
-        None => Supervisor::new_with_mode(config_path, false, service, mode),
+        None => Supervisor::new_with_mode(config_path, false, service, mode, env),
     };
     let mut supervisor = match supervisor {
         Ok(supervisor) => supervisor,
@@ -5437,6 +7741,7 @@ fn run_supervisor_in_process(
     };
     if !handed_off {
         supervisor.set_pipe_stderr(pipe_stderr);
+        supervisor.set_parallel_start(parallel);
     }
     exit_supervisor(supervisor.run());
 }
@@ -5827,7 +8132,10 @@ fn service_selector_name(selector: &str) -> &str {
 /// project's — the supervisor already knows the loaded project's config.
 fn project_loaded_in_supervisor(project_id: &str) -> bool {
     matches!(
-        ipc::send_command(&ControlCommand::Status { live: false }),
+        ipc::send_command(&ControlCommand::Status {
+            live: false,
+            no_children: false,
+        }),
         Ok(ControlResponse::Status(snapshot))
             if snapshot.units.iter().any(|unit| {
                 unit.project
@@ -5840,20 +8148,22 @@ fn project_loaded_in_supervisor(project_id: &str) -> bool {
 fn fail_project_mismatch(requested: &str, config_project: &str) -> ! {
     use systemg::diag::{Diagnostic, SgCode};
 
-    let loaded: Vec<String> =
-        match ipc::send_command(&ControlCommand::Status { live: false }) {
-            Ok(ControlResponse::Status(snapshot)) => {
-                let mut ids: Vec<String> = snapshot
-                    .units
-                    .iter()
-                    .filter_map(|unit| unit.project.as_ref().map(|p| p.id.clone()))
-                    .collect();
-                ids.sort();
-                ids.dedup();
-                ids
-            }
-            _ => Vec::new(),
-        };
+    let loaded: Vec<String> = match ipc::send_command(&ControlCommand::Status {
+        live: false,
+        no_children: false,
+    }) {
+        Ok(ControlResponse::Status(snapshot)) => {
+            let mut ids: Vec<String> = snapshot
+                .units
+                .iter()
+                .filter_map(|unit| unit.project.as_ref().map(|p| p.id.clone()))
+                .collect();
+            ids.sort();
+            ids.dedup();
+            ids
+        }
+        _ => Vec::new(),
+    };
 
     let mut diag = Diagnostic::error(
         SgCode::TargetConfigMismatch,
@@ -6004,6 +8314,122 @@ fn resolve_config_path(path: &str) -> Result<PathBuf, Box<dyn Error>> {
     Ok(cwd_candidate)
 }
 
+/// Lines printed by `sysg version`: the release alone, or with `--verbose` the
+/// build provenance, runtime paths and supervisor state a bug report needs.
+fn version_report(verbose: bool) -> Vec<String> {
+    let mut lines = vec![format!("systemg {}", env!("CARGO_PKG_VERSION"))];
+    if !verbose {
+        return lines;
+    }
+    let mode = match runtime::mode() {
+        RuntimeMode::User => "user",
+        RuntimeMode::System => "system",
+    };
+    let supervisor = match supervisor_health() {
+        SupervisorHealth::Serving => match verified_supervisor_pid() {
+            Some(pid) => {
+                let mut system = System::new();
+                let sys_pid = SysPid::from_u32(pid as u32);
+                system.refresh_processes(ProcessesToUpdate::Some(&[sys_pid]), true);
+                match system.process(sys_pid) {
+                    Some(process) => format!(
+                        "running (pid {pid}, up {})",
+                        format_inspect_elapsed(process.run_time())
+                    ),
+                    None => format!("running (pid {pid})"),
+                }
+            }
+            None => "running".to_string(),
+        },
+        SupervisorHealth::Dying => "not responding".to_string(),
+        SupervisorHealth::Down => "not running".to_string(),
+    };
+    lines.extend([
+        format!("commit:      {}", systemg::constants::BUILD_GIT_COMMIT),
+        format!("rustc:       {}", systemg::constants::BUILD_RUSTC_VERSION),
+        format!("target:      {}", systemg::constants::BUILD_TARGET),
+        format!("mode:        {mode}"),
+        format!(
+            "namespace:   {}",
+            runtime::namespace().unwrap_or_else(|| "default".to_string())
+        ),
+        format!("state dir:   {}", runtime::state_dir().display()),
+        format!("log dir:     {}", runtime::log_dir().display()),
+        format!("supervisor:  {supervisor}"),
+    ]);
+    lines
+}
+
+/// Environment variables `sysg snapshot` records besides `SYSG_*`/`SYSTEMG_*`.
+const SNAPSHOT_ENV_VARS: &[&str] = &["HOME", "LANG", "PATH", "RUST_LOG", "SHELL", "USER"];
+
+/// Collects the bug-report bundle for `sysg snapshot` and writes it to `out`,
+/// returning the archived file names. Anything that cannot be collected is
+/// archived as an `.error` entry so a broken install still yields a report.
+fn write_snapshot(config_path: &str, out: &str) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut redactor = Redactor::new();
+    redactor.learn_env(std::env::vars());
+    let config = load_config(Some(config_path)).ok();
+    if let Some(config) = &config {
+        redactor.learn_config(config);
+    }
+    let mut snapshot = Snapshot::new(redactor);
+
+    let mut environment = version_report(true);
+    environment.push(format!(
+        "os:          {} {}",
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    ));
+    if let Ok(kernel) = fs::read_to_string("/proc/version") {
+        environment.push(format!("kernel:      {}", kernel.trim()));
+    }
+    let mut vars = std::env::vars()
+        .filter(|(name, _)| {
+            SNAPSHOT_ENV_VARS.contains(&name.as_str())
+                || name.starts_with("SYSG_")
+                || name.starts_with("SYSTEMG_")
+        })
+        .collect::<Vec<_>>();
+    vars.sort();
+    environment.extend(
+        vars.into_iter()
+            .map(|(name, value)| format!("env:         {name}={value}")),
+    );
+    snapshot.add("environment.txt", &format!("{}\n", environment.join("\n")));
+
+    let config_name = Path::new(config_path)
+        .file_name()
+        .map_or_else(|| "config".into(), |name| name.to_string_lossy());
+    match fs::read_to_string(config_path) {
+        Ok(contents) => snapshot.add(format!("config/{config_name}"), &contents),
+        Err(err) => snapshot.add("config.error", &format!("{config_path}: {err}")),
+    }
+
+    match fetch_status_reading(config.is_some().then_some(config_path), false, true) {
+        Ok(reading) => snapshot.add(
+            "status.json",
+            &serde_json::to_string_pretty(&reading.snapshot)?,
+        ),
+        Err(err) => snapshot.add("status.error", &err.to_string()),
+    }
+
+    let log_path = supervisor_log_path();
+    match tail_capped(&log_path, SNAPSHOT_LOG_CAP) {
+        Ok(log) => snapshot.add("supervisor.log", &log),
+        Err(err) => snapshot.add(
+            "supervisor.log.error",
+            &format!("{}: {err}", log_path.display()),
+        ),
+    }
+
+    snapshot.add_project_state();
+    snapshot.write_archive(Path::new(out)).map_err(|err| {
+        io::Error::new(err.kind(), format!("failed to write {out}: {err}"))
+    })?;
+    Ok(snapshot.names().map(str::to_string).collect())
+}
+
 /// Handles supervisor running.
 fn supervisor_running() -> bool {
     verified_supervisor_pid().is_some()
@@ -6065,7 +8491,10 @@ fn probe_serving_supervisor() -> SupervisorHealth {
             .name(SUPERVISOR_PROBE_THREAD.into())
             .spawn(move || {
                 let ack = ipc::send_command_with_timeout(
-                    &ControlCommand::Status { live: false },
+                    &ControlCommand::Status {
+                        live: false,
+                        no_children: false,
+                    },
                     SUPERVISOR_PROBE_TIMEOUT,
                 );
                 let _ = tx.send(matches!(ack, Ok(ipc::CommandAck::Response(_))));
@@ -6117,36 +8546,13 @@ fn request_live_upgrade(binary: String) -> Result<String, Box<dyn Error>> {
         }
         SupervisorHealth::Serving => {}
     }
-    let resident = match ipc::send_command(&ControlCommand::Version) {
-        Ok(ControlResponse::DaemonVersion(version)) => version,
-        Ok(ControlResponse::Diag(diag)) => return Err(Box::new(DiagError(diag))),
-        Ok(other) => {
-            return Err(io::Error::other(format!(
-                "unexpected supervisor version response: {other:?}"
-            ))
-            .into());
-        }
-        Err(err) => return Err(err.into()),
-    };
+    let resident = resident_supervisor_version()?;
     if resident == target.version.to_string() {
         return Ok(format!("Supervisor is already running {resident}"));
     }
     systemg::upgrade::validate_resident_version(&resident, &target).map_err(DiagError)?;
     let original_pid = ipc::supervisor_peer_pid()?;
-    let expected = match ipc::send_command(&ControlCommand::Upgrade { binary }) {
-        Ok(ControlResponse::UpgradeAccepted { version }) => version,
-        Ok(ControlResponse::Diag(diag)) => return Err(Box::new(DiagError(diag))),
-        Ok(ControlResponse::Error(message)) => {
-            return Err(ControlError::Server(message).into());
-        }
-        Ok(other) => {
-            return Err(io::Error::other(format!(
-                "unexpected supervisor upgrade response: {other:?}"
-            ))
-            .into());
-        }
-        Err(err) => return Err(err.into()),
-    };
+    let expected = send_upgrade_request(ControlCommand::Upgrade { binary })?;
 
     let deadline = Instant::now() + UPGRADE_CONFIRM_TIMEOUT;
     let mut observed = resident;
@@ -6180,6 +8586,112 @@ fn request_live_upgrade(binary: String) -> Result<String, Box<dyn Error>> {
     ))))
 }
 
+/// Re-executes the resident supervisor from this binary and waits until the
+/// same PID has resumed the handed-off runtime. A newer binary goes through the
+/// regular live upgrade; a same-version binary is handed over in place.
+fn request_supervisor_reexec() -> Result<String, Box<dyn Error>> {
+    let target = systemg::upgrade::LiveUpgradeInfo::current();
+    match supervisor_health() {
+        SupervisorHealth::Down => {
+            return Ok("No running supervisor; nothing to re-execute".into());
+        }
+        SupervisorHealth::Dying => {
+            return Err(Box::new(DiagError(Box::new(
+                systemg::upgrade::environment_unsafe(
+                    "the resident supervisor is alive but is not answering its control socket",
+                ),
+            ))));
+        }
+        SupervisorHealth::Serving => {}
+    }
+    let binary = std::env::current_exe()
+        .and_then(fs::canonicalize)?
+        .to_string_lossy()
+        .to_string();
+    let resident = resident_supervisor_version()?;
+    let resident = systemg::upgrade::validate_reexec_resident(&resident, &target)
+        .map_err(DiagError)?;
+    if resident < target.version {
+        return request_live_upgrade(binary);
+    }
+
+    let original_pid = ipc::supervisor_peer_pid()?;
+    let expected = send_upgrade_request(ControlCommand::Reexec {
+        binary: binary.clone(),
+    })?;
+    // The old image keeps answering read-only commands until it calls `exec`,
+    // so a matching version alone proves nothing here; the handoff record is
+    // removed only once the new image has resumed.
+    let deadline = Instant::now() + UPGRADE_CONFIRM_TIMEOUT;
+    while Instant::now() < deadline {
+        if !systemg::upgrade::handoff_pending(original_pid)
+            && let Ok(ipc::CommandAck::Response(ControlResponse::DaemonVersion(version))) =
+                ipc::send_command_with_timeout(
+                    &ControlCommand::Version,
+                    UPGRADE_PROBE_TIMEOUT,
+                )
+            && version == expected
+        {
+            let resumed_pid = ipc::supervisor_peer_pid()?;
+            if resumed_pid != original_pid {
+                return Err(Box::new(DiagError(Box::new(
+                    systemg::upgrade::resume_failed(format!(
+                        "supervisor PID changed from {original_pid} to {resumed_pid}"
+                    )),
+                ))));
+            }
+            if let Ok(running) = fs::read_link(format!("/proc/{original_pid}/exe"))
+                && running != Path::new(&binary)
+            {
+                return Err(Box::new(DiagError(Box::new(
+                    systemg::upgrade::resume_failed(format!(
+                        "supervisor rolled back to {}",
+                        running.display()
+                    )),
+                ))));
+            }
+            return Ok(format!(
+                "Supervisor {expected} re-executed in place (pid {original_pid})"
+            ));
+        }
+        thread::sleep(UPGRADE_PROBE_INTERVAL);
+    }
+
+    Err(Box::new(DiagError(Box::new(
+        systemg::upgrade::resume_failed(
+            "the replacement supervisor did not resume its runtime in time",
+        ),
+    ))))
+}
+
+/// Asks the resident supervisor for its release version.
+fn resident_supervisor_version() -> Result<String, Box<dyn Error>> {
+    match ipc::send_command(&ControlCommand::Version) {
+        Ok(ControlResponse::DaemonVersion(version)) => Ok(version),
+        Ok(ControlResponse::Diag(diag)) => Err(Box::new(DiagError(diag))),
+        Ok(other) => Err(io::Error::other(format!(
+            "unexpected supervisor version response: {other:?}"
+        ))
+        .into()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Sends an upgrade or re-exec request and returns the version the supervisor
+/// accepted.
+fn send_upgrade_request(command: ControlCommand) -> Result<String, Box<dyn Error>> {
+    match ipc::send_command(&command) {
+        Ok(ControlResponse::UpgradeAccepted { version }) => Ok(version),
+        Ok(ControlResponse::Diag(diag)) => Err(Box::new(DiagError(diag))),
+        Ok(ControlResponse::Error(message)) => Err(ControlError::Server(message).into()),
+        Ok(other) => Err(io::Error::other(format!(
+            "unexpected supervisor upgrade response: {other:?}"
+        ))
+        .into()),
+        Err(err) => Err(err.into()),
+    }
+}
+
 /// Sends a control command and optionally renders its response message.
 fn send_control_command_inner(
     command: ControlCommand,
@@ -6212,8 +8724,21 @@ fn send_control_command_inner(
         Ok(ControlResponse::Error(message)) => Err(ControlError::Server(message).into()),
         Ok(ControlResponse::Diag(diag)) => Err(Box::new(DiagError(diag))),
         Ok(ControlResponse::CurrentOp(_)) => Ok(()),
+        Ok(ControlResponse::ServiceHashes(_)) => Ok(()),
+        Ok(ControlResponse::Samples(_)) => Ok(()),
+        Ok(ControlResponse::Services(_)) => Ok(()),
+        Ok(ControlResponse::Bulk(_)) => Ok(()),
+        Ok(ControlResponse::Restarted(report)) => {
+            if announce {
+                println!("{}", report.message);
+            }
+            Ok(())
+        }
         Err(ControlError::NotAvailable) => Err(ControlError::NotAvailable.into()),
         Err(ControlError::Timeout) => Err(supervisor_busy_error().into()),
+        Err(ControlError::Unresponsive) => Err(Box::new(DiagError(Box::new(
+            supervisor_not_responding_diag(),
+        )))),
         Err(err) => Err(err.into()),
     }
 }
@@ -6230,10 +8755,66 @@ fn send_control_message(command: ControlCommand) -> Result<String, Box<dyn Error
         .into()),
         Err(ControlError::NotAvailable) => Err(ControlError::NotAvailable.into()),
         Err(ControlError::Timeout) => Err(supervisor_busy_error().into()),
+        Err(ControlError::Unresponsive) => Err(Box::new(DiagError(Box::new(
+            supervisor_not_responding_diag(),
+        )))),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Sends a `wait` restart and summarizes the services the supervisor verified.
+///
+/// A service that fails verification comes back as an error, so the process
+/// exits non-zero for deploy scripts to catch.
+fn send_restart_and_wait(command: ControlCommand) -> Result<String, Box<dyn Error>> {
+    match ipc::send_command_and_wait(&command) {
+        Ok(ControlResponse::Restarted(report)) => {
+            let mut summary = report.message;
+            for service in &report.services {
+                if service.project == systemg::state_store::LOOSE_PROJECT_ID {
+                    summary.push_str(&format!("\n  verified {}", service.name));
+                } else {
+                    summary.push_str(&format!(
+                        "\n  verified {}/{}",
+                        service.project, service.name
+                    ));
+                }
+            }
+            Ok(summary)
+        }
+        Ok(ControlResponse::Error(message)) => Err(ControlError::Server(message).into()),
+        Ok(ControlResponse::Diag(diag)) => Err(Box::new(DiagError(diag))),
+        Ok(other) => Err(io::Error::other(format!(
+            "unexpected supervisor response: {other:?}"
+        ))
+        .into()),
+        Err(ControlError::Unresponsive) => Err(Box::new(DiagError(Box::new(
+            supervisor_not_responding_diag(),
+        )))),
+        Err(ControlError::Timeout) => {
+            Err(Box::new(DiagError(Box::new(restart_wait_timed_out_diag()))))
+        }
         Err(err) => Err(err.into()),
     }
 }
 
+/// Diagnostic for a `restart --wait` whose supervisor never answered within
+/// [`ipc::WAIT_READ_TIMEOUT`].
+fn restart_wait_timed_out_diag() -> systemg::diag::Diagnostic {
+    systemg::diag::Diagnostic::error(
+        systemg::diag::SgCode::Catchall,
+        format!(
+            "the restart was not confirmed within {}s",
+            ipc::WAIT_READ_TIMEOUT.as_secs()
+        ),
+    )
+    .note("the supervisor accepted the restart but never reported its outcome")
+    .note("it may still be restarting, or it may be wedged")
+    .help_cmd("see what it is doing", "sysg status")
+    .help_cmd("read the supervisor log", "sysg logs --supervisor")
+    .help_docs()
+}
+
 /// Builds the SG0107 diagnostic for a command the supervisor refused because it
 /// was already mid-mutation.
 ///
@@ -6271,22 +8852,8 @@ fn restart_daemonized(
     config_path: PathBuf,
     allow_recycle: bool,
 ) -> Result<(), Box<dyn Error>> {
-    match daemon_version_drift() {
-        VersionDrift::Drifted(daemon_version) => {
-            if allow_recycle {
-                return recycle_supervisor_for_restart(config_path);
-            }
-            eprintln!(
-                "{YELLOW}Warn: resident supervisor is v{daemon_version} but this CLI is v{}; run a full `sysg restart` to recycle it{RESET}",
-                env!("CARGO_PKG_VERSION")
-            );
-        }
-        VersionDrift::PreVersionDaemon => {
-            if allow_recycle {
-                return recycle_supervisor_for_restart(config_path);
-            }
-        }
-        VersionDrift::Matched | VersionDrift::Unknown => {}
+    if check_restart_version_drift(allow_recycle) {
+        return recycle_supervisor_for_restart(config_path);
     }
 
     match ipc::send_command_with_timeout(&command, RESTART_DAEMON_ACK_TIMEOUT) {
@@ -6315,6 +8882,29 @@ fn restart_daemonized(
     }
 }
 
+/// Compares the resident supervisor's version against this CLI before a
+/// daemonized restart. Returns true when the caller should recycle the
+/// supervisor instead; otherwise warns about drift it cannot fix.
+fn check_restart_version_drift(allow_recycle: bool) -> bool {
+    match daemon_version_drift() {
+        VersionDrift::Drifted(_) | VersionDrift::PreVersionDaemon if allow_recycle => {
+            true
+        }
+        VersionDrift::Drifted(daemon_version) => {
+            eprintln!(
+                "{}Warn: resident supervisor is v{daemon_version} but this CLI is v{}; run a full `sysg restart` to recycle it{}",
+                color_code(YELLOW, false),
+                env!("CARGO_PKG_VERSION"),
+                color_code(RESET, false)
+            );
+            false
+        }
+        VersionDrift::PreVersionDaemon
+        | VersionDrift::Matched
+        | VersionDrift::Unknown => false,
+    }
+}
+
 /// Outcome of comparing the resident supervisor's version against this CLI.
 enum VersionDrift {
     /// Daemon and CLI run the same version.
@@ -6372,12 +8962,13 @@ fn recycle_supervisor_for_restart(config_path: PathBuf) -> Result<(), Box<dyn Er
     wait_for_runtime_cleared(SUPERVISOR_RUNTIME_TIMEOUT);
     cleanup_stopped_runtime();
     let recovery_path = config_path.clone();
-    start_supervisor_daemon(config_path, None, false, false).map_err(|err| {
-        Box::new(DiagError(Box::new(systemg::restart::recycle_failed(
-            &recovery_path,
-            err.to_string(),
-        )))) as Box<dyn Error>
-    })
+    start_supervisor_daemon(config_path, None, false, false, &BTreeMap::new(), false)
+        .map_err(|err| {
+            Box::new(DiagError(Box::new(systemg::restart::recycle_failed(
+                &recovery_path,
+                err.to_string(),
+            )))) as Box<dyn Error>
+        })
 }
 
 fn control_error_is_restart_upgrade_boundary(err: &ControlError) -> bool {
@@ -6387,6 +8978,7 @@ fn control_error_is_restart_upgrade_boundary(err: &ControlError) -> bool {
         ControlError::MissingHome
         | ControlError::Unauthorized(_)
         | ControlError::Timeout
+        | ControlError::Unresponsive
         | ControlError::RuntimeBusy => false,
         ControlError::Io(err) => matches!(
             err.kind(),
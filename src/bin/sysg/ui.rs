@@ -38,12 +38,17 @@ fn force_cooked_mode() {
 /// Represents status render options.
 struct StatusRenderOptions<'a> {
     format: Option<OutputFormat>,
+    /// `--output` layout. Without one, a terminal gets the interactive view.
+    output: Option<StatusOutput>,
     no_color: bool,
-    #[allow(dead_code)]
     full_cmd: bool,
     include_orphans: bool,
     service_filter: Option<&'a str>,
     project_filter: Option<&'a str>,
+    /// `--filter` glob over unit names.
+    name_glob: Option<&'a str>,
+    sort: Option<StatusSort>,
+    limit: Option<usize>,
     /// When set, the overview reads `OFFLINE` instead of a health label — no
     /// supervisor stands behind the data, so a HEALTHY headline would lie.
     offline: bool,
@@ -144,17 +149,19 @@ struct StatusReading {
 /// daemon that is alive but not answering is probed within a bounded window and
 /// its disk state returned as `NotResponding`; a truly absent supervisor yields
 /// the on-disk state marked `Offline`, never dressed up as a supervised reading.
+/// With `include_children` off, spawned-child trees are skipped.
 fn fetch_status_reading(
     config_path: Option<&str>,
     live: bool,
+    include_children: bool,
 ) -> Result<StatusReading, Box<dyn Error>> {
     let health = supervisor_health();
     if health == SupervisorHealth::Serving {
-        if let Some(reading) = try_live_status(live) {
+        if let Some(reading) = try_live_status(live, include_children) {
             return Ok(reading);
         }
         if live
-            && let Some(reading) = try_live_status(false)
+            && let Some(reading) = try_live_status(false, include_children)
         {
             return Ok(reading);
         }
@@ -166,18 +173,31 @@ fn fetch_status_reading(
     };
 
     let Some(config_path) = config_path else {
-        return Err(io::Error::new(
-            io::ErrorKind::NotFound,
-            "No running supervisor",
-        )
-        .into());
+        return Err(Box::new(NoStatusSource(presence)));
     };
     let config = load_status_config(config_path)?;
-    let snapshot =
-        collect_disk_snapshot(config).map_err(|err| Box::new(err) as Box<dyn Error>)?;
+    let snapshot = collect_disk_snapshot(config, include_children)
+        .map_err(|err| Box::new(err) as Box<dyn Error>)?;
     Ok(StatusReading { snapshot, presence })
 }
 
+/// A status read with no serving supervisor to ask and no config to read disk
+/// state from. Kept apart from I/O errors so a missing config or state file is
+/// never mistaken for an absent supervisor.
+#[derive(Debug)]
+struct NoStatusSource(SupervisorPresence);
+
+impl fmt::Display for NoStatusSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            SupervisorPresence::NotResponding => write!(f, "Supervisor not responding"),
+            _ => write!(f, "No running supervisor"),
+        }
+    }
+}
+
+impl Error for NoStatusSource {}
+
 /// Refuses a `-s <service>` that resolves ambiguously — the service is declared
 /// in more than one project and no `-p` narrowed it. status is read-only, but it
 /// keeps the same selector contract as the mutating commands: an ambiguous unit
@@ -229,6 +249,21 @@ fn print_presence_banner(presence: SupervisorPresence) {
     eprintln!("{}", diag.render_for_terminal());
 }
 
+/// Prints a one-line notice when the supervisor is paused (`sysg pause`), so a
+/// crashed unit sitting down is not mistaken for a restart that never fired.
+fn print_paused_banner(snapshot: &StatusSnapshot) {
+    if snapshot.paused {
+        eprintln!(
+            "{}",
+            colorize(
+                "Supervisor paused: crashed services are not restarted (run `sysg resume`)",
+                YELLOW,
+                !color_enabled()
+            )
+        );
+    }
+}
+
 /// The process exit code for a status run. An unsupervised or wedged reading is
 /// never a clean `0`, even when every surviving process looks healthy — the
 /// absence of a supervisor is itself the failing condition.
@@ -244,20 +279,155 @@ fn status_exit_code(presence: SupervisorPresence, health: OverallHealth) -> i32
     }
 }
 
+/// The one-line `status --summary` verdict in monitoring-plugin form
+/// (`OK`/`WARN`/`CRIT` plus the units responsible), paired with the matching
+/// exit code from [`status_exit_code`].
+fn status_check_line(presence: SupervisorPresence, units: &[UnitStatus]) -> (String, i32) {
+    let health = compute_overall_health(units);
+    let code = status_exit_code(presence, health);
+    match presence {
+        SupervisorPresence::Offline => return ("CRIT supervisor not running".into(), code),
+        SupervisorPresence::NotResponding => {
+            return ("WARN supervisor not responding".into(), code);
+        }
+        SupervisorPresence::Live => {}
+    }
+    if health == OverallHealth::Healthy {
+        let count = |kind: UnitKind| units.iter().filter(|unit| unit.kind == kind).count();
+        let noun = |count: usize, one: &str, many: &str| {
+            format!("{count} {}", if count == 1 { one } else { many })
+        };
+        // Cron jobs and orphans are not services; name them only when present.
+        let mut parts = vec![noun(count(UnitKind::Service), "service", "services")];
+        match count(UnitKind::Cron) {
+            0 => {}
+            crons => parts.push(noun(crons, "cron job", "cron jobs")),
+        }
+        match count(UnitKind::Orphaned) {
+            0 => {}
+            orphans => parts.push(noun(orphans, "orphan", "orphans")),
+        }
+        return (format!("OK {}, 0 failing", parts.join(", ")), code);
+    }
+    let label = |unit: &UnitStatus| match &unit.project {
+        Some(project) if project.id != systemg::state_store::LOOSE_PROJECT_ID => {
+            format!("{}/{}", project.id, unit.name)
+        }
+        _ => unit.name.clone(),
+    };
+    let offenders = units
+        .iter()
+        .filter(|unit| unit.health == UnitHealth::Failing)
+        .map(|unit| format!("{} failing", label(unit)))
+        .chain(
+            units
+                .iter()
+                .filter(|unit| unit.health == UnitHealth::Warn)
+                .map(|unit| format!("{} degraded", label(unit))),
+        )
+        .collect::<Vec<_>>()
+        .join(", ");
+    let level = if health == OverallHealth::Failing { "CRIT" } else { "WARN" };
+    (format!("{level} {offenders}"), code)
+}
+
+/// The health policy the supervisor applies to `unit`: its project's
+/// `status.health` overlaid with the service's own `health` block. `None` when
+/// the manifest cannot be read or does not declare the unit's project.
+fn unit_health_policy(config: &str, unit: &UnitStatus) -> Option<HealthPolicy> {
+    let path = resolve_config_path(config).ok()?;
+    let projects =
+        systemg::config::load_projects_from_file(fs::File::open(&path).ok()?, &path)
+            .ok()?;
+    let project_id = unit.project.as_ref().map(|project| project.id.as_str());
+    let project = projects
+        .iter()
+        .find(|project| Some(project.project.id.as_str()) == project_id)
+        .or_else(|| (projects.len() == 1).then(|| &projects[0]))?;
+    let service = project.services.get(&unit.name);
+    Some(
+        project
+            .status
+            .health
+            .overlaid(service.and_then(|service| service.health.as_ref())),
+    )
+}
+
+/// Renders `status --explain`: the inputs behind `unit`'s health and each rule
+/// checked on the way to it. Without a `policy` the defaults are assumed.
+fn render_health_trace(
+    unit: &UnitStatus,
+    policy: Option<&HealthPolicy>,
+    config: &str,
+    no_color: bool,
+) -> String {
+    let trace = trace_unit_health(unit, &policy.copied().unwrap_or_default());
+    let name = match &unit.project {
+        Some(project) if project.id != systemg::state_store::LOOSE_PROJECT_ID => {
+            format!("{}/{}", project.id, unit.name)
+        }
+        _ => unit.name.clone(),
+    };
+    let mut out = format!(
+        "{name}: {}\n\nInputs:\n",
+        colorize(
+            unit_health_label(trace.health),
+            unit_health_color(trace.health),
+            no_color
+        )
+    );
+    let width = trace
+        .inputs
+        .iter()
+        .map(|(input, _)| input.len())
+        .max()
+        .unwrap_or(0);
+    for (input, value) in &trace.inputs {
+        out.push_str(&format!("  {input:<width$}  {value}\n"));
+    }
+    if policy.is_none() {
+        out.push_str(&format!(
+            "  (could not read the health policy from {config}; assuming defaults)\n"
+        ));
+    }
+    out.push_str("\nDecision:\n");
+    for (index, step) in trace.steps.iter().enumerate() {
+        out.push_str(&format!("  {}. {step}\n", index + 1));
+    }
+    if trace.health != unit.health {
+        let reported = unit_health_label(unit.health);
+        out.push_str(&if policy.is_some() {
+            format!(
+                "\nThe supervisor reports {reported}: it classifies with the manifest it \
+loaded, which differs from {config}. Run `sysg reload` to apply the current one.\n"
+            )
+        } else {
+            format!(
+                "\nThe supervisor reports {reported}: its health policy is not the \
+default one assumed above.\n"
+            )
+        });
+    }
+    out
+}
+
 /// Fetches just the snapshot (discarding the presence verdict) for callers that
 /// only need the unit list, such as the log commands.
 fn fetch_status_snapshot(
     config_path: Option<&str>,
     live: bool,
 ) -> Result<StatusSnapshot, Box<dyn Error>> {
-    fetch_status_reading(config_path, live).map(|reading| reading.snapshot)
+    fetch_status_reading(config_path, live, true).map(|reading| reading.snapshot)
 }
 
 /// Asks a serving supervisor for its snapshot within the probe deadline. Returns
 /// `None` if the daemon stops serving between the health check and the request.
-fn try_live_status(live: bool) -> Option<StatusReading> {
+fn try_live_status(live: bool, include_children: bool) -> Option<StatusReading> {
     match ipc::send_command_with_timeout(
-        &ControlCommand::Status { live },
+        &ControlCommand::Status {
+            live,
+            no_children: !include_children,
+        },
         SUPERVISOR_PROBE_TIMEOUT,
     ) {
         Ok(ipc::CommandAck::Response(ControlResponse::Status(snapshot))) => {
@@ -814,6 +984,7 @@ fn render_empty_status(
             captured_at: snapshot.captured_at,
             overall_health: OverallHealth::Warn,
             units: Vec::new(),
+            paused: snapshot.paused,
         };
         println!("{}", serialize_machine_output(&empty, format)?);
     } else if snapshot
@@ -848,12 +1019,16 @@ fn render_status_interactive(
             status_unit_matches_selector(unit, opts.service_filter, opts.project_filter)
         });
     }
+    shape_status_units(&mut units, opts.name_glob, opts.sort);
 
     if units.is_empty() {
         return render_empty_status(snapshot, opts);
     }
 
     let health = compute_overall_health(&units);
+    if let Some(limit) = opts.limit {
+        units.truncate(limit);
+    }
 
     let is_tty = unsafe {
         libc::isatty(libc::STDIN_FILENO) == 1 && libc::isatty(libc::STDOUT_FILENO) == 1
@@ -1315,6 +1490,19 @@ fn render_health_report(unit: &UnitStatus, no_color: bool) -> Vec<String> {
     }
     lines.push(String::new());
 
+    // The report explains the built-in rule; a configured health policy may
+    // have reclassified the verdict the table shows.
+    if unit.health != report.health {
+        let note = format!(
+            "A health policy reclassifies this unit as {}.",
+            unit_health_label(unit.health)
+        );
+        for line in wrap_paragraph(&note, width) {
+            lines.push(line);
+        }
+        lines.push(String::new());
+    }
+
     lines.push(colorize("## Description", CYAN, no_color));
     lines.push(String::new());
     for line in wrap_paragraph(&report.description, width) {
@@ -1514,7 +1702,7 @@ fn render_status(
     watch_mode: bool,
     config_path: &str,
 ) -> Result<OverallHealth, Box<dyn Error>> {
-    if watch_mode || opts.format.is_some() {
+    if watch_mode || opts.format.is_some() || opts.output.is_some() {
         render_status_non_interactive(snapshot, opts, watch_mode)
     } else {
         render_status_interactive(snapshot, opts, config_path)
@@ -1540,12 +1728,16 @@ fn render_status_non_interactive(
             status_unit_matches_selector(unit, opts.service_filter, opts.project_filter)
         });
     }
+    shape_status_units(&mut units, opts.name_glob, opts.sort);
 
     if units.is_empty() {
         return render_empty_status(snapshot, opts);
     }
 
     let health = compute_overall_health(&units);
+    if let Some(limit) = opts.limit {
+        units.truncate(limit);
+    }
 
     if let Some(format) = opts.format {
         let filtered_snapshot = StatusSnapshot {
@@ -1553,6 +1745,7 @@ fn render_status_non_interactive(
             captured_at: snapshot.captured_at,
             overall_health: health,
             units,
+            paused: snapshot.paused,
         };
         println!(
             "{}",
@@ -1566,112 +1759,199 @@ fn render_status_non_interactive(
         let _ = io::stdout().flush();
     }
 
-    let terminal_width = detect_target_table_width(120);
-    let mut widths = compute_status_preferred_widths(&units, opts.no_color);
-    if !agent_mode() {
-        shrink_status_widths_to_fit(&mut widths, terminal_width);
+    // `sysg status <service>` describes the one unit it names in full.
+    let lines = if opts.service_filter.is_some()
+        && opts.output.is_none()
+        && !watch_mode
+        && units.len() == 1
+    {
+        status_detail_lines(&units[0], opts.no_color)
+    } else {
+        let cmd_budget = if opts.full_cmd || agent_mode() {
+            None
+        } else {
+            terminal_size::terminal_size().map(|(width, _)| width.0 as usize)
+        };
+        status_table_lines(
+            &units,
+            opts.output == Some(StatusOutput::Wide),
+            cmd_budget,
+            opts.no_color,
+        )
+    };
+    for line in lines {
+        println!("{line}");
     }
 
-    let columns_array = [
-        Column {
-            title: "UNIT",
-            width: widths[STATUS_COL_UNIT],
-            align: STATUS_COLUMN_ALIGNS[STATUS_COL_UNIT],
-        },
-        Column {
-            title: "KIND",
-            width: widths[STATUS_COL_KIND],
-            align: STATUS_COLUMN_ALIGNS[STATUS_COL_KIND],
-        },
-        Column {
-            title: "STATE",
-            width: widths[STATUS_COL_STATE],
-            align: STATUS_COLUMN_ALIGNS[STATUS_COL_STATE],
-        },
-        Column {
-            title: "USER",
-            width: widths[STATUS_COL_USER],
-            align: STATUS_COLUMN_ALIGNS[STATUS_COL_USER],
-        },
-        Column {
-            title: "PID",
-            width: widths[STATUS_COL_PID],
-            align: STATUS_COLUMN_ALIGNS[STATUS_COL_PID],
-        },
-        Column {
-            title: "CPU",
-            width: widths[STATUS_COL_CPU],
-            align: STATUS_COLUMN_ALIGNS[STATUS_COL_CPU],
-        },
-        Column {
-            title: "RSS",
-            width: widths[STATUS_COL_RSS],
-            align: STATUS_COLUMN_ALIGNS[STATUS_COL_RSS],
-        },
-        Column {
-            title: "UPTIME",
-            width: widths[STATUS_COL_UPTIME],
-            align: STATUS_COLUMN_ALIGNS[STATUS_COL_UPTIME],
-        },
-        Column {
-            title: "CMD",
-            width: widths[STATUS_COL_CMD],
-            align: STATUS_COLUMN_ALIGNS[STATUS_COL_CMD],
-        },
-        Column {
-            title: "LAST_EXIT",
-            width: widths[STATUS_COL_LAST_EXIT],
-            align: STATUS_COLUMN_ALIGNS[STATUS_COL_LAST_EXIT],
-        },
-        Column {
-            title: "HEALTH",
-            width: widths[STATUS_COL_HEALTH],
-            align: STATUS_COLUMN_ALIGNS[STATUS_COL_HEALTH],
-        },
-    ];
+    let _ = io::stdout().flush();
+    Ok(health)
+}
 
-    let columns = &columns_array;
-    for line in
-        status_overview_lines(columns, &units, health, opts.no_color, opts.offline)
-    {
-        println!("{line}");
+/// Lays out `sysg status --output table` (or `wide`): one borderless row per
+/// unit, so a long service list stays scannable. Units of more than one
+/// project are named `project/name`. With `terminal_width` set, the wide
+/// layout's trailing CMD column is shortened to fit it.
+fn status_table_lines(
+    units: &[UnitStatus],
+    wide: bool,
+    terminal_width: Option<usize>,
+    no_color: bool,
+) -> Vec<String> {
+    let qualify = units
+        .iter()
+        .map(|unit| unit.project.as_ref().map(|project| project.id.as_str()))
+        .collect::<HashSet<_>>()
+        .len()
+        > 1;
+    let mut titles = vec!["NAME", "HEALTH", "PID", "CPU%", "MEM", "UPTIME", "RESTARTS"];
+    let mut aligns = vec![
+        Alignment::Left,
+        Alignment::Left,
+        Alignment::Right,
+        Alignment::Right,
+        Alignment::Right,
+        Alignment::Left,
+        Alignment::Right,
+    ];
+    if wide {
+        titles.extend(["STATE", "LAST_EXIT", "CMD"]);
+        aligns.extend([Alignment::Left, Alignment::Left, Alignment::Left]);
     }
-    println!();
 
-    let groups = status_project_groups(&units, opts.no_color);
-    let render_groups = should_render_project_groups(&groups);
-    for (group_index, (label, group_units)) in groups.iter().enumerate() {
-        if render_groups {
-            if group_index > 0 {
-                println!();
+    let rows: Vec<Vec<String>> = units
+        .iter()
+        .map(|unit| {
+            let name = match (&unit.project, qualify) {
+                (Some(project), true) => format!("{}/{}", project.id, unit.name),
+                _ => unit.name.clone(),
+            };
+            let mut row = vec![
+                name,
+                colorize(
+                    &health_label_extended(unit),
+                    unit_health_color(unit.health),
+                    no_color,
+                ),
+                unit.process
+                    .as_ref()
+                    .map(|runtime| runtime.pid.to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+                format_cpu_column(unit.metrics.as_ref()),
+                format_rss_column(unit.metrics.as_ref()),
+                format_uptime_column(unit.uptime.as_ref()),
+                unit.restarts.to_string(),
+            ];
+            if wide {
+                row.push(unit_state_label(unit, no_color));
+                let last_exit = format_last_exit(unit.last_exit.as_ref(), unit.cron.as_ref());
+                row.push(
+                    match last_exit_color(unit.last_exit.as_ref(), unit.cron.as_ref()) {
+                        Some(color) => colorize(&last_exit, color, no_color),
+                        None => last_exit,
+                    },
+                );
+                row.push(
+                    unit.command
+                        .as_ref()
+                        .or(unit.runtime_command.as_ref())
+                        .cloned()
+                        .unwrap_or_else(|| "-".to_string()),
+                );
             }
-            println!("Project: {label}");
+            row
+        })
+        .collect();
+
+    let mut widths: Vec<usize> = titles.iter().map(|title| title.len()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(visible_length(cell));
         }
+    }
+    if wide && let Some(terminal_width) = terminal_width {
+        let cmd = widths.len() - 1;
+        let leading: usize = widths[..cmd].iter().map(|width| width + 2).sum();
+        widths[cmd] = widths[cmd].min(terminal_width.saturating_sub(leading).max(12));
+    }
 
-        println!("{}", make_top_border(columns));
-        println!("{}", format_header_row(columns));
-        println!("{}", make_separator_border(columns));
+    let format_line = |cells: &[String]| {
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .zip(&aligns)
+            .map(|((cell, width), align)| {
+                if visible_length(cell) > *width {
+                    ansi_pad(&ellipsize_ansi_aware(cell, *width), *width, *align)
+                } else {
+                    ansi_pad(cell, *width, *align)
+                }
+            })
+            .collect();
+        padded.join("  ").trim_end().to_string()
+    };
+    let header: Vec<String> = titles.iter().map(|title| title.to_string()).collect();
+    let mut lines = vec![format_line(&header)];
+    lines.extend(rows.iter().map(|row| format_line(row)));
+    lines
+}
 
-        for (_, unit) in group_units {
-            println!(
-                "{}",
-                format_unit_row_with_project_indent(
-                    unit,
-                    columns,
-                    opts.no_color,
-                    render_groups
-                )
-            );
-            if !unit.spawned_children.is_empty() {
-                render_spawn_rows(unit, columns, opts.no_color);
-            }
-        }
+/// Describes one unit for `sysg status <service>`: its health and state, the
+/// live process with its resource use, restarts, command, last exit, and any
+/// processes it spawned.
+fn status_detail_lines(unit: &UnitStatus, no_color: bool) -> Vec<String> {
+    let health_color = unit_health_color(unit.health);
+    let mut lines = vec![format!(
+        "{} {}  {}",
+        colorize("●", health_color, no_color),
+        unit.name,
+        colorize(&health_label_extended(unit), health_color, no_color)
+    )];
+    let mut field = |label: &str, value: String| {
+        lines.push(format!("{label:>10}: {value}"));
+    };
 
-        println!("{}", make_bottom_border(columns));
-    }
+    if let Some(project) = &unit.project {
+        field("Project", project.id.clone());
+    }
+    field("State", unit_state_label(unit, no_color));
+    field(
+        "PID",
+        match &unit.process {
+            Some(runtime) => match &runtime.user {
+                Some(user) => format!("{} ({user})", runtime.pid),
+                None => runtime.pid.to_string(),
+            },
+            None => "-".to_string(),
+        },
+    );
+    field("Uptime", format_uptime_column(unit.uptime.as_ref()));
+    field("CPU", format_cpu_column(unit.metrics.as_ref()));
+    field("Memory", format_rss_column(unit.metrics.as_ref()));
+    field("Restarts", unit.restarts.to_string());
+    field(
+        "Command",
+        unit.command
+            .as_ref()
+            .or(unit.runtime_command.as_ref())
+            .cloned()
+            .unwrap_or_else(|| "-".to_string()),
+    );
+    field(
+        "Last exit",
+        format_last_exit(unit.last_exit.as_ref(), unit.cron.as_ref()),
+    );
 
-    let _ = io::stdout().flush();
-    Ok(health)
+    if !unit.spawned_children.is_empty() {
+        lines.push(format!("{:>10}:", "Children"));
+        visit_spawn_tree(&unit.spawned_children, "  ", &mut |child, prefix, _| {
+            lines.push(format!(
+                "{:>10}{prefix}{}",
+                "",
+                format_tree_child_label(child, no_color)
+            ));
+        });
+    }
+    lines
 }
 
 fn status_interactive_exit_key_event(key_event: &KeyEvent) -> bool {
@@ -1682,13 +1962,19 @@ fn status_interactive_exit_key_event(key_event: &KeyEvent) -> bool {
 
 /// Applies color to this item.
 fn colorize(text: &str, color: &str, no_color: bool) -> String {
-    if no_color {
+    if color_disabled(no_color) {
         text.to_string()
     } else {
         format!("{}{}{}", color, text, RESET)
     }
 }
 
+/// Returns `code`, or an empty string when color is off, so escapes can be
+/// spliced into a `format!` without a branch at every call site.
+fn color_code(code: &'static str, no_color: bool) -> &'static str {
+    if color_disabled(no_color) { "" } else { code }
+}
+
 /// Returns the overall health label.
 fn overall_health_label(health: OverallHealth) -> &'static str {
     match health {
@@ -1732,10 +2018,25 @@ fn unit_health_color(health: UnitHealth) -> &'static str {
     }
 }
 
-/// Builds the unit state label.
+/// Builds the unit state label, noting when the restart policy gave up on the
+/// unit or the running process predates a config change.
 fn unit_state_label(unit: &UnitStatus, no_color: bool) -> String {
     let label = unit_state_plain_label(unit.state);
-    colorize(label, unit_state_color(unit.state), no_color)
+    let label = colorize(label, unit_state_color(unit.state), no_color);
+    if unit.lifecycle == Some(ServiceLifecycleStatus::GaveUp) {
+        let detail = match unit.gave_up_after {
+            Some(max) => format!("(gave up after {max} restarts)"),
+            None => "(gave up)".to_string(),
+        };
+        format!("{label} {}", colorize(&detail, RED_BOLD, no_color))
+    } else if unit.drifted {
+        format!(
+            "{label} {}",
+            colorize("(config changed, restart to apply)", YELLOW, no_color)
+        )
+    } else {
+        label
+    }
 }
 
 fn unit_state_plain_label(state: UnitState) -> &'static str {
@@ -1960,11 +2261,17 @@ fn format_last_exit(
     }
 
     match exit {
-        Some(metadata) => match (metadata.exit_code, metadata.signal) {
-            (Some(code), _) => format!("exit {}", code),
-            (None, Some(_)) => "exit ?".to_string(),
-            _ => "?".to_string(),
-        },
+        Some(metadata) => {
+            let outcome = match (metadata.exit_code, metadata.signal) {
+                (Some(code), _) => format!("exit {}", code),
+                (None, Some(_)) => "exit ?".to_string(),
+                _ => "?".to_string(),
+            };
+            match metadata.at {
+                Some(at) => format!("{}; {}", outcome, format_relative_time_short(at)),
+                None => outcome,
+            }
+        }
         None => "-".to_string(),
     }
 }
@@ -2201,6 +2508,76 @@ fn status_unit_matches_selector(
             .unwrap_or(true)
 }
 
+/// Applies `status --filter` and `--sort` to a selector-filtered unit list.
+/// `--limit` is left to the caller so overall health still covers every
+/// matching unit, not only the ones shown.
+fn shape_status_units(
+    units: &mut Vec<UnitStatus>,
+    name_glob: Option<&str>,
+    sort: Option<StatusSort>,
+) {
+    if let Some(pattern) = name_glob {
+        units.retain(|unit| {
+            status_glob_matches(pattern, &unit.name)
+                || unit.project.as_ref().is_some_and(|project| {
+                    status_glob_matches(pattern, &format!("{}/{}", project.id, unit.name))
+                })
+        });
+    }
+    let health_rank = |health: UnitHealth| match health {
+        UnitHealth::Failing => 0,
+        UnitHealth::Warn => 1,
+        UnitHealth::Idle => 2,
+        UnitHealth::Healthy => 3,
+    };
+    match sort {
+        None => {}
+        Some(StatusSort::Name) => units.sort_by(|a, b| a.name.cmp(&b.name)),
+        Some(StatusSort::Health) => units.sort_by_key(|unit| health_rank(unit.health)),
+        Some(StatusSort::Cpu) => units.sort_by(|a, b| {
+            let cpu = |unit: &UnitStatus| {
+                unit.metrics
+                    .as_ref()
+                    .map_or(f32::NEG_INFINITY, |metrics| metrics.latest_cpu_percent)
+            };
+            cpu(b).total_cmp(&cpu(a))
+        }),
+        Some(StatusSort::Uptime) => units.sort_by_key(|unit| {
+            std::cmp::Reverse(unit.uptime.as_ref().map(|uptime| uptime.seconds))
+        }),
+    }
+}
+
+/// Matches `text` against a glob where `*` spans any run of characters and
+/// `?` exactly one.
+fn status_glob_matches(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&ch) if ch == '?' || ch == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&ch| ch == '*')
+}
+
 #[derive(Clone, Copy)]
 enum OverviewMetric {
     Health(UnitHealth),
@@ -2524,7 +2901,9 @@ fn unit_row_tint_family(unit: &UnitStatus) -> RowTintFamily {
             ServiceLifecycleStatus::Running | ServiceLifecycleStatus::ExitedSuccessfully => {
                 RowTintFamily::Success
             }
-            ServiceLifecycleStatus::ExitedWithError => RowTintFamily::Failing,
+            ServiceLifecycleStatus::ExitedWithError | ServiceLifecycleStatus::GaveUp => {
+                RowTintFamily::Failing
+            }
             ServiceLifecycleStatus::Stopped | ServiceLifecycleStatus::Skipped => {
                 RowTintFamily::Neutral
             }
@@ -2687,6 +3066,88 @@ where
     }
 }
 
+/// Renders `sysg tree`: the supervisor at the root, then every service (under
+/// its project when more than one is loaded) and each process it spawned.
+fn render_process_tree(
+    units: &[UnitStatus],
+    supervisor_pid: Option<libc::pid_t>,
+    no_color: bool,
+) -> Vec<String> {
+    let presence = match supervisor_pid {
+        Some(pid) => colorize(&format!("pid {pid}"), GRAY, no_color),
+        None => colorize("not running", YELLOW, no_color),
+    };
+    let mut lines = vec![format!(
+        "{} {presence}",
+        colorize("sysg supervisor", BRIGHT_WHITE, no_color)
+    )];
+    if units.is_empty() {
+        lines.push(colorize("└─ (no units)", GRAY, no_color));
+        return lines;
+    }
+
+    let groups = status_project_groups(units, no_color);
+    let grouped = should_render_project_groups(&groups);
+    for (group_idx, (label, group_units)) in groups.iter().enumerate() {
+        let group_last = group_idx == groups.len() - 1;
+        let unit_prefix = if grouped {
+            let connector = if group_last { "└─ " } else { "├─ " };
+            lines.push(format!("{connector}{label}"));
+            if group_last { "   " } else { "│  " }
+        } else {
+            ""
+        };
+
+        for (unit_idx, (_, unit)) in group_units.iter().enumerate() {
+            let unit_last = unit_idx == group_units.len() - 1;
+            let connector = if unit_last { "└─ " } else { "├─ " };
+            lines.push(format!(
+                "{unit_prefix}{connector}{}",
+                format_tree_unit_label(unit, no_color)
+            ));
+
+            let child_prefix =
+                format!("{unit_prefix}{}", if unit_last { "   " } else { "│  " });
+            visit_spawn_tree(&unit.spawned_children, &child_prefix, &mut |child, prefix, _| {
+                lines.push(format!("{prefix}{}", format_tree_child_label(child, no_color)));
+            });
+        }
+    }
+
+    lines
+}
+
+/// Formats a service node for `sysg tree`: name, pid, state, and health.
+fn format_tree_unit_label(unit: &UnitStatus, no_color: bool) -> String {
+    let pid = unit
+        .process
+        .as_ref()
+        .map(|process| format!("pid {}", process.pid))
+        .unwrap_or_else(|| "pid -".to_string());
+    format!(
+        "{} {} {} {}",
+        colorize(&unit.name, unit_health_color(unit.health), no_color),
+        colorize(&pid, GRAY, no_color),
+        unit_state_label(unit, no_color),
+        colorize(unit_health_label(unit.health), unit_health_color(unit.health), no_color)
+    )
+}
+
+/// Formats a spawned child node for `sysg tree`, colored by how it exited.
+fn format_tree_child_label(child: &SpawnedChild, no_color: bool) -> String {
+    let state = match &child.last_exit {
+        None => UnitState::Running,
+        Some(exit) if exit.exit_code == Some(0) && exit.signal.is_none() => UnitState::Done,
+        Some(_) => UnitState::Failed,
+    };
+    format!(
+        "{} {} {}",
+        colorize(&child.name, unit_state_color(state), no_color),
+        colorize(&format!("pid {}", child.pid), GRAY, no_color),
+        colorize(unit_state_plain_label(state), unit_state_color(state), no_color)
+    )
+}
+
 /// Formats spawned child row.
 fn format_spawned_child_row(
     child: &SpawnedChild,
@@ -3034,7 +3495,7 @@ fn fetch_inspect(
         .into()),
         Err(ControlError::NotAvailable) => {
             let config = load_config(Some(config_path))?;
-            let snapshot = collect_disk_snapshot(Some(config))?;
+            let snapshot = collect_disk_snapshot(Some(config), true)?;
             let unit_status = snapshot
                 .units
                 .into_iter()
@@ -3457,7 +3918,7 @@ fn render_usage_bar(
                 GREEN
             };
 
-            let reset = if no_color { "" } else { RESET };
+            let reset = color_code(RESET, no_color);
             bar.push_str(&format!("{}|{}", color, reset));
         } else {
             bar.push(' ');
@@ -3857,6 +4318,53 @@ fn collect_inspect_lines(
                 opts.no_color
             )
         ));
+        resource_metrics_lines.push(format!(
+            "{}: {} p50 | {} p95 | {} p99",
+            colorize("CPU tail", DIM_WHITE, opts.no_color),
+            colorize(
+                &format!("{:.2}%", metrics.p50_cpu_percent),
+                WHITE,
+                opts.no_color
+            ),
+            colorize(
+                &format!("{:.2}%", metrics.p95_cpu_percent),
+                WHITE,
+                opts.no_color
+            ),
+            colorize(
+                &format!("{:.2}%", metrics.p99_cpu_percent),
+                WHITE,
+                opts.no_color
+            )
+        ));
+        resource_metrics_lines.push(format!(
+            "{}: {} read | {} written",
+            colorize("Disk IO", DIM_WHITE, opts.no_color),
+            colorize(
+                &format_bytes(metrics.latest_io_read_bytes),
+                WHITE,
+                opts.no_color
+            ),
+            colorize(
+                &format_bytes(metrics.latest_io_write_bytes),
+                WHITE,
+                opts.no_color
+            )
+        ));
+        resource_metrics_lines.push(format!(
+            "{}: {}/s read | {}/s write",
+            colorize("IO rate", DIM_WHITE, opts.no_color),
+            colorize(
+                &format_bytes(metrics.average_io_read_bytes_per_sec as u64),
+                WHITE,
+                opts.no_color
+            ),
+            colorize(
+                &format_bytes(metrics.average_io_write_bytes_per_sec as u64),
+                WHITE,
+                opts.no_color
+            )
+        ));
         resource_metrics_lines.push(format!(
             "{}: {}",
             colorize("Samples", DIM_WHITE, opts.no_color),
@@ -3958,10 +4466,17 @@ fn collect_inspect_lines(
                             .with_timezone(&Local)
                             .format("%Y-%m-%d %H:%M:%S")
                             .to_string(),
-                        status: format_inspect_cron_status(
-                            run.status.as_ref(),
-                            opts.no_color,
-                        ),
+                        status: {
+                            let status = format_inspect_cron_status(
+                                run.status.as_ref(),
+                                opts.no_color,
+                            );
+                            if run.manual {
+                                format!("{status} (manual)")
+                            } else {
+                                status
+                            }
+                        },
                         user: run.user.clone().unwrap_or_else(|| "-".to_string()),
                         pid: run
                             .pid
@@ -4488,13 +5003,9 @@ fn inspect_process_row_values(
     no_color: bool,
 ) -> Vec<String> {
     let virt_plain = format_bytes(row.virt_bytes);
-    let virt_colored = if no_color {
-        format_bytes(row.virt_bytes)
-    } else {
-        format!("{}{}{}", GREEN, virt_plain, RESET)
-    };
+    let virt_colored = colorize(&virt_plain, GREEN, no_color);
 
-    let user_colored = if no_color || row.user == "-" {
+    let user_colored = if color_disabled(no_color) || row.user == "-" {
         row.user.clone()
     } else {
         let color = user_colors.get(&row.user).unwrap_or(&"");
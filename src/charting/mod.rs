@@ -63,6 +63,7 @@ pub fn render_metrics_chart_lines(
 
     let cpu_final = cpu_resampled;
     let mem_final = mem_resampled;
+    let io_values = io_throughput_kib(samples);
 
     let first_time = samples
         .first()
@@ -81,14 +82,17 @@ pub fn render_metrics_chart_lines(
 
     let mut output = vec![String::new()];
 
-    let x_axis_label = format!(
-        "X-axis: Time ({first_time} -> {last_time}) | Window: {}",
-        config.window_desc
-    );
-
     let available_columns = effective_chart_columns(config);
     let chart_width = compute_chart_width(available_columns);
 
+    let x_axis_label = wrap_words(
+        &format!(
+            "X-axis: Time ({first_time} -> {last_time}) | Window: {}",
+            config.window_desc
+        ),
+        available_columns,
+    );
+
     let cpu_graph = plot(
         cpu_final,
         Config::default()
@@ -132,14 +136,32 @@ pub fn render_metrics_chart_lines(
         Some("\x1b[35m"),
     );
 
-    let cards = vec![cpu_card, mem_card];
+    let io_graph = plot(
+        resample_to_width(&io_values, chart_width),
+        Config::default()
+            .with_width(chart_width as u32)
+            .with_height(10)
+            .with_y_precision(1),
+    );
+    let io_card = build_chart_card(
+        ChartCardSpec {
+            title: "Disk IO".to_string(),
+            legend: "Legend: read + write throughput".to_string(),
+            y_axis_label: "Y-axis: KiB/s".to_string(),
+        },
+        &io_graph,
+        config.no_color,
+        Some("\x1b[33m"),
+    );
+
+    let cards = vec![cpu_card, mem_card, io_card];
     let rendered_rows = layout_cards_with_wrapping(&cards, available_columns);
     for (idx, row) in rendered_rows.iter().enumerate() {
         for line in row {
             output.push(line.clone());
         }
         output.push(String::new());
-        output.push(x_axis_label.clone());
+        output.extend(x_axis_label.iter().cloned());
         if idx + 1 < rendered_rows.len() {
             output.push(String::new());
         }
@@ -179,11 +201,98 @@ pub fn render_metrics_chart_lines(
         mem_avg,
         if mem_max.is_finite() { mem_max } else { 0.0 }
     ));
+    for (label, counter) in [
+        ("IO read:", read_counter as fn(&MetricSample) -> u64),
+        ("IO write:", write_counter),
+    ] {
+        let rates = counter_rates(samples, counter);
+        let avg = if rates.is_empty() {
+            0.0
+        } else {
+            rates.iter().sum::<f64>() / rates.len() as f64
+        };
+        let max = rates.iter().copied().fold(0.0, f64::max);
+        output.push(format!(
+            "  {label:<9} avg={} max={}",
+            format_kib_rate(avg),
+            format_kib_rate(max)
+        ));
+    }
     output.push(format!("  Samples: {}", samples.len()));
 
     Ok(output)
 }
 
+/// Reads a sample's cumulative disk-read counter.
+fn read_counter(sample: &MetricSample) -> u64 {
+    sample.io_read_bytes
+}
+
+/// Reads a sample's cumulative disk-write counter.
+fn write_counter(sample: &MetricSample) -> u64 {
+    sample.io_write_bytes
+}
+
+/// Per-interval growth of a cumulative counter in KiB/s, one value per
+/// sample. The first sample has no predecessor, and a counter that went
+/// backwards belongs to a replacement process; both report zero.
+fn counter_rates(
+    samples: &[MetricSample],
+    counter: fn(&MetricSample) -> u64,
+) -> Vec<f64> {
+    let mut rates = Vec::with_capacity(samples.len());
+    rates.extend(samples.first().map(|_| 0.0));
+    for pair in samples.windows(2) {
+        let elapsed =
+            (pair[1].timestamp - pair[0].timestamp).num_milliseconds() as f64 / 1000.0;
+        let delta = counter(&pair[1]).saturating_sub(counter(&pair[0])) as f64;
+        rates.push(if elapsed > 0.0 {
+            delta / 1024.0 / elapsed
+        } else {
+            0.0
+        });
+    }
+    rates
+}
+
+/// Combined read and write throughput in KiB/s for the Disk IO panel.
+fn io_throughput_kib(samples: &[MetricSample]) -> Vec<f64> {
+    counter_rates(samples, read_counter)
+        .into_iter()
+        .zip(counter_rates(samples, write_counter))
+        .map(|(read, write)| read + write)
+        .collect()
+}
+
+/// Formats a KiB/s rate, switching to MiB/s once it is large enough.
+fn format_kib_rate(kib: f64) -> String {
+    if kib >= 1024.0 {
+        format!("{:.1}MiB/s", kib / 1024.0)
+    } else {
+        format!("{kib:.1}KiB/s")
+    }
+}
+
+/// Greedily wraps `text` at spaces so no line exceeds `width` columns, unless
+/// a single word is longer than that.
+fn wrap_words(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        if !current.is_empty() && current.len() + 1 + word.len() > width {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
 /// Represents chart card spec.
 struct ChartCardSpec {
     title: String,
@@ -198,7 +307,12 @@ fn build_chart_card(
     no_color: bool,
     graph_color: Option<&str>,
 ) -> Vec<String> {
-    let mut lines = vec![spec.title, spec.legend, spec.y_axis_label, String::new()];
+    // Captions never widen the card past its graph.
+    let width = graph.lines().map(visible_width).max().unwrap_or(0);
+    let mut lines = vec![spec.title];
+    lines.extend(wrap_words(&spec.legend, width));
+    lines.extend(wrap_words(&spec.y_axis_label, width));
+    lines.push(String::new());
 
     for line in graph.lines() {
         let colored = if no_color {
@@ -559,6 +673,49 @@ mod tests {
         assert!(joined.contains("\n\n"));
     }
 
+    #[test]
+    fn test_io_throughput_derives_rates_from_cumulative_counters() {
+        let start = chrono::Utc::now();
+        let sample = |offset: i64, read: u64, written: u64| MetricSample {
+            timestamp: start + chrono::Duration::seconds(offset),
+            cpu_percent: 0.0,
+            rss_bytes: 0,
+            io_read_bytes: read,
+            io_write_bytes: written,
+            net_rx_bytes: 0,
+            net_tx_bytes: 0,
+        };
+        let samples = [sample(0, 0, 0), sample(2, 4096, 2048), sample(4, 0, 4096)];
+        assert_eq!(counter_rates(&samples, read_counter), vec![0.0, 2.0, 0.0]);
+        assert_eq!(io_throughput_kib(&samples), vec![0.0, 3.0, 1.0]);
+    }
+
+    #[test]
+    fn test_metrics_chart_fits_fifty_columns() {
+        let start = chrono::Utc::now();
+        let samples: Vec<MetricSample> = (0..20)
+            .map(|i| MetricSample {
+                timestamp: start + chrono::Duration::seconds(i * 5),
+                cpu_percent: (i * 5) as f32,
+                rss_bytes: 256 * 1024 * 1024 + i as u64 * 1024 * 1024,
+                io_read_bytes: i as u64 * 512 * 1024,
+                io_write_bytes: i as u64 * 64 * 1024,
+                net_rx_bytes: 0,
+                net_tx_bytes: 0,
+            })
+            .collect();
+        let config = ChartConfig {
+            no_color: true,
+            window_desc: "5m".to_string(),
+            max_width: Some(50),
+        };
+        let lines = render_metrics_chart_lines(&samples, &config).unwrap();
+        assert!(lines.iter().any(|line| line == "Disk IO"));
+        for line in &lines {
+            assert!(visible_width(line) <= 50, "{line:?} overflows 50 columns");
+        }
+    }
+
     #[test]
     fn test_compute_chart_width_prefers_inline_layout() {
         assert_eq!(compute_chart_width(120), 40);
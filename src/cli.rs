@@ -1,10 +1,10 @@
 //! Command-line interface for Systemg.
 use std::{fmt, str::FromStr};
 
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{ArgGroup, Parser, Subcommand, ValueEnum};
 use tracing::level_filters::LevelFilter;
 
-use crate::constants::DEFAULT_LOG_LINES;
+use crate::constants::{DEFAULT_FOLLOW_BUFFER_LINES, DEFAULT_LOG_LINES};
 
 /// Documentation links appended to `--help` output.
 const DOCS_HELP: &str = "\
@@ -128,6 +128,95 @@ pub enum OutputFormat {
     Xml,
 }
 
+/// Orderings `sysg status --sort` can apply to the unit list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum StatusSort {
+    /// Alphabetical by unit name.
+    Name,
+    /// Worst health first.
+    Health,
+    /// Highest latest CPU usage first.
+    Cpu,
+    /// Longest uptime first.
+    Uptime,
+}
+
+/// Layouts `sysg status --output` can print.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum StatusOutput {
+    /// One compact row per unit: name, health, PID, CPU, memory, uptime and
+    /// restarts.
+    Table,
+    /// The compact table plus each unit's state, last exit and command.
+    Wide,
+    /// The status snapshot as JSON, like `--format json`.
+    Json,
+}
+
+/// When terminal output is colored.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum ColorChoice {
+    /// Color only when stdout is a terminal and `NO_COLOR` is unset.
+    #[default]
+    Auto,
+    /// Always emit ANSI colors, even when piped.
+    Always,
+    /// Never emit ANSI colors.
+    Never,
+}
+
+/// States `sysg wait` can block on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum WaitCondition {
+    /// The service has a live process.
+    #[default]
+    Running,
+    /// The service is running and its project boot (including health checks) settled.
+    Healthy,
+    /// The service ran to completion and exited successfully.
+    Exited,
+}
+
+impl WaitCondition {
+    /// Returns the flag spelling used in messages.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WaitCondition::Running => "running",
+            WaitCondition::Healthy => "healthy",
+            WaitCondition::Exited => "exited",
+        }
+    }
+}
+
+/// Shells `sysg completion` can emit a script for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum CompletionShell {
+    /// Bash, via `complete -F`.
+    Bash,
+    /// Zsh, as a `#compdef` function.
+    Zsh,
+    /// Fish, as `complete -c` rules.
+    Fish,
+    /// PowerShell, via `Register-ArgumentCompleter`.
+    Powershell,
+}
+
+/// Source formats `sysg import` can convert.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ImportFormat {
+    /// A docker-compose file.
+    Compose,
+    /// A Procfile of `name: command` lines.
+    Procfile,
+}
+
+/// Candidate lists the hidden `sysg __complete` command can print.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum CompleteTarget {
+    /// Service names declared by the manifest.
+    Services,
+}
+
 /// Command-line interface for Systemg.
 #[derive(Parser)]
 #[command(name = "systemg", version, author)]
@@ -147,6 +236,12 @@ pub struct Cli {
     #[arg(long = "sys", global = true)]
     pub sys: bool,
 
+    /// Run against an isolated runtime: state, control socket, PID file and
+    /// logs live under `systemg-<NAME>` instead of the shared `systemg`
+    /// directory. Defaults to `SYSTEMG_NAMESPACE`.
+    #[arg(long, value_name = "NAME", global = true, value_parser = parse_namespace)]
+    pub namespace: Option<String>,
+
     /// Drop privileges after performing privileged setup.
     #[arg(long = "drop-privileges", global = true)]
     pub drop_privileges: bool,
@@ -159,6 +254,11 @@ pub struct Cli {
     #[arg(long = "plain", global = true)]
     pub plain: bool,
 
+    /// When to color output: `auto` colors only on an interactive stdout with
+    /// `NO_COLOR` unset; `always` and `never` override the detection.
+    #[arg(long, value_enum, value_name = "WHEN", default_value_t = ColorChoice::Auto, global = true)]
+    pub color: ColorChoice,
+
     /// The command to execute.
     #[command(subcommand)]
     pub command: Commands,
@@ -177,9 +277,10 @@ pub enum Commands {
         #[arg(long)]
         daemonize: bool,
 
-        /// Optionally start only the named service.
+        /// Optionally start only the named service. Repeat to start several,
+        /// each reported separately.
         #[arg(short, long)]
-        service: Option<String>,
+        service: Vec<String>,
 
         /// Project id to target.
         #[arg(short = 'p', long)]
@@ -216,20 +317,41 @@ pub enum Commands {
         #[arg(long)]
         stderr: bool,
 
+        /// Set an environment variable in every started service, overriding
+        /// the manifest's `env`. Repeatable.
+        #[arg(long = "env", value_name = "KEY=VALUE", value_parser = parse_env_override)]
+        env: Vec<(String, String)>,
+
+        /// Start services whose dependencies are satisfied concurrently, one
+        /// dependency level at a time, instead of one by one.
+        #[arg(long)]
+        parallel: bool,
+
+        /// Wait for the started one-shot service to exit, then stop the project
+        /// and exit with the service's exit code. Foreground only; requires
+        /// `--service`.
+        #[arg(long, requires = "service", conflicts_with = "daemonize")]
+        follow_exit: bool,
+
         /// Ad-hoc command and arguments to supervise without a manifest.
         #[arg(trailing_var_arg = true)]
         command: Vec<String>,
     },
 
     /// Stop the currently running process manager.
+    #[command(group(ArgGroup::new("targets").args(["service", "services"]).multiple(true)))]
     Stop {
+        /// Services to stop, each reported separately (optional).
+        #[arg(value_name = "SERVICE")]
+        services: Vec<String>,
+
         /// Path to the configuration file (defaults to `systemg.yaml`).
         #[arg(short, long, default_value = "systemg.yaml")]
         config: String,
 
-        /// Name of service to stop (optional).
+        /// Name of service to stop (optional). Repeatable.
         #[arg(short, long)]
-        service: Option<String>,
+        service: Vec<String>,
 
         /// Project id to target.
         #[arg(short = 'p', long)]
@@ -238,17 +360,49 @@ pub enum Commands {
         /// Shut down the resident supervisor and all registered projects.
         #[arg(long)]
         supervisor: bool,
+
+        /// Terminate and clear only orphaned units: processes still recorded for
+        /// services that are no longer in the config. Combine with `-p` to
+        /// limit the sweep to one project.
+        #[arg(long, conflicts_with_all = ["targets", "supervisor"])]
+        orphans: bool,
+
+        /// How long the service gets to exit after SIGTERM before it is killed
+        /// (e.g. `500ms`, `10s`). Defaults to 1s.
+        #[arg(long, value_name = "DURATION", requires = "targets")]
+        timeout: Option<String>,
     },
 
     /// Restart the process manager, optionally specifying a new configuration file.
+    #[command(group(ArgGroup::new("targets").args(["service", "services"]).multiple(true)))]
     Restart {
+        /// Services to restart, each reported separately (optional).
+        #[arg(value_name = "SERVICE")]
+        services: Vec<String>,
+
         /// Path to the configuration file (defaults to `systemg.yaml`).
         #[arg(short, long, default_value = "systemg.yaml")]
         config: String,
 
-        /// Optionally restart only the named service.
+        /// Optionally restart only the named service. Repeatable.
         #[arg(short, long)]
-        service: Option<String>,
+        service: Vec<String>,
+
+        /// Also restart every service that transitively depends on `--service`,
+        /// after the service itself.
+        #[arg(long, requires = "targets")]
+        with_dependents: bool,
+
+        /// Run each restarted service's `deployment.pre_start` before stopping
+        /// it, keeping the old instance up if the build fails. Only for named
+        /// services; project and whole-config restarts are refused.
+        #[arg(long)]
+        build_first: bool,
+
+        /// Restart only the services that are currently failing or exited with
+        /// an error, in dependency order.
+        #[arg(long, conflicts_with_all = ["targets", "build_first", "daemonize", "wait"])]
+        all_failed: bool,
 
         /// Project id to target.
         #[arg(short = 'p', long)]
@@ -257,9 +411,81 @@ pub enum Commands {
         /// Start the supervisor before restarting if it isn't already running.
         #[arg(long)]
         daemonize: bool,
+
+        /// Block until the restarted services are verified running, exiting
+        /// non-zero if any fails to come back.
+        #[arg(long)]
+        wait: bool,
+    },
+
+    /// Mark a service skipped until `sysg enable`, stopping it if running.
+    ///
+    /// The flag lives in the service's persisted state, so `sysg start` and
+    /// `sysg reload` keep skipping the service across supervisor restarts.
+    Disable {
+        /// Service to disable, optionally qualified as `project/service`.
+        service: String,
+
+        /// Path to the configuration file (defaults to `systemg.yaml`).
+        #[arg(short, long, default_value = "systemg.yaml")]
+        config: String,
+
+        /// Project id to target.
+        #[arg(short = 'p', long)]
+        project: Option<String>,
+    },
+
+    /// Clear a previous `sysg disable` so start and reload launch the service again.
+    Enable {
+        /// Service to enable, optionally qualified as `project/service`.
+        service: String,
+
+        /// Path to the configuration file (defaults to `systemg.yaml`).
+        #[arg(short, long, default_value = "systemg.yaml")]
+        config: String,
+
+        /// Project id to target.
+        #[arg(short = 'p', long)]
+        project: Option<String>,
+    },
+
+    /// Stop automatically restarting crashed services without stopping any.
+    ///
+    /// Crashes are still recorded and the supervisor shows as paused in
+    /// `sysg status` until `sysg resume`.
+    Pause,
+
+    /// Re-enable automatic restarts after `sysg pause`.
+    Resume {
+        /// Also restart the services that crashed while paused.
+        #[arg(long)]
+        restart: bool,
+    },
+
+    /// Apply an edited configuration to the running supervisor.
+    ///
+    /// Starts added services, stops removed ones, and restarts only those whose
+    /// settings changed. With `--dry-run`, prints that plan as a diff instead.
+    Reload {
+        /// Path to the configuration file (defaults to `systemg.yaml`).
+        #[arg(short, long, default_value = "systemg.yaml")]
+        config: String,
+
+        /// Project id to reload.
+        #[arg(short = 'p', long)]
+        project: Option<String>,
+
+        /// Preview what the reload would change without applying it.
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Disable ANSI colors in the preview.
+        #[arg(long = "no-color")]
+        no_color: bool,
     },
 
     /// Show the status of currently running services.
+    #[command(group(ArgGroup::new("status_unit").args(["service", "unit"])))]
     Status {
         /// Optional configuration file used to scope status output.
         #[arg(short, long)]
@@ -269,6 +495,10 @@ pub enum Commands {
         #[arg(short, long)]
         service: Option<String>,
 
+        /// Service to check, as an alternative to `--service`.
+        #[arg(value_name = "SERVICE")]
+        unit: Option<String>,
+
         /// Project id to filter status by.
         #[arg(short = 'p', long)]
         project: Option<String>,
@@ -287,6 +517,16 @@ pub enum Commands {
         )]
         format: Option<OutputFormat>,
 
+        /// Print the units as a compact table, a wide table, or JSON instead of
+        /// the interactive view.
+        #[arg(
+            long,
+            value_enum,
+            value_name = "MODE",
+            conflicts_with_all = ["format", "summary", "json", "explain"]
+        )]
+        output: Option<StatusOutput>,
+
         /// Disable ANSI colors in output.
         #[arg(long = "no-color")]
         no_color: bool,
@@ -302,6 +542,98 @@ pub enum Commands {
         /// Continuously refresh output at the provided interval (e.g., "5", "1s", "2m").
         #[arg(long, value_name = "DURATION")]
         stream: Option<String>,
+
+        /// Print a one-line `OK`/`WARN`/`CRIT` verdict for monitoring checks,
+        /// exiting 0, 1 or 2 to match.
+        #[arg(long, conflicts_with_all = ["format", "stream", "full_cmd"])]
+        summary: bool,
+
+        /// Only show units whose name, or `project/name`, matches this glob
+        /// (`*` and `?` are wildcards).
+        #[arg(long, value_name = "GLOB")]
+        filter: Option<String>,
+
+        /// Order units by the given key before display.
+        #[arg(long, value_enum, value_name = "KEY", conflicts_with = "summary")]
+        sort: Option<StatusSort>,
+
+        /// Show at most N units after filtering and sorting.
+        #[arg(
+            long,
+            value_name = "N",
+            value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..),
+            conflicts_with = "summary"
+        )]
+        limit: Option<usize>,
+
+        /// Print only the selected service's status as a versioned JSON
+        /// document, exiting non-zero when no such service exists.
+        #[arg(
+            long,
+            requires = "status_unit",
+            conflicts_with_all = [
+                "format", "stream", "full_cmd", "summary", "filter", "sort", "limit"
+            ]
+        )]
+        json: bool,
+
+        /// Explain why SERVICE has its health: the inputs the classification
+        /// read and each rule it checked on the way to the verdict.
+        #[arg(
+            long,
+            value_name = "SERVICE",
+            conflicts_with_all = [
+                "service", "unit", "format", "stream", "full_cmd", "summary", "json",
+                "filter", "sort", "limit"
+            ]
+        )]
+        explain: Option<String>,
+
+        /// Skip enumerating each service's spawned children, which needs full
+        /// process-table scans; faster on busy hosts.
+        #[arg(long = "no-children")]
+        no_children: bool,
+    },
+
+    /// Show the supervisor, its services, and their spawned children as one tree.
+    Tree {
+        /// Optional configuration file used to scope the tree.
+        #[arg(short, long)]
+        config: Option<String>,
+
+        /// Project id to filter the tree by.
+        #[arg(short = 'p', long)]
+        project: Option<String>,
+
+        /// Include orphaned state (services not in current config).
+        #[arg(long)]
+        all: bool,
+
+        /// Disable ANSI colors in output.
+        #[arg(long = "no-color")]
+        no_color: bool,
+    },
+
+    /// Block until a service reaches a state, then exit 0 (non-zero on timeout).
+    Wait {
+        /// Name of the service to wait for (`project/service` is accepted).
+        service: String,
+
+        /// Optional configuration file used when no supervisor is reachable.
+        #[arg(short, long)]
+        config: Option<String>,
+
+        /// Project id containing the service.
+        #[arg(short = 'p', long)]
+        project: Option<String>,
+
+        /// The state to wait for.
+        #[arg(long = "for", value_enum, default_value_t = WaitCondition::Running)]
+        condition: WaitCondition,
+
+        /// Give up after this long (e.g., "30", "30s", "2m"). Defaults to 60s.
+        #[arg(long, value_name = "DURATION")]
+        timeout: Option<String>,
     },
 
     /// Inspect a single service or cron unit in detail.
@@ -351,6 +683,20 @@ pub enum Commands {
         #[arg(long)]
         purge: bool,
 
+        /// Empty this service's active stdout/stderr logs in place, keeping
+        /// rotated backups. A running service keeps appending to the same
+        /// files. Asks for confirmation unless `--yes` is given.
+        #[arg(long, value_name = "SERVICE", conflicts_with_all = ["service", "clear_all"])]
+        clear: Option<String>,
+
+        /// Like `--clear`, for every service (scoped by `--project` if given).
+        #[arg(long)]
+        clear_all: bool,
+
+        /// Skip the `--clear`/`--clear-all` confirmation prompt.
+        #[arg(short = 'y', long)]
+        yes: bool,
+
         /// Prune rotated log backups instead of displaying logs.
         #[arg(long)]
         prune: bool,
@@ -371,7 +717,8 @@ pub enum Commands {
         #[arg(short = 'p', long)]
         project: Option<String>,
 
-        /// Number of trailing lines to show.
+        /// Number of trailing lines to show. With `--follow`, `0` skips the
+        /// history and prints only lines written after the command starts.
         #[arg(short, long, default_value_t = DEFAULT_LOG_LINES)]
         lines: usize,
 
@@ -398,6 +745,26 @@ pub enum Commands {
         #[arg(long = "no-follow")]
         no_follow: bool,
 
+        /// While following, show at most this many lines per second. Faster
+        /// output waits in the follow buffer (see `--max-lines`).
+        #[arg(
+            long,
+            value_name = "LINES_PER_SEC",
+            value_parser = clap::builder::RangedU64ValueParser::<u32>::new().range(1..)
+        )]
+        max_rate: Option<u32>,
+
+        /// While following, hold at most this many lines for a terminal that
+        /// cannot keep up. Older lines are dropped and reported as
+        /// `… N lines dropped`.
+        #[arg(
+            long,
+            value_name = "N",
+            default_value_t = DEFAULT_FOLLOW_BUFFER_LINES,
+            value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+        )]
+        max_lines: usize,
+
         /// Only show lines captured at or after this time.
         ///
         /// Accepts an RFC3339 timestamp (`2026-07-07T14:00:00Z`), a UTC date
@@ -435,6 +802,15 @@ pub enum Commands {
         #[arg(long)]
         raw: bool,
 
+        /// Interleave stdout and stderr by capture timestamp into one stream,
+        /// tagging each line `[out]` or `[err]`. Prints a one-shot snapshot;
+        /// lines without timestamps are shown concatenated with a warning.
+        #[arg(
+            long,
+            conflicts_with_all = ["kind", "follow", "stream", "format", "raw", "output_file"]
+        )]
+        merge_streams: bool,
+
         /// Strip ANSI color/escape sequences from log lines. Defaults on for
         /// `--format json`, `--raw`, and non-interactive output.
         #[arg(long = "strip-ansi")]
@@ -444,9 +820,38 @@ pub enum Commands {
         #[arg(long = "no-strip-ansi", conflicts_with = "strip_ansi")]
         no_strip_ansi: bool,
 
+        /// Color each line by the level it was logged at: red for errors,
+        /// yellow for warnings, green for info. Recognizes `[ERROR]`, `WARN:`,
+        /// `level=error` and similar. Follows the global `--color` setting.
+        #[arg(long, conflicts_with_all = ["format", "output_file", "path"])]
+        color_by_level: bool,
+
         /// Continuously refresh output at the provided interval (e.g., "5", "1s", "2m").
         #[arg(long, value_name = "DURATION")]
         stream: Option<String>,
+
+        /// Write the selected lines to this file instead of stdout, headed by a
+        /// line naming the service, the captured time range, and the line count.
+        #[arg(
+            long,
+            value_name = "PATH",
+            requires = "service",
+            conflicts_with_all = ["follow", "stream", "path", "purge", "prune", "supervisor", "format"]
+        )]
+        output_file: Option<String>,
+    },
+
+    /// Attach to an `interactive` service's live output and stdin.
+    ///
+    /// Lines typed here are sent to the service's stdin while its output is
+    /// shown as it is written. Press Ctrl-C to detach; the service keeps running.
+    Attach {
+        /// Service to attach to, optionally qualified as `project/service`.
+        service: String,
+
+        /// Project id to target.
+        #[arg(short = 'p', long)]
+        project: Option<String>,
     },
 
     /// Validate a configuration file and report errors with fixes.
@@ -470,6 +875,45 @@ pub enum Commands {
         no_color: bool,
     },
 
+    /// Compare what is running with a configuration file.
+    ///
+    /// Reports declared services that are not running, running services the
+    /// configuration no longer declares, and running services launched from an
+    /// older version of their configuration. Exits non-zero on any difference.
+    Diff {
+        /// Path to the configuration file (defaults to `systemg.yaml`).
+        #[arg(short, long, default_value = "systemg.yaml")]
+        config: String,
+
+        /// Emit machine-readable output in the requested format.
+        #[arg(
+            long,
+            value_enum,
+            value_name = "FORMAT",
+            num_args = 0..=1,
+            default_missing_value = "json"
+        )]
+        format: Option<OutputFormat>,
+
+        /// Disable ANSI colors in output.
+        #[arg(long = "no-color")]
+        no_color: bool,
+    },
+
+    /// Inspect or exercise parts of a configuration without running it.
+    Config {
+        /// The configuration action to perform.
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+
+    /// Observe cron jobs.
+    Cron {
+        /// The cron action to perform.
+        #[command(subcommand)]
+        command: CronCommand,
+    },
+
     /// Convert a legacy `project:` manifest to the canonical `projects:` form.
     Migrate {
         /// Path to the manifest to convert.
@@ -481,6 +925,53 @@ pub enum Commands {
         in_place: bool,
     },
 
+    /// Convert a docker-compose file or Procfile into a systemg manifest.
+    ///
+    /// Features with no systemg equivalent, such as volumes and networks, are
+    /// listed as warnings on stderr.
+    Import {
+        /// Path to the compose file or Procfile to convert.
+        #[arg(long)]
+        from: String,
+
+        /// Source format. Inferred from the file name when omitted: files
+        /// named `Procfile*` are Procfiles, anything else is a compose file.
+        #[arg(long, value_enum)]
+        format: Option<ImportFormat>,
+
+        /// Write the manifest to this path instead of stdout. An existing
+        /// file is never overwritten.
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+
+    /// Print the JSON Schema for the manifest format.
+    ///
+    /// Point a YAML language server at the output to get completion and
+    /// validation while editing `sysg.config.yaml`.
+    Schema,
+
+    /// Print the systemg version. With `--verbose`, also print build
+    /// provenance, runtime directories and supervisor state for bug reports.
+    Version,
+
+    /// Bundle the config, status, supervisor log, and state files into one
+    /// archive for a bug report, with secrets masked.
+    Snapshot {
+        /// Path to the configuration file (defaults to `systemg.yaml`).
+        #[arg(short, long, default_value = "systemg.yaml")]
+        config: String,
+
+        /// Where to write the gzip-compressed tarball.
+        #[arg(
+            short,
+            long,
+            value_name = "PATH",
+            default_value = "sysg-snapshot.tar.gz"
+        )]
+        out: String,
+    },
+
     /// Purge systemg state and runtime files.
     ///
     /// With no selector, wipes the entire state root. `-c` scopes to every
@@ -501,6 +992,34 @@ pub enum Commands {
         force: bool,
     },
 
+    /// Print a shell completion script.
+    ///
+    /// Service-name arguments complete dynamically by calling back into
+    /// `sysg __complete services`, so the script never goes stale.
+    Completion {
+        /// The shell to generate the script for.
+        #[arg(value_enum)]
+        shell: CompletionShell,
+    },
+
+    /// INTERNAL: print completion candidates, one per line, for the scripts
+    /// `sysg completion` generates.
+    #[command(name = "__complete", hide = true)]
+    Complete {
+        /// What to list.
+        #[arg(value_enum)]
+        target: CompleteTarget,
+
+        /// Manifest to read service names from (defaults to `systemg.yaml`,
+        /// then `sysg.yaml`).
+        #[arg(short, long)]
+        config: Option<String>,
+    },
+
+    /// Re-execute the running supervisor from this `sysg` binary, handing its
+    /// services to the new image without restarting them.
+    SupervisorReexec,
+
     /// INTERNAL: report live-upgrade protocol metadata for installer preflight.
     #[command(hide = true)]
     UpgradeInfo,
@@ -541,6 +1060,14 @@ pub enum Commands {
         /// Private state record inherited from a live supervisor re-exec.
         #[arg(long)]
         handoff: Option<String>,
+
+        /// Environment overrides forwarded from `sysg start --env`.
+        #[arg(long = "env", value_parser = parse_env_override)]
+        env: Vec<(String, String)>,
+
+        /// Boot dependency levels concurrently.
+        #[arg(long)]
+        parallel: bool,
     },
 
     /// DEPRECATED: Spawn a dynamic child process from a parent service.
@@ -568,40 +1095,141 @@ pub enum Commands {
     },
 }
 
-impl Commands {
-    /// The subcommand's canonical name, used to attach command-appropriate help
-    /// and docs to an otherwise-generic failure (so a `status` error points at
-    /// status docs, not `sysg logs`).
-    pub fn name(&self) -> &'static str {
-        match self {
-            Commands::Start { .. } => "start",
-            Commands::Stop { .. } => "stop",
-            Commands::Restart { .. } => "restart",
-            Commands::Status { .. } => "status",
-            Commands::Inspect { .. } => "inspect",
-            Commands::Logs { .. } => "logs",
-            Commands::Validate { .. } => "validate",
-            Commands::Migrate { .. } => "migrate",
-            Commands::Purge { .. } => "purge",
-            Commands::UpgradeInfo => "upgrade-info",
-            Commands::UpgradeSupervisor { .. } => "upgrade-supervisor",
-            Commands::Supervise { .. } => "supervise",
-            Commands::Spawn { .. } => "spawn",
-        }
-    }
-}
+/// Actions under `sysg config`.
+#[derive(Subcommand)]
+pub enum ConfigCommand {
+    /// Run one service's skip condition and health check once and report the
+    /// decisions, without starting or stopping anything.
+    Test {
+        /// Path to the configuration file (defaults to `systemg.yaml`).
+        #[arg(short, long, default_value = "systemg.yaml")]
+        config: String,
 
-/// Parses command-line arguments and returns a `Cli` struct.
-pub fn parse_args() -> Cli {
-    Cli::parse()
-}
+        /// The service whose checks to run.
+        #[arg(short, long)]
+        service: String,
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        /// Probe this URL instead of the configured health check.
+        #[arg(long, value_name = "URL")]
+        url: Option<String>,
 
-    #[test]
-    fn status_accepts_stream() {
+        /// Emit machine-readable output in the requested format.
+        #[arg(
+            long,
+            value_enum,
+            value_name = "FORMAT",
+            num_args = 0..=1,
+            default_missing_value = "json"
+        )]
+        format: Option<OutputFormat>,
+
+        /// Disable ANSI colors in output.
+        #[arg(long = "no-color")]
+        no_color: bool,
+    },
+}
+
+/// Actions under `sysg cron`.
+#[derive(Subcommand)]
+pub enum CronCommand {
+    /// Print a cron job's recent runs, then stream each run's start and finish
+    /// as the scheduler records them. Exits on Ctrl-C.
+    Watch {
+        /// Path to the configuration file (defaults to `systemg.yaml`).
+        #[arg(short, long, default_value = "systemg.yaml")]
+        config: String,
+
+        /// The cron service to watch.
+        #[arg(short, long)]
+        service: String,
+
+        /// Number of past runs to print before following.
+        #[arg(short = 'n', long, default_value_t = 10)]
+        lines: usize,
+
+        /// Disable ANSI colors in output.
+        #[arg(long = "no-color")]
+        no_color: bool,
+    },
+
+    /// Ask the running supervisor to execute a cron job now, outside its
+    /// schedule. The run is recorded in the job's history as manual.
+    Run {
+        /// Path to the configuration file (defaults to `systemg.yaml`).
+        #[arg(short, long, default_value = "systemg.yaml")]
+        config: String,
+
+        /// The cron service to run.
+        service: String,
+    },
+}
+
+impl Commands {
+    /// The subcommand's canonical name, used to attach command-appropriate help
+    /// and docs to an otherwise-generic failure (so a `status` error points at
+    /// status docs, not `sysg logs`).
+    pub fn name(&self) -> &'static str {
+        match self {
+            Commands::Start { .. } => "start",
+            Commands::Stop { .. } => "stop",
+            Commands::Restart { .. } => "restart",
+            Commands::Reload { .. } => "reload",
+            Commands::Disable { .. } => "disable",
+            Commands::Enable { .. } => "enable",
+            Commands::Pause => "pause",
+            Commands::Resume { .. } => "resume",
+            Commands::Status { .. } => "status",
+            Commands::Tree { .. } => "tree",
+            Commands::Wait { .. } => "wait",
+            Commands::Inspect { .. } => "inspect",
+            Commands::Logs { .. } => "logs",
+            Commands::Attach { .. } => "attach",
+            Commands::Validate { .. } => "validate",
+            Commands::Diff { .. } => "diff",
+            Commands::Config { .. } => "config",
+            Commands::Cron { .. } => "cron",
+            Commands::Migrate { .. } => "migrate",
+            Commands::Import { .. } => "import",
+            Commands::Schema => "schema",
+            Commands::Version => "version",
+            Commands::Snapshot { .. } => "snapshot",
+            Commands::Purge { .. } => "purge",
+            Commands::Completion { .. } => "completion",
+            Commands::Complete { .. } => "__complete",
+            Commands::SupervisorReexec => "supervisor-reexec",
+            Commands::UpgradeInfo => "upgrade-info",
+            Commands::UpgradeSupervisor { .. } => "upgrade-supervisor",
+            Commands::Supervise { .. } => "supervise",
+            Commands::Spawn { .. } => "spawn",
+        }
+    }
+}
+
+/// Validates a `--namespace` value before any runtime path is derived from it.
+fn parse_namespace(raw: &str) -> Result<String, String> {
+    crate::runtime::validate_namespace(raw)?;
+    Ok(raw.to_string())
+}
+
+/// Splits a `KEY=VALUE` environment override at its first `=`.
+fn parse_env_override(raw: &str) -> Result<(String, String), String> {
+    match raw.split_once('=') {
+        Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+        _ => Err(format!("expected KEY=VALUE, got `{raw}`")),
+    }
+}
+
+/// Parses command-line arguments and returns a `Cli` struct.
+pub fn parse_args() -> Cli {
+    Cli::parse()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_accepts_stream() {
         let cli = Cli::try_parse_from(["sysg", "status", "--stream", "5"]).unwrap();
         match cli.command {
             Commands::Status { stream, .. } => assert_eq!(stream.as_deref(), Some("5")),
@@ -609,6 +1237,504 @@ mod tests {
         }
     }
 
+    #[test]
+    fn attach_takes_a_service_and_project() {
+        let cli = Cli::try_parse_from(["sysg", "attach", "repl", "-p", "tools"]).unwrap();
+        match cli.command {
+            Commands::Attach { service, project } => {
+                assert_eq!(service, "repl");
+                assert_eq!(project.as_deref(), Some("tools"));
+            }
+            _ => panic!("expected attach command"),
+        }
+        assert!(Cli::try_parse_from(["sysg", "attach"]).is_err());
+    }
+
+    #[test]
+    fn logs_output_file_requires_a_service() {
+        let cli = Cli::try_parse_from([
+            "sysg",
+            "logs",
+            "-s",
+            "web",
+            "--output-file",
+            "web.log",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Logs { output_file, .. } => {
+                assert_eq!(output_file.as_deref(), Some("web.log"))
+            }
+            _ => panic!("expected logs command"),
+        }
+        assert!(
+            Cli::try_parse_from(["sysg", "logs", "--output-file", "all.log"]).is_err()
+        );
+        assert!(
+            Cli::try_parse_from([
+                "sysg",
+                "logs",
+                "-s",
+                "web",
+                "-f",
+                "--output-file",
+                "x"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn start_env_overrides_are_repeatable() {
+        let cli = Cli::try_parse_from([
+            "sysg",
+            "start",
+            "--env",
+            "PORT=9000",
+            "--env",
+            "DSN=postgres://db?sslmode=off",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Start { env, .. } => assert_eq!(
+                env,
+                vec![
+                    ("PORT".to_string(), "9000".to_string()),
+                    ("DSN".to_string(), "postgres://db?sslmode=off".to_string()),
+                ]
+            ),
+            _ => panic!("expected start command"),
+        }
+        assert!(Cli::try_parse_from(["sysg", "start", "--env", "PORT"]).is_err());
+        assert!(Cli::try_parse_from(["sysg", "start", "--env", "=1"]).is_err());
+    }
+
+    #[test]
+    fn start_parallel_flag_parses() {
+        let cli = Cli::try_parse_from(["sysg", "start", "--parallel"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Start { parallel: true, .. }
+        ));
+        let cli = Cli::try_parse_from(["sysg", "start"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Start {
+                parallel: false,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn start_follow_exit_requires_a_foreground_service() {
+        let cli =
+            Cli::try_parse_from(["sysg", "start", "--follow-exit", "-s", "migrate"])
+                .unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Start {
+                follow_exit: true,
+                ..
+            }
+        ));
+        assert!(Cli::try_parse_from(["sysg", "start", "--follow-exit"]).is_err());
+        assert!(
+            Cli::try_parse_from([
+                "sysg",
+                "start",
+                "--follow-exit",
+                "-s",
+                "migrate",
+                "--daemonize"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn logs_clear_takes_a_service_and_rejects_a_second_selector() {
+        let cli = Cli::try_parse_from(["sysg", "logs", "--clear", "api", "-y"]).unwrap();
+        match cli.command {
+            Commands::Logs {
+                clear,
+                clear_all,
+                yes,
+                ..
+            } => {
+                assert_eq!(clear.as_deref(), Some("api"));
+                assert!(!clear_all);
+                assert!(yes);
+            }
+            _ => panic!("expected logs command"),
+        }
+        assert!(
+            Cli::try_parse_from(["sysg", "logs", "--clear", "api", "-s", "web"]).is_err()
+        );
+        assert!(
+            Cli::try_parse_from(["sysg", "logs", "--clear", "api", "--clear-all"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn config_test_requires_a_service() {
+        let cli = Cli::try_parse_from([
+            "sysg",
+            "config",
+            "test",
+            "-s",
+            "web",
+            "--url",
+            "http://127.0.0.1:8080/health",
+        ])
+        .unwrap();
+        assert_eq!(cli.command.name(), "config");
+        match cli.command {
+            Commands::Config {
+                command:
+                    ConfigCommand::Test {
+                        config,
+                        service,
+                        url,
+                        format,
+                        ..
+                    },
+            } => {
+                assert_eq!(config, "systemg.yaml");
+                assert_eq!(service, "web");
+                assert_eq!(url.as_deref(), Some("http://127.0.0.1:8080/health"));
+                assert!(format.is_none());
+            }
+            _ => panic!("expected config test command"),
+        }
+        assert!(Cli::try_parse_from(["sysg", "config", "test"]).is_err());
+    }
+
+    #[test]
+    fn snapshot_defaults_its_archive_path() {
+        let cli = Cli::try_parse_from(["sysg", "snapshot"]).unwrap();
+        assert_eq!(cli.command.name(), "snapshot");
+        match cli.command {
+            Commands::Snapshot { config, out } => {
+                assert_eq!(config, "systemg.yaml");
+                assert_eq!(out, "sysg-snapshot.tar.gz");
+            }
+            _ => panic!("expected snapshot command"),
+        }
+        let cli =
+            Cli::try_parse_from(["sysg", "snapshot", "--out", "report.tar.gz"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Snapshot { out, .. } if out == "report.tar.gz"
+        ));
+    }
+
+    #[test]
+    fn supervisor_reexec_parses() {
+        let cli = Cli::try_parse_from(["sysg", "supervisor-reexec"]).unwrap();
+        assert!(matches!(cli.command, Commands::SupervisorReexec));
+        assert_eq!(cli.command.name(), "supervisor-reexec");
+    }
+
+    #[test]
+    fn namespace_is_global_and_validated() {
+        let cli = Cli::try_parse_from(["sysg", "status", "--namespace", "blue"]).unwrap();
+        assert_eq!(cli.namespace.as_deref(), Some("blue"));
+        assert!(Cli::try_parse_from(["sysg", "status", "--namespace", "../x"]).is_err());
+    }
+
+    #[test]
+    fn cron_watch_parses_service_and_history_depth() {
+        let cli =
+            Cli::try_parse_from(["sysg", "cron", "watch", "-s", "backup", "-n", "3"])
+                .unwrap();
+        match cli.command {
+            Commands::Cron {
+                command: CronCommand::Watch { service, lines, .. },
+            } => {
+                assert_eq!(service, "backup");
+                assert_eq!(lines, 3);
+            }
+            _ => panic!("expected cron watch"),
+        }
+        assert!(Cli::try_parse_from(["sysg", "cron", "watch"]).is_err());
+    }
+
+    #[test]
+    fn cron_run_takes_the_job_positionally() {
+        let cli = Cli::try_parse_from(["sysg", "cron", "run", "backup"]).unwrap();
+        match cli.command {
+            Commands::Cron {
+                command: CronCommand::Run { config, service },
+            } => {
+                assert_eq!(service, "backup");
+                assert_eq!(config, "systemg.yaml");
+            }
+            _ => panic!("expected cron run"),
+        }
+    }
+
+    #[test]
+    fn disable_and_enable_take_the_service_positionally() {
+        let cli = Cli::try_parse_from(["sysg", "disable", "web", "-p", "shop"]).unwrap();
+        match cli.command {
+            Commands::Disable {
+                service, project, ..
+            } => {
+                assert_eq!(service, "web");
+                assert_eq!(project.as_deref(), Some("shop"));
+            }
+            _ => panic!("expected disable"),
+        }
+        let cli = Cli::try_parse_from(["sysg", "enable", "web"]).unwrap();
+        assert!(
+            matches!(cli.command, Commands::Enable { service, .. } if service == "web")
+        );
+        assert!(Cli::try_parse_from(["sysg", "disable"]).is_err());
+    }
+
+    #[test]
+    fn resume_accepts_restart() {
+        let cli = Cli::try_parse_from(["sysg", "pause"]).unwrap();
+        assert_eq!(cli.command.name(), "pause");
+        let cli = Cli::try_parse_from(["sysg", "resume", "--restart"]).unwrap();
+        assert!(matches!(cli.command, Commands::Resume { restart: true }));
+    }
+
+    #[test]
+    fn completion_parses_shells_and_hides_the_callback() {
+        let cli = Cli::try_parse_from(["sysg", "completion", "fish"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Completion {
+                shell: CompletionShell::Fish
+            }
+        ));
+        assert!(Cli::try_parse_from(["sysg", "completion", "tcsh"]).is_err());
+
+        let cli = Cli::try_parse_from(["sysg", "__complete", "services", "-c", "x.yaml"])
+            .unwrap();
+        match cli.command {
+            Commands::Complete { target, config } => {
+                assert_eq!(target, CompleteTarget::Services);
+                assert_eq!(config.as_deref(), Some("x.yaml"));
+            }
+            _ => panic!("expected __complete command"),
+        }
+    }
+
+    #[test]
+    fn import_parses_source_format_and_output() {
+        let cli = Cli::try_parse_from([
+            "sysg",
+            "import",
+            "--from",
+            "docker-compose.yml",
+            "--format",
+            "compose",
+            "-o",
+            "sysg.yaml",
+        ])
+        .unwrap();
+        assert_eq!(cli.command.name(), "import");
+        match cli.command {
+            Commands::Import {
+                from,
+                format,
+                output,
+            } => {
+                assert_eq!(from, "docker-compose.yml");
+                assert_eq!(format, Some(ImportFormat::Compose));
+                assert_eq!(output.as_deref(), Some("sysg.yaml"));
+            }
+            _ => panic!("expected import command"),
+        }
+        assert!(Cli::try_parse_from(["sysg", "import"]).is_err());
+    }
+
+    #[test]
+    fn version_accepts_the_global_verbose_flag() {
+        let cli = Cli::try_parse_from(["sysg", "version", "--verbose"]).unwrap();
+        assert!(cli.verbose);
+        assert!(matches!(cli.command, Commands::Version));
+    }
+
+    #[test]
+    fn status_json_selects_one_unit() {
+        let cli = Cli::try_parse_from(["sysg", "status", "web", "--json"]).unwrap();
+        match cli.command {
+            Commands::Status {
+                unit,
+                service,
+                json,
+                ..
+            } => {
+                assert_eq!(unit.as_deref(), Some("web"));
+                assert!(service.is_none());
+                assert!(json);
+            }
+            _ => panic!("expected status command"),
+        }
+        assert!(Cli::try_parse_from(["sysg", "status", "-s", "web", "--json"]).is_ok());
+        assert!(Cli::try_parse_from(["sysg", "status", "--json"]).is_err());
+        assert!(Cli::try_parse_from(["sysg", "status", "web", "-s", "api"]).is_err());
+        assert!(
+            Cli::try_parse_from(["sysg", "status", "web", "--json", "--format", "xml"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn status_explain_takes_a_service() {
+        let cli = Cli::try_parse_from(["sysg", "status", "--explain", "web"]).unwrap();
+        match cli.command {
+            Commands::Status { explain, unit, .. } => {
+                assert_eq!(explain.as_deref(), Some("web"));
+                assert!(unit.is_none());
+            }
+            _ => panic!("expected status command"),
+        }
+        assert!(Cli::try_parse_from(["sysg", "status", "--explain"]).is_err());
+        assert!(
+            Cli::try_parse_from(["sysg", "status", "--explain", "web", "--json"])
+                .is_err()
+        );
+        assert!(
+            Cli::try_parse_from(["sysg", "status", "api", "--explain", "web"]).is_err()
+        );
+    }
+
+    #[test]
+    fn status_sort_filter_and_limit_parse() {
+        let cli = Cli::try_parse_from([
+            "sysg", "status", "--all", "--filter", "worker-*", "--sort", "cpu",
+            "--limit", "10",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Status {
+                filter,
+                sort,
+                limit,
+                ..
+            } => {
+                assert_eq!(filter.as_deref(), Some("worker-*"));
+                assert_eq!(sort, Some(StatusSort::Cpu));
+                assert_eq!(limit, Some(10));
+            }
+            _ => panic!("expected status command"),
+        }
+        assert!(Cli::try_parse_from(["sysg", "status", "--limit", "0"]).is_err());
+        assert!(Cli::try_parse_from(["sysg", "status", "--sort", "memory"]).is_err());
+        assert!(
+            Cli::try_parse_from(["sysg", "status", "--summary", "--limit", "5"]).is_err()
+        );
+    }
+
+    #[test]
+    fn status_output_selects_a_layout() {
+        let cli = Cli::try_parse_from(["sysg", "status", "--output", "wide"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Status {
+                output: Some(StatusOutput::Wide),
+                ..
+            }
+        ));
+        assert!(Cli::try_parse_from(["sysg", "status", "--output", "yaml"]).is_err());
+        assert!(
+            Cli::try_parse_from(["sysg", "status", "--output", "json", "--format"])
+                .is_err()
+        );
+        assert!(
+            Cli::try_parse_from(["sysg", "status", "--output", "table", "--summary"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn status_summary_conflicts_with_other_renderers() {
+        let cli = Cli::try_parse_from(["sysg", "status", "--summary"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Status { summary: true, .. }
+        ));
+        assert!(
+            Cli::try_parse_from(["sysg", "status", "--summary", "--format"]).is_err()
+        );
+        assert!(
+            Cli::try_parse_from(["sysg", "status", "--summary", "--stream", "5"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn restart_accepts_wait() {
+        let cli =
+            Cli::try_parse_from(["sysg", "restart", "-s", "web", "--wait"]).unwrap();
+        match cli.command {
+            Commands::Restart { service, wait, .. } => {
+                assert_eq!(service, vec!["web"]);
+                assert!(wait);
+            }
+            _ => panic!("expected restart command"),
+        }
+    }
+
+    #[test]
+    fn restart_with_dependents_requires_a_service() {
+        let cli =
+            Cli::try_parse_from(["sysg", "restart", "-s", "db", "--with-dependents"])
+                .unwrap();
+        match cli.command {
+            Commands::Restart {
+                service,
+                with_dependents,
+                ..
+            } => {
+                assert_eq!(service, vec!["db"]);
+                assert!(with_dependents);
+            }
+            _ => panic!("expected restart command"),
+        }
+        assert!(Cli::try_parse_from(["sysg", "restart", "--with-dependents"]).is_err());
+    }
+
+    #[test]
+    fn restart_build_first_parses_with_a_service() {
+        let cli =
+            Cli::try_parse_from(["sysg", "restart", "api", "--build-first"]).unwrap();
+        match cli.command {
+            Commands::Restart {
+                services,
+                build_first,
+                ..
+            } => {
+                assert_eq!(services, vec!["api"]);
+                assert!(build_first);
+            }
+            _ => panic!("expected restart command"),
+        }
+        // Without a service the restart itself refuses it, with a diagnostic.
+        assert!(Cli::try_parse_from(["sysg", "restart", "--build-first"]).is_ok());
+        assert!(
+            Cli::try_parse_from(["sysg", "restart", "--all-failed", "--build-first"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn tree_accepts_project_filter() {
+        let cli = Cli::try_parse_from(["sysg", "tree", "-p", "arbitration"]).unwrap();
+        match cli.command {
+            Commands::Tree { project, .. } => {
+                assert_eq!(project.as_deref(), Some("arbitration"))
+            }
+            _ => panic!("expected tree command"),
+        }
+    }
+
     #[test]
     fn status_accepts_live() {
         let cli = Cli::try_parse_from(["sysg", "status", "--live"]).unwrap();
@@ -618,6 +1744,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn status_accepts_no_children() {
+        let cli = Cli::try_parse_from(["sysg", "status", "--no-children"]).unwrap();
+        match cli.command {
+            Commands::Status { no_children, .. } => assert!(no_children),
+            _ => panic!("expected status command"),
+        }
+    }
+
     #[test]
     fn status_accepts_project_filter() {
         let cli = Cli::try_parse_from(["sysg", "status", "-p", "arbitration"]).unwrap();
@@ -658,6 +1793,81 @@ mod tests {
         }
     }
 
+    #[test]
+    fn stop_orphans_excludes_services_and_supervisor() {
+        let cli =
+            Cli::try_parse_from(["sysg", "stop", "--orphans", "-p", "shop"]).unwrap();
+        match cli.command {
+            Commands::Stop {
+                orphans, project, ..
+            } => {
+                assert!(orphans);
+                assert_eq!(project.as_deref(), Some("shop"));
+            }
+            _ => panic!("expected stop command"),
+        }
+        assert!(Cli::try_parse_from(["sysg", "stop", "--orphans", "web"]).is_err());
+        assert!(
+            Cli::try_parse_from(["sysg", "stop", "--orphans", "--supervisor"]).is_err()
+        );
+    }
+
+    #[test]
+    fn restart_all_failed_excludes_a_service_selector() {
+        let cli = Cli::try_parse_from(["sysg", "restart", "--all-failed", "-p", "shop"])
+            .unwrap();
+        match cli.command {
+            Commands::Restart {
+                all_failed,
+                project,
+                ..
+            } => {
+                assert!(all_failed);
+                assert_eq!(project.as_deref(), Some("shop"));
+            }
+            _ => panic!("expected restart command"),
+        }
+        assert!(
+            Cli::try_parse_from(["sysg", "restart", "--all-failed", "-s", "web"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn stop_timeout_requires_a_service() {
+        let cli = Cli::try_parse_from(["sysg", "stop", "-s", "web", "--timeout", "10s"])
+            .unwrap();
+        match cli.command {
+            Commands::Stop { timeout, .. } => assert_eq!(timeout.as_deref(), Some("10s")),
+            _ => panic!("expected stop command"),
+        }
+        assert!(Cli::try_parse_from(["sysg", "stop", "--timeout", "10s"]).is_err());
+    }
+
+    #[test]
+    fn stop_and_restart_accept_several_services() {
+        let cli =
+            Cli::try_parse_from(["sysg", "stop", "web", "worker", "--timeout", "5s"])
+                .unwrap();
+        match cli.command {
+            Commands::Stop {
+                services, service, ..
+            } => {
+                assert_eq!(services, vec!["web", "worker"]);
+                assert!(service.is_empty());
+            }
+            _ => panic!("expected stop command"),
+        }
+
+        let cli =
+            Cli::try_parse_from(["sysg", "restart", "-s", "web", "-s", "db"]).unwrap();
+        match cli.command {
+            Commands::Restart { service, .. } => assert_eq!(service, vec!["web", "db"]),
+            _ => panic!("expected restart command"),
+        }
+        assert!(Cli::try_parse_from(["sysg", "restart", "--all-failed", "web"]).is_err());
+    }
+
     #[test]
     fn stop_accepts_project_filter_with_log_level() {
         let cli = Cli::try_parse_from([
@@ -678,12 +1888,65 @@ mod tests {
                 project, service, ..
             } => {
                 assert_eq!(project.as_deref(), Some("gamecast-dev"));
-                assert!(service.is_none());
+                assert!(service.is_empty());
             }
             _ => panic!("expected stop command"),
         }
     }
 
+    #[test]
+    fn reload_accepts_dry_run() {
+        let cli =
+            Cli::try_parse_from(["sysg", "reload", "--dry-run", "-p", "demo"]).unwrap();
+        match cli.command {
+            Commands::Reload {
+                dry_run, project, ..
+            } => {
+                assert!(dry_run);
+                assert_eq!(project.as_deref(), Some("demo"));
+            }
+            _ => panic!("expected reload command"),
+        }
+    }
+
+    #[test]
+    fn wait_accepts_condition_and_timeout() {
+        let cli = Cli::try_parse_from([
+            "sysg",
+            "wait",
+            "web",
+            "--for",
+            "healthy",
+            "--timeout",
+            "30s",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Wait {
+                service,
+                condition,
+                timeout,
+                ..
+            } => {
+                assert_eq!(service, "web");
+                assert_eq!(condition, WaitCondition::Healthy);
+                assert_eq!(timeout.as_deref(), Some("30s"));
+            }
+            _ => panic!("expected wait command"),
+        }
+    }
+
+    #[test]
+    fn wait_defaults_to_running() {
+        let cli = Cli::try_parse_from(["sysg", "wait", "web"]).unwrap();
+        match cli.command {
+            Commands::Wait { condition, .. } => {
+                assert_eq!(condition, WaitCondition::Running)
+            }
+            _ => panic!("expected wait command"),
+        }
+    }
+
     #[test]
     fn inspect_accepts_stream() {
         let cli = Cli::try_parse_from([
@@ -782,6 +2045,76 @@ mod tests {
         }
     }
 
+    #[test]
+    fn logs_accepts_follow_rate_and_buffer_caps() {
+        let cli = Cli::try_parse_from(["sysg", "logs", "-s", "demo", "-f"]).unwrap();
+        match cli.command {
+            Commands::Logs {
+                max_rate,
+                max_lines,
+                ..
+            } => {
+                assert_eq!(max_rate, None);
+                assert_eq!(max_lines, DEFAULT_FOLLOW_BUFFER_LINES);
+            }
+            _ => panic!("expected logs command"),
+        }
+
+        let cli = Cli::try_parse_from([
+            "sysg",
+            "logs",
+            "-s",
+            "demo",
+            "-f",
+            "--max-rate",
+            "50",
+            "--max-lines",
+            "200",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Logs {
+                max_rate,
+                max_lines,
+                ..
+            } => {
+                assert_eq!(max_rate, Some(50));
+                assert_eq!(max_lines, 200);
+            }
+            _ => panic!("expected logs command"),
+        }
+        assert!(Cli::try_parse_from(["sysg", "logs", "--max-rate", "0"]).is_err());
+    }
+
+    #[test]
+    fn logs_merge_streams_rejects_follow_and_kind() {
+        let cli = Cli::try_parse_from(["sysg", "logs", "-s", "demo", "--merge-streams"])
+            .unwrap();
+        match cli.command {
+            Commands::Logs { merge_streams, .. } => assert!(merge_streams),
+            _ => panic!("expected logs command"),
+        }
+        assert!(Cli::try_parse_from(["sysg", "logs", "--merge-streams", "-f"]).is_err());
+        assert!(
+            Cli::try_parse_from(["sysg", "logs", "--merge-streams", "-k", "stderr"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn logs_color_by_level_rejects_json_format() {
+        let cli = Cli::try_parse_from(["sysg", "logs", "-s", "demo", "--color-by-level"])
+            .unwrap();
+        match cli.command {
+            Commands::Logs { color_by_level, .. } => assert!(color_by_level),
+            _ => panic!("expected logs command"),
+        }
+        assert!(
+            Cli::try_parse_from(["sysg", "logs", "--color-by-level", "--format", "json"])
+                .is_err()
+        );
+    }
+
     #[test]
     fn logs_rejects_follow_with_no_follow() {
         assert!(Cli::try_parse_from(["sysg", "logs", "-f", "--no-follow"]).is_err());
@@ -793,6 +2126,15 @@ mod tests {
         assert!(cli.plain);
     }
 
+    #[test]
+    fn global_color_flag_defaults_to_auto_and_parses_after_subcommand() {
+        let cli = Cli::try_parse_from(["sysg", "status"]).unwrap();
+        assert_eq!(cli.color, ColorChoice::Auto);
+        let cli = Cli::try_parse_from(["sysg", "status", "--color", "never"]).unwrap();
+        assert_eq!(cli.color, ColorChoice::Never);
+        assert!(Cli::try_parse_from(["sysg", "--color", "sometimes", "status"]).is_err());
+    }
+
     #[test]
     fn global_plain_flag_parses_after_subcommand() {
         let cli = Cli::try_parse_from(["sysg", "logs", "--plain"]).unwrap();
@@ -919,6 +2261,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn diff_defaults_config_and_accepts_format() {
+        let cli = Cli::try_parse_from(["sysg", "diff", "--format"]).unwrap();
+        match cli.command {
+            Commands::Diff { config, format, .. } => {
+                assert_eq!(config, "systemg.yaml");
+                assert_eq!(format, Some(OutputFormat::Json));
+            }
+            _ => panic!("expected diff command"),
+        }
+    }
+
     #[test]
     fn status_rejects_watch() {
         assert!(Cli::try_parse_from(["sysg", "status", "--watch", "5"]).is_err());
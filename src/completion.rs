@@ -0,0 +1,749 @@
+//! Shell completion scripts for `sysg completion`.
+//!
+//! Scripts are rendered from the clap command tree, so a new subcommand or flag
+//! completes without touching this module. Arguments that name a service are not
+//! baked into the script: they call back into `sysg __complete services`, which
+//! reads the manifest in the shell's working directory at completion time.
+//!
+//! The generator is in-tree rather than built on `clap_complete`. That crate's
+//! static generators bake every value into the script, so they cannot offer the
+//! services of whichever manifest the shell is sitting next to. Its runtime
+//! engine can, but it is still gated behind the `unstable-dynamic` feature and
+//! changes shape between minor releases. When it stabilises, the
+//! `__complete services` callback maps onto it directly and this module can
+//! shrink to that hook.
+
+use std::{collections::BTreeSet, fmt::Write as _, fs, path::Path};
+
+use clap::{Arg, Command, CommandFactory, ValueHint};
+
+use crate::{
+    cli::{Cli, CompletionShell},
+    config::parse_config_projects,
+};
+
+/// Binary the generated scripts register completions for.
+const BIN: &str = "sysg";
+
+/// Command line a script runs to list service names.
+const SERVICES_CALLBACK: &str = "sysg __complete services 2>/dev/null";
+
+/// Argument ids that carry a service name.
+const SERVICE_ARG_IDS: &[&str] = &["service", "services", "unit"];
+
+/// What a flag's value completes to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ValueKind {
+    /// The flag is a switch and takes no value.
+    Switch,
+    /// A service name from the manifest.
+    Service,
+    /// A filesystem path.
+    Path,
+    /// One of a fixed set of values.
+    Choices(Vec<String>),
+    /// Anything; the script offers nothing.
+    Free,
+}
+
+/// One flag, as every spelling a shell should offer.
+#[derive(Debug, Clone)]
+struct Flag {
+    long: Option<String>,
+    short: Option<char>,
+    help: String,
+    value: ValueKind,
+}
+
+impl Flag {
+    /// Returns the `-x` and `--xyz` spellings.
+    fn spellings(&self) -> Vec<String> {
+        let mut out = Vec::new();
+        if let Some(short) = self.short {
+            out.push(format!("-{short}"));
+        }
+        if let Some(long) = &self.long {
+            out.push(format!("--{long}"));
+        }
+        out
+    }
+}
+
+/// One reachable subcommand, keyed by its space-separated path from `sysg`.
+#[derive(Debug, Clone)]
+struct Node {
+    /// `""` for the root, `"config test"` for a nested subcommand.
+    path: String,
+    children: Vec<(String, String)>,
+    flags: Vec<Flag>,
+    /// Whether a positional argument takes a service name.
+    service_positional: bool,
+}
+
+impl Node {
+    /// Returns the `path:flag` keys of every flag whose value matches `pick`.
+    fn value_keys(&self, pick: impl Fn(&ValueKind) -> bool) -> Vec<String> {
+        self.flags
+            .iter()
+            .filter(|flag| pick(&flag.value))
+            .flat_map(Flag::spellings)
+            .map(|spelling| format!("{}:{spelling}", self.path))
+            .collect()
+    }
+
+    /// Returns the words offered when no flag value is pending.
+    fn words(&self) -> Vec<String> {
+        self.children
+            .iter()
+            .map(|(name, _)| name.clone())
+            .chain(self.flags.iter().flat_map(Flag::spellings))
+            .collect()
+    }
+}
+
+/// Renders the completion script for `shell`.
+pub fn script(shell: CompletionShell) -> String {
+    let nodes = collect_nodes();
+    match shell {
+        CompletionShell::Bash => render_bash(&nodes),
+        CompletionShell::Zsh => render_zsh(&nodes),
+        CompletionShell::Fish => render_fish(&nodes),
+        CompletionShell::Powershell => render_powershell(&nodes),
+    }
+}
+
+/// Returns the sorted, de-duplicated service names a manifest declares.
+///
+/// Completion must never print an error into the user's prompt, so an
+/// unreadable or invalid manifest yields an empty list.
+pub fn service_names(config: Option<&str>) -> Vec<String> {
+    let path = config.map(Path::new).unwrap_or_else(|| {
+        if Path::new("systemg.yaml").exists() {
+            Path::new("systemg.yaml")
+        } else {
+            Path::new("sysg.yaml")
+        }
+    });
+    let Ok(content) = fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    let Ok(projects) = parse_config_projects(&content) else {
+        return Vec::new();
+    };
+    projects
+        .iter()
+        .flat_map(|project| project.services.keys().cloned())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Flattens the visible command tree into one node per subcommand path.
+fn collect_nodes() -> Vec<Node> {
+    let mut command = Cli::command();
+    command.build();
+    let mut nodes = Vec::new();
+    collect_node(&command, String::new(), &mut nodes);
+    nodes
+}
+
+fn collect_node(command: &Command, path: String, nodes: &mut Vec<Node>) {
+    let visible: Vec<&Command> = command
+        .get_subcommands()
+        .filter(|sub| !sub.is_hide_set() && sub.get_name() != "help")
+        .collect();
+    nodes.push(Node {
+        path: path.clone(),
+        children: visible
+            .iter()
+            .map(|sub| (sub.get_name().to_string(), first_line(sub.get_about())))
+            .collect(),
+        flags: command
+            .get_arguments()
+            .filter(|arg| !arg.is_hide_set() && !arg.is_positional())
+            .map(flag_for)
+            .collect(),
+        service_positional: command
+            .get_positionals()
+            .any(|arg| SERVICE_ARG_IDS.contains(&arg.get_id().as_str())),
+    });
+    for sub in visible {
+        let child = if path.is_empty() {
+            sub.get_name().to_string()
+        } else {
+            format!("{path} {}", sub.get_name())
+        };
+        collect_node(sub, child, nodes);
+    }
+}
+
+fn flag_for(arg: &Arg) -> Flag {
+    let value = if !arg.get_action().takes_values() {
+        ValueKind::Switch
+    } else if SERVICE_ARG_IDS.contains(&arg.get_id().as_str()) {
+        ValueKind::Service
+    } else if !arg.get_possible_values().is_empty() {
+        ValueKind::Choices(
+            arg.get_possible_values()
+                .iter()
+                .filter(|value| !value.is_hide_set())
+                .map(|value| value.get_name().to_string())
+                .collect(),
+        )
+    } else if arg.get_id() == "config"
+        || matches!(
+            arg.get_value_hint(),
+            ValueHint::FilePath | ValueHint::DirPath | ValueHint::AnyPath
+        )
+        || arg
+            .get_value_names()
+            .is_some_and(|names| names.iter().any(|name| name == "PATH"))
+    {
+        ValueKind::Path
+    } else {
+        ValueKind::Free
+    };
+    Flag {
+        long: arg.get_long().map(str::to_string),
+        short: arg.get_short(),
+        help: first_line(arg.get_help()),
+        value,
+    }
+}
+
+fn first_line(text: Option<&clap::builder::StyledStr>) -> String {
+    text.map(|text| text.to_string())
+        .unwrap_or_default()
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .trim_end_matches('.')
+        .to_string()
+}
+
+/// Joins `path:flag` keys into a double-quoted `case` pattern.
+fn case_pattern(keys: &[String]) -> String {
+    keys.iter()
+        .map(|key| format!("\"{key}\""))
+        .collect::<Vec<_>>()
+        .join("|")
+}
+
+/// Collects every node's keys for one value kind into a single `case` pattern.
+fn all_value_keys(
+    nodes: &[Node],
+    pick: impl Fn(&ValueKind) -> bool + Copy,
+) -> Vec<String> {
+    nodes
+        .iter()
+        .flat_map(|node| node.value_keys(pick))
+        .collect()
+}
+
+/// Groups choice flags by their value list so each list is emitted once.
+fn choice_groups(nodes: &[Node]) -> Vec<(String, Vec<String>)> {
+    let mut groups: Vec<(String, Vec<String>)> = Vec::new();
+    for node in nodes {
+        for flag in &node.flags {
+            let ValueKind::Choices(values) = &flag.value else {
+                continue;
+            };
+            let values = values.join(" ");
+            let keys = flag
+                .spellings()
+                .into_iter()
+                .map(|spelling| format!("{}:{spelling}", node.path));
+            match groups.iter_mut().find(|(existing, _)| *existing == values) {
+                Some((_, existing)) => existing.extend(keys),
+                None => groups.push((values, keys.collect())),
+            }
+        }
+    }
+    groups
+}
+
+/// Writes one `case` arm matching any of `keys`; an empty key list writes nothing.
+fn write_arm(out: &mut String, indent: &str, keys: &[String], body: &[String]) {
+    if keys.is_empty() {
+        return;
+    }
+    let _ = writeln!(out, "{indent}{})", case_pattern(keys));
+    for line in body {
+        let _ = writeln!(out, "{indent}    {line}");
+    }
+    let _ = writeln!(out, "{indent}    ;;");
+}
+
+/// Writes the path-walking prologue shared by the bash and zsh scripts.
+///
+/// It leaves `cmdpath` at the deepest subcommand typed so far and `prev` at the
+/// flag whose value is being completed, if any.
+fn write_walker(out: &mut String, nodes: &[Node], first: &str, words: &str, cword: &str) {
+    let value_keys = all_value_keys(nodes, |kind| *kind != ValueKind::Switch);
+    let _ = writeln!(out, "    cmdpath=\"\"");
+    let _ = writeln!(out, "    prev=\"\"");
+    let _ = writeln!(out, "    for ((i = {first}; i < {cword}; i++)); do");
+    let _ = writeln!(out, "        word=\"${{{words}[i]}}\"");
+    let _ = writeln!(out, "        case \"$cmdpath:$word\" in");
+    write_arm(
+        out,
+        "            ",
+        &value_keys,
+        &[
+            "((i++))".to_string(),
+            format!("[[ $i -eq ${cword} ]] && prev=\"$word\""),
+        ],
+    );
+    let _ = writeln!(out, "            *:-*) ;;");
+    write_arm(
+        out,
+        "            ",
+        &child_keys(nodes),
+        &["cmdpath=\"${cmdpath:+$cmdpath }$word\"".to_string()],
+    );
+    let _ = writeln!(out, "        esac");
+    let _ = writeln!(out, "    done");
+}
+
+/// Returns the `path:child` key of every visible subcommand.
+fn child_keys(nodes: &[Node]) -> Vec<String> {
+    nodes
+        .iter()
+        .flat_map(|node| {
+            node.children
+                .iter()
+                .map(move |(name, _)| format!("{}:{name}", node.path))
+        })
+        .collect()
+}
+
+/// Commands one shell uses to offer candidates.
+struct Offer {
+    services: &'static str,
+    files: &'static str,
+    words: fn(&str) -> String,
+}
+
+/// Writes the `case` blocks that pick candidates from `cmdpath` and `prev`.
+fn write_dispatch(out: &mut String, nodes: &[Node], offer: &Offer) {
+    let indent = "        ";
+    let _ = writeln!(out, "    case \"$cmdpath:$prev\" in");
+    write_arm(
+        out,
+        indent,
+        &all_value_keys(nodes, |kind| *kind == ValueKind::Service),
+        &[offer.services.to_string(), "return 0".to_string()],
+    );
+    write_arm(
+        out,
+        indent,
+        &all_value_keys(nodes, |kind| *kind == ValueKind::Path),
+        &[offer.files.to_string(), "return 0".to_string()],
+    );
+    for (values, keys) in choice_groups(nodes) {
+        write_arm(
+            out,
+            indent,
+            &keys,
+            &[(offer.words)(&values), "return 0".to_string()],
+        );
+    }
+    write_arm(
+        out,
+        indent,
+        &all_value_keys(nodes, |kind| *kind == ValueKind::Free),
+        &["return 0".to_string()],
+    );
+    let _ = writeln!(out, "    esac");
+    let _ = writeln!(out, "    case \"$cmdpath\" in");
+    for node in nodes {
+        let words = (offer.words)(&node.words().join(" "));
+        let body = if node.service_positional {
+            vec![
+                "if [[ \"$cur\" == -* ]]; then".to_string(),
+                format!("    {words}"),
+                "else".to_string(),
+                format!("    {}", offer.services),
+                "fi".to_string(),
+            ]
+        } else {
+            vec![words]
+        };
+        write_arm(out, indent, std::slice::from_ref(&node.path), &body);
+    }
+    let _ = writeln!(out, "    esac");
+}
+
+fn render_bash(nodes: &[Node]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "# bash completion for {BIN}; generated by `{BIN} completion bash`."
+    );
+    let _ = writeln!(out, "_{BIN}() {{");
+    let _ = writeln!(out, "    local cur prev word cmdpath i");
+    let _ = writeln!(out, "    COMPREPLY=()");
+    let _ = writeln!(out, "    cur=\"${{COMP_WORDS[COMP_CWORD]}}\"");
+    write_walker(&mut out, nodes, "1", "COMP_WORDS", "COMP_CWORD");
+    write_dispatch(
+        &mut out,
+        nodes,
+        &Offer {
+            services: "COMPREPLY=($(compgen -W \"$(sysg __complete services 2>/dev/null)\" -- \"$cur\"))",
+            files: "COMPREPLY=($(compgen -f -- \"$cur\"))",
+            words: |words| format!("COMPREPLY=($(compgen -W \"{words}\" -- \"$cur\"))"),
+        },
+    );
+    let _ = writeln!(out, "}}");
+    let _ = writeln!(out, "complete -F _{BIN} {BIN}");
+    out
+}
+
+fn render_zsh(nodes: &[Node]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "#compdef {BIN}");
+    let _ = writeln!(
+        out,
+        "# zsh completion for {BIN}; generated by `{BIN} completion zsh`."
+    );
+    let _ = writeln!(out, "_{BIN}() {{");
+    let _ = writeln!(out, "    local cur prev word cmdpath i");
+    let _ = writeln!(out, "    cur=\"${{words[CURRENT]}}\"");
+    write_walker(&mut out, nodes, "2", "words", "CURRENT");
+    write_dispatch(
+        &mut out,
+        nodes,
+        &Offer {
+            services: "compadd -- ${(f)\"$(sysg __complete services 2>/dev/null)\"}",
+            files: "_files",
+            words: |words| format!("compadd -- {words}"),
+        },
+    );
+    let _ = writeln!(out, "}}");
+    let _ = writeln!(out, "if [ \"$funcstack[1]\" = \"_{BIN}\" ]; then");
+    let _ = writeln!(out, "    _{BIN} \"$@\"");
+    let _ = writeln!(out, "else");
+    let _ = writeln!(out, "    compdef _{BIN} {BIN}");
+    let _ = writeln!(out, "fi");
+    out
+}
+
+/// Quotes `text` as a fish single-quoted string.
+fn fish_quote(text: &str) -> String {
+    format!("'{}'", text.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+fn render_fish(nodes: &[Node]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "# fish completion for {BIN}; generated by `{BIN} completion fish`."
+    );
+    let value_flags: Vec<String> =
+        all_value_keys(nodes, |kind| *kind != ValueKind::Switch);
+    let child_keys = child_keys(nodes);
+    let quoted = |keys: &[String]| {
+        keys.iter()
+            .map(|key| fish_quote(key))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let _ = writeln!(out, "function __{BIN}_path");
+    let _ = writeln!(out, "    set -l tokens (commandline -opc)");
+    let _ = writeln!(out, "    set -e tokens[1]");
+    let _ = writeln!(out, "    set -l path ''");
+    let _ = writeln!(out, "    set -l skip 0");
+    let _ = writeln!(out, "    for word in $tokens");
+    let _ = writeln!(out, "        if test $skip -eq 1");
+    let _ = writeln!(out, "            set skip 0");
+    let _ = writeln!(out, "            continue");
+    let _ = writeln!(out, "        end");
+    let _ = writeln!(out, "        set -l key \"$path:$word\"");
+    let _ = writeln!(out, "        if contains -- $key {}", quoted(&value_flags));
+    let _ = writeln!(out, "            set skip 1");
+    let _ = writeln!(
+        out,
+        "        else if contains -- $key {}",
+        quoted(&child_keys)
+    );
+    let _ = writeln!(out, "            set path (string trim -- \"$path $word\")");
+    let _ = writeln!(out, "        end");
+    let _ = writeln!(out, "    end");
+    let _ = writeln!(out, "    echo $path");
+    let _ = writeln!(out, "end");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "function __{BIN}_at");
+    let _ = writeln!(out, "    test (__{BIN}_path) = \"$argv[1]\"");
+    let _ = writeln!(out, "end");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "complete -c {BIN} -f");
+    let services = format!("({SERVICES_CALLBACK})");
+    for node in nodes {
+        let at = fish_quote(&format!("__{BIN}_at {}", fish_quote(&node.path)));
+        for (name, about) in &node.children {
+            let _ = writeln!(
+                out,
+                "complete -c {BIN} -n {at} -a {} -d {}",
+                fish_quote(name),
+                fish_quote(about)
+            );
+        }
+        for flag in &node.flags {
+            let mut line = format!("complete -c {BIN} -n {at}");
+            if let Some(short) = flag.short {
+                let _ = write!(line, " -s {short}");
+            }
+            if let Some(long) = &flag.long {
+                let _ = write!(line, " -l {long}");
+            }
+            match &flag.value {
+                ValueKind::Switch => {}
+                ValueKind::Service => {
+                    let _ = write!(line, " -x -a {}", fish_quote(&services));
+                }
+                ValueKind::Path => line.push_str(" -r -F"),
+                ValueKind::Choices(values) => {
+                    let _ = write!(line, " -x -a {}", fish_quote(&values.join(" ")));
+                }
+                ValueKind::Free => line.push_str(" -x"),
+            }
+            let _ = writeln!(out, "{line} -d {}", fish_quote(&flag.help));
+        }
+        if node.service_positional {
+            let _ = writeln!(
+                out,
+                "complete -c {BIN} -n {at} -a {}",
+                fish_quote(&services)
+            );
+        }
+    }
+    out
+}
+
+/// A case-sensitive PowerShell hashtable, so `-s` and `-S` stay distinct keys.
+const ORDINAL_TABLE: &str =
+    "[System.Collections.Hashtable]::new([System.StringComparer]::Ordinal)";
+
+/// Quotes `text` as a PowerShell single-quoted string.
+fn ps_quote(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "''"))
+}
+
+/// Renders `keys` as a PowerShell array literal.
+fn ps_array(items: &[String]) -> String {
+    format!(
+        "@({})",
+        items
+            .iter()
+            .map(|item| ps_quote(item))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+fn render_powershell(nodes: &[Node]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "# PowerShell completion for {BIN}; generated by `{BIN} completion powershell`."
+    );
+    let _ = writeln!(
+        out,
+        "Register-ArgumentCompleter -Native -CommandName {BIN} -ScriptBlock {{"
+    );
+    let _ = writeln!(
+        out,
+        "    param($wordToComplete, $commandAst, $cursorPosition)"
+    );
+    let _ = writeln!(out, "    $words = {ORDINAL_TABLE}");
+    for node in nodes {
+        let _ = writeln!(
+            out,
+            "    $words[{}] = {}",
+            ps_quote(&node.path),
+            ps_array(&node.words())
+        );
+    }
+    let _ = writeln!(out, "    $values = {ORDINAL_TABLE}");
+    for node in nodes {
+        for flag in &node.flags {
+            let kind = match &flag.value {
+                ValueKind::Switch => continue,
+                ValueKind::Service => "@services".to_string(),
+                ValueKind::Path => "@path".to_string(),
+                ValueKind::Choices(values) => values.join(" "),
+                ValueKind::Free => String::new(),
+            };
+            for spelling in flag.spellings() {
+                let _ = writeln!(
+                    out,
+                    "    $values[{}] = {}",
+                    ps_quote(&format!("{}:{spelling}", node.path)),
+                    ps_quote(&kind)
+                );
+            }
+        }
+    }
+    let service_positional: Vec<String> = nodes
+        .iter()
+        .filter(|node| node.service_positional)
+        .map(|node| node.path.clone())
+        .collect();
+    let _ = writeln!(
+        out,
+        "    $servicePositional = {}",
+        ps_array(&service_positional)
+    );
+    let _ = writeln!(out, "    $path = ''");
+    let _ = writeln!(out, "    $prev = $null");
+    let _ = writeln!(
+        out,
+        "    $tokens = @($commandAst.CommandElements | Select-Object -Skip 1 |"
+    );
+    let _ = writeln!(
+        out,
+        "        Where-Object {{ $_.Extent.EndOffset -lt $cursorPosition }} |"
+    );
+    let _ = writeln!(out, "        ForEach-Object {{ $_.ToString() }})");
+    let _ = writeln!(out, "    for ($i = 0; $i -lt $tokens.Count; $i++) {{");
+    let _ = writeln!(out, "        $word = $tokens[$i]");
+    let _ = writeln!(
+        out,
+        "        if ($values.ContainsKey(\"${{path}}:$word\")) {{"
+    );
+    let _ = writeln!(out, "            $i++");
+    let _ = writeln!(
+        out,
+        "            if ($i -ge $tokens.Count) {{ $prev = $word }}"
+    );
+    let _ = writeln!(out, "        }} elseif (-not $word.StartsWith('-')) {{");
+    let _ = writeln!(
+        out,
+        "            $next = if ($path) {{ \"$path $word\" }} else {{ $word }}"
+    );
+    let _ = writeln!(
+        out,
+        "            if ($words.ContainsKey($next)) {{ $path = $next }}"
+    );
+    let _ = writeln!(out, "        }}");
+    let _ = writeln!(out, "    }}");
+    let _ = writeln!(
+        out,
+        "    $services = {{ @(& {BIN} __complete services 2>$null) }}"
+    );
+    let _ = writeln!(out, "    if ($prev) {{");
+    let _ = writeln!(out, "        $kind = $values[\"${{path}}:$prev\"]");
+    let _ = writeln!(
+        out,
+        "        if ($kind -eq '@services') {{ $candidates = & $services }}"
+    );
+    let _ = writeln!(out, "        elseif ($kind -eq '@path') {{ return }}");
+    let _ = writeln!(
+        out,
+        "        elseif ($kind) {{ $candidates = $kind -split ' ' }}"
+    );
+    let _ = writeln!(out, "        else {{ return }}");
+    let _ = writeln!(
+        out,
+        "    }} elseif ($servicePositional -contains $path -and -not $wordToComplete.StartsWith('-')) {{"
+    );
+    let _ = writeln!(out, "        $candidates = & $services");
+    let _ = writeln!(out, "    }} else {{");
+    let _ = writeln!(out, "        $candidates = $words[$path]");
+    let _ = writeln!(out, "    }}");
+    let _ = writeln!(
+        out,
+        "    $candidates | Where-Object {{ $_ -like \"$wordToComplete*\" }} | ForEach-Object {{"
+    );
+    let _ = writeln!(
+        out,
+        "        [System.Management.Automation.CompletionResult]::new($_, $_, 'ParameterValue', $_)"
+    );
+    let _ = writeln!(out, "    }}");
+    let _ = writeln!(out, "}}");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scripts_cover_visible_subcommands_and_call_back_for_services() {
+        for shell in [
+            CompletionShell::Bash,
+            CompletionShell::Zsh,
+            CompletionShell::Fish,
+            CompletionShell::Powershell,
+        ] {
+            let script = script(shell);
+            assert!(script.contains("sysg __complete services"), "{shell:?}");
+            for name in ["start", "stop", "logs", "completion", "config test"] {
+                assert!(script.contains(name), "{shell:?} is missing {name}");
+            }
+            for hidden in ["supervise", "upgrade-info", "spawn"] {
+                assert!(
+                    !script.contains(&format!("'{hidden}'"))
+                        && !script.contains(&format!(":{hidden}\"")),
+                    "{shell:?} leaks hidden command {hidden}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn service_flags_and_positionals_complete_service_names() {
+        let nodes = collect_nodes();
+        let stop = nodes.iter().find(|node| node.path == "stop").unwrap();
+        assert!(stop.service_positional);
+        let service = stop
+            .flags
+            .iter()
+            .find(|flag| flag.long.as_deref() == Some("service"))
+            .unwrap();
+        assert_eq!(service.value, ValueKind::Service);
+
+        let root = nodes.iter().find(|node| node.path.is_empty()).unwrap();
+        let color = root
+            .flags
+            .iter()
+            .find(|flag| flag.long.as_deref() == Some("color"))
+            .unwrap();
+        assert_eq!(
+            color.value,
+            ValueKind::Choices(vec!["auto".into(), "always".into(), "never".into()])
+        );
+    }
+
+    #[test]
+    fn service_names_lists_every_project_and_tolerates_missing_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("systemg.yaml");
+        fs::write(
+            &path,
+            r#"
+version: "2"
+projects:
+  api:
+    services:
+      web:
+        command: "sleep 1"
+      worker:
+        command: "sleep 1"
+  batch:
+    services:
+      web:
+        command: "sleep 1"
+      cleanup:
+        command: "sleep 1"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            service_names(path.to_str()),
+            vec!["cleanup", "web", "worker"]
+        );
+        assert!(service_names(dir.path().join("missing.yaml").to_str()).is_empty());
+    }
+}
@@ -5,13 +5,15 @@
 pub mod supervisor;
 
 use std::{
-    collections::{BTreeSet, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
     env, fmt, fs,
+    net::SocketAddr,
     path::{Path, PathBuf},
     time::Duration,
 };
 
 use regex::Regex;
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
 use serde::{Deserialize, Deserializer, de::Error as _};
 use sha2::{Digest, Sha256};
 use strum_macros::AsRefStr;
@@ -23,11 +25,14 @@ const RESTART_ALWAYS: &str = "always";
 const RESTART_ON_FAILURE: &str = "on-failure";
 /// Restart policy that never relaunches a service.
 const RESTART_NEVER: &str = "never";
+/// Grammar hint for every duration field, matching `Daemon::parse_duration`.
+const DURATION_GRAMMAR: &str = "<n>[ms|s|m|h], e.g. \"500ms\", \"30s\" or \"2m\"";
 
 use crate::{
     error::ProcessManagerError,
     metrics::{MetricsSettings, SpilloverSettings},
     state_store::LOOSE_PROJECT_ID,
+    status::UnitHealth,
 };
 
 /// Current manifest schema version used by the runtime after migration.
@@ -73,6 +78,19 @@ impl serde::Serialize for Version {
     }
 }
 
+impl JsonSchema for Version {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Version".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "Manifest schema version.",
+            "enum": ["2", 2]
+        })
+    }
+}
+
 impl<'de> Deserialize<'de> for Version {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -142,6 +160,29 @@ pub struct Config {
     /// Status and inspect snapshot collection configuration.
     #[serde(default)]
     pub status: StatusConfig,
+    /// Project-wide deployment settings, such as restart parallelism.
+    #[serde(default)]
+    pub deployment: ProjectDeploymentConfig,
+    /// Command run once every service has come up on `sysg start`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_ready: Option<HookAction>,
+    /// Command run instead of `on_ready` when any service fails to start.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_start_failure: Option<HookAction>,
+    /// Whether a full start keeps launching services after one fails.
+    #[serde(default)]
+    pub on_failure: StartFailureMode,
+    /// Pause between a service becoming ready and a full start launching the
+    /// services that depend on it, e.g. `"2s"`. Defaults to none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dependency_settle: Option<String>,
+    /// Settings for the supervisor process itself rather than its services.
+    #[serde(default)]
+    pub supervisor: SupervisorProcessConfig,
+    /// `sysg start --env` overrides this config was loaded with. Kept so every
+    /// later reload of the manifest applies them again.
+    #[serde(skip)]
+    pub env_overrides: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -150,7 +191,7 @@ struct ManifestHeader {
 }
 
 /// Version 1 manifest schema as accepted from YAML before migration.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 pub struct ConfigV1 {
     /// Configuration version.
     pub version: Version,
@@ -164,7 +205,7 @@ pub struct ConfigV1 {
     pub projects: Option<HashMap<String, ProjectEntry>>,
     /// Services with no project (a loose bundle). With `projects`, these are the
     /// project-less units; with the legacy `project`, these are its services.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "deserialize_unique_services")]
     pub services: HashMap<String, ServiceConfig>,
     /// Root directory from which relative paths are resolved.
     pub project_dir: Option<String>,
@@ -179,16 +220,108 @@ pub struct ConfigV1 {
     /// Status and inspect snapshot collection configuration.
     #[serde(default)]
     pub status: StatusConfig,
+    /// Project-wide deployment settings, such as restart parallelism.
+    #[serde(default)]
+    pub deployment: ProjectDeploymentConfig,
+    /// Restart and stop settings inherited by every service that does not set
+    /// its own.
+    #[serde(default)]
+    pub defaults: ServiceDefaults,
+    /// Command run once every service has come up on `sysg start`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_ready: Option<HookAction>,
+    /// Command run instead of `on_ready` when any service fails to start.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_start_failure: Option<HookAction>,
+    /// Whether a full start keeps launching services after one fails.
+    #[serde(default)]
+    pub on_failure: StartFailureMode,
+    /// Pause between a service becoming ready and a full start launching the
+    /// services that depend on it, e.g. `"2s"`. Defaults to none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dependency_settle: Option<String>,
+    /// Settings for the supervisor process itself rather than its services.
+    #[serde(default)]
+    pub supervisor: SupervisorProcessConfig,
+}
+
+/// Deserializes a `services:` map, rejecting a name declared twice. YAML
+/// permits duplicate keys and a plain `HashMap` keeps only the last, which
+/// would silently drop the earlier definition.
+fn deserialize_unique_services<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<String, ServiceConfig>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct UniqueServices;
+
+    impl<'de> serde::de::Visitor<'de> for UniqueServices {
+        type Value = HashMap<String, ServiceConfig>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("a map of service names to service definitions")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::MapAccess<'de>,
+        {
+            let mut services = HashMap::with_capacity(map.size_hint().unwrap_or(0));
+            while let Some(name) = map.next_key::<String>()? {
+                if services.contains_key(&name) {
+                    return Err(A::Error::custom(format!(
+                        "duplicate service `{name}`: each service name may be declared only once"
+                    )));
+                }
+                let service = map.next_value::<ServiceConfig>()?;
+                services.insert(name, service);
+            }
+            Ok(services)
+        }
+    }
+
+    deserializer.deserialize_map(UniqueServices)
+}
+
+/// Returns pairs of service names that differ only by letter case. They are
+/// distinct services, but their log files collide on case-insensitive
+/// filesystems.
+pub(crate) fn case_insensitive_collisions(
+    services: &HashMap<String, ServiceConfig>,
+) -> Vec<(String, String)> {
+    let mut names: Vec<&String> = services.keys().collect();
+    names.sort();
+    let mut seen: HashMap<String, &String> = HashMap::new();
+    let mut collisions = Vec::new();
+    for name in names {
+        if let Some(first) = seen.get(&name.to_lowercase()) {
+            collisions.push(((*first).clone(), name.clone()));
+        } else {
+            seen.insert(name.to_lowercase(), name);
+        }
+    }
+    collisions
+}
+
+/// Warns about service names in `config` that differ only by case.
+fn warn_case_insensitive_collisions(config: &Config) {
+    for (first, second) in case_insensitive_collisions(&config.services) {
+        warn!(
+            "services '{first}' and '{second}' differ only by case; their log files collide on case-insensitive filesystems"
+        );
+    }
 }
 
 /// One project inside a `projects:` map. The map key supplies the id; the entry
 /// carries its display name and services, plus optional per-project overrides.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 pub struct ProjectEntry {
     /// Human-friendly display name. Defaults to the project id (the map key).
     #[serde(default)]
     pub name: Option<String>,
     /// Services belonging to this project.
+    #[serde(deserialize_with = "deserialize_unique_services")]
     pub services: HashMap<String, ServiceConfig>,
     /// Optional per-project environment overrides.
     #[serde(default)]
@@ -239,6 +372,13 @@ impl Default for Config {
             metrics: MetricsConfig::default(),
             logs: LogsConfig::default(),
             status: StatusConfig::default(),
+            deployment: ProjectDeploymentConfig::default(),
+            on_ready: None,
+            on_start_failure: None,
+            on_failure: Default::default(),
+            dependency_settle: None,
+            supervisor: Default::default(),
+            env_overrides: BTreeMap::new(),
         }
     }
 }
@@ -260,7 +400,9 @@ impl ConfigV1 {
             let mut entries: Vec<(String, ProjectEntry)> = projects.into_iter().collect();
             entries.sort_by(|a, b| a.0.cmp(&b.0));
             for (id, entry) in entries {
-                let mut project_services = entry.services;
+                let mut project_services = expand_templates(entry.services)?;
+                self.defaults.apply(&mut project_services);
+                check_forking_services(&project_services)?;
                 tag_project_scope(&mut project_services, &id);
                 configs.push(Config {
                     version: CURRENT_MANIFEST_VERSION,
@@ -274,11 +416,20 @@ impl ConfigV1 {
                     metrics: self.metrics.clone(),
                     logs: entry.logs.unwrap_or_else(|| self.logs.clone()),
                     status: self.status.clone(),
+                    deployment: self.deployment.clone(),
+                    on_ready: self.on_ready.clone(),
+                    on_start_failure: self.on_start_failure.clone(),
+                    on_failure: self.on_failure,
+                    dependency_settle: self.dependency_settle.clone(),
+                    supervisor: self.supervisor.clone(),
+                    env_overrides: BTreeMap::new(),
                 });
             }
 
             if !self.services.is_empty() {
-                let mut loose = self.services;
+                let mut loose = expand_templates(self.services)?;
+                self.defaults.apply(&mut loose);
+                check_forking_services(&loose)?;
                 tag_project_scope(&mut loose, LOOSE_PROJECT_SCOPE);
                 configs.push(Config {
                     version: CURRENT_MANIFEST_VERSION,
@@ -289,21 +440,38 @@ impl ConfigV1 {
                     metrics: self.metrics,
                     logs: self.logs,
                     status: self.status,
+                    deployment: self.deployment,
+                    on_ready: self.on_ready,
+                    on_start_failure: self.on_start_failure,
+                    on_failure: self.on_failure,
+                    dependency_settle: self.dependency_settle,
+                    supervisor: self.supervisor,
+                    env_overrides: BTreeMap::new(),
                 });
             }
 
             return Ok(configs);
         }
 
+        let mut services = expand_templates(self.services)?;
+        self.defaults.apply(&mut services);
+        check_forking_services(&services)?;
         configs.push(Config {
             version: CURRENT_MANIFEST_VERSION,
             project: self.project.map(Into::into).unwrap_or_default(),
-            services: self.services,
+            services,
             project_dir: self.project_dir,
             env: self.env,
             metrics: self.metrics,
             logs: self.logs,
             status: self.status,
+            deployment: self.deployment,
+            on_ready: self.on_ready,
+            on_start_failure: self.on_start_failure,
+            on_failure: self.on_failure,
+            dependency_settle: self.dependency_settle,
+            supervisor: self.supervisor,
+            env_overrides: BTreeMap::new(),
         });
         Ok(configs)
     }
@@ -349,7 +517,7 @@ pub struct ProjectConfig {
 }
 
 /// YAML shapes accepted for top-level project metadata before normalization.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum ProjectConfigInput {
     /// Shorthand project declaration: `project: my_project`.
@@ -419,7 +587,9 @@ fn resolve_project_config(
 }
 
 /// Output sink for supervised service stdout/stderr.
-#[derive(Debug, Deserialize, Clone, Copy, serde::Serialize, PartialEq, Eq, Default)]
+#[derive(
+    Debug, Deserialize, Clone, Copy, serde::Serialize, PartialEq, Eq, Default, JsonSchema,
+)]
 #[serde(rename_all = "lowercase")]
 pub enum LogSink {
     /// Persist service output to systemg-managed log files.
@@ -429,8 +599,23 @@ pub enum LogSink {
     None,
 }
 
+/// When a service's log writer pushes buffered output to disk.
+#[derive(
+    Debug, Deserialize, Clone, Copy, serde::Serialize, PartialEq, Eq, Default, JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFlush {
+    /// Flush after every captured line.
+    #[default]
+    Line,
+    /// Flush at most once per second while output keeps arriving.
+    Interval,
+    /// Flush only when the buffer fills or the service exits.
+    None,
+}
+
 /// Logging configuration shared by global and service-level config blocks.
-#[derive(Debug, Deserialize, Clone, serde::Serialize, Default)]
+#[derive(Debug, Deserialize, Clone, serde::Serialize, Default, JsonSchema)]
 #[serde(default)]
 pub struct LogsConfig {
     /// Where service stdout/stderr should be sent.
@@ -439,6 +624,9 @@ pub struct LogsConfig {
     pub max_bytes: Option<u64>,
     /// Number of rotated files to retain per active log.
     pub max_files: Option<usize>,
+    /// How eagerly captured output is flushed to the log file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flush: Option<LogFlush>,
 }
 
 /// Fully resolved logging policy for a service.
@@ -450,6 +638,9 @@ pub struct EffectiveLogsConfig {
     pub max_bytes: u64,
     /// Number of rotated files to retain per active log.
     pub max_files: usize,
+    /// How eagerly captured output is flushed to the log file.
+    #[serde(default)]
+    pub flush: LogFlush,
 }
 
 impl Default for EffectiveLogsConfig {
@@ -459,6 +650,7 @@ impl Default for EffectiveLogsConfig {
             sink: LogSink::File,
             max_bytes,
             max_files,
+            flush: LogFlush::default(),
         }
     }
 }
@@ -488,12 +680,18 @@ impl LogsConfig {
                 .and_then(|logs| logs.max_files)
                 .or_else(|| global.and_then(|logs| logs.max_files))
                 .unwrap_or(defaults.max_files),
+            flush: service
+                .and_then(|logs| logs.flush)
+                .or_else(|| global.and_then(|logs| logs.flush))
+                .unwrap_or(defaults.flush),
         }
     }
 }
 
 /// Snapshot collection mode for status and inspect views.
-#[derive(Debug, Deserialize, Clone, Copy, serde::Serialize, PartialEq, Eq, Default)]
+#[derive(
+    Debug, Deserialize, Clone, Copy, serde::Serialize, PartialEq, Eq, Default, JsonSchema,
+)]
 #[serde(rename_all = "lowercase")]
 pub enum StatusSnapshotMode {
     /// Use persisted state only; no background live process snapshot refresh.
@@ -506,13 +704,15 @@ pub enum StatusSnapshotMode {
 }
 
 /// Status and inspect snapshot configuration.
-#[derive(Debug, Deserialize, Clone, serde::Serialize)]
+#[derive(Debug, Deserialize, Clone, serde::Serialize, JsonSchema)]
 #[serde(default)]
 pub struct StatusConfig {
     /// Snapshot collection mode.
     pub snapshot_mode: StatusSnapshotMode,
     /// Interval between background status snapshot refreshes.
     pub snapshot_interval_secs: u64,
+    /// Manifest-wide overrides for how unit health is classified.
+    pub health: HealthPolicy,
 }
 
 impl Default for StatusConfig {
@@ -520,6 +720,49 @@ impl Default for StatusConfig {
         Self {
             snapshot_mode: StatusSnapshotMode::Summary,
             snapshot_interval_secs: STATUS_DEFAULT_SNAPSHOT_INTERVAL_SECS,
+            health: HealthPolicy::default(),
+        }
+    }
+}
+
+/// Overrides for the health verdict `sysg status` gives a unit in each of the
+/// conditions below. An unset field keeps the built-in classification.
+#[derive(
+    Debug, Deserialize, Clone, Copy, Default, serde::Serialize, PartialEq, Eq, JsonSchema,
+)]
+#[serde(default, deny_unknown_fields)]
+pub struct HealthPolicy {
+    /// A `serve` service whose tracked PID is gone. Defaults to `warn`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missing_process: Option<UnitHealth>,
+    /// A `serve` service that is stopped. Defaults to `warn`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stopped: Option<UnitHealth>,
+    /// A service whose last exit was an error. Defaults to `failing`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exited_with_error: Option<UnitHealth>,
+    /// A cron job whose latest run failed. Defaults to `failing`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cron_failure: Option<UnitHealth>,
+    /// A cron run skipped because the previous one was still going. Defaults
+    /// to `warn`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cron_overlap: Option<UnitHealth>,
+}
+
+impl HealthPolicy {
+    /// Returns this policy with every field `overrides` sets taking precedence,
+    /// so a service's own `health` block wins over `status.health`.
+    pub fn overlaid(&self, overrides: Option<&HealthPolicy>) -> HealthPolicy {
+        let Some(overrides) = overrides else {
+            return *self;
+        };
+        HealthPolicy {
+            missing_process: overrides.missing_process.or(self.missing_process),
+            stopped: overrides.stopped.or(self.stopped),
+            exited_with_error: overrides.exited_with_error.or(self.exited_with_error),
+            cron_failure: overrides.cron_failure.or(self.cron_failure),
+            cron_overlap: overrides.cron_overlap.or(self.cron_overlap),
         }
     }
 }
@@ -531,8 +774,115 @@ impl StatusConfig {
     }
 }
 
+/// Manifest settings for the supervisor process itself. Unlike the top-level
+/// `env`, nothing here reaches the services the supervisor launches.
+#[derive(Debug, Deserialize, Clone, Default, serde::Serialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct SupervisorProcessConfig {
+    /// Variables set on the supervisor's own environment when it starts, such
+    /// as `TZ` for cron schedules in the local timezone. A variable already
+    /// present in the environment the supervisor was started from keeps that
+    /// value.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+}
+
+/// Top-level deployment settings shared by every service in a project.
+#[derive(Debug, Deserialize, Clone, Default, serde::Serialize, JsonSchema)]
+#[serde(default)]
+pub struct ProjectDeploymentConfig {
+    /// How many services a restart may replace at once. A service still never
+    /// restarts alongside one of its dependencies. Defaults to 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_parallel: Option<usize>,
+}
+
+impl ProjectDeploymentConfig {
+    /// The restart worker-pool width, never less than one.
+    pub fn max_parallel(&self) -> usize {
+        self.max_parallel.unwrap_or(1).max(1)
+    }
+}
+
+/// Manifest-wide fallbacks for per-service restart and stop settings. Merged
+/// into each service at load time, so a service's own value always wins and
+/// nothing downstream needs to know where a setting came from.
+#[derive(Debug, Deserialize, Clone, Default, JsonSchema)]
+#[serde(default)]
+pub struct ServiceDefaults {
+    /// Restart policy for services that do not set `restart_policy`.
+    #[schemars(schema_with = "restart_policy_schema")]
+    pub restart_policy: Option<RestartPolicy>,
+    /// Restart backoff for services that do not set `backoff`.
+    pub backoff: Option<String>,
+    /// Restart cap for services that do not set `max_restarts`.
+    pub max_restarts: Option<u32>,
+    /// Stop grace period for services that do not set `stop_timeout`.
+    pub stop_timeout: Option<String>,
+}
+
+impl ServiceDefaults {
+    /// Fills every unset field of each service from these defaults.
+    fn apply(&self, services: &mut HashMap<String, ServiceConfig>) {
+        for service in services.values_mut() {
+            if service.restart_policy.is_none() {
+                service.restart_policy = self.restart_policy;
+            }
+            if service.backoff.is_none() {
+                service.backoff.clone_from(&self.backoff);
+            }
+            if service.max_restarts.is_none() {
+                service.max_restarts = self.max_restarts;
+            }
+            if service.stop_timeout.is_none() {
+                service.stop_timeout.clone_from(&self.stop_timeout);
+            }
+        }
+    }
+}
+
+/// When a service is relaunched after it exits.
+///
+/// Parsed when the manifest loads, so a misspelled policy is rejected instead
+/// of silently behaving like an unset one.
+#[derive(Debug, Deserialize, Clone, Copy, serde::Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum RestartPolicy {
+    /// Relaunch after every exit.
+    Always,
+    /// Relaunch only after an unsuccessful exit.
+    OnFailure,
+    /// Never relaunch.
+    Never,
+}
+
+impl RestartPolicy {
+    /// Returns the manifest spelling of this policy.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RestartPolicy::Always => RESTART_ALWAYS,
+            RestartPolicy::OnFailure => RESTART_ON_FAILURE,
+            RestartPolicy::Never => RESTART_NEVER,
+        }
+    }
+}
+
+/// What a full project start does once a service fails to come up.
+#[derive(
+    Debug, Deserialize, Clone, Copy, serde::Serialize, PartialEq, Eq, Default, JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum StartFailureMode {
+    /// Start every service that can still start, then report every failure.
+    #[default]
+    Continue,
+    /// Launch nothing further after the first failure. Services already up
+    /// keep running.
+    Abort,
+}
+
 /// Top-level metrics configuration block.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, JsonSchema)]
 #[serde(default)]
 pub struct MetricsConfig {
     /// Number of minutes to retain in-memory samples (minimum: 1).
@@ -602,7 +952,7 @@ impl MetricsConfig {
 }
 
 /// Skip configuration for a service.
-#[derive(Debug, Deserialize, Clone, serde::Serialize)]
+#[derive(Debug, Deserialize, Clone, serde::Serialize, JsonSchema)]
 #[serde(untagged)]
 pub enum SkipConfig {
     /// Boolean flag that, when `true`, always skips the service.
@@ -612,8 +962,70 @@ pub enum SkipConfig {
     Command(String),
 }
 
+/// Declarative preconditions for a service. Every listed condition must hold
+/// for the service to start; otherwise it is skipped, not failed, and
+/// dependents are skipped with it.
+#[derive(Debug, Deserialize, Clone, serde::Serialize, Default, JsonSchema)]
+pub struct ConditionConfig {
+    /// Paths that must exist, relative to the project directory unless absolute.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub path_exists: Vec<String>,
+    /// Environment variables that must be set, either in the service's own
+    /// `env` or inherited from the supervisor.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env_set: Vec<String>,
+    /// Host the service is restricted to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host_is: Option<HostCondition>,
+}
+
+/// Host platform a service requires. Unset fields match any host.
+#[derive(Debug, Deserialize, Clone, serde::Serialize, Default, JsonSchema)]
+pub struct HostCondition {
+    /// Operating system name as Rust reports it, e.g. `linux` or `macos`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub os: Option<String>,
+    /// CPU architecture as Rust reports it, e.g. `x86_64` or `aarch64`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arch: Option<String>,
+}
+
+impl ConditionConfig {
+    /// Describes the first condition that does not hold, or `None` when the
+    /// service may start. Relative paths resolve against `project_root`, and
+    /// `is_set` reports whether a variable reaches the service environment.
+    pub fn unmet(
+        &self,
+        project_root: &Path,
+        is_set: impl Fn(&str) -> bool,
+    ) -> Option<String> {
+        if let Some(host) = &self.host_is {
+            if let Some(os) = &host.os
+                && !os.eq_ignore_ascii_case(env::consts::OS)
+            {
+                return Some(format!("host os is `{}`, not `{os}`", env::consts::OS));
+            }
+            if let Some(arch) = &host.arch
+                && !arch.eq_ignore_ascii_case(env::consts::ARCH)
+            {
+                return Some(format!(
+                    "host arch is `{}`, not `{arch}`",
+                    env::consts::ARCH
+                ));
+            }
+        }
+        if let Some(var) = self.env_set.iter().find(|var| !is_set(var)) {
+            return Some(format!("environment variable `{var}` is not set"));
+        }
+        self.path_exists
+            .iter()
+            .find(|path| !project_root.join(path).exists())
+            .map(|path| format!("path `{path}` does not exist"))
+    }
+}
+
 /// Spawn mode configuration for dynamic child process creation.
-#[derive(Debug, Deserialize, Clone, serde::Serialize, PartialEq, Eq)]
+#[derive(Debug, Deserialize, Clone, serde::Serialize, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum SpawnMode {
     /// Static mode - no dynamic spawning allowed (default).
@@ -623,7 +1035,7 @@ pub enum SpawnMode {
 }
 
 /// Configuration for dynamic process spawning.
-#[derive(Debug, Deserialize, Clone, serde::Serialize, Default)]
+#[derive(Debug, Deserialize, Clone, serde::Serialize, Default, JsonSchema)]
 pub struct SpawnConfig {
     /// Spawn mode (static or dynamic).
     pub mode: Option<SpawnMode>,
@@ -632,7 +1044,7 @@ pub struct SpawnConfig {
 }
 
 /// Resource limits and policies for dynamically spawned children.
-#[derive(Debug, Deserialize, Clone, serde::Serialize, Default)]
+#[derive(Debug, Deserialize, Clone, serde::Serialize, Default, JsonSchema)]
 pub struct SpawnLimitsConfig {
     /// Maximum number of direct children allowed.
     pub children: Option<u32>,
@@ -647,7 +1059,7 @@ pub struct SpawnLimitsConfig {
 }
 
 /// Policy for handling process termination in spawn trees.
-#[derive(Debug, Deserialize, Clone, serde::Serialize, PartialEq, Eq)]
+#[derive(Debug, Deserialize, Clone, serde::Serialize, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum TerminationPolicy {
     /// Cascade - terminate all descendants when parent dies.
@@ -659,7 +1071,9 @@ pub enum TerminationPolicy {
 }
 
 /// Readiness condition a dependency must reach before dependents start.
-#[derive(Debug, Default, Deserialize, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[derive(
+    Debug, Default, Deserialize, Clone, Copy, PartialEq, Eq, serde::Serialize, JsonSchema,
+)]
 #[serde(rename_all = "lowercase")]
 pub enum DependsOnCondition {
     /// Dependency is running (and passed its health check, if any).
@@ -671,7 +1085,7 @@ pub enum DependsOnCondition {
 
 /// A single `depends_on` entry: a bare service name or a detailed form
 /// with an explicit readiness condition.
-#[derive(Debug, Deserialize, Clone, PartialEq, Eq, serde::Serialize)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, serde::Serialize, JsonSchema)]
 #[serde(untagged)]
 pub enum DependsOn {
     /// Bare service name; waits for the dependency to start.
@@ -717,9 +1131,11 @@ impl From<String> for DependsOn {
 }
 
 /// Configuration for an individual service.
-#[derive(Debug, Default, Deserialize, Clone, serde::Serialize)]
+#[derive(Debug, Default, Deserialize, Clone, serde::Serialize, JsonSchema)]
 pub struct ServiceConfig {
-    /// Command used to start the service.
+    /// Command used to start the service. A list is an argv, stored shell-quoted.
+    #[serde(deserialize_with = "deserialize_command")]
+    #[schemars(schema_with = "command_schema")]
     pub command: String,
     /// Optional environment variables for the service.
     pub env: Option<EnvConfig>,
@@ -736,14 +1152,103 @@ pub struct ServiceConfig {
     pub capabilities: Option<Vec<String>>,
     /// Namespace and confinement settings for sandboxed execution.
     pub isolation: Option<IsolationConfig>,
-    /// Restart policy (e.g., "always", "on-failure", "never").
-    pub restart_policy: Option<String>,
+    /// File-creation mask for the service process, as an octal string (e.g.
+    /// "0027"). Unset inherits the supervisor's umask.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub umask: Option<String>,
+    /// Restart policy (`always`, `on-failure`, or `never`).
+    #[serde(default)]
+    #[schemars(schema_with = "restart_policy_schema")]
+    pub restart_policy: Option<RestartPolicy>,
     /// Backoff time before restarting a failed service.
     pub backoff: Option<String>,
     /// Maximum number of restart attempts before giving up (None = unlimited).
     pub max_restarts: Option<u32>,
+    /// Extra launch attempts when the service fails to become ready on start.
+    /// Separate from `restart_policy`, which only governs crashes after startup.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_retries: Option<u32>,
+    /// Pause between start attempts (e.g. "2s", "1m"). Defaults to one second.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_retry_delay: Option<String>,
+    /// How long the process must stay running before it counts as ready (e.g.
+    /// "500ms"). Defaults to the supervisor's `startup_stability_ms`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ready_stable_for: Option<String>,
+    /// Regex matched against each line the service writes to stdout or
+    /// stderr; the service is ready once a line matches, rather than once it
+    /// has merely stayed alive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ready_log_pattern: Option<String>,
+    /// How long the service may take to become ready (e.g. "30s"). Defaults
+    /// to five seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_timeout: Option<String>,
+    /// How long the service may take to exit after SIGTERM before it is
+    /// killed (e.g. "30s"). Defaults to one second.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_timeout: Option<String>,
+    /// How many instances a template service (a name ending in `@`) expands to.
+    /// Each becomes its own `name@N` service; defaults to one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instances: Option<u32>,
+    /// Whether the command daemonizes itself (forks and exits). The supervisor
+    /// then tracks the PID read from `pidfile` instead of the launched process.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forking: Option<bool>,
+    /// File a `forking` service writes its real PID to, relative to the project
+    /// directory unless absolute.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pidfile: Option<String>,
+    /// Listening sockets the supervisor binds and passes to the service as
+    /// inherited descriptors, systemd socket-activation style. They stay open
+    /// across restarts, so the port never stops accepting connections.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sockets: Option<Vec<SocketConfig>>,
+    /// TCP ports the service listens on. The supervisor refuses to launch it
+    /// while another process holds one, and rejects a manifest in which two
+    /// services declare the same port.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ports: Option<Vec<u16>>,
+    /// Restart the service once its resident memory stays above this size
+    /// (e.g. "512M") for `rss_window`: a soft guard against slow leaks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restart_if_rss_exceeds: Option<LimitValue>,
+    /// How long RSS must stay above `restart_if_rss_exceeds` before the restart
+    /// (e.g. "5m"). Defaults to one minute.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rss_window: Option<String>,
     /// List of services that must start before this service.
     pub depends_on: Option<Vec<DependsOn>>,
+    /// Start order among services whose dependencies are equally satisfied;
+    /// lower starts first, and equal priorities fall back to the name.
+    /// Defaults to 0.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
+    /// Services this one starts after, without depending on them: a failed or
+    /// skipped service listed here does not keep this one from starting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<Vec<String>>,
+    /// Services this one starts before, without them depending on it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<Vec<String>>,
+    /// When this service fails to start, a full start stops the services it
+    /// already launched, in reverse order, before reporting the failure.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub critical: Option<bool>,
+    /// Keep the service's stdin open as a pipe the supervisor holds, so
+    /// `sysg attach` can send it input. Defaults to false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interactive: Option<bool>,
+    /// Run the service on a pseudo-terminal instead of pipes, so programs that
+    /// fully buffer non-TTY output write line by line. Stdout and stderr share
+    /// the terminal and are logged as one stream. Defaults to false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pty: Option<bool>,
+    /// Interpreter that runs `command` with `-c`, or `none` to exec the
+    /// command's argv directly. Defaults to `sh`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shell: Option<String>,
     /// Deployment strategy configuration.
     pub deployment: Option<DeploymentConfig>,
     /// Hooks for lifecycle events (e.g., on_start, on_error).
@@ -752,21 +1257,186 @@ pub struct ServiceConfig {
     pub cron: Option<CronConfig>,
     /// Optional skip configuration that determines if the service should be skipped.
     pub skip: Option<SkipConfig>,
+    /// Preconditions (`path_exists`, `env_set`, `host_is`) that must hold for
+    /// the service to start; an unmet one skips it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<ConditionConfig>,
     /// Dynamic process spawning configuration.
     pub spawn: Option<SpawnConfig>,
     /// Service output logging overrides.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub logs: Option<LogsConfig>,
+    /// Health classification overrides for this service, on top of
+    /// `status.health`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<HealthPolicy>,
     /// Project this service belongs to, injected during multi-project fan-out so
     /// identical service configs in different projects hash distinctly and never
     /// collide in the shared pid/state files. `None` for single-project files, so
     /// their existing state-file keys stay byte-for-byte unchanged (no migration).
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(skip)]
     pub project_scope: Option<String>,
 }
 
+/// Accepts `command` as a single shell line or as an argv list. A list is
+/// joined into one shell-quoted line, so every consumer keeps seeing a string
+/// and `split_command` recovers the exact argv for `shell: none`.
+fn deserialize_command<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawCommand {
+        Line(String),
+        Argv(Vec<String>),
+    }
+
+    match RawCommand::deserialize(deserializer)? {
+        RawCommand::Line(line) => Ok(line),
+        RawCommand::Argv(argv) if argv.is_empty() => {
+            Err(D::Error::custom("command list must not be empty"))
+        }
+        RawCommand::Argv(argv) => Ok(argv
+            .iter()
+            .map(|arg| shell_quote(arg))
+            .collect::<Vec<_>>()
+            .join(" ")),
+    }
+}
+
+/// Schema for `command`, which accepts a string or an argv list.
+fn command_schema(_: &mut SchemaGenerator) -> Schema {
+    json_schema!({
+        "description": "Command used to start the service, as a shell line or an argv list.",
+        "anyOf": [
+            { "type": "string" },
+            { "type": "array", "items": { "type": "string" }, "minItems": 1 }
+        ]
+    })
+}
+
+/// Quotes `arg` for a POSIX shell, leaving plain words untouched.
+pub(crate) fn shell_quote(arg: &str) -> String {
+    let plain = !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./=:@%+,".contains(c));
+    if plain {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', "'\\''"))
+    }
+}
+
+/// Splits a command line into argv the way a POSIX shell tokenizes words:
+/// whitespace separates, single quotes are literal, and double quotes and
+/// backslashes escape. No expansion happens, so `$VAR` and globs stay as-is.
+pub fn split_command(line: &str) -> Result<Vec<String>, String> {
+    let mut argv = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                if in_word {
+                    argv.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            '\'' => {
+                in_word = true;
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => word.push(c),
+                        None => return Err("unterminated single quote".into()),
+                    }
+                }
+            }
+            '"' => {
+                in_word = true;
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c @ ('"' | '\\' | '$' | '`')) => word.push(c),
+                            Some(c) => {
+                                word.push('\\');
+                                word.push(c);
+                            }
+                            None => return Err("unterminated double quote".into()),
+                        },
+                        Some(c) => word.push(c),
+                        None => return Err("unterminated double quote".into()),
+                    }
+                }
+            }
+            '\\' => {
+                in_word = true;
+                match chars.next() {
+                    Some(c) => word.push(c),
+                    None => return Err("trailing backslash".into()),
+                }
+            }
+            c => {
+                in_word = true;
+                word.push(c);
+            }
+        }
+    }
+    if in_word {
+        argv.push(word);
+    }
+    Ok(argv)
+}
+
+/// Whether `shell` names an existing file, or a bare name found on `PATH`.
+fn shell_exists(shell: &str) -> bool {
+    if shell.contains('/') {
+        return Path::new(shell).is_file();
+    }
+    std::env::var_os("PATH").is_some_and(|paths| {
+        std::env::split_paths(&paths).any(|dir| dir.join(shell).is_file())
+    })
+}
+
+/// How a service's `command` is launched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandLauncher {
+    /// Run the command line with `<shell> -c`.
+    Shell(String),
+    /// Exec the command's argv directly.
+    Direct(Vec<String>),
+}
+
+/// Schema for `restart_policy`, including the explicit `null` a manifest may use.
+fn restart_policy_schema(_: &mut SchemaGenerator) -> Schema {
+    json_schema!({
+        "description": "When the service is relaunched after it exits.",
+        "enum": [RESTART_ALWAYS, RESTART_ON_FAILURE, RESTART_NEVER, null]
+    })
+}
+
+/// A listening socket held by the supervisor on behalf of a service.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, serde::Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SocketConfig {
+    /// TCP address to listen on, e.g. "0.0.0.0:8080" or "[::]:8080".
+    pub listen: String,
+}
+
+impl SocketConfig {
+    /// Parses `listen` as an IP address and port.
+    pub fn address(&self) -> Result<SocketAddr, String> {
+        self.listen.parse().map_err(|_| self.listen.clone())
+    }
+}
+
 /// Resource limit overrides configured per service.
-#[derive(Debug, Deserialize, Clone, serde::Serialize, Default)]
+#[derive(Debug, Deserialize, Clone, serde::Serialize, Default, JsonSchema)]
 pub struct LimitsConfig {
     /// Maximum number of open file descriptors (`RLIMIT_NOFILE`).
     pub nofile: Option<LimitValue>,
@@ -783,7 +1453,7 @@ pub struct LimitsConfig {
 }
 
 /// Configuration options for cgroup v2 controllers.
-#[derive(Debug, Deserialize, Clone, serde::Serialize, Default)]
+#[derive(Debug, Deserialize, Clone, serde::Serialize, Default, JsonSchema)]
 pub struct CgroupConfig {
     /// Absolute path for the cgroup base; defaults to `/sys/fs/cgroup/systemg` when omitted.
     pub root: Option<String>,
@@ -865,6 +1535,22 @@ impl<'de> Deserialize<'de> for LimitValue {
     }
 }
 
+impl JsonSchema for LimitValue {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "LimitValue".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "A non-negative integer, a size with a K/M/G/T suffix (e.g. 512M), or 'unlimited'.",
+            "anyOf": [
+                { "type": "integer", "minimum": 0 },
+                { "type": "string", "pattern": "^\\s*([Uu][Nn][Ll][Ii][Mm][Ii][Tt][Ee][Dd]|[0-9_]+\\s*[KkMmGgTt]?[Bb]?)\\s*$" }
+            ]
+        })
+    }
+}
+
 #[derive(Debug)]
 /// Defines limit parse error values.
 enum LimitParseError {
@@ -926,7 +1612,7 @@ impl std::fmt::Display for LimitParseError {
 impl std::error::Error for LimitParseError {}
 
 /// Linux namespace and confinement options.
-#[derive(Debug, Deserialize, Clone, serde::Serialize, Default)]
+#[derive(Debug, Deserialize, Clone, serde::Serialize, Default, JsonSchema)]
 pub struct IsolationConfig {
     /// Enable network namespace isolation.
     pub network: Option<bool>,
@@ -950,16 +1636,143 @@ pub struct IsolationConfig {
 
 impl ServiceConfig {
     /// Returns whether this service should restart after an unsuccessful exit.
-    pub(crate) fn restarts_after_failure(&self) -> bool {
+    pub fn restarts_after_failure(&self) -> bool {
         matches!(
-            self.restart_policy.as_deref(),
-            Some(RESTART_ALWAYS | RESTART_ON_FAILURE)
+            self.restart_policy,
+            Some(RestartPolicy::Always | RestartPolicy::OnFailure)
         )
     }
 
     /// Returns whether this service explicitly disables automatic restarts.
     pub(crate) fn restart_is_disabled(&self) -> bool {
-        self.restart_policy.as_deref() == Some(RESTART_NEVER)
+        self.restart_policy == Some(RestartPolicy::Never)
+    }
+
+    /// Returns whether this service daemonizes itself and is tracked by pidfile.
+    pub(crate) fn is_forking(&self) -> bool {
+        self.forking == Some(true)
+    }
+
+    /// Returns whether a failed start of this service rolls back the start.
+    pub(crate) fn is_critical(&self) -> bool {
+        self.critical == Some(true)
+    }
+
+    /// Returns whether the service's stdin is a pipe `sysg attach` writes to.
+    pub(crate) fn is_interactive(&self) -> bool {
+        self.interactive == Some(true)
+    }
+
+    /// Returns whether the service's output goes through a pseudo-terminal.
+    pub(crate) fn uses_pty(&self) -> bool {
+        self.pty == Some(true)
+    }
+
+    /// Returns the RSS threshold in bytes that triggers a memory restart, if any.
+    pub(crate) fn rss_restart_limit(&self) -> Option<u64> {
+        match self.restart_if_rss_exceeds {
+            Some(LimitValue::Fixed(bytes)) if bytes > 0 => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// Parses `rss_window` with the same `<n>[ms|s|m|h]` grammar as the other
+    /// durations. `None` means the service does not set one.
+    pub(crate) fn rss_window_duration(
+        &self,
+    ) -> Result<Option<Duration>, ProcessManagerError> {
+        self.rss_window
+            .as_deref()
+            .map(crate::daemon::Daemon::parse_duration)
+            .transpose()
+    }
+
+    /// Parses `backoff` with the same `<n>[ms|s|m|h]` grammar as the other
+    /// durations. `None` means the service does not set one.
+    pub(crate) fn restart_backoff(
+        &self,
+    ) -> Result<Option<Duration>, ProcessManagerError> {
+        self.backoff
+            .as_deref()
+            .map(crate::daemon::Daemon::parse_duration)
+            .transpose()
+    }
+
+    /// Parses `stop_timeout` with the same `<n>[ms|s|m|h]` grammar as the other
+    /// durations. `None` means the service does not set one.
+    pub(crate) fn stop_grace(&self) -> Result<Option<Duration>, ProcessManagerError> {
+        self.stop_timeout
+            .as_deref()
+            .map(crate::daemon::Daemon::parse_duration)
+            .transpose()
+    }
+
+    /// Parses `ready_stable_for` with the same grammar as the other
+    /// durations. `None` means the service does not set one.
+    pub(crate) fn ready_stability(
+        &self,
+    ) -> Result<Option<Duration>, ProcessManagerError> {
+        self.ready_stable_for
+            .as_deref()
+            .map(crate::daemon::Daemon::parse_duration)
+            .transpose()
+    }
+
+    /// Parses `start_timeout` with the same grammar as the other durations.
+    /// `None` means the service does not set one.
+    pub(crate) fn start_deadline(&self) -> Result<Option<Duration>, ProcessManagerError> {
+        self.start_timeout
+            .as_deref()
+            .map(crate::daemon::Daemon::parse_duration)
+            .transpose()
+    }
+
+    /// Parses `start_retry_delay` with the same `<n>[ms|s|m|h]` grammar as the
+    /// other durations. `None` means the service does not set one.
+    pub(crate) fn start_retry_pause(
+        &self,
+    ) -> Result<Option<Duration>, ProcessManagerError> {
+        self.start_retry_delay
+            .as_deref()
+            .map(crate::daemon::Daemon::parse_duration)
+            .transpose()
+    }
+
+    /// Parses `umask` as an octal mask no wider than `0777`. `None` means the
+    /// service does not set one.
+    pub(crate) fn umask_mode(&self) -> Result<Option<u32>, String> {
+        let Some(raw) = self.umask.as_deref() else {
+            return Ok(None);
+        };
+        let digits = raw.trim();
+        let digits = digits.strip_prefix("0o").unwrap_or(digits);
+        match u32::from_str_radix(digits, 8) {
+            Ok(mask) if !digits.is_empty() && mask <= 0o777 => Ok(Some(mask)),
+            _ => Err(raw.to_string()),
+        }
+    }
+
+    /// Resolves how `command` is launched from `shell`. Fails when `shell: none`
+    /// is paired with a command that does not split into an argv.
+    pub fn command_launcher(&self) -> Result<CommandLauncher, String> {
+        match self.shell.as_deref() {
+            None => Ok(CommandLauncher::Shell(
+                crate::constants::DEFAULT_SHELL.to_string(),
+            )),
+            Some(crate::constants::NO_SHELL) => match split_command(&self.command) {
+                Ok(argv) if argv.is_empty() => Err("the command is empty".into()),
+                Ok(argv) => Ok(CommandLauncher::Direct(argv)),
+                Err(err) => Err(err),
+            },
+            Some(shell) => Ok(CommandLauncher::Shell(shell.to_string())),
+        }
+    }
+
+    /// Resolves the `pidfile` of a forking service against the project directory.
+    pub(crate) fn pidfile_path(&self, project_root: &Path) -> Option<PathBuf> {
+        self.pidfile
+            .as_ref()
+            .map(|pidfile| project_root.join(pidfile))
     }
 
     /// Resolves effective logging settings for this service.
@@ -980,25 +1793,52 @@ impl ServiceConfig {
     /// hash-based comparison (idempotent re-registration, restart reconcile
     /// diffing, state keys) would spuriously see a change. This canonical form is
     /// stable across loads.
+    ///
+    /// No field is excluded: every one of them shapes how the process is launched
+    /// or supervised. The hash is also persisted as each running unit's
+    /// `config_hash`, so changing the canonical form would flag every service as
+    /// drifted after an upgrade.
     pub fn compute_hash(&self) -> String {
         let value = serde_json::to_value(self)
             .expect("ServiceConfig should always be serializable");
-        let json =
-            serde_json::to_string(&value).expect("JSON value is always serializable");
-        let mut hasher = Sha256::new();
-        hasher.update(json.as_bytes());
-        let result = hasher.finalize();
-        format!(
-            "{:016x}",
-            u64::from_be_bytes(result[0..8].try_into().unwrap())
-        )
+        short_value_hash(&value)
+    }
+
+    /// Hashes each top-level field separately, keyed by its manifest name, so a
+    /// reload preview can name *which* settings changed instead of only noticing
+    /// that [`ServiceConfig::compute_hash`] moved. Unset fields are omitted.
+    pub fn field_hashes(&self) -> BTreeMap<String, String> {
+        let value = serde_json::to_value(self)
+            .expect("ServiceConfig should always be serializable");
+        let serde_json::Value::Object(fields) = value else {
+            return BTreeMap::new();
+        };
+        fields
+            .into_iter()
+            .filter(|(_, value)| !value.is_null())
+            .map(|(field, value)| (field, short_value_hash(&value)))
+            .collect()
     }
 }
 
+/// Returns the first 64 bits of the SHA256 of a canonical JSON value as hex.
+fn short_value_hash(value: &serde_json::Value) -> String {
+    let json = serde_json::to_string(value).expect("JSON value is always serializable");
+    let mut hasher = Sha256::new();
+    hasher.update(json.as_bytes());
+    let result = hasher.finalize();
+    format!(
+        "{:016x}",
+        u64::from_be_bytes(result[0..8].try_into().unwrap())
+    )
+}
+
 /// Deployment strategy configuration for a service.
-#[derive(Debug, Deserialize, Clone, serde::Serialize)]
+#[derive(Debug, Deserialize, Clone, serde::Serialize, JsonSchema)]
 pub struct DeploymentConfig {
     /// Deployment strategy: "rolling" or "immediate".
+    #[serde(default)]
+    #[schemars(schema_with = "deployment_strategy_schema")]
     pub strategy: Option<String>,
     /// Command to run before starting the new service.
     pub pre_start: Option<String>,
@@ -1010,8 +1850,16 @@ pub struct DeploymentConfig {
     pub blue_green: Option<BlueGreenDeploymentConfig>,
 }
 
+/// Schema for `deployment.strategy`, which is kept as a string at runtime.
+fn deployment_strategy_schema(_: &mut SchemaGenerator) -> Schema {
+    json_schema!({
+        "description": "How a restart replaces the running service.",
+        "enum": ["rolling", "immediate", null]
+    })
+}
+
 /// Blue/green rollout configuration used by rolling deployments on a single host.
-#[derive(Debug, Deserialize, Clone, serde::Serialize)]
+#[derive(Debug, Deserialize, Clone, serde::Serialize, JsonSchema)]
 pub struct BlueGreenDeploymentConfig {
     /// Environment variable used to inject the selected slot value (defaults to "PORT").
     pub env_var: Option<String>,
@@ -1034,32 +1882,123 @@ pub struct HealthCheckConfig {
     pub url: Option<String>,
     /// Optional command-based health check.
     pub command: Option<String>,
-    /// Time between health check attempts (e.g., "2s").
+    /// Time between health check attempts (e.g., "2s"). With exponential
+    /// backoff this is the first delay.
     pub interval: Option<String>,
+    /// How the delay between attempts grows. Defaults to a fixed `interval`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backoff: Option<HealthBackoff>,
+    /// Ceiling for an exponentially growing delay (e.g., "30s").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_interval: Option<String>,
     /// Per-probe timeout cap (e.g., "30s"). Bounds each individual attempt;
-    /// it does not control the service's whole readiness window.
+    /// it does not control the service's whole readiness window. YAML also
+    /// accepts `request_timeout`.
     pub attempt_timeout: Option<String>,
+    /// How long a URL probe may spend establishing its TCP connection (e.g.,
+    /// "1s"). Unset, the connection shares the `attempt_timeout` budget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connect_timeout: Option<String>,
     /// Total readiness budget (e.g., "5m"). Probing continues for at least this
     /// long even when connection failures return immediately. YAML also accepts
     /// `timeout` for compatibility with early v2 manifests.
     pub total_timeout: Option<String>,
     /// Number of retries before giving up.
     pub retries: Option<u32>,
+    /// Consecutive refused connections after which the check fails at once
+    /// instead of probing for the rest of its budget. Unset never gives up
+    /// early, since a starting service often refuses until it binds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_refused: Option<u32>,
+}
+
+/// How the delay between health-check attempts grows.
+#[derive(
+    Debug, Deserialize, Clone, Copy, serde::Serialize, PartialEq, Eq, Default, JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthBackoff {
+    /// Wait `interval` between every attempt.
+    #[default]
+    Fixed,
+    /// Double the delay after each failed attempt, capped at `max_interval`.
+    Exponential,
 }
 
 /// Deserializes the YAML shape accepted for generic health checks before validation.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 struct RawHealthCheckConfig {
+    /// URL probed until it answers successfully.
     url: Option<String>,
+    /// Command run until it exits successfully.
     command: Option<String>,
+    /// Time between health check attempts (e.g., "2s").
     interval: Option<String>,
+    /// How the delay between attempts grows: `fixed` or `exponential`.
+    backoff: Option<HealthBackoff>,
+    /// Ceiling for an exponentially growing delay (e.g., "30s").
+    max_interval: Option<String>,
+    /// Per-probe timeout cap (e.g., "30s").
+    #[serde(alias = "request_timeout")]
     attempt_timeout: Option<String>,
+    /// TCP connect timeout for URL probes (e.g., "1s").
+    connect_timeout: Option<String>,
+    /// Total readiness budget (e.g., "5m").
     #[serde(alias = "timeout")]
     total_timeout: Option<String>,
+    /// Number of retries before giving up.
     retries: Option<u32>,
+    /// Consecutive refused connections after which the check fails at once.
+    max_refused: Option<u32>,
+}
+
+impl JsonSchema for HealthCheckConfig {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "HealthCheckConfig".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let mut schema = RawHealthCheckConfig::json_schema(generator);
+        schema.insert(
+            "description".into(),
+            "Health check configuration used during rolling deployments.".into(),
+        );
+        // `timeout` is the pre-`total_timeout` spelling still accepted from YAML,
+        // and `request_timeout` pairs with `connect_timeout`.
+        if let Some(properties) = schema
+            .get_mut("properties")
+            .and_then(serde_json::Value::as_object_mut)
+        {
+            properties.insert(
+                "timeout".into(),
+                serde_json::json!({
+                    "description": "Deprecated alias for `total_timeout`.",
+                    "type": ["string", "null"],
+                    "deprecated": true
+                }),
+            );
+            properties.insert(
+                "request_timeout".into(),
+                serde_json::json!({
+                    "description": "Alias for `attempt_timeout`.",
+                    "type": ["string", "null"]
+                }),
+            );
+        }
+        schema.insert(
+            "anyOf".into(),
+            serde_json::json!([{ "required": ["url"] }, { "required": ["command"] }]),
+        );
+        schema
+    }
 }
 
+/// Error for a `url` health check in a build without the `http-health` feature.
+pub const HTTP_HEALTH_DISABLED: &str = "http health checks are not available in this \
+    build: rebuild systemg with the `http-health` feature, or probe with a health check \
+    `command` such as `nc -z 127.0.0.1 8080` or `curl -fsS <url>`";
+
 impl<'de> Deserialize<'de> for HealthCheckConfig {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -1071,14 +2010,26 @@ impl<'de> Deserialize<'de> for HealthCheckConfig {
                 "health check requires at least one of 'url' or 'command'",
             ));
         }
+        if !cfg!(feature = "http-health") && raw.url.is_some() {
+            return Err(D::Error::custom(HTTP_HEALTH_DISABLED));
+        }
+        if raw.max_refused == Some(0) {
+            return Err(D::Error::custom(
+                "health check 'max_refused' must be at least 1",
+            ));
+        }
 
         Ok(Self {
             url: raw.url,
             command: raw.command,
             interval: raw.interval,
+            backoff: raw.backoff,
+            max_interval: raw.max_interval,
             attempt_timeout: raw.attempt_timeout,
+            connect_timeout: raw.connect_timeout,
             total_timeout: raw.total_timeout,
             retries: raw.retries,
+            max_refused: raw.max_refused,
         })
     }
 }
@@ -1102,7 +2053,7 @@ pub struct EnvConfig {
     pub inherit_env: Option<bool>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 /// Deserializes supported `env` block shapes before normalizing them into `EnvConfig`.
 struct RawEnvConfig {
     /// Optional path to an environment file.
@@ -1120,7 +2071,23 @@ struct RawEnvConfig {
     entries: HashMap<String, String>,
 }
 
-impl<'de> Deserialize<'de> for EnvConfig {
+impl JsonSchema for EnvConfig {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "EnvConfig".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let mut schema = RawEnvConfig::json_schema(generator);
+        schema.insert(
+            "description".into(),
+            "Environment variables, given under `vars` or as direct key/value pairs."
+                .into(),
+        );
+        schema
+    }
+}
+
+impl<'de> Deserialize<'de> for EnvConfig {
     /// Deserializes an environment block, accepting either nested `vars` or direct key/value
     /// entries under `env`.
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -1239,6 +2206,16 @@ pub enum HookStage {
     OnStop,
     /// Hook triggered when service restarts.
     OnRestart,
+    /// Hook triggered before a rolling deployment replaces the running instance.
+    OnDeployStart,
+    /// Hook triggered once a rolling deployment's replacement has taken over.
+    OnDeploySuccess,
+    /// Hook triggered when a rolling deployment fails and is rolled back.
+    OnDeployFailure,
+    /// Project-level hook triggered once every service is up after a start.
+    OnReady,
+    /// Project-level hook triggered when a start leaves any service failed.
+    OnStartFailure,
 }
 
 /// Outcomes recorded for a lifecycle stage.
@@ -1252,7 +2229,7 @@ pub enum HookOutcome {
 }
 
 /// Command executed for a hook outcome.
-#[derive(Debug, Deserialize, Clone, serde::Serialize)]
+#[derive(Debug, Deserialize, Clone, serde::Serialize, JsonSchema)]
 pub struct HookAction {
     /// Shell command to execute for this hook.
     pub command: String,
@@ -1261,7 +2238,7 @@ pub struct HookAction {
 }
 
 /// Hook commands grouped by outcome for a lifecycle stage.
-#[derive(Debug, Deserialize, Clone, serde::Serialize)]
+#[derive(Debug, Deserialize, Clone, serde::Serialize, JsonSchema)]
 pub struct HookLifecycleConfig {
     /// Hook action to execute when the lifecycle event succeeds.
     pub success: Option<HookAction>,
@@ -1270,7 +2247,7 @@ pub struct HookLifecycleConfig {
 }
 
 /// Hooks that run on specific service lifecycle events.
-#[derive(Debug, Deserialize, Clone, serde::Serialize)]
+#[derive(Debug, Deserialize, Clone, serde::Serialize, JsonSchema)]
 pub struct Hooks {
     /// Hooks to execute when the service starts.
     pub on_start: Option<HookLifecycleConfig>,
@@ -1279,15 +2256,30 @@ pub struct Hooks {
     /// Hooks to execute when the service restarts.
     #[serde(default)]
     pub on_restart: Option<HookLifecycleConfig>,
+    /// Command to run before a rolling deployment starts replacing the service.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_deploy_start: Option<HookAction>,
+    /// Command to run after a rolling deployment's replacement passes its checks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_deploy_success: Option<HookAction>,
+    /// Command to run after a failed rolling deployment has been rolled back.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_deploy_failure: Option<HookAction>,
 }
 
 impl Hooks {
     /// Returns the configured hook action for a lifecycle stage and outcome.
+    /// Deployment stages already name their outcome, so `outcome` is ignored
+    /// for them.
     pub fn action(&self, stage: HookStage, outcome: HookOutcome) -> Option<&HookAction> {
         let lifecycle = match stage {
             HookStage::OnStart => self.on_start.as_ref(),
             HookStage::OnStop => self.on_stop.as_ref(),
             HookStage::OnRestart => self.on_restart.as_ref(),
+            HookStage::OnDeployStart => return self.on_deploy_start.as_ref(),
+            HookStage::OnDeploySuccess => return self.on_deploy_success.as_ref(),
+            HookStage::OnDeployFailure => return self.on_deploy_failure.as_ref(),
+            HookStage::OnReady | HookStage::OnStartFailure => return None,
         }?;
 
         match outcome {
@@ -1298,12 +2290,31 @@ impl Hooks {
 }
 
 /// Cron configuration for scheduled service execution.
-#[derive(Debug, Deserialize, Clone, serde::Serialize)]
+#[derive(Debug, Deserialize, Clone, serde::Serialize, JsonSchema)]
 pub struct CronConfig {
     /// Cron expression defining the schedule (e.g., "0 * * * * *").
     pub expression: String,
     /// Optional timezone for cron scheduling (defaults to system timezone).
     pub timezone: Option<String>,
+    /// Most execution records to keep for this job (defaults to 10).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub history_limit: Option<usize>,
+    /// Drop finished execution records older than this duration, e.g. `"72h"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub history_retention: Option<String>,
+}
+
+impl CronConfig {
+    /// Parses `history_retention`. `None` means records are only bounded by
+    /// `history_limit`.
+    pub(crate) fn history_retention(
+        &self,
+    ) -> Result<Option<Duration>, ProcessManagerError> {
+        self.history_retention
+            .as_deref()
+            .map(crate::daemon::Daemon::parse_duration)
+            .transpose()
+    }
 }
 
 /// Builds the persistent state key for a service: `{version}:{project}:{service}`.
@@ -1352,41 +2363,391 @@ impl Config {
             .map(|cfg| cfg.compute_hash())
     }
 
-    /// Returns services ordered so dependencies start before dependents.
+    /// How long `service_name` gets to exit after SIGTERM: its `stop_timeout`,
+    /// else [`STOP_GRACE_PERIOD`](crate::constants::STOP_GRACE_PERIOD).
+    pub fn stop_grace(&self, service_name: &str) -> Duration {
+        self.services
+            .get(service_name)
+            .and_then(|service| service.stop_grace().ok().flatten())
+            .unwrap_or(crate::constants::STOP_GRACE_PERIOD)
+    }
+
+    /// How long a full start waits after a service becomes ready before it
+    /// launches that service's dependents: `dependency_settle`, else zero.
+    pub fn dependency_settle(&self) -> Result<Duration, ProcessManagerError> {
+        self.dependency_settle
+            .as_deref()
+            .map_or(Ok(Duration::ZERO), crate::daemon::Daemon::parse_duration)
+    }
+
+    /// Rejects a `dependency_settle` that does not parse, so a typo fails at
+    /// load time instead of on the next full start.
+    pub(crate) fn check_dependency_settle(&self) -> Result<(), ProcessManagerError> {
+        if self.dependency_settle().is_err() {
+            let raw = self.dependency_settle.as_deref().unwrap_or_default();
+            return Err(ProcessManagerError::ConfigParseError(
+                serde_yaml::Error::custom(format!(
+                    "invalid dependency_settle '{raw}': expected {DURATION_GRAMMAR}"
+                )),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Runs every load-time check on a finalized manifest. Both loaders call
+    /// this, so a check added here applies to single- and multi-project loads.
+    pub(crate) fn validate(&self) -> Result<(), ProcessManagerError> {
+        self.service_start_order()?;
+        self.check_duration_field("backoff", |service| service.backoff.as_deref())?;
+        self.check_duration_field("stop_timeout", |service| {
+            service.stop_timeout.as_deref()
+        })?;
+        self.check_dependency_settle()?;
+        self.check_duration_field("start_retry_delay", |service| {
+            service.start_retry_delay.as_deref()
+        })?;
+        self.check_rss_limits()?;
+        self.check_duration_field("ready_stable_for", |service| {
+            service.ready_stable_for.as_deref()
+        })?;
+        self.check_duration_field("start_timeout", |service| {
+            service.start_timeout.as_deref()
+        })?;
+        self.check_umasks()?;
+        self.check_sockets()?;
+        self.check_log_paths()?;
+        self.check_ports()?;
+        self.check_cron_history()?;
+        self.check_shells()?;
+        self.check_ready_log_patterns()?;
+        warn_case_insensitive_collisions(self);
+        Ok(())
+    }
+
+    /// Rejects a per-service duration that does not parse, so a typo fails at
+    /// load time instead of whenever the supervisor first reads the field.
+    fn check_duration_field(
+        &self,
+        field: &str,
+        accessor: impl Fn(&ServiceConfig) -> Option<&str>,
+    ) -> Result<(), ProcessManagerError> {
+        let mut names: Vec<&String> = self.services.keys().collect();
+        names.sort();
+        for name in names {
+            let Some(raw) = accessor(&self.services[name]) else {
+                continue;
+            };
+            if crate::daemon::Daemon::parse_duration(raw).is_err() {
+                return Err(ProcessManagerError::ConfigParseError(
+                    serde_yaml::Error::custom(format!(
+                        "service '{name}' has an invalid {field} '{raw}': expected {DURATION_GRAMMAR}"
+                    )),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Rejects a `restart_if_rss_exceeds` that is not a positive size and an
+    /// `rss_window` that does not parse. Both used to be skipped at runtime
+    /// with at most a warning, leaving the memory guard quietly off.
+    pub(crate) fn check_rss_limits(&self) -> Result<(), ProcessManagerError> {
+        let mut names: Vec<&String> = self.services.keys().collect();
+        names.sort();
+        for name in names {
+            let service = &self.services[name];
+            let invalid_limit = match service.restart_if_rss_exceeds {
+                Some(LimitValue::Unlimited) => Some("unlimited"),
+                Some(LimitValue::Fixed(0)) => Some("0"),
+                _ => None,
+            };
+            if let Some(raw) = invalid_limit {
+                return Err(ProcessManagerError::ConfigParseError(
+                    serde_yaml::Error::custom(format!(
+                        "service '{name}' has an invalid restart_if_rss_exceeds '{raw}': expected a positive size, e.g. \"512M\" or \"2G\""
+                    )),
+                ));
+            }
+        }
+        self.check_duration_field("rss_window", |service| service.rss_window.as_deref())
+    }
+
+    /// Rejects a `umask` that is not an octal mask, so a typo fails at load time
+    /// instead of at exec, where the service would silently keep the default.
+    pub(crate) fn check_umasks(&self) -> Result<(), ProcessManagerError> {
+        let mut names: Vec<&String> = self.services.keys().collect();
+        names.sort();
+        for name in names {
+            if let Err(raw) = self.services[name].umask_mode() {
+                return Err(ProcessManagerError::ConfigParseError(
+                    serde_yaml::Error::custom(format!(
+                        "service '{name}' has an invalid umask '{raw}': expected an octal mask from 0000 to 0777, e.g. \"0027\""
+                    )),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Rejects a socket `listen` that is not an IP address and port, so a typo
+    /// fails at load time instead of when the service first starts.
+    pub(crate) fn check_sockets(&self) -> Result<(), ProcessManagerError> {
+        let mut names: Vec<&String> = self.services.keys().collect();
+        names.sort();
+        for name in names {
+            for socket in self.services[name].sockets.iter().flatten() {
+                if let Err(raw) = socket.address() {
+                    return Err(ProcessManagerError::ConfigParseError(
+                        serde_yaml::Error::custom(format!(
+                            "service '{name}' has an invalid socket '{raw}': expected an IP address and port, e.g. \"0.0.0.0:8080\""
+                        )),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Rejects port 0 and a port declared by more than one service, which
+    /// could never both be listening.
+    pub(crate) fn check_ports(&self) -> Result<(), ProcessManagerError> {
+        let mut names: Vec<&String> = self.services.keys().collect();
+        names.sort();
+        let mut owners: HashMap<u16, &String> = HashMap::new();
+        for name in names {
+            for &port in self.services[name].ports.iter().flatten() {
+                let problem = if port == 0 {
+                    format!("service '{name}' declares invalid port 0")
+                } else if let Some(owner) = owners.get(&port)
+                    && *owner != name
+                {
+                    format!("services '{owner}' and '{name}' both declare port {port}")
+                } else {
+                    owners.insert(port, name);
+                    continue;
+                };
+                return Err(ProcessManagerError::ConfigParseError(
+                    serde_yaml::Error::custom(problem),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Rejects two services whose names sanitize to the same log file, so
+    /// their output can never interleave in one file.
+    pub(crate) fn check_log_paths(&self) -> Result<(), ProcessManagerError> {
+        let mut names: Vec<&String> = self.services.keys().collect();
+        names.sort();
+        let mut seen: HashMap<String, &String> = HashMap::new();
+        for name in names {
+            let stem = crate::logs::log_file_stem(name);
+            if let Some(first) = seen.get(&stem) {
+                return Err(ProcessManagerError::ConfigParseError(
+                    serde_yaml::Error::custom(format!(
+                        "services '{first}' and '{name}' share log file '{stem}.log'; rename one of them"
+                    )),
+                ));
+            }
+            seen.insert(stem, name);
+        }
+        Ok(())
+    }
+
+    /// Rejects a `shell` that does not exist, or a `shell: none` command that
+    /// cannot be split into an argv, before any service tries to launch.
+    pub(crate) fn check_shells(&self) -> Result<(), ProcessManagerError> {
+        let mut names: Vec<&String> = self.services.keys().collect();
+        names.sort();
+        for name in names {
+            let service = &self.services[name];
+            let problem = match (service.shell.as_deref(), service.command_launcher()) {
+                (_, Err(err)) => Some(format!("cannot exec the command directly: {err}")),
+                (Some(shell), Ok(CommandLauncher::Shell(_))) if !shell_exists(shell) => {
+                    Some("no such executable".to_string())
+                }
+                _ => None,
+            };
+            if let Some(problem) = problem {
+                let shell = service.shell.as_deref().unwrap_or_default();
+                return Err(ProcessManagerError::ConfigParseError(
+                    serde_yaml::Error::custom(format!(
+                        "service '{name}' has an invalid shell '{shell}': {problem}"
+                    )),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Rejects a `ready_log_pattern` that is not a valid regex, which would
+    /// otherwise only fail once the service starts.
+    pub(crate) fn check_ready_log_patterns(&self) -> Result<(), ProcessManagerError> {
+        let mut names: Vec<&String> = self.services.keys().collect();
+        names.sort();
+        for name in names {
+            let Some(pattern) = self.services[name].ready_log_pattern.as_deref() else {
+                continue;
+            };
+            if let Err(err) = Regex::new(pattern) {
+                return Err(ProcessManagerError::ConfigParseError(
+                    serde_yaml::Error::custom(format!(
+                        "service '{name}' has an invalid ready_log_pattern '{pattern}': {err}"
+                    )),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Rejects a cron `history_limit` of zero or an unparsable
+    /// `history_retention`, which would otherwise surface only when the job
+    /// first records a run.
+    pub(crate) fn check_cron_history(&self) -> Result<(), ProcessManagerError> {
+        let mut names: Vec<&String> = self.services.keys().collect();
+        names.sort();
+        for name in names {
+            let Some(cron) = self.services[name].cron.as_ref() else {
+                continue;
+            };
+            if cron.history_limit == Some(0) {
+                return Err(ProcessManagerError::ConfigParseError(
+                    serde_yaml::Error::custom(format!(
+                        "service '{name}' has an invalid cron history_limit '0': keep at least 1 record"
+                    )),
+                ));
+            }
+        }
+        self.check_duration_field("cron history_retention", |service| {
+            service
+                .cron
+                .as_ref()
+                .and_then(|cron| cron.history_retention.as_deref())
+        })
+    }
+
+    /// Layers `sysg start --env` overrides onto every service's inline vars,
+    /// so they win over both the env file and the manifest's `env.vars`.
+    pub fn apply_env_overrides(&mut self, overrides: &BTreeMap<String, String>) {
+        if overrides.is_empty() {
+            return;
+        }
+        for service in self.services.values_mut() {
+            let env = service.env.get_or_insert_with(EnvConfig::default);
+            let vars = env.vars.get_or_insert_with(HashMap::new);
+            vars.extend(overrides.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+    }
+
+    /// Names of the services that start before `name`: its `depends_on` and
+    /// `after` entries, plus every service that lists it under `before`.
+    pub(crate) fn start_predecessors<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a str> {
+        let own = self.services.get(name).into_iter().flat_map(|service| {
+            service
+                .depends_on
+                .iter()
+                .flatten()
+                .map(DependsOn::service)
+                .chain(service.after.iter().flatten().map(String::as_str))
+        });
+        let ordered_before = self
+            .services
+            .iter()
+            .filter(move |(_, service)| {
+                service
+                    .before
+                    .as_ref()
+                    .is_some_and(|before| before.iter().any(|target| target == name))
+            })
+            .map(|(other, _)| other.as_str());
+        own.chain(ordered_before)
+    }
+
+    /// Rejects `after`/`before` entries that name no service, or that order a
+    /// service to start ahead of something it depends on.
+    fn validate_start_ordering(&self) -> Result<(), ProcessManagerError> {
+        for (service, cfg) in &self.services {
+            for target in cfg.after.iter().chain(&cfg.before).flatten() {
+                if !self.services.contains_key(target) {
+                    return Err(ProcessManagerError::UnknownOrderingTarget {
+                        service: service.clone(),
+                        target: target.clone(),
+                    });
+                }
+            }
+
+            // Each edge reads "first starts before then".
+            let edges = cfg
+                .before
+                .iter()
+                .flatten()
+                .map(|then| (service, then))
+                .chain(cfg.after.iter().flatten().map(|first| (first, service)));
+            for (first, then) in edges {
+                if self.services[first]
+                    .depends_on
+                    .iter()
+                    .flatten()
+                    .any(|dep| dep.service() == then)
+                {
+                    return Err(ProcessManagerError::OrderingConflict {
+                        service: first.clone(),
+                        dependency: then.clone(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns services ordered so dependencies start before dependents, and so
+    /// every `after`/`before` constraint holds. Services that become startable
+    /// at the same point are ordered by `priority`, then by name, so the order
+    /// is the same on every run.
     pub fn service_start_order(&self) -> Result<Vec<String>, ProcessManagerError> {
         let mut indegree: HashMap<String, usize> =
             self.services.keys().map(|name| (name.clone(), 0)).collect();
         let mut graph: HashMap<String, Vec<String>> = HashMap::new();
 
         for (service, cfg) in &self.services {
-            if let Some(deps) = &cfg.depends_on {
-                for dep in deps {
-                    let dep_name = dep.service();
-                    if !self.services.contains_key(dep_name) {
-                        return Err(ProcessManagerError::UnknownDependency {
-                            service: service.clone(),
-                            dependency: dep_name.to_string(),
-                        });
-                    }
-
-                    *indegree.get_mut(service).expect("service must exist") += 1;
-                    graph
-                        .entry(dep_name.to_string())
-                        .or_default()
-                        .push(service.clone());
+            for dep in cfg.depends_on.iter().flatten() {
+                if !self.services.contains_key(dep.service()) {
+                    return Err(ProcessManagerError::UnknownDependency {
+                        service: service.clone(),
+                        dependency: dep.service().to_string(),
+                    });
                 }
             }
         }
+        self.validate_start_ordering()?;
+
+        for service in self.services.keys() {
+            for predecessor in self.start_predecessors(service) {
+                *indegree.get_mut(service).expect("service must exist") += 1;
+                graph
+                    .entry(predecessor.to_string())
+                    .or_default()
+                    .push(service.clone());
+            }
+        }
 
-        let mut ready: BTreeSet<String> = indegree
+        // Keyed by (priority, name) so the order never depends on map iteration.
+        let rank = |name: &String| {
+            let priority = self.services[name].priority.unwrap_or_default();
+            (priority, name.clone())
+        };
+        let mut ready: BTreeSet<(i32, String)> = indegree
             .iter()
             .filter(|&(_, &deg)| deg == 0)
-            .map(|(name, _)| name.clone())
+            .map(|(name, _)| rank(name))
             .collect();
 
         let mut order = Vec::with_capacity(self.services.len());
 
-        while let Some(service) = ready.pop_first() {
+        while let Some((_, service)) = ready.pop_first() {
             order.push(service.clone());
 
             if let Some(children) = graph.get(&service) {
@@ -1394,7 +2755,7 @@ impl Config {
                     if let Some(deg) = indegree.get_mut(child) {
                         *deg -= 1;
                         if *deg == 0 {
-                            ready.insert(child.clone());
+                            ready.insert(rank(child));
                         }
                     }
                 }
@@ -1416,6 +2777,29 @@ impl Config {
         Ok(order)
     }
 
+    /// Groups `order` (a dependency order from [`Config::service_start_order`],
+    /// possibly filtered) into levels: each service sits one level above its
+    /// deepest dependency or `after`/`before` predecessor, so every service in a
+    /// level can start concurrently once the levels before it are up.
+    /// Predecessors missing from `order` do not raise a service's level.
+    pub fn service_start_levels(&self, order: &[String]) -> Vec<Vec<String>> {
+        let mut depth: HashMap<&str, usize> = HashMap::new();
+        let mut levels: Vec<Vec<String>> = Vec::new();
+        for name in order {
+            let level = self
+                .start_predecessors(name)
+                .filter_map(|predecessor| depth.get(predecessor).map(|level| level + 1))
+                .max()
+                .unwrap_or(0);
+            depth.insert(name, level);
+            if levels.len() <= level {
+                levels.resize_with(level + 1, Vec::new);
+            }
+            levels[level].push(name.clone());
+        }
+        levels
+    }
+
     /// Returns a map of each service to the services that depend on it.
     pub fn reverse_dependencies(&self) -> HashMap<String, Vec<String>> {
         let mut map: HashMap<String, Vec<String>> = HashMap::new();
@@ -1440,13 +2824,26 @@ impl Config {
 
 /// Expands environment variables within a string.
 fn expand_env_vars(input: &str) -> Result<String, ProcessManagerError> {
+    expand_env_vars_with(input, &HashMap::new())
+}
+
+/// Expands environment variables within a string, resolving each name against
+/// `overlay` before the process environment.
+fn expand_env_vars_with(
+    input: &str,
+    overlay: &HashMap<String, String>,
+) -> Result<String, ProcessManagerError> {
     let re = Regex::new(r"\$\{?([A-Za-z_][A-Za-z0-9_]*)\}?").unwrap();
     let mut missing = None;
     let result = re.replace_all(input, |caps: &regex::Captures| {
         let var_name = &caps[1];
-        match env::var(var_name) {
-            Ok(value) => value,
-            Err(_) => {
+        match overlay
+            .get(var_name)
+            .cloned()
+            .or_else(|| env::var(var_name).ok())
+        {
+            Some(value) => value,
+            None => {
                 if missing.is_none() {
                     missing = Some(var_name.to_string());
                 }
@@ -1462,10 +2859,51 @@ fn expand_env_vars(input: &str) -> Result<String, ProcessManagerError> {
     Ok(result.to_string())
 }
 
+/// Expands systemd-style `%` specifiers in a service command: `%n` becomes the
+/// service name, `%i` the instance of a templated `name@N` service, and `%%` a
+/// literal `%`. Any other `%` sequence — including `%i` outside a template
+/// instance — is left intact so commands such as `date +%s` keep working.
+pub fn expand_placeholders(input: &str, name: &str) -> String {
+    let instance = name.rsplit_once('@').map(|(_, instance)| instance);
+    let mut out = String::with_capacity(input.len());
+    let mut chars = input.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '%' {
+            out.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push_str(name),
+            Some('i') => match instance {
+                Some(instance) => out.push_str(instance),
+                None => out.push_str("%i"),
+            },
+            Some('%') => out.push('%'),
+            Some(other) => {
+                out.push('%');
+                out.push(other);
+            }
+            None => out.push('%'),
+        }
+    }
+    out
+}
+
 /// Loads an `.env` file and sets environment variables.
 fn load_env_file(path: &str) -> Result<(), ProcessManagerError> {
+    for (key, value) in read_env_file(path)? {
+        unsafe {
+            env::set_var(key, value);
+        }
+    }
+    Ok(())
+}
+
+/// Reads the `KEY=value` entries of an `.env` file, in file order.
+fn read_env_file(path: &str) -> Result<Vec<(String, String)>, ProcessManagerError> {
     let content =
         fs::read_to_string(path).map_err(ProcessManagerError::ConfigReadError)?;
+    let mut entries = Vec::new();
     for line in content.lines() {
         if let Some((key, value)) = line.split_once('=') {
             let key = key.trim();
@@ -1475,12 +2913,10 @@ fn load_env_file(path: &str) -> Result<(), ProcessManagerError> {
                 value = &value[1..value.len() - 1];
             }
 
-            unsafe {
-                env::set_var(key, value);
-            }
+            entries.push((key.to_string(), value.to_string()));
         }
     }
-    Ok(())
+    Ok(entries)
 }
 
 /// Parses a manifest using its declared schema version and migrates it to the
@@ -1528,6 +2964,73 @@ fn uses_legacy_project_shape(config: &ConfigV1) -> bool {
 /// the on-disk loose id so the scope and the state directory always agree.
 const LOOSE_PROJECT_SCOPE: &str = LOOSE_PROJECT_ID;
 
+/// Rejects a `forking` service without a `pidfile`: once its launcher exits
+/// there is nothing else to identify the daemon it left behind.
+fn check_forking_services(
+    services: &HashMap<String, ServiceConfig>,
+) -> Result<(), String> {
+    let mut names: Vec<&String> = services.keys().collect();
+    names.sort();
+    for name in names {
+        let service = &services[name];
+        if service.is_forking() && service.pidfile.is_none() {
+            return Err(format!(
+                "service '{name}' sets 'forking: true' but has no 'pidfile' to read its PID from"
+            ));
+        }
+        if service.pidfile.is_some() && !service.is_forking() {
+            return Err(format!(
+                "service '{name}' sets 'pidfile' without 'forking: true'"
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Replaces each template service (a name ending in `@`) with its `name@1` …
+/// `name@N` instances, so everything downstream of parsing — pid files, logs,
+/// status, restarts — treats every instance as an ordinary, independent service.
+fn expand_templates(
+    services: HashMap<String, ServiceConfig>,
+) -> Result<HashMap<String, ServiceConfig>, String> {
+    let mut expanded = HashMap::with_capacity(services.len());
+    let mut instances = Vec::new();
+    for (name, mut service) in services {
+        let Some(base) = name.strip_suffix('@') else {
+            if service.instances.is_some() {
+                return Err(format!(
+                    "service '{name}' sets 'instances' but is not a template; \
+                     rename it to '{name}@' to run it more than once"
+                ));
+            }
+            expanded.insert(name, service);
+            continue;
+        };
+        if base.is_empty() || base.contains('@') {
+            return Err(format!("invalid template service name '{name}'"));
+        }
+        let count = service.instances.take().unwrap_or(1);
+        if count == 0 {
+            return Err(format!(
+                "template service '{name}' sets 'instances: 0'; \
+                 it must run at least one instance"
+            ));
+        }
+        for index in 1..=count {
+            instances.push((format!("{base}@{index}"), service.clone()));
+        }
+    }
+    for (name, service) in instances {
+        if expanded.contains_key(&name) {
+            return Err(format!(
+                "service '{name}' is declared explicitly and by its template"
+            ));
+        }
+        expanded.insert(name, service);
+    }
+    Ok(expanded)
+}
+
 /// Stamps each service with its owning project so identical service configs in
 /// different projects hash distinctly and never collide in shared state.
 fn tag_project_scope(services: &mut HashMap<String, ServiceConfig>, scope: &str) {
@@ -1536,6 +3039,18 @@ fn tag_project_scope(services: &mut HashMap<String, ServiceConfig>, scope: &str)
     }
 }
 
+/// Returns a JSON Schema describing the manifest format, for editors and YAML
+/// language servers to validate and complete `sysg.config.yaml` against.
+pub fn manifest_schema() -> serde_json::Value {
+    let mut schema = schemars::schema_for!(ConfigV1);
+    schema.insert("title".into(), "systemg manifest".into());
+    schema.insert(
+        "description".into(),
+        "Services, projects, and runtime settings supervised by systemg.".into(),
+    );
+    schema.to_value()
+}
+
 /// Rewrites a legacy `project:` + `services:` manifest into the canonical
 /// `projects:` form, returning the converted YAML. A manifest that already uses
 /// `projects:` (and has no legacy `project:`) is returned unchanged.
@@ -1706,11 +3221,16 @@ pub fn load_config_from_file(
 
     config.project_dir = Some(base_path.to_string_lossy().to_string());
     config.project = resolve_project_config(config.project, &base_path)?;
-    for service in config.services.values_mut() {
+    for (name, service) in config.services.iter_mut() {
         service.env = EnvConfig::merge(config.env.as_ref(), service.env.as_ref());
+        service.command = expand_placeholders(&service.command, name);
+        service.pidfile = service
+            .pidfile
+            .as_deref()
+            .map(|pidfile| expand_placeholders(pidfile, name));
     }
 
-    config.service_start_order()?;
+    config.validate()?;
     Ok(config)
 }
 
@@ -1718,8 +3238,19 @@ pub fn load_config_from_file(
 /// env resolution and validation as [`load_config_from_file`] to each. This is
 /// how one file fans out into the multiple project runtimes the supervisor holds.
 pub fn load_projects_from_file(
+    file: fs::File,
+    config_path: &Path,
+) -> Result<Vec<Config>, ProcessManagerError> {
+    load_projects_from_file_with_env(file, config_path, &BTreeMap::new())
+}
+
+/// [`load_projects_from_file`] with `sysg start --env` overrides. They are set
+/// after the manifest's own env so `${VAR}` expansion sees them, then layered
+/// onto every service via [`Config::apply_env_overrides`].
+pub fn load_projects_from_file_with_env(
     mut file: fs::File,
     config_path: &Path,
+    env_overrides: &BTreeMap<String, String>,
 ) -> Result<Vec<Config>, ProcessManagerError> {
     use std::io::Read;
 
@@ -1736,15 +3267,20 @@ pub fn load_projects_from_file(
         .unwrap_or_else(|| Path::new("."))
         .to_path_buf();
 
-    // First pass over the raw text applies env-file side effects so ${VAR}
-    // expansion below can see them, mirroring load_config_from_file.
+    // First pass over the raw text collects env files and inline vars so ${VAR}
+    // expansion below can see them, mirroring load_config_from_file. They are
+    // kept off the process environment: the supervisor is multithreaded and
+    // reloads manifests while running, services get their env from their own
+    // config at spawn, and the supervisor keeps only its `supervisor.env`.
+    let mut overlay = HashMap::new();
     for config in
         parse_config_projects(&content).map_err(ProcessManagerError::ConfigParseError)?
     {
-        apply_env_side_effects(&config, &base_path)?;
+        collect_env_side_effects(&config, &base_path, &mut overlay)?;
     }
+    overlay.extend(env_overrides.iter().map(|(k, v)| (k.clone(), v.clone())));
 
-    let expanded_content = expand_env_vars(&content)?;
+    let expanded_content = expand_env_vars_with(&content, &overlay)?;
     let (configs, legacy) = parse_config_projects_with_legacy(&expanded_content)
         .map_err(ProcessManagerError::ConfigParseError)?;
 
@@ -1766,47 +3302,47 @@ pub fn load_projects_from_file(
     for mut config in configs {
         config.project_dir = Some(base_path.to_string_lossy().to_string());
         config.project = resolve_project_config(config.project, &base_path)?;
-        for service in config.services.values_mut() {
+        for (name, service) in config.services.iter_mut() {
             service.env = EnvConfig::merge(config.env.as_ref(), service.env.as_ref());
+            service.command = expand_placeholders(&service.command, name);
+            service.pidfile = service
+                .pidfile
+                .as_deref()
+                .map(|pidfile| expand_placeholders(pidfile, name));
         }
-        config.service_start_order()?;
+        config.apply_env_overrides(env_overrides);
+        config.env_overrides = env_overrides.clone();
+        config.validate()?;
         finalized.push(config);
     }
     Ok(finalized)
 }
 
-/// Applies a config's env-file loads and inline var sets to the process
-/// environment, so subsequent `${VAR}` expansion resolves against them.
-fn apply_env_side_effects(
+/// Collects a config's env-file entries and inline vars into `overlay`, so
+/// subsequent `${VAR}` expansion resolves against them. Later entries win, as
+/// they would if each were set on the process in turn.
+fn collect_env_side_effects(
     config: &Config,
     base_path: &Path,
+    overlay: &mut HashMap<String, String>,
 ) -> Result<(), ProcessManagerError> {
-    if let Some(env_config) = &config.env {
+    let mut collect = |env_config: &EnvConfig| -> Result<(), ProcessManagerError> {
         if let Some(resolved_path) = env_config.path(base_path) {
-            load_env_file(&resolved_path.to_string_lossy())?;
+            overlay.extend(read_env_file(&resolved_path.to_string_lossy())?);
         }
         if let Some(vars) = &env_config.vars {
-            for (key, value) in vars {
-                unsafe {
-                    env::set_var(key, value);
-                }
-            }
+            overlay.extend(vars.iter().map(|(k, v)| (k.clone(), v.clone())));
         }
-    }
+        Ok(())
+    };
 
+    if let Some(env_config) = &config.env {
+        collect(env_config)?;
+    }
     for service in config.services.values() {
         let merged_env = EnvConfig::merge(config.env.as_ref(), service.env.as_ref());
         if let Some(env_config) = &merged_env {
-            if let Some(resolved_path) = env_config.path(base_path) {
-                load_env_file(&resolved_path.to_string_lossy())?;
-            }
-            if let Some(vars) = &env_config.vars {
-                for (key, value) in vars {
-                    unsafe {
-                        env::set_var(key, value);
-                    }
-                }
-            }
+            collect(env_config)?;
         }
     }
     Ok(())
@@ -1942,94 +3478,565 @@ projects:
     }
 
     #[test]
-    fn parse_manifest_accepts_string_version() {
+    fn expand_placeholders_substitutes_name_and_literal_percent() {
+        assert_eq!(
+            expand_placeholders("worker --name %n --load 50%%", "web"),
+            "worker --name web --load 50%"
+        );
+    }
+
+    #[test]
+    fn expand_placeholders_leaves_unknown_specifiers_intact() {
+        assert_eq!(
+            expand_placeholders("date +%s && echo %q %", "web"),
+            "date +%s && echo %q %"
+        );
+    }
+
+    #[test]
+    fn template_service_expands_into_numbered_instances() {
         let config = parse_config_manifest(
             r#"
 version: "2"
 services:
-  api:
-    command: "echo ok"
+  web@:
+    command: "./server --shard %i --name %n"
+    instances: 3
+  db:
+    command: "postgres"
 "#,
         )
-        .expect("parse manifest");
+        .unwrap();
 
-        assert_eq!(config.version, Version::V2);
-        assert_eq!(config.services["api"].command, "echo ok");
+        let mut names = config.services.keys().cloned().collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(names, vec!["db", "web@1", "web@2", "web@3"]);
+        let instance = &config.services["web@2"];
+        assert_eq!(instance.instances, None);
+        assert_eq!(
+            expand_placeholders(&instance.command, "web@2"),
+            "./server --shard 2 --name web@2"
+        );
+        assert_eq!(expand_placeholders("printf %i", "db"), "printf %i");
     }
 
     #[test]
-    fn parse_manifest_accepts_integer_version() {
-        let config = parse_config_manifest(
+    fn instances_on_a_plain_service_is_rejected() {
+        let err = parse_config_manifest(
             r#"
-version: 2
+version: "2"
 services:
-  api:
-    command: "echo ok"
+  web:
+    command: "./server"
+    instances: 2
 "#,
         )
-        .expect("parse manifest");
-
-        assert_eq!(config.version, Version::V2);
-        assert_eq!(config.services["api"].command, "echo ok");
+        .expect_err("instances requires a template");
+        assert!(err.to_string().contains("web@"));
     }
 
     #[test]
-    fn load_config_accepts_project_object() {
-        let dir = tempdir().expect("tempdir");
-        let yaml_path = dir.path().join("systemg.yaml");
-        fs::write(
-            &yaml_path,
+    fn zero_template_instances_is_rejected() {
+        let err = parse_config_manifest(
             r#"
 version: "2"
-project:
-  id: arbitration
-  name: Arbitration
 services:
-  api:
-    command: "echo ok"
+  worker@:
+    command: "./worker"
+    instances: 0
 "#,
         )
-        .expect("write config");
+        .expect_err("a template needs at least one instance");
+        assert!(err.to_string().contains("instances: 0"));
+    }
 
-        let config = load_config(Some(yaml_path.to_str().unwrap())).unwrap();
+    #[test]
+    fn forking_service_requires_a_pidfile() {
+        let err = parse_config_manifest(
+            r#"
+version: "2"
+services:
+  nginx:
+    command: "nginx"
+    forking: true
+"#,
+        )
+        .expect_err("forking requires a pidfile");
+        assert!(err.to_string().contains("pidfile"));
 
-        assert_eq!(config.project.id, "arbitration");
-        assert_eq!(config.project.name, "Arbitration");
+        let config = parse_config_manifest(
+            r#"
+version: "2"
+services:
+  nginx:
+    command: "nginx"
+    forking: true
+    pidfile: "run/nginx.pid"
+"#,
+        )
+        .expect("forking with a pidfile parses");
+        let nginx = &config.services["nginx"];
+        assert!(nginx.is_forking());
+        assert_eq!(
+            nginx.pidfile_path(Path::new("/srv/app")),
+            Some(PathBuf::from("/srv/app/run/nginx.pid"))
+        );
     }
 
     #[test]
-    fn load_config_accepts_project_shorthand() {
-        let dir = tempdir().expect("tempdir");
+    fn backoff_accepts_minutes_and_rejects_garbage() {
+        let dir = tempdir().unwrap();
         let yaml_path = dir.path().join("systemg.yaml");
         fs::write(
             &yaml_path,
             r#"
 version: "2"
-project: arbitration
 services:
   api:
-    command: "echo ok"
+    command: "serve"
+    backoff: "2m"
 "#,
         )
-        .expect("write config");
-
+        .unwrap();
         let config = load_config(Some(yaml_path.to_str().unwrap())).unwrap();
+        assert_eq!(
+            config.services["api"].restart_backoff().unwrap(),
+            Some(Duration::from_secs(120))
+        );
 
-        assert_eq!(config.project.id, "arbitration");
-        assert_eq!(config.project.name, "arbitration");
-    }
-
-    #[test]
-    fn load_config_maps_missing_project_to_loose_bundle() {
-        let dir = tempdir().expect("tempdir");
-        let yaml_path = dir.path().join("systemg.yaml");
         fs::write(
             &yaml_path,
             r#"
 version: "2"
 services:
   api:
-    command: "echo ok"
+    command: "serve"
+    backoff: "soon"
+"#,
+        )
+        .unwrap();
+        let err = load_config(Some(yaml_path.to_str().unwrap())).unwrap_err();
+        assert!(err.to_string().contains("invalid backoff 'soon'"));
+        assert!(err.to_string().contains("<n>[ms|s|m|h]"), "{err}");
+    }
+
+    #[test]
+    fn cron_history_bounds_are_checked_at_load() {
+        let dir = tempdir().unwrap();
+        let yaml_path = dir.path().join("systemg.yaml");
+        let write = |history: &str| {
+            fs::write(
+                &yaml_path,
+                format!(
+                    "version: \"2\"\nservices:\n  job:\n    command: \"run\"\n    cron:\n      expression: \"0 * * * * *\"\n{history}"
+                ),
+            )
+            .unwrap();
+            load_config(Some(yaml_path.to_str().unwrap()))
+        };
+
+        let config =
+            write("      history_limit: 50\n      history_retention: \"72h\"\n").unwrap();
+        let cron = config.services["job"].cron.as_ref().unwrap();
+        assert_eq!(cron.history_limit, Some(50));
+        assert_eq!(
+            cron.history_retention().unwrap(),
+            Some(Duration::from_secs(72 * 3600))
+        );
+
+        let err = write("      history_limit: 0\n").unwrap_err();
+        assert!(err.to_string().contains("invalid cron history_limit"));
+        let err = write("      history_retention: \"3 days\"\n").unwrap_err();
+        assert!(err.to_string().contains("invalid cron history_retention"));
+    }
+
+    #[test]
+    fn dependency_settle_is_checked_at_load() {
+        let dir = tempdir().unwrap();
+        let yaml_path = dir.path().join("systemg.yaml");
+        let write = |settle: &str| {
+            fs::write(
+                &yaml_path,
+                format!(
+                    "version: \"2\"\n{settle}services:\n  api:\n    command: \"serve\"\n"
+                ),
+            )
+            .unwrap();
+            load_config(Some(yaml_path.to_str().unwrap()))
+        };
+
+        assert_eq!(
+            write("").unwrap().dependency_settle().unwrap(),
+            Duration::ZERO
+        );
+        let config = write("dependency_settle: \"1500ms\"\n").unwrap();
+        assert_eq!(
+            config.dependency_settle().unwrap(),
+            Duration::from_millis(1500)
+        );
+
+        let err = write("dependency_settle: \"a bit\"\n").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("invalid dependency_settle 'a bit'")
+        );
+    }
+
+    #[test]
+    fn argv_command_round_trips_through_split_command() {
+        let dir = tempdir().unwrap();
+        let yaml_path = dir.path().join("systemg.yaml");
+        fs::write(
+            &yaml_path,
+            r#"
+version: "2"
+services:
+  script:
+    command: ["python3", "-c", "print(\"it's here\")", ""]
+    shell: none
+  bashy:
+    command: "echo ok"
+    shell: sh
+"#,
+        )
+        .unwrap();
+        let config = load_config(Some(yaml_path.to_str().unwrap())).unwrap();
+        assert_eq!(
+            config.services["script"].command_launcher().unwrap(),
+            CommandLauncher::Direct(vec![
+                "python3".into(),
+                "-c".into(),
+                "print(\"it's here\")".into(),
+                "".into(),
+            ])
+        );
+        assert_eq!(
+            config.services["bashy"].command_launcher().unwrap(),
+            CommandLauncher::Shell("sh".into())
+        );
+        assert_eq!(
+            split_command(r#"run "a \"b\"" c\ d 'e f'"#).unwrap(),
+            vec!["run", "a \"b\"", "c d", "e f"]
+        );
+        assert!(split_command("run 'open").is_err());
+    }
+
+    #[test]
+    fn shell_must_exist_and_none_needs_a_splittable_command() {
+        let dir = tempdir().unwrap();
+        let yaml_path = dir.path().join("systemg.yaml");
+        let write = |service: &str| {
+            fs::write(
+                &yaml_path,
+                format!("version: \"2\"\nservices:\n  api:\n{service}"),
+            )
+            .unwrap();
+            load_config(Some(yaml_path.to_str().unwrap()))
+        };
+
+        assert!(write("    command: \"serve\"\n    shell: /bin/sh\n").is_ok());
+        let err =
+            write("    command: \"serve\"\n    shell: /no/such/shell\n").unwrap_err();
+        assert!(err.to_string().contains("invalid shell '/no/such/shell'"));
+        let err = write("    command: \"serve 'open\"\n    shell: none\n").unwrap_err();
+        assert!(err.to_string().contains("unterminated single quote"));
+    }
+
+    #[test]
+    fn ready_log_pattern_must_be_a_valid_regex() {
+        let dir = tempdir().unwrap();
+        let yaml_path = dir.path().join("systemg.yaml");
+        let write = |pattern: &str| {
+            fs::write(
+                &yaml_path,
+                format!(
+                    "version: \"2\"\nservices:\n  api:\n    command: \"serve\"\n    ready_log_pattern: '{pattern}'\n"
+                ),
+            )
+            .unwrap();
+            load_config(Some(yaml_path.to_str().unwrap()))
+        };
+
+        let config = write(r"Listening on :\d+").unwrap();
+        assert_eq!(
+            config.services["api"].ready_log_pattern.as_deref(),
+            Some(r"Listening on :\d+")
+        );
+        let err = write("Listening (on").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("invalid ready_log_pattern 'Listening (on'")
+        );
+    }
+
+    #[test]
+    fn umask_accepts_octal_and_rejects_the_rest() {
+        let dir = tempdir().unwrap();
+        let yaml_path = dir.path().join("systemg.yaml");
+        fs::write(
+            &yaml_path,
+            r#"
+version: "2"
+services:
+  api:
+    command: "serve"
+    umask: "0027"
+"#,
+        )
+        .unwrap();
+        let config = load_config(Some(yaml_path.to_str().unwrap())).unwrap();
+        assert_eq!(config.services["api"].umask_mode().unwrap(), Some(0o027));
+
+        for bad in ["0089", "1777", ""] {
+            fs::write(
+                &yaml_path,
+                format!(
+                    "version: \"2\"\nservices:\n  api:\n    command: \"serve\"\n    umask: \"{bad}\"\n"
+                ),
+            )
+            .unwrap();
+            let err = load_config(Some(yaml_path.to_str().unwrap())).unwrap_err();
+            assert!(err.to_string().contains(&format!("invalid umask '{bad}'")));
+        }
+    }
+
+    #[test]
+    fn ports_declared_by_two_services_are_rejected() {
+        let dir = tempdir().unwrap();
+        let yaml_path = dir.path().join("systemg.yaml");
+        fs::write(
+            &yaml_path,
+            r#"
+version: "2"
+services:
+  api:
+    command: "serve"
+    ports: [8080, 9090]
+  admin:
+    command: "serve"
+    ports: [8081]
+"#,
+        )
+        .unwrap();
+        let config = load_config(Some(yaml_path.to_str().unwrap())).unwrap();
+        assert_eq!(config.services["api"].ports, Some(vec![8080, 9090]));
+
+        fs::write(
+            &yaml_path,
+            r#"
+version: "2"
+services:
+  api:
+    command: "serve"
+    ports: [8080, 9090]
+  admin:
+    command: "serve"
+    ports: [9090]
+"#,
+        )
+        .unwrap();
+        let err = load_config(Some(yaml_path.to_str().unwrap())).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("services 'admin' and 'api' both declare port 9090"),
+            "{err}"
+        );
+
+        fs::write(
+            &yaml_path,
+            "version: \"2\"\nservices:\n  api:\n    command: \"serve\"\n    ports: [0]\n",
+        )
+        .unwrap();
+        let err = load_config(Some(yaml_path.to_str().unwrap())).unwrap_err();
+        assert!(err.to_string().contains("declares invalid port 0"), "{err}");
+    }
+
+    #[test]
+    fn duplicate_service_names_are_rejected() {
+        let dir = tempdir().unwrap();
+        let yaml_path = dir.path().join("systemg.yaml");
+        fs::write(
+            &yaml_path,
+            r#"
+version: "2"
+services:
+  api:
+    command: "serve one"
+  api:
+    command: "serve two"
+"#,
+        )
+        .unwrap();
+
+        let err = load_config(Some(yaml_path.to_str().unwrap())).unwrap_err();
+        assert!(matches!(err, ProcessManagerError::ConfigParseError(_)));
+        assert!(err.to_string().contains("duplicate service `api`"));
+
+        let config = parse_config_manifest(
+            r#"
+version: "2"
+services:
+  api:
+    command: "serve"
+  API:
+    command: "serve"
+  worker:
+    command: "work"
+"#,
+        )
+        .expect("names differing only by case are distinct services");
+        assert_eq!(
+            case_insensitive_collisions(&config.services),
+            vec![("API".to_string(), "api".to_string())]
+        );
+    }
+
+    #[test]
+    fn manifest_schema_describes_service_fields() {
+        let schema = manifest_schema();
+        assert_eq!(schema["required"], serde_json::json!(["version"]));
+
+        let service = &schema["$defs"]["ServiceConfig"];
+        assert_eq!(service["required"], serde_json::json!(["command"]));
+        let properties = service["properties"].as_object().unwrap();
+        assert!(properties.contains_key("restart_if_rss_exceeds"));
+        assert!(!properties.contains_key("project_scope"));
+        assert_eq!(
+            properties["restart_policy"]["enum"],
+            serde_json::json!(["always", "on-failure", "never", null])
+        );
+
+        let health_check = &schema["$defs"]["HealthCheckConfig"];
+        assert_eq!(health_check["additionalProperties"], false);
+        assert!(health_check["properties"]["timeout"].is_object());
+        assert!(schema["$defs"]["EnvConfig"]["additionalProperties"].is_object());
+    }
+
+    #[test]
+    fn template_instance_colliding_with_explicit_service_is_rejected() {
+        let err = parse_config_manifest(
+            r#"
+version: "2"
+services:
+  web@:
+    command: "./server"
+    instances: 2
+  web@2:
+    command: "./other"
+"#,
+        )
+        .expect_err("duplicate instance must be rejected");
+        assert!(err.to_string().contains("web@2"));
+    }
+
+    #[test]
+    fn load_config_expands_placeholders_in_commands() {
+        let dir = tempdir().unwrap();
+        let yaml_path = dir.path().join("systemg.yaml");
+        fs::write(
+            &yaml_path,
+            r#"
+version: "2"
+services:
+  api:
+    command: "serve --name %n"
+"#,
+        )
+        .unwrap();
+
+        let config = load_config(Some(yaml_path.to_str().unwrap())).unwrap();
+        assert_eq!(config.services["api"].command, "serve --name api");
+    }
+
+    #[test]
+    fn parse_manifest_accepts_string_version() {
+        let config = parse_config_manifest(
+            r#"
+version: "2"
+services:
+  api:
+    command: "echo ok"
+"#,
+        )
+        .expect("parse manifest");
+
+        assert_eq!(config.version, Version::V2);
+        assert_eq!(config.services["api"].command, "echo ok");
+    }
+
+    #[test]
+    fn parse_manifest_accepts_integer_version() {
+        let config = parse_config_manifest(
+            r#"
+version: 2
+services:
+  api:
+    command: "echo ok"
+"#,
+        )
+        .expect("parse manifest");
+
+        assert_eq!(config.version, Version::V2);
+        assert_eq!(config.services["api"].command, "echo ok");
+    }
+
+    #[test]
+    fn load_config_accepts_project_object() {
+        let dir = tempdir().expect("tempdir");
+        let yaml_path = dir.path().join("systemg.yaml");
+        fs::write(
+            &yaml_path,
+            r#"
+version: "2"
+project:
+  id: arbitration
+  name: Arbitration
+services:
+  api:
+    command: "echo ok"
+"#,
+        )
+        .expect("write config");
+
+        let config = load_config(Some(yaml_path.to_str().unwrap())).unwrap();
+
+        assert_eq!(config.project.id, "arbitration");
+        assert_eq!(config.project.name, "Arbitration");
+    }
+
+    #[test]
+    fn load_config_accepts_project_shorthand() {
+        let dir = tempdir().expect("tempdir");
+        let yaml_path = dir.path().join("systemg.yaml");
+        fs::write(
+            &yaml_path,
+            r#"
+version: "2"
+project: arbitration
+services:
+  api:
+    command: "echo ok"
+"#,
+        )
+        .expect("write config");
+
+        let config = load_config(Some(yaml_path.to_str().unwrap())).unwrap();
+
+        assert_eq!(config.project.id, "arbitration");
+        assert_eq!(config.project.name, "arbitration");
+    }
+
+    #[test]
+    fn load_config_maps_missing_project_to_loose_bundle() {
+        let dir = tempdir().expect("tempdir");
+        let yaml_path = dir.path().join("systemg.yaml");
+        fs::write(
+            &yaml_path,
+            r#"
+version: "2"
+services:
+  api:
+    command: "echo ok"
 "#,
         )
         .expect("write config");
@@ -2124,7 +4131,15 @@ services:
             status: StatusConfig {
                 snapshot_mode: StatusSnapshotMode::Detailed,
                 snapshot_interval_secs: 15,
+                health: HealthPolicy::default(),
             },
+            deployment: ProjectDeploymentConfig::default(),
+            defaults: ServiceDefaults::default(),
+            on_ready: None,
+            on_start_failure: None,
+            on_failure: Default::default(),
+            dependency_settle: None,
+            supervisor: Default::default(),
         })
         .expect("migrate v1 config");
 
@@ -2229,9 +4244,31 @@ services:
             limits: None,
             capabilities: None,
             isolation: None,
+            umask: None,
+            priority: None,
+            after: None,
+            before: None,
+            critical: None,
+            interactive: None,
+            pty: None,
+            shell: None,
+            ready_log_pattern: None,
+            start_timeout: None,
+            stop_timeout: None,
             restart_policy: None,
             backoff: None,
             max_restarts: None,
+            start_retries: None,
+            start_retry_delay: None,
+            ready_stable_for: None,
+            instances: None,
+            forking: None,
+            pidfile: None,
+            sockets: None,
+            ports: None,
+            restart_if_rss_exceeds: None,
+            rss_window: None,
+            condition: None,
             depends_on: depends_on
                 .map(|deps| deps.into_iter().map(DependsOn::from).collect()),
             deployment: None,
@@ -2240,38 +4277,184 @@ services:
             skip: None,
             spawn: None,
             logs: None,
+            health: None,
             project_scope: None,
         }
     }
 
     #[test]
-    /// Verifies dependency ordering remains stable across a simple chain.
-    fn service_start_order_resolves_dependencies() {
-        let mut services = HashMap::new();
-        services.insert("a".into(), minimal_service(None));
-        services.insert("b".into(), minimal_service(Some(vec!["a"])));
-        services.insert("c".into(), minimal_service(Some(vec!["b"])));
+    /// Verifies dependency ordering remains stable across a simple chain.
+    fn service_start_order_resolves_dependencies() {
+        let mut services = HashMap::new();
+        services.insert("a".into(), minimal_service(None));
+        services.insert("b".into(), minimal_service(Some(vec!["a"])));
+        services.insert("c".into(), minimal_service(Some(vec!["b"])));
+
+        let config = Config {
+            version: Version::V2,
+            project: ProjectConfig::default(),
+            services,
+            project_dir: None,
+            env: None,
+            metrics: MetricsConfig::default(),
+            logs: crate::config::LogsConfig::default(),
+            status: crate::config::StatusConfig::default(),
+            deployment: crate::config::ProjectDeploymentConfig::default(),
+            on_ready: None,
+            on_start_failure: None,
+            on_failure: Default::default(),
+            dependency_settle: None,
+            supervisor: Default::default(),
+            env_overrides: Default::default(),
+        };
+
+        let order = config.service_start_order().unwrap();
+        assert_eq!(order, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn service_start_order_breaks_ties_by_priority_then_name() {
+        // Fresh HashMaps hash differently, so repeat to catch iteration-order leaks.
+        for _ in 0..20 {
+            let mut zeta = minimal_service(None);
+            zeta.priority = Some(-1);
+            let mut alpha = minimal_service(None);
+            alpha.priority = Some(5);
+            let mut web = minimal_service(Some(vec!["alpha"]));
+            web.priority = Some(-10);
+
+            let mut services = HashMap::new();
+            services.insert("alpha".into(), alpha);
+            services.insert("zeta".into(), zeta);
+            services.insert("beta".into(), minimal_service(None));
+            services.insert("gamma".into(), minimal_service(None));
+            services.insert("web".into(), web);
+
+            let config = Config {
+                version: Version::V2,
+                project: ProjectConfig::default(),
+                services,
+                project_dir: None,
+                env: None,
+                metrics: MetricsConfig::default(),
+                logs: LogsConfig::default(),
+                status: StatusConfig::default(),
+                deployment: ProjectDeploymentConfig::default(),
+                on_ready: None,
+                on_start_failure: None,
+                on_failure: Default::default(),
+                dependency_settle: None,
+                supervisor: Default::default(),
+                env_overrides: Default::default(),
+            };
+
+            assert_eq!(
+                config.service_start_order().unwrap(),
+                vec!["zeta", "beta", "gamma", "alpha", "web"]
+            );
+        }
+    }
+
+    #[test]
+    fn service_start_levels_group_independent_services() {
+        let mut services = HashMap::new();
+        services.insert("db".into(), minimal_service(None));
+        services.insert("cache".into(), minimal_service(None));
+        services.insert("api".into(), minimal_service(Some(vec!["db", "cache"])));
+        services.insert("worker".into(), minimal_service(Some(vec!["db"])));
+        services.insert("web".into(), minimal_service(Some(vec!["api"])));
+
+        let config = Config {
+            version: Version::V2,
+            project: ProjectConfig::default(),
+            services,
+            project_dir: None,
+            env: None,
+            metrics: MetricsConfig::default(),
+            logs: LogsConfig::default(),
+            status: StatusConfig::default(),
+            deployment: ProjectDeploymentConfig::default(),
+            on_ready: None,
+            on_start_failure: None,
+            on_failure: Default::default(),
+            dependency_settle: None,
+            supervisor: Default::default(),
+            env_overrides: Default::default(),
+        };
+
+        let order = config.service_start_order().unwrap();
+        assert_eq!(
+            config.service_start_levels(&order),
+            vec![vec!["cache", "db"], vec!["api", "worker"], vec!["web"]]
+        );
+        assert_eq!(
+            config.service_start_levels(&["web".to_string()]),
+            vec![vec!["web"]]
+        );
+    }
+
+    #[test]
+    fn condition_path_exists_resolves_against_the_project_root() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("present.flag"), "").unwrap();
+        let condition = ConditionConfig {
+            path_exists: vec!["present.flag".into()],
+            ..ConditionConfig::default()
+        };
+        assert_eq!(condition.unmet(dir.path(), |_| true), None);
+
+        let condition = ConditionConfig {
+            path_exists: vec!["present.flag".into(), "missing.flag".into()],
+            ..ConditionConfig::default()
+        };
+        assert_eq!(
+            condition.unmet(dir.path(), |_| true).as_deref(),
+            Some("path `missing.flag` does not exist")
+        );
+    }
+
+    #[test]
+    fn condition_env_set_consults_the_service_environment() {
+        let condition = ConditionConfig {
+            env_set: vec!["DATABASE_URL".into()],
+            ..ConditionConfig::default()
+        };
+        let root = Path::new("/");
+        assert_eq!(condition.unmet(root, |var| var == "DATABASE_URL"), None);
+        assert_eq!(
+            condition.unmet(root, |_| false).as_deref(),
+            Some("environment variable `DATABASE_URL` is not set")
+        );
+    }
+
+    #[test]
+    fn condition_host_is_matches_os_and_arch() {
+        let root = Path::new("/");
+        let condition: ConditionConfig = serde_yaml::from_str(&format!(
+            "host_is:\n  os: {}\n  arch: {}\n",
+            env::consts::OS.to_uppercase(),
+            env::consts::ARCH
+        ))
+        .unwrap();
+        assert_eq!(condition.unmet(root, |_| true), None);
 
-        let config = Config {
-            version: Version::V2,
-            project: ProjectConfig::default(),
-            services,
-            project_dir: None,
-            env: None,
-            metrics: MetricsConfig::default(),
-            logs: crate::config::LogsConfig::default(),
-            status: crate::config::StatusConfig::default(),
-        };
+        let condition: ConditionConfig =
+            serde_yaml::from_str("host_is:\n  os: plan9\n").unwrap();
+        assert_eq!(
+            condition.unmet(root, |_| true),
+            Some(format!("host os is `{}`, not `plan9`", env::consts::OS))
+        );
 
-        let order = config.service_start_order().unwrap();
-        assert_eq!(order, vec!["a", "b", "c"]);
+        let condition: ConditionConfig =
+            serde_yaml::from_str("host_is:\n  arch: vax\n").unwrap();
+        assert!(condition.unmet(root, |_| true).unwrap().contains("`vax`"));
     }
 
     #[test]
     /// Verifies a clean exit can satisfy `completed` regardless of restart policy.
     fn completed_dependency_accepts_always_policy() {
         let mut build = minimal_service(None);
-        build.restart_policy = Some(RESTART_ALWAYS.to_string());
+        build.restart_policy = Some(RestartPolicy::Always);
         let mut app = minimal_service(None);
         app.depends_on = Some(vec![DependsOn::Detailed {
             service: "build".to_string(),
@@ -2290,6 +4473,13 @@ services:
             metrics: MetricsConfig::default(),
             logs: LogsConfig::default(),
             status: StatusConfig::default(),
+            deployment: ProjectDeploymentConfig::default(),
+            on_ready: None,
+            on_start_failure: None,
+            on_failure: Default::default(),
+            dependency_settle: None,
+            supervisor: Default::default(),
+            env_overrides: Default::default(),
         };
 
         assert_eq!(config.service_start_order().unwrap(), vec!["build", "app"]);
@@ -2309,6 +4499,13 @@ services:
             metrics: MetricsConfig::default(),
             logs: crate::config::LogsConfig::default(),
             status: crate::config::StatusConfig::default(),
+            deployment: crate::config::ProjectDeploymentConfig::default(),
+            on_ready: None,
+            on_start_failure: None,
+            on_failure: Default::default(),
+            dependency_settle: None,
+            supervisor: Default::default(),
+            env_overrides: Default::default(),
         };
 
         match config.service_start_order() {
@@ -2338,6 +4535,13 @@ services:
             metrics: MetricsConfig::default(),
             logs: crate::config::LogsConfig::default(),
             status: crate::config::StatusConfig::default(),
+            deployment: crate::config::ProjectDeploymentConfig::default(),
+            on_ready: None,
+            on_start_failure: None,
+            on_failure: Default::default(),
+            dependency_settle: None,
+            supervisor: Default::default(),
+            env_overrides: Default::default(),
         };
 
         match config.service_start_order() {
@@ -2349,6 +4553,115 @@ services:
         }
     }
 
+    #[test]
+    fn service_start_order_honors_after_and_before_without_dependencies() {
+        let config: Config = serde_yaml::from_str(
+            r#"
+version: "2"
+services:
+  a-web:
+    command: "web"
+    after: ["z-db"]
+  m-cache:
+    command: "cache"
+    before: ["z-db"]
+  z-db:
+    command: "db"
+"#,
+        )
+        .unwrap();
+
+        let order = config.service_start_order().unwrap();
+        assert_eq!(order, vec!["m-cache", "z-db", "a-web"]);
+        assert_eq!(
+            config.service_start_levels(&order),
+            vec![vec!["m-cache"], vec!["z-db"], vec!["a-web"]]
+        );
+        assert!(
+            config.services["a-web"].depends_on.is_none(),
+            "ordering does not add a dependency"
+        );
+    }
+
+    #[test]
+    fn service_start_order_rejects_bad_after_and_before_entries() {
+        let order_of = |manifest: &str| {
+            serde_yaml::from_str::<Config>(manifest)
+                .unwrap()
+                .service_start_order()
+        };
+
+        match order_of(
+            r#"
+version: "2"
+services:
+  api:
+    command: "api"
+    depends_on: ["db"]
+    before: ["db"]
+  db:
+    command: "db"
+"#,
+        ) {
+            Err(ProcessManagerError::OrderingConflict {
+                service,
+                dependency,
+            }) => {
+                assert_eq!(service, "api");
+                assert_eq!(dependency, "db");
+            }
+            other => panic!("expected ordering conflict, got {other:?}"),
+        }
+
+        assert!(matches!(
+            order_of(
+                r#"
+version: "2"
+services:
+  api:
+    command: "api"
+    depends_on: ["db"]
+  db:
+    command: "db"
+    after: ["api"]
+"#,
+            ),
+            Err(ProcessManagerError::OrderingConflict { .. })
+        ));
+
+        match order_of(
+            r#"
+version: "2"
+services:
+  api:
+    command: "api"
+    after: ["missing"]
+"#,
+        ) {
+            Err(ProcessManagerError::UnknownOrderingTarget { service, target }) => {
+                assert_eq!(service, "api");
+                assert_eq!(target, "missing");
+            }
+            other => panic!("expected unknown ordering target, got {other:?}"),
+        }
+
+        assert!(matches!(
+            order_of(
+                r#"
+version: "2"
+services:
+  a:
+    command: "a"
+    after: ["b"]
+  b:
+    command: "b"
+    after: ["a"]
+"#,
+            ),
+            Err(ProcessManagerError::DependencyCycle { .. })
+        ));
+    }
+
     #[test]
     fn logs_config_defaults_to_file_with_rotation() {
         let config: Config = serde_yaml::from_str(
@@ -2411,6 +4724,212 @@ services:
         assert!(err.to_string().contains("journald"));
     }
 
+    #[test]
+    fn restart_policy_parses_to_enum_and_rejects_typos() {
+        let config = parse_config_manifest(
+            r#"
+version: "2"
+services:
+  api:
+    command: "echo ok"
+    restart_policy: on-failure
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.services["api"].restart_policy,
+            Some(RestartPolicy::OnFailure)
+        );
+
+        let err = parse_config_manifest(
+            r#"
+version: "2"
+services:
+  api:
+    command: "echo ok"
+    restart_policy: alway
+"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("alway"), "{err}");
+    }
+
+    #[test]
+    fn services_inherit_defaults_unless_they_override_them() {
+        let config = parse_config_manifest(
+            r#"
+version: "2"
+defaults:
+  restart_policy: on-failure
+  backoff: "10s"
+  max_restarts: 3
+  stop_timeout: "30s"
+services:
+  api:
+    command: "echo ok"
+  worker:
+    command: "echo ok"
+    restart_policy: never
+    stop_timeout: "2s"
+"#,
+        )
+        .unwrap();
+
+        let api = &config.services["api"];
+        assert_eq!(api.restart_policy, Some(RestartPolicy::OnFailure));
+        assert_eq!(api.backoff.as_deref(), Some("10s"));
+        assert_eq!(api.max_restarts, Some(3));
+        assert_eq!(config.stop_grace("api"), Duration::from_secs(30));
+
+        let worker = &config.services["worker"];
+        assert_eq!(worker.restart_policy, Some(RestartPolicy::Never));
+        assert_eq!(worker.backoff.as_deref(), Some("10s"));
+        assert_eq!(config.stop_grace("worker"), Duration::from_secs(2));
+        assert_eq!(
+            config.stop_grace("missing"),
+            crate::constants::STOP_GRACE_PERIOD
+        );
+
+        let dir = tempdir().unwrap();
+        let yaml_path = dir.path().join("systemg.yaml");
+        fs::write(
+            &yaml_path,
+            "version: \"2\"\ndefaults:\n  stop_timeout: \"soon\"\nservices:\n  api:\n    command: \"echo ok\"\n",
+        )
+        .unwrap();
+        let err = load_config(Some(yaml_path.to_str().unwrap())).unwrap_err();
+        assert!(err.to_string().contains("invalid stop_timeout"), "{err}");
+    }
+
+    #[test]
+    fn start_retry_delay_is_checked_at_load() {
+        let dir = tempdir().unwrap();
+        let yaml_path = dir.path().join("systemg.yaml");
+        fs::write(
+            &yaml_path,
+            "version: \"2\"\nservices:\n  api:\n    command: \"echo ok\"\n    start_retries: 2\n    start_retry_delay: \"abc\"\n",
+        )
+        .unwrap();
+        let err = load_config(Some(yaml_path.to_str().unwrap())).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("service 'api' has an invalid start_retry_delay 'abc'"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn ready_stable_for_is_checked_at_load() {
+        let dir = tempdir().unwrap();
+        let yaml_path = dir.path().join("systemg.yaml");
+        fs::write(
+            &yaml_path,
+            "version: \"2\"\nservices:\n  api:\n    command: \"echo ok\"\n    ready_stable_for: \"half a second\"\n",
+        )
+        .unwrap();
+        let err = load_config(Some(yaml_path.to_str().unwrap())).unwrap_err();
+        assert!(
+            err.to_string().contains(
+                "service 'api' has an invalid ready_stable_for 'half a second'"
+            ),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn start_timeout_is_checked_at_load() {
+        let dir = tempdir().unwrap();
+        let yaml_path = dir.path().join("systemg.yaml");
+        fs::write(
+            &yaml_path,
+            "version: \"2\"\nservices:\n  api:\n    command: \"echo ok\"\n    start_timeout: \"30 seconds\"\n",
+        )
+        .unwrap();
+        let err = load_config(Some(yaml_path.to_str().unwrap())).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("service 'api' has an invalid start_timeout '30 seconds'"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn rss_limits_are_checked_at_load() {
+        let dir = tempdir().unwrap();
+        let yaml_path = dir.path().join("systemg.yaml");
+        for (fields, expected) in [
+            (
+                "restart_if_rss_exceeds: \"512M\"\n    rss_window: \"soon\"",
+                "service 'api' has an invalid rss_window 'soon'",
+            ),
+            (
+                "restart_if_rss_exceeds: unlimited",
+                "service 'api' has an invalid restart_if_rss_exceeds 'unlimited'",
+            ),
+            (
+                "restart_if_rss_exceeds: 0",
+                "service 'api' has an invalid restart_if_rss_exceeds '0'",
+            ),
+        ] {
+            fs::write(
+                &yaml_path,
+                format!(
+                    "version: \"2\"\nservices:\n  api:\n    command: \"echo ok\"\n    {fields}\n"
+                ),
+            )
+            .unwrap();
+            let err = load_config(Some(yaml_path.to_str().unwrap())).unwrap_err();
+            assert!(err.to_string().contains(expected), "{err}");
+        }
+    }
+
+    #[test]
+    fn service_health_policy_overrides_the_status_default() {
+        let config = parse_config_manifest(
+            r#"
+version: "2"
+status:
+  health:
+    missing_process: failing
+    cron_failure: degraded
+services:
+  api:
+    command: "echo ok"
+  worker:
+    command: "echo ok"
+    health:
+      missing_process: warn
+"#,
+        )
+        .unwrap();
+
+        let global = config.status.health;
+        assert_eq!(global.missing_process, Some(UnitHealth::Failing));
+        assert_eq!(global.cron_failure, Some(UnitHealth::Warn));
+        assert_eq!(
+            global.overlaid(config.services["api"].health.as_ref()),
+            global
+        );
+
+        let worker = global.overlaid(config.services["worker"].health.as_ref());
+        assert_eq!(worker.missing_process, Some(UnitHealth::Warn));
+        assert_eq!(worker.cron_failure, Some(UnitHealth::Warn));
+
+        let err = parse_config_manifest(
+            r#"
+version: "2"
+status:
+  health:
+    missing: failing
+services:
+  api:
+    command: "echo ok"
+"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("missing"), "{err}");
+    }
+
     #[test]
     fn status_config_defaults_to_summary_snapshots() {
         let config: Config = serde_yaml::from_str(
@@ -2617,6 +5136,59 @@ services:
         }
     }
 
+    #[test]
+    fn compute_hash_ignores_manifest_declaration_order() {
+        let hash_of = |manifest: &str| {
+            parse_config_manifest(manifest).unwrap().services["web"].compute_hash()
+        };
+        let forward = hash_of(
+            r#"
+version: "2"
+services:
+  web:
+    command: "run"
+    env:
+      vars:
+        ALPHA: "1"
+        BRAVO: "2"
+        CHARLIE: "3"
+    restart_policy: always
+    hooks:
+      on_start:
+        success:
+          command: "echo up"
+    deployment:
+      health_check:
+        command: "true"
+"#,
+        );
+        let reversed = hash_of(
+            r#"
+version: "2"
+services:
+  web:
+    deployment:
+      health_check:
+        command: "true"
+    hooks:
+      on_start:
+        success:
+          command: "echo up"
+    restart_policy: always
+    env:
+      vars:
+        CHARLIE: "3"
+        BRAVO: "2"
+        ALPHA: "1"
+    command: "run"
+"#,
+        );
+        assert_eq!(forward, reversed);
+        // Pinned: the hash is persisted as each unit's `config_hash`, so the
+        // canonical form must not change between builds.
+        assert_eq!(forward, "efbd8f32afb09b24");
+    }
+
     #[test]
     fn compute_hash_still_changes_on_a_real_change() {
         let base = ServiceConfig {
@@ -2637,6 +5209,23 @@ services:
         assert_ne!(base.compute_hash(), env_added.compute_hash());
     }
 
+    #[test]
+    fn field_hashes_isolate_the_changed_field() {
+        let base = ServiceConfig {
+            command: "run".into(),
+            backoff: Some("5s".into()),
+            ..Default::default()
+        };
+        let mut changed = base.clone();
+        changed.backoff = Some("10s".into());
+
+        let before = base.field_hashes();
+        let after = changed.field_hashes();
+        assert_eq!(before["command"], after["command"]);
+        assert_ne!(before["backoff"], after["backoff"]);
+        assert!(!before.contains_key("restart_policy"));
+    }
+
     #[test]
     fn inherit_env_parses_and_defaults_to_none() {
         let env: EnvConfig = serde_yaml::from_str("inherit_env: true\n").unwrap();
@@ -2878,6 +5467,7 @@ services:
         assert!(vars2.get("SERVICE_ONLY").is_none());
     }
 
+    #[cfg(feature = "http-health")]
     #[test]
     fn load_config_parses_blue_green_deployment_block() {
         let dir = tempdir().expect("tempdir");
@@ -2973,6 +5563,37 @@ services:
         );
     }
 
+    #[cfg(not(feature = "http-health"))]
+    #[test]
+    /// Verifies a `url` probe names the missing feature when HTTP is compiled out.
+    fn load_config_rejects_url_health_check_without_http_health() {
+        let dir = tempdir().expect("tempdir");
+        let yaml_path = dir.path().join("systemg.yaml");
+        let mut yaml_file = File::create(&yaml_path).expect("create yaml");
+        writeln!(
+            yaml_file,
+            r#"
+version: "2"
+services:
+  web:
+    command: "python app.py"
+    deployment:
+      health_check:
+        url: "http://localhost:8000/health"
+"#
+        )
+        .expect("write yaml");
+
+        let err = load_config(Some(yaml_path.to_str().expect("yaml path")))
+            .expect_err("url health check should need http-health");
+
+        assert!(
+            err.to_string().contains("`http-health` feature"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[cfg(feature = "http-health")]
     #[test]
     /// Verifies the early-v2 `timeout` spelling maps to the total readiness budget.
     fn load_config_accepts_health_timeout_alias() {
@@ -3048,19 +5669,44 @@ services:
             limits: None,
             capabilities: None,
             isolation: None,
-            restart_policy: Some("always".to_string()),
+            umask: None,
+            priority: None,
+            after: None,
+            before: None,
+            critical: None,
+            interactive: None,
+            pty: None,
+            shell: None,
+            ready_log_pattern: None,
+            start_timeout: None,
+            stop_timeout: None,
+            restart_policy: Some(RestartPolicy::Always),
             backoff: Some("5s".to_string()),
             max_restarts: Some(3),
+            start_retries: None,
+            start_retry_delay: None,
+            ready_stable_for: None,
+            instances: None,
+            forking: None,
+            pidfile: None,
+            sockets: None,
+            ports: None,
+            restart_if_rss_exceeds: None,
+            rss_window: None,
+            condition: None,
             depends_on: None,
             deployment: None,
             hooks: None,
             cron: Some(CronConfig {
                 expression: "0 * * * * *".to_string(),
                 timezone: Some("UTC".to_string()),
+                history_limit: None,
+                history_retention: None,
             }),
             skip: None,
             spawn: None,
             logs: None,
+            health: None,
             project_scope: None,
         };
 
@@ -3073,19 +5719,44 @@ services:
             limits: None,
             capabilities: None,
             isolation: None,
-            restart_policy: Some("always".to_string()),
+            umask: None,
+            priority: None,
+            after: None,
+            before: None,
+            critical: None,
+            interactive: None,
+            pty: None,
+            shell: None,
+            ready_log_pattern: None,
+            start_timeout: None,
+            stop_timeout: None,
+            restart_policy: Some(RestartPolicy::Always),
             backoff: Some("5s".to_string()),
             max_restarts: Some(3),
+            start_retries: None,
+            start_retry_delay: None,
+            ready_stable_for: None,
+            instances: None,
+            forking: None,
+            pidfile: None,
+            sockets: None,
+            ports: None,
+            restart_if_rss_exceeds: None,
+            rss_window: None,
+            condition: None,
             depends_on: None,
             deployment: None,
             hooks: None,
             cron: Some(CronConfig {
                 expression: "0 * * * * *".to_string(),
                 timezone: Some("UTC".to_string()),
+                history_limit: None,
+                history_retention: None,
             }),
             skip: None,
             spawn: None,
             logs: None,
+            health: None,
             project_scope: None,
         };
 
@@ -3110,9 +5781,31 @@ services:
             limits: None,
             capabilities: None,
             isolation: None,
+            umask: None,
+            priority: None,
+            after: None,
+            before: None,
+            critical: None,
+            interactive: None,
+            pty: None,
+            shell: None,
+            ready_log_pattern: None,
+            start_timeout: None,
+            stop_timeout: None,
             restart_policy: None,
             backoff: None,
             max_restarts: None,
+            start_retries: None,
+            start_retry_delay: None,
+            ready_stable_for: None,
+            instances: None,
+            forking: None,
+            pidfile: None,
+            sockets: None,
+            ports: None,
+            restart_if_rss_exceeds: None,
+            rss_window: None,
+            condition: None,
             depends_on: None,
             deployment: None,
             hooks: None,
@@ -3120,6 +5813,7 @@ services:
             skip: None,
             spawn: None,
             logs: None,
+            health: None,
             project_scope: None,
         };
 
@@ -3132,6 +5826,8 @@ services:
             cron: Some(CronConfig {
                 expression: "*/5 * * * * *".to_string(),
                 timezone: None,
+                history_limit: None,
+                history_retention: None,
             }),
             ..base_config.clone()
         };
@@ -3174,19 +5870,44 @@ services:
             limits: None,
             capabilities: None,
             isolation: None,
-            restart_policy: Some("always".to_string()),
+            umask: None,
+            priority: None,
+            after: None,
+            before: None,
+            critical: None,
+            interactive: None,
+            pty: None,
+            shell: None,
+            ready_log_pattern: None,
+            start_timeout: None,
+            stop_timeout: None,
+            restart_policy: Some(RestartPolicy::Always),
             backoff: None,
             max_restarts: None,
+            start_retries: None,
+            start_retry_delay: None,
+            ready_stable_for: None,
+            instances: None,
+            forking: None,
+            pidfile: None,
+            sockets: None,
+            ports: None,
+            restart_if_rss_exceeds: None,
+            rss_window: None,
+            condition: None,
             depends_on: None,
             deployment: None,
             hooks: None,
             cron: Some(CronConfig {
                 expression: "0 * * * * *".to_string(),
                 timezone: Some("UTC".to_string()),
+                history_limit: None,
+                history_retention: None,
             }),
             skip: None,
             spawn: None,
             logs: None,
+            health: None,
             project_scope: None,
         };
         let hash = config.compute_hash();
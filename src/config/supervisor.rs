@@ -25,6 +25,10 @@ fn default_start_settle_secs() -> u64 {
     START_SETTLE_GRACE.as_secs()
 }
 
+fn default_reader_threads() -> usize {
+    crate::logs::DEFAULT_LOG_READER_THREADS
+}
+
 /// File name of the supervisor config in the state directory.
 pub const SUPERVISOR_CONFIG_FILE: &str = "supervisor.xml";
 
@@ -35,6 +39,10 @@ pub struct SupervisorLogDefaults {
     pub max_bytes: u64,
     /// Number of rotated files retained per service log.
     pub max_files: usize,
+    /// Maximum threads reading service stdout/stderr pipes, and separately
+    /// writing service log files, each pool shared by every managed service.
+    #[serde(default = "default_reader_threads")]
+    pub reader_threads: usize,
 }
 
 impl Default for SupervisorLogDefaults {
@@ -42,6 +50,7 @@ impl Default for SupervisorLogDefaults {
         Self {
             max_bytes: LOGS_DEFAULT_MAX_BYTES,
             max_files: LOGS_DEFAULT_MAX_FILES,
+            reader_threads: default_reader_threads(),
         }
     }
 }
@@ -191,6 +200,7 @@ mod tests {
             logs: SupervisorLogDefaults {
                 max_bytes: 42,
                 max_files: 7,
+                reader_threads: 2,
             },
             timeouts: SupervisorTimeouts {
                 pre_start_secs: 8,
@@ -203,6 +213,7 @@ mod tests {
         let back: SupervisorConfig = xml_from_str(&output).unwrap();
         assert_eq!(back.logs.max_bytes, 42);
         assert_eq!(back.logs.max_files, 7);
+        assert_eq!(back.logs.reader_threads, 2);
         assert_eq!(back.timeouts.pre_start_secs, 8);
         assert_eq!(back.timeouts.startup_stability_ms, 90);
         assert_eq!(back.timeouts.stop_verify_secs, 10);
@@ -219,5 +230,9 @@ mod tests {
         assert_eq!(config.timeouts.pre_start_timeout(), PRE_START_TIMEOUT);
         assert_eq!(config.timeouts.startup_stability(), SERVICE_START_STABILITY);
         assert_eq!(config.timeouts.stop_verify_timeout(), STOP_VERIFY_TIMEOUT);
+        assert_eq!(
+            config.logs.reader_threads,
+            crate::logs::DEFAULT_LOG_READER_THREADS
+        );
     }
 }
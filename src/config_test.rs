@@ -0,0 +1,456 @@
+//! Dry runs of one service's skip condition and health check.
+//!
+//! `sysg config test` evaluates the checks the supervisor would run before and
+//! after starting a service — `skip`, `condition`, and `deployment.health_check`
+//! — exactly once, with the service's shell, working directory, and
+//! environment. Output is captured and each decision is reported instead of
+//! acted on: nothing is started, stopped, or recorded. Rendering is left to the
+//! caller so it can respect color and output-format flags.
+
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "http-health")]
+use reqwest::blocking::Client;
+use serde::Serialize;
+
+use crate::{
+    config::{HealthCheckConfig, ServiceConfig, SkipConfig},
+    constants::{DEFAULT_HEALTH_ATTEMPT_TIMEOUT, PRE_START_TIMEOUT},
+    daemon::{Daemon, command_timeout},
+    diag::{Diagnostic, SgCode},
+    error::ProcessManagerError,
+};
+
+/// Which of the service's checks a result belongs to.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckKind {
+    /// The `skip` flag or command.
+    Skip,
+    /// The declarative `condition` preconditions.
+    Condition,
+    /// The `deployment.health_check` probe, or a `--url` override.
+    Health,
+}
+
+/// What the supervisor would do with a check's result.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Decision {
+    /// The service would be skipped.
+    Skip,
+    /// The service would be started.
+    Start,
+    /// The probe passed.
+    Healthy,
+    /// The probe ran, or tried to, and did not pass; a boot would retry it.
+    Unhealthy,
+    /// The check could not be evaluated, which fails the service's start.
+    Error,
+}
+
+/// The outcome of evaluating one check.
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckReport {
+    /// Which check this is.
+    pub kind: CheckKind,
+    /// The command, URL, or setting that was evaluated.
+    pub target: String,
+    /// Exit code of a command check; `None` when it was killed by a signal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+    /// HTTP status returned to a URL probe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_status: Option<u16>,
+    /// Captured standard output of a command check.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub stdout: String,
+    /// Captured standard error of a command check.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub stderr: String,
+    /// Why the decision was reached, when it is not evident from the exit code
+    /// or HTTP status (an unmet condition, a timeout, a refused connection).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// Wall-clock time the check took, in milliseconds.
+    pub elapsed_ms: u64,
+    /// The resulting decision.
+    pub decision: Decision,
+}
+
+impl CheckReport {
+    fn new(kind: CheckKind, target: impl Into<String>, decision: Decision) -> Self {
+        Self {
+            kind,
+            target: target.into(),
+            exit_code: None,
+            http_status: None,
+            stdout: String::new(),
+            stderr: String::new(),
+            detail: None,
+            elapsed_ms: 0,
+            decision,
+        }
+    }
+}
+
+/// Every check evaluated for one service.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigTestReport {
+    /// Project the service belongs to.
+    pub project: String,
+    /// The service that was tested.
+    pub service: String,
+    /// PID of the service's running instance, when one is alive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub running_pid: Option<u32>,
+    /// Checks in the order the supervisor evaluates them.
+    pub checks: Vec<CheckReport>,
+}
+
+impl ConfigTestReport {
+    /// Whether every check could be evaluated and no health probe failed.
+    pub fn passed(&self) -> bool {
+        self.checks
+            .iter()
+            .all(|check| !matches!(check.decision, Decision::Unhealthy | Decision::Error))
+    }
+}
+
+/// Evaluates `service`'s skip condition, `condition` gates, and health check.
+///
+/// `url` replaces the configured health-check target with an HTTP probe, which
+/// also lets a service without a health check be probed.
+pub fn run(
+    daemon: &Daemon,
+    service: &str,
+    url: Option<&str>,
+) -> Result<ConfigTestReport, ProcessManagerError> {
+    let config = daemon.config();
+    let service_config = config
+        .services
+        .get(service)
+        .ok_or_else(|| ProcessManagerError::Diag(Box::new(service_not_found(service))))?;
+
+    let mut checks = Vec::new();
+    if let Some(skip) = &service_config.skip {
+        checks.push(skip_check(daemon, service, skip));
+    }
+    if service_config.condition.is_some() {
+        checks.push(condition_check(daemon, service, service_config));
+    }
+    let health_check = service_config
+        .deployment
+        .as_ref()
+        .and_then(|deployment| deployment.health_check.as_ref());
+    if let Some(check) = health_probe(daemon, service, health_check, url)? {
+        checks.push(check);
+    }
+
+    Ok(ConfigTestReport {
+        project: config.project.id.clone(),
+        service: service.to_string(),
+        running_pid: daemon.running_pid(service),
+        checks,
+    })
+}
+
+/// Builds the SG0202 diagnostic for a service the manifest does not declare.
+fn service_not_found(service: &str) -> Diagnostic {
+    Diagnostic::error(
+        SgCode::TargetNotFound,
+        format!("no service named `{service}` in this configuration"),
+    )
+    .note("config test runs the checks of one service the manifest declares")
+    .help_cmd("check the manifest", "sysg validate")
+    .help_docs()
+}
+
+/// Evaluates a `skip` flag, or runs a `skip` command.
+fn skip_check(daemon: &Daemon, service: &str, skip: &SkipConfig) -> CheckReport {
+    match skip {
+        SkipConfig::Flag(flag) => CheckReport::new(
+            CheckKind::Skip,
+            format!("skip: {flag}"),
+            if *flag {
+                Decision::Skip
+            } else {
+                Decision::Start
+            },
+        ),
+        SkipConfig::Command(command) => {
+            let mut check = command_check(
+                daemon,
+                service,
+                CheckKind::Skip,
+                command,
+                command_timeout(PRE_START_TIMEOUT),
+            );
+            check.decision = match (check.decision, check.exit_code) {
+                (Decision::Error, _) => Decision::Error,
+                (_, Some(0)) => Decision::Skip,
+                _ => Decision::Start,
+            };
+            check
+        }
+    }
+}
+
+/// Evaluates the declarative `condition` preconditions.
+fn condition_check(
+    daemon: &Daemon,
+    service: &str,
+    service_config: &ServiceConfig,
+) -> CheckReport {
+    match daemon.unmet_condition(service, service_config) {
+        Some(reason) => {
+            let mut check =
+                CheckReport::new(CheckKind::Condition, "condition", Decision::Skip);
+            check.detail = Some(reason);
+            check
+        }
+        None => CheckReport::new(CheckKind::Condition, "condition", Decision::Start),
+    }
+}
+
+/// Runs one health probe against the configured target or the `url` override.
+fn health_probe(
+    daemon: &Daemon,
+    service: &str,
+    health_check: Option<&HealthCheckConfig>,
+    url: Option<&str>,
+) -> Result<Option<CheckReport>, ProcessManagerError> {
+    let attempt_timeout = health_check
+        .and_then(|check| check.attempt_timeout.as_deref())
+        .map_or(Ok(DEFAULT_HEALTH_ATTEMPT_TIMEOUT), Daemon::parse_duration)?;
+    let connect_timeout = health_check
+        .and_then(|check| check.connect_timeout.as_deref())
+        .map(Daemon::parse_duration)
+        .transpose()?;
+
+    if let Some(url) = url.or(health_check.and_then(|check| check.url.as_deref())) {
+        return Ok(Some(url_check(url, attempt_timeout, connect_timeout)));
+    }
+    let Some(command) = health_check.and_then(|check| check.command.as_deref()) else {
+        return Ok(None);
+    };
+    let mut check =
+        command_check(daemon, service, CheckKind::Health, command, attempt_timeout);
+    // A probe that times out or fails to spawn is retried during a boot, so it
+    // reads as unhealthy rather than as an error.
+    check.decision = if check.exit_code == Some(0) {
+        Decision::Healthy
+    } else {
+        Decision::Unhealthy
+    };
+    Ok(Some(check))
+}
+
+/// Runs a shell command as the service would and records its result. The
+/// decision is `Start` on completion and `Error` when it could not run; callers
+/// map the exit code to their own decision.
+fn command_check(
+    daemon: &Daemon,
+    service: &str,
+    kind: CheckKind,
+    command: &str,
+    timeout: Duration,
+) -> CheckReport {
+    let started = Instant::now();
+    let result = daemon.probe_command(service, command, timeout);
+    let mut check = CheckReport::new(kind, command, Decision::Start);
+    check.elapsed_ms = started.elapsed().as_millis() as u64;
+    match result {
+        Ok(output) => {
+            check.exit_code = output.status.code();
+            check.stdout = String::from_utf8_lossy(&output.stdout)
+                .trim_end()
+                .to_string();
+            check.stderr = String::from_utf8_lossy(&output.stderr)
+                .trim_end()
+                .to_string();
+        }
+        Err(err) => {
+            check.decision = Decision::Error;
+            check.detail = Some(err.to_string());
+        }
+    }
+    check
+}
+
+/// Reports an HTTP probe as impossible in a build without `http-health`.
+#[cfg(not(feature = "http-health"))]
+fn url_check(
+    url: &str,
+    _attempt_timeout: Duration,
+    _connect_timeout: Option<Duration>,
+) -> CheckReport {
+    let mut check = CheckReport::new(CheckKind::Health, url, Decision::Error);
+    check.detail = Some(crate::config::HTTP_HEALTH_DISABLED.to_string());
+    check
+}
+
+/// Sends one GET to `url` through a direct (proxy-free) client, as the
+/// supervisor's health probe does.
+#[cfg(feature = "http-health")]
+fn url_check(
+    url: &str,
+    attempt_timeout: Duration,
+    connect_timeout: Option<Duration>,
+) -> CheckReport {
+    let mut check = CheckReport::new(CheckKind::Health, url, Decision::Unhealthy);
+    let mut builder = Client::builder().timeout(attempt_timeout).no_proxy();
+    if let Some(connect_timeout) = connect_timeout {
+        builder = builder.connect_timeout(connect_timeout);
+    }
+    let client = match builder.build() {
+        Ok(client) => client,
+        Err(err) => {
+            check.decision = Decision::Error;
+            check.detail = Some(err.to_string());
+            return check;
+        }
+    };
+
+    let started = Instant::now();
+    let response = client.get(url).send();
+    check.elapsed_ms = started.elapsed().as_millis() as u64;
+    match response {
+        Ok(response) => {
+            check.http_status = Some(response.status().as_u16());
+            if response.status().is_success() {
+                check.decision = Decision::Healthy;
+            }
+        }
+        Err(err) => {
+            check.detail = Some(if err.is_timeout() {
+                format!("timed out after {}s", attempt_timeout.as_secs())
+            } else {
+                err.to_string()
+            });
+        }
+    }
+    check
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "http-health")]
+    use std::collections::HashMap;
+    use std::{
+        fs,
+        sync::{Arc, Mutex},
+    };
+
+    use super::*;
+    use crate::{
+        config::{Config, load_config},
+        daemon::{PidFile, ServiceStateFile},
+    };
+
+    fn daemon_for(dir: &std::path::Path, manifest: &str) -> Daemon {
+        let path = dir.join("systemg.yaml");
+        fs::write(&path, manifest).expect("write manifest");
+        let config: Config = load_config(Some(path.to_str().expect("path"))).unwrap();
+        Daemon::new(
+            config,
+            Arc::new(Mutex::new(PidFile::default())),
+            Arc::new(Mutex::new(ServiceStateFile::default())),
+            false,
+        )
+    }
+
+    #[test]
+    fn reports_skip_output_and_command_health_decisions() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let daemon = daemon_for(
+            dir.path(),
+            r#"
+version: "2"
+services:
+  web:
+    command: "sleep 30"
+    env:
+      vars:
+        STAGE: "canary"
+    skip: "echo checking $STAGE; exit 3"
+    deployment:
+      health_check:
+        command: "echo unhealthy >&2; false"
+"#,
+        );
+
+        let report = run(&daemon, "web", None).expect("config test");
+
+        assert_eq!(report.service, "web");
+        assert_eq!(report.running_pid, None);
+        let skip = &report.checks[0];
+        assert_eq!(skip.kind, CheckKind::Skip);
+        assert_eq!(skip.exit_code, Some(3));
+        assert_eq!(skip.stdout, "checking canary");
+        assert_eq!(skip.decision, Decision::Start);
+        let health = &report.checks[1];
+        assert_eq!(health.kind, CheckKind::Health);
+        assert_eq!(health.stderr, "unhealthy");
+        assert_eq!(health.decision, Decision::Unhealthy);
+        assert!(!report.passed());
+    }
+
+    #[cfg(feature = "http-health")]
+    #[test]
+    fn url_override_probes_a_service_without_a_health_check() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let daemon = daemon_for(
+            dir.path(),
+            r#"
+version: "2"
+services:
+  web:
+    command: "sleep 30"
+    skip: true
+"#,
+        );
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0u8; 1024];
+            let _ = std::io::Read::read(&mut stream, &mut request);
+            std::io::Write::write_all(
+                &mut stream,
+                b"HTTP/1.1 204 No Content\r\nConnection: close\r\n\r\n",
+            )
+            .unwrap();
+        });
+
+        let url = format!("http://127.0.0.1:{port}/health");
+        let report = run(&daemon, "web", Some(&url)).expect("config test");
+        server.join().unwrap();
+
+        let decisions = report
+            .checks
+            .iter()
+            .map(|check| (check.kind, check.decision))
+            .collect::<HashMap<_, _>>();
+        assert_eq!(decisions[&CheckKind::Skip], Decision::Skip);
+        assert_eq!(decisions[&CheckKind::Health], Decision::Healthy);
+        assert_eq!(report.checks[1].http_status, Some(204));
+        assert!(report.passed());
+    }
+
+    #[test]
+    fn unknown_service_is_a_not_found_diagnostic() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let daemon = daemon_for(
+            dir.path(),
+            "version: \"2\"\nservices:\n  web:\n    command: \"sleep 30\"\n",
+        );
+
+        let err = run(&daemon, "ghost", None).expect_err("missing service");
+        assert!(matches!(
+            err,
+            ProcessManagerError::Diag(diag)
+                if diag.code == crate::diag::SgCode::TargetNotFound
+        ));
+    }
+}
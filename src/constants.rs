@@ -136,6 +136,9 @@ pub const STATE_FILE_NAME: &str = "state.xml";
 /// Default shell used for executing service commands and hooks.
 pub const DEFAULT_SHELL: &str = "sh";
 
+/// `shell` value that execs a service's command directly, without a shell.
+pub const NO_SHELL: &str = "none";
+
 /// `PATH` installed for a privilege-dropped service started from a clean
 /// environment, so it can still resolve system binaries without inheriting the
 /// supervisor's (root's) `PATH`.
@@ -156,6 +159,18 @@ pub const SESSION_SCOPED_ENV_VARS: &[&str] = &[
     "SSH_TTY",
 ];
 
+/// Prefix marking an `env.vars` value as a command whose output is the value,
+/// e.g. `DB_PASS: "!cmd: vault read -field=pass secret/db"`.
+pub const ENV_COMMAND_PREFIX: &str = "!cmd:";
+
+/// Maximum time a `!cmd:` env command may run before the launch it feeds fails.
+pub const ENV_COMMAND_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Environment variable naming the `<project>/<service>` a process was
+/// launched for. Children inherit it across `setsid`, so a stop can still find
+/// processes that left the service's session.
+pub const SERVICE_UNIT_ENV: &str = "SYSTEMG_UNIT";
+
 /// Number of checks to perform when waiting for a process to become ready.
 /// Used in conjunction with PROCESS_CHECK_INTERVAL.
 pub const PROCESS_READY_CHECKS: usize = 10;
@@ -163,10 +178,18 @@ pub const PROCESS_READY_CHECKS: usize = 10;
 /// Interval between process readiness checks.
 pub const PROCESS_CHECK_INTERVAL: Duration = Duration::from_millis(100);
 
+/// How long a stopping service has to exit after SIGTERM before it is sent
+/// SIGKILL, unless `sysg stop --timeout` overrides it.
+pub const STOP_GRACE_PERIOD: Duration = Duration::from_secs(1);
+
 /// Maximum time to wait for a service to start before timing out.
 /// Applied during service initialization and health checks.
 pub const SERVICE_START_TIMEOUT: Duration = Duration::from_secs(5);
 
+/// How long a `forking` service's launcher may take to exit and leave a valid
+/// PID in its `pidfile` before the start is treated as failed.
+pub const FORKING_STARTUP_TIMEOUT: Duration = Duration::from_secs(10);
+
 /// Minimum continuous survival time required before a process without a health
 /// check is reported ready. This prevents immediate bind and startup failures
 /// from being mistaken for a successful launch between process probes.
@@ -178,6 +201,9 @@ pub const DEFAULT_HEALTH_ATTEMPT_TIMEOUT: Duration = Duration::from_secs(30);
 /// Default delay between health-check probes.
 pub const DEFAULT_HEALTH_INTERVAL: Duration = Duration::from_secs(2);
 
+/// Default ceiling for an exponentially growing health-check delay.
+pub const DEFAULT_HEALTH_MAX_INTERVAL: Duration = Duration::from_secs(30);
+
 /// Default minimum number of health-check probes before readiness fails.
 pub const DEFAULT_HEALTH_RETRIES: u32 = 3;
 
@@ -229,6 +255,9 @@ pub const DEFAULT_TERMINAL_WIDTH: usize = 80;
 /// Polling interval when waiting for service state changes.
 pub const SERVICE_POLL_INTERVAL: Duration = Duration::from_millis(50);
 
+/// How often `sysg cron watch` re-reads a job's persisted run history.
+pub const CRON_WATCH_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
 /// Number of attempts to verify a service is running after restart.
 pub const POST_RESTART_VERIFY_ATTEMPTS: usize = 2;
 
@@ -242,15 +271,24 @@ pub const MAX_STATUS_LOG_LINES: usize = 50;
 /// Number of recent service log lines shown when no explicit limit is given.
 pub const DEFAULT_LOG_LINES: usize = 100;
 
+/// Lines `sysg logs --follow` holds for a slow terminal before dropping the oldest.
+pub const DEFAULT_FOLLOW_BUFFER_LINES: usize = 10_000;
+
 /// Buffer size for log output streams (stdout/stderr).
 pub const LOG_BUFFER_SIZE: usize = 8192;
 
 /// Maximum size of a single newline-framed control-socket command.
 ///
-/// Caps the buffer `read_command` allocates so one connection cannot exhaust
+/// Caps the buffer `read_request` allocates so one connection cannot exhaust
 /// supervisor memory by streaming bytes without a newline.
 pub const MAX_CONTROL_LINE: u64 = 1024 * 1024;
 
+/// Random bytes in the control-socket token, hex-encoded on disk.
+pub const CONTROL_TOKEN_BYTES: usize = 32;
+
+/// Most bytes of the supervisor log `sysg snapshot` keeps, taken from the end.
+pub const SNAPSHOT_LOG_CAP: u64 = 512 * 1024;
+
 /// Format string for hook labels combining stage and outcome.
 /// Example: "pre_start.pending", "post_start.success"
 pub const HOOK_LABEL_FORMAT: &str = "{}.{}";
@@ -333,3 +371,12 @@ pub const SKIP_CRON_SERVICE_MSG: &str = "Skipping cron-managed service '{}' duri
 
 /// Message logged when skipping cron services during restart.
 pub const SKIP_CRON_RESTART_MSG: &str = "Skipping cron-managed service '{}' during restart; scheduled execution will launch it";
+
+/// Short git commit the binary was built from, or `unknown` outside a checkout.
+pub const BUILD_GIT_COMMIT: &str = env!("SYSG_GIT_COMMIT");
+
+/// `rustc --version` of the compiler that built the binary.
+pub const BUILD_RUSTC_VERSION: &str = env!("SYSG_RUSTC_VERSION");
+
+/// Target triple the binary was compiled for.
+pub const BUILD_TARGET: &str = env!("SYSG_BUILD_TARGET");
@@ -5,7 +5,7 @@ use std::{
     path::PathBuf,
     str::FromStr,
     sync::{Arc, Mutex, MutexGuard, PoisonError},
-    time::{SystemTime, UNIX_EPOCH},
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 use chrono::{Local, Utc};
@@ -21,11 +21,13 @@ use tracing::{debug, info, warn};
 use crate::{
     config::{Config, CronConfig},
     error::ProcessManagerError,
-    state_store::StateStore,
+    state_store::{SchemaVersion, StateStore},
 };
 
-/// Maximum number of execution history entries to keep per cron job.
+/// Execution history entries kept per cron job unless `history_limit` is set.
 const MAX_EXECUTION_HISTORY: usize = 10;
+/// Log lines copied into each completed execution record.
+const CRON_OUTPUT_TAIL_LINES: usize = 20;
 /// Serialized label for a successful cron execution.
 const CRON_STATUS_SUCCESS: &str = "Success";
 /// Serialized label for a failed cron execution.
@@ -438,6 +440,78 @@ pub struct CronExecutionRecord {
     /// Metrics collected during this execution (for resource usage display).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub metrics: Vec<crate::metrics::MetricSample>,
+    /// Whether this execution was requested with `sysg cron run` rather than
+    /// fired by the schedule.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub manual: bool,
+    /// Last lines the run wrote to the service log, captured when it completed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub output: Vec<String>,
+}
+
+/// What a `sysg cron run` request did; both variants carry the job's service name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManualRunOutcome {
+    /// The run was queued for the next scheduler tick.
+    Queued(String),
+    /// The previous execution is still running, so an overlap was recorded.
+    Overlapped(String),
+}
+
+/// A transition observed between two reads of a job's execution history.
+#[derive(Debug, Clone)]
+pub enum CronRunEvent {
+    /// A run was recorded as started.
+    Started(CronExecutionRecord),
+    /// A previously started run reached a terminal status.
+    Finished(CronExecutionRecord),
+}
+
+/// Remembers which history entries a watcher has already reported so repeated
+/// reads of the persisted state only yield new transitions. Runs are keyed by
+/// their start time, which is unique per job.
+#[derive(Debug, Default)]
+pub struct CronHistoryCursor {
+    seen: HashMap<SystemTime, bool>,
+}
+
+impl CronHistoryCursor {
+    /// Creates a cursor that treats every entry of `history` as already reported.
+    pub fn primed<'a>(
+        history: impl IntoIterator<Item = &'a CronExecutionRecord>,
+    ) -> Self {
+        let mut cursor = Self::default();
+        cursor.advance(history);
+        cursor
+    }
+
+    /// Returns the events that happened since the previous call, oldest first.
+    /// A run that both started and finished between reads yields both events.
+    pub fn advance<'a>(
+        &mut self,
+        history: impl IntoIterator<Item = &'a CronExecutionRecord>,
+    ) -> Vec<CronRunEvent> {
+        let mut events = Vec::new();
+        let mut present = HashMap::new();
+        for record in history {
+            let finished = record.status.is_some();
+            match self.seen.get(&record.started_at) {
+                None => {
+                    events.push(CronRunEvent::Started(record.clone()));
+                    if finished {
+                        events.push(CronRunEvent::Finished(record.clone()));
+                    }
+                }
+                Some(false) if finished => {
+                    events.push(CronRunEvent::Finished(record.clone()));
+                }
+                Some(_) => {}
+            }
+            present.insert(record.started_at, finished);
+        }
+        self.seen = present;
+        events
+    }
 }
 
 /// Tracks execution history and state for a single cron job.
@@ -457,8 +531,15 @@ pub struct CronJobState {
     pub next_execution: Option<SystemTime>,
     /// Whether an execution is currently in progress.
     pub currently_running: bool,
-    /// Rolling history of recent executions (limited to MAX_EXECUTION_HISTORY).
+    /// Whether `sysg cron run` asked for an execution on the next tick.
+    pub manual_requested: bool,
+    /// Rolling history of recent executions, bounded by `history_limit` and
+    /// `history_retention`.
     pub execution_history: VecDeque<CronExecutionRecord>,
+    /// Most execution records kept; the oldest finished ones are evicted first.
+    pub history_limit: usize,
+    /// Age past which finished execution records are dropped.
+    pub history_retention: Option<Duration>,
     /// Timezone used for schedule calculations.
     pub timezone: EffectiveTimezone,
     /// Human-readable timezone label for display.
@@ -497,7 +578,10 @@ impl CronJobState {
             last_execution: None,
             next_execution,
             currently_running: false,
+            manual_requested: false,
             execution_history: VecDeque::with_capacity(MAX_EXECUTION_HISTORY),
+            history_limit: MAX_EXECUTION_HISTORY,
+            history_retention: None,
             timezone,
             timezone_label,
         };
@@ -505,9 +589,6 @@ impl CronJobState {
         if let Some(persisted) = persisted {
             state.last_execution = persisted.last_execution;
             state.execution_history = persisted.execution_history;
-            while state.execution_history.len() > MAX_EXECUTION_HISTORY {
-                state.execution_history.pop_front();
-            }
             let live_execution = state
                 .active_record()
                 .filter(|record| incomplete_execution_is_live(record))
@@ -519,9 +600,38 @@ impl CronJobState {
         state
     }
 
-    /// Adds an execution record to the history, evicting the oldest if at capacity.
+    /// Replaces the default history bounds with the job's configured ones and
+    /// applies them to any restored history.
+    pub fn with_history_policy(
+        mut self,
+        limit: usize,
+        retention: Option<Duration>,
+    ) -> Self {
+        self.history_limit = limit.max(1);
+        self.history_retention = retention;
+        self.prune_history(SystemTime::now());
+        self
+    }
+
+    /// Adds an execution record to the history, then evicts records past the
+    /// job's retention and count limits.
     pub fn add_execution_record(&mut self, record: CronExecutionRecord) {
-        if self.execution_history.len() >= MAX_EXECUTION_HISTORY {
+        self.execution_history.push_back(record);
+        self.prune_history(SystemTime::now());
+    }
+
+    /// Drops finished records that started longer ago than `history_retention`,
+    /// then evicts the oldest finished records until `history_limit` is met. A
+    /// run still in progress is only evicted when nothing else is left to drop.
+    fn prune_history(&mut self, now: SystemTime) {
+        if let Some(retention) = self.history_retention
+            && let Some(cutoff) = now.checked_sub(retention)
+        {
+            self.execution_history.retain(|record| {
+                cron_record_is_incomplete(record) || record.started_at >= cutoff
+            });
+        }
+        while self.execution_history.len() > self.history_limit {
             let remove = self
                 .execution_history
                 .iter()
@@ -529,7 +639,6 @@ impl CronJobState {
                 .unwrap_or(0);
             self.execution_history.remove(remove);
         }
-        self.execution_history.push_back(record);
     }
 
     /// Returns the newest execution that has no terminal outcome.
@@ -687,6 +796,10 @@ impl CronManager {
             effective_timezone,
             timezone_label.clone(),
             persisted_state,
+        )
+        .with_history_policy(
+            cron_config.history_limit.unwrap_or(MAX_EXECUTION_HISTORY),
+            cron_config.history_retention()?,
         );
 
         Ok((job_state, normalized, normalized_expression))
@@ -811,15 +924,19 @@ impl CronManager {
         let mut due_jobs = Vec::new();
 
         for job in jobs.iter_mut() {
-            if let Some(next_exec) = job.next_execution
-                && now >= next_exec
-            {
-                let next_dt: chrono::DateTime<Utc> = next_exec.into();
-                let now_dt: chrono::DateTime<Utc> = now.into();
-                debug!(
-                    "Cron job '{}' is due (next_exec: {}, now: {})",
-                    job.service_name, next_dt, now_dt
-                );
+            let scheduled = job.next_execution.is_some_and(|next_exec| now >= next_exec);
+            let manual = std::mem::take(&mut job.manual_requested) && !scheduled;
+            if scheduled || manual {
+                if let Some(next_exec) = job.next_execution.filter(|_| scheduled) {
+                    let next_dt: chrono::DateTime<Utc> = next_exec.into();
+                    let now_dt: chrono::DateTime<Utc> = now.into();
+                    debug!(
+                        "Cron job '{}' is due (next_exec: {}, now: {})",
+                        job.service_name, next_dt, now_dt
+                    );
+                } else {
+                    debug!("Cron job '{}' was run manually", job.service_name);
+                }
 
                 if job.currently_running {
                     warn!(
@@ -836,9 +953,13 @@ impl CronManager {
                         user: None,
                         command: None,
                         metrics: vec![],
+                        manual,
+                        output: vec![],
                     };
                     job.add_execution_record(record);
-                    job.update_next_execution();
+                    if scheduled {
+                        job.update_next_execution();
+                    }
                     self.persist_job_state(job);
                     continue;
                 }
@@ -862,9 +983,13 @@ impl CronManager {
                         user: None,
                         command: None,
                         metrics: vec![],
+                        manual,
+                        output: vec![],
                     };
                     job.add_execution_record(record);
-                    job.update_next_execution();
+                    if scheduled {
+                        job.update_next_execution();
+                    }
                     self.persist_job_state(job);
                 }
             }
@@ -873,6 +998,42 @@ impl CronManager {
         due_jobs
     }
 
+    /// Queues an immediate, unscheduled execution of the job owning
+    /// `service_hash`; the next scheduler tick starts it. A job that is still
+    /// running records an overlap instead, as a scheduled run would. Returns
+    /// `None` when no job owns the hash.
+    pub fn request_manual_run(&self, service_hash: &str) -> Option<ManualRunOutcome> {
+        let mut jobs = lock_recover(&self.jobs);
+        let job = jobs
+            .iter_mut()
+            .find(|job| job.service_hash == service_hash)?;
+        let name = job.service_name.clone();
+        if !job.currently_running {
+            job.manual_requested = true;
+            return Some(ManualRunOutcome::Queued(name));
+        }
+
+        warn!(
+            "Cron job '{name}' was run manually but previous execution is still running"
+        );
+        let now = SystemTime::now();
+        job.add_execution_record(CronExecutionRecord {
+            started_at: now,
+            completed_at: Some(now),
+            status: Some(CronExecutionStatus::OverlapError),
+            exit_code: None,
+            pid: None,
+            process_start: None,
+            user: None,
+            command: None,
+            metrics: vec![],
+            manual: true,
+            output: vec![],
+        });
+        self.persist_job_state(job);
+        Some(ManualRunOutcome::Overlapped(name))
+    }
+
     /// Removes all scheduled jobs owned by one project.
     pub fn remove_project_jobs(&self, project_id: &str) {
         lock_recover(&self.jobs).retain(|job| job.project_id != project_id);
@@ -928,6 +1089,17 @@ impl CronManager {
         exit_code: Option<i32>,
         metrics: Vec<crate::metrics::MetricSample>,
     ) {
+        // The run's last lines may still be in the log pipe when it is reaped;
+        // land them before the record captures its output tail.
+        let owner = lock_recover(&self.jobs)
+            .iter()
+            .find(|job| job.service_hash == service_hash)
+            .map(|job| (job.project_id.clone(), job.service_name.clone()));
+        if let Some((project, service)) = owner
+            && let Err(err) = crate::logs::sync_exited_service_log(&project, &service)
+        {
+            warn!("Failed to sync log for cron job '{service}': {err}");
+        }
         self.mark_job_completed_by(
             |job| job.service_hash == service_hash,
             Some(started_at),
@@ -972,6 +1144,12 @@ impl CronManager {
                 record.status = Some(status);
                 record.exit_code = exit_code;
                 record.metrics = metrics;
+                record.output = crate::logs::tail_service_log_since(
+                    &job.project_id,
+                    &job.service_name,
+                    CRON_OUTPUT_TAIL_LINES,
+                    record.started_at.into(),
+                );
                 job.execution_history.push_back(record);
                 if completed_active {
                     job.currently_running = false;
@@ -1138,6 +1316,9 @@ struct CronJobEntry {
 /// Persistent storage for cron job state across supervisor restarts.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CronStateFile {
+    /// On-disk format version; see [`crate::state_store::STATE_SCHEMA_VERSION`].
+    #[serde(default = "SchemaVersion::unversioned")]
+    schema_version: SchemaVersion,
     #[serde(
         serialize_with = "serialize_cron_jobs",
         deserialize_with = "deserialize_cron_jobs"
@@ -1218,14 +1399,16 @@ impl CronStateFile {
     pub fn load(store: StateStore) -> Result<Self, std::io::Error> {
         let lock = Self::lock(&store)?;
         FileExt::lock_exclusive(&lock)?;
-        let (state, compact) = Self::read(store)?;
-        if compact {
+        let (state, rewrite) = Self::read(store)?;
+        if rewrite {
             state.write()?;
         }
         Ok(state)
     }
 
-    /// Reads cron state while the caller holds the project lock.
+    /// Reads cron state while the caller holds the project lock. The flag is
+    /// set when the file was compact or in an older format and should be
+    /// rewritten.
     fn read(store: StateStore) -> Result<(Self, bool), std::io::Error> {
         let empty = || Self {
             store: store.clone(),
@@ -1250,7 +1433,26 @@ impl CronStateFile {
             )
         })?;
         state.store = store;
-        Ok((state, compact))
+        let migrated = state.migrate();
+        Ok((state, compact || migrated))
+    }
+
+    /// Upgrades a file read in an older format, returning whether it changed.
+    /// Version 0 keyed jobs by config hash; each is re-keyed by the state key
+    /// of the service it recorded, so history survives config edits.
+    fn migrate(&mut self) -> bool {
+        if !self.schema_version.needs_migration(&self.path()) {
+            return false;
+        }
+        let legacy = std::mem::take(&mut self.jobs);
+        for (key, job) in legacy {
+            let key = self
+                .store
+                .upgrade_legacy_key(&key, job.service_name.as_deref());
+            self.jobs.entry(key).or_insert(job);
+        }
+        self.schema_version = SchemaVersion::default();
+        true
     }
 
     /// Updates one cron unit while preserving concurrent scheduler writes.
@@ -1373,6 +1575,7 @@ mod tests {
         let temp = tempfile::tempdir().expect("tempdir");
         let store = StateStore::at(temp.path().to_path_buf());
         let state = CronStateFile {
+            schema_version: SchemaVersion::default(),
             jobs: std::collections::BTreeMap::from([(
                 "v2:test:job".to_string(),
                 PersistedCronJobState {
@@ -1396,6 +1599,33 @@ mod tests {
         );
     }
 
+    #[test]
+    /// Re-keys unversioned (v0) cron history by state key instead of dropping it.
+    fn load_migrates_hash_keyed_cron_state() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let store = StateStore::at(temp.path().join("projects").join("shop"));
+        fs::create_dir_all(store.dir()).expect("create project dir");
+        fs::write(
+            store.cron_path(),
+            "<CronStateFile>\n  <jobs>\n    <hash>0123456789abcdef</hash>\n    <state>\n      <service_name>backup</service_name>\n      <timezone_label>UTC</timezone_label>\n    </state>\n  </jobs>\n</CronStateFile>\n",
+        )
+        .expect("write v0 cron state");
+
+        let state = CronStateFile::load(store.clone()).expect("load v0 cron state");
+
+        let job = state
+            .jobs()
+            .get("v2:shop:backup")
+            .expect("job re-keyed by state key");
+        assert_eq!(job.timezone_label, "UTC");
+        assert_eq!(state.jobs().len(), 1);
+        assert!(
+            fs::read_to_string(store.cron_path())
+                .expect("read migrated cron state")
+                .contains("<schema_version>1</schema_version>")
+        );
+    }
+
     /// Computes a test hash for a cron configuration.
     fn compute_test_hash(cron_config: &CronConfig) -> String {
         let service_config = ServiceConfig {
@@ -1407,9 +1637,31 @@ mod tests {
             limits: None,
             capabilities: None,
             isolation: None,
+            umask: None,
+            priority: None,
+            after: None,
+            before: None,
+            critical: None,
+            interactive: None,
+            pty: None,
+            shell: None,
+            ready_log_pattern: None,
+            start_timeout: None,
+            stop_timeout: None,
             restart_policy: None,
             backoff: None,
             max_restarts: None,
+            start_retries: None,
+            start_retry_delay: None,
+            ready_stable_for: None,
+            instances: None,
+            forking: None,
+            pidfile: None,
+            sockets: None,
+            ports: None,
+            restart_if_rss_exceeds: None,
+            rss_window: None,
+            condition: None,
             depends_on: None,
             deployment: None,
             hooks: None,
@@ -1417,6 +1669,7 @@ mod tests {
             skip: None,
             spawn: None,
             logs: None,
+            health: None,
             project_scope: None,
         };
         service_config.compute_hash()
@@ -1428,6 +1681,8 @@ mod tests {
         let cron_config = CronConfig {
             expression: "0 * * * * *".to_string(),
             timezone: Some("UTC".into()),
+            history_limit: None,
+            history_retention: None,
         };
         let service_hash = compute_test_hash(&cron_config);
 
@@ -1449,6 +1704,8 @@ mod tests {
         let cron_config = CronConfig {
             expression: "invalid cron".to_string(),
             timezone: None,
+            history_limit: None,
+            history_retention: None,
         };
         let service_hash = compute_test_hash(&cron_config);
 
@@ -1465,6 +1722,8 @@ mod tests {
         let cron_config = CronConfig {
             expression: "* * * * *".to_string(),
             timezone: None,
+            history_limit: None,
+            history_retention: None,
         };
         let service_hash = compute_test_hash(&cron_config);
 
@@ -1477,6 +1736,78 @@ mod tests {
         assert!(jobs[0].next_execution.is_some());
     }
 
+    /// Builds a finished execution record that started `age` ago.
+    fn finished_record(age: Duration) -> CronExecutionRecord {
+        let started_at = SystemTime::now() - age;
+        CronExecutionRecord {
+            started_at,
+            completed_at: Some(started_at),
+            status: Some(CronExecutionStatus::Success),
+            exit_code: Some(0),
+            pid: None,
+            process_start: None,
+            user: None,
+            command: None,
+            metrics: vec![],
+            manual: false,
+            output: vec![],
+        }
+    }
+
+    /// Builds an idle job state with the given history bounds.
+    fn job_with_history_policy(
+        limit: usize,
+        retention: Option<Duration>,
+    ) -> CronJobState {
+        CronJobState::new(
+            String::new(),
+            "pruned".to_string(),
+            "pruned-hash".to_string(),
+            Schedule::from_str("0 * * * * *").expect("valid schedule"),
+            EffectiveTimezone::Utc,
+            "UTC".to_string(),
+            None,
+        )
+        .with_history_policy(limit, retention)
+    }
+
+    #[test]
+    fn history_limit_evicts_the_oldest_records() {
+        let mut job = job_with_history_policy(3, None);
+        for age in (1..=5).rev() {
+            job.add_execution_record(finished_record(Duration::from_secs(age * 60)));
+        }
+
+        let ages: Vec<u64> = job
+            .execution_history
+            .iter()
+            .map(|record| {
+                SystemTime::now()
+                    .duration_since(record.started_at)
+                    .unwrap()
+                    .as_secs()
+                    / 60
+            })
+            .collect();
+        assert_eq!(ages, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn history_retention_drops_old_finished_records_but_keeps_a_running_one() {
+        let mut job = job_with_history_policy(10, Some(Duration::from_secs(3600)));
+        let mut running = finished_record(Duration::from_secs(7200));
+        running.completed_at = None;
+        running.status = None;
+        running.exit_code = None;
+        job.add_execution_record(running);
+        job.add_execution_record(finished_record(Duration::from_secs(5400)));
+        job.add_execution_record(finished_record(Duration::from_secs(60)));
+
+        assert_eq!(job.execution_history.len(), 2);
+        assert!(cron_record_is_incomplete(&job.execution_history[0]));
+        assert!(job.execution_history[1].status.is_some());
+    }
+
     #[test]
     fn restores_running_state_for_live_persisted_execution() {
         let schedule = Schedule::from_str("* * * * * *").expect("valid schedule");
@@ -1492,6 +1823,8 @@ mod tests {
             user: Some("rashad".to_string()),
             command: Some("/bin/true".to_string()),
             metrics: vec![],
+            manual: false,
+            output: vec![],
         });
 
         let state = CronJobState::new(
@@ -1546,6 +1879,8 @@ mod tests {
             user: Some("rashad".to_string()),
             command: Some("/bin/true".to_string()),
             metrics: vec![],
+            manual: false,
+            output: vec![],
         });
         let mut job = CronJobState::new(
             String::new(),
@@ -1589,6 +1924,51 @@ mod tests {
         crate::runtime::set_drop_privileges(false);
     }
 
+    #[test]
+    fn history_cursor_reports_only_new_transitions() {
+        let record =
+            |offset: u64, status: Option<CronExecutionStatus>| CronExecutionRecord {
+                started_at: UNIX_EPOCH + Duration::from_secs(offset),
+                completed_at: status
+                    .as_ref()
+                    .map(|_| UNIX_EPOCH + Duration::from_secs(offset + 1)),
+                exit_code: status.as_ref().map(|_| 0),
+                status,
+                pid: Some(100 + offset as u32),
+                process_start: None,
+                user: None,
+                command: None,
+                metrics: vec![],
+                manual: false,
+                output: vec![],
+            };
+
+        let mut history = vec![record(1, Some(CronExecutionStatus::Success))];
+        let mut cursor = CronHistoryCursor::primed(&history);
+        assert!(cursor.advance(&history).is_empty());
+
+        history.push(record(2, None));
+        let events = cursor.advance(&history);
+        assert!(
+            matches!(events.as_slice(), [CronRunEvent::Started(r)] if r.pid == Some(102))
+        );
+
+        history[1] = record(2, Some(CronExecutionStatus::Failed("exit 3".into())));
+        history.push(record(3, Some(CronExecutionStatus::Success)));
+        let events = cursor.advance(&history);
+        assert!(matches!(
+            events.as_slice(),
+            [
+                CronRunEvent::Finished(a),
+                CronRunEvent::Started(b),
+                CronRunEvent::Finished(c),
+            ] if a.pid == Some(102) && b.pid == Some(103) && c.pid == Some(103)
+        ));
+
+        history.remove(0);
+        assert!(cursor.advance(&history).is_empty());
+    }
+
     #[test]
     /// Verifies completed cron records persist exit and process metadata.
     fn persists_execution_history_with_exit_codes() {
@@ -1611,6 +1991,8 @@ mod tests {
         let cron_config = CronConfig {
             expression: "* * * * * *".to_string(),
             timezone: Some("UTC".into()),
+            history_limit: None,
+            history_retention: None,
         };
         let service_hash = compute_test_hash(&cron_config);
 
@@ -1663,6 +2045,138 @@ mod tests {
         crate::runtime::set_drop_privileges(false);
     }
 
+    #[test]
+    fn manual_run_fires_on_next_tick_without_moving_the_schedule() {
+        let _guard = crate::test_utils::env_lock();
+
+        let base = std::env::current_dir()
+            .expect("current_dir")
+            .join("target/tmp-home");
+        fs::create_dir_all(&base).unwrap();
+        let temp = tempfile::tempdir_in(&base).unwrap();
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", temp.path());
+        }
+        crate::runtime::init(crate::runtime::RuntimeMode::User);
+        crate::runtime::set_drop_privileges(false);
+
+        let manager = CronManager::new();
+        let cron_config = CronConfig {
+            expression: "0 0 0 1 1 *".to_string(),
+            timezone: Some("UTC".into()),
+            history_limit: None,
+            history_retention: None,
+        };
+        let service_hash = compute_test_hash(&cron_config);
+        manager
+            .register_job("", "yearly", &service_hash, &cron_config)
+            .unwrap();
+        let next_before = manager.jobs.lock().unwrap()[0].next_execution;
+
+        assert!(manager.get_due_job_refs().is_empty());
+        assert_eq!(
+            manager.request_manual_run(&service_hash),
+            Some(ManualRunOutcome::Queued("yearly".into()))
+        );
+        let due = manager.get_due_job_refs();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].service_name, "yearly");
+
+        assert_eq!(
+            manager.request_manual_run(&service_hash),
+            Some(ManualRunOutcome::Overlapped("yearly".into()))
+        );
+        assert_eq!(manager.request_manual_run("v2:none:missing"), None);
+
+        {
+            let jobs = manager.jobs.lock().unwrap();
+            assert_eq!(jobs[0].next_execution, next_before);
+            let history = &jobs[0].execution_history;
+            assert_eq!(history.len(), 2);
+            assert!(history.iter().all(|record| record.manual));
+            assert!(history[0].status.is_none());
+            assert!(matches!(
+                history[1].status,
+                Some(CronExecutionStatus::OverlapError)
+            ));
+        }
+
+        match original_home {
+            Some(val) => unsafe { std::env::set_var("HOME", val) },
+            None => unsafe { std::env::remove_var("HOME") },
+        }
+        crate::runtime::init(crate::runtime::RuntimeMode::User);
+        crate::runtime::set_drop_privileges(false);
+    }
+
+    #[test]
+    fn completed_run_keeps_only_its_own_log_output() {
+        let _guard = crate::test_utils::env_lock();
+
+        let base = std::env::current_dir()
+            .expect("current_dir")
+            .join("target/tmp-home");
+        fs::create_dir_all(&base).unwrap();
+        let temp = tempfile::tempdir_in(&base).unwrap();
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", temp.path());
+        }
+        crate::runtime::init(crate::runtime::RuntimeMode::User);
+        crate::runtime::set_drop_privileges(false);
+
+        let manager = CronManager::new();
+        let cron_config = CronConfig {
+            expression: "0 0 0 1 1 *".to_string(),
+            timezone: Some("UTC".into()),
+            history_limit: None,
+            history_retention: None,
+        };
+        let service_hash = compute_test_hash(&cron_config);
+        manager
+            .register_job("", "report", &service_hash, &cron_config)
+            .unwrap();
+        manager.request_manual_run(&service_hash);
+        let due = manager.get_due_job_refs();
+
+        let log_path = crate::logs::get_service_log_path("", "report");
+        fs::create_dir_all(log_path.parent().unwrap()).unwrap();
+        let before =
+            chrono::DateTime::<Utc>::from(due[0].started_at - Duration::from_secs(60));
+        let after =
+            chrono::DateTime::<Utc>::from(due[0].started_at + Duration::from_millis(5));
+        fs::write(
+            &log_path,
+            format!(
+                "{} stdout previous run\n{} stderr this run failed\n",
+                before.to_rfc3339_opts(chrono::SecondsFormat::Micros, true),
+                after.to_rfc3339_opts(chrono::SecondsFormat::Micros, true)
+            ),
+        )
+        .unwrap();
+
+        manager.complete_job_run(
+            &service_hash,
+            due[0].started_at,
+            CronExecutionStatus::Failed("Process exited with code 1".into()),
+            Some(1),
+            vec![],
+        );
+        let jobs = manager.get_all_jobs();
+        assert_eq!(
+            jobs[0].execution_history[0].output,
+            vec!["this run failed".to_string()]
+        );
+
+        match original_home {
+            Some(val) => unsafe { std::env::set_var("HOME", val) },
+            None => unsafe { std::env::remove_var("HOME") },
+        }
+        crate::runtime::init(crate::runtime::RuntimeMode::User);
+        crate::runtime::set_drop_privileges(false);
+    }
+
     /// Creates a test service with a cron configuration.
     fn service_with_cron(expr: &str) -> ServiceConfig {
         ServiceConfig {
@@ -1674,19 +2188,44 @@ mod tests {
             limits: None,
             capabilities: None,
             isolation: None,
+            umask: None,
+            priority: None,
+            after: None,
+            before: None,
+            critical: None,
+            interactive: None,
+            pty: None,
+            shell: None,
+            ready_log_pattern: None,
+            start_timeout: None,
+            stop_timeout: None,
             restart_policy: None,
             backoff: None,
             max_restarts: None,
+            start_retries: None,
+            start_retry_delay: None,
+            ready_stable_for: None,
+            instances: None,
+            forking: None,
+            pidfile: None,
+            sockets: None,
+            ports: None,
+            restart_if_rss_exceeds: None,
+            rss_window: None,
+            condition: None,
             depends_on: None,
             deployment: None,
             hooks: None,
             cron: Some(CronConfig {
                 expression: expr.to_string(),
                 timezone: None,
+                history_limit: None,
+                history_retention: None,
             }),
             skip: None,
             spawn: None,
             logs: None,
+            health: None,
             project_scope: None,
         }
     }
@@ -1722,6 +2261,13 @@ mod tests {
             metrics: crate::config::MetricsConfig::default(),
             logs: crate::config::LogsConfig::default(),
             status: crate::config::StatusConfig::default(),
+            deployment: crate::config::ProjectDeploymentConfig::default(),
+            on_ready: None,
+            on_start_failure: None,
+            on_failure: Default::default(),
+            dependency_settle: None,
+            supervisor: Default::default(),
+            env_overrides: Default::default(),
         };
 
         manager.sync_from_config(&config_v1).unwrap();
@@ -1738,6 +2284,13 @@ mod tests {
             metrics: crate::config::MetricsConfig::default(),
             logs: crate::config::LogsConfig::default(),
             status: crate::config::StatusConfig::default(),
+            deployment: crate::config::ProjectDeploymentConfig::default(),
+            on_ready: None,
+            on_start_failure: None,
+            on_failure: Default::default(),
+            dependency_settle: None,
+            supervisor: Default::default(),
+            env_overrides: Default::default(),
         };
 
         let job_two_hash = config_v2.state_key("job_two");
@@ -1793,6 +2346,8 @@ mod tests {
             user: None,
             command: None,
             metrics: vec![],
+            manual: false,
+            output: vec![],
         });
 
         state.jobs.insert(
@@ -1831,6 +2386,8 @@ mod tests {
             user: Some("ubuntu".to_string()),
             command: Some("/bin/true".to_string()),
             metrics: vec![],
+            manual: false,
+            output: vec![],
         });
 
         state.jobs.insert(
@@ -2,10 +2,16 @@
 #[cfg(unix)]
 use std::os::unix::process::ExitStatusExt;
 use std::{
-    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    ffi::OsString,
+    fmt,
     fs::{self, File},
     io::{BufReader, ErrorKind, Read},
-    os::unix::process::CommandExt,
+    os::{
+        fd::{AsRawFd, RawFd},
+        unix::process::CommandExt,
+    },
     path::{Path, PathBuf},
     process::{Child, Command, ExitStatus, Stdio},
     str::FromStr,
@@ -20,6 +26,7 @@ use std::{
 use fs2::FileExt;
 use quick_xml::de::from_str as xml_from_str;
 use regex::Regex;
+#[cfg(feature = "http-health")]
 use reqwest::blocking::Client;
 use serde::{Deserialize, Serialize, de::Error as _};
 use serde_yaml;
@@ -27,34 +34,47 @@ use sysinfo::{ProcessesToUpdate, System};
 use tracing::{debug, error, info, trace, warn};
 
 use crate::{
+    attach,
     config::{
-        BlueGreenDeploymentConfig, Config, DependsOnCondition, EffectiveLogsConfig,
-        EnvConfig, HealthCheckConfig, HookAction, HookOutcome, HookStage, LogSink,
-        ServiceConfig, SkipConfig, supervisor::SupervisorTimeouts,
+        BlueGreenDeploymentConfig, CommandLauncher, Config, DependsOnCondition,
+        EffectiveLogsConfig, EnvConfig, HTTP_HEALTH_DISABLED, HealthBackoff,
+        HealthCheckConfig, HookAction, HookOutcome, HookStage, LogSink, ServiceConfig,
+        SkipConfig, StartFailureMode, TerminationPolicy, supervisor::SupervisorTimeouts,
     },
     constants::{
-        DEFAULT_HEALTH_ATTEMPT_TIMEOUT, DEFAULT_HEALTH_INTERVAL, DEFAULT_HEALTH_RETRIES,
-        DEFAULT_SERVICE_PATH, DEFAULT_SHELL, DaemonLock, DeploymentStrategy,
-        POST_RESTART_VERIFY_ATTEMPTS, POST_RESTART_VERIFY_DELAY, PRE_START_TIMEOUT,
-        PROCESS_CHECK_INTERVAL, PROCESS_READY_CHECKS, SERVICE_POLL_INTERVAL,
-        SERVICE_START_TIMEOUT, SESSION_SCOPED_ENV_VARS, SHELL_COMMAND_FLAG,
+        DEFAULT_HEALTH_ATTEMPT_TIMEOUT, DEFAULT_HEALTH_INTERVAL,
+        DEFAULT_HEALTH_MAX_INTERVAL, DEFAULT_HEALTH_RETRIES, DEFAULT_SERVICE_PATH,
+        DEFAULT_SHELL, DaemonLock, DeploymentStrategy, ENV_COMMAND_PREFIX,
+        ENV_COMMAND_TIMEOUT, FORKING_STARTUP_TIMEOUT, POST_RESTART_VERIFY_ATTEMPTS,
+        POST_RESTART_VERIFY_DELAY, PRE_START_TIMEOUT, PROCESS_CHECK_INTERVAL,
+        PROCESS_READY_CHECKS, SERVICE_POLL_INTERVAL, SERVICE_START_TIMEOUT,
+        SERVICE_UNIT_ENV, SESSION_SCOPED_ENV_VARS, SHELL_COMMAND_FLAG, STOP_GRACE_PERIOD,
     },
     error::{PidFileError, ProcessManagerError, ServiceStateError},
-    logs::{resolve_log_path, spawn_managed_service_log_writers},
+    logs::{resolve_log_path, spawn_managed_service_log_writers, sync_service_log},
     opslot::OpSlot,
-    runtime,
+    pty, runtime, snapshot, sockets,
     spawn::SpawnedExit,
-    state_store::StateStore,
+    state_store::{SchemaVersion, StateStore},
     upgrade::{HandoffDaemonState, HandoffProcess},
     xml,
 };
 
+/// Stand-in for the HTTP client when the `http-health` feature is off. It has
+/// no values, so every HTTP probe path is statically unreachable.
+#[cfg(not(feature = "http-health"))]
+enum Client {}
+
 /// Capacity of the one-result health-check worker channel.
+#[cfg(feature = "http-health")]
 const HEALTH_RESULT_CAPACITY: usize = 1;
 /// Delay before retrying monitor state after a lock failure.
 const MONITOR_RETRY_DELAY: Duration = Duration::from_secs(2);
 /// Delay used when a service does not declare restart backoff.
 const DEFAULT_RESTART_BACKOFF: Duration = Duration::from_secs(5);
+/// Pause between start attempts when a service sets `start_retries` without
+/// `start_retry_delay`.
+const DEFAULT_START_RETRY_DELAY: Duration = Duration::from_secs(1);
 /// Thread name for service launch workers.
 const SERVICE_LAUNCH_THREAD: &str = "sysg-service-launch";
 /// Thread name for foreground stderr forwarding.
@@ -65,6 +85,8 @@ const OUTPUT_STDOUT_THREAD: &str = "sysg-output-stdout";
 const OUTPUT_STDERR_THREAD: &str = "sysg-output-stderr";
 /// Maximum pre-start output lines retained for failure diagnostics.
 const PRE_START_TAIL_LINES: usize = 12;
+/// Lines of already-captured output scanned for a `ready_log_pattern` match.
+const READY_LOG_BACKLOG_LINES: usize = 200;
 /// Poll interval while waiting for bounded helper commands to exit.
 const COMMAND_WAIT_POLL_INTERVAL: Duration = Duration::from_millis(50);
 /// Maximum bytes retained from each helper-command output stream.
@@ -121,11 +143,110 @@ mod systemtime_serde {
     }
 }
 
-/// Builds env map for service (inline vars override file entries).
+/// Provides optional systemtime serde support. `None` is skipped when
+/// serializing, so only `Some` values reach this module.
+mod systemtime_serde_opt {
+    use std::time::SystemTime;
+
+    use serde::{Deserializer, Serializer};
+
+    /// Serializes this item.
+    pub fn serialize<S>(
+        time: &Option<SystemTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match time {
+            Some(time) => super::systemtime_serde::serialize(time, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Handles deserialize.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<SystemTime>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::systemtime_serde::deserialize(deserializer).map(Some)
+    }
+}
+
+/// Builds env map for service (inline vars override file entries), running
+/// any `!cmd:` value to obtain the real one.
 fn collect_service_env(
     env: &Option<EnvConfig>,
     project_root: &Path,
     service_name: &str,
+) -> std::io::Result<HashMap<String, String>> {
+    let mut resolved = declared_service_env(env, project_root, service_name);
+    resolve_env_commands(&mut resolved, project_root, service_name)?;
+    Ok(resolved)
+}
+
+/// Replaces each `!cmd: <command>` value in `vars` with the command's trimmed
+/// stdout. Commands run as the supervisor, in `project_root`. Resolved values
+/// are never logged and are masked in the supervisor log if they turn up.
+fn resolve_env_commands(
+    vars: &mut HashMap<String, String>,
+    project_root: &Path,
+    service_name: &str,
+) -> std::io::Result<()> {
+    for (key, value) in vars.iter_mut() {
+        let Some(command) = value.strip_prefix(ENV_COMMAND_PREFIX) else {
+            continue;
+        };
+        let command = command.trim();
+        if command.is_empty() {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidInput,
+                format!("`{key}` has an empty `{ENV_COMMAND_PREFIX}` command"),
+            ));
+        }
+
+        let mut cmd = Command::new(DEFAULT_SHELL);
+        cmd.arg(SHELL_COMMAND_FLAG)
+            .arg(command)
+            .current_dir(project_root)
+            .stdin(Stdio::null());
+        let output = output_with_timeout(
+            &mut cmd,
+            ENV_COMMAND_TIMEOUT,
+            &format!("env command for `{key}`"),
+            None,
+        )?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let detail = stderr.trim().lines().last().unwrap_or("no stderr output");
+            return Err(std::io::Error::other(format!(
+                "env command for `{key}` failed ({}): {detail}",
+                output.status
+            )));
+        }
+        let stdout = String::from_utf8(output.stdout).map_err(|_| {
+            std::io::Error::new(
+                ErrorKind::InvalidData,
+                format!("env command for `{key}` printed non-UTF-8 output"),
+            )
+        })?;
+        let secret = stdout.trim();
+        snapshot::remember_secret(
+            &format!("{}/{service_name}/{key}", project_root.display()),
+            key,
+            secret,
+        );
+        *value = secret.to_string();
+    }
+    Ok(())
+}
+
+/// The service's env as declared: env-file entries overlaid with inline vars,
+/// `!cmd:` values left unresolved.
+fn declared_service_env(
+    env: &Option<EnvConfig>,
+    project_root: &Path,
+    service_name: &str,
 ) -> HashMap<String, String> {
     let mut resolved = HashMap::new();
 
@@ -226,6 +347,9 @@ struct MetadataEntry {
 /// PID tracking file.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct PidFile {
+    /// On-disk format version; see [`crate::state_store::STATE_SCHEMA_VERSION`].
+    #[serde(default = "SchemaVersion::unversioned")]
+    schema_version: SchemaVersion,
     /// Service name -> PID map.
     #[serde(default, rename = "services")]
     #[serde(
@@ -559,10 +683,22 @@ impl PidFile {
             let store = self.store.clone();
             *self = xml_from_str::<Self>(&contents)?;
             self.store = store;
+            self.migrate();
         }
         Ok(())
     }
 
+    /// Upgrades a file read in an older format, returning whether it changed.
+    /// Version 0 already keyed every map by service name or PID, so only the
+    /// version stamp moves.
+    fn migrate(&mut self) -> bool {
+        if !self.schema_version.needs_migration(&self.path()) {
+            return false;
+        }
+        self.schema_version = SchemaVersion::default();
+        true
+    }
+
     /// Writes `self` to `path`, creating the project directory if needed.
     fn write_at(&self, path: &std::path::Path) -> Result<(), PidFileError> {
         runtime::create_private_dir(path.parent().unwrap())?;
@@ -602,7 +738,7 @@ impl PidFile {
         let bound = this.store.clone();
         this = xml_from_str::<Self>(&contents)?;
         this.store = bound;
-        if compact {
+        if this.migrate() || compact {
             this.write_at(&path)?;
         }
         Ok(this)
@@ -1024,10 +1160,54 @@ mod pidfile_tests {
         );
     }
 
+    #[test]
+    /// Upgrades unversioned (v0) PID and lifecycle state instead of resetting it.
+    fn load_migrates_unversioned_state_files() {
+        let temp = tempdir().expect("tempdir");
+        let store = StateStore::at(temp.path().join("projects").join("shop"));
+        fs::create_dir_all(store.dir()).expect("create project dir");
+        fs::write(
+            store.pid_path(),
+            "<PidFile>\n  <services>\n    <name>api</name>\n    <pid>42</pid>\n  </services>\n</PidFile>\n",
+        )
+        .expect("write v0 pid state");
+        fs::write(
+            store.state_path(),
+            "<ServiceStateFile>\n  <services>\n    <name>api</name>\n    <state>\n      <status>running</status>\n      <pid>42</pid>\n    </state>\n  </services>\n  <services>\n    <name>v2:shop:worker</name>\n    <state>\n      <status>stopped</status>\n    </state>\n  </services>\n</ServiceStateFile>\n",
+        )
+        .expect("write v0 lifecycle state");
+
+        let pid = PidFile::load(store.clone()).expect("load v0 pid state");
+        let state =
+            ServiceStateFile::load(store.clone()).expect("load v0 lifecycle state");
+
+        assert_eq!(pid.pid_for("api"), Some(42));
+        let api = state.get("v2:shop:api").expect("api re-keyed by state key");
+        assert_eq!(api.status, ServiceLifecycleStatus::Running);
+        assert_eq!(api.pid, Some(42));
+        assert_eq!(
+            state.get("v2:shop:worker").map(|entry| entry.status),
+            Some(ServiceLifecycleStatus::Stopped)
+        );
+        assert!(state.get("api").is_none());
+
+        for path in [store.pid_path(), store.state_path()] {
+            let written = fs::read_to_string(&path).expect("read migrated state");
+            assert!(
+                written.contains("<schema_version>1</schema_version>"),
+                "{}: {written}",
+                path.display()
+            );
+        }
+        let reloaded = ServiceStateFile::load(store).expect("reload migrated state");
+        assert_eq!(reloaded.services().len(), 2);
+    }
+
     #[test]
     /// Removes spawn subtree in memory prunes all descendants.
     fn remove_spawn_subtree_in_memory_prunes_all_descendants() {
         let mut pid_file = PidFile {
+            schema_version: SchemaVersion::default(),
             services: HashMap::new(),
             service_groups: HashMap::new(),
             service_starts: HashMap::new(),
@@ -1108,6 +1288,7 @@ mod pidfile_tests {
 
         let store = StateStore::for_project("test");
         let pid_file = PidFile {
+            schema_version: SchemaVersion::default(),
             store: store.clone(),
             services: HashMap::from([("svc".to_string(), 10)]),
             service_groups: HashMap::from([("svc".to_string(), 10)]),
@@ -1192,6 +1373,9 @@ pub enum ServiceLifecycleStatus {
     ExitedWithError,
     /// Manually stopped.
     Stopped,
+    /// Kept failing until the restart policy hit `max_restarts`, so the
+    /// supervisor stopped restarting it. An explicit start re-arms it.
+    GaveUp,
 }
 
 /// Service runtime metadata.
@@ -1208,6 +1392,100 @@ pub struct ServiceStateEntry {
     /// Signal number if the service was terminated by a signal.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub signal: Option<i32>,
+    /// When the current (or most recent) process was observed running.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "systemtime_serde_opt"
+    )]
+    pub started_at: Option<SystemTime>,
+    /// When the most recent exit or stop was observed. Kept across restarts so
+    /// a flapping service can be told apart from one that crashed hours ago.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "systemtime_serde_opt"
+    )]
+    pub finished_at: Option<SystemTime>,
+    /// Set by `sysg disable`; start and reload skip the service until
+    /// `sysg enable` clears it.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub disabled: bool,
+    /// `ServiceConfig::compute_hash` of the config the running process was
+    /// launched from, so status can tell when the manifest has moved on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config_hash: Option<String>,
+    /// How many times the service was launched again after its first run,
+    /// whether by the restart policy or by `sysg restart`.
+    #[serde(default)]
+    pub restarts: u32,
+    /// The `max_restarts` the restart policy exhausted when the service was
+    /// given up on. Only set while the status is `gave_up`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gave_up_after: Option<u32>,
+}
+
+impl ServiceStateEntry {
+    /// Builds the entry that replaces `previous`, carrying its timestamps
+    /// forward. A newly running pid stamps `started_at` (and `config_hash`,
+    /// when given); a newly observed exit or stop stamps `finished_at`.
+    /// Re-recording the same state keeps all three. A launch that follows an
+    /// earlier one counts as a restart.
+    fn transition(
+        previous: Option<&Self>,
+        status: ServiceLifecycleStatus,
+        pid: Option<u32>,
+        exit_code: Option<i32>,
+        signal: Option<i32>,
+        config_hash: Option<&str>,
+        now: SystemTime,
+    ) -> Self {
+        let unchanged = previous.is_some_and(|prev| {
+            prev.status == status
+                && prev.pid == pid
+                && prev.exit_code == exit_code
+                && prev.signal == signal
+        });
+        let started_at = previous.and_then(|prev| prev.started_at);
+        let finished_at = previous.and_then(|prev| prev.finished_at);
+        let launched = matches!(status, ServiceLifecycleStatus::Running)
+            && pid.is_some()
+            && !(unchanged && started_at.is_some());
+        let restarts = previous.map_or(0, |prev| prev.restarts)
+            + u32::from(launched && started_at.is_some());
+        let started_at = if launched { Some(now) } else { started_at };
+        let previous_hash = previous.and_then(|prev| prev.config_hash.clone());
+        let config_hash = match config_hash {
+            Some(hash) if launched => Some(hash.to_string()),
+            _ => previous_hash,
+        };
+        let exited = matches!(
+            status,
+            ServiceLifecycleStatus::ExitedSuccessfully
+                | ServiceLifecycleStatus::ExitedWithError
+                | ServiceLifecycleStatus::Stopped
+        );
+        let finished_at = if exited && !(unchanged && finished_at.is_some()) {
+            Some(now)
+        } else {
+            finished_at
+        };
+
+        Self {
+            status,
+            pid,
+            exit_code,
+            signal,
+            started_at,
+            finished_at,
+            disabled: previous.is_some_and(|prev| prev.disabled),
+            config_hash,
+            restarts,
+            gave_up_after: previous
+                .filter(|_| status == ServiceLifecycleStatus::GaveUp)
+                .and_then(|prev| prev.gave_up_after),
+        }
+    }
 }
 
 /// Wrapper for state entries to make them XML-safe
@@ -1220,6 +1498,9 @@ struct StateEntry {
 /// Persistent record of the last-known state for every managed service.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct ServiceStateFile {
+    /// On-disk format version; see [`crate::state_store::STATE_SCHEMA_VERSION`].
+    #[serde(default = "SchemaVersion::unversioned")]
+    schema_version: SchemaVersion,
     #[serde(
         serialize_with = "serialize_state_entries",
         deserialize_with = "deserialize_state_entries"
@@ -1284,7 +1565,7 @@ impl ServiceStateFile {
         let store = state.store.clone();
         state = xml_from_str::<Self>(&contents)?;
         state.store = store;
-        if compact {
+        if state.migrate() || compact {
             state.save()?;
         }
         Ok(state)
@@ -1310,15 +1591,16 @@ impl ServiceStateFile {
         exit_code: Option<i32>,
         signal: Option<i32>,
     ) {
-        self.services.insert(
-            service_hash.to_string(),
-            ServiceStateEntry {
-                status,
-                pid,
-                exit_code,
-                signal,
-            },
+        let entry = ServiceStateEntry::transition(
+            self.services.get(service_hash),
+            status,
+            pid,
+            exit_code,
+            signal,
+            None,
+            SystemTime::now(),
         );
+        self.services.insert(service_hash.to_string(), entry);
     }
 
     /// Acquires an exclusive lock on the state file (auto-releases on drop).
@@ -1347,9 +1629,26 @@ impl ServiceStateFile {
         let store = self.store.clone();
         *self = xml_from_str::<Self>(&contents)?;
         self.store = store;
+        self.migrate();
         Ok(())
     }
 
+    /// Upgrades a file read in an older format, returning whether it changed.
+    /// Version 0 keyed entries by bare service name; they are re-keyed by
+    /// state key so the current config finds them again.
+    fn migrate(&mut self) -> bool {
+        if !self.schema_version.needs_migration(&self.path()) {
+            return false;
+        }
+        let legacy = std::mem::take(&mut self.services);
+        for (key, entry) in legacy {
+            let key = self.store.upgrade_legacy_key(&key, None);
+            self.services.entry(key).or_insert(entry);
+        }
+        self.schema_version = SchemaVersion::default();
+        true
+    }
+
     /// Saves the state file to disk.
     pub fn save(&self) -> Result<(), ServiceStateError> {
         let path = self.path();
@@ -1382,18 +1681,84 @@ impl ServiceStateFile {
         pid: Option<u32>,
         exit_code: Option<i32>,
         signal: Option<i32>,
+    ) -> Result<(), ServiceStateError> {
+        self.set_with_config_hash(service_hash, status, pid, exit_code, signal, None)
+    }
+
+    /// Like [`Self::set`], additionally recording the hash of the config a
+    /// newly launched process was started from.
+    pub fn set_with_config_hash(
+        &mut self,
+        service_hash: &str,
+        status: ServiceLifecycleStatus,
+        pid: Option<u32>,
+        exit_code: Option<i32>,
+        signal: Option<i32>,
+        config_hash: Option<&str>,
     ) -> Result<(), ServiceStateError> {
         let _lock = self.acquire_lock()?;
         self.reload_locked()?;
-        self.services.insert(
-            service_hash.to_string(),
-            ServiceStateEntry {
-                status,
-                pid,
-                exit_code,
-                signal,
-            },
+        let entry = ServiceStateEntry::transition(
+            self.services.get(service_hash),
+            status,
+            pid,
+            exit_code,
+            signal,
+            config_hash,
+            SystemTime::now(),
+        );
+        self.services.insert(service_hash.to_string(), entry);
+        self.save()
+    }
+
+    /// Records that the restart policy gave up on a service after `max_restarts`
+    /// attempts, keeping the exit code and signal of its last failure.
+    pub fn mark_gave_up(
+        &mut self,
+        service_hash: &str,
+        max_restarts: u32,
+    ) -> Result<(), ServiceStateError> {
+        let _lock = self.acquire_lock()?;
+        self.reload_locked()?;
+        let previous = self.services.get(service_hash);
+        let mut entry = ServiceStateEntry::transition(
+            previous,
+            ServiceLifecycleStatus::GaveUp,
+            None,
+            previous.and_then(|prev| prev.exit_code),
+            previous.and_then(|prev| prev.signal),
+            None,
+            SystemTime::now(),
         );
+        entry.gave_up_after = Some(max_restarts);
+        self.services.insert(service_hash.to_string(), entry);
+        self.save()
+    }
+
+    /// Sets or clears the persistent `disabled` flag for a service. A service
+    /// with no recorded state yet gets a `Skipped` entry to carry the flag.
+    pub fn set_disabled(
+        &mut self,
+        service_hash: &str,
+        disabled: bool,
+    ) -> Result<(), ServiceStateError> {
+        let _lock = self.acquire_lock()?;
+        self.reload_locked()?;
+        let entry = self
+            .services
+            .entry(service_hash.to_string())
+            .or_insert_with(|| {
+                ServiceStateEntry::transition(
+                    None,
+                    ServiceLifecycleStatus::Skipped,
+                    None,
+                    None,
+                    None,
+                    None,
+                    SystemTime::now(),
+                )
+            });
+        entry.disabled = disabled;
         self.save()
     }
 
@@ -1429,8 +1794,17 @@ fn run_hook(
     cmd.arg(SHELL_COMMAND_FLAG).arg(&action.command);
     cmd.current_dir(project_root);
 
-    for (key, value) in collect_service_env(env, project_root, service_name) {
-        cmd.env(key, value);
+    match collect_service_env(env, project_root, service_name) {
+        Ok(vars) => {
+            cmd.envs(vars);
+        }
+        Err(err) => {
+            error!(
+                "Failed to run {} hook for '{}': {}",
+                hook_label, service_name, err
+            );
+            return;
+        }
     }
 
     let timeout = match action.timeout.as_deref() {
@@ -1569,6 +1943,27 @@ fn occupied_command_port(command: Option<&str>) -> Option<u16> {
     crate::reconcile::port_holder(port).map(|_| port)
 }
 
+/// Whether `port` is taken, probed by binding it on every IPv4 interface the
+/// way a service listening on it would. Only `AddrInUse` counts: a bind refused
+/// for lack of privilege says nothing about whether the port is free.
+fn port_taken(port: u16) -> bool {
+    matches!(
+        std::net::TcpListener::bind((std::net::Ipv4Addr::UNSPECIFIED, port)),
+        Err(err) if err.kind() == ErrorKind::AddrInUse
+    )
+}
+
+/// Returns the first of `service_config`'s declared ports another process
+/// holds. Ports the project's own socket listeners hold are skipped.
+fn taken_declared_port(project: &str, service_config: &ServiceConfig) -> Option<u16> {
+    service_config
+        .ports
+        .iter()
+        .flatten()
+        .copied()
+        .find(|&port| !sockets::holds_port(project, port) && port_taken(port))
+}
+
 fn wait_with_epoch(
     child: &mut Child,
     timeout: Duration,
@@ -1594,6 +1989,18 @@ fn wait_with_epoch(
     }
 }
 
+/// Reads the PID a forking daemon wrote to its pidfile.
+fn read_pidfile(path: &Path) -> Option<u32> {
+    fs::read_to_string(path)
+        .ok()?
+        .lines()
+        .next()?
+        .trim()
+        .parse::<u32>()
+        .ok()
+        .filter(|pid| *pid > 0)
+}
+
 fn spawn_session(command: &mut Command) -> std::io::Result<Child> {
     unsafe {
         command.pre_exec(|| {
@@ -1608,7 +2015,7 @@ fn spawn_session(command: &mut Command) -> std::io::Result<Child> {
 }
 
 /// Returns the environment override for bounded helper commands, or `fallback`.
-fn command_timeout(fallback: Duration) -> Duration {
+pub(crate) fn command_timeout(fallback: Duration) -> Duration {
     std::env::var("SYSG_PRE_START_TIMEOUT_SECS")
         .ok()
         .and_then(|raw| raw.trim().parse::<u64>().ok())
@@ -1736,6 +2143,39 @@ struct HealthRun {
     total_timeout: Option<Duration>,
 }
 
+/// How a stopped service went down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopOutcome {
+    /// Nothing was running, so there was nothing to signal.
+    NotRunning,
+    /// The process tree exited on SIGTERM within the grace window.
+    Graceful,
+    /// The process tree outlived the grace window and was sent SIGKILL.
+    Killed {
+        /// How long SIGTERM was given before escalating.
+        after: Duration,
+    },
+}
+
+impl fmt::Display for StopOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StopOutcome::NotRunning => f.write_str("stopped"),
+            StopOutcome::Graceful => f.write_str("stopped gracefully"),
+            StopOutcome::Killed { after } if after.subsec_millis() == 0 => write!(
+                f,
+                "forcibly killed after {}s (service ignored SIGTERM)",
+                after.as_secs()
+            ),
+            StopOutcome::Killed { after } => write!(
+                f,
+                "forcibly killed after {}ms (service ignored SIGTERM)",
+                after.as_millis()
+            ),
+        }
+    }
+}
+
 /// Indicates when a service is considered ready for dependents or has already completed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ServiceReadyState {
@@ -1753,12 +2193,28 @@ struct ManagedChild {
     pid: u32,
     /// Standard-library handle available before the first supervisor re-exec.
     child: Option<Child>,
+    /// Set for a `forking` service's daemon. It was reparented away from the
+    /// supervisor, so its exit is observed by liveness rather than `waitpid`.
+    forked: bool,
 }
 
 impl ManagedChild {
     /// Reconstructs a waitable handle after same-PID supervisor re-execution.
     fn adopt(pid: u32) -> Self {
-        Self { pid, child: None }
+        Self {
+            pid,
+            child: None,
+            forked: false,
+        }
+    }
+
+    /// Tracks the daemon a `forking` service left behind, read from its pidfile.
+    fn forked(pid: u32) -> Self {
+        Self {
+            pid,
+            child: None,
+            forked: true,
+        }
     }
 
     /// Returns the managed process identifier.
@@ -1771,6 +2227,9 @@ impl ManagedChild {
         if let Some(child) = self.child.as_mut() {
             return child.try_wait();
         }
+        if self.forked {
+            return self.try_wait_forked();
+        }
         self.wait_with_flags(libc::WNOHANG)
     }
 
@@ -1779,11 +2238,36 @@ impl ManagedChild {
         if let Some(child) = self.child.as_mut() {
             return child.wait();
         }
+        if self.forked {
+            loop {
+                if let Some(status) = self.try_wait_forked()? {
+                    return Ok(status);
+                }
+                thread::sleep(PROCESS_CHECK_INTERVAL);
+            }
+        }
         self.wait_with_flags(0)?.ok_or_else(|| {
             std::io::Error::other("blocking wait returned without a process status")
         })
     }
 
+    /// Observes a forked daemon. When the supervisor happens to be its reaper
+    /// (running as PID 1, say) the real status is collected; otherwise a
+    /// vanished daemon's status is unknowable and it is reported as exit 1,
+    /// since a daemon that disappears on its own has failed.
+    fn try_wait_forked(&self) -> std::io::Result<Option<ExitStatus>> {
+        match self.wait_with_flags(libc::WNOHANG) {
+            Ok(status) => return Ok(status),
+            Err(err) if err.raw_os_error() == Some(libc::ECHILD) => {}
+            Err(err) => return Err(err),
+        }
+        if Daemon::pid_is_alive(self.pid) {
+            Ok(None)
+        } else {
+            Ok(Some(ExitStatus::from_raw(1 << 8)))
+        }
+    }
+
     /// Calls `waitpid` for an adopted child using the supplied flags.
     fn wait_with_flags(&self, flags: libc::c_int) -> std::io::Result<Option<ExitStatus>> {
         let mut status = 0;
@@ -1805,6 +2289,7 @@ impl From<Child> for ManagedChild {
         Self {
             pid: child.id(),
             child: Some(child),
+            forked: false,
         }
     }
 }
@@ -1949,12 +2434,18 @@ struct DaemonContext {
     /// set of dependencies that felled them. A dependent is revived only once
     /// every felling dependency has recovered.
     stopped_for_dependency: Arc<Mutex<HashMap<String, HashSet<String>>>>,
+    /// Whether automatic restarts are paused for maintenance.
+    restarts_paused: Arc<AtomicBool>,
+    /// Services that crashed while restarts were paused, in crash order.
+    paused_crashes: Arc<Mutex<Vec<String>>>,
     /// Flag indicating whether the monitoring loop should remain active.
     running: Arc<AtomicBool>,
     /// Weak access to the monitor handle without creating a thread ownership cycle.
     monitor_handle: Weak<Mutex<Option<thread::JoinHandle<()>>>>,
     /// Pipe stderr to stdout.
     pipe_stderr: Arc<AtomicBool>,
+    /// Boot independent services of a dependency level concurrently.
+    parallel_start: Arc<AtomicBool>,
     /// Active boot generation shared with cancellation-aware lifecycle gates.
     boot_epoch: Arc<AtomicU64>,
     /// Whether the active project boot was explicitly cancelled.
@@ -1967,6 +2458,8 @@ struct DaemonContext {
     timeouts: Arc<RwLock<SupervisorTimeouts>>,
     /// Services currently being replaced through an explicit deployment strategy.
     replacements: Arc<Mutex<HashSet<String>>>,
+    /// Services with an explicit restart underway; a second one is refused.
+    restarting: Arc<Mutex<HashSet<String>>>,
     /// Cancellation tokens for Linux service generations.
     #[cfg(target_os = "linux")]
     thread_cancellation_tokens: CancelTokens,
@@ -2107,11 +2600,17 @@ pub struct Daemon {
     restart_in_flight: Arc<Mutex<HashSet<String>>>,
     /// Dependents stopped as casualties of a crashed dependency.
     stopped_for_dependency: Arc<Mutex<HashMap<String, HashSet<String>>>>,
+    /// Paused auto-restart flag.
+    restarts_paused: Arc<AtomicBool>,
+    /// Crashes recorded while paused.
+    paused_crashes: Arc<Mutex<Vec<String>>>,
     /// Linux thread cancellation.
     #[cfg(target_os = "linux")]
     thread_cancellation_tokens: CancelTokens,
     /// Pipe stderr to stdout.
     pipe_stderr: Arc<AtomicBool>,
+    /// Boot independent services of a dependency level concurrently.
+    parallel_start: Arc<AtomicBool>,
     /// Ownership sentinel: `Drop` only tears down the shared monitor/threads when
     /// the LAST clone is dropped. `Daemon` is `Clone` over shared `Arc`s, so a
     /// transient clone dropped mid-operation must never shut down the live daemon.
@@ -2123,6 +2622,8 @@ pub struct Daemon {
     boot_epoch: Arc<AtomicU64>,
     boot_cancelled: Arc<AtomicBool>,
     replacements: Arc<Mutex<HashSet<String>>>,
+    /// Explicit restarts underway.
+    restarting: Arc<Mutex<HashSet<String>>>,
 }
 
 impl Daemon {
@@ -2137,15 +2638,19 @@ impl Daemon {
     }
 
     /// Applies one service's resolved environment to a child command.
-    fn set_service_env(&self, command: &mut Command, service_name: &str) {
+    fn set_service_env(
+        &self,
+        command: &mut Command,
+        service_name: &str,
+    ) -> std::io::Result<()> {
         let config = self.cfg();
         let service = config.services.get(service_name);
         let env = service.and_then(|service| service.env.as_ref());
-        for (key, value) in
-            collect_service_env(&env.cloned(), &self.project_root, service_name)
-        {
-            command.env(key, value);
-        }
+        command.envs(collect_service_env(
+            &env.cloned(),
+            &self.project_root,
+            service_name,
+        )?);
         let strip = env.map(EnvConfig::vars_to_strip).unwrap_or_else(|| {
             SESSION_SCOPED_ENV_VARS
                 .iter()
@@ -2156,6 +2661,7 @@ impl Daemon {
             command.env_remove(key);
         }
         command.env("SYSG_SERVICE_NAME", service_name);
+        Ok(())
     }
 
     /// Creates context snapshot.
@@ -2172,15 +2678,19 @@ impl Daemon {
             restart_suppressed: Arc::clone(&self.restart_suppressed),
             restart_in_flight: Arc::clone(&self.restart_in_flight),
             stopped_for_dependency: Arc::clone(&self.stopped_for_dependency),
+            restarts_paused: Arc::clone(&self.restarts_paused),
+            paused_crashes: Arc::clone(&self.paused_crashes),
             running: Arc::clone(&self.running),
             monitor_handle: Arc::downgrade(&self.monitor_handle),
             pipe_stderr: Arc::clone(&self.pipe_stderr),
+            parallel_start: Arc::clone(&self.parallel_start),
             boot_epoch: Arc::clone(&self.boot_epoch),
             boot_cancelled: Arc::clone(&self.boot_cancelled),
             liveness: Arc::downgrade(&self.liveness),
             op_slot: self.op_slot.clone(),
             timeouts: Arc::clone(&self.timeouts),
             replacements: Arc::clone(&self.replacements),
+            restarting: Arc::clone(&self.restarting),
             #[cfg(target_os = "linux")]
             thread_cancellation_tokens: Arc::clone(&self.thread_cancellation_tokens),
         }
@@ -2205,15 +2715,19 @@ impl Daemon {
             restart_suppressed: Arc::clone(&ctx.restart_suppressed),
             restart_in_flight: Arc::clone(&ctx.restart_in_flight),
             stopped_for_dependency: Arc::clone(&ctx.stopped_for_dependency),
+            restarts_paused: Arc::clone(&ctx.restarts_paused),
+            paused_crashes: Arc::clone(&ctx.paused_crashes),
             #[cfg(target_os = "linux")]
             thread_cancellation_tokens: Arc::clone(&ctx.thread_cancellation_tokens),
             pipe_stderr: Arc::clone(&ctx.pipe_stderr),
+            parallel_start: Arc::clone(&ctx.parallel_start),
             liveness: ctx.liveness.upgrade()?,
             op_slot: ctx.op_slot.clone(),
             timeouts: Arc::clone(&ctx.timeouts),
             boot_epoch: Arc::clone(&ctx.boot_epoch),
             boot_cancelled: Arc::clone(&ctx.boot_cancelled),
             replacements: Arc::clone(&ctx.replacements),
+            restarting: Arc::clone(&ctx.restarting),
         })
     }
 
@@ -2293,32 +2807,135 @@ impl Daemon {
         members
     }
 
-    /// Returns all live process IDs currently assigned to `pgid`.
-    #[cfg(not(target_os = "linux"))]
-    fn collect_process_group_members(pgid: libc::pid_t) -> HashSet<u32> {
-        let mut members = HashSet::new();
-        let Ok(output) = Command::new("ps")
-            .args(["-axo", "pid=,pgid=,stat="])
-            .output()
-        else {
-            return members;
+    /// Returns live processes launched for `project/service_name` that no
+    /// longer share the session of `leader`. Every service leads its own
+    /// session, so a marked process elsewhere called `setsid` (typically with a
+    /// double fork) and is out of reach of the service's group signal.
+    #[cfg(target_os = "linux")]
+    fn escaped_session_members(
+        project: &str,
+        service_name: &str,
+        leader: u32,
+    ) -> Vec<u32> {
+        let marker = format!("{SERVICE_UNIT_ENV}={project}/{service_name}");
+        let leader_start = process_start_time(leader);
+        let mut escaped = Vec::new();
+        let Ok(entries) = fs::read_dir("/proc") else {
+            return escaped;
         };
 
-        for line in String::from_utf8_lossy(&output.stdout).lines() {
-            let mut fields = line.split_whitespace();
-            let Some(pid) = fields.next().and_then(|raw| raw.parse::<u32>().ok()) else {
+        for entry in entries.filter_map(Result::ok) {
+            let Some(pid) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse::<u32>().ok())
+            else {
                 continue;
             };
-            let Some(process_group) = fields
-                .next()
-                .and_then(|raw| raw.parse::<libc::pid_t>().ok())
+            if pid == leader {
+                continue;
+            }
+            let Ok(stat) = fs::read_to_string(entry.path().join("stat")) else {
+                continue;
+            };
+            let Some(close_paren) = stat.rfind(')') else {
+                continue;
+            };
+            // state, ppid, pgrp, session
+            let fields: Vec<&str> = stat[close_paren + 1..].split_whitespace().collect();
+            if fields
+                .first()
+                .is_some_and(|state| state.starts_with(['Z', 'X']))
+            {
+                continue;
+            }
+            let Some(session) = fields.get(3).and_then(|raw| raw.parse::<u32>().ok())
             else {
                 continue;
             };
-            let state = fields.next().and_then(|raw| raw.chars().next());
-
-            if process_group == pgid && !matches!(state, Some('Z')) {
-                members.insert(pid);
+            if session == leader {
+                continue;
+            }
+            // A process older than the leader belongs to an earlier instance.
+            let started = fields
+                .get(LINUX_PROC_START_TIME_INDEX)
+                .and_then(|raw| raw.parse::<u64>().ok());
+            if let (Some(started), Some(leader_start)) = (started, leader_start)
+                && started < leader_start
+            {
+                continue;
+            }
+            let Ok(environ) = fs::read(entry.path().join("environ")) else {
+                continue;
+            };
+            if environ
+                .split(|byte| *byte == 0)
+                .any(|var| var == marker.as_bytes())
+            {
+                escaped.push(pid);
+            }
+        }
+
+        escaped.sort_unstable();
+        escaped
+    }
+
+    /// Returns live processes launched for `project/service_name` that no
+    /// longer share the session of `leader`. Without procfs there is no cheap
+    /// way to read another process's environment, so nothing is reported.
+    #[cfg(not(target_os = "linux"))]
+    fn escaped_session_members(
+        _project: &str,
+        _service_name: &str,
+        _leader: u32,
+    ) -> Vec<u32> {
+        Vec::new()
+    }
+
+    /// Terminates processes that left a service's session, each with the
+    /// process group it now leads.
+    fn terminate_escaped_session_members(service_name: &str, escaped: &[u32]) {
+        let supervisor_group = unsafe { libc::getpgid(0) };
+        for &pid in escaped {
+            if !Self::pid_is_alive(pid) {
+                continue;
+            }
+            let group = Self::process_group_for_pid(pid)
+                .filter(|group| *group != supervisor_group);
+            if let Err(err) = Self::terminate_process_tree(service_name, pid, group) {
+                warn!(
+                    "Failed to terminate process {pid} that escaped the session of '{service_name}': {err}"
+                );
+            }
+        }
+    }
+
+    /// Returns all live process IDs currently assigned to `pgid`.
+    #[cfg(not(target_os = "linux"))]
+    fn collect_process_group_members(pgid: libc::pid_t) -> HashSet<u32> {
+        let mut members = HashSet::new();
+        let Ok(output) = Command::new("ps")
+            .args(["-axo", "pid=,pgid=,stat="])
+            .output()
+        else {
+            return members;
+        };
+
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            let mut fields = line.split_whitespace();
+            let Some(pid) = fields.next().and_then(|raw| raw.parse::<u32>().ok()) else {
+                continue;
+            };
+            let Some(process_group) = fields
+                .next()
+                .and_then(|raw| raw.parse::<libc::pid_t>().ok())
+            else {
+                continue;
+            };
+            let state = fields.next().and_then(|raw| raw.chars().next());
+
+            if process_group == pgid && !matches!(state, Some('Z')) {
+                members.insert(pid);
             }
         }
 
@@ -2452,6 +3069,23 @@ impl Daemon {
         root_pid: u32,
         group_hint: Option<libc::pid_t>,
     ) -> Result<(), ProcessManagerError> {
+        Self::terminate_process_tree_within(
+            service_name,
+            root_pid,
+            group_hint,
+            STOP_GRACE_PERIOD,
+        )
+        .map(|_| ())
+    }
+
+    /// [`Self::terminate_process_tree`] with an explicit SIGTERM grace window,
+    /// reporting whether the tree needed SIGKILL.
+    fn terminate_process_tree_within(
+        service_name: &str,
+        root_pid: u32,
+        group_hint: Option<libc::pid_t>,
+        grace: Duration,
+    ) -> Result<StopOutcome, ProcessManagerError> {
         use nix::sys::signal::Signal::{SIGKILL, SIGTERM};
 
         let mut pending = Self::collect_descendants(root_pid);
@@ -2506,20 +3140,28 @@ impl Daemon {
 
         merge_group_members(&mut pending);
 
+        let grace_checks = grace
+            .as_millis()
+            .div_ceil(PROCESS_CHECK_INTERVAL.as_millis())
+            .max(1) as usize;
         signal_group(SIGTERM as libc::c_int);
         pending = Self::send_signal_to_pids(service_name, pending, SIGTERM)?;
         pending = Self::wait_for_exit(
             service_name,
             pending,
-            PROCESS_READY_CHECKS,
+            grace_checks,
             PROCESS_CHECK_INTERVAL,
         )?;
         merge_group_members(&mut pending);
 
         if pending.is_empty() {
-            return Ok(());
+            return Ok(StopOutcome::Graceful);
         }
 
+        warn!(
+            "'{service_name}' ignored SIGTERM for {grace:?}; sending SIGKILL to its process tree"
+        );
+
         signal_group(SIGKILL as libc::c_int);
         pending = Self::send_signal_to_pids(service_name, pending, SIGKILL)?;
         pending = Self::wait_for_exit(
@@ -2537,7 +3179,7 @@ impl Daemon {
         )?;
 
         if pending.is_empty() {
-            Ok(())
+            Ok(StopOutcome::Killed { after: grace })
         } else {
             Err(ProcessManagerError::ServiceStopError {
                 service: service_name.to_string(),
@@ -2552,6 +3194,72 @@ impl Daemon {
         }
     }
 
+    /// Returns the live spawned descendants tracked under `root_pid` in the pid file. A child
+    /// started through `sysg spawn` is parented by the supervisor rather than the service and
+    /// may run in its own process group, so neither the `/proc` walk nor the service's group
+    /// signal reaches it. Services whose `spawn.limits.termination_policy` is `orphan` or
+    /// `reparent` opt out and report no descendants.
+    fn tracked_spawn_descendants(
+        service_name: &str,
+        root_pid: u32,
+        pid_file: &PidFile,
+        config: &Config,
+    ) -> Vec<u32> {
+        let policy = config
+            .services
+            .get(service_name)
+            .and_then(|service| service.spawn.as_ref())
+            .and_then(|spawn| spawn.limits.as_ref())
+            .and_then(|limits| limits.termination_policy.clone())
+            .unwrap_or(TerminationPolicy::Cascade);
+        if !matches!(policy, TerminationPolicy::Cascade) {
+            return Vec::new();
+        }
+
+        let nodes = crate::status::build_spawn_tree_from_pidfile(
+            pid_file, root_pid, None, true, None,
+        );
+        let mut tracked = HashSet::new();
+        crate::status::collect_tracked_pids(&nodes, &mut tracked);
+        let mut pids: Vec<u32> = tracked
+            .into_iter()
+            .filter(|pid| *pid != root_pid && Self::pid_is_alive(*pid))
+            .collect();
+        pids.sort_unstable();
+        pids
+    }
+
+    /// Terminates each tracked descendant whose process group differs from the service's own.
+    /// Descendants still in `service_group` were already covered by the group signal.
+    fn terminate_tracked_descendants(
+        service_name: &str,
+        descendants: &[u32],
+        service_group: Option<libc::pid_t>,
+    ) {
+        let supervisor_group = unsafe { libc::getpgid(0) };
+        let mut signalled = HashSet::new();
+        for &pid in descendants {
+            if !Self::pid_is_alive(pid) {
+                continue;
+            }
+            let Some(group) = Self::process_group_for_pid(pid) else {
+                continue;
+            };
+            if Some(group) == service_group
+                || group == supervisor_group
+                || !signalled.insert(group)
+            {
+                continue;
+            }
+            if let Err(err) = Self::terminate_process_tree(service_name, pid, Some(group))
+            {
+                warn!(
+                    "Failed to terminate spawned descendant {pid} (pgid {group}) of '{service_name}': {err}"
+                );
+            }
+        }
+    }
+
     /// Terminates any live members still lingering in a service's previous process group before
     /// it is restarted. When a wrapper shell exits while its real worker keeps running, the worker
     /// is reparented to PID 1 but retains the original process group. Without this cleanup a restart
@@ -2602,10 +3310,19 @@ impl Daemon {
         exit_code: Option<i32>,
         signal: Option<i32>,
     ) -> Result<(), ProcessManagerError> {
-        if config.services.contains_key(service_name) {
+        if let Some(service) = config.services.get(service_name) {
             let key = config.state_key(service_name);
+            let config_hash = matches!(status, ServiceLifecycleStatus::Running)
+                .then(|| service.compute_hash());
             let mut state_guard = state_file.lock()?;
-            state_guard.set(&key, status, pid, exit_code, signal)?;
+            state_guard.set_with_config_hash(
+                &key,
+                status,
+                pid,
+                exit_code,
+                signal,
+                config_hash.as_deref(),
+            )?;
         }
 
         Ok(())
@@ -2655,15 +3372,19 @@ impl Daemon {
             restart_suppressed: Arc::new(Mutex::new(HashSet::new())),
             restart_in_flight: Arc::new(Mutex::new(HashSet::new())),
             stopped_for_dependency: Arc::new(Mutex::new(HashMap::new())),
+            restarts_paused: Arc::new(AtomicBool::new(false)),
+            paused_crashes: Arc::new(Mutex::new(Vec::new())),
             #[cfg(target_os = "linux")]
             thread_cancellation_tokens: Arc::new(Mutex::new(HashMap::new())),
             pipe_stderr: Arc::new(AtomicBool::new(false)),
+            parallel_start: Arc::new(AtomicBool::new(false)),
             op_slot: OpSlot::new(),
             timeouts: Arc::new(RwLock::new(SupervisorTimeouts::default())),
             liveness: Arc::new(()),
             boot_epoch: Arc::new(AtomicU64::new(0)),
             boot_cancelled: Arc::new(AtomicBool::new(false)),
             replacements: Arc::new(Mutex::new(HashSet::new())),
+            restarting: Arc::new(Mutex::new(HashSet::new())),
         }
     }
 
@@ -2673,6 +3394,31 @@ impl Daemon {
         self.op_slot = op_slot;
     }
 
+    /// Points the daemon at the supervisor's shared pause flag so `sysg pause`
+    /// covers every registered project at once.
+    pub fn set_restart_pause(&mut self, restarts_paused: Arc<AtomicBool>) {
+        self.restarts_paused = restarts_paused;
+    }
+
+    /// Copies the crashes `previous` recorded while paused, so replacing a
+    /// project's runtime does not lose what `sysg resume` has to report.
+    pub fn carry_paused_crashes(&self, previous: &Daemon) {
+        let carried = previous
+            .paused_crashes
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone();
+        let mut crashed = self
+            .paused_crashes
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        for name in carried {
+            if !crashed.contains(&name) {
+                crashed.push(name);
+            }
+        }
+    }
+
     /// Applies the supervisor's lifecycle timeout policy to this daemon and all
     /// views cloned from it.
     pub fn set_timeouts(&self, timeouts: SupervisorTimeouts) {
@@ -2690,6 +3436,26 @@ impl Daemon {
             .clone()
     }
 
+    /// Returns how long `service` must stay running before it counts as ready:
+    /// its own `ready_stable_for`, or the supervisor's `startup_stability_ms`.
+    fn ready_stable_for(
+        &self,
+        service: &ServiceConfig,
+    ) -> Result<Duration, ProcessManagerError> {
+        Ok(service
+            .ready_stability()?
+            .unwrap_or_else(|| self.timeouts().startup_stability()))
+    }
+
+    /// Returns how long `service` may take to become ready: its own
+    /// `start_timeout`, or the built-in default.
+    fn start_timeout(
+        &self,
+        service: &ServiceConfig,
+    ) -> Result<Duration, ProcessManagerError> {
+        Ok(service.start_deadline()?.unwrap_or(SERVICE_START_TIMEOUT))
+    }
+
     /// Starts a new project boot epoch and returns its cancellation token.
     pub(crate) fn begin_boot(&self) -> u64 {
         self.boot_cancelled.store(false, Ordering::SeqCst);
@@ -2735,6 +3501,21 @@ impl Daemon {
         }
     }
 
+    /// Claims the restart of `name` until the returned guard drops. A restart
+    /// already underway keeps its claim and this one is refused, so two
+    /// restarts never interleave their stop, start, and rollback bookkeeping.
+    fn claim_restart(&self, name: &str) -> Result<InFlightGuard, ProcessManagerError> {
+        let claimed = self
+            .restarting
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .insert(name.to_string());
+        if !claimed {
+            return Err(ProcessManagerError::RestartInProgress(name.to_string()));
+        }
+        Ok(InFlightGuard::new(&self.restarting, name.to_string()))
+    }
+
     /// Convenience constructor that loads the PID file automatically.
     pub fn from_config(
         config: Config,
@@ -2751,6 +3532,17 @@ impl Daemon {
         self.pipe_stderr.store(pipe_stderr, Ordering::SeqCst);
     }
 
+    /// Sets whether a boot starts each dependency level's services concurrently
+    /// instead of one at a time.
+    pub fn set_parallel_start(&mut self, parallel_start: bool) {
+        self.parallel_start.store(parallel_start, Ordering::SeqCst);
+    }
+
+    /// Returns whether boots start dependency levels concurrently.
+    pub fn parallel_start(&self) -> bool {
+        self.parallel_start.load(Ordering::SeqCst)
+    }
+
     /// Returns a reference to the configuration.
     pub fn config(&self) -> Arc<Config> {
         self.cfg()
@@ -2785,6 +3577,7 @@ impl Daemon {
             match status {
                 Some(ServiceLifecycleStatus::Skipped) => {
                     matches!(service.skip, Some(SkipConfig::Command(_)))
+                        || service.condition.is_some()
                 }
                 Some(ServiceLifecycleStatus::ExitedSuccessfully) => false,
                 _ => true,
@@ -2794,6 +3587,7 @@ impl Daemon {
 
     /// Swaps the daemon's live config for a live reconcile.
     pub fn set_config(&self, config: Config) {
+        sockets::release_undeclared(&config);
         *self
             .config
             .lock()
@@ -2895,10 +3689,17 @@ impl Daemon {
                     ),
                 ));
             }
-            if processes
-                .insert(process.service.clone(), ManagedChild::adopt(process.pid))
-                .is_some()
+            let handle = if self
+                .cfg()
+                .services
+                .get(&process.service)
+                .is_some_and(ServiceConfig::is_forking)
             {
+                ManagedChild::forked(process.pid)
+            } else {
+                ManagedChild::adopt(process.pid)
+            };
+            if processes.insert(process.service.clone(), handle).is_some() {
                 return Err(Self::handoff_identity_error(
                     &process.service,
                     "handoff contains the service more than once",
@@ -3036,11 +3837,62 @@ impl Daemon {
         Arc::clone(&self.state_file)
     }
 
+    /// Whether `sysg disable` has marked `service` to be skipped.
+    pub fn is_service_disabled(&self, service: &str) -> bool {
+        let key = self.cfg().state_key(service);
+        self.state_file
+            .lock()
+            .map(|guard| guard.get(&key).is_some_and(|entry| entry.disabled))
+            .unwrap_or(false)
+    }
+
+    /// Persists `sysg disable`/`sysg enable` for `service`. Disabling also
+    /// records the service as skipped; the caller stops any running instance.
+    pub fn set_service_disabled(
+        &self,
+        service: &str,
+        disabled: bool,
+    ) -> Result<(), ProcessManagerError> {
+        let key = self.cfg().state_key(service);
+        self.state_file.lock()?.set_disabled(&key, disabled)?;
+        if disabled {
+            self.mark_skipped(service)?;
+        }
+        Ok(())
+    }
+
     /// Explicitly records a skipped service in the persistent state store, clearing any stale PID.
     pub fn mark_service_skipped(&self, service: &str) -> Result<(), ProcessManagerError> {
         self.mark_skipped(service)
     }
 
+    /// Explains why `service`'s `condition` block rules it out, or returns
+    /// `None` when every precondition holds.
+    pub fn unmet_condition(
+        &self,
+        service_name: &str,
+        service: &ServiceConfig,
+    ) -> Option<String> {
+        let condition = service.condition.as_ref()?;
+        let declared =
+            declared_service_env(&service.env, &self.project_root, service_name);
+        let stripped = service
+            .env
+            .as_ref()
+            .map(EnvConfig::vars_to_strip)
+            .unwrap_or_else(|| {
+                SESSION_SCOPED_ENV_VARS
+                    .iter()
+                    .map(|v| v.to_string())
+                    .collect()
+            });
+        condition.unmet(&self.project_root, |var| {
+            declared.contains_key(var)
+                || (std::env::var_os(var).is_some()
+                    && !stripped.iter().any(|key| key == var))
+        })
+    }
+
     /// Gets the configuration hash for a service by name.
     /// Returns None if the service doesn't exist in the config.
     pub fn get_service_hash(&self, service_name: &str) -> Option<String> {
@@ -3167,13 +4019,52 @@ impl Daemon {
         let command = &service_config.command;
         debug!("Launching service: '{service_name}' with command: `{command}`");
 
-        let mut cmd = Command::new(DEFAULT_SHELL);
-        cmd.arg(SHELL_COMMAND_FLAG).arg(command);
+        let launcher = service_config.command_launcher().map_err(|err| {
+            ProcessManagerError::ServiceStartError {
+                service: service_name.to_string(),
+                source: std::io::Error::new(ErrorKind::InvalidInput, err),
+            }
+        })?;
+        let mut argv: Vec<OsString> = match launcher {
+            CommandLauncher::Shell(shell) => {
+                vec![shell.into(), SHELL_COMMAND_FLAG.into(), command.into()]
+            }
+            CommandLauncher::Direct(argv) => argv.into_iter().map(Into::into).collect(),
+        };
+        if let Some(port) = taken_declared_port(project, service_config) {
+            return Err(Self::declared_port_error(service_name, port));
+        }
+        let socket_configs = service_config.sockets.as_deref().unwrap_or_default();
+        let listeners = sockets::acquire(project, socket_configs).map_err(|source| {
+            ProcessManagerError::ServiceStartError {
+                service: service_name.to_string(),
+                source,
+            }
+        })?;
+        if !listeners.is_empty() {
+            argv = sockets::with_listen_pid(argv);
+        }
+        let mut cmd = Command::new(&argv[0]);
+        cmd.args(&argv[1..]);
         cmd.current_dir(&working_dir);
 
         debug!("Executing command: {cmd:?}");
 
+        // Only output headed for the log file goes through a terminal; the
+        // foreground stderr relay and `sink: none` keep their pipes.
+        let mut pty_master = None;
         match log_settings.sink {
+            LogSink::File if service_config.uses_pty() && !pipe_stderr => {
+                let pty_error = |source| ProcessManagerError::ServiceStartError {
+                    service: service_name.to_string(),
+                    source,
+                };
+                let pty = pty::open().map_err(pty_error)?;
+                let stderr = pty.slave.try_clone().map_err(pty_error)?;
+                cmd.stdout(Stdio::from(pty.slave))
+                    .stderr(Stdio::from(stderr));
+                pty_master = Some(pty.master);
+            }
             LogSink::File => {
                 cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
             }
@@ -3188,7 +4079,11 @@ impl Daemon {
         }
 
         let mut merged_env =
-            collect_service_env(&service_config.env, &working_dir, service_name);
+            collect_service_env(&service_config.env, &working_dir, service_name)
+                .map_err(|source| ProcessManagerError::ServiceStartError {
+                    service: service_name.to_string(),
+                    source,
+                })?;
 
         let privilege = crate::privilege::PrivilegeContext::from_service(
             service_name,
@@ -3216,6 +4111,14 @@ impl Daemon {
                 .or_insert_with(|| DEFAULT_SERVICE_PATH.to_string());
         }
 
+        // `supervisor.env` belongs to the supervisor alone; a service sees one
+        // of those variables only when its own env declares it.
+        for key in runtime::supervisor_env_keys() {
+            if !merged_env.contains_key(&key) {
+                cmd.env_remove(key);
+            }
+        }
+
         if !merged_env.is_empty() {
             let keys: Vec<_> = merged_env.keys().cloned().collect();
             debug!("Setting environment variables: {:?}", keys);
@@ -3224,6 +4127,18 @@ impl Daemon {
             }
         }
 
+        cmd.env(SERVICE_UNIT_ENV, format!("{project}/{service_name}"));
+        for key in sockets::LISTEN_ENV_VARS {
+            cmd.env_remove(key);
+        }
+        if !listeners.is_empty() {
+            cmd.env(sockets::LISTEN_FDS_ENV, listeners.len().to_string());
+        }
+        let mut listen_fds: Vec<RawFd> = listeners
+            .iter()
+            .map(|listener| listener.as_raw_fd())
+            .collect();
+
         let to_strip = match &service_config.env {
             Some(env_config) => env_config.vars_to_strip(),
             None => SESSION_SCOPED_ENV_VARS
@@ -3239,6 +4154,25 @@ impl Daemon {
         }
 
         let privilege_clone = privilege.clone();
+        let umask = service_config
+            .umask_mode()
+            .map_err(|raw| Self::config_error(format!("Invalid umask value: '{raw}'")))?;
+
+        // A pidfile left by an earlier run could name a dead or recycled PID;
+        // clearing it means only the daemon launched now can be adopted.
+        let forking_pidfile = service_config
+            .is_forking()
+            .then(|| service_config.pidfile_path(&working_dir))
+            .flatten();
+        if let Some(pidfile) = forking_pidfile.as_deref() {
+            let _ = fs::remove_file(pidfile);
+        }
+        // A forking launcher exits right away, so only a service the supervisor
+        // tracks directly can keep a stdin pipe open for `sysg attach`.
+        let interactive = service_config.is_interactive() && forking_pidfile.is_none();
+        if interactive {
+            cmd.stdin(Stdio::piped());
+        }
 
         unsafe {
             cmd.pre_exec(move || {
@@ -3260,6 +4194,15 @@ impl Daemon {
                     return Err(err);
                 }
 
+                if let Some(mask) = umask {
+                    libc::umask(mask as libc::mode_t);
+                }
+
+                sockets::install_in_child(&mut listen_fds).map_err(|err| {
+                    eprintln!("systemg pre_exec: passing listen sockets failed: {err}");
+                    err
+                })?;
+
                 privilege_clone.apply_pre_exec().map_err(|err| {
                     eprintln!("systemg pre_exec: privilege setup failed: {}", err);
                     err
@@ -3267,13 +4210,20 @@ impl Daemon {
             });
         }
 
-        match cmd.spawn() {
+        let spawned = cmd.spawn();
+        // The parent's copies of the pty slave must close, or reading the
+        // master never sees the service go away.
+        drop(cmd);
+        match spawned {
             Ok(mut child) => {
                 let pid = child.id();
                 debug!("Service '{service_name}' started with PID: {pid}");
 
                 let stdout = child.stdout.take();
                 let stderr = child.stderr.take();
+                if interactive && let Some(stdin) = child.stdin.take() {
+                    attach::register(project, service_name, stdin);
+                }
 
                 let log_result = if pipe_stderr {
                     if let Some(err) = stderr {
@@ -3298,6 +4248,14 @@ impl Daemon {
                     } else {
                         Ok(())
                     }
+                } else if let Some(master) = pty_master {
+                    spawn_managed_service_log_writers(
+                        project,
+                        service_name,
+                        Some(master),
+                        stderr,
+                        log_settings,
+                    )
                 } else {
                     spawn_managed_service_log_writers(
                         project,
@@ -3320,9 +4278,19 @@ impl Daemon {
                     });
                 }
 
-                processes
-                    .lock()?
-                    .insert(service_name.to_string(), child.into());
+                let (pid, handle) = match forking_pidfile.as_deref() {
+                    Some(pidfile) => {
+                        let daemon_pid =
+                            Self::await_forked_daemon(service_name, child, pidfile)?;
+                        debug!(
+                            "Service '{service_name}' daemonized; tracking PID {daemon_pid} from {}",
+                            pidfile.display()
+                        );
+                        (daemon_pid, ManagedChild::forked(daemon_pid))
+                    }
+                    None => (pid, child.into()),
+                };
+                processes.lock()?.insert(service_name.to_string(), handle);
 
                 if let Err(err) = privilege.apply_post_spawn(pid as libc::pid_t) {
                     warn!(
@@ -3345,6 +4313,70 @@ impl Daemon {
         }
     }
 
+    /// Waits for a `forking` service's launcher to exit cleanly, then returns the
+    /// live daemon PID it wrote to `pidfile`. The launcher's own exit is the
+    /// expected handoff, not a crash, so it is reaped here and never reaches the
+    /// monitor.
+    fn await_forked_daemon(
+        service_name: &str,
+        mut launcher: Child,
+        pidfile: &Path,
+    ) -> Result<u32, ProcessManagerError> {
+        let start_error = |message: String| ProcessManagerError::ServiceStartError {
+            service: service_name.to_string(),
+            source: std::io::Error::other(message),
+        };
+        let deadline = Instant::now() + FORKING_STARTUP_TIMEOUT;
+
+        loop {
+            match launcher.try_wait() {
+                Ok(Some(status)) if status.success() => break,
+                Ok(Some(status)) => {
+                    return Err(start_error(format!(
+                        "launcher exited with {status} before daemonizing"
+                    )));
+                }
+                Ok(None) if Instant::now() >= deadline => {
+                    let launcher_pid = launcher.id();
+                    let _ = Self::terminate_process_tree(
+                        service_name,
+                        launcher_pid,
+                        Some(launcher_pid as libc::pid_t),
+                    );
+                    let _ = launcher.wait();
+                    return Err(start_error(format!(
+                        "launcher did not exit within {}s; a 'forking' service must \
+                         fork into the background and exit",
+                        FORKING_STARTUP_TIMEOUT.as_secs()
+                    )));
+                }
+                Ok(None) => thread::sleep(PROCESS_CHECK_INTERVAL),
+                Err(source) => {
+                    return Err(ProcessManagerError::ServiceStartError {
+                        service: service_name.to_string(),
+                        source,
+                    });
+                }
+            }
+        }
+
+        loop {
+            if let Some(pid) = read_pidfile(pidfile)
+                && Self::pid_is_alive(pid)
+            {
+                return Ok(pid);
+            }
+            if Instant::now() >= deadline {
+                return Err(start_error(format!(
+                    "no live PID appeared in {} within {}s",
+                    pidfile.display(),
+                    FORKING_STARTUP_TIMEOUT.as_secs()
+                )));
+            }
+            thread::sleep(PROCESS_CHECK_INTERVAL);
+        }
+    }
+
     /// Launches a Linux service from a dedicated lifetime thread so `PR_SET_PDEATHSIG`
     /// remains tied to a live parent until the service is explicitly stopped.
     #[cfg(target_os = "linux")]
@@ -3570,6 +4602,12 @@ impl Daemon {
             stopped.remove(name);
         }
 
+        if self.is_service_disabled(name) {
+            info!("Skipping service '{name}': disabled with `sysg disable`");
+            self.mark_skipped(name)?;
+            return Ok(Some(ServiceReadyState::CompletedSuccess));
+        }
+
         if let Some(skip_config) = &service.skip {
             match skip_config {
                 SkipConfig::Flag(true) => {
@@ -3603,6 +4641,12 @@ impl Daemon {
             }
         }
 
+        if let Some(reason) = self.unmet_condition(name, service) {
+            info!("Skipping service '{name}': {reason}");
+            self.mark_skipped(name)?;
+            return Ok(Some(ServiceReadyState::CompletedSuccess));
+        }
+
         if let Some(pre_start) = service
             .deployment
             .as_ref()
@@ -3676,9 +4720,37 @@ impl Daemon {
         // depends on it must not start, or `skip` silently leaks the dependent
         // into running against a dependency that never came up.
         let mut skipped_services = HashSet::new();
-        let mut first_error: Option<ProcessManagerError> = None;
+        let mut failures: Vec<(String, ProcessManagerError)> = Vec::new();
+        let abort = config.on_failure == StartFailureMode::Abort;
+        let settle = config.dependency_settle()?;
+        // When each service started or completed, so its dependents can wait
+        // out `dependency_settle` from that moment.
+        let mut ready_at: HashMap<String, Instant> = HashMap::new();
+        // Services left running by this start, in launch order, so a critical
+        // failure can stop them again.
+        let mut started = Vec::new();
+        let is_critical = |name: &str| {
+            config
+                .services
+                .get(name)
+                .is_some_and(ServiceConfig::is_critical)
+        };
 
         'service_loop: for service_name in order {
+            if abort && !failures.is_empty() {
+                info!(
+                    "Not starting '{service_name}' or later services: start aborted after a failure (on_failure: abort)"
+                );
+                break 'service_loop;
+            }
+            if let Some((critical, _)) =
+                failures.iter().find(|(name, _)| is_critical(name))
+            {
+                info!(
+                    "Not starting '{service_name}' or later services: critical service '{critical}' failed"
+                );
+                break 'service_loop;
+            }
             let service = match config.services.get(&service_name) {
                 Some(service) => service,
                 None => continue,
@@ -3694,6 +4766,13 @@ impl Daemon {
                 continue 'service_loop;
             }
 
+            if self.is_service_disabled(&service_name) {
+                info!("Skipping service '{service_name}': disabled with `sysg disable`");
+                self.mark_skipped(&service_name)?;
+                skipped_services.insert(service_name.clone());
+                continue 'service_loop;
+            }
+
             if let Some(skip_config) = &service.skip {
                 match skip_config {
                     SkipConfig::Flag(true) => {
@@ -3726,9 +4805,7 @@ impl Daemon {
                                 error!(
                                     "Failed to evaluate skip condition for '{service_name}': {err}"
                                 );
-                                if first_error.is_none() {
-                                    first_error = Some(err);
-                                }
+                                failures.push((service_name.clone(), err));
                                 failed_services.insert(service_name.clone());
                                 continue 'service_loop;
                             }
@@ -3737,6 +4814,13 @@ impl Daemon {
                 }
             }
 
+            if let Some(reason) = self.unmet_condition(&service_name, service) {
+                info!("Skipping service '{service_name}': {reason}");
+                self.mark_skipped(&service_name)?;
+                skipped_services.insert(service_name.clone());
+                continue 'service_loop;
+            }
+
             if let Some(deps) = &service.depends_on {
                 for dep in deps {
                     let dep_name = dep.service();
@@ -3755,12 +4839,13 @@ impl Daemon {
                         error!(
                             "Skipping start of '{service_name}' because dependency '{dep_name}' failed."
                         );
-                        if first_error.is_none() {
-                            first_error = Some(ProcessManagerError::DependencyFailed {
+                        failures.push((
+                            service_name.clone(),
+                            ProcessManagerError::DependencyFailed {
                                 service: service_name.clone(),
                                 dependency: dep_name.to_string(),
-                            });
-                        }
+                            },
+                        ));
                         failed_services.insert(service_name.clone());
                         continue 'service_loop;
                     }
@@ -3769,12 +4854,13 @@ impl Daemon {
                         error!(
                             "Skipping start of '{service_name}' because dependency '{dep_name}' is not running."
                         );
-                        if first_error.is_none() {
-                            first_error = Some(ProcessManagerError::DependencyError {
+                        failures.push((
+                            service_name.clone(),
+                            ProcessManagerError::DependencyError {
                                 service: service_name.clone(),
                                 dependency: dep_name.to_string(),
-                            });
-                        }
+                            },
+                        ));
                         failed_services.insert(service_name.clone());
                         continue 'service_loop;
                     }
@@ -3788,13 +4874,12 @@ impl Daemon {
                             error!(
                                 "Skipping start of '{service_name}' because dependency '{dep_name}' did not complete: {err}"
                             );
-                            if first_error.is_none() {
-                                first_error = Some(err);
-                            }
+                            failures.push((service_name.clone(), err));
                             failed_services.insert(service_name.clone());
                             continue 'service_loop;
                         }
                         completed_services.insert(dep_name.to_string());
+                        ready_at.insert(dep_name.to_string(), Instant::now());
                     }
                     let completed = completed_services.contains(dep_name);
                     let running = healthy_services.contains(dep_name) && !completed;
@@ -3806,47 +4891,171 @@ impl Daemon {
                         error!(
                             "Skipping start of '{service_name}' because dependency '{dep_name}' did not reach its target."
                         );
-                        first_error.get_or_insert(
+                        failures.push((
+                            service_name.clone(),
                             ProcessManagerError::DependencyFailed {
                                 service: service_name.clone(),
                                 dependency: dep_name.to_string(),
                             },
-                        );
+                        ));
                         failed_services.insert(service_name.clone());
                         continue 'service_loop;
                     }
                 }
             }
 
+            let last_ready = service
+                .depends_on
+                .iter()
+                .flatten()
+                .filter_map(|dep| ready_at.get(dep.service()))
+                .max();
+            if let Some(&last_ready) = last_ready
+                && !settle.is_zero()
+                && let Err(err) =
+                    self.settle_dependencies(&service_name, last_ready + settle)
+            {
+                failures.push((service_name.clone(), err));
+                failed_services.insert(service_name.clone());
+                continue 'service_loop;
+            }
+
             let mut service_to_start = service.clone();
             service_to_start.skip = None;
+            service_to_start.condition = None;
             match self.start_service(&service_name, &service_to_start) {
                 Ok(ServiceReadyState::Running) => {
                     healthy_services.insert(service_name.clone());
+                    ready_at.insert(service_name.clone(), Instant::now());
+                    started.push(service_name.clone());
                 }
                 Ok(ServiceReadyState::CompletedSuccess) => {
                     info!("Service '{service_name}' completed successfully.");
                     healthy_services.insert(service_name.clone());
                     completed_services.insert(service_name.clone());
+                    ready_at.insert(service_name.clone(), Instant::now());
                 }
                 Err(err) => {
                     error!("Failed to start service '{service_name}': {err}");
-                    if first_error.is_none() {
-                        first_error = Some(err);
-                    }
+                    failures.push((service_name.clone(), err));
                     failed_services.insert(service_name.clone());
                 }
             }
         }
 
-        if let Some(err) = first_error {
+        if let Some(index) = failures.iter().position(|(name, _)| is_critical(name)) {
+            let (critical, err) = failures.swap_remove(index);
+            self.roll_back_start(&critical, &started);
+            self.run_start_barrier_hook(false);
             return Err(err);
         }
 
-        info!("All services started successfully.");
-
-        thread::sleep(Duration::from_millis(200));
-        Ok(())
+        self.run_start_barrier_hook(failures.is_empty());
+        if failures.len() > 1 {
+            return Err(ProcessManagerError::ServicesFailedToStart {
+                failures: failures
+                    .into_iter()
+                    .map(|(service, err)| format!("{service}: {err}"))
+                    .collect(),
+            });
+        }
+        if let Some((_, err)) = failures.pop() {
+            return Err(err);
+        }
+
+        info!("All services started successfully.");
+
+        thread::sleep(Duration::from_millis(200));
+        Ok(())
+    }
+
+    /// Holds back the launch of `service_name` until `until`, the moment its
+    /// last dependency became ready plus `dependency_settle`. Returns early
+    /// with an error when the project start is cancelled meanwhile.
+    pub(crate) fn settle_dependencies(
+        &self,
+        service_name: &str,
+        until: Instant,
+    ) -> Result<(), ProcessManagerError> {
+        let epoch = self.boot_epoch.load(Ordering::SeqCst);
+        debug!("Letting dependencies of '{service_name}' settle before it starts");
+        loop {
+            if self.boot_cancelled() || !self.boot_active(epoch) {
+                return Err(Self::interrupted(service_name));
+            }
+            let remaining = until.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Ok(());
+            }
+            thread::sleep(remaining.min(SERVICE_POLL_INTERVAL));
+        }
+    }
+
+    /// Stops the services a start already launched, newest first, because
+    /// critical service `critical` failed and the project must not stay half-up.
+    pub(crate) fn roll_back_start(&self, critical: &str, started: &[String]) {
+        for service_name in started.iter().rev() {
+            warn!(
+                "Stopping '{service_name}' because critical service '{critical}' failed to start."
+            );
+            if let Err(err) = self.stop_service(service_name) {
+                error!(
+                    "Failed to stop '{service_name}' while rolling back the start: {err}"
+                );
+            }
+        }
+    }
+
+    /// Runs the project's `on_ready` hook once a full start brought every
+    /// service up (or skipped it), or `on_start_failure` when any failed. Both
+    /// get the top-level env rather than any one service's.
+    pub fn run_start_barrier_hook(&self, all_started: bool) {
+        let config = self.cfg();
+        let (stage, outcome, action) = if all_started {
+            (HookStage::OnReady, HookOutcome::Success, &config.on_ready)
+        } else {
+            (
+                HookStage::OnStartFailure,
+                HookOutcome::Error,
+                &config.on_start_failure,
+            )
+        };
+        if let Some(action) = action {
+            run_hook(
+                action,
+                &config.env,
+                stage,
+                outcome,
+                &config.project.id,
+                &self.project_root,
+                Some((&self.boot_epoch, &self.boot_cancelled)),
+            );
+        }
+    }
+
+    /// Runs `command` the way a skip condition or health-check command runs for
+    /// `service_name` — same shell, working directory, and environment — and
+    /// returns its captured output. Nothing about the service is recorded.
+    pub fn probe_command(
+        &self,
+        service_name: &str,
+        command: &str,
+        timeout: Duration,
+    ) -> std::io::Result<std::process::Output> {
+        let mut cmd = Command::new(DEFAULT_SHELL);
+        cmd.arg(SHELL_COMMAND_FLAG).arg(command);
+        cmd.current_dir(&self.project_root);
+        self.set_service_env(&mut cmd, service_name)?;
+        output_with_timeout(&mut cmd, timeout, command, None)
+    }
+
+    /// Returns the PID of the service's recorded process when it is still alive.
+    pub fn running_pid(&self, service_name: &str) -> Option<u32> {
+        self.pid_file
+            .lock()
+            .ok()?
+            .pid_for(service_name)
+            .filter(|pid| Self::pid_is_alive(*pid))
     }
 
     /// Evaluates a skip condition command for a service.
@@ -3867,7 +5076,11 @@ impl Daemon {
         cmd.arg(SHELL_COMMAND_FLAG).arg(skip_command);
         cmd.current_dir(&self.project_root);
         cmd.stdout(Stdio::null()).stderr(Stdio::null());
-        self.set_service_env(&mut cmd, service_name);
+        self.set_service_env(&mut cmd, service_name)
+            .map_err(|source| ProcessManagerError::ServiceStartError {
+                service: service_name.to_string(),
+                source,
+            })?;
 
         let mut child = spawn_session(&mut cmd).map_err(|source| {
             ProcessManagerError::ServiceStartError {
@@ -3943,15 +5156,22 @@ impl Daemon {
     ) -> Result<ServiceReadyState, ProcessManagerError> {
         let config = self.cfg();
         let epoch = self.boot_epoch.load(Ordering::SeqCst);
-        let state = Self::wait_for_ready(
-            service_name,
-            &self.processes,
-            &self.pid_file,
-            (&self.state_file, &config),
-            Some((&self.boot_epoch, epoch, &self.boot_cancelled)),
-            self.timeouts().startup_stability(),
-            started_at,
-        )?;
+        let start_timeout = self.start_timeout(service)?;
+        let state = match service.ready_log_pattern.as_deref() {
+            Some(pattern) => {
+                self.wait_for_ready_log(service_name, pattern, start_timeout, started_at)?
+            }
+            None => Self::wait_for_ready(
+                service_name,
+                &self.processes,
+                &self.pid_file,
+                (&self.state_file, &config),
+                Some((&self.boot_epoch, epoch, &self.boot_cancelled)),
+                start_timeout,
+                self.ready_stable_for(service)?,
+                started_at,
+            )?,
+        };
 
         if let ServiceReadyState::Running = state
             && let Some(health_check) = service
@@ -3972,8 +5192,11 @@ impl Daemon {
                 warn!(
                     "Service '{service_name}' failed its health check; stopping it (not leaving a never-healthy process)"
                 );
-                if let Err(stop_err) = self.stop_service_with_intent(service_name, false)
-                {
+                if let Err(stop_err) = self.stop_service_with_intent(
+                    service_name,
+                    false,
+                    self.stop_grace(service_name),
+                ) {
                     warn!(
                         "Failed to stop '{service_name}' after health-check failure: {stop_err}"
                     );
@@ -3985,6 +5208,30 @@ impl Daemon {
         Ok(state)
     }
 
+    /// Builds the diagnostic for a service not launched because another
+    /// process already holds one of its declared `ports`.
+    fn declared_port_error(service_name: &str, port: u16) -> ProcessManagerError {
+        let holder = match crate::reconcile::port_holder(port) {
+            Some(pid) => format!("pid {pid} is listening on port {port}"),
+            None => format!("another process is listening on port {port}"),
+        };
+        let diag = crate::diag::Diagnostic::error(
+            crate::diag::SgCode::PortInUse,
+            format!("service `{service_name}` not started: port {port} already in use"),
+        )
+        .note(format!(
+            "{holder}, so systemg did not launch a service that declares it"
+        ))
+        .note("stop whatever holds the port, or change this service's `ports`")
+        .help_cmd("see what sysg manages", "sysg status")
+        .help_cmd(
+            "retry once the port is free",
+            format!("sysg restart -s {service_name}"),
+        )
+        .help_docs();
+        ProcessManagerError::Diag(Box::new(diag))
+    }
+
     /// Builds a port-conflict diagnostic when startup output or ownership
     /// shows that another process holds the service's declared port.
     fn startup_port_error(
@@ -4117,12 +5364,14 @@ impl Daemon {
 
     /// Polls explicit process and state handles until one service reaches a
     /// running, completed, or failed startup state.
+    #[allow(clippy::too_many_arguments)]
     fn wait_for_ready(
         service_name: &str,
         processes: &Arc<Mutex<HashMap<String, ManagedChild>>>,
         pid_file: &Arc<Mutex<PidFile>>,
         state: (&Arc<Mutex<ServiceStateFile>>, &Arc<Config>),
         epoch: Option<(&AtomicU64, u64, &AtomicBool)>,
+        start_timeout: Duration,
         startup_stability: Duration,
         started_at: chrono::DateTime<chrono::Utc>,
     ) -> Result<ServiceReadyState, ProcessManagerError> {
@@ -4133,7 +5382,9 @@ impl Daemon {
             .and_then(|service| port_from_command(Some(&service.command)));
         let mut waited = Duration::ZERO;
         let mut running_since = None;
-        while waited <= SERVICE_START_TIMEOUT {
+        // The start timeout bounds reaching a running state; a long stability
+        // window extends the wait instead of eating into it.
+        while waited <= start_timeout + startup_stability {
             if epoch.is_some_and(|(current, expected, cancelled)| {
                 cancelled.load(Ordering::SeqCst)
                     || current.load(Ordering::SeqCst) != expected
@@ -4196,6 +5447,103 @@ impl Daemon {
         })
     }
 
+    /// Waits for the service to write a line matching its `ready_log_pattern`,
+    /// failing if it exits first or stays silent past `start_timeout`. A
+    /// service that never logs the line is stopped rather than left running
+    /// as if it were ready.
+    fn wait_for_ready_log(
+        &self,
+        service_name: &str,
+        pattern: &str,
+        start_timeout: Duration,
+        started_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<ServiceReadyState, ProcessManagerError> {
+        let pattern = Regex::new(pattern).map_err(|err| {
+            ProcessManagerError::ServiceStartError {
+                service: service_name.to_string(),
+                source: std::io::Error::new(ErrorKind::InvalidInput, err.to_string()),
+            }
+        })?;
+        let config = self.cfg();
+        let project = config.project.id.clone();
+        let epoch = self.boot_epoch.load(Ordering::SeqCst);
+        info!("Waiting for '{service_name}' to log a line matching /{pattern}/");
+        self.op_slot.detail_for(
+            &project,
+            format!("waiting for '{service_name}' to log /{pattern}/"),
+        );
+
+        // Watch first, then check what was logged before the watch existed, so
+        // a line written in between is caught by one or the other.
+        let watch = crate::logs::watch_ready_log(&project, service_name, pattern.clone());
+        let _ = sync_service_log(&project, service_name);
+        let already_logged = crate::logs::tail_service_log_since(
+            &project,
+            service_name,
+            READY_LOG_BACKLOG_LINES,
+            started_at,
+        )
+        .iter()
+        .any(|line| pattern.is_match(line));
+
+        let deadline = Instant::now() + start_timeout;
+        let mut matched = already_logged;
+        while !matched {
+            if self.boot_cancelled() || !self.boot_active(epoch) {
+                return Err(Self::interrupted(service_name));
+            }
+            if let ServiceProbe::Exited(status) = Self::probe_service_state_recording(
+                service_name,
+                &self.processes,
+                &self.pid_file,
+                Some((&self.state_file, &config)),
+            )? {
+                if status.success() {
+                    return Ok(ServiceReadyState::CompletedSuccess);
+                }
+                return Err(Self::startup_exit_error(
+                    service_name,
+                    status,
+                    &config,
+                    started_at,
+                ));
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+            matched = watch.wait(remaining.min(SERVICE_POLL_INTERVAL));
+        }
+        if matched {
+            info!("Service '{service_name}' logged its ready line");
+            return Ok(ServiceReadyState::Running);
+        }
+
+        warn!(
+            "Service '{service_name}' did not log a line matching /{pattern}/ within {}s; stopping it",
+            start_timeout.as_secs_f64()
+        );
+        if let Err(err) = self.stop_service_with_intent(
+            service_name,
+            false,
+            self.stop_grace(service_name),
+        ) {
+            warn!(
+                "Failed to stop '{service_name}' after its ready line timed out: {err}"
+            );
+        }
+        Err(ProcessManagerError::ServiceStartError {
+            service: service_name.to_string(),
+            source: std::io::Error::new(
+                ErrorKind::TimedOut,
+                format!(
+                    "no output matched ready_log_pattern /{pattern}/ within {}s",
+                    start_timeout.as_secs_f64()
+                ),
+            ),
+        })
+    }
+
     /// Blocks until a `condition: completed` dependency exits cleanly.
     ///
     /// Polls the dependency's process without a timeout — builds and migrations can
@@ -4579,6 +5927,7 @@ impl Daemon {
 
         let config = self.cfg();
         let order = config.service_start_order()?;
+        let max_parallel = config.deployment.max_parallel();
         self.shutdown_monitor();
         let mut restarted_services = Vec::new();
         let mut healthy_services = HashSet::new();
@@ -4586,168 +5935,228 @@ impl Daemon {
         let mut failed_services = HashSet::new();
         let mut skipped_services = HashSet::new();
         let mut first_error = None;
-
-        'services: for service_name in order {
-            if !services.contains(&service_name) {
-                continue;
-            }
-            let service = match config.services.get(&service_name) {
-                Some(service) => service,
-                None => continue,
-            };
-
-            if service.cron.is_some() {
-                info!(
-                    "Skipping cron-managed service '{}' during restart; scheduled execution will launch it",
-                    service_name
-                );
-                completed_services.insert(service_name.clone());
-                continue;
-            }
-
-            let should_skip = match &service.skip {
-                Some(SkipConfig::Flag(value)) => Ok(*value),
-                Some(SkipConfig::Command(command)) => {
-                    self.evaluate_skip_condition(&service_name, command)
-                }
-                None => Ok(false),
-            };
-            match should_skip {
-                Ok(true) => {
-                    if let Err(err) = self.stop_service(&service_name) {
-                        first_error.get_or_insert(err);
-                        failed_services.insert(service_name.clone());
+        let mut pending: Vec<String> = order
+            .into_iter()
+            .filter(|name| services.contains(name))
+            .collect();
+        let mut in_flight = HashSet::new();
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        // A worker pool of `max_parallel` restarts. A service is admitted only
+        // once everything it starts after in this restart has settled, so a
+        // service and its `depends_on`/`after` predecessors are never
+        // mid-restart together.
+        thread::scope(|scope| {
+            loop {
+                let mut index = 0;
+                'services: while in_flight.len() < max_parallel && index < pending.len() {
+                    let service_name = pending[index].clone();
+                    let service = match config.services.get(&service_name) {
+                        Some(service) => service,
+                        None => {
+                            pending.remove(index);
+                            continue;
+                        }
+                    };
+                    let blocked =
+                        config.start_predecessors(&service_name).any(|dep_name| {
+                            in_flight.contains(dep_name)
+                                || pending.iter().any(|name| name.as_str() == dep_name)
+                        });
+                    if blocked {
+                        index += 1;
                         continue;
                     }
-                    if let Err(err) = self.mark_skipped(&service_name) {
-                        first_error.get_or_insert(err);
-                        failed_services.insert(service_name.clone());
+                    pending.remove(index);
+
+                    if service.cron.is_some() {
+                        info!(
+                            "Skipping cron-managed service '{}' during restart; scheduled execution will launch it",
+                            service_name
+                        );
+                        completed_services.insert(service_name.clone());
                         continue;
                     }
-                    skipped_services.insert(service_name.clone());
-                    continue;
-                }
-                Ok(false) => {}
-                Err(err) => {
-                    first_error.get_or_insert(err);
-                    failed_services.insert(service_name.clone());
-                    continue;
-                }
-            }
 
-            if let Some(deps) = &service.depends_on {
-                for dep in deps {
-                    let dep_name = dep.service();
-                    let dep_skipped = skipped_services.contains(dep_name)
-                        || matches!(
-                            self.recorded_status(dep_name),
-                            Some(ServiceLifecycleStatus::Skipped)
-                        );
-                    if dep_skipped {
-                        if let Err(err) = self.stop_service(&service_name) {
-                            first_error.get_or_insert(err);
-                            failed_services.insert(service_name.clone());
-                        } else if let Err(err) = self.mark_skipped(&service_name) {
-                            first_error.get_or_insert(err);
-                            failed_services.insert(service_name.clone());
-                        } else {
-                            skipped_services.insert(service_name.clone());
+                    let should_skip = match &service.skip {
+                        Some(SkipConfig::Flag(value)) => Ok(*value),
+                        Some(SkipConfig::Command(command)) => {
+                            self.evaluate_skip_condition(&service_name, command)
                         }
-                        continue 'services;
-                    }
-                    if failed_services.contains(dep_name)
-                        || (services.contains(dep_name)
-                            && !healthy_services.contains(dep_name))
-                    {
-                        let err = ProcessManagerError::DependencyFailed {
-                            service: service_name.clone(),
-                            dependency: dep_name.to_string(),
-                        };
-                        first_error.get_or_insert(err);
-                        failed_services.insert(service_name.clone());
-                        continue 'services;
+                        None => Ok(false),
                     }
-                    if !services.contains(dep_name) {
-                        if !self.dependency_ready(dep) {
-                            let err = ProcessManagerError::DependencyFailed {
-                                service: service_name.clone(),
-                                dependency: dep_name.to_string(),
-                            };
-                            first_error.get_or_insert(err);
-                            failed_services.insert(service_name.clone());
-                            continue 'services;
+                    .map(|skip| {
+                        skip || self.unmet_condition(&service_name, service).is_some()
+                    });
+                    match should_skip {
+                        Ok(true) => {
+                            if let Err(err) = self.stop_service(&service_name) {
+                                first_error.get_or_insert(err);
+                                failed_services.insert(service_name.clone());
+                                continue;
+                            }
+                            if let Err(err) = self.mark_skipped(&service_name) {
+                                first_error.get_or_insert(err);
+                                failed_services.insert(service_name.clone());
+                                continue;
+                            }
+                            skipped_services.insert(service_name.clone());
+                            continue;
                         }
-                        continue;
-                    }
-                    if dep.condition() == DependsOnCondition::Completed
-                        && !completed_services.contains(dep_name)
-                    {
-                        if let Err(err) =
-                            self.wait_for_dependency_completion(&service_name, dep_name)
-                        {
-                            error!(
-                                "Failed to restart '{service_name}' because dependency '{dep_name}' did not complete: {err}"
-                            );
+                        Ok(false) => {}
+                        Err(err) => {
                             first_error.get_or_insert(err);
                             failed_services.insert(service_name.clone());
-                            continue 'services;
+                            continue;
                         }
-                        completed_services.insert(dep_name.to_string());
                     }
-                    let completed = completed_services.contains(dep_name);
-                    let running = healthy_services.contains(dep_name) && !completed;
-                    let finite = config
-                        .services
-                        .get(dep_name)
-                        .is_some_and(|dependency| !dependency.restarts_after_failure());
-                    if !Self::dependency_satisfied(dep, running, completed, finite) {
-                        let err = ProcessManagerError::DependencyFailed {
-                            service: service_name.clone(),
-                            dependency: dep_name.to_string(),
-                        };
-                        first_error.get_or_insert(err);
-                        failed_services.insert(service_name.clone());
-                        continue 'services;
+
+                    if let Some(deps) = &service.depends_on {
+                        for dep in deps {
+                            let dep_name = dep.service();
+                            let dep_skipped = skipped_services.contains(dep_name)
+                                || matches!(
+                                    self.recorded_status(dep_name),
+                                    Some(ServiceLifecycleStatus::Skipped)
+                                );
+                            if dep_skipped {
+                                if let Err(err) = self.stop_service(&service_name) {
+                                    first_error.get_or_insert(err);
+                                    failed_services.insert(service_name.clone());
+                                } else if let Err(err) = self.mark_skipped(&service_name)
+                                {
+                                    first_error.get_or_insert(err);
+                                    failed_services.insert(service_name.clone());
+                                } else {
+                                    skipped_services.insert(service_name.clone());
+                                }
+                                continue 'services;
+                            }
+                            if failed_services.contains(dep_name)
+                                || (services.contains(dep_name)
+                                    && !healthy_services.contains(dep_name))
+                            {
+                                let err = ProcessManagerError::DependencyFailed {
+                                    service: service_name.clone(),
+                                    dependency: dep_name.to_string(),
+                                };
+                                first_error.get_or_insert(err);
+                                failed_services.insert(service_name.clone());
+                                continue 'services;
+                            }
+                            if !services.contains(dep_name) {
+                                if !self.dependency_ready(dep) {
+                                    let err = ProcessManagerError::DependencyFailed {
+                                        service: service_name.clone(),
+                                        dependency: dep_name.to_string(),
+                                    };
+                                    first_error.get_or_insert(err);
+                                    failed_services.insert(service_name.clone());
+                                    continue 'services;
+                                }
+                                continue;
+                            }
+                            if dep.condition() == DependsOnCondition::Completed
+                                && !completed_services.contains(dep_name)
+                            {
+                                if let Err(err) = self.wait_for_dependency_completion(
+                                    &service_name,
+                                    dep_name,
+                                ) {
+                                    error!(
+                                        "Failed to restart '{service_name}' because dependency '{dep_name}' did not complete: {err}"
+                                    );
+                                    first_error.get_or_insert(err);
+                                    failed_services.insert(service_name.clone());
+                                    continue 'services;
+                                }
+                                completed_services.insert(dep_name.to_string());
+                            }
+                            let completed = completed_services.contains(dep_name);
+                            let running =
+                                healthy_services.contains(dep_name) && !completed;
+                            let finite =
+                                config.services.get(dep_name).is_some_and(|dependency| {
+                                    !dependency.restarts_after_failure()
+                                });
+                            if !Self::dependency_satisfied(
+                                dep, running, completed, finite,
+                            ) {
+                                let err = ProcessManagerError::DependencyFailed {
+                                    service: service_name.clone(),
+                                    dependency: dep_name.to_string(),
+                                };
+                                first_error.get_or_insert(err);
+                                failed_services.insert(service_name.clone());
+                                continue 'services;
+                            }
+                        }
                     }
+
+                    let strategy_str = service
+                        .deployment
+                        .as_ref()
+                        .and_then(|deployment| deployment.strategy.as_deref());
+
+                    let strategy = strategy_str
+                        .and_then(|s| DeploymentStrategy::from_str(s).ok())
+                        .unwrap_or_default();
+
+                    let mut service_to_start = service.clone();
+                    service_to_start.skip = None;
+                    service_to_start.condition = None;
+                    in_flight.insert(service_name.clone());
+                    let tx = tx.clone();
+                    scope.spawn(move || {
+                        let result = std::panic::catch_unwind(
+                            std::panic::AssertUnwindSafe(|| {
+                                let _restart = self.claim_restart(&service_name)?;
+                                match strategy {
+                                    DeploymentStrategy::Rolling => self
+                                        .rolling_restart_service(
+                                            &service_name,
+                                            &service_to_start,
+                                        ),
+                                    DeploymentStrategy::Immediate => self
+                                        .immediate_restart_service(
+                                            &service_name,
+                                            &service_to_start,
+                                            false,
+                                        ),
+                                }
+                            }),
+                        );
+                        let _ = tx.send((service_name, result));
+                    });
                 }
-            }
 
-            let strategy_str = service
-                .deployment
-                .as_ref()
-                .and_then(|deployment| deployment.strategy.as_deref());
-
-            let strategy = strategy_str
-                .and_then(|s| DeploymentStrategy::from_str(s).ok())
-                .unwrap_or_default();
-
-            let mut service_to_start = service.clone();
-            service_to_start.skip = None;
-            let result = match strategy {
-                DeploymentStrategy::Rolling => {
-                    self.rolling_restart_service(&service_name, &service_to_start)
-                }
-                DeploymentStrategy::Immediate => {
-                    self.immediate_restart_service(&service_name, &service_to_start)
-                }
-            };
-            match result {
-                Ok(ServiceReadyState::CompletedSuccess) => {
-                    healthy_services.insert(service_name.clone());
-                    completed_services.insert(service_name.clone());
-                    restarted_services.push(service_name);
-                }
-                Ok(ServiceReadyState::Running) => {
-                    healthy_services.insert(service_name.clone());
-                    restarted_services.push(service_name);
+                if in_flight.is_empty() {
+                    break;
                 }
-                Err(err) => {
-                    error!("Failed to restart '{service_name}': {err}");
-                    first_error.get_or_insert(err);
-                    failed_services.insert(service_name);
+                let Ok((service_name, result)) = rx.recv() else {
+                    break;
+                };
+                in_flight.remove(&service_name);
+                let result =
+                    result.unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+                match result {
+                    Ok(ServiceReadyState::CompletedSuccess) => {
+                        healthy_services.insert(service_name.clone());
+                        completed_services.insert(service_name.clone());
+                        restarted_services.push(service_name);
+                    }
+                    Ok(ServiceReadyState::Running) => {
+                        healthy_services.insert(service_name.clone());
+                        restarted_services.push(service_name);
+                    }
+                    Err(err) => {
+                        error!("Failed to restart '{service_name}': {err}");
+                        first_error.get_or_insert(err);
+                        failed_services.insert(service_name);
+                    }
                 }
             }
-        }
+        });
 
         if let Err(err) = self.spawn_monitor_thread() {
             first_error.get_or_insert(err);
@@ -4766,12 +6175,16 @@ impl Daemon {
         }
     }
 
-    /// Restarts a single service, honoring its deployment strategy.
+    /// Restarts a single service, honoring its deployment strategy. With
+    /// `build_first`, an immediate restart runs `pre_start` before stopping
+    /// the old instance; a rolling restart always builds while it serves.
     pub fn restart_service(
         &self,
         name: &str,
         service: &ServiceConfig,
+        build_first: bool,
     ) -> Result<(), ProcessManagerError> {
+        let _restart = self.claim_restart(name)?;
         let strategy_str = service
             .deployment
             .as_ref()
@@ -4784,7 +6197,7 @@ impl Daemon {
         let start_state = match strategy {
             DeploymentStrategy::Rolling => self.rolling_restart_service(name, service)?,
             DeploymentStrategy::Immediate => {
-                self.immediate_restart_service(name, service)?
+                self.immediate_restart_service(name, service, build_first)?
             }
         };
 
@@ -4799,12 +6212,98 @@ impl Daemon {
         Ok(())
     }
 
+    /// Restarts `name` in the background because its RSS stayed above `limit`.
+    ///
+    /// Skipped while another restart of the service is in flight, so the guard
+    /// never races the monitor's own restart policy.
+    pub(crate) fn restart_for_memory(&self, name: &str, rss_bytes: u64, limit: u64) {
+        if !self.running.load(Ordering::SeqCst) {
+            return;
+        }
+        let Some(service) = self.cfg().services.get(name).cloned() else {
+            return;
+        };
+        {
+            let mut in_flight = self
+                .restart_in_flight
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            if !in_flight.insert(name.to_string()) {
+                return;
+            }
+        }
+
+        warn!(
+            "memory-triggered restart: service '{name}' RSS {rss_bytes} bytes stayed above {limit} bytes"
+        );
+        let daemon = self.clone();
+        let service_name = name.to_string();
+        if let Err(err) = thread::Builder::new()
+            .name(format!("sysg-restart-{name}"))
+            .spawn(move || {
+                let _in_flight =
+                    InFlightGuard::new(&daemon.restart_in_flight, service_name.clone());
+                if let Err(err) = daemon.restart_service(&service_name, &service, false) {
+                    error!("memory-triggered restart of '{service_name}' failed: {err}");
+                }
+            })
+        {
+            error!("Failed to spawn memory-triggered restart for '{name}': {err}");
+            self.restart_in_flight
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .remove(name);
+        }
+    }
+
     /// Performs a rolling restart keeping the previous instance alive until the replacement is
-    /// verified healthy.
+    /// verified healthy. The service's deployment hooks run before the attempt and after it
+    /// either succeeds or rolls back.
     fn rolling_restart_service(
         &self,
         name: &str,
         service: &ServiceConfig,
+    ) -> Result<ServiceReadyState, ProcessManagerError> {
+        self.run_deploy_hook(name, service, HookStage::OnDeployStart);
+        let result = self.replace_service_generation(name, service);
+        let stage = if result.is_ok() {
+            HookStage::OnDeploySuccess
+        } else {
+            HookStage::OnDeployFailure
+        };
+        self.run_deploy_hook(name, service, stage);
+        result
+    }
+
+    /// Runs a service's deployment hook for `stage`, if one is configured.
+    fn run_deploy_hook(&self, name: &str, service: &ServiceConfig, stage: HookStage) {
+        let outcome = match stage {
+            HookStage::OnDeployFailure => HookOutcome::Error,
+            _ => HookOutcome::Success,
+        };
+        if let Some(action) = service
+            .hooks
+            .as_ref()
+            .and_then(|cfg| cfg.action(stage, outcome))
+        {
+            run_hook(
+                action,
+                &service.env,
+                stage,
+                outcome,
+                name,
+                &self.project_root,
+                None,
+            );
+        }
+    }
+
+    /// Replaces the running instance with a new generation, keeping the old one
+    /// until the replacement is verified and restoring it if verification fails.
+    fn replace_service_generation(
+        &self,
+        name: &str,
+        service: &ServiceConfig,
     ) -> Result<ServiceReadyState, ProcessManagerError> {
         if let Some(blue_green) = service
             .deployment
@@ -4826,7 +6325,7 @@ impl Daemon {
             info!(
                 "Service '{name}' uses configured port {port}; switching to immediate restart semantics."
             );
-            return self.immediate_restart_service(name, service);
+            return self.immediate_restart_service(name, service, false);
         }
 
         let previous = self.detach_service_handle(name)?;
@@ -4852,7 +6351,7 @@ impl Daemon {
             };
             let observation = grace
                 .unwrap_or_default()
-                .max(self.timeouts().startup_stability());
+                .max(self.ready_stable_for(service)?);
             if !observation.is_zero() {
                 info!(
                     "Observing replacement for {:?} before stopping previous instance of '{name}'",
@@ -4967,7 +6466,8 @@ impl Daemon {
             .ok()
             .and_then(|processes| processes.get(name).map(ManagedChild::id));
         if current == Some(pid)
-            && let Err(err) = self.stop_service_with_intent(name, false)
+            && let Err(err) =
+                self.stop_service_with_intent(name, false, self.stop_grace(name))
         {
             warn!("Failed to stop replacement generation of '{name}': {err}");
         }
@@ -5103,7 +6603,7 @@ impl Daemon {
 
         let observation = grace
             .unwrap_or_default()
-            .max(self.timeouts().startup_stability());
+            .max(self.ready_stable_for(service)?);
         if let Err(err) = self.wait_for_generation(
             name,
             candidate_pid,
@@ -5147,15 +6647,33 @@ impl Daemon {
     }
 
     /// Performs an immediate restart by stopping and starting the service sequentially.
+    ///
+    /// With `build_first`, the service's `pre_start` runs before the stop, so
+    /// the old instance keeps serving during the build and is left running if
+    /// the build fails.
     fn immediate_restart_service(
         &self,
         name: &str,
         service: &ServiceConfig,
+        build_first: bool,
     ) -> Result<ServiceReadyState, ProcessManagerError> {
         info!("Performing immediate restart for service: {name}");
 
-        self.stop_service_with_intent(name, false)?;
-        let start_state = self.start_service(name, service)?;
+        let pre_start = service
+            .deployment
+            .as_ref()
+            .and_then(|deployment| deployment.pre_start.as_deref());
+        let mut service = Cow::Borrowed(service);
+        if build_first && let Some(pre_start) = pre_start {
+            info!("Building '{name}' before stopping it: {pre_start}");
+            self.run_pre_start_command(name, pre_start)?;
+            if let Some(deployment) = service.to_mut().deployment.as_mut() {
+                deployment.pre_start = None;
+            }
+        }
+
+        self.stop_service_with_intent(name, false, self.stop_grace(name))?;
+        let start_state = self.start_service(name, &service)?;
 
         if let ServiceReadyState::CompletedSuccess = start_state {
             info!("Service '{name}' completed successfully immediately after restart.");
@@ -5189,7 +6707,11 @@ impl Daemon {
             .current_dir(&self.project_root)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped());
-        self.set_service_env(&mut cmd, service_name);
+        self.set_service_env(&mut cmd, service_name)
+            .map_err(|source| ProcessManagerError::ServiceStartError {
+                service: service_name.to_string(),
+                source,
+            })?;
         let mut child = spawn_session(&mut cmd).map_err(|source| {
             ProcessManagerError::ServiceStartError {
                 service: service_name.to_string(),
@@ -5463,6 +6985,27 @@ impl Daemon {
         Ok(())
     }
 
+    /// Delay before the attempt that follows failed attempt number `attempt`.
+    /// Exponential backoff starts at `interval` and doubles up to `max_interval`.
+    fn health_retry_delay(
+        interval: Duration,
+        backoff: HealthBackoff,
+        max_interval: Duration,
+        attempt: u32,
+    ) -> Duration {
+        match backoff {
+            HealthBackoff::Fixed => interval,
+            HealthBackoff::Exponential => {
+                let factor = 1u32
+                    .checked_shl(attempt.saturating_sub(1))
+                    .unwrap_or(u32::MAX);
+                interval
+                    .saturating_mul(factor)
+                    .min(max_interval.max(interval))
+            }
+        }
+    }
+
     /// Waits for the configured health check to report success before completing the rolling
     /// restart.
     fn wait_for_health_check(
@@ -5491,29 +7034,43 @@ impl Daemon {
             .interval
             .as_deref()
             .map_or(Ok(DEFAULT_HEALTH_INTERVAL), Self::parse_duration)?;
+        let backoff = health_check.backoff.unwrap_or_default();
+        let max_interval = health_check
+            .max_interval
+            .as_deref()
+            .map_or(Ok(DEFAULT_HEALTH_MAX_INTERVAL), Self::parse_duration)?;
+        #[cfg(feature = "http-health")]
         let client = if health_check.url.is_some() {
             // A health check is a DIRECT probe to the service — never route it
             // through an HTTP proxy. reqwest reads HTTP_PROXY/ALL_PROXY from the
             // environment by default, which made a probe to 127.0.0.1 hang for
             // the full attempt_timeout (the proxy can't reach localhost) while
             // `curl` — which bypasses the proxy for localhost — succeeded at once.
-            Some(
-                Client::builder()
-                    .timeout(attempt_timeout)
-                    .no_proxy()
-                    .build()
-                    .map_err(|err| ProcessManagerError::ServiceStartError {
-                        service: service_name.to_string(),
-                        source: std::io::Error::other(err.to_string()),
-                    })?,
-            )
+            let connect_timeout = health_check
+                .connect_timeout
+                .as_deref()
+                .map(Self::parse_duration)
+                .transpose()?;
+            let mut builder = Client::builder().timeout(attempt_timeout).no_proxy();
+            if let Some(connect_timeout) = connect_timeout {
+                builder = builder.connect_timeout(connect_timeout);
+            }
+            Some(builder.build().map_err(|err| {
+                ProcessManagerError::ServiceStartError {
+                    service: service_name.to_string(),
+                    source: std::io::Error::other(err.to_string()),
+                }
+            })?)
         } else {
             None
         };
+        #[cfg(not(feature = "http-health"))]
+        let client: Option<Client> = None;
 
         let mut last_outcome: HealthProbeOutcome;
         let started_at = Instant::now();
         let mut attempt = 0u32;
+        let mut refused_streak = 0u32;
 
         loop {
             attempt = attempt.saturating_add(1);
@@ -5546,12 +7103,19 @@ impl Daemon {
                 &self.cfg().project.id.clone(),
                 format!("health check for '{service_name}' ({progress})"),
             );
-            match self.perform_configured_health_check(
+            let probe = self.perform_configured_health_check(
                 service_name,
                 health_check,
                 client.as_ref(),
                 attempt_timeout,
-            ) {
+            );
+            refused_streak = match &probe {
+                Err(err) if err.kind() == ErrorKind::ConnectionRefused => {
+                    refused_streak.saturating_add(1)
+                }
+                _ => 0,
+            };
+            match probe {
                 Ok(true) => {
                     info!(
                         "Health check passed for '{service_name}' on attempt {attempt}"
@@ -5582,6 +7146,16 @@ impl Daemon {
                 return Err(Self::interrupted(service_name));
             }
 
+            if health_check
+                .max_refused
+                .is_some_and(|limit| refused_streak >= limit)
+            {
+                warn!(
+                    "Health check for '{service_name}' was refused {refused_streak} times in a row; giving up early",
+                );
+                break;
+            }
+
             let elapsed = started_at.elapsed();
             let retry_floor_pending = attempt < retries;
             let budget_remaining = total_timeout
@@ -5590,10 +7164,11 @@ impl Daemon {
             if !retry_floor_pending && budget_remaining.is_none() {
                 break;
             }
+            let step = Self::health_retry_delay(interval, backoff, max_interval, attempt);
             let delay = if retry_floor_pending {
-                interval
+                step
             } else {
-                interval.min(budget_remaining.unwrap_or_default())
+                step.min(budget_remaining.unwrap_or_default())
             };
             if !self.wait_boot_delay(epoch, delay) {
                 return Err(Self::interrupted(service_name));
@@ -5728,9 +7303,13 @@ impl Daemon {
             self.perform_command_health_check(service_name, command, timeout)
         } else if let Some(url) = &health_check.url {
             let client = client.ok_or_else(|| {
-                std::io::Error::other("HTTP health check client was not initialized")
+                std::io::Error::other(if cfg!(feature = "http-health") {
+                    "HTTP health check client was not initialized"
+                } else {
+                    HTTP_HEALTH_DISABLED
+                })
             })?;
-            self.perform_http_health_check(service_name, client, url)
+            self.perform_http_health_check(service_name, client, url, timeout)
         } else {
             Err(std::io::Error::other(
                 "health check requires either a command or a url",
@@ -5739,12 +7318,15 @@ impl Daemon {
     }
 
     /// Performs a single health check request and evaluates the response.
+    #[cfg(feature = "http-health")]
     fn perform_health_check(client: &Client, url: &str) -> Result<bool, std::io::Error> {
         let response = client.get(url).send().map_err(|err| {
             let kind = if err.is_timeout() {
                 ErrorKind::TimedOut
-            } else {
+            } else if err.is_connect() {
                 ErrorKind::ConnectionRefused
+            } else {
+                ErrorKind::Other
             };
             std::io::Error::new(kind, err.to_string())
         })?;
@@ -5752,11 +7334,28 @@ impl Daemon {
         Ok(response.status().is_success())
     }
 
+    #[cfg(not(feature = "http-health"))]
+    fn perform_http_health_check(
+        &self,
+        _service_name: &str,
+        client: &Client,
+        _url: &str,
+        _timeout: Duration,
+    ) -> Result<bool, std::io::Error> {
+        match *client {}
+    }
+
+    /// Runs one HTTP probe on its own thread and gives up once `timeout` has
+    /// passed on the wall clock, even if the request itself never returns (a
+    /// connect or DNS lookup that hangs past the client's own timeouts). The
+    /// abandoned worker finishes on its own; its late result is dropped.
+    #[cfg(feature = "http-health")]
     fn perform_http_health_check(
         &self,
         service_name: &str,
         client: &Client,
         url: &str,
+        timeout: Duration,
     ) -> Result<bool, std::io::Error> {
         use std::sync::mpsc;
 
@@ -5771,6 +7370,7 @@ impl Daemon {
             .map_err(|err| std::io::Error::other(err.to_string()))?;
 
         let epoch = self.boot_epoch.load(Ordering::SeqCst);
+        let deadline = Instant::now() + timeout;
         loop {
             if self.boot_cancelled() || !self.boot_active(epoch) {
                 return Err(std::io::Error::new(
@@ -5778,7 +7378,17 @@ impl Daemon {
                     format!("health check for '{service_name}' was cancelled"),
                 ));
             }
-            match rx.recv_timeout(SERVICE_POLL_INTERVAL) {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(std::io::Error::new(
+                    ErrorKind::TimedOut,
+                    format!(
+                        "health check for '{service_name}' did not answer within {}ms",
+                        timeout.as_millis()
+                    ),
+                ));
+            }
+            match rx.recv_timeout(remaining.min(SERVICE_POLL_INTERVAL)) {
                 Ok(result) => return result,
                 Err(mpsc::RecvTimeoutError::Timeout) => {}
                 Err(mpsc::RecvTimeoutError::Disconnected) => {
@@ -5812,7 +7422,7 @@ impl Daemon {
         let mut child = Command::new(DEFAULT_SHELL);
         child.arg(SHELL_COMMAND_FLAG).arg(command);
         child.current_dir(&self.project_root);
-        self.set_service_env(&mut child, service_name);
+        self.set_service_env(&mut child, service_name)?;
         child.stdout(Stdio::null());
         child.stderr(Stdio::null());
         let mut child = spawn_session(&mut child)?;
@@ -5849,13 +7459,20 @@ impl Daemon {
         }
     }
 
-    /// Parses a user-facing duration string in the format `<number>[s|m|h]`.
-    fn parse_duration(raw: &str) -> Result<Duration, ProcessManagerError> {
+    /// Parses a user-facing duration string in the format `<number>[ms|s|m|h]`.
+    pub(crate) fn parse_duration(raw: &str) -> Result<Duration, ProcessManagerError> {
         let value = raw.trim();
         if value.is_empty() {
             return Err(Self::config_error("Duration value cannot be empty"));
         }
 
+        if let Some(stripped) = value.strip_suffix("ms") {
+            let millis: u64 = stripped.trim().parse().map_err(|_| {
+                Self::config_error(format!("Invalid duration value: '{raw}'"))
+            })?;
+            return Ok(Duration::from_millis(millis));
+        }
+
         let (amount_str, multiplier) = if let Some(stripped) = value.strip_suffix('s') {
             (stripped.trim(), 1)
         } else if let Some(stripped) = value.strip_suffix('m') {
@@ -5907,9 +7524,13 @@ impl Daemon {
             url: health_check.url.as_deref().map(render),
             command: health_check.command.as_deref().map(render),
             interval: health_check.interval.clone(),
+            backoff: health_check.backoff,
+            max_interval: health_check.max_interval.clone(),
             attempt_timeout: health_check.attempt_timeout.clone(),
+            connect_timeout: health_check.connect_timeout.clone(),
             total_timeout: health_check.total_timeout.clone(),
             retries: health_check.retries,
+            max_refused: health_check.max_refused,
         }
     }
 
@@ -6033,7 +7654,11 @@ impl Daemon {
         cmd.env("SYSG_ACTIVE_SLOT", active_slot);
         cmd.env("SYSG_CANDIDATE_SLOT", candidate_slot);
         cmd.env("SYSG_SERVICE_NAME", service_name);
-        self.set_service_env(&mut cmd, service_name);
+        self.set_service_env(&mut cmd, service_name)
+            .map_err(|source| ProcessManagerError::ServiceStartError {
+                service: service_name.to_string(),
+                source,
+            })?;
 
         let epoch = self.boot_epoch.load(Ordering::SeqCst);
         let output = output_with_timeout(
@@ -6069,6 +7694,34 @@ impl Daemon {
         true
     }
 
+    /// Re-verifies services after a restart has returned, for `restart --wait`.
+    ///
+    /// Skipped services, cron jobs, and one-shots are left out; the rest must
+    /// pass the same observation as [`Self::restart_service`]. Returns the names
+    /// that were verified.
+    pub(crate) fn verify_restarted(
+        &self,
+        services: &[String],
+    ) -> Result<Vec<String>, ProcessManagerError> {
+        let config = self.cfg();
+        let verifiable = services
+            .iter()
+            .filter(|name| {
+                config
+                    .services
+                    .get(name.as_str())
+                    .is_some_and(Self::should_verify_service)
+                    && !matches!(
+                        self.recorded_status(name),
+                        Some(ServiceLifecycleStatus::Skipped)
+                    )
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        self.verify_services_running(&verifiable, &HashSet::new())?;
+        Ok(verifiable)
+    }
+
     /// Verifies that restarted services reach a valid terminal target.
     ///
     /// A service satisfies the restart when it remains running across the
@@ -6236,10 +7889,10 @@ impl Daemon {
         Ok(())
     }
 
-    /// Starts a service on Unix and macOS using the shared startup path, then
-    /// waits for the launch thread to report the initial PID registration
-    /// result before performing readiness checks.
-    #[cfg(not(target_os = "linux"))]
+    /// Starts a service using the shared startup path, then launches it and waits
+    /// for readiness. A failed launch or readiness check is retried up to the
+    /// service's `start_retries`, pausing `start_retry_delay` between attempts;
+    /// `on_start` hooks fire once, for the final outcome.
     pub fn start_service(
         &self,
         name: &str,
@@ -6249,6 +7902,59 @@ impl Daemon {
             return Ok(state);
         }
 
+        let retries = service.start_retries.unwrap_or(0);
+        let delay = service
+            .start_retry_pause()?
+            .unwrap_or(DEFAULT_START_RETRY_DELAY);
+        let mut attempt = 0;
+        let result = loop {
+            match self.launch_until_ready(name, service) {
+                Err(err) if attempt < retries && !self.boot_cancelled() => {
+                    attempt += 1;
+                    warn!(
+                        "Starting '{name}' failed: {err}; retry {attempt}/{retries} in {delay:?}"
+                    );
+                    thread::sleep(delay);
+                    if self.boot_cancelled() {
+                        break Err(Self::interrupted(name));
+                    }
+                }
+                result => break result,
+            }
+        };
+
+        let outcome = if result.is_ok() {
+            HookOutcome::Success
+        } else {
+            HookOutcome::Error
+        };
+        if let Some(action) = service
+            .hooks
+            .as_ref()
+            .and_then(|cfg| cfg.action(HookStage::OnStart, outcome))
+        {
+            run_hook(
+                action,
+                &service.env,
+                HookStage::OnStart,
+                outcome,
+                name,
+                &self.project_root,
+                Some((&self.boot_epoch, &self.boot_cancelled)),
+            );
+        }
+        result
+    }
+
+    /// Launches one instance on Unix and macOS, waiting for the launch thread to
+    /// report the initial PID registration result before performing readiness
+    /// checks.
+    #[cfg(not(target_os = "linux"))]
+    fn launch_until_ready(
+        &self,
+        name: &str,
+        service: &ServiceConfig,
+    ) -> Result<ServiceReadyState, ProcessManagerError> {
         let started_at = chrono::Utc::now();
         let processes = Arc::clone(&self.processes);
         let service_config = service.clone();
@@ -6305,132 +8011,43 @@ impl Daemon {
 
         debug!("Service launch thread for '{name}' completed");
 
-        match launch_result {
-            Ok(pid) => {
-                self.mark_running(name, pid)?;
-            }
-            Err(err) => {
-                if let Some(action) = service
-                    .hooks
-                    .as_ref()
-                    .and_then(|cfg| cfg.action(HookStage::OnStart, HookOutcome::Error))
-                {
-                    run_hook(
-                        action,
-                        &service.env,
-                        HookStage::OnStart,
-                        HookOutcome::Error,
-                        name,
-                        &self.project_root,
-                        Some((&self.boot_epoch, &self.boot_cancelled)),
-                    );
-                }
-                return Err(err);
-            }
-        }
-
-        let readiness = self.wait_for_service_ready(name, service, started_at);
+        let pid = launch_result?;
+        self.mark_running(name, pid)?;
 
-        match readiness {
-            Ok(state) => {
-                if matches!(state, ServiceReadyState::CompletedSuccess) {
-                    self.update_state(
-                        name,
-                        ServiceLifecycleStatus::ExitedSuccessfully,
-                        None,
-                        Some(0),
-                        None,
-                    )?;
-                }
-                if let Some(action) = service
-                    .hooks
-                    .as_ref()
-                    .and_then(|cfg| cfg.action(HookStage::OnStart, HookOutcome::Success))
-                {
-                    run_hook(
-                        action,
-                        &service.env,
-                        HookStage::OnStart,
-                        HookOutcome::Success,
-                        name,
-                        &self.project_root,
-                        Some((&self.boot_epoch, &self.boot_cancelled)),
-                    );
-                }
-                Ok(state)
-            }
-            Err(err) => {
-                if let Some(action) = service
-                    .hooks
-                    .as_ref()
-                    .and_then(|cfg| cfg.action(HookStage::OnStart, HookOutcome::Error))
-                {
-                    run_hook(
-                        action,
-                        &service.env,
-                        HookStage::OnStart,
-                        HookOutcome::Error,
-                        name,
-                        &self.project_root,
-                        Some((&self.boot_epoch, &self.boot_cancelled)),
-                    );
-                }
-                Err(err)
-            }
+        let state = self.wait_for_service_ready(name, service, started_at)?;
+        if matches!(state, ServiceReadyState::CompletedSuccess) {
+            self.update_state(
+                name,
+                ServiceLifecycleStatus::ExitedSuccessfully,
+                None,
+                Some(0),
+                None,
+            )?;
         }
+        Ok(state)
     }
 
-    /// Starts a service on Linux using the shared startup path and keeps the
-    /// launcher thread alive so `PR_SET_PDEATHSIG` remains tied to a live
-    /// parent until cancellation.
+    /// Launches one instance on Linux and keeps the launcher thread alive so
+    /// `PR_SET_PDEATHSIG` remains tied to a live parent until cancellation.
     #[cfg(target_os = "linux")]
-    pub fn start_service(
+    fn launch_until_ready(
         &self,
         name: &str,
         service: &ServiceConfig,
     ) -> Result<ServiceReadyState, ProcessManagerError> {
-        if let Some(state) = self.start_service_common(name, service)? {
-            return Ok(state);
-        }
-
         let started_at = chrono::Utc::now();
         let ctx = self.context();
         let config = self.cfg();
         let log_settings = service.effective_logs(&config.logs);
-        let launch_result = Self::launch_service_with_lifetime_thread(
+        let pid = Self::launch_service_with_lifetime_thread(
             &ctx,
             name.to_string(),
             service.clone(),
             log_settings,
-        );
-
-        let pid =
-            match launch_result {
-                Ok(pid) => {
-                    self.mark_running(name, pid)?;
-                    pid
-                }
-                Err(err) => {
-                    if let Some(action) = service.hooks.as_ref().and_then(|cfg| {
-                        cfg.action(HookStage::OnStart, HookOutcome::Error)
-                    }) {
-                        run_hook(
-                            action,
-                            &service.env,
-                            HookStage::OnStart,
-                            HookOutcome::Error,
-                            name,
-                            &self.project_root,
-                            Some((&self.boot_epoch, &self.boot_cancelled)),
-                        );
-                    }
-                    return Err(err);
-                }
-            };
-
-        let readiness = self.wait_for_service_ready(name, service, started_at);
+        )?;
+        self.mark_running(name, pid)?;
 
-        match readiness {
+        match self.wait_for_service_ready(name, service, started_at) {
             Ok(state) => {
                 if matches!(state, ServiceReadyState::CompletedSuccess) {
                     ctx.cancel_service_thread(name, pid);
@@ -6442,40 +8059,10 @@ impl Daemon {
                         None,
                     )?;
                 }
-                if let Some(action) = service
-                    .hooks
-                    .as_ref()
-                    .and_then(|cfg| cfg.action(HookStage::OnStart, HookOutcome::Success))
-                {
-                    run_hook(
-                        action,
-                        &service.env,
-                        HookStage::OnStart,
-                        HookOutcome::Success,
-                        name,
-                        &self.project_root,
-                        Some((&self.boot_epoch, &self.boot_cancelled)),
-                    );
-                }
                 Ok(state)
             }
             Err(err) => {
                 ctx.cancel_service_thread(name, pid);
-                if let Some(action) = service
-                    .hooks
-                    .as_ref()
-                    .and_then(|cfg| cfg.action(HookStage::OnStart, HookOutcome::Error))
-                {
-                    run_hook(
-                        action,
-                        &service.env,
-                        HookStage::OnStart,
-                        HookOutcome::Error,
-                        name,
-                        &self.project_root,
-                        Some((&self.boot_epoch, &self.boot_cancelled)),
-                    );
-                }
                 Err(err)
             }
         }
@@ -6485,7 +8072,9 @@ impl Daemon {
     /// reusable from helpers that already hold references to the daemon's
     /// shared state. It resolves both PID and process-group metadata before
     /// tearing down the process tree so leaked descendants can still be
-    /// terminated when the root leader has already disappeared.
+    /// terminated when the root leader has already disappeared. Spawned
+    /// children tracked in the pid file are signalled by their own process
+    /// group afterwards, unless the service opted out of cascading.
     fn stop_service_with_handles(
         service_name: &str,
         processes: &Arc<Mutex<HashMap<String, ManagedChild>>>,
@@ -6493,7 +8082,8 @@ impl Daemon {
         state_file: &Arc<Mutex<ServiceStateFile>>,
         config: &Arc<Config>,
         stop_verify_timeout: Duration,
-    ) -> Result<(), ProcessManagerError> {
+        grace: Duration,
+    ) -> Result<StopOutcome, ProcessManagerError> {
         let (pid, service_group_id, has_child, started) = {
             let mut processes_guard = processes.lock()?;
             let (persisted_group, persisted_start) = pid_file
@@ -6561,14 +8151,45 @@ impl Daemon {
             }
         }
 
-        if let Some(process_id) = pid {
-            match Self::terminate_process_tree(service_name, process_id, service_group_id)
-            {
-                Ok(_) => {
-                    debug!(
-                        "Process tree for '{service_name}' (pid {process_id}) terminated successfully"
-                    );
-                }
+        let tracked_descendants = match (pid, pid_file.lock()) {
+            (Some(process_id), Ok(guard)) => {
+                Self::tracked_spawn_descendants(service_name, process_id, &guard, config)
+            }
+            _ => Vec::new(),
+        };
+
+        let escaped = match pid {
+            Some(process_id) => Self::escaped_session_members(
+                &config.project.id,
+                service_name,
+                process_id,
+            )
+            .into_iter()
+            .filter(|escaped| !tracked_descendants.contains(escaped))
+            .collect(),
+            None => Vec::new(),
+        };
+        if !escaped.is_empty() {
+            warn!(
+                "'{service_name}' has {} process(es) outside its session (pids {escaped:?}), likely from `setsid`; terminating them with the service",
+                escaped.len()
+            );
+        }
+
+        let mut outcome = StopOutcome::NotRunning;
+        if let Some(process_id) = pid {
+            match Self::terminate_process_tree_within(
+                service_name,
+                process_id,
+                service_group_id,
+                grace,
+            ) {
+                Ok(terminated) => {
+                    debug!(
+                        "Process tree for '{service_name}' (pid {process_id}) terminated successfully"
+                    );
+                    outcome = terminated;
+                }
                 Err(err) => match &err {
                     ProcessManagerError::ServiceStopError { source, .. }
                         if source.kind() == std::io::ErrorKind::TimedOut =>
@@ -6576,6 +8197,7 @@ impl Daemon {
                         warn!(
                             "Timed out terminating process tree for '{service_name}' (pid {process_id}); forcing cleanup"
                         );
+                        outcome = StopOutcome::Killed { after: grace };
                     }
                     _ => return Err(err),
                 },
@@ -6590,9 +8212,21 @@ impl Daemon {
                     ),
                 });
             }
-            Self::terminate_process_tree(service_name, group_id as u32, Some(group_id))?;
+            outcome = Self::terminate_process_tree_within(
+                service_name,
+                group_id as u32,
+                Some(group_id),
+                grace,
+            )?;
         }
 
+        Self::terminate_tracked_descendants(
+            service_name,
+            &tracked_descendants,
+            service_group_id,
+        );
+        Self::terminate_escaped_session_members(service_name, &escaped);
+
         let child_handle = {
             let mut processes_guard = processes.lock()?;
             processes_guard.remove(service_name)
@@ -6663,7 +8297,7 @@ impl Daemon {
 
         debug!("Service '{service_name}' stopped successfully.");
 
-        Ok(())
+        Ok(outcome)
     }
 
     /// Stops a specific service by name.
@@ -6673,7 +8307,8 @@ impl Daemon {
         &self,
         service_name: &str,
         suppress_auto_restart: bool,
-    ) -> Result<(), ProcessManagerError> {
+        grace: Duration,
+    ) -> Result<StopOutcome, ProcessManagerError> {
         {
             let mut manual_guard = self.manual_stop_flags.lock()?;
             manual_guard.insert(service_name.to_string());
@@ -6699,6 +8334,7 @@ impl Daemon {
             &self.state_file,
             &config,
             self.timeouts().stop_verify_timeout(),
+            grace,
         );
 
         if result.is_err() {
@@ -6738,8 +8374,26 @@ impl Daemon {
     }
 
     /// Stops a specific service and suppresses automatic restarts.
-    pub fn stop_service(&self, service_name: &str) -> Result<(), ProcessManagerError> {
-        self.stop_service_with_intent(service_name, true)
+    pub fn stop_service(
+        &self,
+        service_name: &str,
+    ) -> Result<StopOutcome, ProcessManagerError> {
+        self.stop_service_within(service_name, self.stop_grace(service_name))
+    }
+
+    /// The SIGTERM grace window for `service_name`, honoring its `stop_timeout`.
+    pub fn stop_grace(&self, service_name: &str) -> Duration {
+        self.config().stop_grace(service_name)
+    }
+
+    /// Stops a service like [`Self::stop_service`], giving it `grace` to exit
+    /// after SIGTERM before it is killed.
+    pub fn stop_service_within(
+        &self,
+        service_name: &str,
+        grace: Duration,
+    ) -> Result<StopOutcome, ProcessManagerError> {
+        self.stop_service_with_intent(service_name, true, grace)
     }
 
     /// Recursively stops any services that depend (directly or indirectly) on the specified root
@@ -6783,6 +8437,7 @@ impl Daemon {
                     .read()
                     .unwrap_or_else(std::sync::PoisonError::into_inner)
                     .stop_verify_timeout(),
+                ctx.config.stop_grace(&service),
             ) {
                 error!(
                     "Failed to stop dependent service '{service}' after '{root}' failure: {err}"
@@ -6933,7 +8588,10 @@ impl Daemon {
 
     /// Stops all running services.
     ///
-    /// Iterates over all active processes and terminates them.
+    /// Services go down in reverse dependency order, so nothing loses a
+    /// dependency while it is still running, and each one gets its own
+    /// `stop_timeout` before it is killed. Units the config does not order,
+    /// such as spawned children, go first.
     pub fn stop_services(&self) -> Result<(), ProcessManagerError> {
         let mut services: HashSet<String> = {
             let guard = self.pid_file.lock()?;
@@ -6945,8 +8603,17 @@ impl Daemon {
                 .collect()
         };
         services.extend(self.processes.lock()?.keys().cloned());
+        let start_order = self.config().service_start_order().unwrap_or_default();
+        let position = |service: &str| {
+            start_order
+                .iter()
+                .position(|name| name == service)
+                .unwrap_or(usize::MAX)
+        };
         let mut services: Vec<String> = services.into_iter().collect();
-        services.sort_unstable();
+        services.sort_unstable_by(|a, b| {
+            position(b).cmp(&position(a)).then_with(|| a.cmp(b))
+        });
         let mut first_error = None;
 
         for service in services {
@@ -6982,54 +8649,10 @@ impl Daemon {
                 .pgid_for(&service)
                 .map(|value| value as libc::pid_t);
             let started = pid_file.start_for(&service);
-            let pid_live = pid.is_some_and(Self::pid_is_alive);
-
-            if pid_live
-                && (started.is_none() || pid.and_then(process_start_time) != started)
-            {
-                first_error.get_or_insert_with(|| {
-                    ProcessManagerError::ServiceStopError {
-                        service: service.clone(),
-                        source: std::io::Error::other(
-                            "recorded process identity does not match",
-                        ),
-                    }
-                });
+            if let Err(err) = Self::terminate_recorded(&service, pid, pgid, started) {
+                first_error.get_or_insert(err);
                 continue;
             }
-            if !pid_live
-                && let Some(group_id) = pgid
-                && Self::process_group_is_alive(group_id)
-            {
-                let valid = started.is_some_and(|expected| {
-                    process_start_time(group_id as u32)
-                        .is_none_or(|actual| actual == expected)
-                });
-                let supervisor_group = unsafe { libc::getpgid(0) };
-                if !valid || group_id <= 0 || group_id == supervisor_group {
-                    first_error.get_or_insert_with(|| {
-                        ProcessManagerError::ServiceStopError {
-                            service: service.clone(),
-                            source: std::io::Error::other(
-                                "recorded process group identity does not match",
-                            ),
-                        }
-                    });
-                    continue;
-                }
-            }
-
-            if pid_live || pgid.is_some_and(Self::process_group_is_alive) {
-                let root = pid.or_else(|| {
-                    pgid.filter(|value| *value > 0).map(|value| value as u32)
-                });
-                if let Some(root) = root
-                    && let Err(err) = Self::terminate_process_tree(&service, root, pgid)
-                {
-                    first_error.get_or_insert(err);
-                    continue;
-                }
-            }
 
             if let Err(err) = pid_file.remove(&service)
                 && !matches!(err, PidFileError::ServiceNotFound)
@@ -7066,6 +8689,120 @@ impl Daemon {
         }
     }
 
+    /// Terminates a recorded process, or its group once the leader is gone,
+    /// after checking the recorded start time still identifies it so a reused
+    /// PID is never signaled.
+    fn terminate_recorded(
+        service: &str,
+        pid: Option<u32>,
+        pgid: Option<libc::pid_t>,
+        started: Option<u64>,
+    ) -> Result<(), ProcessManagerError> {
+        let pid_live = pid.is_some_and(Self::pid_is_alive);
+
+        if pid_live && (started.is_none() || pid.and_then(process_start_time) != started)
+        {
+            return Err(ProcessManagerError::ServiceStopError {
+                service: service.to_string(),
+                source: std::io::Error::other("recorded process identity does not match"),
+            });
+        }
+        if !pid_live
+            && let Some(group_id) = pgid
+            && Self::process_group_is_alive(group_id)
+        {
+            let valid = started.is_some_and(|expected| {
+                process_start_time(group_id as u32)
+                    .is_none_or(|actual| actual == expected)
+            });
+            let supervisor_group = unsafe { libc::getpgid(0) };
+            if !valid || group_id <= 0 || group_id == supervisor_group {
+                return Err(ProcessManagerError::ServiceStopError {
+                    service: service.to_string(),
+                    source: std::io::Error::other(
+                        "recorded process group identity does not match",
+                    ),
+                });
+            }
+        }
+
+        if pid_live || pgid.is_some_and(Self::process_group_is_alive) {
+            let root =
+                pid.or_else(|| pgid.filter(|value| *value > 0).map(|value| value as u32));
+            if let Some(root) = root {
+                Self::terminate_process_tree(service, root, pgid)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Terminates and forgets every process recorded for a service that is no
+    /// longer in the config, leaving configured services alone.
+    ///
+    /// These are the units `sysg status --all` lists as orphaned: PID-file
+    /// entries and service-state records left behind after a service was removed
+    /// or renamed. Returns the cleared service names, sorted.
+    pub fn stop_orphans(&self) -> Result<Vec<String>, ProcessManagerError> {
+        let config = self.config();
+        let orphans: BTreeSet<String> = {
+            let guard = self.pid_file.lock()?;
+            guard
+                .services
+                .keys()
+                .chain(guard.service_groups.keys())
+                .filter(|name| !config.services.contains_key(*name))
+                .cloned()
+                .collect()
+        };
+
+        let mut cleared = BTreeSet::new();
+        let mut first_error = None;
+        for service in orphans {
+            let (pid, pgid, started) = {
+                let guard = self.pid_file.lock()?;
+                (
+                    guard.get(&service),
+                    guard.pgid_for(&service).map(|value| value as libc::pid_t),
+                    guard.start_for(&service),
+                )
+            };
+            if let Err(err) = Self::terminate_recorded(&service, pid, pgid, started) {
+                error!("Failed to stop orphaned service '{service}': {err}");
+                first_error.get_or_insert(err);
+                continue;
+            }
+            match self.pid_file.lock()?.remove(&service) {
+                Ok(()) | Err(PidFileError::ServiceNotFound) => {}
+                Err(err) => {
+                    first_error.get_or_insert(err.into());
+                    continue;
+                }
+            }
+            cleared.insert(service);
+        }
+
+        let configured: HashSet<String> = config.state_keys().into_values().collect();
+        let mut state_file = self.state_file.lock()?;
+        let stale: Vec<String> = state_file
+            .services()
+            .keys()
+            .filter(|key| !configured.contains(*key))
+            .cloned()
+            .collect();
+        for key in stale {
+            if let Err(err) = state_file.remove(&key) {
+                first_error.get_or_insert(err.into());
+                continue;
+            }
+            cleared.insert(key.rsplit(':').next().unwrap_or(&key).to_string());
+        }
+
+        match first_error {
+            Some(err) => Err(err),
+            None => Ok(cleared.into_iter().collect()),
+        }
+    }
+
     /// Ensures that the monitor thread is running, spawning it if necessary.
     fn spawn_monitor_thread(&self) -> Result<(), ProcessManagerError> {
         let mut handle_slot = self
@@ -7118,6 +8855,109 @@ impl Daemon {
         self.wait_for_monitor();
     }
 
+    /// Pauses automatic restarts without touching running services.
+    ///
+    /// Crashes are still recorded (state file, `on_stop` hooks, dependent
+    /// teardown) but nothing is restarted until [`Daemon::resume_restarts`].
+    /// Returns `false` when restarts were already paused.
+    pub fn pause_restarts(&self) -> bool {
+        !self.restarts_paused.swap(true, Ordering::SeqCst)
+    }
+
+    /// Re-enables automatic restarts and returns the services that crashed while
+    /// paused and are still configured and down. With `restart_crashed`, those
+    /// are fed back through the normal restart path (backoff, `max_restarts`)
+    /// and only the ones actually scheduled for restart are returned.
+    pub fn resume_restarts(&self, restart_crashed: bool) -> Vec<String> {
+        self.restarts_paused.store(false, Ordering::SeqCst);
+        let crashed = std::mem::take(
+            &mut *self
+                .paused_crashes
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+        );
+        let ctx = self.context();
+        if !restart_crashed {
+            // A reload may have brought some back or dropped them from the
+            // config; only the ones still down are worth reporting.
+            let processes = ctx.lock_processes().ok();
+            return crashed
+                .into_iter()
+                .filter(|name| {
+                    ctx.config.services.contains_key(name)
+                        && !processes
+                            .as_ref()
+                            .is_some_and(|processes| processes.contains_key(name))
+                })
+                .collect();
+        }
+
+        let mut restarted = Vec::new();
+        for name in crashed {
+            let running = ctx
+                .lock_processes()
+                .map(|processes| processes.contains_key(&name))
+                .unwrap_or(true);
+            let Some(service) = ctx.config.services.get(&name) else {
+                continue;
+            };
+            if running {
+                continue;
+            }
+            let already = match ctx.lock_restart_in_flight() {
+                Ok(mut guard) => !guard.insert(name.clone()),
+                Err(_) => true,
+            };
+            if !already {
+                info!(
+                    "Restarting '{name}', which crashed while auto-restart was paused."
+                );
+                Self::handle_restart(&name, service, ctx.clone());
+                restarted.push(name);
+            }
+        }
+        restarted
+    }
+
+    /// Returns whether automatic restarts are currently paused.
+    pub fn restarts_paused(&self) -> bool {
+        self.restarts_paused.load(Ordering::SeqCst)
+    }
+
+    /// Clears the restart count of `name`, so a service the restart policy
+    /// gave up on gets its full `max_restarts` again. Returns whether the
+    /// service had any restarts counted.
+    pub fn rearm_restarts(&self, name: &str) -> bool {
+        self.restart_counts
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .remove(name)
+            .is_some()
+    }
+
+    /// Returns how many automatic restarts in a row `name` has used without
+    /// coming back up ready.
+    pub fn restart_count(&self, name: &str) -> u32 {
+        self.restart_counts
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get(name)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Notes a crash that a paused monitor deliberately left down.
+    fn record_paused_crash(ctx: &DaemonContext, name: &str) {
+        warn!("Service '{name}' crashed while auto-restart is paused; leaving it down.");
+        let mut crashed = ctx
+            .paused_crashes
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if !crashed.iter().any(|existing| existing == name) {
+            crashed.push(name.to_string());
+        }
+    }
+
     /// Monitors all running services and restarts them if they exit unexpectedly.
     fn monitor_loop(ctx: DaemonContext) {
         while ctx.running.load(Ordering::SeqCst) {
@@ -7195,6 +9035,10 @@ impl Daemon {
 
                     Self::reap_orphaned_group_before_restart(&name, recorded_pgid);
 
+                    if let Err(err) = sync_service_log(&ctx.config.project.id, &name) {
+                        warn!("Failed to sync logs for '{name}' after exit: {err}");
+                    }
+
                     let manually_stopped = ctx
                         .lock_manual_stop_flags()
                         .map(|mut guard| guard.remove(&name))
@@ -7289,7 +9133,9 @@ impl Daemon {
                             .get(&name)
                             .is_some_and(|service| service.restarts_after_failure());
 
-                        if should_restart {
+                        if should_restart && ctx.restarts_paused.load(Ordering::SeqCst) {
+                            Self::record_paused_crash(&ctx, &name);
+                        } else if should_restart {
                             let already = ctx
                                 .lock_restart_in_flight()
                                 .map(|g| g.contains(&name))
@@ -7364,10 +9210,14 @@ impl Daemon {
                 debug!("No active services detected in monitor loop.");
             }
 
-            Self::revive_ready_dependents(&ctx);
+            // While paused, nothing is brought back: crashed units stay down for
+            // inspection and their dependents stay stopped until resume.
+            if !ctx.restarts_paused.load(Ordering::SeqCst) {
+                Self::revive_ready_dependents(&ctx);
 
-            let mut reconciled = Self::reconcile_lost_services(&ctx);
-            restarted_services.append(&mut reconciled);
+                let mut reconciled = Self::reconcile_lost_services(&ctx);
+                restarted_services.append(&mut reconciled);
+            }
 
             for (name, recorded_pgid) in restarted_services {
                 let live_pgid = ctx.lock_pid_file().ok().and_then(|g| g.pgid_for(&name));
@@ -7444,9 +9294,11 @@ impl Daemon {
                 continue;
             }
 
+            // A service the restart policy gave up on stays down until an
+            // explicit start re-arms it.
             if matches!(
                 Self::recorded_status_in_context(ctx, name),
-                Some(ServiceLifecycleStatus::Skipped)
+                Some(ServiceLifecycleStatus::Skipped | ServiceLifecycleStatus::GaveUp)
             ) {
                 continue;
             }
@@ -7588,6 +9440,13 @@ impl Daemon {
                 error!(
                     "Service '{name}' has reached maximum restart attempts ({max}). Giving up."
                 );
+                let key = ctx.config.state_key(&name);
+                if let Err(err) = ctx
+                    .lock_state_file()
+                    .and_then(|mut state| Ok(state.mark_gave_up(&key, max)?))
+                {
+                    warn!("Failed to record that '{name}' was given up on: {err}");
+                }
                 ctx.restart_in_flight
                     .lock()
                     .unwrap_or_else(std::sync::PoisonError::into_inner)
@@ -7596,17 +9455,16 @@ impl Daemon {
             }
         }
 
-        let backoff = match service.backoff.as_deref() {
-            Some(raw) => match Self::parse_duration(raw) {
-                Ok(duration) => duration,
-                Err(err) => {
-                    warn!(
-                        "Invalid restart backoff '{raw}' for '{name}': {err}; using {DEFAULT_RESTART_BACKOFF:?}."
-                    );
-                    DEFAULT_RESTART_BACKOFF
-                }
-            },
-            None => DEFAULT_RESTART_BACKOFF,
+        // Manifests are checked at load, so an unparsable value here can only
+        // come from a config built in code; keep the default rather than panic.
+        let backoff = match service.restart_backoff() {
+            Ok(backoff) => backoff.unwrap_or(DEFAULT_RESTART_BACKOFF),
+            Err(err) => {
+                warn!(
+                    "Invalid restart backoff for '{name}': {err}; using {DEFAULT_RESTART_BACKOFF:?}."
+                );
+                DEFAULT_RESTART_BACKOFF
+            }
         };
 
         let in_flight = Arc::clone(&ctx.restart_in_flight);
@@ -7623,6 +9481,11 @@ impl Daemon {
                     return;
                 }
 
+                if ctx.restarts_paused.load(Ordering::SeqCst) {
+                    Self::record_paused_crash(&ctx, &name);
+                    return;
+                }
+
                 if ctx
                     .lock_restart_suppressed()
                     .map(|guard| guard.contains(&name))
@@ -7670,7 +9533,7 @@ impl Daemon {
 
                 if !ctx.running.load(Ordering::SeqCst) {
                     if matches!(&restart_result, Ok(ServiceReadyState::Running)) {
-                        let _ = daemon.stop_service_with_intent(&name, false);
+                        let _ = daemon.stop_service_with_intent(&name, false, daemon.stop_grace(&name));
                     }
                     return;
                 }
@@ -7732,8 +9595,8 @@ impl Daemon {
     }
 }
 
-/// Clears a service's `restart_in_flight` entry when the restart thread ends,
-/// on every exit path including early returns.
+/// Clears a service's `restart_in_flight` or `restarting` entry when the
+/// restart ends, on every exit path including early returns.
 struct InFlightGuard {
     /// Shared set whose entry belongs to this worker.
     set: Arc<Mutex<HashSet<String>>>,
@@ -7848,6 +9711,7 @@ mod tests {
     };
 
     use super::*;
+    use crate::config::RestartPolicy;
 
     /// Helper to build a minimal service definition for unit tests.
     fn make_service(command: &str, deps: &[&str]) -> ServiceConfig {
@@ -7860,9 +9724,31 @@ mod tests {
             limits: None,
             capabilities: None,
             isolation: None,
+            umask: None,
+            priority: None,
+            after: None,
+            before: None,
+            critical: None,
+            interactive: None,
+            pty: None,
+            shell: None,
+            ready_log_pattern: None,
+            start_timeout: None,
+            stop_timeout: None,
             restart_policy: None,
             backoff: None,
             max_restarts: None,
+            start_retries: None,
+            start_retry_delay: None,
+            ready_stable_for: None,
+            instances: None,
+            forking: None,
+            pidfile: None,
+            sockets: None,
+            ports: None,
+            restart_if_rss_exceeds: None,
+            rss_window: None,
+            condition: None,
             depends_on: if deps.is_empty() {
                 None
             } else {
@@ -7878,6 +9764,7 @@ mod tests {
             skip: None,
             spawn: None,
             logs: None,
+            health: None,
             project_scope: None,
         }
     }
@@ -7898,6 +9785,13 @@ mod tests {
             metrics: crate::config::MetricsConfig::default(),
             logs: crate::config::LogsConfig::default(),
             status: crate::config::StatusConfig::default(),
+            deployment: crate::config::ProjectDeploymentConfig::default(),
+            on_ready: None,
+            on_start_failure: None,
+            on_failure: Default::default(),
+            dependency_settle: None,
+            supervisor: Default::default(),
+            env_overrides: Default::default(),
         };
         config.service_start_order().unwrap();
 
@@ -7992,7 +9886,7 @@ fi
 
             let mut services = HashMap::new();
             let mut service = make_service("sh app.sh", &[]);
-            service.restart_policy = Some("always".into());
+            service.restart_policy = Some(RestartPolicy::Always);
             services.insert("app".into(), service);
 
             let daemon = create_daemon(dir, services);
@@ -8007,7 +9901,7 @@ fi
 
             let config = daemon.config();
             let svc = config.services.get("app").unwrap();
-            let err = daemon.restart_service("app", svc).unwrap_err();
+            let err = daemon.restart_service("app", svc, false).unwrap_err();
 
             match err {
                 ProcessManagerError::Diag(diag) => {
@@ -8049,6 +9943,121 @@ fi
         });
     }
 
+    #[test]
+    fn restart_services_caps_parallel_restarts_and_respects_dependencies() {
+        with_temp_home(|dir| {
+            fs::write(
+                dir.join("track.sh"),
+                "echo \"start $1\" >> events.log\nsleep 0.4\necho \"end $1\" >> events.log\n",
+            )
+            .unwrap();
+
+            let mut services = HashMap::new();
+            for (name, deps) in [
+                ("a", &[][..]),
+                ("b", &[]),
+                ("c", &[]),
+                ("d", &[]),
+                ("e", &["a"]),
+            ] {
+                let mut service = make_service("sleep 30", deps);
+                service.deployment = Some(crate::config::DeploymentConfig {
+                    strategy: Some("immediate".into()),
+                    pre_start: Some(format!("sh track.sh {name}")),
+                    health_check: None,
+                    grace_period: None,
+                    blue_green: None,
+                });
+                services.insert(name.to_string(), service);
+            }
+
+            let daemon = create_daemon(dir, services);
+            let mut config = (*daemon.config()).clone();
+            config.deployment.max_parallel = Some(2);
+            daemon.set_config(config);
+            daemon.start_services().unwrap();
+            fs::remove_file(dir.join("events.log")).unwrap();
+
+            daemon.restart_services().unwrap();
+            daemon.stop_services().ok();
+            daemon.shutdown_monitor();
+
+            let events = fs::read_to_string(dir.join("events.log")).unwrap();
+            let mut active = HashSet::new();
+            let mut peak = 0;
+            for line in events.lines() {
+                match line.split_once(' ').unwrap() {
+                    ("start", name) => {
+                        if name == "e" {
+                            assert!(!active.contains("a"), "e restarted alongside a");
+                        }
+                        active.insert(name.to_string());
+                    }
+                    (_, name) => {
+                        active.remove(name);
+                    }
+                }
+                peak = peak.max(active.len());
+            }
+            assert_eq!(peak, 2, "events:\n{events}");
+            assert!(events.find("end a").unwrap() < events.find("start e").unwrap());
+        });
+    }
+
+    #[test]
+    fn parallel_restarts_respect_after_and_before_ordering() {
+        with_temp_home(|dir| {
+            fs::write(
+                dir.join("track.sh"),
+                "echo \"start $1\" >> events.log\nsleep 0.4\necho \"end $1\" >> events.log\n",
+            )
+            .unwrap();
+
+            let mut services = HashMap::new();
+            for name in ["db", "api", "cache", "web"] {
+                let mut service = make_service("sleep 30", &[]);
+                service.deployment = Some(crate::config::DeploymentConfig {
+                    strategy: Some("immediate".into()),
+                    pre_start: Some(format!("sh track.sh {name}")),
+                    health_check: None,
+                    grace_period: None,
+                    blue_green: None,
+                });
+                match name {
+                    "api" => service.after = Some(vec!["db".into()]),
+                    "cache" => service.before = Some(vec!["web".into()]),
+                    _ => {}
+                }
+                services.insert(name.to_string(), service);
+            }
+
+            let daemon = create_daemon(dir, services);
+            let mut config = (*daemon.config()).clone();
+            config.deployment.max_parallel = Some(4);
+            daemon.set_config(config);
+            daemon.start_services().unwrap();
+            fs::remove_file(dir.join("events.log")).unwrap();
+
+            daemon.restart_services().unwrap();
+            daemon.stop_services().ok();
+            daemon.shutdown_monitor();
+
+            let events = fs::read_to_string(dir.join("events.log")).unwrap();
+            assert!(
+                events.find("end db").unwrap() < events.find("start api").unwrap(),
+                "api restarted alongside db:\n{events}"
+            );
+            assert!(
+                events.find("end cache").unwrap() < events.find("start web").unwrap(),
+                "web restarted alongside cache:\n{events}"
+            );
+            assert!(
+                events.find("start cache").unwrap() < events.find("end db").unwrap(),
+                "unordered services should still restart together:\n{events}"
+            );
+        });
+    }
+
     #[test]
     /// Verifies `always` still leaves a clean post-readiness exit completed.
     fn monitor_reaps_services_that_exit_after_running_state() {
@@ -8057,7 +10066,7 @@ fi
 
             let mut services = HashMap::new();
             let mut service = make_service("sh slow_exit.sh", &[]);
-            service.restart_policy = Some("always".into());
+            service.restart_policy = Some(RestartPolicy::Always);
             services.insert("slow".into(), service);
 
             let daemon = create_daemon(dir, services);
@@ -8088,48 +10097,360 @@ fi
         });
     }
 
-    #[cfg(target_os = "linux")]
     #[test]
-    fn automatic_restart_keeps_restarted_service_alive() {
+    fn exhausted_restarts_are_recorded_as_gave_up_until_rearmed() {
         with_temp_home(|dir| {
-            let restarted_pid_path = dir.join("restarted.pid");
+            // Runs long enough to come up once, then fails every restart before
+            // it is ready, until `fixed` appears.
             fs::write(
                 dir.join("flaky.sh"),
-                format!(
-                    r#"
+                r#"
+if [ -f fixed ]; then
+  exec sleep 30
+fi
 if [ ! -f first-run.done ]; then
   touch first-run.done
-  sleep 0.3
-  exit 1
+  sleep 0.5
 fi
-echo $$ > "{}"
-sleep 30
+exit 3
 "#,
-                    restarted_pid_path.display()
-                ),
             )
             .unwrap();
 
             let mut service = make_service("sh flaky.sh", &[]);
-            service.restart_policy = Some("always".into());
+            service.restart_policy = Some(RestartPolicy::Always);
             service.backoff = Some("0s".into());
-
+            service.max_restarts = Some(1);
             let mut services = HashMap::new();
-            services.insert("flaky".into(), service);
+            services.insert("flaky".into(), service.clone());
 
             let daemon = create_daemon(dir, services);
             daemon.start_services().unwrap();
 
-            let deadline = Instant::now() + Duration::from_secs(5);
-            let restarted_pid = loop {
-                if let Ok(contents) = fs::read_to_string(&restarted_pid_path)
-                    && let Ok(pid) = contents.trim().parse::<u32>()
-                {
-                    break pid;
-                }
-
-                if Instant::now() >= deadline {
-                    panic!("automatic restart did not write replacement PID");
+            let key = daemon.config().state_key("flaky");
+            let status = || {
+                daemon
+                    .state_file
+                    .lock()
+                    .unwrap()
+                    .get(&key)
+                    .map(|entry| (entry.status, entry.gave_up_after, entry.exit_code))
+            };
+            let deadline = Instant::now() + Duration::from_secs(10);
+            while status().map(|(status, ..)| status)
+                != Some(ServiceLifecycleStatus::GaveUp)
+            {
+                assert!(Instant::now() < deadline, "restart policy never gave up");
+                thread::sleep(Duration::from_millis(50));
+            }
+            assert_eq!(
+                status(),
+                Some((ServiceLifecycleStatus::GaveUp, Some(1), Some(3)))
+            );
+
+            // Given up means left down: the monitor must not bring it back.
+            thread::sleep(Duration::from_millis(2500));
+            assert!(daemon.pid_file.lock().unwrap().get("flaky").is_none());
+            assert_eq!(
+                status().map(|(status, ..)| status),
+                Some(ServiceLifecycleStatus::GaveUp)
+            );
+
+            fs::write(dir.join("fixed"), "").unwrap();
+            assert!(daemon.rearm_restarts("flaky"));
+            assert_eq!(daemon.restart_count("flaky"), 0);
+            daemon.start_service("flaky", &service).unwrap();
+            assert_eq!(
+                status(),
+                Some((ServiceLifecycleStatus::Running, None, None))
+            );
+
+            daemon.stop_services().ok();
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[test]
+    fn paused_monitor_records_crash_and_resume_restarts_it() {
+        with_temp_home(|dir| {
+            let mut service = make_service("sleep 30", &[]);
+            service.restart_policy = Some(RestartPolicy::Always);
+            service.backoff = Some("100ms".into());
+            let mut services = HashMap::new();
+            services.insert("worker".into(), service.clone());
+
+            let daemon = create_daemon(dir, services);
+            daemon.start_service("worker", &service).unwrap();
+            daemon.ensure_monitoring().unwrap();
+            assert!(daemon.pause_restarts());
+            assert!(!daemon.pause_restarts());
+
+            let pid = daemon.pid_file.lock().unwrap().get("worker").unwrap();
+            unsafe {
+                libc::kill(pid as libc::pid_t, libc::SIGKILL);
+            }
+            thread::sleep(Duration::from_millis(3000));
+
+            assert!(daemon.pid_file.lock().unwrap().get("worker").is_none());
+            assert_eq!(
+                daemon.recorded_status("worker"),
+                Some(ServiceLifecycleStatus::ExitedWithError)
+            );
+
+            assert_eq!(daemon.resume_restarts(true), vec!["worker".to_string()]);
+            assert!(!daemon.restarts_paused());
+            let deadline = Instant::now() + Duration::from_secs(5);
+            let mut restarted = None;
+            while Instant::now() < deadline && restarted.is_none() {
+                restarted = daemon.pid_file.lock().unwrap().get("worker");
+                thread::sleep(Duration::from_millis(50));
+            }
+            assert!(restarted.is_some_and(|new_pid| new_pid != pid));
+
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[test]
+    fn replacement_daemon_carries_crashes_recorded_while_paused() {
+        with_temp_home(|dir| {
+            let service = make_service("sleep 30", &[]);
+            let mut services = HashMap::new();
+            services.insert("worker".into(), service.clone());
+
+            let previous = create_daemon(dir, services.clone());
+            assert!(previous.pause_restarts());
+            Daemon::record_paused_crash(&previous.context(), "worker");
+            Daemon::record_paused_crash(&previous.context(), "retired");
+
+            let replacement = create_daemon(dir, services);
+            replacement.carry_paused_crashes(&previous);
+            assert_eq!(
+                replacement.resume_restarts(false),
+                vec!["worker".to_string()]
+            );
+        });
+    }
+
+    #[test]
+    fn stop_orphans_clears_only_unconfigured_units() {
+        use std::os::unix::process::CommandExt;
+
+        with_temp_home(|dir| {
+            let service = make_service("sleep 30", &[]);
+            let mut services = HashMap::new();
+            services.insert("web".into(), service.clone());
+            let daemon = create_daemon(dir, services);
+            daemon.start_service("web", &service).unwrap();
+            let web_pid = daemon.pid_file.lock().unwrap().get("web").unwrap();
+
+            let mut ghost = Command::new("sleep")
+                .arg("30")
+                .process_group(0)
+                .stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn()
+                .unwrap();
+            let ghost_pid = ghost.id();
+            daemon
+                .pid_file
+                .lock()
+                .unwrap()
+                .insert_with_group("ghost", ghost_pid, Some(ghost_pid as i32))
+                .unwrap();
+            let ghost_key = daemon.config().state_key("ghost");
+            daemon
+                .state_file
+                .lock()
+                .unwrap()
+                .set(
+                    &ghost_key,
+                    ServiceLifecycleStatus::Running,
+                    Some(ghost_pid),
+                    None,
+                    None,
+                )
+                .unwrap();
+
+            assert_eq!(daemon.stop_orphans().unwrap(), vec!["ghost".to_string()]);
+            assert!(!Daemon::pid_is_alive(ghost_pid));
+            let _ = ghost.wait();
+            assert!(daemon.pid_file.lock().unwrap().get("ghost").is_none());
+            assert!(daemon.state_file.lock().unwrap().get(&ghost_key).is_none());
+
+            assert_eq!(daemon.pid_file.lock().unwrap().get("web"), Some(web_pid));
+            assert!(Daemon::pid_is_alive(web_pid));
+            assert!(daemon.stop_orphans().unwrap().is_empty());
+
+            daemon.stop_services().unwrap();
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn killed_service_keeps_its_final_log_line() {
+        with_temp_home(|dir| {
+            fs::write(dir.join("crashy.sh"), "echo final-line\nsleep 30\n").unwrap();
+
+            let mut service = make_service("sh crashy.sh", &[]);
+            service.restart_policy = Some(RestartPolicy::Never);
+            service.logs = Some(crate::config::LogsConfig {
+                flush: Some(crate::config::LogFlush::None),
+                ..crate::config::LogsConfig::default()
+            });
+            let mut services = HashMap::new();
+            services.insert("crashy".into(), service.clone());
+
+            let daemon = create_daemon(dir, services);
+            daemon.start_service("crashy", &service).unwrap();
+            daemon.ensure_monitoring().unwrap();
+            thread::sleep(Duration::from_millis(300));
+
+            let pid = daemon.pid_file.lock().unwrap().get("crashy").unwrap();
+            unsafe {
+                libc::kill(pid as libc::pid_t, libc::SIGKILL);
+            }
+
+            let log_path =
+                crate::logs::get_service_log_path(&daemon.config().project.id, "crashy");
+            let deadline = Instant::now() + Duration::from_secs(5);
+            let mut contents = String::new();
+            while Instant::now() < deadline {
+                contents = fs::read_to_string(&log_path).unwrap_or_default();
+                if contents.contains("final-line") {
+                    break;
+                }
+                thread::sleep(Duration::from_millis(50));
+            }
+            assert!(contents.contains("final-line"), "log was: {contents:?}");
+
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[test]
+    fn failing_cron_job_stderr_lands_in_its_log() {
+        with_temp_home(|dir| {
+            let mut service = make_service("sh -c 'echo cron-broke >&2; exit 4'", &[]);
+            service.cron = Some(crate::config::CronConfig {
+                expression: "0 0 * * * *".into(),
+                timezone: None,
+                history_limit: None,
+                history_retention: None,
+            });
+            let mut services = HashMap::new();
+            services.insert("nightly".into(), service.clone());
+
+            let daemon = create_daemon(dir, services);
+            let _ = daemon.start_service("nightly", &service);
+
+            let log_path =
+                crate::logs::get_service_log_path(&daemon.config().project.id, "nightly");
+            let deadline = Instant::now() + Duration::from_secs(5);
+            let mut contents = String::new();
+            while Instant::now() < deadline {
+                contents = fs::read_to_string(&log_path).unwrap_or_default();
+                if contents.contains("cron-broke") {
+                    break;
+                }
+                thread::sleep(Duration::from_millis(50));
+            }
+            assert!(
+                contents
+                    .lines()
+                    .any(|line| line.contains(" stderr ") && line.ends_with("cron-broke")),
+                "log was: {contents:?}"
+            );
+
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn forking_service_tracks_the_daemon_from_its_pidfile() {
+        with_temp_home(|dir| {
+            fs::write(dir.join("forker.sh"), "sleep 30 &\necho $! > app.pid\n").unwrap();
+
+            let mut service = make_service("sh forker.sh", &[]);
+            service.restart_policy = Some(RestartPolicy::Always);
+            service.forking = Some(true);
+            service.pidfile = Some("app.pid".into());
+            let mut services = HashMap::new();
+            services.insert("forker".into(), service.clone());
+
+            let daemon = create_daemon(dir, services);
+            daemon.start_service("forker", &service).unwrap();
+            daemon.ensure_monitoring().unwrap();
+
+            let daemon_pid: u32 = fs::read_to_string(dir.join("app.pid"))
+                .unwrap()
+                .trim()
+                .parse()
+                .unwrap();
+            assert_eq!(
+                daemon.pid_file.lock().unwrap().get("forker"),
+                Some(daemon_pid)
+            );
+
+            // Outlast a monitor tick: the launcher's exit must not read as a crash.
+            thread::sleep(Duration::from_millis(2500));
+            assert_eq!(
+                daemon.pid_file.lock().unwrap().get("forker"),
+                Some(daemon_pid)
+            );
+            assert!(Daemon::pid_is_alive(daemon_pid));
+
+            daemon.stop_service("forker").unwrap();
+            assert!(!Daemon::pid_is_alive(daemon_pid));
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn automatic_restart_keeps_restarted_service_alive() {
+        with_temp_home(|dir| {
+            let restarted_pid_path = dir.join("restarted.pid");
+            fs::write(
+                dir.join("flaky.sh"),
+                format!(
+                    r#"
+if [ ! -f first-run.done ]; then
+  touch first-run.done
+  sleep 0.3
+  exit 1
+fi
+echo $$ > "{}"
+sleep 30
+"#,
+                    restarted_pid_path.display()
+                ),
+            )
+            .unwrap();
+
+            let mut service = make_service("sh flaky.sh", &[]);
+            service.restart_policy = Some(RestartPolicy::Always);
+            service.backoff = Some("0s".into());
+
+            let mut services = HashMap::new();
+            services.insert("flaky".into(), service);
+
+            let daemon = create_daemon(dir, services);
+            daemon.start_services().unwrap();
+
+            let deadline = Instant::now() + Duration::from_secs(5);
+            let restarted_pid = loop {
+                if let Ok(contents) = fs::read_to_string(&restarted_pid_path)
+                    && let Ok(pid) = contents.trim().parse::<u32>()
+                {
+                    break pid;
+                }
+
+                if Instant::now() >= deadline {
+                    panic!("automatic restart did not write replacement PID");
                 }
 
                 thread::sleep(Duration::from_millis(50));
@@ -8164,6 +10485,10 @@ sleep 30
             Daemon::parse_duration("15").unwrap(),
             Duration::from_secs(15)
         );
+        assert_eq!(
+            Daemon::parse_duration("250ms").unwrap(),
+            Duration::from_millis(250)
+        );
     }
 
     #[test]
@@ -8201,66 +10526,340 @@ sleep 30
     }
 
     #[test]
-    fn dependent_not_started_when_dependency_fails() {
+    fn dependency_settle_delays_dependents_after_readiness() {
         with_temp_home(|dir| {
-            fs::write(dir.join("fail.sh"), "exit 1\n").unwrap();
-            fs::write(dir.join("dependent.sh"), "echo dependent >> started.log\n")
-                .unwrap();
+            let mut db = make_service(
+                "date +%s%N > db.ready; echo 'db ready'; exec sleep 30",
+                &[],
+            );
+            db.ready_log_pattern = Some("db ready".into());
+            let web = make_service("date +%s%N > web.launched; exec sleep 30", &["db"]);
 
             let mut services = HashMap::new();
-            services.insert("fail".into(), make_service("sh fail.sh", &[]));
-            services.insert(
-                "dependent".into(),
-                make_service("sh dependent.sh", &["fail"]),
+            services.insert("db".into(), db);
+            services.insert("web".into(), web);
+            let daemon = create_daemon(dir, services);
+            let mut config = (*daemon.config()).clone();
+            config.dependency_settle = Some("800ms".into());
+            daemon.set_config(config);
+
+            daemon.start_services().unwrap();
+            daemon.stop_services().ok();
+            daemon.shutdown_monitor();
+
+            let nanos = |file: &str| -> u128 {
+                fs::read_to_string(dir.join(file))
+                    .unwrap()
+                    .trim()
+                    .parse()
+                    .unwrap()
+            };
+            let gap =
+                Duration::from_nanos((nanos("web.launched") - nanos("db.ready")) as u64);
+            assert!(
+                gap >= Duration::from_millis(800),
+                "dependent launched after {gap:?}"
             );
+        });
+    }
+
+    #[test]
+    fn service_shell_picks_the_interpreter_or_execs_directly() {
+        with_temp_home(|dir| {
+            let mut bash = make_service("[[ -n $BASH_VERSION ]] && touch bash.ran", &[]);
+            bash.shell = Some("/bin/bash".into());
+            let mut direct = make_service("touch 'a file' $NOT_EXPANDED", &[]);
+            direct.shell = Some("none".into());
+            let mut services = HashMap::new();
+            services.insert("bash".into(), bash);
+            services.insert("direct".into(), direct);
 
             let daemon = create_daemon(dir, services);
-            let result = daemon.start_services();
-            assert!(result.is_err());
-            assert!(!dir.join("started.log").exists());
+            daemon.start_services().unwrap();
             daemon.shutdown_monitor();
+
+            assert!(dir.join("bash.ran").exists());
+            assert!(dir.join("a file").exists());
+            assert!(dir.join("$NOT_EXPANDED").exists());
         });
     }
 
     #[test]
-    fn dependents_stopped_when_dependency_crashes() {
+    fn start_barrier_hooks_follow_the_start_outcome() {
         with_temp_home(|dir| {
-            fs::write(
-                dir.join("parent.sh"),
-                "echo parent >> events.log\nsleep 1\nexit 1\n",
-            )
-            .unwrap();
-            fs::write(dir.join("child.sh"), "echo child >> events.log\nsleep 30\n")
-                .unwrap();
+            let barrier_hooks = |config: &mut Config| {
+                config.env = Some(EnvConfig {
+                    vars: Some(HashMap::from([("STACK".into(), "shop".into())])),
+                    ..EnvConfig::default()
+                });
+                config.on_ready = Some(HookAction {
+                    command: "echo \"ready $STACK\" >> barrier.log".into(),
+                    timeout: None,
+                });
+                config.on_start_failure = Some(HookAction {
+                    command: "echo failed >> barrier.log".into(),
+                    timeout: None,
+                });
+            };
 
             let mut services = HashMap::new();
-            services.insert("parent".into(), make_service("sh parent.sh", &[]));
-            services.insert("child".into(), make_service("sh child.sh", &["parent"]));
-
+            services.insert("web".into(), make_service("sleep 30", &[]));
             let daemon = create_daemon(dir, services);
+            let mut config = (*daemon.config()).clone();
+            barrier_hooks(&mut config);
+            daemon.set_config(config);
             daemon.start_services().unwrap();
+            daemon.stop_services().ok();
+            daemon.shutdown_monitor();
+            assert_eq!(
+                fs::read_to_string(dir.join("barrier.log")).unwrap(),
+                "ready shop\n"
+            );
 
-            let deadline = Instant::now() + Duration::from_secs(5);
-            loop {
-                if daemon.pid_file.lock().unwrap().get("child").is_none() {
-                    break;
-                }
+            fs::write(dir.join("fail.sh"), "exit 1\n").unwrap();
+            let mut services = HashMap::new();
+            services.insert("fail".into(), make_service("sh fail.sh", &[]));
+            let daemon = create_daemon(dir, services);
+            let mut config = (*daemon.config()).clone();
+            barrier_hooks(&mut config);
+            daemon.set_config(config);
+            assert!(daemon.start_services().is_err());
+            daemon.shutdown_monitor();
+            assert_eq!(
+                fs::read_to_string(dir.join("barrier.log")).unwrap(),
+                "ready shop\nfailed\n"
+            );
+        });
+    }
 
-                if Instant::now() >= deadline {
-                    panic!("dependent service still recorded in pid file");
-                }
+    /// Two failing services sorted ahead of one that succeeds, so the start
+    /// order is `api`, `cache`, `web`.
+    fn two_failing_services_daemon(
+        dir: &std::path::Path,
+        mode: crate::config::StartFailureMode,
+    ) -> Daemon {
+        fs::write(dir.join("fail.sh"), "touch \"$1.ran\"\nexit 1\n").unwrap();
+        let mut services = HashMap::new();
+        services.insert("api".into(), make_service("sh fail.sh api", &[]));
+        services.insert("cache".into(), make_service("sh fail.sh cache", &[]));
+        services.insert("web".into(), make_service("touch web.ran", &[]));
+        let daemon = create_daemon(dir, services);
+        let mut config = (*daemon.config()).clone();
+        config.on_failure = mode;
+        daemon.set_config(config);
+        daemon
+    }
 
-                thread::sleep(Duration::from_millis(100));
+    #[test]
+    fn continue_mode_starts_everything_and_reports_every_failure() {
+        with_temp_home(|dir| {
+            let daemon = two_failing_services_daemon(
+                dir,
+                crate::config::StartFailureMode::Continue,
+            );
+            match daemon.start_services() {
+                Err(ProcessManagerError::ServicesFailedToStart { failures }) => {
+                    assert_eq!(failures.len(), 2);
+                    assert!(failures[0].starts_with("api: "));
+                    assert!(failures[1].starts_with("cache: "));
+                }
+                other => panic!("expected every failure to be reported, got {other:?}"),
             }
-
+            thread::sleep(Duration::from_millis(200));
+            assert!(dir.join("cache.ran").exists());
+            assert!(dir.join("web.ran").exists());
             daemon.shutdown_monitor();
         });
     }
 
     #[test]
-    fn concurrent_pid_file_operations_no_lost_updates() {
-        with_temp_home(|_| {
-            let mut initial = PidFile {
+    fn abort_mode_stops_launching_after_the_first_failure() {
+        with_temp_home(|dir| {
+            let daemon =
+                two_failing_services_daemon(dir, crate::config::StartFailureMode::Abort);
+            let err = daemon.start_services().expect_err("start should fail");
+            assert!(
+                !matches!(err, ProcessManagerError::ServicesFailedToStart { .. }),
+                "abort reports only the first failure, got {err:?}"
+            );
+            thread::sleep(Duration::from_millis(200));
+            assert!(dir.join("api.ran").exists());
+            assert!(!dir.join("cache.ran").exists());
+            assert!(!dir.join("web.ran").exists());
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[test]
+    fn critical_failure_stops_services_already_started() {
+        with_temp_home(|dir| {
+            let mut db = make_service("sh -c 'exit 1'", &[]);
+            db.critical = Some(true);
+            let mut services = HashMap::new();
+            services.insert("api".into(), make_service("sleep 30", &[]));
+            services.insert("cache".into(), make_service("sleep 30", &[]));
+            services.insert("db".into(), db);
+            services.insert("web".into(), make_service("touch web.ran", &[]));
+
+            let daemon = create_daemon(dir, services);
+            assert!(daemon.start_services().is_err());
+
+            let running = daemon.pid_file_handle().lock().unwrap().services().clone();
+            assert!(
+                running.is_empty(),
+                "started services should be rolled back, still running: {running:?}"
+            );
+            thread::sleep(Duration::from_millis(200));
+            assert!(!dir.join("web.ran").exists());
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[test]
+    fn dependent_not_started_when_dependency_fails() {
+        with_temp_home(|dir| {
+            fs::write(dir.join("fail.sh"), "exit 1\n").unwrap();
+            fs::write(dir.join("dependent.sh"), "echo dependent >> started.log\n")
+                .unwrap();
+
+            let mut services = HashMap::new();
+            services.insert("fail".into(), make_service("sh fail.sh", &[]));
+            services.insert(
+                "dependent".into(),
+                make_service("sh dependent.sh", &["fail"]),
+            );
+
+            let daemon = create_daemon(dir, services);
+            let result = daemon.start_services();
+            assert!(result.is_err());
+            assert!(!dir.join("started.log").exists());
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[test]
+    fn service_ordered_after_a_failed_service_still_starts() {
+        with_temp_home(|dir| {
+            fs::write(dir.join("fail.sh"), "echo fail >> started.log\nexit 1\n").unwrap();
+            fs::write(dir.join("after.sh"), "echo after >> started.log\n").unwrap();
+
+            let mut after = make_service("sh after.sh", &[]);
+            after.after = Some(vec!["z-fail".into()]);
+            let mut services = HashMap::new();
+            services.insert("z-fail".into(), make_service("sh fail.sh", &[]));
+            services.insert("a-after".into(), after);
+
+            let daemon = create_daemon(dir, services);
+            assert!(daemon.start_services().is_err());
+            assert_eq!(
+                fs::read_to_string(dir.join("started.log")).unwrap(),
+                "fail\nafter\n"
+            );
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[test]
+    fn unmet_condition_skips_service_and_its_dependents() {
+        with_temp_home(|dir| {
+            let mut gated = make_service("touch gated.ran", &[]);
+            gated.condition = Some(crate::config::ConditionConfig {
+                path_exists: vec!["enable.flag".into()],
+                ..Default::default()
+            });
+            let mut services = HashMap::new();
+            services.insert("gated".into(), gated);
+            services.insert(
+                "dependent".into(),
+                make_service("touch dependent.ran", &["gated"]),
+            );
+
+            let daemon = create_daemon(dir, services);
+            daemon.start_services().unwrap();
+            daemon.shutdown_monitor();
+
+            assert!(!dir.join("gated.ran").exists());
+            assert!(!dir.join("dependent.ran").exists());
+            for name in ["gated", "dependent"] {
+                assert_eq!(
+                    daemon.recorded_status(name),
+                    Some(ServiceLifecycleStatus::Skipped)
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn disabled_service_is_skipped_until_enabled() {
+        with_temp_home(|dir| {
+            let mut services = HashMap::new();
+            services.insert("worker".into(), make_service("touch worker.ran", &[]));
+
+            let daemon = create_daemon(dir, services);
+            daemon.set_service_disabled("worker", true).unwrap();
+            daemon.start_services().unwrap();
+            let service = daemon.cfg().services["worker"].clone();
+            daemon.start_service("worker", &service).unwrap();
+
+            assert!(!dir.join("worker.ran").exists());
+            assert_eq!(
+                daemon.recorded_status("worker"),
+                Some(ServiceLifecycleStatus::Skipped)
+            );
+            let reloaded = ServiceStateFile::load(daemon.store()).unwrap();
+            let key = daemon.cfg().state_key("worker");
+            assert!(reloaded.get(&key).is_some_and(|entry| entry.disabled));
+
+            daemon.set_service_disabled("worker", false).unwrap();
+            daemon.start_service("worker", &service).unwrap();
+            let deadline = Instant::now() + Duration::from_secs(5);
+            while !dir.join("worker.ran").exists() {
+                assert!(Instant::now() < deadline, "enabled service never ran");
+                thread::sleep(Duration::from_millis(50));
+            }
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[test]
+    fn dependents_stopped_when_dependency_crashes() {
+        with_temp_home(|dir| {
+            fs::write(
+                dir.join("parent.sh"),
+                "echo parent >> events.log\nsleep 1\nexit 1\n",
+            )
+            .unwrap();
+            fs::write(dir.join("child.sh"), "echo child >> events.log\nsleep 30\n")
+                .unwrap();
+
+            let mut services = HashMap::new();
+            services.insert("parent".into(), make_service("sh parent.sh", &[]));
+            services.insert("child".into(), make_service("sh child.sh", &["parent"]));
+
+            let daemon = create_daemon(dir, services);
+            daemon.start_services().unwrap();
+
+            let deadline = Instant::now() + Duration::from_secs(5);
+            loop {
+                if daemon.pid_file.lock().unwrap().get("child").is_none() {
+                    break;
+                }
+
+                if Instant::now() >= deadline {
+                    panic!("dependent service still recorded in pid file");
+                }
+
+                thread::sleep(Duration::from_millis(100));
+            }
+
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[test]
+    fn concurrent_pid_file_operations_no_lost_updates() {
+        with_temp_home(|_| {
+            let mut initial = PidFile {
                 store: StateStore::for_project("test"),
                 ..PidFile::default()
             };
@@ -8339,104 +10938,861 @@ sleep 30
     }
 
     #[test]
-    fn individual_service_stop_removes_from_tracking() {
+    fn individual_service_stop_removes_from_tracking() {
+        with_temp_home(|dir| {
+            let mut services = HashMap::new();
+            services.insert("test_service".into(), make_service("sleep 60", &[]));
+
+            let daemon = create_daemon(dir, services);
+            daemon.start_services().unwrap();
+
+            thread::sleep(Duration::from_millis(100));
+            assert!(
+                daemon
+                    .processes
+                    .lock()
+                    .unwrap()
+                    .contains_key("test_service")
+            );
+            assert!(
+                daemon
+                    .pid_file
+                    .lock()
+                    .unwrap()
+                    .get("test_service")
+                    .is_some()
+            );
+            daemon.stop_service("test_service").unwrap();
+            assert!(
+                !daemon
+                    .processes
+                    .lock()
+                    .unwrap()
+                    .contains_key("test_service")
+            );
+            assert!(
+                daemon
+                    .pid_file
+                    .lock()
+                    .unwrap()
+                    .get("test_service")
+                    .is_none()
+            );
+        });
+    }
+
+    #[test]
+    fn service_state_records_start_and_exit_times() {
+        let mut state = ServiceStateFile::default();
+        state.set_in_memory("svc", ServiceLifecycleStatus::Running, Some(10), None, None);
+        let first_start = state.get("svc").unwrap().started_at;
+        assert!(first_start.is_some());
+        assert!(state.get("svc").unwrap().finished_at.is_none());
+
+        state.set_in_memory(
+            "svc",
+            ServiceLifecycleStatus::ExitedWithError,
+            None,
+            Some(1),
+            None,
+        );
+        let exited = state.get("svc").unwrap().clone();
+        assert_eq!(exited.started_at, first_start);
+        assert!(exited.finished_at.is_some());
+
+        state.set_in_memory(
+            "svc",
+            ServiceLifecycleStatus::ExitedWithError,
+            None,
+            Some(1),
+            None,
+        );
+        assert_eq!(state.get("svc").unwrap().finished_at, exited.finished_at);
+
+        state.set_in_memory("svc", ServiceLifecycleStatus::Running, Some(11), None, None);
+        let restarted = state.get("svc").unwrap();
+        assert_ne!(restarted.started_at, first_start);
+        assert_eq!(restarted.finished_at, exited.finished_at);
+
+        let xml = xml::to_string(&state).unwrap();
+        let reloaded: ServiceStateFile = xml_from_str(&xml).unwrap();
+        let persisted = reloaded.get("svc").unwrap();
+        assert!(persisted.started_at.is_some());
+        assert!(persisted.finished_at.is_some());
+    }
+
+    #[test]
+    fn service_state_records_config_hash_only_on_launch() {
+        let now = SystemTime::now();
+        let running = |previous: Option<&ServiceStateEntry>, pid, hash| {
+            ServiceStateEntry::transition(
+                previous,
+                ServiceLifecycleStatus::Running,
+                Some(pid),
+                None,
+                None,
+                Some(hash),
+                now,
+            )
+        };
+
+        let launched = running(None, 10, "first");
+        assert_eq!(launched.config_hash.as_deref(), Some("first"));
+
+        let rerecorded = running(Some(&launched), 10, "second");
+        assert_eq!(rerecorded.config_hash.as_deref(), Some("first"));
+
+        let exited = ServiceStateEntry::transition(
+            Some(&rerecorded),
+            ServiceLifecycleStatus::ExitedWithError,
+            None,
+            Some(1),
+            None,
+            None,
+            now,
+        );
+        assert_eq!(exited.config_hash.as_deref(), Some("first"));
+
+        let relaunched = running(Some(&exited), 11, "second");
+        assert_eq!(relaunched.config_hash.as_deref(), Some("second"));
+    }
+
+    #[test]
+    fn service_state_counts_each_relaunch_as_a_restart() {
+        let now = SystemTime::now();
+        let running = |previous: Option<&ServiceStateEntry>, pid| {
+            ServiceStateEntry::transition(
+                previous,
+                ServiceLifecycleStatus::Running,
+                Some(pid),
+                None,
+                None,
+                None,
+                now,
+            )
+        };
+
+        let first = running(None, 10);
+        assert_eq!(first.restarts, 0);
+        assert_eq!(running(Some(&first), 10).restarts, 0);
+
+        let exited = ServiceStateEntry::transition(
+            Some(&first),
+            ServiceLifecycleStatus::ExitedWithError,
+            None,
+            Some(1),
+            None,
+            None,
+            now,
+        );
+        assert_eq!(exited.restarts, 0);
+        let second = running(Some(&exited), 11);
+        assert_eq!(second.restarts, 1);
+        assert_eq!(running(Some(&second), 12).restarts, 2);
+    }
+
+    #[test]
+    fn stop_service_terminates_tracked_children_in_their_own_group() {
+        use std::os::unix::process::CommandExt;
+
+        with_temp_home(|dir| {
+            let mut services = HashMap::new();
+            services.insert("parent".into(), make_service("sleep 60", &[]));
+
+            let daemon = create_daemon(dir, services);
+            daemon.start_services().unwrap();
+            thread::sleep(Duration::from_millis(100));
+            let parent_pid = daemon.pid_file.lock().unwrap().get("parent").unwrap();
+
+            let mut child = Command::new("sleep")
+                .arg("60")
+                .process_group(0)
+                .stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn()
+                .unwrap();
+            let child_pid = child.id();
+            assert_ne!(
+                Daemon::process_group_for_pid(child_pid),
+                Daemon::process_group_for_pid(parent_pid)
+            );
+            daemon
+                .pid_file
+                .lock()
+                .unwrap()
+                .record_spawn(PersistedSpawnChild {
+                    pid: child_pid,
+                    name: "worker".into(),
+                    command: "sleep 60".into(),
+                    started_at: SystemTime::now(),
+                    ttl_secs: None,
+                    depth: 1,
+                    parent_pid,
+                    service_hash: daemon.get_service_hash("parent"),
+                    cpu_percent: None,
+                    rss_bytes: None,
+                    last_exit: None,
+                })
+                .unwrap();
+
+            daemon.stop_service("parent").unwrap();
+
+            let deadline = Instant::now() + Duration::from_secs(5);
+            while Instant::now() < deadline && Daemon::pid_is_alive(child_pid) {
+                thread::sleep(Duration::from_millis(50));
+            }
+            let alive = Daemon::pid_is_alive(child_pid);
+            let _ = child.kill();
+            let _ = child.wait();
+            assert!(!alive, "tracked child in its own group should be stopped");
+        });
+    }
+
+    #[test]
+    fn stop_service_handles_termination_failure() {
+        with_temp_home(|dir| {
+            let mut services = HashMap::new();
+            services.insert(
+                "stubborn_service".into(),
+                make_service("sh -c 'trap \"\" TERM; sleep 10'", &[]),
+            );
+
+            let daemon = create_daemon(dir, services);
+            daemon.start_services().unwrap();
+
+            thread::sleep(Duration::from_millis(100));
+            assert!(
+                daemon
+                    .processes
+                    .lock()
+                    .unwrap()
+                    .contains_key("stubborn_service")
+            );
+            daemon.stop_service("stubborn_service").unwrap();
+            assert!(
+                !daemon
+                    .processes
+                    .lock()
+                    .unwrap()
+                    .contains_key("stubborn_service")
+            );
+        });
+    }
+
+    #[test]
+    fn start_individual_service_after_stop() {
+        with_temp_home(|dir| {
+            let mut service = make_service("echo 'test'", &[]);
+            service.restart_policy = Some(RestartPolicy::Never);
+
+            let mut services = HashMap::new();
+            services.insert("test_service".into(), service.clone());
+
+            let daemon = create_daemon(dir, services);
+            let result = daemon.start_service("test_service", &service).unwrap();
+            assert!(matches!(result, ServiceReadyState::CompletedSuccess));
+
+            thread::sleep(Duration::from_millis(100));
+            daemon.stop_service("test_service").unwrap();
+            assert!(
+                !daemon
+                    .processes
+                    .lock()
+                    .unwrap()
+                    .contains_key("test_service")
+            );
+            let result = daemon.start_service("test_service", &service).unwrap();
+            assert!(matches!(result, ServiceReadyState::CompletedSuccess));
+        });
+    }
+
+    #[test]
+    fn start_retries_relaunch_a_service_that_fails_during_startup() {
+        with_temp_home(|dir| {
+            let command = "n=$(cat attempts 2>/dev/null || echo 0); n=$((n + 1)); \
+                 echo $n > attempts; [ $n -ge 3 ] || exit 1";
+            let mut service = make_service(command, &[]);
+            service.restart_policy = Some(RestartPolicy::Never);
+            service.start_retries = Some(2);
+            service.start_retry_delay = Some("0s".into());
+
+            let mut services = HashMap::new();
+            services.insert("flaky".into(), service.clone());
+
+            let daemon = create_daemon(dir, services);
+            let result = daemon.start_service("flaky", &service).unwrap();
+            assert!(matches!(result, ServiceReadyState::CompletedSuccess));
+            assert_eq!(
+                fs::read_to_string(dir.join("attempts")).unwrap().trim(),
+                "3"
+            );
+        });
+    }
+
+    #[test]
+    fn ready_stable_for_catches_an_early_exit_and_readies_a_stable_service() {
+        with_temp_home(|dir| {
+            let mut early = make_service("sleep 0.08; exit 1", &[]);
+            early.restart_policy = Some(RestartPolicy::Never);
+            early.ready_stable_for = Some("100ms".into());
+            let mut late = make_service("sleep 0.4; exit 1", &[]);
+            late.restart_policy = Some(RestartPolicy::Never);
+            late.ready_stable_for = Some("1s".into());
+            let mut stable = make_service("sleep 30", &[]);
+            stable.ready_stable_for = Some("100ms".into());
+
+            let mut services = HashMap::new();
+            services.insert("early".into(), early.clone());
+            services.insert("late".into(), late.clone());
+            services.insert("stable".into(), stable.clone());
+
+            let daemon = create_daemon(dir, services);
+            assert!(daemon.start_service("early", &early).is_err());
+            // Past the default window, but inside this service's own.
+            assert!(daemon.start_service("late", &late).is_err());
+            let started = Instant::now();
+            let result = daemon.start_service("stable", &stable).unwrap();
+            assert!(matches!(result, ServiceReadyState::Running));
+            assert!(started.elapsed() >= Duration::from_millis(100));
+
+            daemon.stop_services().ok();
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[test]
+    fn ready_log_pattern_waits_for_the_matching_line() {
+        with_temp_home(|dir| {
+            let mut slow =
+                make_service("sleep 0.5; echo 'Listening on :8080'; sleep 30", &[]);
+            slow.ready_log_pattern = Some(r"Listening on :\d+".into());
+            slow.start_timeout = Some("10s".into());
+            let mut eager = make_service("echo 'Listening on :9090'; sleep 30", &[]);
+            eager.ready_log_pattern = Some(r"Listening on :\d+".into());
+            let mut silent = make_service("sleep 30", &[]);
+            silent.ready_log_pattern = Some("never printed".into());
+            silent.start_timeout = Some("300ms".into());
+
+            let mut services = HashMap::new();
+            services.insert("slow".into(), slow.clone());
+            services.insert("eager".into(), eager.clone());
+            services.insert("silent".into(), silent.clone());
+
+            let daemon = create_daemon(dir, services);
+            let started = Instant::now();
+            let result = daemon.start_service("slow", &slow).unwrap();
+            assert!(matches!(result, ServiceReadyState::Running));
+            assert!(started.elapsed() >= Duration::from_millis(500));
+
+            // Logged at once, likely before the waiter registers; still found.
+            let result = daemon.start_service("eager", &eager).unwrap();
+            assert!(matches!(result, ServiceReadyState::Running));
+
+            assert!(daemon.start_service("silent", &silent).is_err());
+            assert!(!daemon.processes.lock().unwrap().contains_key("silent"));
+
+            daemon.stop_services().ok();
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[test]
+    fn stop_service_reports_whether_sigkill_was_needed() {
+        with_temp_home(|dir| {
+            let polite = make_service("sleep 30", &[]);
+            let stubborn = make_service("trap '' TERM; while :; do sleep 0.1; done", &[]);
+
+            let mut services = HashMap::new();
+            services.insert("polite".into(), polite.clone());
+            services.insert("stubborn".into(), stubborn.clone());
+
+            let daemon = create_daemon(dir, services);
+            daemon.start_service("polite", &polite).unwrap();
+            daemon.start_service("stubborn", &stubborn).unwrap();
+
+            assert_eq!(
+                daemon.stop_service("polite").unwrap(),
+                StopOutcome::Graceful
+            );
+            let grace = Duration::from_millis(300);
+            assert_eq!(
+                daemon.stop_service_within("stubborn", grace).unwrap(),
+                StopOutcome::Killed { after: grace }
+            );
+            assert_eq!(
+                daemon.stop_service("polite").unwrap(),
+                StopOutcome::NotRunning
+            );
+
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[test]
+    fn stop_outcome_names_the_forced_kill() {
+        assert_eq!(StopOutcome::Graceful.to_string(), "stopped gracefully");
+        assert_eq!(
+            StopOutcome::Killed {
+                after: Duration::from_secs(1)
+            }
+            .to_string(),
+            "forcibly killed after 1s (service ignored SIGTERM)"
+        );
+        assert_eq!(
+            StopOutcome::Killed {
+                after: Duration::from_millis(300)
+            }
+            .to_string(),
+            "forcibly killed after 300ms (service ignored SIGTERM)"
+        );
+    }
+
+    #[test]
+    fn umask_applies_to_files_the_service_creates() {
+        use std::os::unix::fs::PermissionsExt;
+
+        with_temp_home(|dir| {
+            let mut service = make_service("touch created; sleep 30", &[]);
+            service.umask = Some("0077".into());
+
+            let mut services = HashMap::new();
+            services.insert("writer".into(), service.clone());
+
+            let daemon = create_daemon(dir, services);
+            daemon.start_service("writer", &service).unwrap();
+            let created = dir.join("created");
+            let deadline = Instant::now() + Duration::from_secs(5);
+            while !created.exists() && Instant::now() < deadline {
+                thread::sleep(Duration::from_millis(20));
+            }
+            let mode = fs::metadata(&created).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+
+            daemon.stop_services().ok();
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[test]
+    fn a_taken_declared_port_fails_the_start_until_it_is_freed() {
+        with_temp_home(|dir| {
+            let holder = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            let port = holder.local_addr().unwrap().port();
+
+            let mut service = make_service("sleep 30", &[]);
+            service.ports = Some(vec![port]);
+            let mut services = HashMap::new();
+            services.insert("api".into(), service.clone());
+
+            let daemon = create_daemon(dir, services);
+            let err = daemon.start_service("api", &service).unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains(&format!("port {port} already in use")),
+                "{err}"
+            );
+            assert!(daemon.pid_file.lock().unwrap().get("api").is_none());
+
+            drop(holder);
+            daemon.start_service("api", &service).unwrap();
+
+            daemon.stop_services().ok();
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn declared_sockets_are_passed_to_the_service_and_held_across_restarts() {
+        use std::net::{SocketAddr, TcpListener, TcpStream};
+
+        with_temp_home(|dir| {
+            let addr: SocketAddr = TcpListener::bind("127.0.0.1:0")
+                .unwrap()
+                .local_addr()
+                .unwrap();
+            let mut service = make_service(
+                "echo \"$LISTEN_FDS $LISTEN_PID $$ $(readlink /proc/$$/fd/3)\" >> activated; exec sleep 30",
+                &[],
+            );
+            service.sockets = Some(vec![crate::config::SocketConfig {
+                listen: addr.to_string(),
+            }]);
+
+            let mut services = HashMap::new();
+            services.insert("web".into(), service.clone());
+            let daemon = create_daemon(dir, services);
+            let project = daemon.config().project.id.clone();
+
+            let activations = |count: usize| {
+                let deadline = Instant::now() + Duration::from_secs(5);
+                loop {
+                    let lines = fs::read_to_string(dir.join("activated"))
+                        .unwrap_or_default()
+                        .lines()
+                        .map(str::to_string)
+                        .collect::<Vec<_>>();
+                    if lines.len() >= count || Instant::now() >= deadline {
+                        return lines;
+                    }
+                    thread::sleep(Duration::from_millis(20));
+                }
+            };
+
+            daemon.start_service("web", &service).unwrap();
+            let first = activations(1);
+            let fields: Vec<&str> = first[0].split(' ').collect();
+            assert_eq!(fields[0], "1");
+            assert_eq!(fields[1], fields[2], "LISTEN_PID must be the service's PID");
+            assert!(fields[3].starts_with("socket:"), "fd 3 is {}", fields[3]);
+
+            // The supervisor keeps the port bound while the service is down, so
+            // a client connecting mid-restart waits in the backlog.
+            daemon.stop_service("web").unwrap();
+            assert!(TcpStream::connect(addr).is_ok());
+
+            daemon.start_service("web", &service).unwrap();
+            let second = activations(2);
+            assert_eq!(second[1].split(' ').nth(3), Some(fields[3]));
+            daemon.stop_services().ok();
+
+            let mut config = (*daemon.config()).clone();
+            config.services.get_mut("web").unwrap().sockets = None;
+            daemon.set_config(config);
+            assert!(!sockets::is_held(&project, addr));
+            assert!(TcpListener::bind(addr).is_ok());
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[test]
+    fn an_interactive_service_reads_what_attach_relays() {
+        with_temp_home(|dir| {
+            let mut repl =
+                make_service("while read -r line; do echo \"$line\" >> fed; done", &[]);
+            repl.interactive = Some(true);
+            let plain = make_service("exec sleep 30", &[]);
+
+            let mut services = HashMap::new();
+            services.insert("attach-repl".into(), repl.clone());
+            services.insert("attach-plain".into(), plain.clone());
+            let daemon = create_daemon(dir, services);
+            let project = daemon.config().project.id.clone();
+
+            daemon.start_service("attach-repl", &repl).unwrap();
+            daemon.start_service("attach-plain", &plain).unwrap();
+            assert!(attach::is_attachable(&project, "attach-repl"));
+            assert!(!attach::is_attachable(&project, "attach-plain"));
+
+            // Each attach ends with its input; the service keeps reading.
+            attach::relay(&project, "attach-repl", &b"first\n"[..]).unwrap();
+            attach::relay(&project, "attach-repl", &b"second\n"[..]).unwrap();
+            let deadline = Instant::now() + Duration::from_secs(5);
+            let fed = loop {
+                let fed = fs::read_to_string(dir.join("fed")).unwrap_or_default();
+                if fed.lines().count() >= 2 || Instant::now() >= deadline {
+                    break fed;
+                }
+                thread::sleep(Duration::from_millis(20));
+            };
+            assert_eq!(fed, "first\nsecond\n");
+            assert!(daemon.running_pid("attach-repl").is_some());
+
+            daemon.stop_services().ok();
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[test]
+    fn a_pty_service_writes_to_a_terminal_that_lands_in_its_log() {
+        with_temp_home(|dir| {
+            let mut service = make_service(
+                "[ -t 1 ] && echo stdout-is-tty; [ -t 2 ] && echo stderr-is-tty >&2; exec sleep 30",
+                &[],
+            );
+            service.pty = Some(true);
+            let mut services = HashMap::new();
+            services.insert("pty-app".into(), service.clone());
+
+            let daemon = create_daemon(dir, services);
+            daemon.start_service("pty-app", &service).unwrap();
+
+            let log_path =
+                crate::logs::get_service_log_path(&daemon.config().project.id, "pty-app");
+            let deadline = Instant::now() + Duration::from_secs(5);
+            let mut contents = String::new();
+            while Instant::now() < deadline {
+                contents = fs::read_to_string(&log_path).unwrap_or_default();
+                if contents.contains("stderr-is-tty") {
+                    break;
+                }
+                thread::sleep(Duration::from_millis(50));
+            }
+            assert!(contents.contains("stdout-is-tty"), "log was: {contents:?}");
+            assert!(contents.contains("stderr-is-tty"), "log was: {contents:?}");
+            assert!(!contents.contains('\r'), "log was: {contents:?}");
+
+            daemon.stop_services().ok();
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[test]
+    fn stop_services_stops_dependents_first_and_waits_out_slow_exits() {
+        with_temp_home(|dir| {
+            // The dependency sorts first by name and needs 500ms to flush after
+            // SIGTERM, well past a flat grace but inside its own stop_timeout.
+            let mut store = make_service(
+                "trap 'date +%s%N > store.term; sleep 0.5; echo flushed > store.done; exit 0' TERM; \
+                 while :; do sleep 0.05; done",
+                &[],
+            );
+            store.stop_timeout = Some("3s".into());
+            let web = make_service(
+                "trap 'date +%s%N > web.term; exit 0' TERM; while :; do sleep 0.05; done",
+                &["a-store"],
+            );
+            let mut services = HashMap::new();
+            services.insert("a-store".into(), store);
+            services.insert("z-web".into(), web);
+
+            let daemon = create_daemon(dir, services);
+            daemon.start_services().unwrap();
+            daemon.stop_services().unwrap();
+
+            let stamp = |file: &str| -> u128 {
+                fs::read_to_string(dir.join(file))
+                    .unwrap()
+                    .trim()
+                    .parse()
+                    .unwrap()
+            };
+            assert!(stamp("web.term") <= stamp("store.term"));
+            assert_eq!(
+                fs::read_to_string(dir.join("store.done")).unwrap(),
+                "flushed\n"
+            );
+
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[test]
+    fn concurrent_restarts_of_one_service_are_serialized() {
         with_temp_home(|dir| {
+            let service = make_service("echo started >> starts; exec sleep 30", &[]);
             let mut services = HashMap::new();
-            services.insert("test_service".into(), make_service("sleep 60", &[]));
+            services.insert("app".into(), service.clone());
 
             let daemon = create_daemon(dir, services);
             daemon.start_services().unwrap();
 
-            thread::sleep(Duration::from_millis(100));
-            assert!(
-                daemon
-                    .processes
-                    .lock()
-                    .unwrap()
-                    .contains_key("test_service")
-            );
-            assert!(
-                daemon
-                    .pid_file
-                    .lock()
-                    .unwrap()
-                    .get("test_service")
-                    .is_some()
-            );
-            daemon.stop_service("test_service").unwrap();
-            assert!(
-                !daemon
-                    .processes
-                    .lock()
-                    .unwrap()
-                    .contains_key("test_service")
-            );
-            assert!(
-                daemon
-                    .pid_file
-                    .lock()
-                    .unwrap()
-                    .get("test_service")
-                    .is_none()
-            );
+            let barrier = std::sync::Barrier::new(2);
+            let results = thread::scope(|scope| {
+                let restarts: Vec<_> = (0..2)
+                    .map(|_| {
+                        scope.spawn(|| {
+                            barrier.wait();
+                            daemon.restart_service("app", &service, false)
+                        })
+                    })
+                    .collect();
+                restarts
+                    .into_iter()
+                    .map(|restart| restart.join().unwrap())
+                    .collect::<Vec<_>>()
+            });
+
+            assert_eq!(results.iter().filter(|result| result.is_ok()).count(), 1);
+            assert!(results.iter().any(|result| matches!(
+                result,
+                Err(ProcessManagerError::RestartInProgress(name)) if name == "app"
+            )));
+            let starts = fs::read_to_string(dir.join("starts")).unwrap();
+            assert_eq!(starts.lines().count(), 2, "one start, one restart");
+            assert_eq!(daemon.processes.lock().unwrap().len(), 1);
+
+            daemon.restart_service("app", &service, false).unwrap();
+            daemon.stop_services().ok();
+            daemon.shutdown_monitor();
         });
     }
 
     #[test]
-    fn stop_service_handles_termination_failure() {
+    fn start_failure_without_retries_is_not_relaunched() {
         with_temp_home(|dir| {
+            let command = "n=$(cat attempts 2>/dev/null || echo 0); n=$((n + 1)); \
+                 echo $n > attempts; [ $n -ge 2 ] || exit 1";
+            let mut service = make_service(command, &[]);
+            service.restart_policy = Some(RestartPolicy::Never);
+
             let mut services = HashMap::new();
-            services.insert(
-                "stubborn_service".into(),
-                make_service("sh -c 'trap \"\" TERM; sleep 10'", &[]),
-            );
+            services.insert("flaky".into(), service.clone());
 
             let daemon = create_daemon(dir, services);
-            daemon.start_services().unwrap();
-
-            thread::sleep(Duration::from_millis(100));
-            assert!(
-                daemon
-                    .processes
-                    .lock()
-                    .unwrap()
-                    .contains_key("stubborn_service")
-            );
-            daemon.stop_service("stubborn_service").unwrap();
-            assert!(
-                !daemon
-                    .processes
-                    .lock()
-                    .unwrap()
-                    .contains_key("stubborn_service")
+            assert!(daemon.start_service("flaky", &service).is_err());
+            assert_eq!(
+                fs::read_to_string(dir.join("attempts")).unwrap().trim(),
+                "1"
             );
         });
     }
 
     #[test]
-    fn start_individual_service_after_stop() {
+    fn health_retry_delay_doubles_up_to_the_cap_when_exponential() {
+        let interval = Duration::from_millis(100);
+        let cap = Duration::from_millis(500);
+        let delays = (1..=5)
+            .map(|attempt| {
+                Daemon::health_retry_delay(
+                    interval,
+                    HealthBackoff::Exponential,
+                    cap,
+                    attempt,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            delays,
+            [100, 200, 400, 500, 500]
+                .map(Duration::from_millis)
+                .to_vec()
+        );
+        assert_eq!(
+            Daemon::health_retry_delay(interval, HealthBackoff::Fixed, cap, 5),
+            interval
+        );
+        assert_eq!(
+            Daemon::health_retry_delay(interval, HealthBackoff::Exponential, cap, 200),
+            cap
+        );
+    }
+
+    #[cfg(feature = "http-health")]
+    #[test]
+    fn health_check_backs_off_until_a_late_server_answers() {
         with_temp_home(|dir| {
-            let mut service = make_service("echo 'test'", &[]);
-            service.restart_policy = Some("never".into());
+            let port = std::net::TcpListener::bind("127.0.0.1:0")
+                .unwrap()
+                .local_addr()
+                .unwrap()
+                .port();
+            let server = thread::spawn(move || {
+                thread::sleep(Duration::from_millis(700));
+                let listener = std::net::TcpListener::bind(("127.0.0.1", port)).unwrap();
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = [0u8; 1024];
+                let _ = std::io::Read::read(&mut stream, &mut request);
+                std::io::Write::write_all(
+                    &mut stream,
+                    b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                )
+                .unwrap();
+            });
 
+            let mut service = make_service("sleep 30", &[]);
+            service.restart_policy = Some(RestartPolicy::Never);
+            service.deployment = Some(
+                serde_yaml::from_str(&format!(
+                    r#"
+health_check:
+  url: "http://127.0.0.1:{port}/health"
+  interval: "50ms"
+  backoff: exponential
+  max_interval: "400ms"
+  connect_timeout: "500ms"
+  request_timeout: "2s"
+  retries: 1
+  total_timeout: "10s"
+"#
+                ))
+                .unwrap(),
+            );
             let mut services = HashMap::new();
-            services.insert("test_service".into(), service.clone());
+            services.insert("api".into(), service.clone());
 
             let daemon = create_daemon(dir, services);
-            let result = daemon.start_service("test_service", &service).unwrap();
-            assert!(matches!(result, ServiceReadyState::CompletedSuccess));
+            let started = Instant::now();
+            assert!(matches!(
+                daemon.start_service("api", &service).unwrap(),
+                ServiceReadyState::Running
+            ));
+            assert!(started.elapsed() >= Duration::from_millis(700));
+            server.join().unwrap();
+            daemon.stop_service("api").unwrap();
+        });
+    }
 
-            thread::sleep(Duration::from_millis(100));
-            daemon.stop_service("test_service").unwrap();
-            assert!(
-                !daemon
-                    .processes
-                    .lock()
-                    .unwrap()
-                    .contains_key("test_service")
+    #[cfg(feature = "http-health")]
+    #[test]
+    fn health_check_gives_up_on_an_unresponsive_endpoint() {
+        with_temp_home(|dir| {
+            // Bound but never accepted: the connection lands in the backlog and
+            // the request is never answered.
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            let port = listener.local_addr().unwrap().port();
+
+            let mut service = make_service("sleep 30", &[]);
+            service.restart_policy = Some(RestartPolicy::Never);
+            service.deployment = Some(
+                serde_yaml::from_str(&format!(
+                    r#"
+health_check:
+  url: "http://127.0.0.1:{port}/health"
+  interval: "50ms"
+  attempt_timeout: "300ms"
+  retries: 2
+"#
+                ))
+                .unwrap(),
             );
-            let result = daemon.start_service("test_service", &service).unwrap();
-            assert!(matches!(result, ServiceReadyState::CompletedSuccess));
+            let mut services = HashMap::new();
+            services.insert("api".into(), service.clone());
+
+            let daemon = create_daemon(dir, services);
+            let started = Instant::now();
+            let err = daemon.start_service("api", &service).unwrap_err();
+            assert!(started.elapsed() < Duration::from_secs(5));
+            assert!(err.to_string().contains("timed out"), "{err}");
+            drop(listener);
+            let _ = daemon.stop_service("api");
+        });
+    }
+
+    #[cfg(feature = "http-health")]
+    #[test]
+    fn health_check_fails_fast_after_consecutive_refusals() {
+        with_temp_home(|dir| {
+            let port = std::net::TcpListener::bind("127.0.0.1:0")
+                .unwrap()
+                .local_addr()
+                .unwrap()
+                .port();
+
+            let mut service = make_service("sleep 30", &[]);
+            service.restart_policy = Some(RestartPolicy::Never);
+            service.deployment = Some(
+                serde_yaml::from_str(&format!(
+                    r#"
+health_check:
+  url: "http://127.0.0.1:{port}/health"
+  interval: "200ms"
+  total_timeout: "30s"
+  max_refused: 3
+"#
+                ))
+                .unwrap(),
+            );
+            let mut services = HashMap::new();
+            services.insert("api".into(), service.clone());
+
+            let daemon = create_daemon(dir, services);
+            let started = Instant::now();
+            let err = daemon.start_service("api", &service).unwrap_err();
+            assert!(started.elapsed() < Duration::from_secs(5));
+            assert!(err.to_string().contains("could not reach"), "{err}");
+            let _ = daemon.stop_service("api");
         });
     }
 
@@ -8445,7 +11801,7 @@ sleep 30
     fn manual_stop_flag_prevents_restart() {
         with_temp_home(|dir| {
             let mut service = make_service("sh -c 'sleep 1 && exit 1'", &[]);
-            service.restart_policy = Some("always".into());
+            service.restart_policy = Some(RestartPolicy::Always);
 
             let mut services = HashMap::new();
             services.insert("test_service".into(), service);
@@ -8511,6 +11867,9 @@ sleep 30
                     }),
                 }),
                 on_restart: None,
+                on_deploy_start: None,
+                on_deploy_success: None,
+                on_deploy_failure: None,
             };
 
             let mut service = make_service("sleep 60", &[]);
@@ -8531,6 +11890,213 @@ sleep 30
         });
     }
 
+    #[test]
+    fn stop_terminates_children_that_left_the_session_with_setsid() {
+        with_temp_home(|dir| {
+            fs::write(
+                dir.join("escape.sh"),
+                "(setsid sh -c 'echo $$ > escaped.pid; exec sleep 30' &)\nexec sleep 30\n",
+            )
+            .unwrap();
+            let mut services = HashMap::new();
+            services.insert("app".into(), make_service("sh escape.sh", &[]));
+
+            let daemon = create_daemon(dir, services);
+            daemon.start_services().unwrap();
+            let pid_path = dir.join("escaped.pid");
+            let deadline = Instant::now() + Duration::from_secs(5);
+            let escaped = loop {
+                if let Some(pid) = fs::read_to_string(&pid_path)
+                    .ok()
+                    .and_then(|raw| raw.trim().parse::<u32>().ok())
+                {
+                    break pid;
+                }
+                assert!(Instant::now() < deadline, "escaped child never started");
+                thread::sleep(Duration::from_millis(20));
+            };
+            let leader = daemon
+                .pid_file_handle()
+                .lock()
+                .unwrap()
+                .pid_for("app")
+                .expect("service pid");
+            assert_ne!(
+                unsafe { libc::getsid(escaped as libc::pid_t) },
+                leader as i32
+            );
+
+            daemon.stop_service("app").unwrap();
+            let deadline = Instant::now() + Duration::from_secs(5);
+            while Daemon::pid_is_alive(escaped) && Instant::now() < deadline {
+                thread::sleep(Duration::from_millis(20));
+            }
+            assert!(
+                !Daemon::pid_is_alive(escaped),
+                "process {escaped} escaped the stop via setsid"
+            );
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[test]
+    fn build_first_restart_keeps_old_instance_when_build_fails() {
+        with_temp_home(|dir| {
+            fs::write(dir.join("build.txt"), "ok\n").unwrap();
+            fs::write(
+                dir.join("build.sh"),
+                "echo build >> builds.log\n[ \"$(cat build.txt)\" = ok ]\n",
+            )
+            .unwrap();
+            let mut service = make_service("sleep 30", &[]);
+            service.deployment = Some(crate::config::DeploymentConfig {
+                strategy: Some("immediate".into()),
+                pre_start: Some("sh build.sh".into()),
+                health_check: None,
+                grace_period: None,
+                blue_green: None,
+            });
+            let mut services = HashMap::new();
+            services.insert("app".into(), service.clone());
+
+            let daemon = create_daemon(dir, services);
+            daemon.start_services().unwrap();
+            let pid = || daemon.pid_file_handle().lock().unwrap().pid_for("app");
+            let builds = || {
+                fs::read_to_string(dir.join("builds.log"))
+                    .unwrap()
+                    .lines()
+                    .count()
+            };
+            assert_eq!(builds(), 1);
+
+            daemon.restart_service("app", &service, true).unwrap();
+            assert_eq!(builds(), 2, "a build-first restart builds once");
+            let serving = pid().expect("restarted instance");
+
+            fs::write(dir.join("build.txt"), "broken\n").unwrap();
+            assert!(daemon.restart_service("app", &service, true).is_err());
+            assert_eq!(pid(), Some(serving), "old instance keeps serving");
+            assert!(
+                Daemon::pid_is_alive(serving),
+                "old instance should still be running"
+            );
+            daemon.stop_services().unwrap();
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[test]
+    fn rolling_restart_runs_deploy_hooks_around_the_attempt() {
+        with_temp_home(|dir| {
+            let hook_log = dir.join("deploy.log");
+            fs::write(dir.join("mode.txt"), "ok\n").unwrap();
+            fs::write(
+                dir.join("app.sh"),
+                "if [ \"$(cat mode.txt)\" = ok ]; then exec sleep 30; fi\nexit 1\n",
+            )
+            .unwrap();
+            let hook = |label: &str| {
+                Some(crate::config::HookAction {
+                    command: format!(
+                        "echo \"{label} $DEPLOY_ENV\" >> {}",
+                        hook_log.display()
+                    ),
+                    timeout: None,
+                })
+            };
+
+            let mut service = make_service("sh app.sh", &[]);
+            service.deployment = Some(crate::config::DeploymentConfig {
+                strategy: Some("rolling".into()),
+                pre_start: None,
+                health_check: None,
+                grace_period: None,
+                blue_green: None,
+            });
+            service.env = Some(EnvConfig {
+                vars: Some(HashMap::from([("DEPLOY_ENV".into(), "prod".into())])),
+                ..EnvConfig::default()
+            });
+            service.hooks = Some(crate::config::Hooks {
+                on_start: None,
+                on_stop: None,
+                on_restart: None,
+                on_deploy_start: hook("start"),
+                on_deploy_success: hook("success"),
+                on_deploy_failure: hook("failure"),
+            });
+            let mut services = HashMap::new();
+            services.insert("app".into(), service.clone());
+
+            let daemon = create_daemon(dir, services);
+            daemon.start_services().unwrap();
+            daemon.restart_service("app", &service, false).unwrap();
+            assert_eq!(
+                fs::read_to_string(&hook_log).unwrap(),
+                "start prod\nsuccess prod\n"
+            );
+
+            fs::write(dir.join("mode.txt"), "broken\n").unwrap();
+            assert!(daemon.restart_service("app", &service, false).is_err());
+            assert_eq!(
+                fs::read_to_string(&hook_log).unwrap(),
+                "start prod\nsuccess prod\nstart prod\nfailure prod\n"
+            );
+
+            daemon.stop_services().ok();
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[test]
+    fn rolling_restart_of_a_service_with_declared_ports_replaces_it() {
+        with_temp_home(|dir| {
+            let port = std::net::TcpListener::bind("127.0.0.1:0")
+                .unwrap()
+                .local_addr()
+                .unwrap()
+                .port();
+            let mut service = make_service(
+                &format!(
+                    "exec python3 -c \"import socket, time; s = socket.socket(); \
+                     s.bind(('0.0.0.0', {port})); s.listen(); time.sleep(30)\""
+                ),
+                &[],
+            );
+            service.ports = Some(vec![port]);
+            service.deployment = Some(crate::config::DeploymentConfig {
+                strategy: Some("rolling".into()),
+                pre_start: None,
+                health_check: None,
+                grace_period: None,
+                blue_green: None,
+            });
+            let mut services = HashMap::new();
+            services.insert("api".into(), service.clone());
+            let await_listening = || {
+                let deadline = Instant::now() + Duration::from_secs(5);
+                while !port_taken(port) {
+                    assert!(Instant::now() < deadline, "api never bound {port}");
+                    thread::sleep(Duration::from_millis(20));
+                }
+            };
+
+            let daemon = create_daemon(dir, services);
+            daemon.start_services().unwrap();
+            await_listening();
+            let first = daemon.pid_file.lock().unwrap().get("api").unwrap();
+
+            daemon.restart_service("api", &service, false).unwrap();
+            await_listening();
+            let second = daemon.pid_file.lock().unwrap().get("api").unwrap();
+            assert_ne!(first, second);
+
+            daemon.stop_services().ok();
+            daemon.shutdown_monitor();
+        });
+    }
+
     #[test]
     fn terminate_process_tree_kills_all_descendants() {
         with_temp_home(|_| {
@@ -8568,4 +12134,80 @@ sleep 30
             let _ = parent.wait();
         });
     }
+
+    #[test]
+    fn collect_service_env_resolves_command_values() {
+        let dir = tempfile::tempdir().unwrap();
+        let env = Some(EnvConfig {
+            vars: Some(HashMap::from([
+                (
+                    "DB_PASS".to_string(),
+                    "!cmd: printf '  s3cr3t-from-cmd\\n'".to_string(),
+                ),
+                ("PLAIN".to_string(), "as-is".to_string()),
+            ])),
+            ..EnvConfig::default()
+        });
+
+        let vars = collect_service_env(&env, dir.path(), "api").unwrap();
+
+        assert_eq!(vars["DB_PASS"], "s3cr3t-from-cmd");
+        assert_eq!(vars["PLAIN"], "as-is");
+        assert_eq!(
+            snapshot::mask_resolved_secrets("connecting with s3cr3t-from-cmd"),
+            "connecting with ***"
+        );
+        assert_eq!(
+            declared_service_env(&env, dir.path(), "api")["DB_PASS"],
+            "!cmd: printf '  s3cr3t-from-cmd\\n'"
+        );
+    }
+
+    #[test]
+    fn restarted_service_sees_the_current_env_file() {
+        with_temp_home(|dir| {
+            fs::write(dir.join(".env"), "GREETING=hello\n").unwrap();
+            let mut service = make_service(
+                "sh -c 'echo \"$GREETING\" >> seen.txt; exec sleep 30'",
+                &[],
+            );
+            service.env = Some(EnvConfig {
+                file: Some(".env".into()),
+                ..EnvConfig::default()
+            });
+            let mut services = HashMap::new();
+            services.insert("app".into(), service.clone());
+
+            let daemon = create_daemon(dir, services);
+            daemon.start_services().unwrap();
+            fs::write(dir.join(".env"), "GREETING=bonjour\n").unwrap();
+            daemon.restart_service("app", &service, false).unwrap();
+
+            assert_eq!(
+                fs::read_to_string(dir.join("seen.txt")).unwrap(),
+                "hello\nbonjour\n",
+                "a restart reads the env file again"
+            );
+            daemon.stop_services().ok();
+            daemon.shutdown_monitor();
+        });
+    }
+
+    #[test]
+    fn collect_service_env_fails_when_an_env_command_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let env = Some(EnvConfig {
+            vars: Some(HashMap::from([(
+                "DB_PASS".to_string(),
+                "!cmd: echo 'permission denied' >&2; exit 3".to_string(),
+            )])),
+            ..EnvConfig::default()
+        });
+
+        let err = collect_service_env(&env, dir.path(), "api").unwrap_err();
+
+        let message = err.to_string();
+        assert!(message.contains("`DB_PASS`"), "{message}");
+        assert!(message.contains("permission denied"), "{message}");
+    }
 }
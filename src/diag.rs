@@ -79,6 +79,9 @@ pub enum SgCode {
     HealthCheckUnreachable,
     /// SG0023 — no health check probe completed within the per-attempt budget.
     HealthCheckTimeout,
+    /// SG0024 — `logs --clear`/`--clear-all` had no `--yes` and no terminal to
+    /// confirm on, so nothing was cleared.
+    LogsClearUnconfirmed,
     /// SG0101 — a direct lifecycle command targeted a schedule-driven cron unit.
     CronDirectControl,
     /// SG0102 — a service exited immediately at start, before it came up.
@@ -180,6 +183,7 @@ impl SgCode {
             SgCode::LooseServiceNotFound => "SG0021",
             SgCode::HealthCheckUnreachable => "SG0022",
             SgCode::HealthCheckTimeout => "SG0023",
+            SgCode::LogsClearUnconfirmed => "SG0024",
             SgCode::CronDirectControl => "SG0101",
             SgCode::UnitImmediateExit => "SG0102",
             SgCode::PreStartFailed => "SG0103",
@@ -215,7 +219,7 @@ impl SgCode {
     }
 
     /// Every code, so callers can enumerate or round-trip the taxonomy.
-    pub const ALL: [SgCode; 48] = [
+    pub const ALL: [SgCode; 49] = [
         SgCode::Catchall,
         SgCode::CronStateRecoveryFailed,
         SgCode::CronRegistrationConflict,
@@ -238,6 +242,7 @@ impl SgCode {
         SgCode::LooseServiceNotFound,
         SgCode::HealthCheckUnreachable,
         SgCode::HealthCheckTimeout,
+        SgCode::LogsClearUnconfirmed,
         SgCode::CronDirectControl,
         SgCode::UnitImmediateExit,
         SgCode::PreStartFailed,
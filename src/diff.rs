@@ -0,0 +1,219 @@
+//! Compares what is running against what the configuration declares.
+//!
+//! `sysg diff` answers "is what's running what the config says?" from disk
+//! alone: it loads the manifest, builds a status snapshot from the persisted
+//! pid and state files, and sorts every mismatch into one of three buckets.
+//! Rendering is left to the caller so it can respect output-format flags.
+
+use serde::Serialize;
+
+use crate::{
+    config::{Config, load_config},
+    daemon::ServiceStateFile,
+    state_store::StateStore,
+    status::{
+        ProcessState, StatusError, StatusSnapshot, UnitIntent, UnitKind, UnitState,
+        collect_disk_snapshot,
+    },
+};
+
+/// Prefix status gives the display name of a unit the config no longer has.
+const ORPHANED_PREFIX: &str = "[orphaned] ";
+
+/// Differences between a configuration and the services on this machine.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct DiffReport {
+    /// Path of the configuration that was compared.
+    pub config: String,
+    /// Long-running services the config declares that are not running.
+    pub not_running: Vec<String>,
+    /// Live processes of services the config no longer declares.
+    pub orphans: Vec<String>,
+    /// Running services launched from a config that has since changed.
+    pub drifted: Vec<String>,
+}
+
+impl DiffReport {
+    /// Returns whether what is running matches the configuration.
+    pub fn in_sync(&self) -> bool {
+        self.not_running.is_empty() && self.orphans.is_empty() && self.drifted.is_empty()
+    }
+}
+
+/// Loads the configuration at `path` and compares it with the persisted state
+/// of its project.
+pub fn diff(path: &str) -> Result<DiffReport, StatusError> {
+    let config = load_config(Some(path))?;
+    let state = ServiceStateFile::load(StateStore::for_project(&config.project.id))?;
+    let snapshot = collect_disk_snapshot(Some(config.clone()), false)?;
+    let mut report = compare(&config, &snapshot, |name| {
+        state
+            .get(&config.state_key(name))
+            .is_some_and(|entry| entry.disabled)
+    });
+    report.config = path.to_string();
+    Ok(report)
+}
+
+/// Sorts the units of `snapshot` that disagree with `config`. A service that
+/// exited cleanly has done its job, and one that is `disabled` is expected to
+/// be down, so neither counts as not running.
+pub fn compare(
+    config: &Config,
+    snapshot: &StatusSnapshot,
+    disabled: impl Fn(&str) -> bool,
+) -> DiffReport {
+    let mut report = DiffReport::default();
+    for unit in &snapshot.units {
+        let live = unit
+            .process
+            .as_ref()
+            .is_some_and(|process| process.state == ProcessState::Running);
+        match unit.kind {
+            UnitKind::Orphaned if live => report.orphans.push(
+                unit.name
+                    .strip_prefix(ORPHANED_PREFIX)
+                    .unwrap_or(&unit.name)
+                    .to_string(),
+            ),
+            UnitKind::Service if !config.services.contains_key(&unit.name) => {}
+            UnitKind::Service if unit.drifted => report.drifted.push(unit.name.clone()),
+            UnitKind::Service
+                if unit.intent == UnitIntent::Serve
+                    && !matches!(unit.state, UnitState::Running | UnitState::Done)
+                    && !disabled(&unit.name) =>
+            {
+                report.not_running.push(unit.name.clone());
+            }
+            _ => {}
+        }
+    }
+    // A removed service can leave more than one state entry behind.
+    for names in [
+        &mut report.not_running,
+        &mut report.orphans,
+        &mut report.drifted,
+    ] {
+        names.sort_unstable();
+        names.dedup();
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::parse_config_manifest,
+        daemon::ServiceLifecycleStatus,
+        status::{ProcessRuntime, UnitHealth, UnitStatus},
+    };
+
+    fn unit(
+        name: &str,
+        kind: UnitKind,
+        intent: UnitIntent,
+        pid: Option<u32>,
+    ) -> UnitStatus {
+        UnitStatus {
+            name: name.into(),
+            hash: format!("hash-{name}"),
+            project: None,
+            kind,
+            lifecycle: Some(if pid.is_some() {
+                ServiceLifecycleStatus::Running
+            } else {
+                ServiceLifecycleStatus::Stopped
+            }),
+            state: if pid.is_some() {
+                UnitState::Running
+            } else {
+                UnitState::Stopped
+            },
+            intent,
+            health: UnitHealth::Healthy,
+            process: pid.map(|pid| ProcessRuntime {
+                pid,
+                state: ProcessState::Running,
+                user: None,
+            }),
+            uptime: None,
+            last_exit: None,
+            cron: None,
+            metrics: None,
+            command: None,
+            runtime_command: None,
+            spawned_children: Vec::new(),
+            drifted: false,
+            restarts: 0,
+            gave_up_after: None,
+        }
+    }
+
+    #[test]
+    fn compare_sorts_mismatches_into_their_buckets() {
+        let config = parse_config_manifest(
+            r#"
+version: "2"
+services:
+  api:
+    command: "serve"
+    restart_policy: always
+  worker:
+    command: "work"
+    restart_policy: always
+  web:
+    command: "web"
+    restart_policy: always
+  paused:
+    command: "pause"
+    restart_policy: always
+  migrate:
+    command: "migrate"
+  seed:
+    command: "seed"
+"#,
+        )
+        .unwrap();
+
+        let mut seed = unit("seed", UnitKind::Service, UnitIntent::Serve, None);
+        seed.state = UnitState::Done;
+        let mut web = unit("web", UnitKind::Service, UnitIntent::Serve, Some(12));
+        web.drifted = true;
+        let snapshot = StatusSnapshot {
+            units: vec![
+                unit("api", UnitKind::Service, UnitIntent::Serve, Some(10)),
+                unit("worker", UnitKind::Service, UnitIntent::Serve, None),
+                web,
+                seed,
+                unit("paused", UnitKind::Service, UnitIntent::Serve, None),
+                unit("migrate", UnitKind::Service, UnitIntent::Once, None),
+                unit(
+                    "[orphaned] old",
+                    UnitKind::Orphaned,
+                    UnitIntent::Orphan,
+                    Some(13),
+                ),
+                unit(
+                    "[orphaned] gone",
+                    UnitKind::Orphaned,
+                    UnitIntent::Orphan,
+                    None,
+                ),
+            ],
+            ..StatusSnapshot::empty()
+        };
+
+        let report = compare(&config, &snapshot, |name| name == "paused");
+        assert_eq!(report.not_running, ["worker"]);
+        assert_eq!(report.orphans, ["old"]);
+        assert_eq!(report.drifted, ["web"]);
+        assert!(!report.in_sync());
+
+        let running = StatusSnapshot {
+            units: vec![unit("api", UnitKind::Service, UnitIntent::Serve, Some(10))],
+            ..StatusSnapshot::empty()
+        };
+        assert!(compare(&config, &running, |_| false).in_sync());
+    }
+}
@@ -79,6 +79,26 @@ pub enum ProcessManagerError {
         dependency: String,
     },
 
+    /// Error when an `after` or `before` entry names an undefined service.
+    #[error("Service '{service}' is ordered against unknown service '{target}'")]
+    UnknownOrderingTarget {
+        /// The service with an invalid ordering reference.
+        service: String,
+        /// The missing service name.
+        target: String,
+    },
+
+    /// Error when `before`/`after` ordering contradicts a `depends_on` edge.
+    #[error(
+        "Service '{service}' depends on '{dependency}' but is ordered to start before it"
+    )]
+    OrderingConflict {
+        /// The service ordered ahead of its own dependency.
+        service: String,
+        /// The dependency it is ordered ahead of.
+        dependency: String,
+    },
+
     /// Error when dependency graph contains a cycle.
     #[error("Detected dependency cycle: {cycle}")]
     DependencyCycle {
@@ -112,6 +132,13 @@ pub enum ProcessManagerError {
         source: std::io::Error,
     },
 
+    /// Error when a best-effort start leaves more than one service failed.
+    #[error("{} services failed to start: {}", .failures.len(), .failures.join("; "))]
+    ServicesFailedToStart {
+        /// One `service: reason` entry per failed service, in start order.
+        failures: Vec<String>,
+    },
+
     /// Error when services fail to remain running after a restart completes.
     #[error("Service(s) failed to remain running after restart: {services:?}")]
     ServicesNotRunning {
@@ -119,6 +146,10 @@ pub enum ProcessManagerError {
         services: Vec<String>,
     },
 
+    /// A restart of the service is already underway.
+    #[error("Restart of service '{0}' is already in progress")]
+    RestartInProgress(String),
+
     /// Error when spawn limits are exceeded.
     #[error("Spawn limit exceeded: {0}")]
     SpawnLimitExceeded(String),
@@ -0,0 +1,584 @@
+//! Manifest import from other process managers for `sysg import`.
+//!
+//! Each source format has its own parser that maps what it can onto a
+//! [`Config`] and records a warning for everything it cannot express, such as
+//! compose volumes and networks. [`to_manifest`] then renders the config as a
+//! `version: "2"` manifest with unset fields left out.
+
+use std::{collections::HashMap, path::Path};
+
+use serde::de::Error as _;
+use serde_yaml::{Mapping, Value};
+
+use crate::{
+    cli::ImportFormat,
+    config::{
+        CURRENT_MANIFEST_VERSION, Config, DependsOn, DependsOnCondition, EnvConfig,
+        LogsConfig, MetricsConfig, ProjectConfig, ProjectDeploymentConfig, RestartPolicy,
+        ServiceConfig, StatusConfig, shell_quote,
+    },
+    error::ProcessManagerError,
+};
+
+/// Compose service fields the importer maps onto a service.
+const COMPOSE_MAPPED_FIELDS: &[&str] = &[
+    "command",
+    "entrypoint",
+    "environment",
+    "env_file",
+    "depends_on",
+    "restart",
+    "user",
+];
+
+/// Compose top-level keys that carry no runtime meaning for systemg.
+const COMPOSE_SILENT_KEYS: &[&str] = &["version", "name"];
+
+/// A converted manifest plus everything the source declared that it could not
+/// carry over.
+#[derive(Debug)]
+pub struct Imported {
+    /// The equivalent systemg configuration.
+    pub config: Config,
+    /// One line per source feature that was dropped or approximated.
+    pub warnings: Vec<String>,
+}
+
+/// Guesses the source format from a file name: anything named like
+/// `Procfile` (including `Procfile.dev`) is a Procfile, everything else is
+/// treated as a compose file.
+pub fn detect_format(path: &Path) -> ImportFormat {
+    let is_procfile = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with("Procfile"));
+    if is_procfile {
+        ImportFormat::Procfile
+    } else {
+        ImportFormat::Compose
+    }
+}
+
+/// Parses `content` in the given source format.
+pub fn import(
+    content: &str,
+    format: ImportFormat,
+) -> Result<Imported, ProcessManagerError> {
+    match format {
+        ImportFormat::Compose => from_compose(content),
+        ImportFormat::Procfile => from_procfile(content),
+    }
+}
+
+/// Maps a docker-compose file's services onto systemg services. Commands,
+/// environment, env files, dependencies, restart policies and users carry
+/// over; images, builds, ports, volumes, networks and the rest are reported.
+pub fn from_compose(content: &str) -> Result<Imported, ProcessManagerError> {
+    let root: Value =
+        serde_yaml::from_str(content).map_err(ProcessManagerError::ConfigParseError)?;
+    let Value::Mapping(root) = root else {
+        return Err(parse_error("compose file root must be a mapping"));
+    };
+
+    let mut warnings = Vec::new();
+    for key in root.keys().filter_map(Value::as_str) {
+        if key != "services" && !COMPOSE_SILENT_KEYS.contains(&key) {
+            warnings.push(format!("top-level `{key}` is not imported"));
+        }
+    }
+
+    let Some(Value::Mapping(compose_services)) = root.get("services") else {
+        return Err(parse_error("compose file has no `services` mapping"));
+    };
+
+    let mut services = HashMap::new();
+    for (name, body) in compose_services {
+        let Some(name) = name.as_str() else {
+            return Err(parse_error("compose service names must be strings"));
+        };
+        let empty = Mapping::new();
+        let body = match body {
+            Value::Mapping(body) => body,
+            Value::Null => &empty,
+            _ => {
+                return Err(parse_error(&format!(
+                    "compose service `{name}` must be a mapping"
+                )));
+            }
+        };
+        if let Some(service) = compose_service(name, body, &mut warnings)? {
+            services.insert(name.to_string(), service);
+        }
+    }
+
+    prune_dependencies(&mut services, &mut warnings);
+    Ok(Imported {
+        config: config_with(services),
+        warnings,
+    })
+}
+
+/// Converts one compose service, or returns `None` (with a warning) when it
+/// has nothing systemg can run.
+fn compose_service(
+    name: &str,
+    body: &Mapping,
+    warnings: &mut Vec<String>,
+) -> Result<Option<ServiceConfig>, ProcessManagerError> {
+    for key in body.keys().filter_map(Value::as_str) {
+        if !COMPOSE_MAPPED_FIELDS.contains(&key) {
+            warnings.push(format!("services.{name}.{key} is not imported"));
+        }
+    }
+
+    let mut argv = Vec::new();
+    for field in ["entrypoint", "command"] {
+        match body.get(field) {
+            None | Some(Value::Null) => {}
+            Some(Value::String(line)) => argv.push(line.clone()),
+            Some(Value::Sequence(items)) => {
+                for item in items {
+                    argv.push(shell_quote(&scalar(item).ok_or_else(|| {
+                        parse_error(&format!(
+                            "services.{name}.{field} entries must be strings"
+                        ))
+                    })?));
+                }
+            }
+            Some(_) => {
+                return Err(parse_error(&format!(
+                    "services.{name}.{field} must be a string or a list"
+                )));
+            }
+        }
+    }
+    if argv.is_empty() {
+        warnings.push(format!(
+            "service `{name}` has no command or entrypoint (image-only services \
+             cannot be run directly) and was skipped"
+        ));
+        return Ok(None);
+    }
+
+    let mut service = ServiceConfig {
+        command: argv.join(" "),
+        ..ServiceConfig::default()
+    };
+    service.env = compose_env(name, body, warnings);
+    service.depends_on = compose_depends_on(body);
+    service.user = body.get("user").and_then(scalar);
+
+    if let Some(restart) = body.get("restart").and_then(Value::as_str) {
+        let (policy, limit) = restart.split_once(':').unwrap_or((restart, ""));
+        service.restart_policy = match policy {
+            "no" => Some(RestartPolicy::Never),
+            "always" | "unless-stopped" => Some(RestartPolicy::Always),
+            "on-failure" => Some(RestartPolicy::OnFailure),
+            other => {
+                warnings.push(format!(
+                    "services.{name}.restart `{other}` has no systemg equivalent"
+                ));
+                None
+            }
+        };
+        if !limit.is_empty() {
+            match limit.parse() {
+                Ok(max) => service.max_restarts = Some(max),
+                Err(_) => warnings.push(format!(
+                    "services.{name}.restart retry count `{limit}` is not a number"
+                )),
+            }
+        }
+    }
+
+    Ok(Some(service))
+}
+
+/// Collects `environment` (map or `KEY=VALUE` list) and the first `env_file`.
+fn compose_env(
+    name: &str,
+    body: &Mapping,
+    warnings: &mut Vec<String>,
+) -> Option<EnvConfig> {
+    let mut vars = HashMap::new();
+    let mut passthrough = Vec::new();
+    match body.get("environment") {
+        Some(Value::Mapping(map)) => {
+            for (key, value) in map {
+                let Some(key) = key.as_str() else { continue };
+                match scalar(value) {
+                    Some(value) => {
+                        vars.insert(key.to_string(), value);
+                    }
+                    None => passthrough.push(key.to_string()),
+                }
+            }
+        }
+        Some(Value::Sequence(items)) => {
+            for item in items.iter().filter_map(Value::as_str) {
+                match item.split_once('=') {
+                    Some((key, value)) => {
+                        vars.insert(key.to_string(), value.to_string());
+                    }
+                    None => passthrough.push(item.to_string()),
+                }
+            }
+        }
+        _ => {}
+    }
+    for key in passthrough {
+        warnings.push(format!(
+            "services.{name}.environment `{key}` passes a host value through; \
+             set it explicitly"
+        ));
+    }
+
+    let files: Vec<String> = match body.get("env_file") {
+        Some(Value::String(file)) => vec![file.clone()],
+        Some(Value::Sequence(items)) => items
+            .iter()
+            .filter_map(|item| match item {
+                Value::Mapping(entry) => entry.get("path").and_then(scalar),
+                other => scalar(other),
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    if files.len() > 1 {
+        warnings.push(format!(
+            "services.{name}.env_file lists {} files; only `{}` is imported",
+            files.len(),
+            files[0]
+        ));
+    }
+
+    let file = files.into_iter().next();
+    if vars.is_empty() && file.is_none() {
+        return None;
+    }
+    Some(EnvConfig {
+        file,
+        vars: (!vars.is_empty()).then_some(vars),
+        ..EnvConfig::default()
+    })
+}
+
+/// Reads `depends_on` in either its list or its condition-map form. Compose's
+/// `service_completed_successfully` becomes `condition: completed`; the other
+/// conditions match systemg's default of waiting for the dependency to start
+/// and pass its health check.
+fn compose_depends_on(body: &Mapping) -> Option<Vec<DependsOn>> {
+    let deps: Vec<DependsOn> = match body.get("depends_on") {
+        Some(Value::Sequence(items)) => items
+            .iter()
+            .filter_map(scalar)
+            .map(DependsOn::from)
+            .collect(),
+        Some(Value::Mapping(map)) => map
+            .iter()
+            .filter_map(|(name, spec)| {
+                let name = scalar(name)?;
+                let completed = spec.get("condition").and_then(Value::as_str)
+                    == Some("service_completed_successfully");
+                Some(if completed {
+                    DependsOn::Detailed {
+                        service: name,
+                        condition: DependsOnCondition::Completed,
+                    }
+                } else {
+                    DependsOn::Name(name)
+                })
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    (!deps.is_empty()).then_some(deps)
+}
+
+/// Maps each `name: command` line of a Procfile onto a service. Procfiles
+/// carry no restart or dependency information, so neither is set.
+pub fn from_procfile(content: &str) -> Result<Imported, ProcessManagerError> {
+    let mut warnings = Vec::new();
+    let mut services = HashMap::new();
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let entry = line.split_once(':').and_then(|(name, command)| {
+            let name = name.trim();
+            let command = command.trim();
+            let valid_name = !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+            (valid_name && !command.is_empty()).then_some((name, command))
+        });
+        let Some((name, command)) = entry else {
+            warnings.push(format!(
+                "line {} is not a `name: command` entry and was skipped",
+                index + 1
+            ));
+            continue;
+        };
+        let service = ServiceConfig {
+            command: command.to_string(),
+            ..ServiceConfig::default()
+        };
+        if services.insert(name.to_string(), service).is_some() {
+            warnings.push(format!(
+                "process `{name}` is declared more than once; the last entry wins"
+            ));
+        }
+    }
+
+    if services.is_empty() {
+        return Err(parse_error("Procfile declares no processes"));
+    }
+    Ok(Imported {
+        config: config_with(services),
+        warnings,
+    })
+}
+
+/// Renders `config` as a manifest, dropping every unset field so the output
+/// reads like a hand-written file.
+pub fn to_manifest(config: &Config) -> Result<String, ProcessManagerError> {
+    let mut names: Vec<&String> = config.services.keys().collect();
+    names.sort();
+
+    let mut services = Mapping::new();
+    for name in names {
+        let mut service = serde_yaml::to_value(&config.services[name])
+            .map_err(ProcessManagerError::ConfigParseError)?;
+        strip_nulls(&mut service);
+        services.insert(Value::String(name.clone()), service);
+    }
+
+    let mut root = Mapping::new();
+    root.insert(
+        "version".into(),
+        Value::String(CURRENT_MANIFEST_VERSION.to_string()),
+    );
+    if let Some(env) = &config.env {
+        let mut env =
+            serde_yaml::to_value(env).map_err(ProcessManagerError::ConfigParseError)?;
+        strip_nulls(&mut env);
+        root.insert("env".into(), env);
+    }
+    root.insert("services".into(), Value::Mapping(services));
+    serde_yaml::to_string(&Value::Mapping(root))
+        .map_err(ProcessManagerError::ConfigParseError)
+}
+
+/// Drops dependencies on services that were not imported, so the manifest
+/// still loads.
+fn prune_dependencies(
+    services: &mut HashMap<String, ServiceConfig>,
+    warnings: &mut Vec<String>,
+) {
+    let known: Vec<String> = services.keys().cloned().collect();
+    let mut names: Vec<String> = known.clone();
+    names.sort();
+    for name in names {
+        let service = services.get_mut(&name).expect("service listed above");
+        let Some(deps) = service.depends_on.as_mut() else {
+            continue;
+        };
+        deps.retain(|dep| {
+            let keep = known.iter().any(|known| known == dep.service());
+            if !keep {
+                warnings.push(format!(
+                    "service `{name}` depended on `{}`, which was not imported",
+                    dep.service()
+                ));
+            }
+            keep
+        });
+        if deps.is_empty() {
+            service.depends_on = None;
+        }
+    }
+}
+
+/// Wraps imported services in an otherwise default, project-less config.
+fn config_with(services: HashMap<String, ServiceConfig>) -> Config {
+    Config {
+        version: CURRENT_MANIFEST_VERSION,
+        project: ProjectConfig::default(),
+        services,
+        project_dir: None,
+        env: None,
+        metrics: MetricsConfig::default(),
+        logs: LogsConfig::default(),
+        status: StatusConfig::default(),
+        deployment: ProjectDeploymentConfig::default(),
+        on_ready: None,
+        on_start_failure: None,
+        on_failure: Default::default(),
+        dependency_settle: None,
+        supervisor: Default::default(),
+        env_overrides: Default::default(),
+    }
+}
+
+/// Returns a YAML scalar as text, or `None` for nulls and collections.
+fn scalar(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(flag) => Some(flag.to_string()),
+        _ => None,
+    }
+}
+
+/// Removes null entries from every mapping in `value`, recursively.
+fn strip_nulls(value: &mut Value) {
+    match value {
+        Value::Mapping(map) => {
+            map.retain(|_, entry| !entry.is_null());
+            for entry in map.values_mut() {
+                strip_nulls(entry);
+            }
+        }
+        Value::Sequence(items) => items.iter_mut().for_each(strip_nulls),
+        _ => {}
+    }
+}
+
+/// Builds the error for a source file the importer cannot read.
+fn parse_error(message: &str) -> ProcessManagerError {
+    ProcessManagerError::ConfigParseError(serde_yaml::Error::custom(message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::parse_config_manifest;
+
+    const COMPOSE: &str = r#"
+version: "3.9"
+services:
+  db:
+    image: postgres:16
+    volumes:
+      - data:/var/lib/postgresql/data
+  api:
+    image: example/api
+    command: ["gunicorn", "app:create()", "-b", "0.0.0.0:8000"]
+    environment:
+      PORT: 8000
+      DEBUG: "false"
+      TOKEN:
+    env_file: .env
+    depends_on:
+      db:
+        condition: service_healthy
+    restart: on-failure:5
+    ports:
+      - "8000:8000"
+  worker:
+    entrypoint: python
+    command: worker.py
+    environment:
+      - QUEUE=jobs
+    depends_on: [api]
+    restart: unless-stopped
+    user: app
+volumes:
+  data: {}
+networks:
+  default: {}
+"#;
+
+    #[test]
+    fn compose_maps_common_fields_and_reports_the_rest() {
+        let imported = from_compose(COMPOSE).unwrap();
+        let services = &imported.config.services;
+
+        assert!(!services.contains_key("db"));
+        let api = &services["api"];
+        assert_eq!(api.command, "gunicorn 'app:create()' -b 0.0.0.0:8000");
+        assert_eq!(api.restart_policy, Some(RestartPolicy::OnFailure));
+        assert_eq!(api.max_restarts, Some(5));
+        assert_eq!(api.depends_on, None);
+        let env = api.env.as_ref().unwrap();
+        assert_eq!(env.file.as_deref(), Some(".env"));
+        let vars = env.vars.as_ref().unwrap();
+        assert_eq!(vars["PORT"], "8000");
+        assert_eq!(vars["DEBUG"], "false");
+        assert!(!vars.contains_key("TOKEN"));
+
+        let worker = &services["worker"];
+        assert_eq!(worker.command, "python worker.py");
+        assert_eq!(worker.restart_policy, Some(RestartPolicy::Always));
+        assert_eq!(worker.depends_on, Some(vec![DependsOn::from("api")]));
+        assert_eq!(worker.user.as_deref(), Some("app"));
+
+        let warnings = imported.warnings.join("\n");
+        for expected in [
+            "top-level `volumes`",
+            "top-level `networks`",
+            "services.db.volumes",
+            "services.api.ports",
+            "`db` has no command",
+            "`TOKEN` passes a host value",
+            "`api` depended on `db`",
+        ] {
+            assert!(
+                warnings.contains(expected),
+                "missing {expected:?}: {warnings}"
+            );
+        }
+    }
+
+    #[test]
+    fn imported_manifest_loads_back_to_the_same_services() {
+        let imported = from_compose(COMPOSE).unwrap();
+        let manifest = to_manifest(&imported.config).unwrap();
+        assert!(!manifest.contains("null"), "{manifest}");
+
+        let reparsed = parse_config_manifest(&manifest).unwrap();
+        let mut names: Vec<_> = reparsed.services.keys().cloned().collect();
+        names.sort();
+        assert_eq!(names, ["api", "worker"]);
+        assert_eq!(
+            reparsed.services["api"].command,
+            imported.config.services["api"].command
+        );
+        assert_eq!(
+            reparsed.services["worker"].depends_on,
+            Some(vec![DependsOn::from("api")])
+        );
+    }
+
+    #[test]
+    fn procfile_maps_each_process_and_flags_bad_lines() {
+        let imported = from_procfile(
+            "# dev processes\nweb: bundle exec rails s -p $PORT\nworker:  sidekiq\n\nnot a process\n",
+        )
+        .unwrap();
+        let services = &imported.config.services;
+        assert_eq!(services["web"].command, "bundle exec rails s -p $PORT");
+        assert_eq!(services["worker"].command, "sidekiq");
+        assert_eq!(
+            imported.warnings,
+            ["line 5 is not a `name: command` entry and was skipped"]
+        );
+
+        assert!(from_procfile("# nothing here\n").is_err());
+    }
+
+    #[test]
+    fn format_is_detected_from_the_file_name() {
+        assert_eq!(detect_format(Path::new("Procfile")), ImportFormat::Procfile);
+        assert_eq!(
+            detect_format(Path::new("app/Procfile.dev")),
+            ImportFormat::Procfile
+        );
+        assert_eq!(
+            detect_format(Path::new("docker-compose.yml")),
+            ImportFormat::Compose
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    collections::BTreeMap,
     fs,
     io::{self, BufRead, BufReader, Read, Write},
     os::unix::net::UnixStream,
@@ -21,9 +22,19 @@ use crate::{
 /// unbounded spinner.
 const COMMAND_READ_TIMEOUT: Duration = Duration::from_secs(120);
 
+/// Upper bound for [`send_command_and_wait`]. Long enough for rolling restarts
+/// and their health checks, but a supervisor that wedges mid-restart still
+/// surfaces as [`ControlError::Timeout`] rather than hanging the CLI forever.
+pub const WAIT_READ_TIMEOUT: Duration = Duration::from_secs(15 * 60);
+
 /// Short bound for the diagnostic current-op probe, which must never itself hang.
 const CURRENT_OP_TIMEOUT: Duration = Duration::from_secs(2);
 
+/// How long the supervisor gets to answer the handshake sent ahead of each
+/// command. Request workers answer it without waiting on in-flight work, so
+/// only a supervisor whose control socket has stopped serving misses it.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+
 /// Directory under `$HOME` where runtime artifacts (PID/socket files) are stored.
 fn runtime_dir() -> Result<PathBuf, ControlError> {
     let path = runtime::state_dir();
@@ -98,6 +109,79 @@ fn config_hint_path() -> Result<PathBuf, ControlError> {
     Ok(runtime_dir()?.join("config_hint"))
 }
 
+/// Returns the path of the token clients present with each control command.
+fn control_token_path() -> Result<PathBuf, ControlError> {
+    Ok(runtime_dir()?.join("control.token"))
+}
+
+/// Generates a fresh control token and writes it to an owner-only file.
+///
+/// Socket permissions alone stop other users only while they stay `0600`; the
+/// token is a second check, since a peer must also be able to read this file.
+/// The file is created with mode `0600` rather than tightened afterwards, so
+/// the token is never briefly world-readable.
+pub fn write_control_token() -> Result<String, ControlError> {
+    use std::os::unix::fs::OpenOptionsExt;
+
+    let mut bytes = [0u8; crate::constants::CONTROL_TOKEN_BYTES];
+    fs::File::open("/dev/urandom")?.read_exact(&mut bytes)?;
+    let token = bytes
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<String>();
+
+    let path = control_token_path()?;
+    let _ = fs::remove_file(&path);
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(crate::constants::PRIVATE_FILE_MODE)
+        .open(&path)?;
+    file.write_all(token.as_bytes())?;
+    Ok(token)
+}
+
+/// Reads the running supervisor's control token, if it wrote one.
+pub fn read_control_token() -> Option<String> {
+    let token = fs::read_to_string(control_token_path().ok()?).ok()?;
+    let token = token.trim();
+    (!token.is_empty()).then(|| token.to_string())
+}
+
+/// Compares tokens without exiting early on the first differing byte.
+fn tokens_match(presented: &str, expected: &str) -> bool {
+    presented.len() == expected.len()
+        && presented
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+/// One control-socket request: a command and the token that authorizes it.
+///
+/// On the wire a request is either `{"token": .., "command": ..}` or, from a
+/// client that found no token file, the bare command. Clients only send the
+/// envelope when a token exists, so a newer CLI can still drive an older
+/// supervisor (for example to hand it a live upgrade).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ControlRequest {
+    /// Token read from the supervisor's runtime directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /// The command to run.
+    pub command: ControlCommand,
+}
+
+impl ControlRequest {
+    /// Whether this request carries the supervisor's `expected` token.
+    pub fn is_authorized(&self, expected: &str) -> bool {
+        self.token
+            .as_deref()
+            .is_some_and(|token| tokens_match(token, expected))
+    }
+}
+
 /// Message sent from CLI invocations to the resident supervisor.
 #[derive(Debug, Serialize, Deserialize)]
 pub enum ControlCommand {
@@ -120,6 +204,9 @@ pub enum ControlCommand {
         /// Requested project run mode.
         #[serde(default)]
         mode: ProjectRunMode,
+        /// Environment overrides applied to every service of the added project.
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        env: BTreeMap<String, String>,
     },
     /// Stop all services for one project.
     StopProject {
@@ -134,6 +221,16 @@ pub enum ControlCommand {
         /// Optional project id to target.
         #[serde(default, skip_serializing_if = "Option::is_none")]
         project: Option<String>,
+        /// How long a single stopped service gets to exit after SIGTERM before
+        /// it is killed. Defaults to the daemon's stop grace period.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        timeout_ms: Option<u64>,
+    },
+    /// Terminate and forget processes whose service is no longer configured.
+    StopOrphans {
+        /// Optional project id to limit the sweep to.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        project: Option<String>,
     },
     /// Restart services, optionally with a new configuration.
     Restart {
@@ -146,6 +243,78 @@ pub enum ControlCommand {
         /// Optional project id to target.
         #[serde(default, skip_serializing_if = "Option::is_none")]
         project: Option<String>,
+        /// Also restart the named service's transitive dependents.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        with_dependents: bool,
+        /// Run each restarted service's `pre_start` before stopping it.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        build_first: bool,
+        /// Re-verify the restarted services and answer with a
+        /// [`ControlResponse::Restarted`] report instead of a plain message.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        wait: bool,
+    },
+    /// Apply one start, stop, or restart to several named services in a single
+    /// request, answered with a [`ControlResponse::Bulk`] outcome per service.
+    Bulk {
+        /// What to do to each service, with its per-action options.
+        action: BulkAction,
+        /// Service names, optionally qualified as `project/service`, in the
+        /// order they are applied.
+        services: Vec<String>,
+        /// Optional project id to target.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        project: Option<String>,
+    },
+    /// Restart only the services that are currently failing or exited with an
+    /// error, in dependency order.
+    RestartFailed {
+        /// Optional project id to limit the restart to.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        project: Option<String>,
+    },
+    /// Run only the verification half of a `wait` restart, answering with a
+    /// [`ControlResponse::Restarted`] report. Used when `restart --daemonize
+    /// --wait` had to boot a fresh supervisor instead of restarting one.
+    VerifyRestart {
+        /// Service name to verify, with its dependents when `with_dependents`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        service: Option<String>,
+        /// Optional project id to target.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        project: Option<String>,
+        /// Also verify the service's transitive dependents.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        with_dependents: bool,
+    },
+    /// Persistently skip a service across start and reload, stopping it if running.
+    Disable {
+        /// Service name, optionally qualified as `project/service`.
+        service: String,
+        /// Optional project id to target.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        project: Option<String>,
+    },
+    /// Clear a previous `Disable`.
+    Enable {
+        /// Service name, optionally qualified as `project/service`.
+        service: String,
+        /// Optional project id to target.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        project: Option<String>,
+    },
+    /// Stop automatically restarting crashed services, leaving running ones alone.
+    Pause,
+    /// Re-enable automatic restarts after a `Pause`.
+    Resume {
+        /// Also restart the services that crashed while paused.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        restart_crashed: bool,
+    },
+    /// Run a cron job now, outside its schedule.
+    RunCron {
+        /// State hash of the cron service to run.
+        hash: String,
     },
     /// Shutdown the supervisor daemon.
     Shutdown,
@@ -154,6 +323,9 @@ pub enum ControlCommand {
         /// Whether to force live runtime collection instead of the configured snapshot mode.
         #[serde(default)]
         live: bool,
+        /// Whether to skip building spawned-child trees for a faster snapshot.
+        #[serde(default)]
+        no_children: bool,
     },
     /// Inspect an individual unit with metrics.
     Inspect {
@@ -211,6 +383,9 @@ pub enum ControlCommand {
         /// Optional project id to scope the clear.
         #[serde(default, skip_serializing_if = "Option::is_none")]
         project: Option<String>,
+        /// Empty only the active log files and keep rotated backups.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        keep_history: bool,
     },
     /// Report the version of the resident supervisor binary.
     Version,
@@ -219,8 +394,36 @@ pub enum ControlCommand {
         /// Canonical or resolvable path to the staged replacement binary.
         binary: String,
     },
+    /// Re-execute the resident supervisor from a binary of the same or a newer
+    /// release, handing its running services to the new image.
+    Reexec {
+        /// Canonical or resolvable path to the replacement binary.
+        binary: String,
+    },
     /// Report the operation the supervisor is currently blocked on, if any.
     CurrentOp,
+    /// Fetch the most recent metric samples recorded for one unit.
+    Samples {
+        /// Name or hash of the unit.
+        unit: String,
+        /// Optional project id containing the unit.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        project: Option<String>,
+        /// Maximum number of samples to return, newest last.
+        samples: u32,
+    },
+    /// List the services the supervisor has loaded as `project/service` selectors.
+    Services {
+        /// Optional project id to scope the list.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        project: Option<String>,
+    },
+    /// Report per-field config hashes for every service the supervisor runs.
+    ServiceHashes {
+        /// Optional project id to scope the report.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        project: Option<String>,
+    },
     /// Spawn a dynamic child process.
     Spawn {
         /// Parent process PID (from Unix socket peer credentials).
@@ -240,6 +443,56 @@ pub enum ControlCommand {
     /// replays every boot frame recorded so far, then streams live frames as
     /// line-delimited JSON until the terminal `Done` frame.
     BootStream,
+    /// Follow one service's output through the supervisor's log writer. The
+    /// supervisor pushes [`LogFrame`]s as line-delimited JSON until the client
+    /// disconnects.
+    LogFollow {
+        /// Service to follow.
+        service: String,
+        /// Optional project id to target.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        project: Option<String>,
+        /// Number of historical lines sent before live output.
+        #[serde(default)]
+        lines: usize,
+        /// Optional stream kind filter (`stdout` or `stderr`).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        kind: Option<String>,
+        /// Optional regular expression a line must match.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        grep: Option<String>,
+    },
+    /// Attach to an `interactive` service: the supervisor answers with
+    /// [`LogFrame::Attached`], then relays every byte the client sends to the
+    /// service's stdin while pushing its live output as [`LogFrame`]s, until
+    /// the client disconnects.
+    Attach {
+        /// Service to attach to.
+        service: String,
+        /// Optional project id to target.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        project: Option<String>,
+    },
+}
+
+/// One event on a `LogFollow` stream. Frames are line-delimited JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LogFrame {
+    /// One captured line, in the canonical `<timestamp> <stream> <text>` form.
+    Line {
+        /// The service that wrote it.
+        service: String,
+        /// The captured line, without its trailing newline.
+        line: String,
+    },
+    /// The follow could not start or continue. Terminal frame.
+    Error {
+        /// Why.
+        message: String,
+    },
+    /// Input sent from now on reaches the service's stdin. First frame of an
+    /// `Attach` stream; the client must not send input before it.
+    Attached,
 }
 
 /// Response sent by the supervisor.
@@ -271,8 +524,125 @@ pub enum ControlResponse {
     },
     /// The operation the supervisor is currently working on, if any.
     CurrentOp(Option<crate::opslot::OpReport>),
+    /// Per-field config hashes, keyed by project id then service name.
+    ServiceHashes(ProjectServiceHashes),
+    /// A `wait` restart finished and its services were verified running.
+    Restarted(RestartReport),
+    /// Recent metric samples for one unit, oldest first.
+    Samples(Vec<MetricSample>),
+    /// Loaded services as sorted `project/service` selectors.
+    Services(Vec<String>),
+    /// Per-service results of a [`ControlCommand::Bulk`], in request order.
+    Bulk(Vec<BulkOutcome>),
+}
+
+/// The operation a [`ControlCommand::Bulk`] applies to each of its services.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum BulkAction {
+    /// Start each service.
+    Start,
+    /// Stop each service.
+    Stop {
+        /// Per-service SIGTERM grace period; the daemon default when unset.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        timeout_ms: Option<u64>,
+    },
+    /// Restart each service.
+    Restart {
+        /// Optional path to a new configuration file.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        config: Option<String>,
+        /// Also restart each service's transitive dependents.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        with_dependents: bool,
+        /// Run each service's `pre_start` before stopping it.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        build_first: bool,
+        /// Verify each restarted service before reporting it restarted.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        wait: bool,
+    },
+}
+
+impl BulkAction {
+    /// The single-service command this action applies to `service`.
+    pub fn command(&self, service: &str, project: Option<&str>) -> ControlCommand {
+        let service = Some(service.to_string());
+        let project = project.map(str::to_string);
+        match self.clone() {
+            BulkAction::Start => ControlCommand::Start { service, project },
+            BulkAction::Stop { timeout_ms } => ControlCommand::Stop {
+                service,
+                project,
+                timeout_ms,
+            },
+            BulkAction::Restart {
+                config,
+                with_dependents,
+                build_first,
+                wait,
+            } => ControlCommand::Restart {
+                config,
+                service,
+                project,
+                with_dependents,
+                build_first,
+                wait,
+            },
+        }
+    }
+
+    /// Past-tense verb for a service the action succeeded on.
+    pub fn past_tense(&self) -> &'static str {
+        match self {
+            BulkAction::Start => "started",
+            BulkAction::Stop { .. } => "stopped",
+            BulkAction::Restart { .. } => "restarted",
+        }
+    }
+
+    /// Present-participle verb for progress labels.
+    pub fn progressive(&self) -> &'static str {
+        match self {
+            BulkAction::Start => "starting",
+            BulkAction::Stop { .. } => "stopping",
+            BulkAction::Restart { .. } => "restarting",
+        }
+    }
+}
+
+/// How one service of a [`ControlCommand::Bulk`] fared.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BulkOutcome {
+    /// The service as it was named in the request.
+    pub service: String,
+    /// Why the action failed for this service; `None` when it succeeded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Outcome of a restart whose services were verified after it completed.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RestartReport {
+    /// Summary of what was restarted.
+    pub message: String,
+    /// Services confirmed running (or completed) after the restart.
+    pub services: Vec<RestartedService>,
+}
+
+/// One service confirmed healthy after a restart.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RestartedService {
+    /// Project id the service belongs to.
+    pub project: String,
+    /// Service name within the project.
+    pub name: String,
 }
 
+/// Running config fingerprints: project id → service name → field → hash.
+pub type ProjectServiceHashes =
+    BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>;
+
 /// Result of sending a command with a short acknowledgement window.
 #[derive(Debug)]
 pub enum CommandAck {
@@ -313,6 +683,9 @@ pub enum ControlError {
     /// The supervisor accepted the command but did not reply in time.
     #[error("supervisor did not respond in time")]
     Timeout,
+    /// The supervisor's socket accepts connections but never answers them.
+    #[error("supervisor is not answering its control socket")]
+    Unresponsive,
     /// Another supervisor owns the runtime.
     #[error("another supervisor owns the runtime")]
     RuntimeBusy,
@@ -427,8 +800,27 @@ pub fn authenticate_peer(stream: &UnixStream) -> Result<(), ControlError> {
 
 /// Sends a command to the supervisor and waits for a response.
 pub fn send_command(command: &ControlCommand) -> Result<ControlResponse, ControlError> {
+    send_command_within(command, Some(COMMAND_READ_TIMEOUT), HANDSHAKE_TIMEOUT)
+}
+
+/// Sends a command and waits up to [`WAIT_READ_TIMEOUT`] for its response.
+///
+/// Used by `restart --wait` and bulk actions, whose rolling restarts and health
+/// checks can run well past the usual response timeout.
+pub fn send_command_and_wait(
+    command: &ControlCommand,
+) -> Result<ControlResponse, ControlError> {
+    send_command_within(command, Some(WAIT_READ_TIMEOUT), HANDSHAKE_TIMEOUT)
+}
+
+fn send_command_within(
+    command: &ControlCommand,
+    read_timeout: Option<Duration>,
+    handshake_timeout: Duration,
+) -> Result<ControlResponse, ControlError> {
+    handshake(handshake_timeout)?;
     let stream = connect_stream()?;
-    stream.set_read_timeout(Some(COMMAND_READ_TIMEOUT))?;
+    stream.set_read_timeout(read_timeout)?;
     let mut stream = stream;
     write_command(&mut stream, command)?;
 
@@ -459,6 +851,38 @@ pub fn send_command(command: &ControlCommand) -> Result<ControlResponse, Control
     Ok(response)
 }
 
+/// Confirms the supervisor is still serving its socket before a command is sent.
+///
+/// A live supervisor process whose request handling has hung still accepts
+/// connections, so without this a command would sit out the full response
+/// timeout, or the much longer one of [`send_command_and_wait`]. Any reply
+/// counts, even an error from a supervisor that predates the probe command.
+fn handshake(timeout: Duration) -> Result<(), ControlError> {
+    let mut stream = connect_stream()?;
+    stream.set_write_timeout(Some(timeout))?;
+    stream.set_read_timeout(Some(timeout))?;
+    let unresponsive = |err: ControlError| match err {
+        ControlError::Io(err)
+            if matches!(
+                err.kind(),
+                io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+            ) =>
+        {
+            ControlError::Unresponsive
+        }
+        other => other,
+    };
+    write_command(&mut stream, &ControlCommand::CurrentOp).map_err(unresponsive)?;
+
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    match reader.read_line(&mut line) {
+        Ok(0) => Err(ControlError::NotAvailable),
+        Ok(_) => Ok(()),
+        Err(err) => Err(unresponsive(err.into())),
+    }
+}
+
 /// Fetches the supervisor's current operation without disturbing an in-flight
 /// command. Returns `None` when the supervisor is idle or unreachable.
 pub fn current_op() -> Option<crate::opslot::OpReport> {
@@ -538,7 +962,20 @@ fn write_command(
     stream: &mut UnixStream,
     command: &ControlCommand,
 ) -> Result<(), ControlError> {
-    let payload = serde_json::to_vec(command)?;
+    /// Borrowed form of [`ControlRequest`], so sending never clones the command.
+    #[derive(Serialize)]
+    struct Envelope<'a> {
+        token: &'a str,
+        command: &'a ControlCommand,
+    }
+
+    let payload = match read_control_token() {
+        Some(token) => serde_json::to_vec(&Envelope {
+            token: &token,
+            command,
+        })?,
+        None => serde_json::to_vec(command)?,
+    };
     stream.write_all(&payload)?;
     stream.write_all(b"\n")?;
     stream.flush()?;
@@ -574,10 +1011,7 @@ pub fn stream_command_output_interruptible(
         }
         Err(e) => return Err(e.into()),
     };
-    let payload = serde_json::to_vec(command)?;
-    stream.write_all(&payload)?;
-    stream.write_all(b"\n")?;
-    stream.flush()?;
+    write_command(&mut stream, command)?;
 
     // Hand a clone of the connection to the caller so it can force-close the read
     // side from another thread; a shutdown() unblocks the io::copy below at once.
@@ -640,8 +1074,95 @@ pub fn stream_boot_frames(
     }
 }
 
-/// Utility to read a command from a `UnixStream`. Used by the supervisor event loop.
-pub fn read_command(stream: &mut UnixStream) -> Result<ControlCommand, ControlError> {
+/// Sends a `LogFollow` command and writes each streamed line, newline-terminated,
+/// into `writer` until the supervisor closes the stream. Publishes the live
+/// connection into `shutdown_slot` like [`stream_command_output_interruptible`].
+pub fn stream_log_follow(
+    command: &ControlCommand,
+    mut writer: impl Write,
+    shutdown_slot: Option<&std::sync::Mutex<Option<UnixStream>>>,
+) -> Result<(), ControlError> {
+    let path = socket_path()?;
+    if !path.exists() {
+        return Err(ControlError::NotAvailable);
+    }
+
+    let mut stream = match UnixStream::connect(&path) {
+        Ok(s) => s,
+        Err(e) if e.kind() == io::ErrorKind::ConnectionRefused => {
+            return Err(ControlError::NotAvailable);
+        }
+        Err(e) => return Err(e.into()),
+    };
+    write_command(&mut stream, command)?;
+
+    if let Some(slot) = shutdown_slot
+        && let Ok(clone) = stream.try_clone()
+        && let Ok(mut guard) = slot.lock()
+    {
+        *guard = Some(clone);
+    }
+
+    for line in BufReader::new(stream).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<LogFrame>(line.trim())? {
+            LogFrame::Line { line, .. } => {
+                writer.write_all(line.as_bytes())?;
+                writer.write_all(b"\n")?;
+                writer.flush()?;
+            }
+            LogFrame::Error { message } => return Err(ControlError::Server(message)),
+            LogFrame::Attached => {}
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Sends an `Attach` command, then copies `input` to the supervisor and each
+/// streamed line, newline-terminated, into `writer` until the supervisor closes
+/// the stream. Input is held back until the supervisor confirms the attach,
+/// and running out of input leaves the output flowing: only disconnecting
+/// detaches, and the service keeps running either way.
+pub fn stream_attach(
+    command: &ControlCommand,
+    mut writer: impl Write,
+    input: impl Read + Send + 'static,
+) -> Result<(), ControlError> {
+    let mut stream = connect_stream()?;
+    write_command(&mut stream, command)?;
+    let mut sender = Some((input, stream.try_clone()?));
+
+    for line in BufReader::new(stream).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<LogFrame>(line.trim())? {
+            LogFrame::Attached => {
+                if let Some((mut input, mut socket)) = sender.take() {
+                    std::thread::spawn(move || {
+                        let _ = io::copy(&mut input, &mut socket);
+                    });
+                }
+            }
+            LogFrame::Line { line, .. } => {
+                writer.write_all(line.as_bytes())?;
+                writer.write_all(b"\n")?;
+                writer.flush()?;
+            }
+            LogFrame::Error { message } => return Err(ControlError::Server(message)),
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Utility to read a request from a `UnixStream`. Used by the supervisor event loop.
+pub fn read_request(stream: &mut UnixStream) -> Result<ControlRequest, ControlError> {
     let cap = crate::constants::MAX_CONTROL_LINE;
     let mut reader = BufReader::new(stream).take(cap + 1);
     let mut buf = Vec::new();
@@ -664,7 +1185,15 @@ pub fn read_command(stream: &mut UnixStream) -> Result<ControlCommand, ControlEr
         )));
     }
 
-    Ok(serde_json::from_str(line.trim())?)
+    let value: serde_json::Value = serde_json::from_str(line.trim())?;
+    if value.get("command").is_some() {
+        Ok(serde_json::from_value(value)?)
+    } else {
+        Ok(ControlRequest {
+            token: None,
+            command: serde_json::from_value(value)?,
+        })
+    }
 }
 
 /// Writes a response to the connected CLI client.
@@ -770,6 +1299,12 @@ pub fn cleanup_runtime() -> Result<(), ControlError> {
         let _ = fs::remove_file(config_path);
     }
 
+    if let Ok(token_path) = control_token_path()
+        && token_path.exists()
+    {
+        let _ = fs::remove_file(token_path);
+    }
+
     Ok(())
 }
 
@@ -844,6 +1379,7 @@ mod tests {
         let stop = ControlCommand::Stop {
             service: None,
             project: None,
+            timeout_ms: None,
         };
         let json = serde_json::to_string(&stop).unwrap();
         assert!(json.contains("Stop"));
@@ -852,6 +1388,9 @@ mod tests {
             config: Some("config.yaml".to_string()),
             service: Some("service".to_string()),
             project: None,
+            with_dependents: false,
+            build_first: false,
+            wait: false,
         };
         let json = serde_json::to_string(&restart).unwrap();
         assert!(json.contains("Restart"));
@@ -873,18 +1412,56 @@ mod tests {
         assert!(json.contains("\"samples\":10"));
         assert!(json.contains("\"live\":true"));
 
-        let status = ControlCommand::Status { live: true };
+        let status = ControlCommand::Status {
+            live: true,
+            no_children: false,
+        };
         let json = serde_json::to_string(&status).unwrap();
         assert!(json.contains("Status"));
         assert!(json.contains("\"live\":true"));
     }
 
+    #[test]
+    fn bulk_action_expands_to_single_service_commands() {
+        let bulk = ControlCommand::Bulk {
+            action: BulkAction::Stop {
+                timeout_ms: Some(500),
+            },
+            services: vec!["web".to_string(), "worker".to_string()],
+            project: None,
+        };
+        let json = serde_json::to_string(&bulk).expect("serialize bulk");
+        assert_eq!(
+            json,
+            r#"{"Bulk":{"action":{"Stop":{"timeout_ms":500}},"services":["web","worker"]}}"#
+        );
+
+        let ControlCommand::Bulk {
+            action, project, ..
+        } = bulk
+        else {
+            unreachable!();
+        };
+        assert!(matches!(
+            action.command("worker", project.as_deref()),
+            ControlCommand::Stop {
+                service: Some(service),
+                project: None,
+                timeout_ms: Some(500),
+            } if service == "worker"
+        ));
+        assert_eq!(action.past_tense(), "stopped");
+    }
+
     #[test]
     fn restart_omits_null_optional_fields() {
         let restart = ControlCommand::Restart {
             config: Some("sysg.config.yaml".to_string()),
             service: None,
             project: None,
+            with_dependents: false,
+            build_first: false,
+            wait: false,
         };
 
         let json = serde_json::to_string(&restart).expect("serialize restart");
@@ -892,6 +1469,88 @@ mod tests {
         assert_eq!(json, r#"{"Restart":{"config":"sysg.config.yaml"}}"#);
     }
 
+    #[test]
+    fn restart_wait_round_trips_with_its_report() {
+        let restart = ControlCommand::Restart {
+            config: None,
+            service: Some("web".to_string()),
+            project: None,
+            with_dependents: false,
+            build_first: false,
+            wait: true,
+        };
+        let json = serde_json::to_string(&restart).expect("serialize restart");
+        assert_eq!(json, r#"{"Restart":{"service":"web","wait":true}}"#);
+
+        let response = ControlResponse::Restarted(RestartReport {
+            message: "Service 'web' restarted".to_string(),
+            services: vec![RestartedService {
+                project: "shop".to_string(),
+                name: "web".to_string(),
+            }],
+        });
+        let json = serde_json::to_string(&response).expect("serialize report");
+        let parsed: ControlResponse =
+            serde_json::from_str(&json).expect("deserialize report");
+        assert!(matches!(
+            parsed,
+            ControlResponse::Restarted(report) if report.services.len() == 1
+        ));
+    }
+
+    #[test]
+    fn verify_restart_omits_unset_targets() {
+        let json = serde_json::to_string(&ControlCommand::VerifyRestart {
+            service: None,
+            project: None,
+            with_dependents: false,
+        })
+        .expect("serialize verify");
+        assert_eq!(json, r#"{"VerifyRestart":{}}"#);
+
+        let parsed: ControlCommand = serde_json::from_str(
+            r#"{"VerifyRestart":{"service":"web","with_dependents":true}}"#,
+        )
+        .expect("deserialize verify");
+        assert!(matches!(
+            parsed,
+            ControlCommand::VerifyRestart {
+                service: Some(ref name),
+                project: None,
+                with_dependents: true,
+            } if name == "web"
+        ));
+    }
+
+    #[test]
+    fn samples_and_services_round_trip_as_structured_payloads() {
+        let json = serde_json::to_string(&ControlCommand::Services { project: None })
+            .expect("serialize services");
+        assert_eq!(json, r#"{"Services":{}}"#);
+
+        let response = ControlResponse::Services(vec!["shop/web".to_string()]);
+        let json = serde_json::to_string(&response).expect("serialize services");
+        assert_eq!(json, r#"{"Services":["shop/web"]}"#);
+
+        let response = ControlResponse::Samples(vec![MetricSample {
+            timestamp: chrono::Utc::now(),
+            cpu_percent: 12.5,
+            rss_bytes: 4096,
+            io_read_bytes: 0,
+            io_write_bytes: 0,
+            net_rx_bytes: 10,
+            net_tx_bytes: 20,
+        }]);
+        let json = serde_json::to_string(&response).expect("serialize samples");
+        let parsed: ControlResponse =
+            serde_json::from_str(&json).expect("deserialize samples");
+        assert!(matches!(
+            parsed,
+            ControlResponse::Samples(samples)
+                if samples.len() == 1 && samples[0].rss_bytes == 4096
+        ));
+    }
+
     #[test]
     fn restart_deserializes_missing_and_null_optional_fields() {
         let missing = r#"{"Restart":{"config":"sysg.config.yaml"}}"#;
@@ -902,7 +1561,10 @@ mod tests {
             ControlCommand::Restart {
                 config: Some(_),
                 service: None,
-                project: None
+                project: None,
+                with_dependents: false,
+                build_first: false,
+                wait: false
             }
         ));
 
@@ -915,7 +1577,10 @@ mod tests {
             ControlCommand::Restart {
                 config: Some(_),
                 service: None,
-                project: None
+                project: None,
+                with_dependents: false,
+                build_first: false,
+                wait: false
             }
         ));
     }
@@ -946,6 +1611,30 @@ mod tests {
         assert!(json.contains("Inspect"));
     }
 
+    #[test]
+    fn log_follow_omits_unset_filters() {
+        let command = ControlCommand::LogFollow {
+            service: "api".into(),
+            project: None,
+            lines: 0,
+            kind: None,
+            grep: Some("ERROR".into()),
+        };
+        let json = serde_json::to_string(&command).unwrap();
+        assert!(json.contains("LogFollow"));
+        assert!(json.contains("ERROR"));
+        assert!(!json.contains("project"));
+
+        let frame: LogFrame =
+            serde_json::from_str(r#"{"Error":{"message":"gone"}}"#).unwrap();
+        assert_eq!(
+            frame,
+            LogFrame::Error {
+                message: "gone".into()
+            }
+        );
+    }
+
     #[test]
     fn write_and_read_supervisor_pid() {
         let _guard = crate::test_utils::env_lock();
@@ -1028,6 +1717,47 @@ mod tests {
         crate::runtime::set_drop_privileges(false);
     }
 
+    #[test]
+    fn send_command_gives_up_on_a_socket_that_never_answers() {
+        let _guard = crate::test_utils::env_lock();
+        let temp = tempdir().unwrap();
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", temp.path());
+        }
+        crate::runtime::init(crate::runtime::RuntimeMode::User);
+        crate::runtime::set_drop_privileges(false);
+
+        let listener = bind_control_socket().expect("bind control socket");
+        std::thread::spawn(move || {
+            let mut held = Vec::new();
+            for stream in listener.incoming() {
+                held.push(stream);
+            }
+        });
+
+        let handshake_timeout = Duration::from_millis(200);
+        let started = std::time::Instant::now();
+        let result = send_command_within(
+            &ControlCommand::Shutdown,
+            Some(WAIT_READ_TIMEOUT),
+            handshake_timeout,
+        );
+        assert!(
+            matches!(result, Err(ControlError::Unresponsive)),
+            "expected Unresponsive, got {result:?}"
+        );
+        assert!(started.elapsed() < handshake_timeout * 10);
+
+        cleanup_runtime().unwrap();
+        match original_home {
+            Some(val) => unsafe { std::env::set_var("HOME", val) },
+            None => unsafe { std::env::remove_var("HOME") },
+        }
+        crate::runtime::init(crate::runtime::RuntimeMode::User);
+        crate::runtime::set_drop_privileges(false);
+    }
+
     #[test]
     fn write_and_read_command_response() {
         let temp = tempdir().unwrap();
@@ -1044,8 +1774,9 @@ mod tests {
         std::thread::spawn(move || {
             let (mut stream, _) = listener.accept().unwrap();
 
-            let cmd = read_command(&mut stream).unwrap();
-            assert!(matches!(cmd, ControlCommand::Start { .. }));
+            let request = read_request(&mut stream).unwrap();
+            assert!(request.token.is_none());
+            assert!(matches!(request.command, ControlCommand::Start { .. }));
 
             let response = ControlResponse::Message("Started".to_string());
             write_response(&mut stream, &response).unwrap();
@@ -1092,13 +1823,66 @@ mod tests {
         });
 
         let (mut stream, _) = listener.accept().unwrap();
-        let result = read_command(&mut stream);
+        let result = read_request(&mut stream);
         assert!(matches!(
             result,
             Err(ControlError::Io(err)) if err.kind() == io::ErrorKind::InvalidData
         ));
     }
 
+    #[test]
+    fn read_request_accepts_the_token_envelope() {
+        let (mut client, mut server) = UnixStream::pair().unwrap();
+        client
+            .write_all(b"{\"token\":\"abc\",\"command\":\"Shutdown\"}\n")
+            .unwrap();
+        let request = read_request(&mut server).unwrap();
+        assert!(matches!(request.command, ControlCommand::Shutdown));
+        assert!(request.is_authorized("abc"));
+        assert!(!request.is_authorized("abd"));
+        assert!(!request.is_authorized("abcd"));
+
+        client.write_all(b"\"Shutdown\"\n").unwrap();
+        let bare = read_request(&mut server).unwrap();
+        assert!(!bare.is_authorized("abc"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn control_token_is_owner_only_and_cleared_with_the_runtime() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let _guard = crate::test_utils::env_lock();
+        let temp = tempdir().unwrap();
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", temp.path());
+        }
+        crate::runtime::init(crate::runtime::RuntimeMode::User);
+        crate::runtime::set_drop_privileges(false);
+
+        let token = write_control_token().expect("write control token");
+        assert_eq!(token.len(), crate::constants::CONTROL_TOKEN_BYTES * 2);
+        assert_eq!(read_control_token().as_deref(), Some(token.as_str()));
+        let mode = fs::metadata(control_token_path().unwrap())
+            .unwrap()
+            .permissions()
+            .mode()
+            & 0o777;
+        assert_eq!(mode, crate::constants::PRIVATE_FILE_MODE);
+        assert_ne!(write_control_token().unwrap(), token);
+
+        cleanup_runtime().unwrap();
+        assert!(read_control_token().is_none());
+
+        match original_home {
+            Some(val) => unsafe { std::env::set_var("HOME", val) },
+            None => unsafe { std::env::remove_var("HOME") },
+        }
+        crate::runtime::init(crate::runtime::RuntimeMode::User);
+        crate::runtime::set_drop_privileges(false);
+    }
+
     #[test]
     fn control_error_from_io_error() {
         let io_err = io::Error::new(io::ErrorKind::NotFound, "file not found");
@@ -23,9 +23,21 @@ pub mod cli;
 /// Config loading.
 pub mod config;
 
+/// Shell completion scripts.
+pub mod completion;
+
+/// Manifest import from docker-compose files and Procfiles.
+pub mod import;
+
 /// Configuration validation and diagnostics.
 pub mod validate;
 
+/// Dry runs of one service's skip condition and health check.
+pub mod config_test;
+
+/// Redacted diagnostic bundles for bug reports.
+pub mod snapshot;
+
 /// Constants.
 pub mod constants;
 
@@ -93,6 +105,9 @@ pub mod stop;
 /// The `restart` command, rebuilt from first principles.
 pub mod restart;
 
+/// The `reload` command's dry-run preview.
+pub mod reload;
+
 /// The `purge` command, rebuilt from first principles.
 pub mod purge;
 
@@ -102,5 +117,23 @@ pub mod logs_cmd;
 /// The `inspect` command's plan layer, rebuilt from first principles.
 pub mod inspect;
 
+/// The `wait` command's plan layer.
+pub mod wait;
+
 /// Privilege dropping.
 pub mod privilege;
+
+/// Readiness and watchdog notifications for a parent service manager.
+pub mod notify;
+
+/// Listening sockets held for socket-activated services.
+pub mod sockets;
+
+/// Stdin pipes held for interactive services, written by `sysg attach`.
+pub mod attach;
+
+/// Pseudo-terminals for services that should see a TTY on stdout.
+pub mod pty;
+
+/// Differences between a configuration and what is running, for `sysg diff`.
+pub mod diff;
@@ -3,19 +3,20 @@
 //! Service output is captured into one canonical per-service log, with each line
 //! tagged by capture timestamp and source stream.
 use std::{
-    collections::{HashMap, HashSet},
+    borrow::Cow,
+    collections::{HashMap, HashSet, VecDeque},
     env,
     fs::{self, File, OpenOptions},
     io::{self, BufWriter, Read, Seek, SeekFrom, Write},
     path::{Path, PathBuf},
     sync::{
-        Arc, Mutex, OnceLock,
-        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Condvar, Mutex, OnceLock,
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
         mpsc,
         mpsc::RecvTimeoutError,
     },
     thread,
-    time::Duration,
+    time::{Duration, Instant},
 };
 #[cfg(any(target_os = "linux", target_os = "macos"))]
 use std::{
@@ -23,14 +24,18 @@ use std::{
         io::{AsRawFd, FromRawFd, IntoRawFd, RawFd},
         net::UnixStream,
     },
-    process::{ChildStderr, ChildStdout, Command, Stdio},
+    process::{ChildStderr, Command, Stdio},
 };
 
+use regex::Regex;
+use sha2::{Digest, Sha256};
 use terminal_size::Width;
-use tracing::debug;
+use tracing::{debug, warn};
 
 use crate::{
-    config::EffectiveLogsConfig, error::LogsManagerError, runtime,
+    config::{EffectiveLogsConfig, LogFlush},
+    error::LogsManagerError,
+    runtime, snapshot,
     upgrade::HandoffLogPipe,
 };
 
@@ -40,6 +45,17 @@ const SERVICE_LOG_THREAD: &str = "sysg-service-log";
 const SERVICE_STDOUT_THREAD: &str = "sysg-service-stdout";
 /// Thread name for service stderr readers.
 const SERVICE_STDERR_THREAD: &str = "sysg-service-stderr";
+/// Thread name for pooled managed-service pipe readers.
+const SERVICE_LOG_READER_THREAD: &str = "sysg-log-reader";
+/// Thread name for pooled managed-service canonical log writers.
+const SERVICE_LOG_WRITER_THREAD: &str = "sysg-log-writer";
+/// Default cap on the reader threads, and separately on the writer threads,
+/// shared by every managed service stream.
+pub const DEFAULT_LOG_READER_THREADS: usize = 4;
+/// Longest a pooled reader sleeps in `poll` before rechecking the handoff pause.
+const LOG_READER_POLL_TIMEOUT_MS: libc::c_int = 100;
+/// Reads taken from one ready pipe before the others get a turn.
+const LOG_READER_READ_BURST: usize = 16;
 /// Thread name for dynamic child log readers.
 const CHILD_LOG_THREAD: &str = "sysg-child-log";
 /// Directory containing separately managed dynamic-child logs.
@@ -50,6 +66,16 @@ const MIN_HANDOFF_FD: RawFd = 3;
 const LOG_HANDOFF_TIMEOUT: Duration = Duration::from_secs(5);
 /// Delay between log-reader pause checks.
 const LOG_HANDOFF_POLL_INTERVAL: Duration = Duration::from_millis(5);
+/// Longest a `flush: interval` writer holds buffered output.
+const LOG_FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+/// Maximum time the monitor waits for a service's log to sync after it exits.
+const LOG_EXIT_SYNC_TIMEOUT: Duration = Duration::from_millis(500);
+/// Maximum time a reaped service's writer gets to drain its queued lines.
+const LOG_EXIT_DRAIN_TIMEOUT: Duration = Duration::from_secs(2);
+/// Thread name for the writer draining a [`FollowBuffer`].
+const LOG_FOLLOW_THREAD: &str = "sysg-log-follow";
+/// Largest slice of a followed log file read per pass.
+const FOLLOW_READ_CHUNK: u64 = 64 * 1024;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 /// High-level bucket for all-services log rendering.
@@ -494,6 +520,49 @@ fn captured_line_timestamp(line: &[u8]) -> Option<chrono::DateTime<chrono::Utc>>
         .map(|parsed| parsed.with_timezone(&chrono::Utc))
 }
 
+/// Line count and capture-time range of a log selection written to a file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogExportSummary {
+    /// Number of non-empty lines in the selection.
+    pub lines: usize,
+    /// Earliest capture timestamp seen in the selection.
+    pub first: Option<chrono::DateTime<chrono::Utc>>,
+    /// Latest capture timestamp seen in the selection.
+    pub last: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl LogExportSummary {
+    /// Summarizes newline-delimited captured log bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut summary = Self::default();
+        for line in bytes.split(|byte| *byte == b'\n') {
+            let line = line.trim_ascii_end();
+            if line.is_empty() {
+                continue;
+            }
+            summary.lines += 1;
+            if let Some(ts) = captured_line_timestamp(line) {
+                summary.first = Some(summary.first.map_or(ts, |first| first.min(ts)));
+                summary.last = Some(summary.last.map_or(ts, |last| last.max(ts)));
+            }
+        }
+        summary
+    }
+
+    /// Renders the header line that opens an exported log file.
+    pub fn header(&self, service: &str) -> String {
+        let range = match (self.first, self.last) {
+            (Some(first), Some(last)) => format!(
+                "{} to {}",
+                first.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+                last.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+            ),
+            _ => "unknown time range".to_string(),
+        };
+        format!("# sysg logs for '{service}': {} lines, {range}", self.lines)
+    }
+}
+
 /// Returns a service's active log path followed by its rotated backups,
 /// ordered oldest to newest, for full-history reads.
 pub fn rotated_history_paths(active: &Path) -> Vec<PathBuf> {
@@ -557,6 +626,91 @@ pub enum LogFormat {
     Raw,
     /// One JSON object per line: `{ts, stream, service, line}`.
     Json,
+    /// Stdout and stderr interleaved by capture timestamp, each line tagged
+    /// `[out]` or `[err]`. Lines are buffered until flush so they can be sorted.
+    Merged,
+}
+
+/// Severity read from a service's own log line, used by `--color-by-level`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogLevel {
+    /// `error`, `fatal`, `critical`, `panic` and kin.
+    Error,
+    /// `warn` or `warning`.
+    Warn,
+    /// `info` or `notice`.
+    Info,
+    /// `debug`.
+    Debug,
+    /// `trace`.
+    Trace,
+}
+
+impl LogLevel {
+    /// Maps a level word, in any case, to its level.
+    fn from_word(word: &str) -> Option<Self> {
+        match word.to_ascii_lowercase().as_str() {
+            "error" | "fatal" | "crit" | "critical" | "panic" | "emerg" | "alert" => {
+                Some(Self::Error)
+            }
+            "warn" | "warning" => Some(Self::Warn),
+            "info" | "notice" => Some(Self::Info),
+            "debug" => Some(Self::Debug),
+            "trace" => Some(Self::Trace),
+            _ => None,
+        }
+    }
+
+    /// ANSI color a line at this level is drawn in.
+    fn color(self) -> &'static str {
+        match self {
+            Self::Error => "\x1b[31m",
+            Self::Warn => "\x1b[33m",
+            Self::Info => "\x1b[32m",
+            Self::Debug => "\x1b[34m",
+            Self::Trace => "\x1b[2m",
+        }
+    }
+}
+
+/// Leading words of a message searched for a bare level, enough to step over
+/// an application's own timestamp and logger name.
+const LEVEL_WORD_WINDOW: usize = 4;
+
+/// Detects the level of a log message from a `level=`, `lvl=`, or `severity=`
+/// field (also JSON's `"level":"error"`) anywhere in it, or from a level word
+/// among its first few words, bare or wrapped as in `[ERROR]` and `WARN:`.
+pub fn detect_log_level(message: &str) -> Option<LogLevel> {
+    let field = message
+        .split(|c: char| c.is_whitespace() || matches!(c, ',' | '{' | '}'))
+        .find_map(|field| {
+            let (key, value) = field.split_once(['=', ':'])?;
+            matches!(
+                key.trim_matches('"').to_ascii_lowercase().as_str(),
+                "level" | "lvl" | "severity"
+            )
+            .then(|| LogLevel::from_word(value.trim_matches('"')))?
+        });
+    field.or_else(|| {
+        message
+            .split_whitespace()
+            .take(LEVEL_WORD_WINDOW)
+            .find_map(|word| {
+                LogLevel::from_word(word.trim_matches(|c: char| !c.is_ascii_alphabetic()))
+            })
+    })
+}
+
+/// Draws `line` in the color of the level detected in `message`. A line that
+/// already carries escape sequences keeps the application's own colors.
+fn tint_by_level<'a>(line: &'a str, message: &str) -> Cow<'a, str> {
+    if line.contains('\x1b') {
+        return Cow::Borrowed(line);
+    }
+    match detect_log_level(message) {
+        Some(level) => Cow::Owned(format!("{}{line}\x1b[0m", level.color())),
+        None => Cow::Borrowed(line),
+    }
 }
 
 /// Prefix of the control line the supervisor emits before a service's bytes so
@@ -666,8 +820,16 @@ pub struct LogWriter<W: Write> {
     inner: W,
     format: LogFormat,
     strip_ansi: bool,
+    level_colors: bool,
     service: Option<String>,
     pending: Vec<u8>,
+    merged: Vec<MergedLine>,
+}
+
+/// A line buffered by [`LogFormat::Merged`] until the writer is flushed.
+struct MergedLine {
+    timestamp: Option<chrono::DateTime<chrono::FixedOffset>>,
+    text: String,
 }
 
 impl<W: Write> LogWriter<W> {
@@ -682,9 +844,41 @@ impl<W: Write> LogWriter<W> {
             inner,
             format,
             strip_ansi,
+            level_colors: false,
             service,
             pending: Vec::new(),
+            merged: Vec::new(),
+        }
+    }
+
+    /// Colors human-readable lines by the level the service logged them at.
+    pub fn with_level_colors(mut self, enabled: bool) -> Self {
+        self.level_colors = enabled;
+        self
+    }
+
+    /// Writes the buffered merged lines ordered by capture timestamp.
+    ///
+    /// The sort is stable, so lines sharing a timestamp keep their read order.
+    /// When any line lacks a parseable timestamp there is nothing to order by,
+    /// so the lines are written as read (the streams concatenated) instead.
+    fn emit_merged(&mut self) -> std::io::Result<()> {
+        if self.merged.is_empty() {
+            return Ok(());
         }
+        let mut lines = std::mem::take(&mut self.merged);
+        if lines.iter().all(|line| line.timestamp.is_some()) {
+            lines.sort_by_key(|line| line.timestamp);
+        } else {
+            warn!(
+                "Some log lines carry no capture timestamp; showing stdout and stderr concatenated instead of merged"
+            );
+        }
+        for line in lines {
+            self.inner.write_all(line.text.as_bytes())?;
+            self.inner.write_all(b"\n")?;
+        }
+        Ok(())
     }
 
     /// Renders and writes a single complete line (newline already stripped).
@@ -698,12 +892,23 @@ impl<W: Write> LogWriter<W> {
         if let Ok(text) = std::str::from_utf8(&bytes)
             && let Some(service) = parse_service_marker(text)
         {
+            if matches!(self.format, LogFormat::Merged) {
+                self.emit_merged()?;
+            }
             self.service = Some(service.to_string());
             return Ok(());
         }
 
         if matches!(self.format, LogFormat::Text) {
-            self.inner.write_all(&bytes)?;
+            if self.level_colors {
+                let text = String::from_utf8_lossy(&bytes);
+                let message =
+                    parse_captured_line(&text).map_or(&*text, |parsed| parsed.message);
+                self.inner
+                    .write_all(tint_by_level(&text, message).as_bytes())?;
+            } else {
+                self.inner.write_all(&bytes)?;
+            }
             self.inner.write_all(b"\n")?;
             return Ok(());
         }
@@ -715,7 +920,12 @@ impl<W: Write> LogWriter<W> {
             LogFormat::Text => unreachable!(),
             LogFormat::Raw => {
                 if let Some(parsed) = parsed {
-                    self.inner.write_all(parsed.message.as_bytes())?;
+                    let line = if self.level_colors {
+                        tint_by_level(parsed.message, parsed.message)
+                    } else {
+                        Cow::Borrowed(parsed.message)
+                    };
+                    self.inner.write_all(line.as_bytes())?;
                     self.inner.write_all(b"\n")?;
                 }
             }
@@ -732,6 +942,35 @@ impl<W: Write> LogWriter<W> {
                     self.inner.write_all(json.as_bytes())?;
                 }
             }
+            LogFormat::Merged => match parsed {
+                Some(parsed) => {
+                    let marker = match parsed.stream {
+                        "stdout" => "out",
+                        "stderr" => "err",
+                        other => other,
+                    };
+                    self.merged.push(MergedLine {
+                        timestamp: chrono::DateTime::parse_from_rfc3339(parsed.timestamp)
+                            .ok(),
+                        text: {
+                            let text = format!(
+                                "{} [{marker}] {}",
+                                parsed.timestamp, parsed.message
+                            );
+                            if self.level_colors {
+                                tint_by_level(&text, parsed.message).into_owned()
+                            } else {
+                                text
+                            }
+                        },
+                    });
+                }
+                None if !text.trim().is_empty() => self.merged.push(MergedLine {
+                    timestamp: None,
+                    text: text.into_owned(),
+                }),
+                None => {}
+            },
         }
         Ok(())
     }
@@ -739,7 +978,10 @@ impl<W: Write> LogWriter<W> {
 
 impl<W: Write> Write for LogWriter<W> {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        if matches!(self.format, LogFormat::Text) && !self.strip_ansi {
+        if matches!(self.format, LogFormat::Text)
+            && !self.strip_ansi
+            && !self.level_colors
+        {
             return self.inner.write(buf);
         }
         self.pending.extend_from_slice(buf);
@@ -756,10 +998,192 @@ impl<W: Write> Write for LogWriter<W> {
             let line = std::mem::take(&mut self.pending);
             self.render_line(&line)?;
         }
+        self.emit_merged()?;
         self.inner.flush()
     }
 }
 
+/// A bounded line buffer between a log follow and a consumer that may not keep
+/// up with it.
+///
+/// Writes never block: complete lines go into a ring of `capacity` lines that a
+/// background thread drains into the inner writer, at most `max_rate` lines a
+/// second when a rate is set. When the ring is full the oldest line is dropped,
+/// and the drain thread writes `… N lines dropped` ahead of the next line it
+/// shows, so a log flood costs lines rather than memory or a stalled producer.
+pub struct FollowBuffer {
+    shared: Arc<FollowShared>,
+    pending: Vec<u8>,
+    drain: Option<thread::JoinHandle<io::Result<()>>>,
+}
+
+/// State shared between a [`FollowBuffer`] and its drain thread.
+struct FollowShared {
+    ring: Mutex<FollowRing>,
+    ready: Condvar,
+}
+
+/// Lines waiting to be written, plus how many were discarded since the last
+/// one written.
+struct FollowRing {
+    lines: VecDeque<Vec<u8>>,
+    capacity: usize,
+    dropped: u64,
+    /// Set once the producer has finished; the drain exits when empty.
+    closed: bool,
+    /// Set when the inner writer failed, so further writes are refused.
+    failed: bool,
+}
+
+impl FollowBuffer {
+    /// Starts draining into `inner`. A `capacity` of zero holds one line.
+    pub fn new<W: Write + Send + 'static>(
+        inner: W,
+        capacity: usize,
+        max_rate: Option<u32>,
+    ) -> io::Result<Self> {
+        let shared = Arc::new(FollowShared {
+            ring: Mutex::new(FollowRing {
+                lines: VecDeque::new(),
+                capacity: capacity.max(1),
+                dropped: 0,
+                closed: false,
+                failed: false,
+            }),
+            ready: Condvar::new(),
+        });
+        let drain_shared = Arc::clone(&shared);
+        let drain = thread::Builder::new()
+            .name(LOG_FOLLOW_THREAD.into())
+            .spawn(move || {
+                let result = drain_follow_buffer(&drain_shared, inner, max_rate);
+                if result.is_err() {
+                    let mut ring = lock_follow_ring(&drain_shared);
+                    ring.failed = true;
+                    ring.lines.clear();
+                }
+                result
+            })?;
+        Ok(Self {
+            shared,
+            pending: Vec::new(),
+            drain: Some(drain),
+        })
+    }
+
+    /// Queues one complete line, dropping the oldest queued line when full.
+    fn push_line(&self, line: Vec<u8>) -> io::Result<()> {
+        let mut ring = lock_follow_ring(&self.shared);
+        if ring.failed {
+            return Err(io::Error::from(io::ErrorKind::BrokenPipe));
+        }
+        if ring.lines.len() >= ring.capacity {
+            ring.lines.pop_front();
+            ring.dropped += 1;
+        }
+        ring.lines.push_back(line);
+        self.shared.ready.notify_one();
+        Ok(())
+    }
+
+    /// Queues any unterminated trailing output, waits for the drain thread to
+    /// write everything still buffered, and reports its write error, if any.
+    pub fn finish(mut self) -> io::Result<()> {
+        self.close()
+    }
+
+    /// Marks the producer side finished and joins the drain thread.
+    fn close(&mut self) -> io::Result<()> {
+        let Some(drain) = self.drain.take() else {
+            return Ok(());
+        };
+        if !self.pending.is_empty() {
+            let line = std::mem::take(&mut self.pending);
+            let _ = self.push_line(line);
+        }
+        lock_follow_ring(&self.shared).closed = true;
+        self.shared.ready.notify_one();
+        drain
+            .join()
+            .unwrap_or_else(|_| Err(io::Error::other("log follow writer panicked")))
+    }
+}
+
+impl Write for FollowBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.pending.extend_from_slice(buf);
+        while let Some(pos) = self.pending.iter().position(|byte| *byte == b'\n') {
+            let line: Vec<u8> = self.pending.drain(..=pos).collect();
+            self.push_line(line)?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for FollowBuffer {
+    fn drop(&mut self) {
+        let _ = self.close();
+    }
+}
+
+/// Locks a follow ring, recovering it if a holder panicked.
+fn lock_follow_ring(shared: &FollowShared) -> std::sync::MutexGuard<'_, FollowRing> {
+    shared
+        .ring
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Writes queued lines into `inner` until the producer closes the buffer,
+/// pacing them to `max_rate` lines a second and announcing dropped lines.
+fn drain_follow_buffer(
+    shared: &FollowShared,
+    mut inner: impl Write,
+    max_rate: Option<u32>,
+) -> io::Result<()> {
+    let interval =
+        max_rate.map(|rate| Duration::from_secs_f64(1.0 / f64::from(rate.max(1))));
+    let mut next_slot = Instant::now();
+    loop {
+        let (line, dropped) = {
+            let mut ring = lock_follow_ring(shared);
+            match ring.lines.pop_front() {
+                Some(line) => (Some(line), std::mem::take(&mut ring.dropped)),
+                None if ring.closed => (None, std::mem::take(&mut ring.dropped)),
+                None => (None, 0),
+            }
+        };
+        if dropped > 0 {
+            writeln!(inner, "\u{2026} {dropped} lines dropped")?;
+        }
+        let Some(line) = line else {
+            inner.flush()?;
+            let ring = lock_follow_ring(shared);
+            if ring.closed && ring.lines.is_empty() {
+                return Ok(());
+            }
+            let _ring = shared
+                .ready
+                .wait_while(ring, |ring| ring.lines.is_empty() && !ring.closed)
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            continue;
+        };
+        if let Some(interval) = interval {
+            let now = Instant::now();
+            if next_slot > now {
+                inner.flush()?;
+                thread::sleep(next_slot - now);
+            }
+            next_slot = next_slot.max(now) + interval;
+        }
+        inner.write_all(&line)?;
+    }
+}
+
 /// Returns the legacy path to the log file for a given project, service and kind.
 pub fn get_log_path(project: &str, service: &str, kind: &str) -> PathBuf {
     resolve_log_path(project, service, kind)
@@ -814,17 +1238,48 @@ fn project_log_dir(project: &str) -> PathBuf {
     runtime::log_dir().join(project)
 }
 
+/// Returns the filename stem a service's logs are written under.
+///
+/// ASCII letters, digits, `-`, `_`, `.` and `@` are kept as they are; every
+/// other character becomes `_`, as does a leading `.` so no log is hidden.
+/// Because that mapping is lossy, a name that needed any substitution also
+/// gets `-` and the first eight hex digits of its SHA-256, so `web api` and
+/// `web:api` stay apart from each other and from a service named `web_api`.
+pub fn log_file_stem(service: &str) -> String {
+    let mut stem = String::with_capacity(service.len());
+    let mut substituted = false;
+    for (index, c) in service.chars().enumerate() {
+        let keep = c.is_ascii_alphanumeric()
+            || matches!(c, '-' | '_' | '@')
+            || (c == '.' && index > 0);
+        if keep {
+            stem.push(c);
+        } else {
+            stem.push('_');
+            substituted = true;
+        }
+    }
+    if substituted {
+        let digest = Sha256::digest(service.as_bytes());
+        stem.push_str(&format!(
+            "-{:08x}",
+            u32::from_be_bytes(digest[0..4].try_into().unwrap())
+        ));
+    }
+    stem
+}
+
 /// Returns the canonical path for a service log without performing any existence checks.
 fn canonical_log_path(project: &str, service: &str, kind: &str) -> PathBuf {
     let mut path = project_log_dir(project);
-    path.push(format!("{service}_{kind}.log"));
+    path.push(format!("{}_{kind}.log", log_file_stem(service)));
     path
 }
 
 /// Returns the canonical stdout/stderr log path for a service.
 fn canonical_combined_log_path(project: &str, service: &str) -> PathBuf {
     let mut path = project_log_dir(project);
-    path.push(format!("{service}.log"));
+    path.push(format!("{}.log", log_file_stem(service)));
     path
 }
 
@@ -902,6 +1357,79 @@ impl Drop for ProjectLogSub {
     }
 }
 
+/// Readiness waiters keyed by project and service, each woken once by the
+/// first captured line that matches its pattern.
+type ReadyLogWatchers = std::collections::HashMap<(String, String), ReadyLogPattern>;
+
+/// A `ready_log_pattern` and the channel that reports its first match.
+struct ReadyLogPattern {
+    pattern: Regex,
+    matched: mpsc::SyncSender<()>,
+}
+
+/// Registration for one service's `ready_log_pattern`, removed when dropped.
+pub struct ReadyLogWatch {
+    key: (String, String),
+    matched: mpsc::Receiver<()>,
+}
+
+impl ReadyLogWatch {
+    /// Waits up to `timeout` for a matching line; `false` on timeout.
+    pub fn wait(&self, timeout: Duration) -> bool {
+        self.matched.recv_timeout(timeout).is_ok()
+    }
+}
+
+impl Drop for ReadyLogWatch {
+    fn drop(&mut self) {
+        ready_log_watchers()
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .remove(&self.key);
+    }
+}
+
+/// Watches `service`'s captured stdout and stderr for a line matching
+/// `pattern`. Only lines captured after this call are seen, so callers check
+/// what was already logged separately. Replaces any earlier watch.
+pub fn watch_ready_log(project: &str, service: &str, pattern: Regex) -> ReadyLogWatch {
+    let key = (project.to_string(), service.to_string());
+    let (matched, receiver) = mpsc::sync_channel(1);
+    ready_log_watchers()
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .insert(key.clone(), ReadyLogPattern { pattern, matched });
+    ReadyLogWatch {
+        key,
+        matched: receiver,
+    }
+}
+
+/// Wakes the service's readiness waiter if `line` matches its pattern.
+fn notify_ready_log(project: &str, service: &str, line: &[u8]) {
+    let mut watchers = ready_log_watchers()
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if watchers.is_empty() {
+        return;
+    }
+    let key = (project.to_string(), service.to_string());
+    let Some(watch) = watchers.get(&key) else {
+        return;
+    };
+    let text = String::from_utf8_lossy(&strip_ansi(line)).into_owned();
+    if watch.pattern.is_match(&text) {
+        let _ = watch.matched.try_send(());
+        watchers.remove(&key);
+    }
+}
+
+/// Returns the registry of pending `ready_log_pattern` waiters.
+fn ready_log_watchers() -> &'static Mutex<ReadyLogWatchers> {
+    static WATCHERS: OnceLock<Mutex<ReadyLogWatchers>> = OnceLock::new();
+    WATCHERS.get_or_init(Default::default)
+}
+
 /// Returns the global live log registry shared by supervisor-side log readers.
 fn live_log_registry()
 -> &'static Mutex<std::collections::HashMap<LiveLogKey, LiveLogEntry>> {
@@ -1119,7 +1647,9 @@ pub fn resolve_log_path(project: &str, service: &str, kind: &str) -> PathBuf {
         return canonical;
     }
 
-    locate_existing_log(project, service, kind).unwrap_or(canonical)
+    legacy_log_path(project, service, &format!("_{kind}.log"))
+        .or_else(|| locate_existing_log(project, service, kind))
+        .unwrap_or(canonical)
 }
 
 /// Attempts to resolve an on-disk merged log path for the given service.
@@ -1129,7 +1659,20 @@ fn resolve_combined_log_path(project: &str, service: &str) -> PathBuf {
         return canonical;
     }
 
-    locate_existing_combined_log(project, service).unwrap_or(canonical)
+    legacy_log_path(project, service, ".log")
+        .or_else(|| locate_existing_combined_log(project, service))
+        .unwrap_or(canonical)
+}
+
+/// Returns the file a service whose name needs sanitizing wrote before log
+/// filenames went through [`log_file_stem`], when it is still on disk. It is
+/// only read until the service writes its first line under the new name.
+fn legacy_log_path(project: &str, service: &str, suffix: &str) -> Option<PathBuf> {
+    if log_file_stem(service) == service || validate_service_name(service).is_err() {
+        return None;
+    }
+    let path = project_log_dir(project).join(format!("{service}{suffix}"));
+    path.is_file().then_some(path)
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -1331,10 +1874,15 @@ impl ActiveLogFile {
 
     /// Writes one already-formatted log line.
     fn write_line(&mut self, line: &[u8]) -> std::io::Result<()> {
-        if self.settings.max_bytes > 0
-            && self.active_len > 0
-            && self.active_len.saturating_add(line.len() as u64) > self.settings.max_bytes
-        {
+        if self.rotation_due(line.len()) {
+            // `logs --clear` truncates the file under this writer; re-read its
+            // length so a cleared file is not rotated on a stale count.
+            self.file.flush()?;
+            if let Ok(meta) = self.file.get_ref().metadata() {
+                self.active_len = meta.len();
+            }
+        }
+        if self.rotation_due(line.len()) {
             self.file.flush()?;
             rotate_log_file(&self.path, self.settings.max_files)?;
             let raw_file = OpenOptions::new()
@@ -1350,10 +1898,23 @@ impl ActiveLogFile {
         Ok(())
     }
 
+    /// Whether appending `len` more bytes would exceed the rotation limit.
+    fn rotation_due(&self, len: usize) -> bool {
+        self.settings.max_bytes > 0
+            && self.active_len > 0
+            && self.active_len.saturating_add(len as u64) > self.settings.max_bytes
+    }
+
     /// Flushes the active file.
     fn flush(&mut self) -> std::io::Result<()> {
         self.file.flush()
     }
+
+    /// Flushes the active file and asks the OS to persist it to disk.
+    fn sync(&mut self) -> std::io::Result<()> {
+        self.file.flush()?;
+        self.file.get_ref().sync_data()
+    }
 }
 
 /// Shared, rotation-aware writer for the supervisor's own tracing output.
@@ -1373,7 +1934,12 @@ impl RotatingLogWriter {
 
 impl Write for RotatingLogWriter {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        let payload = truncate_log_payload(buf);
+        let mut payload = truncate_log_payload(buf);
+        if let Ok(text) = std::str::from_utf8(&payload)
+            && let Cow::Owned(masked) = snapshot::mask_resolved_secrets(text)
+        {
+            payload = masked.into_bytes();
+        }
         let mut file = self
             .inner
             .lock()
@@ -1549,6 +2115,37 @@ fn write_project_log_event(
     Ok(())
 }
 
+/// Writes each complete captured line in `bytes` that passes the stream and
+/// content filters to a `LogFollow` client as one JSON frame.
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn write_log_frames(
+    socket: &mut UnixStream,
+    service: &str,
+    bytes: &[u8],
+    stream: Option<LogStream>,
+    filter: &LogFilter,
+) -> Result<(), LogsManagerError> {
+    let mut wrote = false;
+    for line in bytes.split(|byte| *byte == b'\n') {
+        let line = line.trim_ascii_end();
+        if line.is_empty() || !line_matches_stream(line, stream) || !filter.matches(line)
+        {
+            continue;
+        }
+        let frame = crate::ipc::LogFrame::Line {
+            service: service.to_string(),
+            line: String::from_utf8_lossy(line).into_owned(),
+        };
+        let encoded = serde_json::to_string(&frame).map_err(io::Error::other)?;
+        writeln!(socket, "{encoded}")?;
+        wrote = true;
+    }
+    if wrote {
+        socket.flush()?;
+    }
+    Ok(())
+}
+
 /// Writes a section header used by the all-services log view.
 pub fn write_log_section_header(
     mut writer: impl Write,
@@ -1649,26 +2246,60 @@ fn tail_log_file(path: &Path, lines: usize) -> Result<Vec<u8>, LogsManagerError>
     }
 
     let mut file = File::open(path)?;
-    let mut remaining = file.metadata()?.len();
-    let mut bytes = Vec::new();
-
-    while remaining > 0 {
-        let chunk_len = remaining.min(LOG_TAIL_CHUNK_SIZE);
-        remaining -= chunk_len;
-
-        file.seek(SeekFrom::Start(remaining))?;
-        let mut chunk = vec![0_u8; chunk_len as usize];
-        file.read_exact(&mut chunk)?;
-
-        chunk.extend_from_slice(&bytes);
-        bytes = chunk;
+    let len = file.metadata()?.len();
+    Ok(tail_matching_lines(&mut file, len, lines, |_| true)?)
+}
 
-        if tail_log_bytes(&bytes, lines).len() < bytes.len() {
-            break;
+/// Collects the last `lines` lines that `keep` accepts by reading `reader`
+/// backwards from `len` in fixed-size blocks. Only the kept lines and the line
+/// being assembled are held, so memory tracks the size of the answer rather
+/// than of the file. Lines keep their trailing newline.
+fn tail_matching_lines<R: Read + Seek>(
+    reader: &mut R,
+    len: u64,
+    lines: usize,
+    keep: impl Fn(&[u8]) -> bool,
+) -> io::Result<Vec<u8>> {
+    // Newest first; reversed once the scan stops.
+    let mut kept: Vec<Vec<u8>> = Vec::new();
+    // The end of the line whose start has not been read yet.
+    let mut partial = Vec::new();
+    let mut end = len;
+
+    while end > 0 && kept.len() < lines {
+        let start = end.saturating_sub(LOG_TAIL_CHUNK_SIZE);
+        reader.seek(SeekFrom::Start(start))?;
+        let mut block = vec![0_u8; (end - start) as usize];
+        reader.read_exact(&mut block)?;
+        end = start;
+
+        let mut cut = block.len();
+        while let Some(newline) = block[..cut].iter().rposition(|byte| *byte == b'\n') {
+            let mut line = block[newline + 1..cut].to_vec();
+            line.append(&mut partial);
+            // Only the gap after a final newline is empty; every real line
+            // carries at least its own newline.
+            if !line.is_empty() && keep(&line) {
+                kept.push(line);
+                if kept.len() == lines {
+                    break;
+                }
+            }
+            partial.push(b'\n');
+            cut = newline;
+        }
+        if kept.len() < lines {
+            let mut head = block[..cut].to_vec();
+            head.append(&mut partial);
+            partial = head;
         }
     }
 
-    Ok(tail_log_bytes(&bytes, lines))
+    if end == 0 && kept.len() < lines && !partial.is_empty() && keep(&partial) {
+        kept.push(partial);
+    }
+    kept.reverse();
+    Ok(kept.concat())
 }
 
 /// Returns whether a captured canonical service log line belongs to `kind`.
@@ -1695,40 +2326,10 @@ fn tail_log_file_filtered(
     }
 
     let mut file = File::open(path)?;
-    let mut remaining = file.metadata()?.len();
-    let mut bytes = Vec::new();
-
-    while remaining > 0 {
-        let chunk_len = remaining.min(LOG_TAIL_CHUNK_SIZE);
-        remaining -= chunk_len;
-
-        file.seek(SeekFrom::Start(remaining))?;
-        let mut chunk = vec![0_u8; chunk_len as usize];
-        file.read_exact(&mut chunk)?;
-
-        chunk.extend_from_slice(&bytes);
-        bytes = chunk;
-
-        let matching_count = bytes
-            .split(|byte| *byte == b'\n')
-            .filter(|line| captured_log_line_matches_kind(line, kind))
-            .count();
-        if matching_count > lines {
-            break;
-        }
-    }
-
-    let mut matching = bytes
-        .split_inclusive(|byte| *byte == b'\n')
-        .filter(|line| captured_log_line_matches_kind(line.trim_ascii_end(), kind))
-        .map(Vec::from)
-        .collect::<Vec<_>>();
-
-    if matching.len() > lines {
-        matching.drain(..matching.len() - lines);
-    }
-
-    Ok(matching.concat())
+    let len = file.metadata()?.len();
+    Ok(tail_matching_lines(&mut file, len, lines, |line| {
+        captured_log_line_matches_kind(line.trim_ascii_end(), kind)
+    })?)
 }
 
 /// Filters canonical captured log bytes by stream kind.
@@ -1750,6 +2351,9 @@ fn line_matches_stream(line: &[u8], stream: Option<LogStream>) -> bool {
 
 /// Follows a canonical service log while emitting only lines that pass the
 /// optional stream kind filter and the content filter (e.g. `--grep`).
+///
+/// `lines == 0` is `tail -n 0 -f`: the follow anchors at the current end of the
+/// file and emits only lines appended after it started.
 fn follow_filtered_log_file(
     mut writer: impl Write,
     path: &Path,
@@ -1757,18 +2361,24 @@ fn follow_filtered_log_file(
     stream: Option<LogStream>,
     filter: &LogFilter,
 ) -> Result<(), LogsManagerError> {
-    let initial = match stream {
-        Some(stream) => tail_log_file_filtered(path, lines, stream.as_str())?,
-        None => tail_log_file(path, lines)?,
-    };
-    writer.write_all(&filter.apply(&initial))?;
-    writer.flush()?;
+    if lines > 0 {
+        let initial = match stream {
+            Some(stream) => tail_log_file_filtered(path, lines, stream.as_str())?,
+            None => tail_log_file(path, lines)?,
+        };
+        writer.write_all(&filter.apply(&initial))?;
+        writer.flush()?;
+    }
 
     let mut offset = fs::metadata(path)?.len();
     let mut pending = Vec::new();
 
+    let mut caught_up = true;
+
     loop {
-        thread::sleep(Duration::from_millis(250));
+        if caught_up {
+            thread::sleep(Duration::from_millis(250));
+        }
 
         let current_len = match fs::metadata(path) {
             Ok(metadata) => metadata.len(),
@@ -1786,14 +2396,20 @@ fn follow_filtered_log_file(
         }
 
         if current_len == offset {
+            caught_up = true;
             continue;
         }
 
+        // Read a bounded slice per pass so a flood is consumed as fast as the
+        // writer accepts it rather than buffered whole.
+        let want = (current_len - offset).min(FOLLOW_READ_CHUNK);
+        caught_up = offset + want == current_len;
         let mut file = File::open(path)?;
         file.seek(SeekFrom::Start(offset))?;
-        let mut chunk = Vec::with_capacity((current_len - offset) as usize);
-        file.read_to_end(&mut chunk)?;
-        offset = current_len;
+        let mut chunk = Vec::with_capacity(want as usize);
+        file.take(want).read_to_end(&mut chunk)?;
+        caught_up |= chunk.is_empty();
+        offset += chunk.len() as u64;
         pending.extend_from_slice(&chunk);
 
         while let Some(newline_pos) = pending.iter().position(|byte| *byte == b'\n') {
@@ -2033,32 +2649,60 @@ fn read_service_log_stream(
     Ok(())
 }
 
-/// Writes all service output streams into one canonical append-only service log.
-fn write_service_log(
-    project: &str,
-    service_label: &str,
-    path: PathBuf,
-    receiver: mpsc::Receiver<ServiceLogMessage>,
-    settings: EffectiveLogsConfig,
-) -> std::io::Result<()> {
-    let mut file = ActiveLogFile::open(path, settings)?;
+/// One canonical service log file and its pending-flush state.
+struct ServiceLogSink {
+    /// Project that owns the service.
+    project: String,
+    /// Service whose streams land in the file.
+    service: String,
+    /// Active log file with rotation.
+    file: ActiveLogFile,
+    /// How eagerly written lines reach the file.
+    flush: LogFlush,
+    /// Whether lines were written since the last flush.
+    dirty: bool,
+    /// When the file was last flushed.
+    last_flush: Instant,
+}
+
+impl ServiceLogSink {
+    /// Opens the canonical file for one service.
+    fn open(
+        project: &str,
+        service: &str,
+        path: PathBuf,
+        settings: EffectiveLogsConfig,
+    ) -> io::Result<Self> {
+        Ok(Self {
+            project: project.to_string(),
+            service: service.to_string(),
+            file: ActiveLogFile::open(path, settings)?,
+            flush: settings.flush,
+            dirty: false,
+            last_flush: Instant::now(),
+        })
+    }
 
-    for message in receiver {
+    /// Applies one writer message, then flushes if the policy says so.
+    fn handle(&mut self, message: ServiceLogMessage) -> io::Result<()> {
         match message {
             ServiceLogMessage::Line(line) => {
+                notify_ready_log(&self.project, &self.service, &line.line);
                 let formatted =
                     format_captured_log_line(line.stream.as_str(), &line.line);
-                file.write_line(&formatted)?;
-                file.flush()?;
+                self.file.write_line(&formatted)?;
+                self.dirty = true;
                 append_live_log_chunk(
-                    project,
-                    service_label,
+                    &self.project,
+                    &self.service,
                     LogStream::Combined,
                     &formatted,
                 );
             }
-            ServiceLogMessage::Flush(reply) => match file.flush() {
+            ServiceLogMessage::Flush(reply) => match self.file.sync() {
                 Ok(()) => {
+                    self.dirty = false;
+                    self.last_flush = Instant::now();
                     let _ = reply.send(Ok(()));
                 }
                 Err(err) => {
@@ -2068,25 +2712,77 @@ fn write_service_log(
                 }
             },
         }
+        self.flush_if_due()
     }
 
-    file.flush()
-}
+    /// Flushes buffered lines once the flush policy makes them due.
+    fn flush_if_due(&mut self) -> io::Result<()> {
+        let due = match self.flush {
+            LogFlush::Line => true,
+            LogFlush::Interval => self.last_flush.elapsed() >= LOG_FLUSH_INTERVAL,
+            LogFlush::None => false,
+        };
+        if self.dirty && due {
+            self.file.flush()?;
+            self.dirty = false;
+            self.last_flush = Instant::now();
+        }
+        Ok(())
+    }
 
-/// Copies a spawned-child output stream into its log file while optionally echoing completed lines.
-fn stream_dynamic_child_log(
-    path: &Path,
-    owner_label: Option<&str>,
-    child_label: &str,
-    mut reader: impl Read,
-    echo_to_console: bool,
-) -> std::io::Result<()> {
-    if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent)?;
+    /// How long the writer may block before an interval flush falls due, or
+    /// `None` when nothing is waiting to be flushed.
+    fn flush_wait(&self) -> Option<Duration> {
+        (self.dirty && self.flush == LogFlush::Interval)
+            .then(|| LOG_FLUSH_INTERVAL.saturating_sub(self.last_flush.elapsed()))
     }
+}
 
-    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
-    let mut buffer = [0_u8; 8192];
+/// Writes all service output streams into one canonical append-only service log.
+fn write_service_log(
+    project: &str,
+    service_label: &str,
+    path: PathBuf,
+    receiver: mpsc::Receiver<ServiceLogMessage>,
+    settings: EffectiveLogsConfig,
+) -> std::io::Result<()> {
+    let mut sink = ServiceLogSink::open(project, service_label, path, settings)?;
+    loop {
+        let message = match sink.flush_wait() {
+            Some(wait) => match receiver.recv_timeout(wait) {
+                Ok(message) => Some(message),
+                Err(RecvTimeoutError::Timeout) => None,
+                Err(RecvTimeoutError::Disconnected) => break,
+            },
+            None => match receiver.recv() {
+                Ok(message) => Some(message),
+                Err(_) => break,
+            },
+        };
+        match message {
+            Some(message) => sink.handle(message)?,
+            None => sink.flush_if_due()?,
+        }
+    }
+
+    // Both streams closed: the service is gone, so make what it wrote durable.
+    sink.file.sync()
+}
+
+/// Copies a spawned-child output stream into its log file while optionally echoing completed lines.
+fn stream_dynamic_child_log(
+    path: &Path,
+    owner_label: Option<&str>,
+    child_label: &str,
+    mut reader: impl Read,
+    echo_to_console: bool,
+) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let mut buffer = [0_u8; 8192];
     let mut pending = Vec::new();
 
     loop {
@@ -2159,7 +2855,7 @@ struct RegisteredLogPipe {
     /// Reader pause and partial-line state.
     state: Arc<LogReaderState>,
     /// Channel used to flush the canonical writer before re-exec.
-    writer: mpsc::Sender<ServiceLogMessage>,
+    writer: ServiceLogWriter,
 }
 
 /// Returns the process-wide registry of managed service output pipes.
@@ -2218,33 +2914,262 @@ fn duplicate_for_handoff(fd: RawFd) -> io::Result<File> {
     Ok(unsafe { File::from_raw_fd(duplicate) })
 }
 
-/// Starts the canonical writer shared by a service's stdout and stderr readers.
+/// Registers the canonical writer shared by a service's stdout and stderr
+/// readers with the pooled writer thread that owns the service.
 fn spawn_canonical_service_writer(
     project: &str,
     service: &str,
     settings: EffectiveLogsConfig,
-) -> io::Result<(u64, mpsc::Sender<ServiceLogMessage>)> {
-    let path = get_service_log_path(project, service);
-    let project_label = project.to_string();
-    let service_label = service.to_string();
+) -> io::Result<(u64, ServiceLogWriter)> {
+    let writer_id = next_log_handoff_id();
+    if let Ok(mut writers) = live_log_writers().lock() {
+        writers.push((writer_id, project.to_string(), service.to_string()));
+    }
+    let pool = match submit_pooled_log_writer(writer_id, project, service, settings) {
+        Ok(pool) => pool,
+        Err(err) => {
+            remove_live_log_writer(writer_id);
+            return Err(err);
+        }
+    };
+    let lease = Arc::new(ServiceLogWriterLease {
+        id: writer_id,
+        pool: pool.clone(),
+    });
+    Ok((
+        writer_id,
+        ServiceLogWriter {
+            id: writer_id,
+            pool,
+            _lease: lease,
+        },
+    ))
+}
+
+/// Command delivered to a pooled writer thread for one of its services.
+enum PooledWriterMessage {
+    /// Open the canonical log for a newly captured service.
+    Open {
+        /// Writer identifier later messages are addressed to.
+        id: u64,
+        /// Project that owns the service.
+        project: String,
+        /// Service whose streams land in the log.
+        service: String,
+        /// Rotation and flush policy for the log.
+        settings: EffectiveLogsConfig,
+    },
+    /// Deliver a line or flush request to an open writer.
+    Message(u64, ServiceLogMessage),
+    /// Sync and forget a writer whose readers have all gone.
+    Close(u64),
+}
+
+/// Handle to one service's canonical writer on its pooled writer thread.
+/// Clones share the writer; dropping the last one closes it, as dropping the
+/// last sender of a dedicated writer channel used to.
+#[derive(Clone)]
+struct ServiceLogWriter {
+    /// Writer identifier on the pooled thread.
+    id: u64,
+    /// Channel to the pooled thread that owns the writer.
+    pool: mpsc::Sender<PooledWriterMessage>,
+    /// Shared lease whose drop tells the pooled thread to close the writer.
+    _lease: Arc<ServiceLogWriterLease>,
+}
+
+impl ServiceLogWriter {
+    /// Queues one message for the writer; fails only if its thread is gone.
+    fn send(&self, message: ServiceLogMessage) -> Result<(), ()> {
+        self.pool
+            .send(PooledWriterMessage::Message(self.id, message))
+            .map_err(|_| ())
+    }
+}
+
+/// Closes a pooled writer when the last handle to it is dropped.
+struct ServiceLogWriterLease {
+    /// Writer identifier on the pooled thread.
+    id: u64,
+    /// Channel to the pooled thread that owns the writer.
+    pool: mpsc::Sender<PooledWriterMessage>,
+}
+
+impl Drop for ServiceLogWriterLease {
+    fn drop(&mut self) {
+        let _ = self.pool.send(PooledWriterMessage::Close(self.id));
+    }
+}
+
+/// Returns the process-wide pool of canonical writer threads, indexed by the
+/// slot a service hashes to.
+fn log_writer_pool() -> &'static Mutex<Vec<Option<mpsc::Sender<PooledWriterMessage>>>> {
+    static POOL: OnceLock<Mutex<Vec<Option<mpsc::Sender<PooledWriterMessage>>>>> =
+        OnceLock::new();
+    POOL.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+/// Opens a writer on the pooled thread keyed by its service, starting that
+/// thread on first use. Keying by service keeps a restarted service's old and
+/// new writers on one thread, so their lines reach the file in order.
+fn submit_pooled_log_writer(
+    id: u64,
+    project: &str,
+    service: &str,
+    settings: EffectiveLogsConfig,
+) -> io::Result<mpsc::Sender<PooledWriterMessage>> {
+    let mut hasher = std::hash::DefaultHasher::new();
+    std::hash::Hash::hash(&(project, service), &mut hasher);
+    let limit = log_reader_limit().load(Ordering::Relaxed);
+    let slot = (std::hash::Hasher::finish(&hasher) % limit as u64) as usize;
+
+    let mut pool = log_writer_pool()
+        .lock()
+        .map_err(|_| io::Error::other("log writer pool is poisoned"))?;
+    if pool.len() <= slot {
+        pool.resize_with(slot + 1, || None);
+    }
+    let mut open = PooledWriterMessage::Open {
+        id,
+        project: project.to_string(),
+        service: service.to_string(),
+        settings,
+    };
+    // A thread that died is replaced once before giving up.
+    for _ in 0..2 {
+        let sender = match &pool[slot] {
+            Some(sender) => sender.clone(),
+            None => {
+                let sender = spawn_log_writer_thread()?;
+                pool[slot] = Some(sender.clone());
+                sender
+            }
+        };
+        match sender.send(open) {
+            Ok(()) => return Ok(sender),
+            Err(mpsc::SendError(returned)) => {
+                pool[slot] = None;
+                open = returned;
+            }
+        }
+    }
+    Err(io::Error::other("log writer thread stopped unexpectedly"))
+}
+
+/// Starts one pooled writer thread.
+fn spawn_log_writer_thread() -> io::Result<mpsc::Sender<PooledWriterMessage>> {
     let (sender, receiver) = mpsc::channel();
     thread::Builder::new()
-        .name(SERVICE_LOG_THREAD.into())
-        .spawn(move || {
-            if let Err(err) = write_service_log(
-                &project_label,
-                &service_label,
-                path.clone(),
-                receiver,
+        .name(SERVICE_LOG_WRITER_THREAD.into())
+        .spawn(move || run_log_writer_thread(receiver))?;
+    Ok(sender)
+}
+
+/// Serves the canonical logs of every service keyed to this thread, applying
+/// each one's flush policy as a dedicated writer would.
+fn run_log_writer_thread(receiver: mpsc::Receiver<PooledWriterMessage>) {
+    let mut sinks: HashMap<u64, ServiceLogSink> = HashMap::new();
+    loop {
+        let message = match sinks.values().filter_map(ServiceLogSink::flush_wait).min() {
+            Some(wait) => match receiver.recv_timeout(wait) {
+                Ok(message) => Some(message),
+                Err(RecvTimeoutError::Timeout) => None,
+                Err(RecvTimeoutError::Disconnected) => break,
+            },
+            None => match receiver.recv() {
+                Ok(message) => Some(message),
+                Err(_) => break,
+            },
+        };
+
+        match message {
+            Some(PooledWriterMessage::Open {
+                id,
+                project,
+                service,
                 settings,
-            ) {
+            }) => {
+                // Writers take the exact canonical file; the loose match
+                // readers fall back to could land in another service's log.
+                let path = canonical_combined_log_path(&project, &service);
+                match ServiceLogSink::open(&project, &service, path.clone(), settings) {
+                    Ok(sink) => {
+                        sinks.insert(id, sink);
+                    }
+                    Err(err) => {
+                        eprintln!(
+                            "Warning: Unable to write service log file at {:?}: {}",
+                            path, err
+                        );
+                        remove_live_log_writer(id);
+                    }
+                }
+            }
+            Some(PooledWriterMessage::Message(id, message)) => {
+                let Some(sink) = sinks.get_mut(&id) else {
+                    if let ServiceLogMessage::Flush(reply) = message {
+                        let _ = reply.send(Err(io::Error::new(
+                            io::ErrorKind::BrokenPipe,
+                            "service log writer has stopped",
+                        )));
+                    }
+                    continue;
+                };
+                if let Err(err) = sink.handle(message) {
+                    eprintln!(
+                        "Warning: Unable to write service log file at {:?}: {}",
+                        sink.file.path, err
+                    );
+                    sinks.remove(&id);
+                    remove_live_log_writer(id);
+                }
+            }
+            Some(PooledWriterMessage::Close(id)) => {
+                // Both streams closed: the service is gone, so make what it
+                // wrote durable.
+                if let Some(mut sink) = sinks.remove(&id)
+                    && let Err(err) = sink.file.sync()
+                {
+                    eprintln!(
+                        "Warning: Unable to write service log file at {:?}: {}",
+                        sink.file.path, err
+                    );
+                }
+                remove_live_log_writer(id);
+            }
+            None => {}
+        }
+
+        let mut failed = Vec::new();
+        for (id, sink) in sinks.iter_mut() {
+            if let Err(err) = sink.flush_if_due() {
                 eprintln!(
                     "Warning: Unable to write service log file at {:?}: {}",
-                    path, err
+                    sink.file.path, err
                 );
+                failed.push(*id);
             }
-        })?;
-    Ok((next_log_handoff_id(), sender))
+        }
+        for id in failed {
+            sinks.remove(&id);
+            remove_live_log_writer(id);
+        }
+    }
+}
+
+/// Canonical writers still draining, as `(writer_id, project, service)`. An
+/// entry outlives its readers, which leave the pipe registry at EOF while
+/// their last lines may still be queued for the writer.
+fn live_log_writers() -> &'static Mutex<Vec<(u64, String, String)>> {
+    static WRITERS: OnceLock<Mutex<Vec<(u64, String, String)>>> = OnceLock::new();
+    WRITERS.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+/// Forgets a canonical writer once it has synced and closed.
+fn remove_live_log_writer(id: u64) {
+    if let Ok(mut writers) = live_log_writers().lock() {
+        writers.retain(|(writer_id, _, _)| *writer_id != id);
+    }
 }
 
 /// Removes a managed log pipe after its reader reaches EOF or fails.
@@ -2254,79 +3179,274 @@ fn remove_registered_log_pipe(id: u64) {
     }
 }
 
-/// Reads one managed service pipe while retaining its partial line for handoff.
-fn read_registered_log_stream(
-    service_label: &str,
+/// Sets the most threads the managed-service reader pool, and separately the
+/// writer pool, may run. Called by the supervisor at startup from
+/// `supervisor.xml`; values below one are treated as one. Threads already
+/// running are kept.
+pub fn set_max_log_reader_threads(limit: usize) {
+    log_reader_limit().store(limit.max(1), Ordering::Relaxed);
+}
+
+/// Upper bound on pooled reader threads.
+fn log_reader_limit() -> &'static AtomicUsize {
+    static LIMIT: AtomicUsize = AtomicUsize::new(DEFAULT_LOG_READER_THREADS);
+    &LIMIT
+}
+
+/// A descriptor-backed pipe a pooled reader can `poll`.
+trait LogPipe: Read + AsRawFd + Send {}
+
+impl<T: Read + AsRawFd + Send> LogPipe for T {}
+
+/// One managed service stream owned by a pooled reader thread.
+struct PooledLogStream {
+    /// Registry identifier removed when the stream closes.
+    id: u64,
+    /// Service label used for forwarded lines and warnings.
+    service: String,
+    /// Captured output stream.
     stream: LogStream,
-    mut reader: impl Read,
-    sender: mpsc::Sender<ServiceLogMessage>,
-    state: &LogReaderState,
-) -> io::Result<()> {
-    let mut buffer = [0_u8; 8192];
-    let mut pending = state
-        .pending
+    /// Nonblocking pipe read end.
+    reader: Box<dyn LogPipe>,
+    /// Unterminated bytes carried between reads.
+    pending: Vec<u8>,
+    /// Canonical writer for the service.
+    writer: ServiceLogWriter,
+    /// Pause and partial-line state shared with the handoff registry.
+    state: Arc<LogReaderState>,
+}
+
+/// Handle to one pooled reader thread.
+struct LogReaderThread {
+    /// Hands newly registered streams to the thread.
+    inbox: mpsc::Sender<PooledLogStream>,
+    /// Write end of the thread's wake pipe, so a new stream is polled at once.
+    wake: File,
+    /// Streams currently owned by the thread.
+    load: Arc<AtomicUsize>,
+}
+
+/// Returns the process-wide pool of managed-service pipe readers.
+fn log_reader_pool() -> &'static Mutex<Vec<LogReaderThread>> {
+    static POOL: OnceLock<Mutex<Vec<LogReaderThread>>> = OnceLock::new();
+    POOL.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+/// Hands a stream to the least-loaded pooled reader, starting another thread
+/// only while every existing one is busy and the pool is below its limit.
+fn submit_pooled_log_stream(stream: PooledLogStream) -> io::Result<()> {
+    let mut pool = log_reader_pool()
         .lock()
-        .unwrap_or_else(std::sync::PoisonError::into_inner)
-        .clone();
+        .map_err(|_| io::Error::other("log reader pool is poisoned"))?;
+    let least_loaded = pool
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, thread)| thread.load.load(Ordering::Relaxed))
+        .map(|(index, thread)| (index, thread.load.load(Ordering::Relaxed)));
+    let index = match least_loaded {
+        Some((index, load))
+            if load == 0 || pool.len() >= log_reader_limit().load(Ordering::Relaxed) =>
+        {
+            index
+        }
+        _ => {
+            pool.push(spawn_log_reader_thread()?);
+            pool.len() - 1
+        }
+    };
+    let thread = &mut pool[index];
+    thread.load.fetch_add(1, Ordering::Relaxed);
+    if thread.inbox.send(stream).is_err() {
+        thread.load.fetch_sub(1, Ordering::Relaxed);
+        pool.remove(index);
+        return Err(io::Error::other("log reader thread stopped unexpectedly"));
+    }
+    // A full wake pipe already guarantees a wakeup, so the result is moot.
+    let _ = thread.wake.write(&[1]);
+    Ok(())
+}
+
+/// Starts one pooled reader thread and its wake pipe.
+fn spawn_log_reader_thread() -> io::Result<LogReaderThread> {
+    let mut fds = [0 as RawFd; 2];
+    if unsafe { libc::pipe(fds.as_mut_ptr()) } < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let wake_reader = unsafe { File::from_raw_fd(fds[0]) };
+    let wake_writer = unsafe { File::from_raw_fd(fds[1]) };
+    for fd in fds {
+        set_close_on_exec(fd, true)?;
+        set_nonblocking(fd)?;
+    }
+    let (inbox, streams) = mpsc::channel();
+    let load = Arc::new(AtomicUsize::new(0));
+    let thread_load = Arc::clone(&load);
+    thread::Builder::new()
+        .name(SERVICE_LOG_READER_THREAD.into())
+        .spawn(move || run_log_reader_thread(streams, wake_reader, thread_load))?;
+    Ok(LogReaderThread {
+        inbox,
+        wake: wake_writer,
+        load,
+    })
+}
+
+/// Multiplexes many managed service pipes on one thread with `poll`, keeping
+/// the per-stream line splitting and handoff pause of a dedicated reader.
+fn run_log_reader_thread(
+    inbox: mpsc::Receiver<PooledLogStream>,
+    mut wake: File,
+    load: Arc<AtomicUsize>,
+) {
+    let mut streams: Vec<PooledLogStream> = Vec::new();
+    let mut buffer = [0_u8; 8192];
     loop {
+        streams.extend(inbox.try_iter());
         if log_handoff_paused().load(Ordering::Acquire) {
-            state.paused.store(true, Ordering::Release);
+            for stream in &streams {
+                stream.state.paused.store(true, Ordering::Release);
+            }
             while log_handoff_paused().load(Ordering::Acquire) {
+                for stream in inbox.try_iter() {
+                    stream.state.paused.store(true, Ordering::Release);
+                    streams.push(stream);
+                }
+                thread::sleep(LOG_HANDOFF_POLL_INTERVAL);
+            }
+            for stream in &streams {
+                stream.state.paused.store(false, Ordering::Release);
+            }
+            continue;
+        }
+
+        let mut fds = Vec::with_capacity(streams.len() + 1);
+        fds.push(libc::pollfd {
+            fd: wake.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        });
+        fds.extend(streams.iter().map(|stream| libc::pollfd {
+            fd: stream.reader.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        }));
+        let ready = unsafe {
+            libc::poll(
+                fds.as_mut_ptr(),
+                fds.len() as libc::nfds_t,
+                LOG_READER_POLL_TIMEOUT_MS,
+            )
+        };
+        if ready < 0 {
+            let err = io::Error::last_os_error();
+            if err.kind() != io::ErrorKind::Interrupted {
+                eprintln!("Warning: Unable to poll service log pipes: {err}");
                 thread::sleep(LOG_HANDOFF_POLL_INTERVAL);
             }
-            state.paused.store(false, Ordering::Release);
             continue;
         }
-        match reader.read(&mut buffer) {
-            Ok(0) => break,
+        if fds[0].revents != 0 {
+            while matches!(wake.read(&mut buffer), Ok(read) if read > 0) {}
+        }
+
+        let mut closed = Vec::new();
+        for (index, polled) in fds[1..].iter().enumerate() {
+            if polled.revents != 0 && !pump_log_stream(&mut streams[index], &mut buffer) {
+                closed.push(index);
+            }
+        }
+        // Descending order keeps `swap_remove` from moving an index still queued.
+        for index in closed.into_iter().rev() {
+            finish_log_stream(streams.swap_remove(index));
+            load.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Reads what a ready pipe has buffered and forwards each complete line.
+/// Returns `false` once the stream has reached EOF, failed, or lost its writer.
+fn pump_log_stream(stream: &mut PooledLogStream, buffer: &mut [u8]) -> bool {
+    let mut open = true;
+    for _ in 0..LOG_READER_READ_BURST {
+        match stream.reader.read(buffer) {
+            Ok(0) => {
+                open = false;
+                break;
+            }
             Ok(bytes_read) => {
-                pending.extend_from_slice(&buffer[..bytes_read]);
+                stream.pending.extend_from_slice(&buffer[..bytes_read]);
                 while let Some(newline_pos) =
-                    pending.iter().position(|byte| *byte == b'\n')
+                    stream.pending.iter().position(|byte| *byte == b'\n')
                 {
-                    let mut line = pending.drain(..=newline_pos).collect::<Vec<_>>();
+                    let mut line =
+                        stream.pending.drain(..=newline_pos).collect::<Vec<_>>();
                     if matches!(line.last(), Some(b'\n')) {
                         line.pop();
                     }
                     if matches!(line.last(), Some(b'\r')) {
                         line.pop();
                     }
-                    forward_prefixed_line(service_label, &line, false);
-                    if sender
-                        .send(ServiceLogMessage::Line(ServiceLogLine { stream, line }))
+                    forward_prefixed_line(&stream.service, &line, false);
+                    if stream
+                        .writer
+                        .send(ServiceLogMessage::Line(ServiceLogLine {
+                            stream: stream.stream,
+                            line,
+                        }))
                         .is_err()
                     {
-                        return Ok(());
+                        stream.pending.clear();
+                        return false;
                     }
                 }
-                *state
-                    .pending
-                    .lock()
-                    .unwrap_or_else(std::sync::PoisonError::into_inner) = pending.clone();
-            }
-            Err(err) if err.kind() == io::ErrorKind::WouldBlock => {
-                thread::sleep(LOG_HANDOFF_POLL_INTERVAL);
             }
+            Err(err) if err.kind() == io::ErrorKind::WouldBlock => break,
             Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
-            Err(err) => return Err(err),
+            // A pty master reports EIO once the service's side is closed.
+            Err(err) if err.raw_os_error() == Some(libc::EIO) => {
+                open = false;
+                break;
+            }
+            Err(err) => {
+                eprintln!(
+                    "Warning: Unable to read {} for [{}]: {}",
+                    stream.stream.as_str(),
+                    stream.service,
+                    err
+                );
+                open = false;
+                break;
+            }
         }
     }
-    if !pending.is_empty() {
-        forward_prefixed_line(service_label, &pending, false);
-        let _ = sender.send(ServiceLogMessage::Line(ServiceLogLine {
-            stream,
-            line: pending,
+    stream
+        .state
+        .pending
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone_from(&stream.pending);
+    open
+}
+
+/// Writes a closed stream's trailing partial line and unregisters its pipe.
+fn finish_log_stream(stream: PooledLogStream) {
+    if !stream.pending.is_empty() {
+        forward_prefixed_line(&stream.service, &stream.pending, false);
+        let _ = stream.writer.send(ServiceLogMessage::Line(ServiceLogLine {
+            stream: stream.stream,
+            line: stream.pending,
         }));
     }
-    state
+    stream
+        .state
         .pending
         .lock()
         .unwrap_or_else(std::sync::PoisonError::into_inner)
         .clear();
-    Ok(())
+    remove_registered_log_pipe(stream.id);
 }
 
-/// Registers and starts one managed service output reader.
+/// Registers one managed service output pipe and hands it to the reader pool.
 #[allow(clippy::too_many_arguments)]
 fn spawn_registered_log_reader<R>(
     project: &str,
@@ -2336,7 +3456,7 @@ fn spawn_registered_log_reader<R>(
     pending: Vec<u8>,
     settings: EffectiveLogsConfig,
     writer_id: u64,
-    writer: mpsc::Sender<ServiceLogMessage>,
+    writer: ServiceLogWriter,
 ) -> io::Result<()>
 where
     R: Read + AsRawFd + Send + 'static,
@@ -2347,7 +3467,7 @@ where
     let id = next_log_handoff_id();
     let state = Arc::new(LogReaderState {
         paused: AtomicBool::new(false),
-        pending: Mutex::new(pending),
+        pending: Mutex::new(pending.clone()),
     });
     registered_log_pipes()
         .lock()
@@ -2363,28 +3483,16 @@ where
             state: Arc::clone(&state),
             writer: writer.clone(),
         });
-    let service_label = service.to_string();
-    let thread_name = match stream {
-        LogStream::Stdout => SERVICE_STDOUT_THREAD,
-        LogStream::Stderr => SERVICE_STDERR_THREAD,
-        LogStream::Combined => SERVICE_LOG_THREAD,
-    };
-    if let Err(err) = thread::Builder::new()
-        .name(thread_name.into())
-        .spawn(move || {
-            if let Err(err) =
-                read_registered_log_stream(&service_label, stream, reader, writer, &state)
-            {
-                eprintln!(
-                    "Warning: Unable to read {} for [{}]: {}",
-                    stream.as_str(),
-                    service_label,
-                    err
-                );
-            }
-            remove_registered_log_pipe(id);
-        })
-    {
+    let submitted = submit_pooled_log_stream(PooledLogStream {
+        id,
+        service: service.to_string(),
+        stream,
+        reader: Box::new(reader),
+        pending,
+        writer,
+        state,
+    });
+    if let Err(err) = submitted {
         remove_registered_log_pipe(id);
         return Err(err);
     }
@@ -2400,7 +3508,7 @@ where
 pub fn spawn_managed_service_log_writers(
     project: &str,
     service: &str,
-    stdout: Option<ChildStdout>,
+    stdout: Option<impl Read + AsRawFd + Send + 'static>,
     stderr: Option<ChildStderr>,
     settings: EffectiveLogsConfig,
 ) -> io::Result<()> {
@@ -2432,6 +3540,55 @@ pub fn spawn_managed_service_log_writers(
     Ok(())
 }
 
+/// Flushes and syncs a managed service's canonical log, waiting briefly for the
+/// writer to acknowledge. Called when the monitor sees the service exit, so the
+/// lines it wrote just before dying are on disk even if its pipes stay open.
+pub fn sync_service_log(project: &str, service: &str) -> io::Result<()> {
+    let writer = registered_log_pipes()
+        .lock()
+        .map_err(|_| io::Error::other("managed log pipe registry is poisoned"))?
+        .iter()
+        .find(|entry| entry.project == project && entry.service == service)
+        .map(|entry| entry.writer.clone());
+    let Some(writer) = writer else {
+        return Ok(());
+    };
+    let (reply, response) = mpsc::sync_channel(1);
+    if writer.send(ServiceLogMessage::Flush(reply)).is_err() {
+        return Ok(());
+    }
+    match response.recv_timeout(LOG_EXIT_SYNC_TIMEOUT) {
+        Ok(result) => result,
+        Err(_) => Err(io::Error::new(
+            io::ErrorKind::TimedOut,
+            format!("log writer for '{service}' did not sync in time"),
+        )),
+    }
+}
+
+/// Like [`sync_service_log`], for a service that has just been reaped. Its
+/// readers leave the pipe registry at EOF, so this waits (bounded) for the
+/// canonical writer to drain what they queued and sync on close. A grandchild
+/// still holding the pipes keeps the writer open; that case falls back to a
+/// plain flush after [`LOG_EXIT_DRAIN_TIMEOUT`].
+pub fn sync_exited_service_log(project: &str, service: &str) -> io::Result<()> {
+    let deadline = std::time::Instant::now() + LOG_EXIT_DRAIN_TIMEOUT;
+    loop {
+        let writing = live_log_writers()
+            .lock()
+            .map_err(|_| io::Error::other("managed log writer registry is poisoned"))?
+            .iter()
+            .any(|(_, owner, name)| owner == project && name == service);
+        if !writing {
+            return Ok(());
+        }
+        if std::time::Instant::now() >= deadline {
+            return sync_service_log(project, service);
+        }
+        thread::sleep(LOG_HANDOFF_POLL_INTERVAL);
+    }
+}
+
 /// Returns whether both managed file-log streams can survive supervisor re-exec.
 pub fn service_log_handoff_ready(project: &str, service: &str) -> bool {
     let Ok(registry) = registered_log_pipes().lock() else {
@@ -2489,7 +3646,13 @@ pub fn prepare_log_pipe_handoff() -> io::Result<Vec<HandoffLogPipe>> {
         }
         let remaining = deadline.saturating_duration_since(std::time::Instant::now());
         match response.recv_timeout(remaining) {
-            Ok(result) => result?,
+            Ok(Ok(())) => {}
+            // A pooled writer that has stopped answers the flush with an error
+            // rather than refusing the send, so this unwinds the pause too.
+            Ok(Err(err)) => {
+                cancel_log_pipe_handoff();
+                return Err(err);
+            }
             Err(_) => {
                 cancel_log_pipe_handoff();
                 return Err(io::Error::new(
@@ -2622,8 +3785,7 @@ pub fn resume_log_pipe_handoff(pipes: &[HandoffLogPipe]) -> io::Result<()> {
         ));
     }
     let streams = validate_log_pipe_handoff(pipes)?;
-    let mut writers: HashMap<(String, String), (u64, mpsc::Sender<ServiceLogMessage>)> =
-        HashMap::new();
+    let mut writers: HashMap<(String, String), (u64, ServiceLogWriter)> = HashMap::new();
     for (pipe, stream) in pipes.iter().zip(streams) {
         let key = (pipe.project.clone(), pipe.service.clone());
         let (writer_id, writer) = match writers.get(&key) {
@@ -2718,7 +3880,9 @@ pub fn spawn_service_log_writers(
     stderr: Option<Box<dyn Read + Send>>,
     settings: EffectiveLogsConfig,
 ) -> io::Result<()> {
-    let path = get_service_log_path(project, service);
+    // Writers take the exact canonical file; the loose match readers fall
+    // back to could land in another service's log.
+    let path = canonical_combined_log_path(project, service);
     let project_label = project.to_string();
     let service_label = service.to_string();
     let (sender, receiver) = mpsc::channel();
@@ -3042,6 +4206,9 @@ impl LogManager {
             return Ok(());
         }
 
+        // Filtered lines write nothing, so the peer is checked on a wall-clock
+        // cadence rather than only when the channel goes quiet.
+        let mut peer_checked = Instant::now();
         loop {
             match subscription.receiver.recv_timeout(LOG_FOLLOW_POLL_INTERVAL) {
                 Ok(event) => write_project_log_event(
@@ -3053,37 +4220,190 @@ impl LogManager {
                     structured,
                     false,
                 )?,
-                Err(RecvTimeoutError::Timeout) => {
-                    if socket_peer_disconnected(&socket) {
-                        break;
-                    }
-                }
+                Err(RecvTimeoutError::Timeout) => {}
                 Err(RecvTimeoutError::Disconnected) => break,
             }
+            if peer_checked.elapsed() >= LOG_FOLLOW_POLL_INTERVAL {
+                if socket_peer_disconnected(&socket) {
+                    break;
+                }
+                peer_checked = Instant::now();
+            }
         }
         Ok(())
     }
 
-    /// Clears stdout and stderr logs for a specific service.
-    pub fn clear_service_logs(
+    /// Serves a `LogFollow` request: pushes one service's captured lines to the
+    /// socket as [`crate::ipc::LogFrame`]s, straight from the live writer
+    /// subscription rather than by tailing files, until the client disconnects.
+    ///
+    /// The backlog is cut from the live buffer captured atomically with the
+    /// subscription, so no line falls between history and live output. When the
+    /// buffer holds fewer than `lines` (e.g. just after a supervisor re-exec) the
+    /// backlog comes from the log file instead.
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    pub fn stream_log_frames_to_socket(
         &self,
         project: &str,
         service_name: &str,
+        lines: usize,
+        kind: Option<&str>,
+        filter: &LogFilter,
+        stream: &UnixStream,
     ) -> Result<(), LogsManagerError> {
         validate_service_name(service_name)?;
-        let stdout_path = resolve_log_path(project, service_name, "stdout");
-        let stderr_path = resolve_log_path(project, service_name, "stderr");
-        let combined_path = resolve_combined_log_path(project, service_name);
-
-        truncate_log_file(&stdout_path)?;
-        truncate_log_file(&stderr_path)?;
-        truncate_log_file(&combined_path)?;
-        remove_rotated_log_files(&stdout_path)?;
-        remove_rotated_log_files(&stderr_path)?;
-        remove_rotated_log_files(&combined_path)?;
+        let stream_kind = kind.and_then(LogStream::from_filter);
+        let (snapshot, subscription) = subscribe_project_logs(&[project.to_string()]);
+        let mut socket = stream.try_clone()?;
 
-        Ok(())
-    }
+        let buffered = snapshot
+            .into_iter()
+            .find(|chunk| chunk.service == service_name)
+            .map(|chunk| chunk.bytes)
+            .unwrap_or_default();
+        let buffered = match stream_kind {
+            Some(stream) => filter_captured_log_bytes(&buffered, stream.as_str()),
+            None => buffered,
+        };
+        let buffered = filter.apply(&buffered);
+        let buffered_lines = buffered.iter().filter(|byte| **byte == b'\n').count();
+        let backlog = if lines == 0 || buffered_lines >= lines {
+            tail_log_bytes(&buffered, lines)
+        } else {
+            // Scanning backwards stops after `lines` matches, so a selective
+            // filter never pulls the whole file into memory.
+            let path = get_service_log_path(project, service_name);
+            File::open(&path)
+                .and_then(|mut file| {
+                    let len = file.metadata()?.len();
+                    tail_matching_lines(&mut file, len, lines, |line| {
+                        let line = line.trim_ascii_end();
+                        line_matches_stream(line, stream_kind) && filter.matches(line)
+                    })
+                })
+                .unwrap_or_default()
+        };
+        write_log_frames(&mut socket, service_name, &backlog, stream_kind, filter)?;
+
+        // Sibling services can keep the subscription busy, and filtered lines
+        // write nothing, so the peer is checked on a wall-clock cadence rather
+        // than only when the channel goes quiet.
+        let mut peer_checked = Instant::now();
+        loop {
+            match subscription.receiver.recv_timeout(LOG_FOLLOW_POLL_INTERVAL) {
+                Ok(chunk) if chunk.service == service_name => write_log_frames(
+                    &mut socket,
+                    service_name,
+                    &chunk.bytes,
+                    stream_kind,
+                    filter,
+                )?,
+                Ok(_) | Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+            if peer_checked.elapsed() >= LOG_FOLLOW_POLL_INTERVAL {
+                if socket_peer_disconnected(&socket) {
+                    break;
+                }
+                peer_checked = Instant::now();
+            }
+        }
+        Ok(())
+    }
+
+    /// Clears stdout and stderr logs for a specific service.
+    pub fn clear_service_logs(
+        &self,
+        project: &str,
+        service_name: &str,
+    ) -> Result<(), LogsManagerError> {
+        for path in self.truncate_service_logs(project, service_name)? {
+            remove_rotated_log_files(&path)?;
+        }
+
+        Ok(())
+    }
+
+    /// Empties a service's active stdout, stderr and merged logs in place and
+    /// returns their paths. The files are truncated rather than unlinked, so a
+    /// writer holding them open in append mode keeps writing to the same,
+    /// now-empty files. Rotated backups are left alone.
+    pub fn truncate_service_logs(
+        &self,
+        project: &str,
+        service_name: &str,
+    ) -> Result<Vec<PathBuf>, LogsManagerError> {
+        validate_service_name(service_name)?;
+        let paths = vec![
+            resolve_log_path(project, service_name, "stdout"),
+            resolve_log_path(project, service_name, "stderr"),
+            resolve_combined_log_path(project, service_name),
+        ];
+        for path in &paths {
+            truncate_log_file(path)?;
+        }
+        Ok(paths)
+    }
+
+    /// Clears the stdout, stderr and merged logs of every service in
+    /// `project`, rotated backups included.
+    pub fn clear_project_logs(&self, project: &str) -> Result<(), LogsManagerError> {
+        for path in self.truncate_project_service_logs(project)? {
+            remove_rotated_log_files(&path)?;
+        }
+
+        Ok(())
+    }
+
+    /// Empties the active log files of every service in `project` in place and
+    /// returns their paths, leaving other projects' logs and rotated backups
+    /// alone.
+    pub fn truncate_project_service_logs(
+        &self,
+        project: &str,
+    ) -> Result<Vec<PathBuf>, LogsManagerError> {
+        // A project id names a directory under the log dir, so it follows the
+        // same path rules as a service name.
+        validate_service_name(project)?;
+        let dir = project_log_dir(project);
+        if !dir.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            let is_active = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.ends_with(".log"));
+            if is_active && path.is_file() {
+                truncate_log_file(&path)?;
+                paths.push(path);
+            }
+        }
+        Ok(paths)
+    }
+
+    /// Empties every service's active log files in place, keeping rotated
+    /// backups and the supervisor log.
+    pub fn truncate_all_service_logs(&self) -> Result<(), LogsManagerError> {
+        let log_dir = runtime::log_dir();
+        runtime::create_private_dir(&log_dir)?;
+        let supervisor_log = supervisor_log_path();
+
+        for path in managed_log_files(&log_dir)? {
+            let is_active = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.ends_with(".log"));
+            if is_active && path != supervisor_log {
+                truncate_log_file(&path)?;
+            }
+        }
+
+        Ok(())
+    }
 
     /// Clears all known service and supervisor log files.
     pub fn clear_all_logs(&self) -> Result<(), LogsManagerError> {
@@ -3509,6 +4829,121 @@ mod tests {
         assert_eq!(tail_log_bytes(b"line 1\nline 2\n", 0), b"");
     }
 
+    /// Wraps a reader and counts the bytes read through it.
+    struct CountingReader<R> {
+        inner: R,
+        read: u64,
+    }
+
+    impl<R: Read> Read for CountingReader<R> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let read = self.inner.read(buf)?;
+            self.read += read as u64;
+            Ok(read)
+        }
+    }
+
+    impl<R: Seek> Seek for CountingReader<R> {
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            self.inner.seek(pos)
+        }
+    }
+
+    #[test]
+    fn tail_matching_lines_agrees_with_tail_log_bytes() {
+        let long = "x".repeat(LOG_TAIL_CHUNK_SIZE as usize * 2 + 7);
+        let samples = [
+            String::new(),
+            "only\n".to_string(),
+            "a\n\nb\n".to_string(),
+            "a\nb\nc".to_string(),
+            format!("{long}\nshort\n{long}\n"),
+            format!("head\n{long}"),
+        ];
+        for sample in &samples {
+            for lines in 1..=4 {
+                let bytes = sample.as_bytes();
+                let tail = tail_matching_lines(
+                    &mut Cursor::new(bytes),
+                    bytes.len() as u64,
+                    lines,
+                    |_| true,
+                )
+                .unwrap();
+                assert_eq!(tail, tail_log_bytes(bytes, lines), "{lines} of {sample:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn tail_of_a_large_log_reads_only_its_end() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("big.log");
+        let mut log = BufWriter::new(File::create(&path).unwrap());
+        let mut len = 0_u64;
+        let mut second_to_last_stderr = 0;
+        for index in 0..200_000 {
+            let stream = if index % 50_000 == 0 {
+                "stderr"
+            } else {
+                "stdout"
+            };
+            if index == 100_000 {
+                second_to_last_stderr = len;
+            }
+            let line = format!("2026-01-01T00:00:00.000000Z {stream} line {index}\n");
+            log.write_all(line.as_bytes()).unwrap();
+            len += line.len() as u64;
+        }
+        log.flush().unwrap();
+        drop(log);
+        assert_eq!(fs::metadata(&path).unwrap().len(), len);
+
+        let mut file = CountingReader {
+            inner: File::open(&path).unwrap(),
+            read: 0,
+        };
+        let tail = tail_matching_lines(&mut file, len, 3, |_| true).unwrap();
+        assert_eq!(
+            String::from_utf8(tail).unwrap(),
+            "2026-01-01T00:00:00.000000Z stdout line 199997\n\
+             2026-01-01T00:00:00.000000Z stdout line 199998\n\
+             2026-01-01T00:00:00.000000Z stdout line 199999\n"
+        );
+        assert!(
+            file.read <= LOG_TAIL_CHUNK_SIZE,
+            "read {} of {len} bytes for three lines",
+            file.read
+        );
+
+        // A rare stream only costs the blocks back to its last two lines.
+        let mut file = CountingReader {
+            inner: File::open(&path).unwrap(),
+            read: 0,
+        };
+        let stderr = tail_matching_lines(&mut file, len, 2, |line| {
+            captured_log_line_matches_kind(line.trim_ascii_end(), "stderr")
+        })
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(stderr).unwrap(),
+            "2026-01-01T00:00:00.000000Z stderr line 100000\n\
+             2026-01-01T00:00:00.000000Z stderr line 150000\n"
+        );
+        assert!(
+            file.read <= len - second_to_last_stderr + LOG_TAIL_CHUNK_SIZE,
+            "read {} of {len} bytes for two stderr lines",
+            file.read
+        );
+        assert_eq!(
+            tail_log_file_filtered(&path, 2, "stderr").unwrap(),
+            tail_matching_lines(&mut File::open(&path).unwrap(), len, 2, |line| {
+                captured_log_line_matches_kind(line.trim_ascii_end(), "stderr")
+            })
+            .unwrap()
+        );
+    }
+
     #[test]
     fn diagnostic_log_lines_exclude_prior_generations() {
         let cutoff = "2026-07-20T18:33:14.000000Z".parse().unwrap();
@@ -3610,6 +5045,100 @@ mod tests {
         crate::runtime::set_drop_privileges(false);
     }
 
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn managed_service_streams_share_bounded_reader_and_writer_pools() {
+        let _guard = crate::test_utils::env_lock();
+
+        let base = std::env::current_dir()
+            .expect("current_dir")
+            .join("target/tmp-home");
+        fs::create_dir_all(&base).unwrap();
+        let temp = tempdir_in(&base).unwrap();
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", temp.path());
+        }
+        crate::runtime::init(crate::runtime::RuntimeMode::User);
+        crate::runtime::set_drop_privileges(false);
+
+        let services = (0..12).map(|i| format!("pooled{i}")).collect::<Vec<_>>();
+        let mut children = Vec::new();
+        for service in &services {
+            let mut child = Command::new("sh")
+                .arg("-c")
+                .arg(format!(
+                    "echo out-{service}; echo err-{service} >&2; read _"
+                ))
+                .stdin(Stdio::piped())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .spawn()
+                .unwrap();
+            spawn_managed_service_log_writers(
+                "__loose__",
+                service,
+                child.stdout.take(),
+                child.stderr.take(),
+                EffectiveLogsConfig::default(),
+            )
+            .unwrap();
+            children.push(child);
+        }
+
+        // Every service is still running, so a per-service thread would show.
+        let count_threads = |name: &str| {
+            fs::read_dir("/proc/self/task")
+                .unwrap()
+                .filter_map(Result::ok)
+                .filter(|task| {
+                    fs::read_to_string(task.path().join("comm"))
+                        .is_ok_and(|comm| comm.trim() == name)
+                })
+                .count()
+        };
+        let readers = count_threads(SERVICE_LOG_READER_THREAD);
+        assert!(
+            readers <= DEFAULT_LOG_READER_THREADS,
+            "{readers} reader threads"
+        );
+        let writers = count_threads(SERVICE_LOG_WRITER_THREAD);
+        assert!(
+            writers <= DEFAULT_LOG_READER_THREADS,
+            "{writers} writer threads"
+        );
+
+        for mut child in children {
+            drop(child.stdin.take());
+            child.wait().unwrap();
+        }
+
+        for service in &services {
+            let path = get_service_log_path("__loose__", service);
+            let deadline = std::time::Instant::now() + Duration::from_secs(5);
+            let mut contents = String::new();
+            while std::time::Instant::now() < deadline {
+                contents = fs::read_to_string(&path).unwrap_or_default();
+                if contents.contains(&format!(" stderr err-{service}")) {
+                    break;
+                }
+                thread::sleep(Duration::from_millis(20));
+            }
+            assert!(contents.contains(&format!(" stdout out-{service}\n")));
+            assert!(contents.contains(&format!(" stderr err-{service}\n")));
+        }
+
+        unsafe {
+            if let Some(home) = original_home {
+                std::env::set_var("HOME", home);
+            } else {
+                std::env::remove_var("HOME");
+            }
+        }
+        crate::runtime::init(crate::runtime::RuntimeMode::User);
+        crate::runtime::set_drop_privileges(false);
+    }
+
     #[test]
     fn spawn_log_writer_persists_unterminated_output() {
         let _guard = crate::test_utils::env_lock();
@@ -3653,6 +5182,117 @@ mod tests {
         crate::runtime::set_drop_privileges(false);
     }
 
+    #[test]
+    fn colliding_service_names_get_distinct_log_files() {
+        let _guard = crate::test_utils::env_lock();
+
+        let base = std::env::current_dir()
+            .expect("current_dir")
+            .join("target/tmp-home");
+        fs::create_dir_all(&base).unwrap();
+        let temp = tempdir_in(&base).unwrap();
+        let home = temp.path();
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", home);
+        }
+        crate::runtime::init(crate::runtime::RuntimeMode::User);
+        crate::runtime::set_drop_privileges(false);
+
+        assert_eq!(log_file_stem("web_api"), "web_api");
+        assert_eq!(log_file_stem("web@2"), "web@2");
+        // Pinned, so the stem cannot drift between calls or releases.
+        let spaced = log_file_stem("web api");
+        assert_eq!(spaced, "web_api-c4556a1f");
+        assert_ne!(spaced, log_file_stem("web:api"));
+        assert_ne!(spaced, log_file_stem("web_api"));
+        assert!(log_file_stem(".web").starts_with("_web-"));
+
+        // Each writer starts only once the previous file exists, so a loose
+        // match onto an earlier service's log would show up as interleaving.
+        let names = ["web-api", "web_api", "web api", "web:api"];
+        for name in names {
+            super::spawn_log_writer(
+                "__loose__",
+                name,
+                Cursor::new(format!("from {name}\n").into_bytes()),
+                "stdout",
+            )
+            .expect("spawn service log writer");
+            let path = canonical_combined_log_path("__loose__", name);
+            let deadline = Instant::now() + Duration::from_secs(5);
+            while !fs::read_to_string(&path).is_ok_and(|c| c.contains(name))
+                && Instant::now() < deadline
+            {
+                thread::sleep(Duration::from_millis(20));
+            }
+        }
+
+        let paths: HashSet<PathBuf> = names
+            .iter()
+            .map(|name| get_service_log_path("__loose__", name))
+            .collect();
+        assert_eq!(paths.len(), names.len());
+        for name in names {
+            let contents =
+                fs::read_to_string(get_service_log_path("__loose__", name)).unwrap();
+            assert_eq!(contents.lines().count(), 1, "{name}: {contents}");
+            assert!(contents.contains(&format!(" stdout from {name}")));
+        }
+
+        unsafe {
+            if let Some(home) = original_home {
+                std::env::set_var("HOME", home);
+            } else {
+                std::env::remove_var("HOME");
+            }
+        }
+        crate::runtime::init(crate::runtime::RuntimeMode::User);
+        crate::runtime::set_drop_privileges(false);
+    }
+
+    #[test]
+    fn sanitized_service_names_still_read_their_pre_rename_log() {
+        let _guard = crate::test_utils::env_lock();
+
+        let base = std::env::current_dir()
+            .expect("current_dir")
+            .join("target/tmp-home");
+        fs::create_dir_all(&base).unwrap();
+        let temp = tempdir_in(&base).unwrap();
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", temp.path());
+        }
+        crate::runtime::init(crate::runtime::RuntimeMode::User);
+        crate::runtime::set_drop_privileges(false);
+
+        let dir = project_log_dir("shop");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("web_api.log"), "another service\n").unwrap();
+        fs::write(dir.join("web api.log"), "before the rename\n").unwrap();
+        assert_eq!(
+            get_service_log_path("shop", "web api"),
+            dir.join("web api.log")
+        );
+
+        fs::write(canonical_combined_log_path("shop", "web api"), "after\n").unwrap();
+        assert_eq!(
+            get_service_log_path("shop", "web api"),
+            dir.join("web_api-c4556a1f.log")
+        );
+
+        unsafe {
+            if let Some(home) = original_home {
+                std::env::set_var("HOME", home);
+            } else {
+                std::env::remove_var("HOME");
+            }
+        }
+        crate::runtime::init(crate::runtime::RuntimeMode::User);
+        crate::runtime::set_drop_privileges(false);
+    }
+
     #[test]
     fn spawn_log_writer_persists_non_utf8_output() {
         let _guard = crate::test_utils::env_lock();
@@ -3718,6 +5358,7 @@ mod tests {
             sink: crate::config::LogSink::File,
             max_bytes: 6,
             max_files: 1,
+            ..EffectiveLogsConfig::default()
         };
         let log_path = get_service_log_path("__loose__", "svc");
         fs::create_dir_all(log_path.parent().expect("log parent")).unwrap();
@@ -3858,6 +5499,7 @@ mod tests {
             sink: crate::config::LogSink::File,
             max_bytes: 8,
             max_files: 1,
+            ..EffectiveLogsConfig::default()
         };
         let mut writer = RotatingLogWriter::open(path.clone(), settings).unwrap();
         writer.write_all(b"first\n").unwrap();
@@ -3929,6 +5571,296 @@ mod tests {
         assert!(parse_time_bound("not-a-time", now).is_err());
     }
 
+    #[test]
+    fn follow_with_zero_lines_emits_only_appended_output() {
+        struct ChannelWriter(std::sync::mpsc::Sender<Vec<u8>>);
+
+        impl Write for ChannelWriter {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0
+                    .send(buf.to_vec())
+                    .map_err(|_| std::io::Error::from(std::io::ErrorKind::BrokenPipe))?;
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let base = std::env::current_dir()
+            .expect("current_dir")
+            .join("target/tmp-home");
+        fs::create_dir_all(&base).unwrap();
+        let temp = tempdir_in(&base).unwrap();
+        let path = temp.path().join("svc.log");
+        fs::write(&path, "2026-07-07T09:00:00Z stdout history\n").unwrap();
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let follow_path = path.clone();
+        let follower = thread::spawn(move || {
+            follow_filtered_log_file(
+                ChannelWriter(sender),
+                &follow_path,
+                0,
+                None,
+                &LogFilter::default(),
+            )
+        });
+
+        // The follower starts from wherever the file ends when it first looks,
+        // so keep appending probes until one comes back; anything written after
+        // that is guaranteed to be past its starting offset.
+        let probe = b"2026-07-07T09:00:00Z stdout probe\n";
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        let first = loop {
+            assert!(
+                std::time::Instant::now() < deadline,
+                "follower never picked up an appended line"
+            );
+            file.write_all(probe).unwrap();
+            if let Ok(frame) = receiver.recv_timeout(Duration::from_millis(50)) {
+                break frame;
+            }
+        };
+        assert_eq!(first, probe, "history must not be replayed");
+
+        file.write_all(b"2026-07-07T09:00:01Z stdout fresh\n")
+            .unwrap();
+        let received = loop {
+            let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+            let frame = receiver
+                .recv_timeout(remaining)
+                .expect("appended line should be followed");
+            if frame != probe {
+                break frame;
+            }
+        };
+        assert_eq!(received, b"2026-07-07T09:00:01Z stdout fresh\n");
+
+        drop(receiver);
+        file.write_all(b"2026-07-07T09:00:02Z stdout later\n")
+            .unwrap();
+        assert!(follower.join().unwrap().is_err());
+    }
+
+    /// Writer that records everything and blocks its first write until released.
+    struct GatedWriter {
+        output: Arc<Mutex<Vec<u8>>>,
+        entered: Option<mpsc::Sender<()>>,
+        release: mpsc::Receiver<()>,
+    }
+
+    impl Write for GatedWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            if let Some(entered) = self.entered.take() {
+                entered.send(()).unwrap();
+                self.release.recv().unwrap();
+            }
+            self.output.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn follow_buffer_drops_oldest_lines_when_the_consumer_stalls() {
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let (entered_tx, entered_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel();
+        let writer = GatedWriter {
+            output: Arc::clone(&output),
+            entered: Some(entered_tx),
+            release: release_rx,
+        };
+        let mut buffer = FollowBuffer::new(writer, 3, None).unwrap();
+
+        buffer.write_all(b"line 1\n").unwrap();
+        entered_rx
+            .recv_timeout(Duration::from_secs(5))
+            .expect("drain thread should start writing");
+        for n in 2..=10 {
+            buffer.write_all(format!("line {n}\n").as_bytes()).unwrap();
+        }
+        release_tx.send(()).unwrap();
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !output.lock().unwrap().ends_with(b"line 10\n") {
+            assert!(Instant::now() < deadline, "buffered lines should drain");
+            thread::sleep(Duration::from_millis(5));
+        }
+        buffer.write_all(b"partial").unwrap();
+        buffer.finish().unwrap();
+
+        assert_eq!(
+            String::from_utf8(output.lock().unwrap().clone()).unwrap(),
+            "line 1\n\u{2026} 6 lines dropped\nline 8\nline 9\nline 10\npartial"
+        );
+    }
+
+    #[test]
+    fn follow_buffer_paces_lines_to_max_rate() {
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let (_entered_tx, release_rx) = mpsc::channel();
+        let writer = GatedWriter {
+            output: Arc::clone(&output),
+            entered: None,
+            release: release_rx,
+        };
+        let started = Instant::now();
+        let mut buffer = FollowBuffer::new(writer, 100, Some(20)).unwrap();
+        for n in 1..=5 {
+            buffer.write_all(format!("line {n}\n").as_bytes()).unwrap();
+        }
+        buffer.finish().unwrap();
+
+        assert!(started.elapsed() >= Duration::from_millis(180));
+        assert_eq!(
+            output
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|b| **b == b'\n')
+                .count(),
+            5
+        );
+    }
+
+    #[test]
+    fn write_log_frames_applies_stream_and_grep_filters() {
+        let (mut server, client) = UnixStream::pair().unwrap();
+        let filter = LogFilter {
+            grep: Some(regex::Regex::new("boom|fine").unwrap()),
+            ..LogFilter::default()
+        };
+        let bytes = b"2026-07-07T09:00:00Z stdout fine\n\
+2026-07-07T09:00:01Z stderr boom\n\
+2026-07-07T09:00:02Z stdout skipped\n";
+        write_log_frames(&mut server, "api", bytes, Some(LogStream::Stdout), &filter)
+            .unwrap();
+        drop(server);
+
+        let frames = std::io::BufRead::lines(std::io::BufReader::new(client))
+            .map(|line| serde_json::from_str::<crate::ipc::LogFrame>(&line.unwrap()))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            frames,
+            vec![crate::ipc::LogFrame::Line {
+                service: "api".into(),
+                line: "2026-07-07T09:00:00Z stdout fine".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn log_follow_exits_when_the_client_leaves_during_sibling_output() {
+        let project = "follow-leaves-busy";
+        let (server, client) = UnixStream::pair().unwrap();
+        let (done_tx, done_rx) = mpsc::channel();
+        thread::spawn(move || {
+            let result = LogManager::new().stream_log_frames_to_socket(
+                project,
+                "api",
+                0,
+                None,
+                &LogFilter::default(),
+                &server,
+            );
+            done_tx.send(result.is_ok()).unwrap();
+        });
+        drop(client);
+
+        // A sibling logging faster than the poll interval keeps the
+        // subscription from ever timing out.
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let finished = loop {
+            append_live_log_chunk(
+                project,
+                "worker",
+                LogStream::Combined,
+                b"2026-07-07T09:00:00Z stdout busy\n",
+            );
+            match done_rx.recv_timeout(Duration::from_millis(10)) {
+                Ok(ok) => break Some(ok),
+                Err(_) if Instant::now() < deadline => {}
+                Err(_) => break None,
+            }
+        };
+        assert_eq!(finished, Some(true), "follow outlived its client");
+    }
+
+    #[test]
+    fn log_follow_backlog_keeps_the_last_matching_lines() {
+        let _guard = crate::test_utils::env_lock();
+
+        let base = std::env::current_dir()
+            .expect("current_dir")
+            .join("target/tmp-home");
+        fs::create_dir_all(&base).unwrap();
+        let temp = tempdir_in(&base).unwrap();
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", temp.path());
+        }
+        crate::runtime::init(crate::runtime::RuntimeMode::User);
+        crate::runtime::set_drop_privileges(false);
+
+        let project = "follow-backlog-grep";
+        let log_path = get_service_log_path(project, "api");
+        fs::create_dir_all(log_path.parent().expect("log parent")).unwrap();
+        let mut contents = String::new();
+        for n in 0..50 {
+            let tag = if n % 10 == 0 { "boom" } else { "fine" };
+            contents.push_str(&format!("2026-07-07T09:00:{n:02}Z stdout {tag} {n}\n"));
+        }
+        fs::write(&log_path, contents).unwrap();
+
+        let (server, client) = UnixStream::pair().unwrap();
+        let filter = LogFilter {
+            grep: Some(regex::Regex::new("boom").unwrap()),
+            ..LogFilter::default()
+        };
+        let follower = thread::spawn(move || {
+            LogManager::new()
+                .stream_log_frames_to_socket(project, "api", 2, None, &filter, &server)
+        });
+        let frames = std::io::BufRead::lines(std::io::BufReader::new(&client))
+            .take(2)
+            .map(|line| serde_json::from_str::<crate::ipc::LogFrame>(&line.unwrap()))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        drop(client);
+        follower.join().unwrap().unwrap();
+
+        assert_eq!(
+            frames,
+            vec![
+                crate::ipc::LogFrame::Line {
+                    service: "api".into(),
+                    line: "2026-07-07T09:00:30Z stdout boom 30".into(),
+                },
+                crate::ipc::LogFrame::Line {
+                    service: "api".into(),
+                    line: "2026-07-07T09:00:40Z stdout boom 40".into(),
+                },
+            ]
+        );
+
+        unsafe {
+            if let Some(home) = original_home {
+                std::env::set_var("HOME", home);
+            } else {
+                std::env::remove_var("HOME");
+            }
+        }
+        crate::runtime::init(crate::runtime::RuntimeMode::User);
+        crate::runtime::set_drop_privileges(false);
+    }
+
     #[test]
     fn log_filter_applies_time_window() {
         let bytes = b"2026-07-07T09:00:00Z stdout early\n\
@@ -3943,6 +5875,27 @@ mod tests {
         assert_eq!(out, "2026-07-07T10:30:00Z stdout middle\n");
     }
 
+    #[test]
+    fn log_export_summary_counts_lines_and_time_range() {
+        let bytes = b"2026-07-07T10:30:00Z stdout middle\n\
+2026-07-07T09:00:00Z stderr early\n\
+continuation without timestamp\n\
+\n\
+2026-07-07T12:00:00Z stdout late\n";
+        let summary = LogExportSummary::from_bytes(bytes);
+        assert_eq!(summary.lines, 4);
+        assert_eq!(summary.first, Some(utc("2026-07-07T09:00:00Z")));
+        assert_eq!(summary.last, Some(utc("2026-07-07T12:00:00Z")));
+        assert_eq!(
+            summary.header("shop/web"),
+            "# sysg logs for 'shop/web': 4 lines, 2026-07-07T09:00:00Z to 2026-07-07T12:00:00Z"
+        );
+        assert_eq!(
+            LogExportSummary::from_bytes(b"").header("web"),
+            "# sysg logs for 'web': 0 lines, unknown time range"
+        );
+    }
+
     #[test]
     fn log_filter_applies_grep() {
         let bytes = b"2026-07-07T09:00:00Z stdout hello world\n\
@@ -4156,6 +6109,94 @@ mod tests {
         );
     }
 
+    #[test]
+    fn detect_log_level_accepts_common_formats() {
+        for (message, level) in [
+            ("[ERROR] disk full", Some(LogLevel::Error)),
+            ("WARN: retrying", Some(LogLevel::Warn)),
+            (
+                "time=2026-07-08T09:00:00Z level=info msg=ready",
+                Some(LogLevel::Info),
+            ),
+            (
+                r#"{"ts":1,"level":"debug","msg":"tick"}"#,
+                Some(LogLevel::Debug),
+            ),
+            (
+                "2026-07-08 09:00:00,123 app.db TRACE query",
+                Some(LogLevel::Trace),
+            ),
+            ("Error: Address already in use", Some(LogLevel::Error)),
+            ("served 12 requests, 0 errors", None),
+            ("GET /health 200", None),
+        ] {
+            assert_eq!(detect_log_level(message), level, "{message}");
+        }
+    }
+
+    #[test]
+    fn log_writer_colors_lines_by_level() {
+        let mut out = Vec::new();
+        {
+            let mut writer = LogWriter::new(&mut out, LogFormat::Text, false, None)
+                .with_level_colors(true);
+            writer
+                .write_all(
+                    b"2026-07-08T09:00:00Z stderr [ERROR] boom\n\
+                      2026-07-08T09:00:01Z stdout plain\n\
+                      2026-07-08T09:00:02Z stdout \x1b[1mWARN\x1b[0m own colors\n",
+                )
+                .unwrap();
+            writer.flush().unwrap();
+        }
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\x1b[31m2026-07-08T09:00:00Z stderr [ERROR] boom\x1b[0m\n\
+             2026-07-08T09:00:01Z stdout plain\n\
+             2026-07-08T09:00:02Z stdout \x1b[1mWARN\x1b[0m own colors\n"
+        );
+    }
+
+    #[test]
+    fn log_writer_merged_interleaves_streams_by_timestamp() {
+        let mut out = Vec::new();
+        {
+            let mut writer = LogWriter::new(&mut out, LogFormat::Merged, true, None);
+            writer
+                .write_all(
+                    b"2026-07-07T09:00:00Z stdout boot\n2026-07-07T09:00:02Z stdout ready\n",
+                )
+                .unwrap();
+            writer
+                .write_all(b"2026-07-07T09:00:01Z stderr retrying db\n")
+                .unwrap();
+            writer.flush().unwrap();
+        }
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "2026-07-07T09:00:00Z [out] boot\n\
+2026-07-07T09:00:01Z [err] retrying db\n\
+2026-07-07T09:00:02Z [out] ready\n"
+        );
+    }
+
+    #[test]
+    fn log_writer_merged_concatenates_lines_without_timestamps() {
+        let mut out = Vec::new();
+        {
+            let mut writer = LogWriter::new(&mut out, LogFormat::Merged, true, None);
+            writer
+                .write_all(b"2026-07-07T09:00:05Z stdout late\n")
+                .unwrap();
+            writer.write_all(b"legacy stderr line\n").unwrap();
+            writer.flush().unwrap();
+        }
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "2026-07-07T09:00:05Z [out] late\nlegacy stderr line\n"
+        );
+    }
+
     #[test]
     fn rotated_history_paths_orders_oldest_to_newest() {
         let dir = std::env::temp_dir().join(format!(
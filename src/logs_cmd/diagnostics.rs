@@ -48,6 +48,18 @@ pub fn target_required() -> Diagnostic {
     .help_docs()
 }
 
+/// Builds the SG0024 diagnostic for a `--clear`/`--clear-all` that could not be
+/// confirmed because stdin is not a terminal and `--yes` was not passed.
+pub fn clear_unconfirmed(subject: &str) -> Diagnostic {
+    Diagnostic::error(
+        SgCode::LogsClearUnconfirmed,
+        format!("refusing to clear {subject} without confirmation"),
+    )
+    .note("clearing cannot be undone, and there is no terminal to confirm on")
+    .help_cmd("confirm up front", "sysg logs --clear <service> --yes")
+    .help_docs()
+}
+
 /// Builds the SG0020 diagnostic for `--supervisor` combined with a selector.
 pub fn supervisor_with_selector() -> Diagnostic {
     Diagnostic::error(
@@ -1,7 +1,7 @@
 //! Resolving `logs`'s many flags into one exhaustive mode plan.
 //!
-//! `logs` is really five commands wearing one name — show, follow, print-path,
-//! purge, and prune. The flags that pick a mode are mutually exclusive, so the
+//! `logs` is really six commands wearing one name — show, follow, print-path,
+//! purge, clear, and prune. The flags that pick a mode are mutually exclusive, so the
 //! honest model is an enum resolved once, up front:
 //!
 //! - [`LogsPlan`] — which mode this invocation is, with its resolved selector.
@@ -35,6 +35,11 @@ pub enum LogsPlan {
         /// The resolved selector.
         target: Target,
     },
+    /// Empty a target's active log files in place, keeping rotated backups.
+    Clear {
+        /// The resolved selector.
+        target: Target,
+    },
     /// Trim rotated log backups against a size and/or age bound.
     Prune {
         /// Cap total rotated-backup size, e.g. "500MB".
@@ -55,6 +60,8 @@ pub struct Modes {
     pub purge: bool,
     /// `--prune`: trim rotated backups.
     pub prune: bool,
+    /// `--clear`/`--clear-all`: empty active log files in place.
+    pub clear: bool,
     /// `--follow`: stream rather than snapshot (only meaningful for show).
     pub follow: bool,
     /// `--supervisor`: show the supervisor's own log.
@@ -103,6 +110,7 @@ pub fn resolve_plan(
             || modes.path
             || modes.purge
             || modes.prune
+            || modes.clear
             || modes.follow)
     {
         return Err(LogsPlanError::SupervisorWithSelector);
@@ -122,6 +130,9 @@ pub fn resolve_plan(
     if modes.prune {
         set.push("--prune");
     }
+    if modes.clear {
+        set.push("--clear");
+    }
     if set.len() > 1 {
         return Err(LogsPlanError::ConflictingModes { modes: set });
     }
@@ -147,6 +158,9 @@ pub fn resolve_plan(
     if modes.purge {
         return Ok(LogsPlan::Purge { target });
     }
+    if modes.clear {
+        return Ok(LogsPlan::Clear { target });
+    }
     if matches!(target, Target::Everything) {
         return Err(LogsPlanError::TargetRequired);
     }
@@ -165,6 +179,7 @@ mod tests {
             path,
             purge,
             prune,
+            clear: false,
             follow,
             supervisor: false,
         }
@@ -285,6 +300,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn clear_resolves_with_selector_and_rejects_purge() {
+        let clear = Modes {
+            clear: true,
+            ..modes(false, false, false, false)
+        };
+        assert_eq!(
+            resolve_plan(clear, Some("web"), None, None, None).unwrap(),
+            LogsPlan::Clear {
+                target: Target::Service {
+                    service: "web".into(),
+                    project: None
+                }
+            }
+        );
+        assert_eq!(
+            resolve_plan(clear, None, None, None, None).unwrap(),
+            LogsPlan::Clear {
+                target: Target::Everything
+            }
+        );
+        let both = Modes {
+            purge: true,
+            ..clear
+        };
+        assert_eq!(
+            resolve_plan(both, Some("web"), None, None, None),
+            Err(LogsPlanError::ConflictingModes {
+                modes: vec!["--purge", "--clear"]
+            })
+        );
+    }
+
     #[test]
     fn purge_resolves_with_selector() {
         assert_eq!(
@@ -1,16 +1,17 @@
 #![allow(missing_docs)]
 use std::{
-    collections::{HashMap, VecDeque},
+    collections::{HashMap, HashSet, VecDeque},
     fs,
     io::{self, Write},
     mem,
     path::PathBuf,
     sync::{
-        Arc, Mutex, RwLock,
+        Arc, LockResult, Mutex, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard,
+        TryLockResult,
         atomic::{AtomicBool, Ordering},
     },
     thread,
-    time::{Duration, SystemTime},
+    time::{Duration, Instant, SystemTime},
 };
 
 use chrono::{DateTime, Duration as ChronoDuration, Utc};
@@ -22,12 +23,25 @@ use tracing::error;
 use crate::{
     config::Config,
     constants::PROCESS_CHECK_INTERVAL,
-    daemon::{PidFile, ServiceStateFile},
+    daemon::{Daemon, PidFile, ServiceStateFile},
 };
 
 const DEFAULT_RETENTION_MINUTES: u64 = 720;
 const DEFAULT_SAMPLE_INTERVAL_SECS: u64 = 1;
 const DEFAULT_MAX_MEMORY_BYTES: usize = 10 * 1024 * 1024;
+/// How long RSS must stay above `restart_if_rss_exceeds` when `rss_window` is unset.
+const DEFAULT_RSS_WINDOW: Duration = Duration::from_secs(60);
+/// Bytes one buffered sample occupies inside a unit's `VecDeque`.
+const SAMPLE_SLOT_BYTES: usize = mem::size_of::<MetricSample>();
+/// `VecDeque` doubles its buffer when full, so each live sample is charged for
+/// the spare slot it may be carrying alongside it.
+const SAMPLE_CHARGE_BYTES: usize = SAMPLE_SLOT_BYTES * 2;
+/// Smallest buffer `VecDeque` allocates once a unit holds any samples.
+const MIN_BUFFER_SLOTS: usize = 4;
+/// Bytes of one `HashMap` bucket: the key/value pair plus its control byte.
+const UNIT_BUCKET_BYTES: usize = mem::size_of::<(String, UnitMetrics)>() + 1;
+/// Trailing control bytes `HashMap` allocates past the last bucket.
+const UNIT_TABLE_TRAILER_BYTES: usize = 16;
 
 /// Sample collected for a managed unit at a specific timestamp.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -57,8 +71,29 @@ pub struct MetricsSummary {
     pub average_cpu_percent: f32,
     /// Maximum CPU usage observed.
     pub max_cpu_percent: f32,
+    /// Median CPU usage across all samples.
+    #[serde(default)]
+    pub p50_cpu_percent: f32,
+    /// CPU usage that 95% of samples stay at or below.
+    #[serde(default)]
+    pub p95_cpu_percent: f32,
+    /// CPU usage that 99% of samples stay at or below.
+    #[serde(default)]
+    pub p99_cpu_percent: f32,
     /// Most recent resident set size in bytes.
     pub latest_rss_bytes: u64,
+    /// Cumulative bytes the process has read from disk at the latest sample.
+    #[serde(default)]
+    pub latest_io_read_bytes: u64,
+    /// Cumulative bytes the process has written to disk at the latest sample.
+    #[serde(default)]
+    pub latest_io_write_bytes: u64,
+    /// Average disk read throughput across the sampled window, in bytes/sec.
+    #[serde(default)]
+    pub average_io_read_bytes_per_sec: f64,
+    /// Average disk write throughput across the sampled window, in bytes/sec.
+    #[serde(default)]
+    pub average_io_write_bytes_per_sec: f64,
     /// Total number of samples used for statistics.
     pub samples: usize,
 }
@@ -120,8 +155,88 @@ struct UnitMetrics {
     estimated_bytes: usize,
 }
 
+impl UnitMetrics {
+    /// Upper bound on the heap this unit owns: its key, the smallest sample
+    /// buffer, and a growth-slack charge per buffered sample.
+    fn footprint(&self, unit_hash: &str) -> usize {
+        unit_hash.len()
+            + MIN_BUFFER_SLOTS * SAMPLE_SLOT_BYTES
+            + self.samples.len() * SAMPLE_CHARGE_BYTES
+    }
+
+    /// Releases spare buffer capacity once evictions leave it more than half
+    /// empty, keeping the real allocation within [`Self::footprint`].
+    fn reclaim(&mut self) {
+        if self.samples.capacity() > (self.samples.len() * 2).max(MIN_BUFFER_SLOTS) {
+            self.samples.shrink_to(self.samples.len());
+        }
+    }
+}
+
+/// Per-unit summaries keyed by unit hash, as of the last publish.
+pub type UnitSummaries = HashMap<String, MetricsSummary>;
+
+/// Most recently published [`UnitSummaries`].
+///
+/// The lock only guards swapping the `Arc`: readers clone it and let go, and
+/// the store swaps in a fresh map once per sampling pass, so neither side waits
+/// on the other's real work.
+#[derive(Debug, Clone, Default)]
+struct SummaryBoard(Arc<RwLock<Arc<UnitSummaries>>>);
+
+impl SummaryBoard {
+    /// Returns the current summaries.
+    fn load(&self) -> Arc<UnitSummaries> {
+        Arc::clone(&self.0.read().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Replaces the current summaries.
+    fn store(&self, summaries: UnitSummaries) {
+        *self.0.write().unwrap_or_else(PoisonError::into_inner) = Arc::new(summaries);
+    }
+}
+
 /// Thread-safe handle for interacting with metrics storage.
-pub type MetricsHandle = Arc<RwLock<MetricsStore>>;
+///
+/// Sample buffers sit behind a lock shared with the collector. Status reads go
+/// through [`MetricsHandle::summaries`] instead, which never touches that lock.
+#[derive(Debug, Clone)]
+pub struct MetricsHandle {
+    store: Arc<RwLock<MetricsStore>>,
+    summaries: SummaryBoard,
+}
+
+impl MetricsHandle {
+    /// Wraps `store` in a shareable handle.
+    pub fn new(store: MetricsStore) -> Self {
+        let summaries = store.summaries.clone();
+        Self {
+            store: Arc::new(RwLock::new(store)),
+            summaries,
+        }
+    }
+
+    /// Locks the store for reading sample buffers.
+    pub fn read(&self) -> LockResult<RwLockReadGuard<'_, MetricsStore>> {
+        self.store.read()
+    }
+
+    /// Locks the store for reading unless it is held for writing.
+    pub fn try_read(&self) -> TryLockResult<RwLockReadGuard<'_, MetricsStore>> {
+        self.store.try_read()
+    }
+
+    /// Locks the store for recording samples.
+    pub fn write(&self) -> LockResult<RwLockWriteGuard<'_, MetricsStore>> {
+        self.store.write()
+    }
+
+    /// Returns the summaries published by the last sampling pass without
+    /// locking the store.
+    pub fn summaries(&self) -> Arc<UnitSummaries> {
+        self.summaries.load()
+    }
+}
 
 /// In-memory storage for recently collected metrics with bounded memory usage.
 #[derive(Debug)]
@@ -130,6 +245,9 @@ pub struct MetricsStore {
     total_estimated_bytes: usize,
     units: HashMap<String, UnitMetrics>,
     spillover: Option<MetricsSpillover>,
+    summaries: SummaryBoard,
+    /// Units whose samples changed since summaries were last published.
+    stale: HashSet<String>,
 }
 
 impl MetricsStore {
@@ -145,12 +263,18 @@ impl MetricsStore {
             total_estimated_bytes: 0,
             units: HashMap::new(),
             spillover,
+            summaries: SummaryBoard::default(),
+            stale: HashSet::new(),
         })
     }
 
     /// Ensures a unit hash is present in the metrics store.
     pub fn register_unit(&mut self, unit_hash: &str) {
-        self.units.entry(unit_hash.to_string()).or_default();
+        if !self.units.contains_key(unit_hash) {
+            self.units
+                .insert(unit_hash.to_string(), UnitMetrics::default());
+            self.refresh_unit_estimate(unit_hash);
+        }
     }
 
     /// Removes all metrics history for the given unit hash.
@@ -159,6 +283,7 @@ impl MetricsStore {
             self.total_estimated_bytes = self
                 .total_estimated_bytes
                 .saturating_sub(buffer.estimated_bytes);
+            self.stale.insert(unit_hash.to_string());
         }
     }
 
@@ -178,35 +303,27 @@ impl MetricsStore {
             .checked_sub_signed(retention_duration)
             .unwrap_or(DateTime::<Utc>::MIN_UTC);
 
-        let buffer = self.units.entry(unit_hash.to_string()).or_default();
-
-        let sample_estimated_bytes = mem::size_of::<MetricSample>();
-        buffer.samples.push_back(sample.clone());
-        buffer.estimated_bytes = buffer
-            .estimated_bytes
-            .saturating_add(sample_estimated_bytes);
-        self.total_estimated_bytes = self
-            .total_estimated_bytes
-            .saturating_add(sample_estimated_bytes);
+        self.register_unit(unit_hash);
+        let Some(buffer) = self.units.get_mut(unit_hash) else {
+            return Ok(());
+        };
+        buffer.samples.push_back(sample);
 
         while let Some(front) = buffer.samples.front() {
             if front.timestamp >= retention_cutoff {
                 break;
             }
 
-            if let Some(evicted) = buffer.samples.pop_front() {
-                buffer.estimated_bytes = buffer
-                    .estimated_bytes
-                    .saturating_sub(sample_estimated_bytes);
-                self.total_estimated_bytes = self
-                    .total_estimated_bytes
-                    .saturating_sub(sample_estimated_bytes);
-                if let Some(spillover) = self.spillover.as_mut() {
-                    spillover.persist(unit_hash, &evicted)?;
-                }
+            if let Some(evicted) = buffer.samples.pop_front()
+                && let Some(spillover) = self.spillover.as_mut()
+            {
+                spillover.persist(unit_hash, &evicted)?;
             }
         }
+        buffer.reclaim();
 
+        self.stale.insert(unit_hash.to_string());
+        self.refresh_unit_estimate(unit_hash);
         self.enforce_memory_budget()?;
         Ok(())
     }
@@ -221,33 +338,62 @@ impl MetricsStore {
         self.settings.sample_interval
     }
 
+    /// Estimated heap bytes held by the store: the unit table plus every unit's
+    /// key and sample buffer.
+    ///
+    /// This is the figure checked against `max_memory_bytes`. It is an upper
+    /// bound on the real allocation rather than a count of live samples, so
+    /// container growth cannot push the supervisor past the configured cap.
+    pub fn memory_usage(&self) -> usize {
+        self.table_bytes()
+            .saturating_add(self.total_estimated_bytes)
+    }
+
+    /// Bytes allocated by the unit table itself, derived from its capacity.
+    fn table_bytes(&self) -> usize {
+        let capacity = self.units.capacity();
+        if capacity == 0 {
+            return 0;
+        }
+        let buckets = (capacity * 8).div_ceil(7).next_power_of_two();
+        buckets * UNIT_BUCKET_BYTES + UNIT_TABLE_TRAILER_BYTES
+    }
+
+    /// Recomputes one unit's footprint and folds the change into the total.
+    fn refresh_unit_estimate(&mut self, unit_hash: &str) {
+        if let Some(buffer) = self.units.get_mut(unit_hash) {
+            let estimated_bytes = buffer.footprint(unit_hash);
+            self.total_estimated_bytes = self
+                .total_estimated_bytes
+                .saturating_sub(buffer.estimated_bytes)
+                .saturating_add(estimated_bytes);
+            buffer.estimated_bytes = estimated_bytes;
+        }
+    }
+
     /// Handles enforce memory budget.
     fn enforce_memory_budget(&mut self) -> Result<(), MetricsError> {
-        if self.total_estimated_bytes <= self.settings.max_memory_bytes {
+        if self.memory_usage() <= self.settings.max_memory_bytes {
             return Ok(());
         }
 
         let mut unit_keys: Vec<String> = self.units.keys().cloned().collect();
         unit_keys.sort();
-        while self.total_estimated_bytes > self.settings.max_memory_bytes {
+        while self.memory_usage() > self.settings.max_memory_bytes {
             let mut removed_any = false;
             for key in unit_keys.iter() {
                 if let Some(buffer) = self.units.get_mut(key)
                     && let Some(sample) = buffer.samples.pop_front()
                 {
-                    let sample_estimated_bytes = mem::size_of::<MetricSample>();
-                    buffer.estimated_bytes = buffer
-                        .estimated_bytes
-                        .saturating_sub(sample_estimated_bytes);
-                    self.total_estimated_bytes = self
-                        .total_estimated_bytes
-                        .saturating_sub(sample_estimated_bytes);
+                    buffer.reclaim();
                     if let Some(spillover) = self.spillover.as_mut() {
                         spillover.persist(key, &sample)?;
                     }
+                    self.stale.insert(key.clone());
+                    self.refresh_unit_estimate(key);
                     removed_any = true;
                 }
-                if self.total_estimated_bytes <= self.settings.max_memory_bytes {
+                if self.memory_usage() <= self.settings.max_memory_bytes {
                     break;
                 }
             }
@@ -294,21 +440,77 @@ impl MetricsStore {
         }
 
         let samples = buffer.samples.len();
+        let first = buffer.samples.front()?;
         let latest = buffer.samples.back()?;
         let sum_cpu: f32 = buffer.samples.iter().map(|sample| sample.cpu_percent).sum();
         let max_cpu = buffer
             .samples
             .iter()
             .fold(0.0_f32, |acc, sample| acc.max(sample.cpu_percent));
+        let mut sorted_cpu: Vec<f32> = buffer
+            .samples
+            .iter()
+            .map(|sample| sample.cpu_percent)
+            .collect();
+        sorted_cpu.sort_by(f32::total_cmp);
 
         Some(MetricsSummary {
             latest_cpu_percent: latest.cpu_percent,
             average_cpu_percent: sum_cpu / samples as f32,
             max_cpu_percent: max_cpu,
+            p50_cpu_percent: percentile(&sorted_cpu, 50),
+            p95_cpu_percent: percentile(&sorted_cpu, 95),
+            p99_cpu_percent: percentile(&sorted_cpu, 99),
             latest_rss_bytes: latest.rss_bytes,
+            latest_io_read_bytes: latest.io_read_bytes,
+            latest_io_write_bytes: latest.io_write_bytes,
+            average_io_read_bytes_per_sec: average_rate(first, latest, |sample| {
+                sample.io_read_bytes
+            }),
+            average_io_write_bytes_per_sec: average_rate(first, latest, |sample| {
+                sample.io_write_bytes
+            }),
             samples,
         })
     }
+
+    /// Recomputes the summaries of units whose samples changed and publishes
+    /// them to [`MetricsHandle::summaries`] readers.
+    pub fn publish_summaries(&mut self) {
+        if self.stale.is_empty() {
+            return;
+        }
+        let mut summaries = UnitSummaries::clone(&self.summaries.load());
+        for unit_hash in mem::take(&mut self.stale) {
+            match self.summarize_unit(&unit_hash) {
+                Some(summary) => summaries.insert(unit_hash, summary),
+                None => summaries.remove(&unit_hash),
+            };
+        }
+        self.summaries.store(summaries);
+    }
+}
+
+/// Nearest-rank percentile of ascending `sorted` values: the smallest value
+/// that at least `rank` percent of them do not exceed.
+fn percentile(sorted: &[f32], rank: usize) -> f32 {
+    let index = (sorted.len() * rank).div_ceil(100).saturating_sub(1);
+    sorted.get(index).copied().unwrap_or_default()
+}
+
+/// Average per-second growth of a cumulative counter between two samples. A
+/// counter that went backwards (the process was replaced) or a window with no
+/// elapsed time averages to zero.
+fn average_rate(
+    first: &MetricSample,
+    last: &MetricSample,
+    counter: impl Fn(&MetricSample) -> u64,
+) -> f64 {
+    let elapsed = (last.timestamp - first.timestamp).num_milliseconds() as f64 / 1000.0;
+    if elapsed <= 0.0 {
+        return 0.0;
+    }
+    counter(last).saturating_sub(counter(first)) as f64 / elapsed
 }
 
 /// Persists evicted metrics samples to disk for later inspection.
@@ -480,7 +682,7 @@ struct SpilloverRecord<'a> {
 
 /// Creates a new shared, thread-safe metrics store with the given settings.
 pub fn shared_store(settings: MetricsSettings) -> Result<MetricsHandle, MetricsError> {
-    Ok(Arc::new(RwLock::new(MetricsStore::new(settings)?)))
+    Ok(MetricsHandle::new(MetricsStore::new(settings)?))
 }
 
 /// Unit metadata used by the collector to emit samples.
@@ -508,20 +710,21 @@ pub struct MetricsCollector {
 }
 
 impl MetricsCollector {
-    #[allow(clippy::too_many_arguments)]
     /// Starts the metrics collector and returns its shutdown handle.
     ///
+    /// The collector also enforces `restart_if_rss_exceeds`, asking the daemon to
+    /// restart a service whose sampled RSS stays above its limit.
+    ///
     /// Thread creation errors are returned to the supervisor so it can fail the
     /// worker startup transaction instead of running without metric collection.
-    pub fn spawn(
-        store: MetricsHandle,
-        config: Arc<Config>,
-        pid_file: Arc<Mutex<PidFile>>,
-        service_state: Arc<Mutex<ServiceStateFile>>,
-    ) -> io::Result<Self> {
+    pub fn spawn(store: MetricsHandle, daemon: Daemon) -> io::Result<Self> {
+        let config = daemon.config();
+        let pid_file = daemon.pid_file_handle();
+        let service_state = daemon.service_state_handle();
+        let rss_limits = rss_limits(config.as_ref());
         let stop = Arc::new(AtomicBool::new(false));
         let stop_clone = Arc::clone(&stop);
-        let store_clone = Arc::clone(&store);
+        let store_clone = store.clone();
 
         let interval = {
             store
@@ -534,6 +737,7 @@ impl MetricsCollector {
             .name("sysg-metrics".to_string())
             .spawn(move || {
                 let mut system = System::new();
+                let mut rss_guard = RssGuard::default();
 
                 while !stop_clone.load(Ordering::SeqCst) {
                     let targets =
@@ -561,6 +765,30 @@ impl MetricsCollector {
                                 error!("failed to record metrics sample: {err}");
                             }
                         }
+                        guard.publish_summaries();
+                    }
+
+                    if !rss_limits.is_empty() {
+                        let now = Utc::now();
+                        let mut exceeded = Vec::new();
+                        let summaries = store_clone.summaries();
+                        for limit in &rss_limits {
+                            let rss = summaries
+                                .get(&limit.hash)
+                                .map_or(0, |summary| summary.latest_rss_bytes);
+                            if rss_guard.observe(
+                                &limit.hash,
+                                rss,
+                                limit.bytes,
+                                limit.window,
+                                now,
+                            ) {
+                                exceeded.push((limit, rss));
+                            }
+                        }
+                        for (limit, rss) in exceeded {
+                            daemon.restart_for_memory(&limit.service, rss, limit.bytes);
+                        }
                     }
 
                     let mut slept = Duration::ZERO;
@@ -606,6 +834,72 @@ impl Drop for MetricsCollector {
 }
 
 /// Gathers unit targets.
+/// A service's `restart_if_rss_exceeds` threshold, resolved once per collector.
+struct RssLimit {
+    service: String,
+    hash: String,
+    bytes: u64,
+    window: Duration,
+}
+
+/// Resolves the RSS restart thresholds configured across `config`'s services.
+fn rss_limits(config: &Config) -> Vec<RssLimit> {
+    let mut limits = Vec::new();
+    for (name, service) in &config.services {
+        let Some(bytes) = service.rss_restart_limit() else {
+            continue;
+        };
+        // `check_rss_limits` rejected an unparsable window at load.
+        let window = service
+            .rss_window_duration()
+            .ok()
+            .flatten()
+            .unwrap_or(DEFAULT_RSS_WINDOW);
+        limits.push(RssLimit {
+            service: name.clone(),
+            hash: config.state_key(name),
+            bytes,
+            window,
+        });
+    }
+    limits
+}
+
+/// Tracks how long each unit's RSS has stayed above its restart threshold.
+#[derive(Debug, Default)]
+pub struct RssGuard {
+    over_since: HashMap<String, DateTime<Utc>>,
+}
+
+impl RssGuard {
+    /// Records one RSS reading and reports whether the unit should be restarted.
+    ///
+    /// Returns `true` once every reading for `window` has been above `limit`, then
+    /// starts a fresh window so a restart that does not help is retried only after
+    /// another full window. A reading at or below the limit resets the unit.
+    pub fn observe(
+        &mut self,
+        unit: &str,
+        rss_bytes: u64,
+        limit: u64,
+        window: Duration,
+        at: DateTime<Utc>,
+    ) -> bool {
+        if rss_bytes <= limit {
+            self.over_since.remove(unit);
+            return false;
+        }
+
+        let since = *self.over_since.entry(unit.to_string()).or_insert(at);
+        let elapsed = at.signed_duration_since(since).to_std().unwrap_or_default();
+        if elapsed < window {
+            return false;
+        }
+        self.over_since.remove(unit);
+        true
+    }
+}
+
 fn gather_unit_targets(
     config: &Config,
     pid_file: &Arc<Mutex<PidFile>>,
@@ -647,8 +941,69 @@ fn gather_unit_targets(
     targets
 }
 
+/// Most samples [`RunSampler`] keeps for one run before thinning them out.
+const RUN_SAMPLE_CAP: usize = 120;
+
+/// Samples one short-lived process at a finer cadence than the collector, so a
+/// cron run that exits within a collector interval still has a resource series.
+///
+/// Samples are taken no faster than sysinfo can compute CPU usage. When the cap
+/// is reached every other sample is dropped and the interval doubles, so a long
+/// run keeps an evenly spaced series of bounded size.
+pub struct RunSampler {
+    system: System,
+    pid: u32,
+    interval: Duration,
+    last: Option<Instant>,
+    samples: Vec<MetricSample>,
+}
+
+impl RunSampler {
+    /// Creates a sampler for `pid`; nothing is read until [`RunSampler::poll`].
+    pub fn new(pid: u32) -> Self {
+        Self {
+            system: System::new(),
+            pid,
+            interval: sysinfo::MINIMUM_CPU_UPDATE_INTERVAL,
+            last: None,
+            samples: Vec::new(),
+        }
+    }
+
+    /// Takes a sample if the sampling interval has elapsed and the process still
+    /// exists. Cheap to call more often than the interval.
+    pub fn poll(&mut self) {
+        if self.last.is_some_and(|last| last.elapsed() < self.interval) {
+            return;
+        }
+        self.last = Some(Instant::now());
+        let Some(sample) = read_process(&mut self.system, self.pid) else {
+            return;
+        };
+        if self.samples.len() >= RUN_SAMPLE_CAP {
+            let mut index = 0;
+            self.samples.retain(|_| {
+                index += 1;
+                index % 2 == 1
+            });
+            self.interval *= 2;
+        }
+        self.samples.push(sample);
+    }
+
+    /// The samples collected so far, oldest first.
+    pub fn into_samples(self) -> Vec<MetricSample> {
+        self.samples
+    }
+}
+
 /// Samples process.
 fn sample_process(system: &mut System, pid: u32) -> MetricSample {
+    read_process(system, pid).unwrap_or_else(missing_process_sample)
+}
+
+/// Reads one sample for `pid`, or `None` when the process no longer exists.
+fn read_process(system: &mut System, pid: u32) -> Option<MetricSample> {
     let pid_sys = Pid::from_u32(pid);
     let refresh_kind = ProcessRefreshKind::everything();
     let processes = [pid_sys];
@@ -658,7 +1013,9 @@ fn sample_process(system: &mut System, pid: u32) -> MetricSample {
         refresh_kind,
     );
 
-    if let Some(process) = system.process(pid_sys) {
+    system.process(pid_sys).map(|process| {
+        let disk = process.disk_usage();
+        let (net_rx_bytes, net_tx_bytes) = namespace_net_counters(pid).unwrap_or((0, 0));
         MetricSample {
             timestamp: Utc::now(),
             cpu_percent: process.cpu_usage(),
@@ -666,14 +1023,61 @@ fn sample_process(system: &mut System, pid: u32) -> MetricSample {
             // scale it. Multiplying by 1024 inflated RSS 1024x — a 66MB API read
             // as 63GB.
             rss_bytes: process.memory(),
-            io_read_bytes: 0,
-            io_write_bytes: 0,
-            net_rx_bytes: 0,
-            net_tx_bytes: 0,
+            io_read_bytes: disk.total_read_bytes,
+            io_write_bytes: disk.total_written_bytes,
+            net_rx_bytes,
+            net_tx_bytes,
+        }
+    })
+}
+
+/// Reads cumulative network counters for a process that owns its network namespace.
+///
+/// The kernel has no per-process network accounting, so counters are only
+/// attributed when the service runs with `isolation.network` and every interface
+/// it can see belongs to it. `/proc/<pid>/net/dev` reports the interfaces of the
+/// process's own namespace, which avoids entering it with `setns`. Processes that
+/// share the supervisor's namespace report `None` rather than host-wide traffic.
+#[cfg(target_os = "linux")]
+fn namespace_net_counters(pid: u32) -> Option<(u64, u64)> {
+    let own = fs::read_link("/proc/self/ns/net").ok()?;
+    let target = fs::read_link(format!("/proc/{pid}/ns/net")).ok()?;
+    if own == target {
+        return None;
+    }
+    let contents = fs::read_to_string(format!("/proc/{pid}/net/dev")).ok()?;
+    Some(parse_net_dev(&contents))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn namespace_net_counters(_pid: u32) -> Option<(u64, u64)> {
+    None
+}
+
+/// Sums received and transmitted bytes across the non-loopback interfaces of a
+/// `/proc/net/dev` table.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_net_dev(contents: &str) -> (u64, u64) {
+    let mut rx = 0u64;
+    let mut tx = 0u64;
+    for line in contents.lines().skip(2) {
+        let Some((iface, counters)) = line.split_once(':') else {
+            continue;
+        };
+        if iface.trim() == "lo" {
+            continue;
+        }
+        let fields: Vec<u64> = counters
+            .split_whitespace()
+            .map(|field| field.parse().unwrap_or(0))
+            .collect();
+        // Eight receive columns precede the transmit columns.
+        if let (Some(received), Some(sent)) = (fields.first(), fields.get(8)) {
+            rx = rx.saturating_add(*received);
+            tx = tx.saturating_add(*sent);
         }
-    } else {
-        missing_process_sample()
     }
+    (rx, tx)
 }
 
 /// Builds the placeholder process sample.
@@ -688,3 +1092,209 @@ fn missing_process_sample() -> MetricSample {
         net_tx_bytes: 0,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_sampler_thins_its_series_at_the_cap() {
+        let mut sampler = RunSampler::new(std::process::id());
+        sampler.interval = Duration::from_nanos(1);
+        for _ in 0..RUN_SAMPLE_CAP + 10 {
+            sampler.poll();
+        }
+        let interval = sampler.interval;
+        let samples = sampler.into_samples();
+        assert!(samples.len() <= RUN_SAMPLE_CAP);
+        assert!(samples.len() > RUN_SAMPLE_CAP / 2);
+        assert!(samples.iter().all(|sample| sample.rss_bytes > 0));
+        assert_eq!(interval, Duration::from_nanos(2));
+
+        let mut gone = RunSampler::new(u32::MAX);
+        gone.poll();
+        assert!(gone.into_samples().is_empty());
+    }
+
+    fn rss_sample(timestamp: DateTime<Utc>, rss_bytes: u64) -> MetricSample {
+        MetricSample {
+            timestamp,
+            cpu_percent: 0.0,
+            rss_bytes,
+            io_read_bytes: 0,
+            io_write_bytes: 0,
+            net_rx_bytes: 0,
+            net_tx_bytes: 0,
+        }
+    }
+
+    #[test]
+    fn net_dev_totals_skip_loopback_and_sum_other_interfaces() {
+        let table = "\
+Inter-|   Receive                                                |  Transmit
+ face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed
+    lo:    5000      50    0    0    0     0          0         0     5000      50    0    0    0     0       0          0
+  eth0:    1200      10    0    0    0     0          0         0      800       8    0    0    0     0       0          0
+ veth1:     300       3    0    0    0     0          0         0      200       2    0    0    0     0       0          0
+";
+        assert_eq!(parse_net_dev(table), (1500, 1000));
+        assert_eq!(parse_net_dev(""), (0, 0));
+    }
+
+    #[test]
+    fn summary_reports_latest_io_totals_and_average_throughput() {
+        let mut store = MetricsStore::new(MetricsSettings::default()).unwrap();
+        store.register_unit("writer");
+        let started = Utc::now();
+        for (offset, read, written) in
+            [(0, 1_000, 0), (5, 6_000, 10_000), (10, 11_000, 40_000)]
+        {
+            let mut sample = rss_sample(started + ChronoDuration::seconds(offset), 0);
+            sample.io_read_bytes = read;
+            sample.io_write_bytes = written;
+            store.record_sample("writer", sample).unwrap();
+        }
+
+        let summary = store.summarize_unit("writer").unwrap();
+        assert_eq!(summary.latest_io_read_bytes, 11_000);
+        assert_eq!(summary.latest_io_write_bytes, 40_000);
+        assert_eq!(summary.average_io_read_bytes_per_sec, 1_000.0);
+        assert_eq!(summary.average_io_write_bytes_per_sec, 4_000.0);
+    }
+
+    #[test]
+    fn rss_guard_restarts_only_after_a_sustained_window() {
+        let mut store = MetricsStore::new(MetricsSettings::default()).unwrap();
+        store.register_unit("leaky");
+        let mut guard = RssGuard::default();
+        let limit = 512 * 1024 * 1024;
+        let window = Duration::from_secs(30);
+        let started = Utc::now();
+
+        // A brief spike that drops back under the limit never triggers.
+        let sequence = [
+            (0, limit + 1),
+            (10, limit * 2),
+            (20, limit),
+            (25, limit + 1),
+            (45, limit + 1),
+            (55, limit + 1),
+            (65, limit + 1),
+        ];
+        let mut triggered = Vec::new();
+        for (offset, rss) in sequence {
+            let at = started + ChronoDuration::seconds(offset);
+            store.record_sample("leaky", rss_sample(at, rss)).unwrap();
+            let latest = store.summarize_unit("leaky").unwrap().latest_rss_bytes;
+            if guard.observe("leaky", latest, limit, window, at) {
+                triggered.push(offset);
+            }
+        }
+
+        assert_eq!(triggered, vec![55]);
+    }
+
+    #[test]
+    fn summary_reports_cpu_percentiles_by_nearest_rank() {
+        let mut store = MetricsStore::new(MetricsSettings::default()).unwrap();
+        let started = Utc::now();
+        // 1% through 100% in shuffled order, so the percentiles must come from
+        // a sorted copy rather than the buffer's arrival order.
+        for (offset, cpu) in (1..=100).map(|n| (n * 37) % 100 + 1).enumerate() {
+            let mut sample =
+                rss_sample(started + ChronoDuration::seconds(offset as i64), 0);
+            sample.cpu_percent = cpu as f32;
+            store.record_sample("busy", sample).unwrap();
+        }
+
+        let summary = store.summarize_unit("busy").unwrap();
+        assert_eq!(summary.p50_cpu_percent, 50.0);
+        assert_eq!(summary.p95_cpu_percent, 95.0);
+        assert_eq!(summary.p99_cpu_percent, 99.0);
+        assert_eq!(summary.max_cpu_percent, 100.0);
+
+        assert_eq!(percentile(&[7.0], 99), 7.0);
+        assert_eq!(percentile(&[], 50), 0.0);
+    }
+
+    #[test]
+    fn summary_readers_and_the_collector_never_wait_on_each_other() {
+        const UNITS: usize = 8;
+        const PASSES: usize = 500;
+        const READERS: usize = 4;
+
+        let handle = shared_store(MetricsSettings::default()).unwrap();
+        let done = Arc::new(AtomicBool::new(false));
+
+        // A reader gets the published summaries while a pass holds the store.
+        {
+            let _pass = handle.write().unwrap();
+            let reader = handle.clone();
+            let (tx, rx) = std::sync::mpsc::channel();
+            thread::spawn(move || tx.send(reader.summaries().len()).unwrap());
+            assert_eq!(rx.recv_timeout(Duration::from_secs(5)), Ok(0));
+        }
+
+        let readers: Vec<_> = (0..READERS)
+            .map(|_| {
+                let handle = handle.clone();
+                let done = Arc::clone(&done);
+                thread::spawn(move || {
+                    let mut last_seen = [0; UNITS];
+                    let mut reads = 0_usize;
+                    while !done.load(Ordering::SeqCst) {
+                        let summaries = handle.summaries();
+                        for (unit, last) in last_seen.iter_mut().enumerate() {
+                            if let Some(summary) = summaries.get(&format!("unit-{unit}"))
+                            {
+                                assert!(
+                                    summary.samples >= *last,
+                                    "summaries went backwards"
+                                );
+                                *last = summary.samples;
+                            }
+                        }
+                        reads += 1;
+                    }
+                    reads
+                })
+            })
+            .collect();
+
+        let collector = {
+            let handle = handle.clone();
+            thread::spawn(move || {
+                let started = Utc::now();
+                for pass in 0..PASSES {
+                    let mut store = handle.write().unwrap();
+                    for unit in 0..UNITS {
+                        let timestamp =
+                            started + ChronoDuration::milliseconds(pass as i64);
+                        store
+                            .record_sample(
+                                &format!("unit-{unit}"),
+                                rss_sample(timestamp, 1),
+                            )
+                            .unwrap();
+                    }
+                    store.publish_summaries();
+                }
+            })
+        };
+
+        collector.join().expect("collector finished");
+        done.store(true, Ordering::SeqCst);
+        for reader in readers {
+            assert!(reader.join().expect("reader finished") > 0);
+        }
+
+        let summaries = handle.summaries();
+        assert_eq!(summaries.len(), UNITS);
+        assert!(summaries.values().all(|summary| summary.samples == PASSES));
+
+        let mut store = handle.write().unwrap();
+        store.remove_unit("unit-0");
+        store.publish_summaries();
+        assert!(!handle.summaries().contains_key("unit-0"));
+    }
+}
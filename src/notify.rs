@@ -0,0 +1,213 @@
+//! Readiness and watchdog notifications for a parent service manager, so a
+//! foreground `sysg start` can run under systemd as a `Type=notify` unit.
+//!
+//! This speaks the `sd_notify` datagram protocol directly: each state change is
+//! a newline-separated `KEY=VALUE` message sent to the socket named by
+//! `NOTIFY_SOCKET`. When the variable is unset there is no manager listening and
+//! every call is a no-op.
+use std::{
+    ffi::OsString,
+    io,
+    os::unix::net::{SocketAddr, UnixDatagram},
+    time::{Duration, Instant},
+};
+
+use tracing::{debug, warn};
+
+/// Variables a service manager sets for its notify-aware main process. They
+/// describe that one process, so they are left out of the re-exec'd
+/// supervisor's environment and never leak into managed services.
+pub const NOTIFY_ENV_VARS: &[&str] = &["NOTIFY_SOCKET", "WATCHDOG_USEC", "WATCHDOG_PID"];
+
+/// Connection to the parent service manager's notification socket.
+#[derive(Debug)]
+pub struct Notifier {
+    socket: UnixDatagram,
+    addr: SocketAddr,
+    watchdog: Option<Duration>,
+    next_heartbeat: Option<Instant>,
+}
+
+impl Notifier {
+    /// Builds a notifier from `NOTIFY_SOCKET`, `WATCHDOG_USEC` and
+    /// `WATCHDOG_PID`. Returns `None` when no manager asked to be notified or
+    /// the socket address is not one this platform can reach.
+    pub fn from_env() -> Option<Self> {
+        Self::from_vars(
+            std::env::var_os("NOTIFY_SOCKET"),
+            std::env::var("WATCHDOG_USEC").ok().as_deref(),
+            std::env::var("WATCHDOG_PID").ok().as_deref(),
+            std::process::id(),
+        )
+    }
+
+    /// Builds a notifier from explicit variable values; `pid` is the process
+    /// the watchdog must belong to.
+    fn from_vars(
+        socket: Option<OsString>,
+        watchdog_usec: Option<&str>,
+        watchdog_pid: Option<&str>,
+        pid: u32,
+    ) -> Option<Self> {
+        let socket_path = socket.filter(|value| !value.is_empty())?;
+        let addr = match notify_addr(&socket_path) {
+            Ok(addr) => addr,
+            Err(err) => {
+                warn!("Ignoring NOTIFY_SOCKET {socket_path:?}: {err}");
+                return None;
+            }
+        };
+        let socket = match UnixDatagram::unbound() {
+            Ok(socket) => socket,
+            Err(err) => {
+                warn!("Unable to open a notification socket: {err}");
+                return None;
+            }
+        };
+        let watchdog = watchdog_interval(watchdog_usec, watchdog_pid, pid);
+        Some(Self {
+            socket,
+            addr,
+            watchdog,
+            next_heartbeat: None,
+        })
+    }
+
+    /// Interval the manager expects heartbeats within, if it enabled a watchdog.
+    pub fn watchdog(&self) -> Option<Duration> {
+        self.watchdog
+    }
+
+    /// Tells the manager that startup finished and starts the watchdog clock.
+    pub fn ready(&mut self) -> io::Result<()> {
+        self.send("READY=1")?;
+        self.next_heartbeat = self.watchdog.map(|interval| Instant::now() + interval / 2);
+        Ok(())
+    }
+
+    /// Sends `WATCHDOG=1` when half the watchdog interval has passed since the
+    /// last heartbeat. Call it from a loop that has just confirmed the
+    /// supervisor is healthy; a wedged loop then lets the watchdog fire.
+    pub fn heartbeat(&mut self) -> io::Result<()> {
+        let (Some(interval), Some(due)) = (self.watchdog, self.next_heartbeat) else {
+            return Ok(());
+        };
+        let now = Instant::now();
+        if now < due {
+            return Ok(());
+        }
+        self.send("WATCHDOG=1")?;
+        self.next_heartbeat = Some(now + interval / 2);
+        Ok(())
+    }
+
+    /// Tells the manager that shutdown has begun.
+    pub fn stopping(&self) -> io::Result<()> {
+        self.send("STOPPING=1")
+    }
+
+    /// Sends one notification datagram.
+    fn send(&self, state: &str) -> io::Result<()> {
+        debug!("Notifying service manager: {state}");
+        self.socket.send_to_addr(state.as_bytes(), &self.addr)?;
+        Ok(())
+    }
+}
+
+/// Resolves `NOTIFY_SOCKET` to a socket address. A leading `@` names a Linux
+/// abstract-namespace socket; anything else must be an absolute path.
+fn notify_addr(value: &OsString) -> io::Result<SocketAddr> {
+    let raw = value.as_encoded_bytes();
+    if let Some(name) = raw.strip_prefix(b"@") {
+        #[cfg(target_os = "linux")]
+        {
+            use std::os::linux::net::SocketAddrExt;
+            return SocketAddr::from_abstract_name(name);
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            let _ = name;
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "abstract notification sockets require Linux",
+            ));
+        }
+    }
+    if raw.first() != Some(&b'/') {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "expected an absolute socket path or an @abstract name",
+        ));
+    }
+    SocketAddr::from_pathname(value)
+}
+
+/// Parses the watchdog interval. `WATCHDOG_PID`, when set, must name `pid`;
+/// otherwise the watchdog belongs to a different process and is ignored.
+fn watchdog_interval(
+    usec: Option<&str>,
+    owner: Option<&str>,
+    pid: u32,
+) -> Option<Duration> {
+    if let Some(owner) = owner
+        && owner.trim().parse::<u32>().ok() != Some(pid)
+    {
+        return None;
+    }
+    let usec = usec?.trim().parse::<u64>().ok().filter(|usec| *usec > 0)?;
+    Some(Duration::from_micros(usec))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn watchdog_interval_requires_a_matching_owner() {
+        assert_eq!(
+            watchdog_interval(Some("30000000"), None, 42),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            watchdog_interval(Some("30000000"), Some("42"), 42),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(watchdog_interval(Some("30000000"), Some("7"), 42), None);
+        assert_eq!(watchdog_interval(Some("0"), None, 42), None);
+        assert_eq!(watchdog_interval(None, None, 42), None);
+    }
+
+    #[test]
+    fn ready_and_heartbeats_reach_the_notify_socket() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notify.sock");
+        let listener = UnixDatagram::bind(&path).unwrap();
+        listener
+            .set_read_timeout(Some(Duration::from_secs(2)))
+            .unwrap();
+
+        assert!(Notifier::from_vars(None, None, None, 1).is_none());
+        assert!(
+            Notifier::from_vars(Some("relative.sock".into()), None, None, 1).is_none()
+        );
+
+        let mut notifier = Notifier::from_vars(
+            Some(path.clone().into_os_string()),
+            Some("2000"),
+            None,
+            1,
+        )
+        .unwrap();
+        assert_eq!(notifier.watchdog(), Some(Duration::from_millis(2)));
+
+        let mut buf = [0u8; 64];
+        notifier.ready().unwrap();
+        let len = listener.recv(&mut buf).unwrap();
+        assert_eq!(&buf[..len], b"READY=1");
+
+        std::thread::sleep(Duration::from_millis(5));
+        notifier.heartbeat().unwrap();
+        let len = listener.recv(&mut buf).unwrap();
+        assert_eq!(&buf[..len], b"WATCHDOG=1");
+    }
+}
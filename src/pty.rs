@@ -0,0 +1,76 @@
+//! Pseudo-terminals for services that only line-buffer on a terminal.
+//!
+//! C stdio and most language runtimes fully buffer stdout when it is a pipe, so
+//! a service logging through one shows nothing until a buffer fills or it
+//! exits. A `pty` service gets the slave side of a pseudo-terminal as stdout and
+//! stderr instead, and the supervisor reads the master side into its log. Once
+//! every copy of the slave is closed, reads from the master fail with `EIO`,
+//! which the log readers take as end-of-file.
+
+use std::{
+    fs::File,
+    io,
+    os::fd::{AsRawFd, OwnedFd, RawFd},
+};
+
+/// Both ends of a freshly opened pseudo-terminal.
+pub(crate) struct Pty {
+    /// The end the supervisor reads the service's output from.
+    pub(crate) master: File,
+    /// The end the service writes to, handed over as its stdout and stderr.
+    pub(crate) slave: OwnedFd,
+}
+
+/// Opens a pseudo-terminal whose descriptors are close-on-exec, so only the
+/// service that is handed the slave inherits it.
+pub(crate) fn open() -> io::Result<Pty> {
+    let pty = nix::pty::openpty(None, None).map_err(io::Error::from)?;
+    set_close_on_exec(pty.master.as_raw_fd())?;
+    set_close_on_exec(pty.slave.as_raw_fd())?;
+    Ok(Pty {
+        master: File::from(pty.master),
+        slave: pty.slave,
+    })
+}
+
+fn set_close_on_exec(fd: RawFd) -> io::Result<()> {
+    if unsafe { libc::fcntl(fd, libc::F_SETFD, libc::FD_CLOEXEC) } < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::Read,
+        process::{Command, Stdio},
+    };
+
+    use super::*;
+
+    #[test]
+    fn a_child_on_the_slave_sees_a_terminal() {
+        let Pty { mut master, slave } = open().unwrap();
+        let mut command = Command::new("sh");
+        command
+            .args(["-c", "if [ -t 1 ]; then echo tty; else echo pipe; fi"])
+            .stdout(Stdio::from(slave.try_clone().unwrap()))
+            .stderr(Stdio::from(slave));
+        let mut child = command.spawn().unwrap();
+        drop(command);
+        child.wait().unwrap();
+
+        let mut output = Vec::new();
+        let mut buf = [0_u8; 64];
+        loop {
+            match master.read(&mut buf) {
+                Ok(0) => break,
+                Ok(read) => output.extend_from_slice(&buf[..read]),
+                Err(err) if err.raw_os_error() == Some(libc::EIO) => break,
+                Err(err) => panic!("reading the master failed: {err}"),
+            }
+        }
+        assert_eq!(output, b"tty\r\n");
+    }
+}
@@ -22,9 +22,13 @@ use tracing::{info, warn};
 
 use crate::config::{Config, ServiceConfig};
 
-/// Extracts the TCP port a service is expected to own, if one can be inferred
-/// from its health-check URL or a `PORT` entry in its environment.
+/// Extracts the TCP port a service is expected to own: the first of its
+/// declared `ports`, else one inferred from its health-check URL or a `PORT`
+/// entry in its environment.
 pub fn service_port(service: &ServiceConfig) -> Option<u16> {
+    if let Some(&port) = service.ports.iter().flatten().next() {
+        return Some(port);
+    }
     if let Some(port) = service
         .deployment
         .as_ref()
@@ -317,6 +321,18 @@ mod tests {
         assert_eq!(port_from_url("http://localhost/health"), None);
     }
 
+    #[test]
+    fn declared_ports_name_the_service_port() {
+        let mut service = ServiceConfig {
+            command: "./server".into(),
+            ..Default::default()
+        };
+        assert_eq!(service_port(&service), None);
+
+        service.ports = Some(vec![8100, 8101]);
+        assert_eq!(service_port(&service), Some(8100));
+    }
+
     #[test]
     fn extracts_listen_inode_for_matching_port() {
         // 0x1F9E = 8094; a LISTEN (0A) row bound to 127.0.0.1:8094 with inode 54321.
@@ -0,0 +1,12 @@
+//! The `reload` command's plan layer.
+//!
+//! - [`crate::reload::plan`] — compares an on-disk manifest against the per-field
+//!   config hashes a running supervisor reports, producing a
+//!   [`crate::reload::ReloadPreview`] of what a reload would add, remove,
+//!   restart, or leave alone.
+//!
+//! Applying a reload is a whole-project `restart`; this module only previews it.
+
+pub mod plan;
+
+pub use plan::{ReloadChange, ReloadPreview, RunningServices, preview};
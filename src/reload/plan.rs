@@ -0,0 +1,206 @@
+//! Previewing a reload against the running supervisor.
+//!
+//! A reload is a whole-config restart, which [`crate::restart::ManifestDiff`]
+//! applies surgically. The preview answers the same question from the client
+//! side, before anything is touched: the supervisor reports a hash per service
+//! *field*, so the preview can say not only that `api` will restart but that it
+//! restarts because its `command` and `env` changed.
+//!
+//! - [`ReloadPreview`] — one project's per-service verdicts, sorted by name.
+//! - [`preview`] — builds the preview from the new config and the live hashes.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::config::Config;
+
+/// Running services for one project: service name → field → hash.
+pub type RunningServices = BTreeMap<String, BTreeMap<String, String>>;
+
+/// What a reload would do to one service.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReloadChange {
+    /// Declared on disk but not running — would be started.
+    Add,
+    /// Running but no longer declared — would be stopped.
+    Remove,
+    /// Declared and running with different settings — would be restarted.
+    Restart {
+        /// The manifest fields whose hashes differ, sorted.
+        fields: Vec<String>,
+    },
+    /// Declared and running with identical settings — left running.
+    Unchanged,
+}
+
+/// The preview of reloading one project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReloadPreview {
+    /// The project id the preview covers.
+    pub project: String,
+    /// Whether the supervisor currently runs this project at all.
+    pub loaded: bool,
+    /// Every service on either side, with its verdict, sorted by name.
+    pub services: Vec<(String, ReloadChange)>,
+}
+
+impl ReloadPreview {
+    /// Whether applying the reload would touch nothing.
+    pub fn is_noop(&self) -> bool {
+        self.services
+            .iter()
+            .all(|(_, change)| *change == ReloadChange::Unchanged)
+    }
+}
+
+/// Compares `config` against `running`, the live field hashes for the same
+/// project (`None` when the supervisor does not run it, so every service is an
+/// [`ReloadChange::Add`]).
+pub fn preview(config: &Config, running: Option<&RunningServices>) -> ReloadPreview {
+    let empty = RunningServices::new();
+    let live = running.unwrap_or(&empty);
+    let names = config
+        .services
+        .keys()
+        .chain(live.keys())
+        .cloned()
+        .collect::<BTreeSet<_>>();
+
+    let services = names
+        .into_iter()
+        .map(|name| {
+            let change = match (config.services.get(&name), live.get(&name)) {
+                (Some(_), None) => ReloadChange::Add,
+                (None, _) => ReloadChange::Remove,
+                (Some(service), Some(live_fields)) => {
+                    let fields = changed_fields(&service.field_hashes(), live_fields);
+                    if fields.is_empty() {
+                        ReloadChange::Unchanged
+                    } else {
+                        ReloadChange::Restart { fields }
+                    }
+                }
+            };
+            (name, change)
+        })
+        .collect();
+
+    ReloadPreview {
+        project: config.project.id.clone(),
+        loaded: running.is_some(),
+        services,
+    }
+}
+
+/// Returns the fields set on either side whose hashes differ.
+fn changed_fields(
+    new: &BTreeMap<String, String>,
+    live: &BTreeMap<String, String>,
+) -> Vec<String> {
+    new.keys()
+        .chain(live.keys())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter(|field| new.get(*field) != live.get(*field))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::config::{ProjectConfig, ServiceConfig, Version};
+
+    fn svc(command: &str) -> ServiceConfig {
+        ServiceConfig {
+            command: command.to_string(),
+            ..ServiceConfig::default()
+        }
+    }
+
+    fn config(services: Vec<(&str, ServiceConfig)>) -> Config {
+        Config {
+            version: Version::V2,
+            project: ProjectConfig {
+                id: "demo".into(),
+                ..ProjectConfig::default()
+            },
+            services: services
+                .into_iter()
+                .map(|(name, service)| (name.to_string(), service))
+                .collect::<HashMap<_, _>>(),
+            project_dir: None,
+            env: None,
+            metrics: Default::default(),
+            logs: Default::default(),
+            status: Default::default(),
+            deployment: Default::default(),
+            on_ready: None,
+            on_start_failure: None,
+            on_failure: Default::default(),
+            dependency_settle: None,
+            supervisor: Default::default(),
+            env_overrides: Default::default(),
+        }
+    }
+
+    fn running(services: Vec<(&str, ServiceConfig)>) -> RunningServices {
+        services
+            .into_iter()
+            .map(|(name, service)| (name.to_string(), service.field_hashes()))
+            .collect()
+    }
+
+    #[test]
+    fn classifies_every_service() {
+        let mut changed = svc("serve");
+        changed.backoff = Some("9s".into());
+        let new = config(vec![
+            ("web", svc("run")),
+            ("api", changed),
+            ("fresh", svc("new")),
+        ]);
+        let live = running(vec![
+            ("web", svc("run")),
+            ("api", svc("serve")),
+            ("gone", svc("old")),
+        ]);
+
+        let preview = preview(&new, Some(&live));
+        assert!(preview.loaded);
+        assert_eq!(
+            preview.services,
+            vec![
+                (
+                    "api".to_string(),
+                    ReloadChange::Restart {
+                        fields: vec!["backoff".to_string()]
+                    }
+                ),
+                ("fresh".to_string(), ReloadChange::Add),
+                ("gone".to_string(), ReloadChange::Remove),
+                ("web".to_string(), ReloadChange::Unchanged),
+            ]
+        );
+        assert!(!preview.is_noop());
+    }
+
+    #[test]
+    fn unloaded_project_adds_everything() {
+        let new = config(vec![("web", svc("run"))]);
+        let preview = preview(&new, None);
+        assert!(!preview.loaded);
+        assert_eq!(
+            preview.services,
+            vec![("web".to_string(), ReloadChange::Add)]
+        );
+    }
+
+    #[test]
+    fn identical_config_is_a_noop() {
+        let new = config(vec![("web", svc("run"))]);
+        let live = running(vec![("web", svc("run"))]);
+        assert!(preview(&new, Some(&live)).is_noop());
+    }
+}
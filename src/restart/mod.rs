@@ -8,7 +8,7 @@ pub mod plan;
 pub mod reconcile;
 
 pub use plan::{
-    Preflight, RestartPlan, World, manifest_rejected, preflight, reconcile_incomplete,
-    recycle_failed, recycle_refused, resolve_plan,
+    Preflight, RestartPlan, World, build_first_needs_service, manifest_rejected,
+    preflight, reconcile_incomplete, recycle_failed, recycle_refused, resolve_plan,
 };
 pub use reconcile::ManifestDiff;
@@ -175,6 +175,23 @@ pub fn reconcile_incomplete(failed: &[String]) -> Diagnostic {
     .help_docs()
 }
 
+/// Diagnostic for `restart --build-first` without a named service. A project
+/// or whole-config restart reconciles its services together, so there is no
+/// point at which one service's `pre_start` can run ahead of its own stop.
+pub fn build_first_needs_service() -> Diagnostic {
+    Diagnostic::error(
+        SgCode::Catchall,
+        "`--build-first` only applies to restarts of named services",
+    )
+    .note("project and whole-config restarts reconcile every service together")
+    .note("nothing was restarted")
+    .help_cmd(
+        "build and restart named services",
+        "sysg restart <service>... --build-first",
+    )
+    .help_docs()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -200,6 +217,17 @@ mod tests {
         assert!(help.contains("sysg start --daemonize --config /x/stack.yaml"));
     }
 
+    #[test]
+    fn build_first_without_a_service_points_at_named_restarts() {
+        let diag = build_first_needs_service();
+        assert!(
+            diag.notes
+                .iter()
+                .any(|n| n.contains("nothing was restarted"))
+        );
+        assert!(format!("{diag}").contains("sysg restart <service>... --build-first"));
+    }
+
     #[test]
     fn no_selectors_targets_everything() {
         assert_eq!(
@@ -90,6 +90,13 @@ mod tests {
             metrics: Default::default(),
             logs: Default::default(),
             status: Default::default(),
+            deployment: Default::default(),
+            on_ready: None,
+            on_start_failure: None,
+            on_failure: Default::default(),
+            dependency_settle: None,
+            supervisor: Default::default(),
+            env_overrides: Default::default(),
         }
     }
 
@@ -1,16 +1,22 @@
 //! Runtime paths and privilege modes.
-#[cfg(test)]
-use std::path::Path;
 use std::{
+    collections::BTreeMap,
     env,
     os::fd::RawFd,
-    path::PathBuf,
+    path::{Path, PathBuf},
     sync::{OnceLock, RwLock},
 };
 
 #[cfg(unix)]
 use libc;
 
+/// Environment variable carrying the runtime namespace to re-executed
+/// supervisors, managed services, and nested `sysg` invocations.
+pub const NAMESPACE_ENV: &str = "SYSTEMG_NAMESPACE";
+
+/// Longest accepted runtime namespace name.
+const MAX_NAMESPACE_LEN: usize = 64;
+
 /// Where to store state/logs.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RuntimeMode {
@@ -29,6 +35,7 @@ struct RuntimeContext {
     config_dirs: Vec<PathBuf>,
     drop_privileges: bool,
     activation_fds: Vec<RawFd>,
+    namespace: Option<String>,
 }
 
 static CONTEXT: OnceLock<RwLock<RuntimeContext>> = OnceLock::new();
@@ -68,6 +75,7 @@ impl RuntimeContext {
             config_dirs: vec![config_dir],
             drop_privileges: false,
             activation_fds: Vec::new(),
+            namespace: None,
         }
     }
 
@@ -84,8 +92,33 @@ impl RuntimeContext {
             config_dirs: vec![config_dir],
             drop_privileges: false,
             activation_fds: Vec::new(),
+            namespace: None,
         }
     }
+
+    /// Moves the state and log directories to siblings suffixed with the
+    /// namespace (`systemg-<name>`), leaving config search paths shared.
+    fn namespaced(mut self, namespace: Option<&str>) -> Self {
+        let Some(namespace) = namespace else {
+            return self;
+        };
+        let state_dir = suffixed(&self.state_dir, namespace);
+        self.log_dir = match self.log_dir.strip_prefix(&self.state_dir) {
+            Ok(relative) => state_dir.join(relative),
+            Err(_) => suffixed(&self.log_dir, namespace),
+        };
+        self.state_dir = state_dir;
+        self.namespace = Some(namespace.to_string());
+        self
+    }
+}
+
+/// Appends `-<namespace>` to the final component of `dir`.
+fn suffixed(dir: &Path, namespace: &str) -> PathBuf {
+    let mut name = dir.file_name().unwrap_or_default().to_os_string();
+    name.push("-");
+    name.push(namespace);
+    dir.with_file_name(name)
 }
 
 /// Sets runtime mode. Can be called multiple times (e.g., supervisor forks).
@@ -95,7 +128,8 @@ pub fn init(mode: RuntimeMode) {
         .unwrap_or_else(std::sync::PoisonError::into_inner);
     let drop_privileges = guard.drop_privileges;
     let activation_fds = guard.activation_fds.clone();
-    let mut context = RuntimeContext::from_mode(mode);
+    let namespace = guard.namespace.clone();
+    let mut context = RuntimeContext::from_mode(mode).namespaced(namespace.as_deref());
     context.drop_privileges = drop_privileges;
     context.activation_fds = activation_fds;
     *guard = context;
@@ -109,12 +143,59 @@ pub fn init_with_test_home(home: &Path) {
         .unwrap_or_else(std::sync::PoisonError::into_inner);
     let drop_privileges = guard.drop_privileges;
     let activation_fds = guard.activation_fds.clone();
-    let mut context = RuntimeContext::from_user_home(home.to_path_buf());
+    let namespace = guard.namespace.clone();
+    let mut context = RuntimeContext::from_user_home(home.to_path_buf())
+        .namespaced(namespace.as_deref());
     context.drop_privileges = drop_privileges;
     context.activation_fds = activation_fds;
     *guard = context;
 }
 
+/// Selects the runtime namespace, re-deriving the state and log directories for
+/// the current mode. `None` restores the shared default directories.
+pub fn set_namespace(namespace: Option<&str>) {
+    let mut guard = context_lock()
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let drop_privileges = guard.drop_privileges;
+    let activation_fds = guard.activation_fds.clone();
+    let mut context = RuntimeContext::from_mode(guard.mode).namespaced(namespace);
+    context.drop_privileges = drop_privileges;
+    context.activation_fds = activation_fds;
+    *guard = context;
+}
+
+/// Returns the runtime namespace, if one was selected.
+pub fn namespace() -> Option<String> {
+    context_lock()
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .namespace
+        .clone()
+}
+
+/// Checks that a namespace is safe to embed in a directory name: 1 to 64
+/// ASCII letters, digits, `-` or `_`, not starting with `-`.
+pub fn validate_namespace(namespace: &str) -> Result<(), String> {
+    if namespace.is_empty() || namespace.len() > MAX_NAMESPACE_LEN {
+        return Err(format!(
+            "namespace must be 1 to {MAX_NAMESPACE_LEN} characters long"
+        ));
+    }
+    if namespace.starts_with('-') {
+        return Err("namespace must not start with '-'".into());
+    }
+    if let Some(invalid) = namespace
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || *c == '-' || *c == '_'))
+    {
+        return Err(format!(
+            "namespace '{namespace}' contains '{invalid}'; use letters, digits, '-' or '_'"
+        ));
+    }
+    Ok(())
+}
+
 /// Returns the current runtime mode (User or System).
 pub fn mode() -> RuntimeMode {
     context_lock()
@@ -383,6 +464,47 @@ pub fn capture_socket_activation() {
     clear_activation_fds();
 }
 
+/// Names of the variables `apply_supervisor_env` set on this process.
+static SUPERVISOR_ENV_KEYS: RwLock<Vec<String>> = RwLock::new(Vec::new());
+
+/// Applies a manifest's `supervisor.env` block to this process. Variables
+/// already present in the inherited environment win and are left untouched.
+/// Returns the names that were set.
+///
+/// Must run before the supervisor starts service or cron threads, since
+/// mutating the environment is not thread-safe.
+pub fn apply_supervisor_env(vars: &BTreeMap<String, String>) -> Vec<String> {
+    let mut applied = Vec::new();
+    for (key, value) in vars {
+        if env::var_os(key).is_some() {
+            continue;
+        }
+        unsafe {
+            env::set_var(key, value);
+        }
+        applied.push(key.clone());
+    }
+
+    let mut guard = SUPERVISOR_ENV_KEYS
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    for key in &applied {
+        if !guard.contains(key) {
+            guard.push(key.clone());
+        }
+    }
+    applied
+}
+
+/// Returns the variables set from `supervisor.env`, which services must not
+/// inherit.
+pub fn supervisor_env_keys() -> Vec<String> {
+    SUPERVISOR_ENV_KEYS
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone()
+}
+
 #[cfg(test)]
 mod tests {
     use std::env;
@@ -422,6 +544,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn namespace_moves_state_and_logs_to_sibling_directories() {
+        let _guard = env_lock();
+        let temp = tempdir().expect("tempdir");
+        let original_home = env::var("HOME").ok();
+        unsafe {
+            env::set_var("HOME", temp.path());
+        }
+        init(RuntimeMode::User);
+
+        set_namespace(Some("blue"));
+        let state = temp.path().join(".local/share/systemg-blue");
+        assert_eq!(state_dir(), state);
+        assert_eq!(log_dir(), state.join("logs"));
+        assert_eq!(namespace().as_deref(), Some("blue"));
+
+        init(RuntimeMode::System);
+        assert_eq!(state_dir(), PathBuf::from("/var/lib/systemg-blue"));
+        assert_eq!(log_dir(), PathBuf::from("/var/log/systemg-blue"));
+
+        set_namespace(None);
+        init(RuntimeMode::User);
+        assert_eq!(namespace(), None);
+        assert_eq!(state_dir(), temp.path().join(".local/share/systemg"));
+
+        if let Some(previous) = original_home {
+            unsafe { env::set_var("HOME", previous) };
+        } else {
+            unsafe { env::remove_var("HOME") };
+        }
+    }
+
+    #[test]
+    fn namespace_names_are_directory_safe() {
+        assert!(validate_namespace("staging_2").is_ok());
+        assert!(validate_namespace("").is_err());
+        assert!(validate_namespace("-a").is_err());
+        assert!(validate_namespace("a/b").is_err());
+        assert!(validate_namespace("..").is_err());
+        assert!(validate_namespace(&"x".repeat(65)).is_err());
+    }
+
     #[test]
     fn system_mode_uses_var_directories() {
         let _guard = env_lock();
@@ -0,0 +1,157 @@
+//! Listening sockets the supervisor binds and holds on behalf of services.
+//!
+//! A service that declares `sockets` inherits each listener as a descriptor
+//! starting at fd 3, with `LISTEN_FDS` and `LISTEN_PID` set the way systemd
+//! socket activation sets them. The supervisor keeps every listener open
+//! between runs of the service, so the port stays bound and new connections
+//! wait in the backlog while it restarts.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    ffi::OsString,
+    io,
+    net::{SocketAddr, TcpListener},
+    os::fd::RawFd,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use crate::{
+    config::{Config, SocketConfig},
+    constants::DEFAULT_SHELL,
+};
+
+/// First descriptor a socket-activated service receives.
+pub const LISTEN_FDS_START: RawFd = 3;
+
+/// Number of descriptors passed, counted from [`LISTEN_FDS_START`].
+pub const LISTEN_FDS_ENV: &str = "LISTEN_FDS";
+
+/// PID the descriptors are meant for; libraries ignore them in any other process.
+pub const LISTEN_PID_ENV: &str = "LISTEN_PID";
+
+/// Variables a service without sockets must not inherit from the supervisor.
+pub const LISTEN_ENV_VARS: [&str; 3] = [LISTEN_FDS_ENV, LISTEN_PID_ENV, "LISTEN_FDNAMES"];
+
+/// Listeners held for services, keyed by project id and address. Services of
+/// one project that declare the same address share its listener.
+static HELD: Mutex<BTreeMap<(String, SocketAddr), Arc<TcpListener>>> =
+    Mutex::new(BTreeMap::new());
+
+/// Returns the listeners for `sockets`, binding any `project` does not already
+/// hold.
+pub(crate) fn acquire(
+    project: &str,
+    sockets: &[SocketConfig],
+) -> io::Result<Vec<Arc<TcpListener>>> {
+    let mut held = HELD.lock().unwrap_or_else(PoisonError::into_inner);
+    sockets
+        .iter()
+        .map(|socket| {
+            let addr = socket.address().map_err(|raw| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid socket address '{raw}'"),
+                )
+            })?;
+            let key = (project.to_string(), addr);
+            if let Some(listener) = held.get(&key) {
+                return Ok(Arc::clone(listener));
+            }
+            let listener = TcpListener::bind(addr).map(Arc::new).map_err(|err| {
+                io::Error::new(err.kind(), format!("failed to bind {addr}: {err}"))
+            })?;
+            held.insert(key, Arc::clone(&listener));
+            Ok(listener)
+        })
+        .collect()
+}
+
+/// Closes the listeners of `config`'s project that none of its services
+/// declares any more.
+pub(crate) fn release_undeclared(config: &Config) {
+    let declared: HashSet<SocketAddr> = config
+        .services
+        .values()
+        .flat_map(|service| service.sockets.iter().flatten())
+        .filter_map(|socket| socket.address().ok())
+        .collect();
+    HELD.lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .retain(|(project, addr), _| {
+            project != &config.project.id || declared.contains(addr)
+        });
+}
+
+/// Returns whether `project` holds a listener on `addr`.
+pub fn is_held(project: &str, addr: SocketAddr) -> bool {
+    HELD.lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .contains_key(&(project.to_string(), addr))
+}
+
+/// Returns whether `project` holds a listener on `port` at any address.
+pub(crate) fn holds_port(project: &str, port: u16) -> bool {
+    HELD.lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .keys()
+        .any(|(held, addr)| held == project && addr.port() == port)
+}
+
+/// Moves `fds` onto consecutive descriptors from [`LISTEN_FDS_START`] without
+/// close-on-exec, so the service inherits them. Runs in the forked child.
+pub(crate) fn install_in_child(fds: &mut [RawFd]) -> io::Result<()> {
+    // Stage every listener above the target range first, so moving one can
+    // never overwrite another that still sits on a target descriptor.
+    let above_targets = LISTEN_FDS_START + fds.len() as RawFd;
+    for fd in fds.iter_mut() {
+        let staged = unsafe { libc::fcntl(*fd, libc::F_DUPFD_CLOEXEC, above_targets) };
+        if staged < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        *fd = staged;
+    }
+    for (target, fd) in (LISTEN_FDS_START..).zip(fds.iter()) {
+        if unsafe { libc::dup2(*fd, target) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+/// Wraps `argv` in a shell that exports its own PID as `LISTEN_PID` and then
+/// execs `argv`, since the PID is only known after fork and the exec keeps it.
+pub(crate) fn with_listen_pid(argv: Vec<OsString>) -> Vec<OsString> {
+    let mut wrapped: Vec<OsString> = vec![
+        DEFAULT_SHELL.into(),
+        "-c".into(),
+        format!("export {LISTEN_PID_ENV}=$$; exec \"$@\"").into(),
+        "sysg-listen".into(),
+    ];
+    wrapped.extend(argv);
+    wrapped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn acquire_reuses_the_listener_a_project_already_holds() {
+        let socket = SocketConfig {
+            listen: "127.0.0.1:0".into(),
+        };
+
+        let first = acquire("sockets-test-a", std::slice::from_ref(&socket)).unwrap();
+        let again = acquire("sockets-test-a", std::slice::from_ref(&socket)).unwrap();
+        let other = acquire("sockets-test-b", std::slice::from_ref(&socket)).unwrap();
+
+        assert!(Arc::ptr_eq(&first[0], &again[0]));
+        assert!(!Arc::ptr_eq(&first[0], &other[0]));
+
+        let bad = SocketConfig {
+            listen: "localhost".into(),
+        };
+        let err = acquire("sockets-test-a", &[bad]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -6,9 +6,13 @@
 //! state-file name onto the raw state dir. Project-less ("loose") services live
 //! under the `__loose__` directory so the layout is uniform.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
 
 use crate::{
+    config::{CURRENT_MANIFEST_VERSION, state_key},
     constants::{PID_FILE_NAME, PID_LOCK_SUFFIX, STATE_FILE_NAME},
     runtime,
 };
@@ -22,6 +26,62 @@ pub const LOOSE_PROJECT_ID: &str = "__loose__";
 /// Name of the cron state file within a project directory.
 pub const CRON_FILE_NAME: &str = "cron_state.xml";
 
+/// Current on-disk format of `pid.xml`, `state.xml` and `cron_state.xml`.
+///
+/// Files written before the format was versioned carry no `schema_version`
+/// element and load as version 0. Their entries were keyed by bare service
+/// name (state) or config hash (cron) instead of by [`state_key`]. Bump this
+/// and add a step to each file's `migrate` when the layout changes again.
+pub const STATE_SCHEMA_VERSION: u32 = 1;
+
+/// The `schema_version` element of a state file.
+///
+/// Defaults to [`STATE_SCHEMA_VERSION`] so files built in memory are written
+/// in the current format; a file without the element deserializes through
+/// [`SchemaVersion::unversioned`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SchemaVersion(pub u32);
+
+impl Default for SchemaVersion {
+    fn default() -> Self {
+        Self(STATE_SCHEMA_VERSION)
+    }
+}
+
+impl SchemaVersion {
+    /// Version assumed for files that predate the `schema_version` element.
+    pub fn unversioned() -> Self {
+        Self(0)
+    }
+
+    /// Checks the version of a state file just read from `path`. Logs and
+    /// returns `true` when the file is older than [`STATE_SCHEMA_VERSION`] and
+    /// must be migrated; a file from a newer systemg is loaded as-is with a
+    /// warning rather than discarded.
+    pub fn needs_migration(self, path: &Path) -> bool {
+        if self.0 > STATE_SCHEMA_VERSION {
+            warn!(
+                "{} uses state schema v{}, newer than this build's v{}; loading it as-is",
+                path.display(),
+                self.0,
+                STATE_SCHEMA_VERSION
+            );
+            return false;
+        }
+        if self.0 == STATE_SCHEMA_VERSION {
+            return false;
+        }
+        info!(
+            "Migrating {} from state schema v{} to v{}",
+            path.display(),
+            self.0,
+            STATE_SCHEMA_VERSION
+        );
+        true
+    }
+}
+
 /// Resolves the on-disk paths for a single project's state files.
 ///
 /// The [`Default`] value is an empty, unusable placeholder — it exists only so
@@ -60,6 +120,35 @@ impl StateStore {
         &self.dir
     }
 
+    /// The project id this store belongs to, taken from its directory name.
+    pub fn project_id(&self) -> &str {
+        self.dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default()
+    }
+
+    /// Rewrites a version-0 entry key as the [`state_key`] the current format
+    /// uses. `service` names the entry's service when the file recorded it
+    /// separately (cron); otherwise the legacy key itself is the service name.
+    /// Keys already in state-key form, and bare config hashes with no service
+    /// name to recover, come back unchanged.
+    pub fn upgrade_legacy_key(&self, key: &str, service: Option<&str>) -> String {
+        if key.contains(':') {
+            return key.to_string();
+        }
+        let is_hash = key.len() == 16 && key.chars().all(|c| c.is_ascii_hexdigit());
+        match service {
+            Some(service) => {
+                state_key(CURRENT_MANIFEST_VERSION, self.project_id(), service)
+            }
+            None if !is_hash => {
+                state_key(CURRENT_MANIFEST_VERSION, self.project_id(), key)
+            }
+            None => key.to_string(),
+        }
+    }
+
     /// Path to the project's PID file.
     pub fn pid_path(&self) -> PathBuf {
         self.dir.join(PID_FILE_NAME)
@@ -116,6 +205,29 @@ mod tests {
         assert!(a.cron_path() != b.cron_path());
     }
 
+    #[test]
+    fn legacy_keys_upgrade_to_state_keys() {
+        let store = StateStore::at(PathBuf::from("/x/projects/alpha"));
+        assert_eq!(store.project_id(), "alpha");
+        assert_eq!(store.upgrade_legacy_key("api", None), "v2:alpha:api");
+        assert_eq!(
+            store.upgrade_legacy_key("0123456789abcdef", Some("backup")),
+            "v2:alpha:backup"
+        );
+        assert_eq!(
+            store.upgrade_legacy_key("0123456789abcdef", None),
+            "0123456789abcdef"
+        );
+        assert_eq!(
+            store.upgrade_legacy_key("v2:alpha:api", None),
+            "v2:alpha:api"
+        );
+        assert_eq!(
+            StateStore::loose().upgrade_legacy_key("api", None),
+            "v2:none:api"
+        );
+    }
+
     #[test]
     fn paths_nest_under_project_dir() {
         let s = StateStore::at(PathBuf::from("/x/projects/alpha"));
@@ -45,6 +45,17 @@ pub fn state_inconsistent(detail: impl Into<String>) -> Diagnostic {
     .help_docs()
 }
 
+/// `status --json` was asked for a single unit that the reading does not contain.
+pub fn unit_not_found(service: &str) -> Diagnostic {
+    Diagnostic::error(
+        SgCode::TargetNotFound,
+        format!("no unit named `{service}` in the status reading"),
+    )
+    .note("orphaned state is only considered with `--all`")
+    .help_cmd("list units", "sysg status")
+    .help_docs()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -64,6 +75,13 @@ mod tests {
         );
     }
 
+    #[test]
+    fn unit_not_found_is_typed_and_names_the_unit() {
+        let diag = unit_not_found("web");
+        assert_eq!(diag.code, SgCode::TargetNotFound);
+        assert!(diag.render(false).contains("`web`"));
+    }
+
     #[test]
     fn inconsistent_is_sg0009_and_carries_detail() {
         let diag = state_inconsistent("web recorded running but pid 12 is gone");
@@ -34,14 +34,14 @@ use thiserror::Error;
 use tracing::{debug, error};
 
 use crate::{
-    config::{Config, ProjectConfig, ServiceConfig, StatusSnapshotMode},
+    config::{Config, HealthPolicy, ProjectConfig, ServiceConfig, StatusSnapshotMode},
     constants::PROCESS_CHECK_INTERVAL,
     cron::{
         CronExecutionRecord, CronExecutionStatus, CronStateFile, PersistedCronJobState,
     },
     daemon::{PidFile, ServiceLifecycleStatus, ServiceStateFile},
     error::{PidFileError, ProcessManagerError, ServiceStateError},
-    metrics::{MetricSample, MetricsHandle, MetricsStore, MetricsSummary},
+    metrics::{MetricSample, MetricsHandle, MetricsSummary, UnitSummaries},
     spawn::{DynamicSpawnManager, SpawnedChild, SpawnedChildKind},
     state_store::StateStore,
 };
@@ -165,7 +165,9 @@ pub enum UnitIntent {
 ///
 /// `UnitHealth` answers "does this unit need operator attention?" It is not a
 /// runtime state and does not say whether a process is currently running.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, schemars::JsonSchema,
+)]
 #[serde(rename_all = "snake_case")]
 pub enum UnitHealth {
     /// Unit is doing exactly what its intent requires.
@@ -173,6 +175,7 @@ pub enum UnitHealth {
     /// Unit is acceptable but not active, such as a completed one-shot or queued cron.
     Idle,
     /// Unit is suspicious or not in the desired shape, but has not hard-failed.
+    #[serde(alias = "degraded")]
     Warn,
     /// Unit is in a known failed condition requiring action.
     Failing,
@@ -189,6 +192,10 @@ pub struct StatusSnapshot {
     pub overall_health: OverallHealth,
     /// List of all managed units and their current status.
     pub units: Vec<UnitStatus>,
+    /// Whether the supervisor is paused (`sysg pause`), so crashed services are
+    /// recorded but not restarted.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub paused: bool,
 }
 
 impl StatusSnapshot {
@@ -200,6 +207,7 @@ impl StatusSnapshot {
             captured_at: Utc::now(),
             overall_health,
             units,
+            paused: false,
         }
     }
 
@@ -210,10 +218,40 @@ impl StatusSnapshot {
             captured_at: Utc::now(),
             overall_health: OverallHealth::Healthy,
             units: Vec::new(),
+            paused: false,
         }
     }
 }
 
+/// One unit's status as a standalone machine-readable document, versioned and
+/// timestamped like the snapshot it came from. The unit's own fields are
+/// inlined, so `health` is a top-level key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnitStatusDocument {
+    /// Version identifier for the snapshot schema format.
+    pub schema_version: String,
+    /// Timestamp when the source snapshot was captured.
+    pub captured_at: DateTime<Utc>,
+    /// The selected unit.
+    #[serde(flatten)]
+    pub unit: UnitStatus,
+}
+
+impl StatusSnapshot {
+    /// Extracts the first unit accepted by `matches` as a standalone document.
+    pub fn unit_status(
+        &self,
+        matches: impl Fn(&UnitStatus) -> bool,
+    ) -> Option<UnitStatusDocument> {
+        let unit = self.units.iter().find(|unit| matches(unit))?;
+        Some(UnitStatusDocument {
+            schema_version: self.schema_version.clone(),
+            captured_at: self.captured_at,
+            unit: unit.clone(),
+        })
+    }
+}
+
 /// Hierarchical status for a dynamically spawned child process.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpawnedProcessNode {
@@ -230,7 +268,10 @@ impl SpawnedProcessNode {
 }
 
 /// Collects tracked pids.
-fn collect_tracked_pids(nodes: &[SpawnedProcessNode], seen: &mut HashSet<u32>) {
+pub(crate) fn collect_tracked_pids(
+    nodes: &[SpawnedProcessNode],
+    seen: &mut HashSet<u32>,
+) {
     for node in nodes {
         if seen.insert(node.child.pid) {
             collect_tracked_pids(&node.children, seen);
@@ -369,7 +410,7 @@ fn augment_spawn_tree_with_system_descendants(
 }
 
 /// Builds spawn tree from pidfile.
-fn build_spawn_tree_from_pidfile(
+pub(crate) fn build_spawn_tree_from_pidfile(
     pid_file: &PidFile,
     parent_pid: u32,
     service_hash: Option<&str>,
@@ -657,6 +698,17 @@ pub struct UnitStatus {
     pub runtime_command: Option<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub spawned_children: Vec<SpawnedProcessNode>,
+    /// The running process was launched from a config that differs from the
+    /// current one; a restart is needed to apply the change.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub drifted: bool,
+    /// How many times the unit was launched again after its first run.
+    #[serde(default)]
+    pub restarts: u32,
+    /// The `max_restarts` the restart policy exhausted before giving up on the
+    /// unit, while it stays given up.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gave_up_after: Option<u32>,
 }
 
 /// Project metadata attached to a status entry.
@@ -712,7 +764,21 @@ pub struct UnitMetricsSummary {
     pub latest_cpu_percent: f32,
     pub average_cpu_percent: f32,
     pub max_cpu_percent: f32,
+    #[serde(default)]
+    pub p50_cpu_percent: f32,
+    #[serde(default)]
+    pub p95_cpu_percent: f32,
+    #[serde(default)]
+    pub p99_cpu_percent: f32,
     pub latest_rss_bytes: u64,
+    #[serde(default)]
+    pub latest_io_read_bytes: u64,
+    #[serde(default)]
+    pub latest_io_write_bytes: u64,
+    #[serde(default)]
+    pub average_io_read_bytes_per_sec: f64,
+    #[serde(default)]
+    pub average_io_write_bytes_per_sec: f64,
     pub samples: usize,
 }
 
@@ -723,7 +789,14 @@ impl From<MetricsSummary> for UnitMetricsSummary {
             latest_cpu_percent: summary.latest_cpu_percent,
             average_cpu_percent: summary.average_cpu_percent,
             max_cpu_percent: summary.max_cpu_percent,
+            p50_cpu_percent: summary.p50_cpu_percent,
+            p95_cpu_percent: summary.p95_cpu_percent,
+            p99_cpu_percent: summary.p99_cpu_percent,
             latest_rss_bytes: summary.latest_rss_bytes,
+            latest_io_read_bytes: summary.latest_io_read_bytes,
+            latest_io_write_bytes: summary.latest_io_write_bytes,
+            average_io_read_bytes_per_sec: summary.average_io_read_bytes_per_sec,
+            average_io_write_bytes_per_sec: summary.average_io_write_bytes_per_sec,
             samples: summary.samples,
         }
     }
@@ -754,6 +827,9 @@ pub struct ExitMetadata {
     pub exit_code: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub signal: Option<i32>,
+    /// When the exit (or stop) was observed by the supervisor.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub at: Option<DateTime<Utc>>,
 }
 
 /// Cron-specific status attributes that augment a unit entry.
@@ -786,6 +862,9 @@ pub struct CronExecutionSummary {
     pub command: Option<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub metrics: Vec<MetricSample>,
+    /// Whether the run was requested with `sysg cron run`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub manual: bool,
 }
 
 /// Thread-safe cache of the most recent status snapshot.
@@ -893,6 +972,7 @@ impl Drop for StatusRefresher {
 }
 
 /// Builds a fresh snapshot from the supervisor runtime, locking shared state while collecting.
+/// With `include_children` off, spawned-child trees are skipped entirely.
 pub fn collect_runtime_snapshot(
     config: Arc<Config>,
     pid_file: &Arc<Mutex<PidFile>>,
@@ -900,6 +980,7 @@ pub fn collect_runtime_snapshot(
     metrics: Option<&MetricsHandle>,
     spawn_manager: Option<&DynamicSpawnManager>,
     mode: StatusSnapshotMode,
+    include_children: bool,
 ) -> Result<StatusSnapshot, StatusError> {
     collect_runtime_snapshot_with_cron_hashes(
         config,
@@ -908,11 +989,13 @@ pub fn collect_runtime_snapshot(
         metrics,
         spawn_manager,
         mode,
+        include_children,
         None,
     )
 }
 
 /// Builds a fresh snapshot using a caller-provided set of valid cron hashes.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn collect_runtime_snapshot_with_cron_hashes(
     config: Arc<Config>,
     pid_file: &Arc<Mutex<PidFile>>,
@@ -920,6 +1003,7 @@ pub(crate) fn collect_runtime_snapshot_with_cron_hashes(
     metrics: Option<&MetricsHandle>,
     spawn_manager: Option<&DynamicSpawnManager>,
     mode: StatusSnapshotMode,
+    include_children: bool,
     _valid_cron_hashes: Option<&HashSet<String>>,
 ) -> Result<StatusSnapshot, StatusError> {
     let store = StateStore::for_project(&config.project.id);
@@ -928,19 +1012,17 @@ pub(crate) fn collect_runtime_snapshot_with_cron_hashes(
     let mut state_guard = service_state
         .lock()
         .map_err(|_| StatusError::ServiceStatePoisoned)?;
-    let metrics_guard = match metrics {
-        Some(handle) => Some(handle.read().map_err(|_| StatusError::MetricsPoisoned)?),
-        None => None,
-    };
+    let metrics_summaries = metrics.map(MetricsHandle::summaries);
 
     Ok(build_snapshot(
         Some(config.as_ref()),
         &pid_guard,
         &mut state_guard,
         &mut cron_state,
-        metrics_guard.as_deref(),
+        metrics_summaries.as_deref(),
         spawn_manager,
         mode,
+        include_children,
     ))
 }
 
@@ -954,27 +1036,38 @@ pub(crate) fn cron_hashes_for_config(config: &Config) -> HashSet<String> {
         .collect()
 }
 
-/// Builds a snapshot purely from persisted state on disk.
+/// Builds a snapshot purely from persisted state on disk. With
+/// `include_children` off, spawned-child trees are skipped entirely.
 pub fn collect_disk_snapshot(
     config: Option<Config>,
+    include_children: bool,
+) -> Result<StatusSnapshot, StatusError> {
+    disk_snapshot(config.as_ref(), include_children)
+}
+
+/// Loads the pid, service-state, and cron files of `config`'s project (or the
+/// loose store without one) and builds a detailed snapshot from them.
+fn disk_snapshot(
+    config: Option<&Config>,
+    include_children: bool,
 ) -> Result<StatusSnapshot, StatusError> {
-    let store = match config.as_ref() {
+    let store = match config {
         Some(c) => StateStore::for_project(&c.project.id),
         None => StateStore::loose(),
     };
     let pid_file = PidFile::load(store.clone())?;
     let mut service_state = ServiceStateFile::load(store.clone())?;
     let mut cron_state = CronStateFile::load(store)?;
-    let config_ref = config.as_ref();
 
     Ok(build_snapshot(
-        config_ref,
+        config,
         &pid_file,
         &mut service_state,
         &mut cron_state,
         None,
         None,
         StatusSnapshotMode::Detailed,
+        include_children,
     ))
 }
 
@@ -1004,16 +1097,20 @@ fn reconcile_cron_hashes_by_name(cron_state: &CronStateFile) -> HashMap<String,
         .collect()
 }
 
-/// Builds snapshot.
+/// Builds snapshot. Spawned-child trees are only built in detailed mode with
+/// `include_children` set, since they cost full process-table scans.
+#[allow(clippy::too_many_arguments)]
 fn build_snapshot(
     config: Option<&Config>,
     pid_file: &PidFile,
     service_state: &mut ServiceStateFile,
     cron_state: &mut CronStateFile,
-    metrics_store: Option<&MetricsStore>,
+    metrics_summaries: Option<&UnitSummaries>,
     spawn_manager: Option<&DynamicSpawnManager>,
     mode: StatusSnapshotMode,
+    include_children: bool,
 ) -> StatusSnapshot {
+    let walk_children = include_children && matches!(mode, StatusSnapshotMode::Detailed);
     let mut hash_to_name: HashMap<String, String> = HashMap::new();
     let mut hash_kind: HashMap<String, UnitKind> = HashMap::new();
     let mut unit_hashes: BTreeSet<String> = BTreeSet::new();
@@ -1039,7 +1136,7 @@ fn build_snapshot(
 
     let mut units = Vec::new();
 
-    let process_system = if matches!(mode, StatusSnapshotMode::Detailed) {
+    let process_system = if walk_children {
         let mut sys = System::new();
         sys.refresh_processes(ProcessesToUpdate::All, true);
         Some(sys)
@@ -1131,6 +1228,7 @@ fn build_snapshot(
                 Some(ExitMetadata {
                     exit_code: entry.exit_code,
                     signal: entry.signal,
+                    at: entry.finished_at.map(DateTime::<Utc>::from),
                 })
             } else {
                 None
@@ -1181,6 +1279,11 @@ fn build_snapshot(
 
         let state =
             derive_unit_state(kind, lifecycle, process_runtime.as_ref(), cron.as_ref());
+        let health_policy = config.map(|cfg| {
+            cfg.status
+                .health
+                .overlaid(service_config.and_then(|service| service.health.as_ref()))
+        });
         let health = derive_unit_health(
             kind,
             state,
@@ -1188,16 +1291,18 @@ fn build_snapshot(
             lifecycle,
             process_runtime.as_ref(),
             cron.as_ref(),
+            &health_policy.unwrap_or_default(),
         );
-        let metrics_summary = metrics_store
-            .and_then(|store| {
-                store.summarize_unit(&hash).or_else(|| {
+        let metrics_summary = metrics_summaries
+            .and_then(|summaries| {
+                summaries.get(&hash).or_else(|| {
                     cron_hash
                         .as_deref()
                         .filter(|cron_hash| *cron_hash != hash)
-                        .and_then(|cron_hash| store.summarize_unit(cron_hash))
+                        .and_then(|cron_hash| summaries.get(cron_hash))
                 })
             })
+            .cloned()
             .map(UnitMetricsSummary::from);
 
         let command = service_config.map(|service_config| service_config.command.clone());
@@ -1216,9 +1321,7 @@ fn build_snapshot(
             None
         };
 
-        let spawned_children = if matches!(mode, StatusSnapshotMode::Detailed)
-            && let Some(runtime) = &process_runtime
-        {
+        let spawned_children = if walk_children && let Some(runtime) = &process_runtime {
             let mut seen = HashSet::new();
             seen.insert(runtime.pid);
 
@@ -1285,6 +1388,9 @@ fn build_snapshot(
             command,
             runtime_command,
             spawned_children,
+            drifted: false,
+            restarts: state_entry.as_ref().map_or(0, |entry| entry.restarts),
+            gave_up_after: state_entry.as_ref().and_then(|entry| entry.gave_up_after),
         });
     }
 
@@ -1323,19 +1429,17 @@ fn build_snapshot(
         let state = derive_unit_state(UnitKind::Orphaned, None, runtime.as_ref(), None);
         let intent = UnitIntent::Orphan;
 
-        let metrics_summary = metrics_store
-            .and_then(|store| store.summarize_unit(service_name))
+        let metrics_summary = metrics_summaries
+            .and_then(|summaries| summaries.get(service_name).cloned())
             .map(UnitMetricsSummary::from);
 
-        let mut spawned_children = if matches!(mode, StatusSnapshotMode::Detailed)
-            && let Some(manager) = spawn_manager
-        {
+        let mut spawned_children = if walk_children && let Some(manager) = spawn_manager {
             build_spawn_tree(manager, pid_value, process_system.as_ref())
         } else {
             Vec::new()
         };
 
-        if matches!(mode, StatusSnapshotMode::Detailed) && spawned_children.is_empty() {
+        if walk_children && spawned_children.is_empty() {
             let pidfile_system = if spawn_manager.is_some() {
                 process_system.as_ref()
             } else {
@@ -1372,10 +1476,45 @@ fn build_snapshot(
                 None
             },
             spawned_children,
+            drifted: false,
+            restarts: 0,
+            gave_up_after: None,
         });
     }
 
-    StatusSnapshot::new(units)
+    let mut snapshot = StatusSnapshot::new(units);
+    if let Some(cfg) = config {
+        mark_config_drift(&mut snapshot, cfg, service_state);
+    }
+    snapshot
+}
+
+/// Flags running services whose recorded launch config no longer matches
+/// `config`. Entries launched before the hash was recorded are left alone.
+pub fn mark_config_drift(
+    snapshot: &mut StatusSnapshot,
+    config: &Config,
+    service_state: &ServiceStateFile,
+) {
+    for unit in &mut snapshot.units {
+        unit.drifted = false;
+        if unit.kind != UnitKind::Service
+            || unit.lifecycle != Some(ServiceLifecycleStatus::Running)
+        {
+            continue;
+        }
+        let Some(service) = config.services.get(&unit.name) else {
+            continue;
+        };
+        if config.state_key(&unit.name) != unit.hash {
+            continue;
+        }
+        let launched_from = service_state
+            .get(&unit.hash)
+            .and_then(|entry| entry.config_hash.as_deref());
+        unit.drifted =
+            launched_from.is_some_and(|launched| launched != service.compute_hash());
+    }
 }
 
 /// Handles cron record to summary.
@@ -1389,6 +1528,7 @@ fn cron_record_to_summary(record: &CronExecutionRecord) -> CronExecutionSummary
         user: record.user.clone(),
         command: record.command.clone(),
         metrics: record.metrics.clone(),
+        manual: record.manual,
     }
 }
 
@@ -1463,7 +1603,9 @@ fn derive_unit_state(
     match lifecycle {
         Some(ServiceLifecycleStatus::Running) => UnitState::Running,
         Some(ServiceLifecycleStatus::ExitedSuccessfully) => UnitState::Done,
-        Some(ServiceLifecycleStatus::ExitedWithError) => UnitState::Failed,
+        Some(
+            ServiceLifecycleStatus::ExitedWithError | ServiceLifecycleStatus::GaveUp,
+        ) => UnitState::Failed,
         Some(ServiceLifecycleStatus::Stopped) => UnitState::Stopped,
         Some(ServiceLifecycleStatus::Skipped) => UnitState::Skipped,
         None if matches!(kind, UnitKind::Cron) => UnitState::Queued,
@@ -1497,7 +1639,8 @@ fn derive_unit_intent(
     }
 }
 
-/// Derives the operator-action health classification for a unit.
+/// Derives the operator-action health classification for a unit. `policy`
+/// reclassifies the conditions it names; everything else is fixed.
 fn derive_unit_health(
     kind: UnitKind,
     state: UnitState,
@@ -1505,19 +1648,67 @@ fn derive_unit_health(
     lifecycle: Option<ServiceLifecycleStatus>,
     runtime: Option<&ProcessRuntime>,
     cron: Option<&CronUnitStatus>,
+    policy: &HealthPolicy,
+) -> UnitHealth {
+    derive_unit_health_traced(
+        kind, state, intent, lifecycle, runtime, cron, policy, &mut None,
+    )
+}
+
+/// [`derive_unit_health`], recording each rule it checks into `trace` when one
+/// is given. Both share this body so `status --explain` can never drift from
+/// the verdict in the status table.
+#[allow(clippy::too_many_arguments)]
+fn derive_unit_health_traced(
+    kind: UnitKind,
+    state: UnitState,
+    intent: UnitIntent,
+    lifecycle: Option<ServiceLifecycleStatus>,
+    runtime: Option<&ProcessRuntime>,
+    cron: Option<&CronUnitStatus>,
+    policy: &HealthPolicy,
+    trace: &mut Option<Vec<String>>,
 ) -> UnitHealth {
     if let Some(runtime) = runtime {
         match runtime.state {
-            ProcessState::Running => return UnitHealth::Healthy,
+            ProcessState::Running => {
+                trace_step(trace, || {
+                    format!("process {} is running -> healthy", runtime.pid)
+                });
+                return UnitHealth::Healthy;
+            }
             ProcessState::Zombie => {
+                trace_step(trace, || {
+                    format!("process {} is a zombie -> failing", runtime.pid)
+                });
                 return UnitHealth::Failing;
             }
             ProcessState::Missing => {
                 if matches!(intent, UnitIntent::Serve) {
-                    return UnitHealth::Warn;
+                    trace_step(trace, || {
+                        format!(
+                            "tracked PID {} is gone and the intent is serve",
+                            runtime.pid
+                        )
+                    });
+                    return policed_health(
+                        trace,
+                        "missing_process",
+                        policy.missing_process,
+                        UnitHealth::Warn,
+                    );
                 }
+                trace_step(trace, || {
+                    format!(
+                        "tracked PID {} is gone, which a {} unit does not act on",
+                        runtime.pid,
+                        label(&intent)
+                    )
+                });
             }
         }
+    } else {
+        trace_step(trace, || "no process is tracked".to_string());
     }
 
     if let Some(cron_status) = cron {
@@ -1525,47 +1716,248 @@ fn derive_unit_health(
             && let Some(status) = &last.status
         {
             return match status {
-                CronExecutionStatus::Success => UnitHealth::Healthy,
+                CronExecutionStatus::Success => {
+                    trace_step(trace, || {
+                        "last cron run succeeded -> healthy".to_string()
+                    });
+                    UnitHealth::Healthy
+                }
                 CronExecutionStatus::Failed(reason) => {
                     if reason.contains("Failed to get PID") {
+                        trace_step(trace, || {
+                            "last cron run exited before its PID was captured -> idle"
+                                .to_string()
+                        });
                         UnitHealth::Idle
                     } else {
-                        UnitHealth::Failing
+                        trace_step(trace, || format!("last cron run failed: {reason}"));
+                        policed_health(
+                            trace,
+                            "cron_failure",
+                            policy.cron_failure,
+                            UnitHealth::Failing,
+                        )
                     }
                 }
-                CronExecutionStatus::Interrupted(_) => UnitHealth::Idle,
-                CronExecutionStatus::OverlapError => UnitHealth::Warn,
+                CronExecutionStatus::Interrupted(reason) => {
+                    trace_step(trace, || {
+                        format!("last cron run was interrupted ({reason}) -> idle")
+                    });
+                    UnitHealth::Idle
+                }
+                CronExecutionStatus::OverlapError => {
+                    trace_step(trace, || {
+                        "last cron run overlapped the previous one".to_string()
+                    });
+                    policed_health(
+                        trace,
+                        "cron_overlap",
+                        policy.cron_overlap,
+                        UnitHealth::Warn,
+                    )
+                }
             };
         }
 
+        trace_step(trace, || {
+            "cron job has no finished run yet -> idle".to_string()
+        });
         return UnitHealth::Idle;
     }
 
     match lifecycle {
-        Some(ServiceLifecycleStatus::ExitedWithError) => return UnitHealth::Failing,
-        Some(ServiceLifecycleStatus::Running) => return UnitHealth::Healthy,
+        Some(ServiceLifecycleStatus::ExitedWithError) => {
+            trace_step(trace, || "lifecycle is exited_with_error".to_string());
+            return policed_health(
+                trace,
+                "exited_with_error",
+                policy.exited_with_error,
+                UnitHealth::Failing,
+            );
+        }
+        Some(ServiceLifecycleStatus::GaveUp) => {
+            trace_step(trace, || {
+                "lifecycle is gave_up: max_restarts is exhausted -> failing".to_string()
+            });
+            return UnitHealth::Failing;
+        }
+        Some(ServiceLifecycleStatus::Running) => {
+            trace_step(trace, || "lifecycle is running -> healthy".to_string());
+            return UnitHealth::Healthy;
+        }
         Some(ServiceLifecycleStatus::Skipped) => {
+            trace_step(trace, || "lifecycle is skipped -> idle".to_string());
             return UnitHealth::Idle;
         }
         Some(ServiceLifecycleStatus::Stopped) => {
             if matches!(intent, UnitIntent::Serve) {
-                return UnitHealth::Warn;
+                trace_step(trace, || {
+                    "lifecycle is stopped and the intent is serve".to_string()
+                });
+                return policed_health(
+                    trace,
+                    "stopped",
+                    policy.stopped,
+                    UnitHealth::Warn,
+                );
             }
+            trace_step(trace, || {
+                format!(
+                    "lifecycle is stopped, which a {} unit allows -> idle",
+                    label(&intent)
+                )
+            });
             return UnitHealth::Idle;
         }
         Some(ServiceLifecycleStatus::ExitedSuccessfully) => {
+            trace_step(trace, || {
+                "lifecycle is exited_successfully -> healthy".to_string()
+            });
             return UnitHealth::Healthy;
         }
-        None => {}
+        None => trace_step(trace, || "no lifecycle is recorded".to_string()),
     }
 
-    match (state, kind) {
+    let health = match (state, kind) {
         (UnitState::Lost, _) => UnitHealth::Warn,
         (UnitState::Zombie | UnitState::Failed, _) => UnitHealth::Failing,
         (UnitState::Queued | UnitState::Stopped | UnitState::Skipped, _) => {
             UnitHealth::Idle
         }
         (_, UnitKind::Cron | UnitKind::Service | UnitKind::Orphaned) => UnitHealth::Warn,
+    };
+    trace_step(trace, || {
+        format!(
+            "falling back to the {} state -> {}",
+            label(&state),
+            label(&health)
+        )
+    });
+    health
+}
+
+/// Records one rule `derive_unit_health_traced` checked, when tracing.
+fn trace_step(trace: &mut Option<Vec<String>>, step: impl FnOnce() -> String) {
+    if let Some(trace) = trace.as_mut() {
+        trace.push(step());
+    }
+}
+
+/// Applies a health-policy override to a rule's default verdict, noting which
+/// one won.
+fn policed_health(
+    trace: &mut Option<Vec<String>>,
+    field: &str,
+    value: Option<UnitHealth>,
+    default: UnitHealth,
+) -> UnitHealth {
+    let health = value.unwrap_or(default);
+    trace_step(trace, || {
+        if health == default {
+            format!("`{field}` -> {}", label(&health))
+        } else {
+            format!(
+                "health policy `{field}` reclassifies {} as {}",
+                label(&default),
+                label(&health)
+            )
+        }
+    });
+    health
+}
+
+/// The snake_case name a status enum serializes as, for human output.
+fn label<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+/// The inputs `derive_unit_health` read for one unit and the rules it checked,
+/// in order, on the way to its verdict.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthTrace {
+    /// The verdict the trace arrives at.
+    pub health: UnitHealth,
+    /// `(input, value)` pairs the decision read.
+    pub inputs: Vec<(&'static str, String)>,
+    /// One line per rule checked, ending with the one that decided.
+    pub steps: Vec<String>,
+}
+
+/// Replays `derive_unit_health` for `unit` under `policy`, recording why it
+/// lands on its health. `policy` should be the one the supervisor used: the
+/// project's `status.health` overlaid with the service's own `health` block.
+pub fn trace_unit_health(unit: &UnitStatus, policy: &HealthPolicy) -> HealthTrace {
+    let mut inputs = vec![
+        ("kind", label(&unit.kind)),
+        ("intent", label(&unit.intent)),
+        ("state", label(&unit.state)),
+        (
+            "lifecycle",
+            unit.lifecycle
+                .map(|lifecycle| label(&lifecycle))
+                .unwrap_or_else(|| "none".to_string()),
+        ),
+        (
+            "process",
+            unit.process
+                .as_ref()
+                .map(|runtime| format!("PID {} {}", runtime.pid, label(&runtime.state)))
+                .unwrap_or_else(|| "none tracked".to_string()),
+        ),
+    ];
+    if let Some(exit) = describe_exit(unit.last_exit.as_ref()) {
+        inputs.push(("last exit", exit));
+    }
+    if let Some(cron) = unit.cron.as_ref() {
+        let last_run = cron
+            .last_run
+            .as_ref()
+            .and_then(|run| run.status.as_ref())
+            .map(|status| match status {
+                CronExecutionStatus::Success => "success".to_string(),
+                CronExecutionStatus::Failed(reason) => format!("failed: {reason}"),
+                CronExecutionStatus::Interrupted(reason) => {
+                    format!("interrupted: {reason}")
+                }
+                CronExecutionStatus::OverlapError => "overlap".to_string(),
+            })
+            .unwrap_or_else(|| "none yet".to_string());
+        inputs.push(("cron last run", last_run));
+    }
+    let overrides = serde_json::to_value(policy)
+        .ok()
+        .and_then(|value| value.as_object().cloned())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(field, health)| format!("{field}={}", health.as_str().unwrap_or_default()))
+        .collect::<Vec<_>>();
+    inputs.push((
+        "health policy",
+        if overrides.is_empty() {
+            "defaults".to_string()
+        } else {
+            overrides.join(", ")
+        },
+    ));
+
+    let mut trace = Some(Vec::new());
+    let health = derive_unit_health_traced(
+        unit.kind,
+        unit.state,
+        unit.intent,
+        unit.lifecycle,
+        unit.process.as_ref(),
+        unit.cron.as_ref(),
+        policy,
+        &mut trace,
+    );
+    HealthTrace {
+        health,
+        inputs,
+        steps: trace.unwrap_or_default(),
     }
 }
 
@@ -1609,6 +2001,7 @@ pub fn explain_unit_health(unit: &UnitStatus) -> HealthReport {
     let name = unit.name.as_str();
     let restart = format!("sysg restart -s {name} --log-level debug");
     let logs = format!("sysg logs -s {name} -l 200");
+    let start = format!("sysg start -s {name}");
 
     if let Some(runtime) = unit.process.as_ref() {
         match runtime.state {
@@ -1798,6 +2191,30 @@ A service that should stay available has crashed or returned a non-zero status."
                 ),
             };
         }
+        Some(ServiceLifecycleStatus::GaveUp) => {
+            let attempts = unit
+                .gave_up_after
+                .map(|max| format!(" after {max} restarts"))
+                .unwrap_or_default();
+            let exit = describe_exit(unit.last_exit.as_ref())
+                .map(|phrase| format!(" It last {phrase}."))
+                .unwrap_or_default();
+            return HealthReport {
+                health: UnitHealth::Failing,
+                severity: 9,
+                title: format!("'{name}' was given up on{attempts}"),
+                tldr: "The service kept failing and is no longer restarted.".to_string(),
+                description: format!(
+                    "'{name}' kept exiting unsuccessfully until its restart policy hit \
+`max_restarts`, so systemg stopped restarting it.{exit} It stays down until it is \
+started again."
+                ),
+                recommended_fix: format!(
+                    "Read the logs to find why it keeps failing, then start it again, \
+which re-arms its restarts:\n\n    {logs}\n    {start}"
+                ),
+            };
+        }
         Some(ServiceLifecycleStatus::Running) => {
             return HealthReport {
                 health: UnitHealth::Healthy,
@@ -2084,6 +2501,40 @@ impl StatusManager {
         }
     }
 
+    /// Builds the full status snapshot from persisted state, without a running
+    /// supervisor.
+    ///
+    /// The pid, service-state, and cron files are read from disk for
+    /// `config`'s project, or from the loose store when `config` is `None`.
+    /// The manager's own pid and state handles are left untouched.
+    ///
+    /// ```no_run
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// use systemg::{
+    ///     config::load_config,
+    ///     daemon::{PidFile, ServiceStateFile},
+    ///     status::StatusManager,
+    /// };
+    ///
+    /// let config = load_config(Some("systemg.yaml"))?;
+    /// let manager = StatusManager::new(
+    ///     Arc::new(Mutex::new(PidFile::default())),
+    ///     Arc::new(Mutex::new(ServiceStateFile::default())),
+    /// );
+    /// let snapshot = manager.snapshot(Some(&config))?;
+    /// for unit in &snapshot.units {
+    ///     println!("{}: {:?}", unit.name, unit.health);
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn snapshot(
+        &self,
+        config: Option<&Config>,
+    ) -> Result<StatusSnapshot, StatusError> {
+        disk_snapshot(config, true)
+    }
+
     /// Clears service pid.
     fn clear_service_pid(&self, service_name: &str, service_hash: &str) {
         if let Ok(mut guard) = self.pid_file.lock() {
@@ -2366,7 +2817,8 @@ impl StatusManager {
                 | ServiceLifecycleStatus::ExitedSuccessfully => {
                     return GREEN_BOLD;
                 }
-                ServiceLifecycleStatus::ExitedWithError => {
+                ServiceLifecycleStatus::ExitedWithError
+                | ServiceLifecycleStatus::GaveUp => {
                     return RED_BOLD;
                 }
                 ServiceLifecycleStatus::Stopped | ServiceLifecycleStatus::Skipped => {
@@ -2533,6 +2985,17 @@ impl StatusManager {
                     println!("● {} - Stopped", display_name);
                     return;
                 }
+                ServiceLifecycleStatus::GaveUp => {
+                    let limit = entry
+                        .gave_up_after
+                        .map(|max| format!(" after {max} restarts"))
+                        .unwrap_or_default();
+                    println!(
+                        "● {} - {}Failed (gave up{}){}",
+                        display_name, RED_BOLD, limit, RESET
+                    );
+                    return;
+                }
                 ServiceLifecycleStatus::Running => {}
             }
         }
@@ -2842,7 +3305,7 @@ mod tests {
     use tempfile::tempdir_in;
 
     use super::*;
-    use crate::{daemon::PersistedSpawnChild, spawn::SpawnedExit};
+    use crate::{config::RestartPolicy, daemon::PersistedSpawnChild, spawn::SpawnedExit};
 
     #[test]
     fn process_index_maps_children_from_single_refresh() {
@@ -2863,6 +3326,135 @@ mod tests {
         assert!(found, "process index should map parent pid to child pid");
     }
 
+    #[test]
+    fn unit_status_document_inlines_the_unit_under_the_schema_version() {
+        let unit = |name: &str| UnitStatus {
+            name: name.into(),
+            hash: name.into(),
+            project: None,
+            kind: UnitKind::Service,
+            lifecycle: None,
+            state: UnitState::Running,
+            intent: UnitIntent::Serve,
+            health: UnitHealth::Healthy,
+            process: None,
+            uptime: None,
+            last_exit: None,
+            cron: None,
+            metrics: None,
+            command: None,
+            runtime_command: None,
+            spawned_children: Vec::new(),
+            drifted: false,
+            restarts: 0,
+            gave_up_after: None,
+        };
+        let snapshot = StatusSnapshot::new(vec![unit("api"), unit("web")]);
+
+        let document = snapshot
+            .unit_status(|unit| unit.name == "web")
+            .expect("web is in the snapshot");
+        let value = serde_json::to_value(&document).unwrap();
+        assert_eq!(value["schema_version"], json!(STATUS_SCHEMA_VERSION));
+        assert_eq!(value["name"], json!("web"));
+        assert_eq!(value["health"], json!("healthy"));
+        assert!(snapshot.unit_status(|unit| unit.name == "db").is_none());
+    }
+
+    #[test]
+    fn unit_status_document_round_trips_cron_history_with_failure_reasons() {
+        let started = Utc::now();
+        let failed = CronExecutionSummary {
+            started_at: started,
+            completed_at: Some(started + chrono::Duration::seconds(2)),
+            status: Some(CronExecutionStatus::Failed("backup volume is full".into())),
+            exit_code: Some(2),
+            pid: Some(4242),
+            user: Some("backup".into()),
+            command: Some("./backup.sh".into()),
+            metrics: vec![crate::metrics::MetricSample {
+                timestamp: started + chrono::Duration::seconds(1),
+                cpu_percent: 12.5,
+                rss_bytes: 4096,
+                io_read_bytes: 0,
+                io_write_bytes: 512,
+                net_rx_bytes: 0,
+                net_tx_bytes: 0,
+            }],
+            manual: true,
+        };
+        let succeeded = CronExecutionSummary {
+            started_at: started - chrono::Duration::hours(1),
+            completed_at: Some(started - chrono::Duration::hours(1)),
+            status: Some(CronExecutionStatus::Success),
+            exit_code: Some(0),
+            pid: None,
+            user: None,
+            command: None,
+            metrics: vec![],
+            manual: false,
+        };
+        let snapshot = StatusSnapshot::new(vec![UnitStatus {
+            name: "backup".into(),
+            hash: "backup".into(),
+            project: None,
+            kind: UnitKind::Cron,
+            lifecycle: None,
+            state: UnitState::Failed,
+            intent: UnitIntent::Cron,
+            health: UnitHealth::Failing,
+            process: None,
+            uptime: None,
+            last_exit: None,
+            cron: Some(CronUnitStatus {
+                timezone_label: "Europe/Berlin".into(),
+                timezone: Some("Europe/Berlin".into()),
+                last_run: Some(failed.clone()),
+                recent_runs: vec![failed, succeeded],
+            }),
+            metrics: None,
+            command: None,
+            runtime_command: None,
+            spawned_children: Vec::new(),
+            drifted: false,
+            restarts: 0,
+            gave_up_after: None,
+        }]);
+
+        let document = snapshot
+            .unit_status(|unit| unit.name == "backup")
+            .expect("backup is in the snapshot");
+        let json = serde_json::to_string(&document).unwrap();
+        let decoded: UnitStatusDocument = serde_json::from_str(&json).unwrap();
+
+        let cron = decoded
+            .unit
+            .cron
+            .expect("cron status survives the round trip");
+        assert_eq!(cron.timezone.as_deref(), Some("Europe/Berlin"));
+        assert_eq!(cron.recent_runs.len(), 2);
+        let run = &cron.recent_runs[0];
+        assert_eq!(
+            run.status,
+            Some(CronExecutionStatus::Failed("backup volume is full".into()))
+        );
+        assert_eq!(run.started_at, started);
+        assert_eq!(
+            run.completed_at,
+            Some(started + chrono::Duration::seconds(2))
+        );
+        assert_eq!(run.exit_code, Some(2));
+        assert_eq!(run.pid, Some(4242));
+        assert_eq!(run.user.as_deref(), Some("backup"));
+        assert!(run.manual);
+        assert_eq!(run.metrics.len(), 1);
+        assert_eq!(run.metrics[0].rss_bytes, 4096);
+        assert_eq!(
+            cron.recent_runs[1].status,
+            Some(CronExecutionStatus::Success)
+        );
+    }
+
     #[test]
     fn format_cron_status_success_includes_green_exit_code() {
         let record = CronExecutionRecord {
@@ -2875,6 +3467,8 @@ mod tests {
             user: None,
             command: None,
             metrics: vec![],
+            manual: false,
+            output: vec![],
         };
 
         let formatted = StatusManager::format_cron_status(&record);
@@ -2894,6 +3488,8 @@ mod tests {
             user: None,
             command: None,
             metrics: vec![],
+            manual: false,
+            output: vec![],
         };
 
         let formatted = StatusManager::format_cron_status(&record);
@@ -3164,7 +3760,8 @@ services:
             .set(&hash, ServiceLifecycleStatus::Running, Some(42), None, None)
             .expect("persist running state");
 
-        let snapshot = collect_disk_snapshot(Some(config)).expect("collect snapshot");
+        let snapshot =
+            collect_disk_snapshot(Some(config), true).expect("collect snapshot");
         let unit = snapshot
             .units
             .iter()
@@ -3200,6 +3797,13 @@ services:
             metrics: crate::config::MetricsConfig::default(),
             logs: crate::config::LogsConfig::default(),
             status: crate::config::StatusConfig::default(),
+            deployment: crate::config::ProjectDeploymentConfig::default(),
+            on_ready: None,
+            on_start_failure: None,
+            on_failure: Default::default(),
+            dependency_settle: None,
+            supervisor: Default::default(),
+            env_overrides: Default::default(),
         };
 
         let pid_file = PidFile::default();
@@ -3227,6 +3831,7 @@ services:
             None,
             None,
             StatusSnapshotMode::Off,
+            true,
         );
 
         let orphan_cron_units: Vec<_> = snapshot
@@ -3251,6 +3856,8 @@ services:
             cron: Some(crate::config::CronConfig {
                 expression: "* * * * *".into(),
                 timezone: None,
+                history_limit: None,
+                history_retention: None,
             }),
             ..crate::config::ServiceConfig::default()
         };
@@ -3264,6 +3871,13 @@ services:
             metrics: crate::config::MetricsConfig::default(),
             logs: crate::config::LogsConfig::default(),
             status: crate::config::StatusConfig::default(),
+            deployment: crate::config::ProjectDeploymentConfig::default(),
+            on_ready: None,
+            on_start_failure: None,
+            on_failure: Default::default(),
+            dependency_settle: None,
+            supervisor: Default::default(),
+            env_overrides: Default::default(),
         };
         let hash = config.state_key("nightly");
 
@@ -3299,6 +3913,7 @@ services:
             None,
             None,
             StatusSnapshotMode::Summary,
+            true,
         );
 
         let unit = snapshot
@@ -3323,6 +3938,8 @@ services:
             cron: Some(crate::config::CronConfig {
                 expression: "* * * * *".into(),
                 timezone: Some("UTC".into()),
+                history_limit: None,
+                history_retention: None,
             }),
             ..crate::config::ServiceConfig::default()
         };
@@ -3337,6 +3954,13 @@ services:
             metrics: crate::config::MetricsConfig::default(),
             logs: crate::config::LogsConfig::default(),
             status: crate::config::StatusConfig::default(),
+            deployment: crate::config::ProjectDeploymentConfig::default(),
+            on_ready: None,
+            on_start_failure: None,
+            on_failure: Default::default(),
+            dependency_settle: None,
+            supervisor: Default::default(),
+            env_overrides: Default::default(),
         };
 
         let pid_file = PidFile::default();
@@ -3371,6 +3995,7 @@ services:
             None,
             None,
             StatusSnapshotMode::Summary,
+            true,
         );
 
         let unit = snapshot
@@ -3391,7 +4016,7 @@ services:
         let mut services = std::collections::HashMap::new();
         let service = crate::config::ServiceConfig {
             command: "/bin/echo hi".into(),
-            restart_policy: Some("never".into()),
+            restart_policy: Some(RestartPolicy::Never),
             ..crate::config::ServiceConfig::default()
         };
         services.insert("migrate".into(), service);
@@ -3404,6 +4029,13 @@ services:
             metrics: crate::config::MetricsConfig::default(),
             logs: crate::config::LogsConfig::default(),
             status: crate::config::StatusConfig::default(),
+            deployment: crate::config::ProjectDeploymentConfig::default(),
+            on_ready: None,
+            on_start_failure: None,
+            on_failure: Default::default(),
+            dependency_settle: None,
+            supervisor: Default::default(),
+            env_overrides: Default::default(),
         };
         let hash = config.state_key("migrate");
 
@@ -3428,6 +4060,7 @@ services:
             None,
             None,
             StatusSnapshotMode::Summary,
+            true,
         );
 
         let unit = snapshot
@@ -3449,7 +4082,7 @@ services:
         let mut services = std::collections::HashMap::new();
         let service = crate::config::ServiceConfig {
             command: "/bin/sleep 30".into(),
-            restart_policy: Some("always".into()),
+            restart_policy: Some(RestartPolicy::Always),
             ..crate::config::ServiceConfig::default()
         };
         services.insert("api".into(), service);
@@ -3462,6 +4095,13 @@ services:
             metrics: crate::config::MetricsConfig::default(),
             logs: crate::config::LogsConfig::default(),
             status: crate::config::StatusConfig::default(),
+            deployment: crate::config::ProjectDeploymentConfig::default(),
+            on_ready: None,
+            on_start_failure: None,
+            on_failure: Default::default(),
+            dependency_settle: None,
+            supervisor: Default::default(),
+            env_overrides: Default::default(),
         };
         let hash = config.state_key("api");
 
@@ -3486,6 +4126,7 @@ services:
             None,
             None,
             StatusSnapshotMode::Summary,
+            true,
         );
 
         let unit = snapshot
@@ -3502,6 +4143,82 @@ services:
         assert_eq!(unit.health, UnitHealth::Warn);
     }
 
+    #[test]
+    fn build_snapshot_flags_services_launched_from_an_older_config() {
+        let mut services = std::collections::HashMap::new();
+        for name in ["api", "worker"] {
+            let service = crate::config::ServiceConfig {
+                command: "/bin/sleep 30".into(),
+                ..crate::config::ServiceConfig::default()
+            };
+            services.insert(name.to_string(), service);
+        }
+        let config = Config {
+            version: crate::config::Version::V2,
+            project: crate::config::ProjectConfig::default(),
+            services,
+            project_dir: None,
+            env: None,
+            metrics: crate::config::MetricsConfig::default(),
+            logs: crate::config::LogsConfig::default(),
+            status: crate::config::StatusConfig::default(),
+            deployment: crate::config::ProjectDeploymentConfig::default(),
+            on_ready: None,
+            on_start_failure: None,
+            on_failure: Default::default(),
+            dependency_settle: None,
+            supervisor: Default::default(),
+            env_overrides: Default::default(),
+        };
+
+        let mut service_state = ServiceStateFile::default();
+        for name in ["api", "worker"] {
+            service_state.set_in_memory(
+                &config.state_key(name),
+                ServiceLifecycleStatus::Running,
+                Some(std::process::id()),
+                None,
+                None,
+            );
+        }
+        let launched_from = |name: &str| {
+            if name == "api" {
+                "stale".to_string()
+            } else {
+                config.services[name].compute_hash()
+            }
+        };
+        let mut state_json = serde_json::to_value(&service_state).expect("serialize");
+        for entry in state_json["services"].as_array_mut().expect("entries") {
+            let name = service_from_key(entry["name"].as_str().expect("name"));
+            entry["state"]["config_hash"] = json!(launched_from(&name));
+        }
+        let mut service_state: ServiceStateFile =
+            serde_json::from_value(state_json).expect("deserialize");
+
+        let snapshot = build_snapshot(
+            Some(&config),
+            &PidFile::default(),
+            &mut service_state,
+            &mut CronStateFile::default(),
+            None,
+            None,
+            StatusSnapshotMode::Off,
+            true,
+        );
+
+        let drifted = |name: &str| {
+            snapshot
+                .units
+                .iter()
+                .find(|unit| unit.name == name)
+                .expect("unit")
+                .drifted
+        };
+        assert!(drifted("api"));
+        assert!(!drifted("worker"));
+    }
+
     #[test]
     fn missing_pid_is_expected_only_for_finished_cron_and_oneshot() {
         let success = CronExecutionSummary {
@@ -3513,6 +4230,7 @@ services:
             user: None,
             command: None,
             metrics: vec![],
+            manual: false,
         };
         let completed_cron = CronUnitStatus {
             timezone_label: "UTC".into(),
@@ -3593,6 +4311,13 @@ services:
             metrics: crate::config::MetricsConfig::default(),
             logs: crate::config::LogsConfig::default(),
             status: crate::config::StatusConfig::default(),
+            deployment: crate::config::ProjectDeploymentConfig::default(),
+            on_ready: None,
+            on_start_failure: None,
+            on_failure: Default::default(),
+            dependency_settle: None,
+            supervisor: Default::default(),
+            env_overrides: Default::default(),
         };
         let hash = config.state_key("demo");
 
@@ -3630,6 +4355,7 @@ services:
             None,
             None,
             StatusSnapshotMode::Summary,
+            true,
         );
         let unit = snapshot
             .units
@@ -3643,6 +4369,27 @@ services:
         assert!(unit.runtime_command.is_none());
         assert!(unit.spawned_children.is_empty());
 
+        let detailed = |include_children| {
+            build_snapshot(
+                Some(&config),
+                &pid_file,
+                &mut service_state.clone(),
+                &mut CronStateFile::default(),
+                None,
+                None,
+                StatusSnapshotMode::Detailed,
+                include_children,
+            )
+            .units
+            .into_iter()
+            .find(|unit| unit.name == "demo")
+            .expect("demo unit")
+        };
+        assert_eq!(detailed(true).spawned_children.len(), 1);
+        let unit = detailed(false);
+        assert!(unit.process.as_ref().unwrap().user.is_some());
+        assert!(unit.spawned_children.is_empty());
+
         unsafe {
             if let Some(home) = original_home {
                 env::set_var("HOME", home);
@@ -3674,6 +4421,9 @@ services:
                 command: None,
                 runtime_command: None,
                 spawned_children: Vec::new(),
+                drifted: false,
+                restarts: 0,
+                gave_up_after: None,
             },
             UnitStatus {
                 name: "svc-b".into(),
@@ -3692,6 +4442,9 @@ services:
                 command: None,
                 runtime_command: None,
                 spawned_children: Vec::new(),
+                drifted: false,
+                restarts: 0,
+                gave_up_after: None,
             },
         ];
 
@@ -3709,6 +4462,7 @@ services:
             user: None,
             command: None,
             metrics: vec![],
+            manual: false,
         };
 
         let cron_status = CronUnitStatus {
@@ -3725,6 +4479,7 @@ services:
             None,
             None,
             Some(&cron_status),
+            &HealthPolicy::default(),
         );
         assert_eq!(health, UnitHealth::Healthy);
     }
@@ -3741,6 +4496,7 @@ services:
             user: None,
             command: None,
             metrics: vec![],
+            manual: false,
         };
 
         let cron_status = CronUnitStatus {
@@ -3757,6 +4513,7 @@ services:
             None,
             None,
             Some(&cron_status),
+            &HealthPolicy::default(),
         );
         assert_eq!(health, UnitHealth::Failing);
     }
@@ -3777,6 +4534,7 @@ services:
             None,
             None,
             Some(&cron_status),
+            &HealthPolicy::default(),
         );
         assert_eq!(health, UnitHealth::Idle);
     }
@@ -3790,6 +4548,7 @@ services:
             Some(ServiceLifecycleStatus::ExitedWithError),
             None,
             None,
+            &HealthPolicy::default(),
         );
         assert_eq!(health, UnitHealth::Failing);
     }
@@ -3803,6 +4562,7 @@ services:
             Some(ServiceLifecycleStatus::Stopped),
             None,
             None,
+            &HealthPolicy::default(),
         );
 
         assert_eq!(health, UnitHealth::Warn);
@@ -3817,6 +4577,7 @@ services:
             Some(ServiceLifecycleStatus::ExitedSuccessfully),
             None,
             None,
+            &HealthPolicy::default(),
         );
 
         assert_eq!(health, UnitHealth::Healthy);
@@ -3831,6 +4592,7 @@ services:
             Some(ServiceLifecycleStatus::ExitedSuccessfully),
             None,
             None,
+            &HealthPolicy::default(),
         );
 
         assert_eq!(health, UnitHealth::Healthy);
@@ -3847,6 +4609,7 @@ services:
             user: None,
             command: None,
             metrics: vec![],
+            manual: false,
         };
         let cron_status = CronUnitStatus {
             timezone_label: "UTC".into(),
@@ -3867,6 +4630,7 @@ services:
             None,
             Some(&runtime),
             Some(&cron_status),
+            &HealthPolicy::default(),
         );
         assert_eq!(health, UnitHealth::Healthy);
     }
@@ -3886,6 +4650,7 @@ services:
             Some(ServiceLifecycleStatus::ExitedSuccessfully),
             Some(&runtime),
             None,
+            &HealthPolicy::default(),
         );
         assert_eq!(health, UnitHealth::Healthy);
     }
@@ -3905,8 +4670,77 @@ services:
             Some(ServiceLifecycleStatus::Running),
             Some(&runtime),
             None,
+            &HealthPolicy::default(),
+        );
+        assert_eq!(health, UnitHealth::Warn);
+    }
+
+    #[test]
+    fn derive_unit_health_honors_policy_overrides() {
+        let strict = HealthPolicy {
+            missing_process: Some(UnitHealth::Failing),
+            cron_failure: Some(UnitHealth::Warn),
+            ..HealthPolicy::default()
+        };
+        let runtime = ProcessRuntime {
+            pid: 17165,
+            state: ProcessState::Missing,
+            user: None,
+        };
+        let missing = |policy: &HealthPolicy| {
+            derive_unit_health(
+                UnitKind::Service,
+                UnitState::Lost,
+                UnitIntent::Serve,
+                Some(ServiceLifecycleStatus::Running),
+                Some(&runtime),
+                None,
+                policy,
+            )
+        };
+        assert_eq!(missing(&HealthPolicy::default()), UnitHealth::Warn);
+        assert_eq!(missing(&strict), UnitHealth::Failing);
+
+        let now = Utc::now();
+        let failed = CronExecutionSummary {
+            started_at: now,
+            completed_at: Some(now),
+            status: Some(CronExecutionStatus::Failed("exit status 1".into())),
+            exit_code: Some(1),
+            pid: None,
+            user: None,
+            command: None,
+            metrics: vec![],
+            manual: false,
+        };
+        let cron_status = CronUnitStatus {
+            timezone_label: "UTC".into(),
+            timezone: Some("UTC".into()),
+            last_run: Some(failed.clone()),
+            recent_runs: vec![failed],
+        };
+        let health = derive_unit_health(
+            UnitKind::Cron,
+            UnitState::Failed,
+            UnitIntent::Cron,
+            None,
+            None,
+            Some(&cron_status),
+            &strict,
         );
         assert_eq!(health, UnitHealth::Warn);
+
+        // Conditions the policy leaves unset keep their built-in verdict.
+        let stopped = derive_unit_health(
+            UnitKind::Service,
+            UnitState::Stopped,
+            UnitIntent::Serve,
+            Some(ServiceLifecycleStatus::Stopped),
+            None,
+            None,
+            &strict,
+        );
+        assert_eq!(stopped, UnitHealth::Warn);
     }
 
     fn unit_for_health(name: &str) -> UnitStatus {
@@ -3927,6 +4761,9 @@ services:
             command: None,
             runtime_command: None,
             spawned_children: Vec::new(),
+            drifted: false,
+            restarts: 0,
+            gave_up_after: None,
         }
     }
 
@@ -3966,6 +4803,7 @@ services:
         unit.last_exit = Some(ExitMetadata {
             exit_code: Some(2),
             signal: None,
+            at: None,
         });
 
         let report = explain_unit_health(&unit);
@@ -3974,6 +4812,23 @@ services:
         assert!(report.description.contains("exited with code 2"));
     }
 
+    #[test]
+    fn explain_unit_health_for_gave_up_service_points_at_start() {
+        let mut unit = unit_for_health("worker");
+        unit.intent = UnitIntent::Serve;
+        unit.lifecycle = Some(ServiceLifecycleStatus::GaveUp);
+        unit.gave_up_after = Some(3);
+
+        let report = explain_unit_health(&unit);
+        assert_eq!(report.health, UnitHealth::Failing);
+        assert_eq!(report.title, "'worker' was given up on after 3 restarts");
+        assert!(report.recommended_fix.contains("sysg start -s worker"));
+        assert_eq!(
+            derive_unit_state(UnitKind::Service, unit.lifecycle, None, None),
+            UnitState::Failed
+        );
+    }
+
     #[test]
     fn explain_unit_health_for_successful_serve_exit_is_healthy() {
         let mut unit = unit_for_health("worker");
@@ -3983,6 +4838,7 @@ services:
         unit.last_exit = Some(ExitMetadata {
             exit_code: Some(0),
             signal: None,
+            at: None,
         });
 
         let report = explain_unit_health(&unit);
@@ -4002,6 +4858,7 @@ services:
             user: None,
             command: None,
             metrics: vec![],
+            manual: false,
         };
         let mut unit = unit_for_health("nightly");
         unit.kind = UnitKind::Cron;
@@ -4034,6 +4891,7 @@ services:
             user: None,
             command: None,
             metrics: vec![],
+            manual: false,
         };
         let mut unit = unit_for_health("curate_tiktok");
         unit.kind = UnitKind::Cron;
@@ -4087,6 +4945,7 @@ services:
             unit.lifecycle,
             unit.process.as_ref(),
             unit.cron.as_ref(),
+            &HealthPolicy::default(),
         );
         assert_eq!(explain_unit_health(&unit).health, derived);
     }
@@ -4102,6 +4961,7 @@ services:
             user: None,
             command: None,
             metrics: vec![],
+            manual: false,
         };
         let mut unit = unit_for_health("curate_tiktok");
         unit.kind = UnitKind::Cron;
@@ -4125,7 +4985,70 @@ services:
             unit.lifecycle,
             unit.process.as_ref(),
             unit.cron.as_ref(),
+            &HealthPolicy::default(),
         );
         assert_eq!(explain_unit_health(&unit).health, derived);
     }
+
+    #[test]
+    fn trace_unit_health_walks_to_the_stopped_rule() {
+        let mut unit = unit_for_health("api");
+        unit.intent = UnitIntent::Serve;
+        unit.lifecycle = Some(ServiceLifecycleStatus::Stopped);
+        unit.state = UnitState::Stopped;
+
+        let trace = trace_unit_health(&unit, &HealthPolicy::default());
+
+        assert_eq!(trace.health, UnitHealth::Warn);
+        assert_eq!(
+            trace.steps,
+            vec![
+                "no process is tracked".to_string(),
+                "lifecycle is stopped and the intent is serve".to_string(),
+                "`stopped` -> warn".to_string(),
+            ]
+        );
+        assert!(trace.inputs.contains(&("lifecycle", "stopped".to_string())));
+        assert!(
+            trace
+                .inputs
+                .contains(&("health policy", "defaults".to_string()))
+        );
+    }
+
+    #[test]
+    fn trace_unit_health_names_the_policy_override_that_decided() {
+        let mut unit = unit_for_health("api");
+        unit.lifecycle = Some(ServiceLifecycleStatus::ExitedWithError);
+        unit.state = UnitState::Failed;
+        let policy = HealthPolicy {
+            exited_with_error: Some(UnitHealth::Warn),
+            ..HealthPolicy::default()
+        };
+
+        let trace = trace_unit_health(&unit, &policy);
+
+        assert_eq!(trace.health, UnitHealth::Warn);
+        assert_eq!(
+            trace.steps.last().map(String::as_str),
+            Some("health policy `exited_with_error` reclassifies failing as warn")
+        );
+        assert!(
+            trace
+                .inputs
+                .contains(&("health policy", "exited_with_error=warn".to_string()))
+        );
+        assert_eq!(
+            trace.health,
+            derive_unit_health(
+                unit.kind,
+                unit.state,
+                unit.intent,
+                unit.lifecycle,
+                unit.process.as_ref(),
+                unit.cron.as_ref(),
+                &policy,
+            )
+        );
+    }
 }
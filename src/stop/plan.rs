@@ -5,7 +5,7 @@
 //! enum makes the compiler cover every dispatch case, so no combination of flags
 //! silently does the wrong thing.
 
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Duration};
 
 use crate::selector::{ProjectMismatch, Target, resolve_target};
 
@@ -31,9 +31,20 @@ pub enum StopPlan {
         /// `project/service` selector. `None` means "resolve from the resident
         /// supervisor", where SG0006 ambiguity is enforced.
         project: Option<String>,
+        /// How long the service gets to exit after SIGTERM before it is killed.
+        /// `None` uses the daemon's default grace period.
+        grace: Option<Duration>,
     },
     /// Shut the whole supervisor down (and with it every service).
     Supervisor,
+    /// Terminate and forget processes whose service is no longer in the
+    /// config, leaving every configured service alone.
+    Orphans {
+        /// The resolved config path (for a local stop without a supervisor).
+        config: PathBuf,
+        /// Limit the sweep to one project. `None` sweeps every project.
+        project: Option<String>,
+    },
 }
 
 /// A `--supervisor` flag combined with a unit selector, which is contradictory.
@@ -47,6 +58,15 @@ pub enum StopPlanError {
     Mismatch(ProjectMismatch),
     /// `--supervisor` was combined with a `-s`/`-p` selector.
     SupervisorWithSelector,
+    /// `--orphans` was combined with a service selector.
+    OrphansWithService,
+    /// `--timeout` was not a valid duration.
+    InvalidTimeout {
+        /// The rejected value.
+        value: String,
+        /// Why it was rejected.
+        reason: String,
+    },
 }
 
 /// Resolves the selectors into a [`StopPlan`]. `config` is the already-resolved
@@ -54,11 +74,15 @@ pub enum StopPlanError {
 ///
 /// `--supervisor` is exclusive: combining it with a `-s`/`-p` selector is a
 /// conflict, since you cannot both shut the supervisor down and target one unit.
+/// `--orphans` takes at most a `-p`: orphans have no configured service to name.
+/// `timeout` only applies to a single-service stop; the CLI enforces that.
 pub fn resolve_plan(
     config: PathBuf,
     service: Option<&str>,
     project: Option<&str>,
     supervisor: bool,
+    orphans: bool,
+    timeout: Option<&str>,
 ) -> Result<StopPlan, StopPlanError> {
     if supervisor {
         if service.is_some() || project.is_some() {
@@ -66,13 +90,35 @@ pub fn resolve_plan(
         }
         return Ok(StopPlan::Supervisor);
     }
+    if orphans {
+        if service.is_some() {
+            return Err(StopPlanError::OrphansWithService);
+        }
+        return Ok(StopPlan::Orphans {
+            config,
+            project: project.map(str::to_string),
+        });
+    }
+
+    let grace = timeout
+        .map(|raw| {
+            crate::daemon::Daemon::parse_duration(raw).map_err(|_| {
+                StopPlanError::InvalidTimeout {
+                    value: raw.to_string(),
+                    reason: "expected `<number>[ms|s|m|h]`".to_string(),
+                }
+            })
+        })
+        .transpose()?;
 
     match resolve_target(service, project).map_err(StopPlanError::Mismatch)? {
         Target::Everything => Ok(StopPlan::Everything { config }),
         Target::Project { project } => Ok(StopPlan::Project { project }),
-        Target::Service { service, project } => {
-            Ok(StopPlan::Service { service, project })
-        }
+        Target::Service { service, project } => Ok(StopPlan::Service {
+            service,
+            project,
+            grace,
+        }),
     }
 }
 
@@ -87,7 +133,7 @@ mod tests {
     #[test]
     fn no_selectors_stops_everything() {
         assert_eq!(
-            resolve_plan(cfg(), None, None, false).unwrap(),
+            resolve_plan(cfg(), None, None, false, false, None).unwrap(),
             StopPlan::Everything { config: cfg() }
         );
     }
@@ -95,7 +141,7 @@ mod tests {
     #[test]
     fn supervisor_flag_targets_the_supervisor() {
         assert_eq!(
-            resolve_plan(cfg(), None, None, true).unwrap(),
+            resolve_plan(cfg(), None, None, true, false, None).unwrap(),
             StopPlan::Supervisor
         );
     }
@@ -103,11 +149,11 @@ mod tests {
     #[test]
     fn supervisor_with_a_selector_is_a_conflict() {
         assert_eq!(
-            resolve_plan(cfg(), Some("web"), None, true).unwrap_err(),
+            resolve_plan(cfg(), Some("web"), None, true, false, None).unwrap_err(),
             StopPlanError::SupervisorWithSelector
         );
         assert_eq!(
-            resolve_plan(cfg(), None, Some("alpha"), true).unwrap_err(),
+            resolve_plan(cfg(), None, Some("alpha"), true, false, None).unwrap_err(),
             StopPlanError::SupervisorWithSelector
         );
     }
@@ -115,7 +161,7 @@ mod tests {
     #[test]
     fn project_flag_stops_one_project() {
         assert_eq!(
-            resolve_plan(cfg(), None, Some("alpha"), false).unwrap(),
+            resolve_plan(cfg(), None, Some("alpha"), false, false, None).unwrap(),
             StopPlan::Project {
                 project: "alpha".into()
             }
@@ -125,10 +171,11 @@ mod tests {
     #[test]
     fn bare_service_leaves_project_for_resident_resolution() {
         assert_eq!(
-            resolve_plan(cfg(), Some("worker"), None, false).unwrap(),
+            resolve_plan(cfg(), Some("worker"), None, false, false, None).unwrap(),
             StopPlan::Service {
                 service: "worker".into(),
-                project: None
+                project: None,
+                grace: None
             }
         );
     }
@@ -136,18 +183,26 @@ mod tests {
     #[test]
     fn qualified_selector_splits_project_and_service() {
         assert_eq!(
-            resolve_plan(cfg(), Some("alpha/worker"), None, false).unwrap(),
+            resolve_plan(cfg(), Some("alpha/worker"), None, false, false, None).unwrap(),
             StopPlan::Service {
                 service: "worker".into(),
-                project: Some("alpha".into())
+                project: Some("alpha".into()),
+                grace: None
             }
         );
     }
 
     #[test]
     fn project_flag_conflicting_with_selector_is_a_mismatch() {
-        let err =
-            resolve_plan(cfg(), Some("beta/worker"), Some("alpha"), false).unwrap_err();
+        let err = resolve_plan(
+            cfg(),
+            Some("beta/worker"),
+            Some("alpha"),
+            false,
+            false,
+            None,
+        )
+        .unwrap_err();
         assert_eq!(
             err,
             StopPlanError::Mismatch(ProjectMismatch {
@@ -156,4 +211,36 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn timeout_sets_the_service_grace_period() {
+        assert_eq!(
+            resolve_plan(cfg(), Some("worker"), None, false, false, Some("500ms"))
+                .unwrap(),
+            StopPlan::Service {
+                service: "worker".into(),
+                project: None,
+                grace: Some(Duration::from_millis(500))
+            }
+        );
+        assert!(matches!(
+            resolve_plan(cfg(), Some("worker"), None, false, false, Some("soon")),
+            Err(StopPlanError::InvalidTimeout { .. })
+        ));
+    }
+
+    #[test]
+    fn orphans_flag_sweeps_orphans_and_rejects_a_service() {
+        assert_eq!(
+            resolve_plan(cfg(), None, Some("alpha"), false, true, None).unwrap(),
+            StopPlan::Orphans {
+                config: cfg(),
+                project: Some("alpha".into())
+            }
+        );
+        assert_eq!(
+            resolve_plan(cfg(), Some("web"), None, false, true, None).unwrap_err(),
+            StopPlanError::OrphansWithService
+        );
+    }
 }
@@ -3,18 +3,18 @@ use std::os::unix::process::{CommandExt, ExitStatusExt};
 use std::{
     collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
     ffi::CString,
-    fs::File,
+    fs::{self, File},
     io,
     io::Write,
     os::fd::{AsRawFd, FromRawFd},
     path::{Path, PathBuf},
     sync::{
-        Arc, RwLock,
+        Arc, Mutex, OnceLock, RwLock,
         atomic::{AtomicBool, Ordering},
         mpsc,
     },
     thread,
-    time::{Duration, SystemTime},
+    time::{Duration, Instant, SystemTime},
 };
 
 use nix::unistd::{Uid, User};
@@ -23,21 +23,25 @@ use tracing::{debug, error, info, warn};
 
 use crate::{
     config::{
-        Config, LogSink, SkipConfig, SpawnMode, StatusSnapshotMode, TerminationPolicy,
-        load_projects_from_file, supervisor::SupervisorTimeouts,
+        Config, LogSink, ServiceConfig, SkipConfig, SpawnMode, StatusSnapshotMode,
+        TerminationPolicy, load_projects_from_file_with_env,
+        supervisor::SupervisorTimeouts,
     },
-    cron::{CronExecutionStatus, CronManager},
+    cron::{CronExecutionStatus, CronManager, ManualRunOutcome},
     daemon::{
         Daemon, PersistedSpawnChild, ServiceLifecycleStatus, ServiceReadyState,
-        ServiceStateFile,
+        ServiceStateFile, StopOutcome,
     },
     error::{LogsManagerError, ProcessManagerError},
-    ipc::{self, ControlCommand, ControlResponse, InspectPayload},
+    ipc::{
+        self, BulkOutcome, ControlCommand, ControlResponse, InspectPayload,
+        RestartReport, RestartedService,
+    },
     logs::{
         LogManager, LogSection, get_service_log_path, resolve_log_path,
         spawn_dynamic_child_log_writer, write_log_section_header,
     },
-    metrics::{self, MetricSample, MetricsCollector, MetricsHandle},
+    metrics::{self, MetricSample, MetricsCollector, MetricsHandle, RunSampler},
     opslot::OpSlot,
     runtime,
     spawn::{DynamicSpawnManager, SpawnedChild, SpawnedChildKind, SpawnedExit},
@@ -46,7 +50,7 @@ use crate::{
         BootStatus, ProjectRunMode, StatusCache, StatusError, StatusRefresher,
         StatusSnapshot, collect_runtime_snapshot,
         collect_runtime_snapshot_with_cron_hashes, compute_overall_health,
-        cron_hashes_for_config,
+        cron_hashes_for_config, mark_config_drift,
     },
     upgrade::{
         HANDOFF_SCHEMA_VERSION, HandoffProject, LIVE_REEXEC_PROTOCOL, LiveUpgradeInfo,
@@ -107,6 +111,54 @@ fn error_response(err: &SupervisorError) -> ControlResponse {
     }
 }
 
+/// Parsed manifests keyed by path and `--env` overrides, reused until the
+/// file's mtime changes.
+type ManifestCache =
+    HashMap<(PathBuf, BTreeMap<String, String>), (SystemTime, Vec<Config>)>;
+
+/// A failed service `restart --all-failed` could not bring back, and why.
+type FailedRestart = (RestartedService, String);
+
+/// Loads `project_id`'s config from the manifest as it currently sits on
+/// disk, with the `--env` overrides its running config was started with.
+/// Status refreshes call this every tick, so parses are cached until the file
+/// is modified; a manifest that fails to load yields `None`.
+fn manifest_on_disk(
+    config_path: &Path,
+    project_id: &str,
+    env_overrides: &BTreeMap<String, String>,
+) -> Option<Config> {
+    static CACHE: OnceLock<Mutex<ManifestCache>> = OnceLock::new();
+
+    let modified = fs::metadata(config_path).and_then(|m| m.modified()).ok()?;
+    let mut cache = CACHE.get_or_init(Default::default).lock().ok()?;
+    let key = (config_path.to_path_buf(), env_overrides.clone());
+    let fresh = cache.get(&key).is_some_and(|(stamp, _)| *stamp == modified);
+    if !fresh {
+        let loaded = runtime::open_trusted_config(config_path)
+            .map_err(ProcessManagerError::from)
+            .and_then(|file| {
+                load_projects_from_file_with_env(file, config_path, env_overrides)
+            });
+        match loaded {
+            Ok(configs) => {
+                cache.insert(key.clone(), (modified, configs));
+            }
+            Err(err) => {
+                debug!("skipping drift check for {}: {err}", config_path.display());
+                cache.remove(&key);
+                return None;
+            }
+        }
+    }
+    cache
+        .get(&key)?
+        .1
+        .iter()
+        .find(|config| config.project.id == project_id)
+        .cloned()
+}
+
 /// Daemon supervisor that handles CLI commands.
 pub struct Supervisor {
     /// Canonical path associated with the primary project manifest.
@@ -133,6 +185,8 @@ pub struct Supervisor {
     spawn_manager: DynamicSpawnManager,
     /// Whether service stderr is forwarded to supervisor stdout.
     pipe_stderr: bool,
+    /// Whether project boots start each dependency level concurrently.
+    parallel_start: bool,
     /// Attachment mode of the primary project.
     primary_project_mode: ProjectRunMode,
     /// Whether the primary project remains registered.
@@ -154,6 +208,8 @@ pub struct Supervisor {
     boots: Arc<RwLock<HashMap<String, BootStatus>>>,
     /// Whether the control plane is quiescing for live re-execution.
     upgrading: Arc<AtomicBool>,
+    /// Whether automatic restarts are paused, shared with every project daemon.
+    restarts_paused: Arc<AtomicBool>,
     /// Serializes cron due-state mutation with upgrade preflight.
     cron_gate: Arc<std::sync::Mutex<()>>,
     /// Inherited runtime state awaiting activation in a replacement image.
@@ -222,6 +278,8 @@ struct SupervisorLogRequest<'a> {
 struct CronCompletionOutcome {
     status: CronExecutionStatus,
     exit_code: Option<i32>,
+    /// Resource samples taken while the run was waited on.
+    metrics: Vec<MetricSample>,
 }
 
 /// Units left down by one project boot and the first concrete cause observed.
@@ -275,8 +333,13 @@ struct ReadContext {
     boot_journal: BootJournal,
     boot_projects: Arc<RwLock<HashMap<String, Daemon>>>,
     boots: Arc<RwLock<HashMap<String, BootStatus>>>,
+    metrics_store: MetricsHandle,
     /// Whether mutations are refused while a live upgrade is committing.
     upgrading: Arc<AtomicBool>,
+    /// Whether automatic restarts are paused (`sysg pause`).
+    restarts_paused: Arc<AtomicBool>,
+    /// Token every control request must carry, from `ipc::write_control_token`.
+    control_token: Arc<str>,
 }
 
 /// A mutation command routed from the acceptor to the single-writer owner thread,
@@ -383,6 +446,29 @@ fn reject_direct_cron_control(
     Ok(())
 }
 
+/// Refuses to start a service that `sysg disable` marked skipped, pointing at
+/// `sysg enable` instead of silently doing nothing.
+fn reject_disabled_service(
+    daemon: &Daemon,
+    service_name: &str,
+    target_project: &str,
+) -> Result<(), SupervisorError> {
+    if daemon.is_service_disabled(service_name) {
+        let diag = crate::diag::Diagnostic::error(
+            crate::diag::SgCode::Catchall,
+            format!("service `{service_name}` is disabled"),
+        )
+        .note("`sysg disable` keeps a service skipped across start and reload")
+        .help_cmd(
+            "re-enable it",
+            format!("sysg enable {service_name} -p {target_project}"),
+        )
+        .help_docs();
+        return Err(ProcessManagerError::Diag(Box::new(diag)).into());
+    }
+    Ok(())
+}
+
 /// Splits a qualified selector of the form `project_id/service_name`.
 fn split_project_selector(selector: &str) -> Option<(&str, &str)> {
     let (project, service) = selector.split_once('/')?;
@@ -394,10 +480,9 @@ fn split_project_selector(selector: &str) -> Option<(&str, &str)> {
 }
 
 /// Orders `root` and its transitive dependents so a dependency is always
-/// restarted before anything that depends on it. `restart -s A` must bounce A
-/// and everything that depends on A, because a dependent needs to re-handshake
-/// the freshly-restarted A; a stale dependent left pointing at the old A is the
-/// leaky state this cascade exists to prevent.
+/// restarted before anything that depends on it. `restart -s A --with-dependents`
+/// bounces A and everything that depends on A, so a dependent re-handshakes the
+/// freshly-restarted A instead of staying pointed at the old one.
 fn cascade_restart_order(config: &Config, root: &str) -> Vec<String> {
     let reverse = config.reverse_dependencies();
     let mut order: Vec<String> = Vec::new();
@@ -418,6 +503,20 @@ fn cascade_restart_order(config: &Config, root: &str) -> Vec<String> {
     order
 }
 
+/// The services a single-service restart touches: just `root`, or `root` and
+/// its transitive dependents when `with_dependents` is set.
+fn service_restart_order(
+    config: &Config,
+    root: &str,
+    with_dependents: bool,
+) -> Vec<String> {
+    if with_dependents {
+        cascade_restart_order(config, root)
+    } else {
+        vec![root.to_string()]
+    }
+}
+
 /// Returns whether a status unit belongs to the requested project id.
 fn project_matches(unit: &crate::status::UnitStatus, project: Option<&str>) -> bool {
     project.is_none_or(|project_id| {
@@ -548,53 +647,171 @@ impl Supervisor {
         let project_id = &config.project.id;
         let boot_epoch = daemon.begin_boot();
         let service_order = Self::startup_service_order(config, service_filter)?;
+        // A sequential boot is the degenerate case of one service per level.
+        let levels = if daemon.parallel_start() {
+            config.service_start_levels(&service_order)
+        } else {
+            service_order.into_iter().map(|name| vec![name]).collect()
+        };
         let mut healthy = HashSet::new();
         let mut completed = HashSet::new();
         let mut failed = HashSet::new();
         let mut skipped = HashSet::new();
         let mut cause = None;
-        'services: for service_name in service_order {
+        let abort = config.on_failure == crate::config::StartFailureMode::Abort;
+        let settle = config.dependency_settle()?;
+        // When each service started or completed, so its dependents can wait
+        // out `dependency_settle` from that moment.
+        let mut ready_at: HashMap<String, Instant> = HashMap::new();
+        // Long-running services this boot launched, in order, for rollback.
+        let mut launched = Vec::new();
+        for level in levels {
             if !daemon.boot_active(boot_epoch) {
                 break;
             }
-            let Some(service_config) = config.services.get(&service_name) else {
-                continue;
-            };
-
-            if service_config.cron.is_some() {
-                healthy.insert(service_name.clone());
-                completed.insert(service_name.clone());
-                continue;
+            if abort && !failed.is_empty() {
+                info!(
+                    "Project '{project_id}' start aborted after a failure (on_failure: abort); skipping {} and the rest of the start order",
+                    level.join(", ")
+                );
+                break;
             }
+            let mut launches = Vec::new();
+            'services: for service_name in level {
+                let Some(service_config) = config.services.get(&service_name) else {
+                    continue;
+                };
 
-            if let Some(skip_config) = &service_config.skip {
-                match skip_config {
-                    SkipConfig::Flag(true) => {
-                        info!("Skipping service '{service_name}' due to skip flag");
-                        daemon.mark_service_skipped(&service_name)?;
-                        skipped.insert(service_name.clone());
-                        continue;
+                if service_config.cron.is_some() {
+                    healthy.insert(service_name.clone());
+                    completed.insert(service_name.clone());
+                    continue;
+                }
+
+                if daemon.is_service_disabled(&service_name) {
+                    info!(
+                        "Skipping service '{service_name}': disabled with `sysg disable`"
+                    );
+                    daemon.mark_service_skipped(&service_name)?;
+                    skipped.insert(service_name.clone());
+                    continue;
+                }
+
+                if let Some(skip_config) = &service_config.skip {
+                    match skip_config {
+                        SkipConfig::Flag(true) => {
+                            info!("Skipping service '{service_name}' due to skip flag");
+                            daemon.mark_service_skipped(&service_name)?;
+                            skipped.insert(service_name.clone());
+                            continue;
+                        }
+                        SkipConfig::Flag(false) => {}
+                        SkipConfig::Command(skip_command) => {
+                            match daemon
+                                .evaluate_skip_condition(&service_name, skip_command)
+                            {
+                                Ok(true) => {
+                                    info!(
+                                        "Skipping service '{service_name}' due to skip condition"
+                                    );
+                                    daemon.mark_service_skipped(&service_name)?;
+                                    skipped.insert(service_name.clone());
+                                    continue;
+                                }
+                                Ok(false) => {}
+                                Err(err) => {
+                                    error!(
+                                        "Failed to evaluate skip condition for '{service_name}': {err}"
+                                    );
+                                    failed.insert(service_name.clone());
+                                    let diag = start::unit_start_failed(
+                                        &service_name,
+                                        err.to_string(),
+                                    );
+                                    cause.get_or_insert_with(|| diag.clone());
+                                    if let Some(journal) = boot_journal {
+                                        journal.push(BootFrame::UnitStarting {
+                                            project: project_id.clone(),
+                                            service: service_name.clone(),
+                                        });
+                                        journal.record(
+                                            project_id,
+                                            &service_name,
+                                            start::Outcome::Failed(diag),
+                                        );
+                                    }
+                                    continue 'services;
+                                }
+                            }
+                        }
                     }
-                    SkipConfig::Flag(false) => {}
-                    SkipConfig::Command(skip_command) => {
-                        match daemon.evaluate_skip_condition(&service_name, skip_command)
+                }
+
+                if let Some(reason) =
+                    daemon.unmet_condition(&service_name, service_config)
+                {
+                    info!("Skipping service '{service_name}': {reason}");
+                    daemon.mark_service_skipped(&service_name)?;
+                    skipped.insert(service_name.clone());
+                    continue;
+                }
+
+                if service_filter.is_none()
+                    && let Some(dependencies) = &service_config.depends_on
+                {
+                    for dependency in dependencies {
+                        let dependency_name = dependency.service();
+                        if skipped.contains(dependency_name) {
+                            info!(
+                                "Skipping service '{service_name}' because dependency '{dependency_name}' was skipped"
+                            );
+                            daemon.mark_service_skipped(&service_name)?;
+                            skipped.insert(service_name.clone());
+                            continue 'services;
+                        }
+                        if failed.contains(dependency_name)
+                            || !healthy.contains(dependency_name)
                         {
-                            Ok(true) => {
-                                info!(
-                                    "Skipping service '{service_name}' due to skip condition"
+                            error!(
+                                "Skipping service '{service_name}' because dependency '{dependency_name}' did not start"
+                            );
+                            failed.insert(service_name.clone());
+                            let diag = start::dependency_unavailable(
+                                &service_name,
+                                dependency_name,
+                                format!(
+                                    "dependency `{dependency_name}` did not reach its required state"
+                                ),
+                            );
+                            cause.get_or_insert_with(|| diag.clone());
+                            if let Some(journal) = boot_journal {
+                                journal.push(BootFrame::UnitStarting {
+                                    project: project_id.clone(),
+                                    service: service_name.clone(),
+                                });
+                                journal.record(
+                                    project_id,
+                                    &service_name,
+                                    start::Outcome::Failed(diag),
                                 );
-                                daemon.mark_service_skipped(&service_name)?;
-                                skipped.insert(service_name.clone());
-                                continue;
                             }
-                            Ok(false) => {}
-                            Err(err) => {
+                            continue 'services;
+                        }
+                        if dependency.condition()
+                            == crate::config::DependsOnCondition::Completed
+                            && !completed.contains(dependency_name)
+                        {
+                            if let Err(err) = daemon.wait_for_dependency_completion(
+                                &service_name,
+                                dependency_name,
+                            ) {
                                 error!(
-                                    "Failed to evaluate skip condition for '{service_name}': {err}"
+                                    "Skipping service '{service_name}' because dependency '{dependency_name}' did not complete: {err}"
                                 );
                                 failed.insert(service_name.clone());
-                                let diag = start::unit_start_failed(
+                                let diag = start::dependency_unavailable(
                                     &service_name,
+                                    dependency_name,
                                     err.to_string(),
                                 );
                                 cause.get_or_insert_with(|| diag.clone());
@@ -611,68 +828,31 @@ impl Supervisor {
                                 }
                                 continue 'services;
                             }
+                            completed.insert(dependency_name.to_string());
+                            ready_at.insert(dependency_name.to_string(), Instant::now());
                         }
-                    }
-                }
-            }
-
-            if service_filter.is_none()
-                && let Some(dependencies) = &service_config.depends_on
-            {
-                for dependency in dependencies {
-                    let dependency_name = dependency.service();
-                    if skipped.contains(dependency_name) {
-                        info!(
-                            "Skipping service '{service_name}' because dependency '{dependency_name}' was skipped"
-                        );
-                        daemon.mark_service_skipped(&service_name)?;
-                        skipped.insert(service_name.clone());
-                        continue 'services;
-                    }
-                    if failed.contains(dependency_name)
-                        || !healthy.contains(dependency_name)
-                    {
-                        error!(
-                            "Skipping service '{service_name}' because dependency '{dependency_name}' did not start"
-                        );
-                        failed.insert(service_name.clone());
-                        let diag = start::dependency_unavailable(
-                            &service_name,
-                            dependency_name,
-                            format!(
-                                "dependency `{dependency_name}` did not reach its required state"
-                            ),
+                        let dependency_completed = completed.contains(dependency_name);
+                        let dependency_running =
+                            healthy.contains(dependency_name) && !dependency_completed;
+                        let finite = config.services.get(dependency_name).is_some_and(
+                            |dependency| !dependency.restarts_after_failure(),
                         );
-                        cause.get_or_insert_with(|| diag.clone());
-                        if let Some(journal) = boot_journal {
-                            journal.push(BootFrame::UnitStarting {
-                                project: project_id.clone(),
-                                service: service_name.clone(),
-                            });
-                            journal.record(
-                                project_id,
-                                &service_name,
-                                start::Outcome::Failed(diag),
-                            );
-                        }
-                        continue 'services;
-                    }
-                    if dependency.condition()
-                        == crate::config::DependsOnCondition::Completed
-                        && !completed.contains(dependency_name)
-                    {
-                        if let Err(err) = daemon.wait_for_dependency_completion(
-                            &service_name,
-                            dependency_name,
+                        if !Daemon::dependency_satisfied(
+                            dependency,
+                            dependency_running,
+                            dependency_completed,
+                            finite,
                         ) {
                             error!(
-                                "Skipping service '{service_name}' because dependency '{dependency_name}' did not complete: {err}"
+                                "Skipping service '{service_name}' because dependency '{dependency_name}' did not reach its target"
                             );
                             failed.insert(service_name.clone());
                             let diag = start::dependency_unavailable(
                                 &service_name,
                                 dependency_name,
-                                err.to_string(),
+                                format!(
+                                    "dependency `{dependency_name}` did not reach its required state"
+                                ),
                             );
                             cause.get_or_insert_with(|| diag.clone());
                             if let Some(journal) = boot_journal {
@@ -688,115 +868,159 @@ impl Supervisor {
                             }
                             continue 'services;
                         }
-                        completed.insert(dependency_name.to_string());
                     }
-                    let dependency_completed = completed.contains(dependency_name);
-                    let dependency_running =
-                        healthy.contains(dependency_name) && !dependency_completed;
-                    let finite = config
-                        .services
-                        .get(dependency_name)
-                        .is_some_and(|dependency| !dependency.restarts_after_failure());
-                    if !Daemon::dependency_satisfied(
-                        dependency,
-                        dependency_running,
-                        dependency_completed,
-                        finite,
-                    ) {
+                }
+
+                if let Some(journal) = boot_journal {
+                    journal.push(BootFrame::UnitStarting {
+                        project: project_id.clone(),
+                        service: service_name.clone(),
+                    });
+                }
+                let settle_until = service_config
+                    .depends_on
+                    .iter()
+                    .flatten()
+                    .filter_map(|dependency| ready_at.get(dependency.service()))
+                    .max()
+                    .filter(|_| !settle.is_zero())
+                    .map(|&last_ready| last_ready + settle);
+                let mut service_to_start = service_config.clone();
+                service_to_start.skip = None;
+                service_to_start.condition = None;
+                launches.push((service_name, service_to_start, settle_until));
+            }
+
+            let results = Self::launch_level(daemon, launches);
+            let cancelled = !daemon.boot_active(boot_epoch);
+            for (service_name, service_config, result, finished_at) in results {
+                if cancelled {
+                    if let Err(err) = daemon.stop_service(&service_name) {
                         error!(
-                            "Skipping service '{service_name}' because dependency '{dependency_name}' did not reach its target"
+                            "Failed to stop '{service_name}' after project boot cancellation: {err}"
                         );
+                    }
+                    failed.insert(service_name.clone());
+                    continue;
+                }
+                match &result {
+                    Ok(ServiceReadyState::Running) => {
+                        healthy.insert(service_name.clone());
+                        ready_at.insert(service_name.clone(), finished_at);
+                        launched.push(service_name.clone());
+                    }
+                    Ok(ServiceReadyState::CompletedSuccess) => {
+                        healthy.insert(service_name.clone());
+                        completed.insert(service_name.clone());
+                        ready_at.insert(service_name.clone(), finished_at);
+                    }
+                    Err(_) => {
                         failed.insert(service_name.clone());
-                        let diag = start::dependency_unavailable(
-                            &service_name,
-                            dependency_name,
-                            format!(
-                                "dependency `{dependency_name}` did not reach its required state"
-                            ),
-                        );
-                        cause.get_or_insert_with(|| diag.clone());
-                        if let Some(journal) = boot_journal {
-                            journal.push(BootFrame::UnitStarting {
-                                project: project_id.clone(),
-                                service: service_name.clone(),
-                            });
-                            journal.record(
-                                project_id,
-                                &service_name,
-                                start::Outcome::Failed(diag),
-                            );
-                        }
-                        continue 'services;
                     }
                 }
-            }
-
-            if let Some(journal) = boot_journal {
-                journal.push(BootFrame::UnitStarting {
-                    project: project_id.clone(),
-                    service: service_name.clone(),
-                });
-            }
-            let mut service_to_start = service_config.clone();
-            service_to_start.skip = None;
-            let result = daemon.start_service(&service_name, &service_to_start);
-            if !daemon.boot_active(boot_epoch) {
-                if let Err(err) = daemon.stop_service(&service_name) {
+                let pid =
+                    daemon.pid_file_handle().lock().ok().and_then(|pid_file| {
+                        pid_file.services().get(&service_name).copied()
+                    });
+                let outcome = start::outcome_of(&service_name, result, pid);
+                if let Some(diag) = outcome.diagnostic() {
+                    cause.get_or_insert_with(|| diag.clone());
                     error!(
-                        "Failed to stop '{service_name}' after project boot cancellation: {err}"
+                        "Service '{service_name}' failed to start [{}]: {}.",
+                        diag.code_str(),
+                        diag.title
                     );
                 }
-                failed.insert(service_name.clone());
-                break;
-            }
-            match &result {
-                Ok(ServiceReadyState::Running) => {
-                    healthy.insert(service_name.clone());
+                if let Some(journal) = boot_journal {
+                    journal.record(project_id, &service_name, outcome);
                 }
-                Ok(ServiceReadyState::CompletedSuccess) => {
-                    healthy.insert(service_name.clone());
-                    completed.insert(service_name.clone());
+                if failed.contains(&service_name) {
+                    continue;
                 }
-                Err(_) => {
-                    failed.insert(service_name.clone());
+
+                if let Some(ref spawn) = service_config.spawn
+                    && let Some(SpawnMode::Dynamic) = spawn.mode
+                    && let Ok(pid_file) = daemon.pid_file_handle().lock()
+                    && let Some(&pid) = pid_file.services().get(&service_name)
+                {
+                    spawn_manager.register_service_pid(service_name.clone(), pid);
                 }
             }
-            let pid = daemon
-                .pid_file_handle()
-                .lock()
-                .ok()
-                .and_then(|pid_file| pid_file.services().get(&service_name).copied());
-            let outcome = start::outcome_of(&service_name, result, pid);
-            if let Some(diag) = outcome.diagnostic() {
-                cause.get_or_insert_with(|| diag.clone());
-                error!(
-                    "Service '{service_name}' failed to start [{}]: {}.",
-                    diag.code_str(),
-                    diag.title
-                );
-            }
-            if let Some(journal) = boot_journal {
-                journal.record(project_id, &service_name, outcome);
-            }
-            if failed.contains(&service_name) {
-                continue;
+            if cancelled {
+                break;
             }
-
-            if let Some(ref spawn) = service_config.spawn
-                && let Some(SpawnMode::Dynamic) = spawn.mode
-                && let Ok(pid_file) = daemon.pid_file_handle().lock()
-                && let Some(&pid) = pid_file.services().get(&service_name)
-            {
-                spawn_manager.register_service_pid(service_name.clone(), pid);
+            let mut critical_failures: Vec<_> = failed
+                .iter()
+                .filter(|name| {
+                    config
+                        .services
+                        .get(*name)
+                        .is_some_and(ServiceConfig::is_critical)
+                })
+                .collect();
+            critical_failures.sort_unstable();
+            if let Some(critical) = critical_failures.first() {
+                daemon.roll_back_start(critical, &launched);
+                break;
             }
         }
 
         if daemon.boot_active(boot_epoch) {
             daemon.ensure_monitoring()?;
+            if service_filter.is_none() {
+                daemon.run_start_barrier_hook(failed.is_empty());
+            }
         }
         Ok(BootFailures::new(failed.into_iter().collect(), cause))
     }
 
+    /// Starts one dependency level's services, each on its own thread when the
+    /// level holds more than one, and returns every result in launch order with
+    /// the moment it came back. A launch that carries a settle deadline first
+    /// waits it out, so `dependency_settle` holds on every boot path.
+    fn launch_level(
+        daemon: &Daemon,
+        launches: Vec<(String, ServiceConfig, Option<Instant>)>,
+    ) -> Vec<(
+        String,
+        ServiceConfig,
+        Result<ServiceReadyState, ProcessManagerError>,
+        Instant,
+    )> {
+        let launch =
+            |name: String, service: ServiceConfig, settle_until: Option<Instant>| {
+                let result = match settle_until {
+                    Some(until) => daemon
+                        .settle_dependencies(&name, until)
+                        .and_then(|()| daemon.start_service(&name, &service)),
+                    None => daemon.start_service(&name, &service),
+                };
+                (name, service, result, Instant::now())
+            };
+        if launches.len() <= 1 {
+            return launches
+                .into_iter()
+                .map(|(name, service, settle_until)| launch(name, service, settle_until))
+                .collect();
+        }
+        thread::scope(|scope| {
+            let handles: Vec<_> = launches
+                .into_iter()
+                .map(|(name, service, settle_until)| {
+                    scope.spawn(move || launch(name, service, settle_until))
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                })
+                .collect()
+        })
+    }
+
     /// Combines per-project snapshots into the supervisor status view.
     fn aggregate_snapshots(mut snapshots: Vec<StatusSnapshot>) -> StatusSnapshot {
         let Some(mut aggregate) = snapshots.first().cloned() else {
@@ -829,11 +1053,13 @@ impl Supervisor {
     }
 
     /// Collects a status snapshot for one project daemon.
+    #[allow(clippy::too_many_arguments)]
     fn collect_daemon_snapshot(
         daemon: &Daemon,
         metrics_store: &MetricsHandle,
         spawn_manager: &DynamicSpawnManager,
         mode: StatusSnapshotMode,
+        include_children: bool,
         run_mode: ProjectRunMode,
         config_path: &Path,
         valid_cron_hashes: Option<&HashSet<String>>,
@@ -850,6 +1076,7 @@ impl Supervisor {
                 Some(metrics_store),
                 Some(spawn_manager),
                 mode,
+                include_children,
                 Some(valid_cron_hashes),
             ),
             None => collect_runtime_snapshot(
@@ -859,10 +1086,19 @@ impl Supervisor {
                 Some(metrics_store),
                 Some(spawn_manager),
                 mode,
+                include_children,
             ),
         }
         .map_err(SupervisorError::Status)?;
         Self::apply_project_metadata(&mut snapshot, run_mode, config_path);
+        // The daemon's in-memory config is what its processes were launched
+        // from, so drift is judged against the manifest as it is on disk now.
+        if let Some(current) =
+            manifest_on_disk(config_path, &config.project.id, &config.env_overrides)
+            && let Ok(state) = state_handle.lock()
+        {
+            mark_config_drift(&mut snapshot, &current, &state);
+        }
         Ok(snapshot)
     }
 
@@ -899,6 +1135,7 @@ impl Supervisor {
                 metrics_store,
                 spawn_manager,
                 mode,
+                true,
                 runtime.mode,
                 &runtime.config_path,
                 Some(&valid_cron_hashes),
@@ -926,10 +1163,12 @@ impl Supervisor {
         hashes
     }
 
-    /// Collects a fresh aggregate snapshot across all loaded projects.
+    /// Collects a fresh aggregate snapshot across all loaded projects. With
+    /// `include_children` off, spawned-child trees are skipped.
     fn collect_aggregate_snapshot(
         &self,
         live_request: bool,
+        include_children: bool,
     ) -> Result<StatusSnapshot, SupervisorError> {
         let primary_config = self.daemon.config();
         let primary_mode = if live_request {
@@ -945,6 +1184,7 @@ impl Supervisor {
                 &self.metrics_store,
                 &self.spawn_manager,
                 primary_mode,
+                include_children,
                 self.primary_project_mode,
                 &self.config_path,
                 Some(&valid_cron_hashes),
@@ -963,6 +1203,7 @@ impl Supervisor {
                 &self.metrics_store,
                 &self.spawn_manager,
                 mode,
+                include_children,
                 project.mode,
                 &project.config_path,
                 Some(&valid_cron_hashes),
@@ -993,27 +1234,34 @@ impl Supervisor {
     /// supervisor: truncates the on-disk files AND drops the in-memory live-log
     /// buffer the log reader serves from. Doing this CLI-side would leave the
     /// buffer intact, so the reader would keep replaying "cleared" lines.
+    ///
+    /// With `keep_history`, only the active files are emptied. Each writer is
+    /// flushed first so lines it already buffered do not land after the clear.
     fn clear_logs(
         &self,
         service: Option<&str>,
-        _project: Option<&str>,
+        project: Option<&str>,
+        keep_history: bool,
     ) -> Result<(), SupervisorError> {
         let manager = LogManager::new();
         let mut targets: Vec<(String, String)> = Vec::new();
         match service {
             Some(name) => {
-                let project = _project
+                let project = project
                     .map(str::to_string)
                     .unwrap_or_else(|| self.daemon.config().project.id.clone());
                 targets.push((project, name.to_string()));
             }
             None => {
-                let primary = self.daemon.config();
-                for name in primary.services.keys() {
-                    targets.push((primary.project.id.clone(), name.clone()));
-                }
-                for project in self.extra_projects.values() {
-                    let config = project.daemon.config();
+                let configs = std::iter::once(self.daemon.config()).chain(
+                    self.extra_projects
+                        .values()
+                        .map(|runtime| runtime.daemon.config()),
+                );
+                for config in configs {
+                    if project.is_some_and(|project| project != config.project.id) {
+                        continue;
+                    }
                     for name in config.services.keys() {
                         targets.push((config.project.id.clone(), name.clone()));
                     }
@@ -1021,9 +1269,18 @@ impl Supervisor {
             }
         }
         for (project, name) in targets {
-            manager
-                .clear_service_logs(&project, &name)
-                .map_err(SupervisorError::from)?;
+            if keep_history {
+                if let Err(err) = crate::logs::sync_service_log(&project, &name) {
+                    warn!("Failed to flush logs for '{name}' before clearing: {err}");
+                }
+                manager
+                    .truncate_service_logs(&project, &name)
+                    .map_err(SupervisorError::from)?;
+            } else {
+                manager
+                    .clear_service_logs(&project, &name)
+                    .map_err(SupervisorError::from)?;
+            }
             crate::logs::clear_live_log(&project, &name);
         }
         Ok(())
@@ -1140,6 +1397,7 @@ impl Supervisor {
             &target_project,
             "started",
         )?;
+        reject_disabled_service(daemon, service_name, &target_project)?;
 
         // Explicitly naming a service is a direct order to run THIS one, so it
         // overrides a `skip` default — a skipped service you ask for by name must
@@ -1147,6 +1405,12 @@ impl Supervisor {
         let mut service_config = service_config;
         service_config.skip = None;
 
+        // Likewise an explicit start re-arms a service the restart policy gave
+        // up on: it gets its full `max_restarts` again.
+        if daemon.rearm_restarts(service_name) {
+            info!("Re-armed restarts for '{service_name}' on explicit start.");
+        }
+
         daemon.begin_boot();
         daemon.start_service(service_name, &service_config)?;
         daemon.ensure_monitoring()?;
@@ -1254,6 +1518,19 @@ impl Supervisor {
         Ok(())
     }
 
+    /// The `sysg start --env` overrides the projects registered from
+    /// `config_path` were started with, so a reload keeps applying them.
+    fn manifest_env_overrides(&self, config_path: &Path) -> BTreeMap<String, String> {
+        if config_path == self.config_path {
+            return self.daemon.config().env_overrides.clone();
+        }
+        self.extra_projects
+            .values()
+            .find(|runtime| runtime.config_path == config_path)
+            .map(|runtime| runtime.daemon.config().env_overrides.clone())
+            .unwrap_or_default()
+    }
+
     /// Resolves, trust-checks, parses, and validates a restart manifest before
     /// any managed process is touched.
     fn load_restart_manifest(
@@ -1269,9 +1546,11 @@ impl Supervisor {
                 .join(path)
         };
         let resolved = resolved.canonicalize().unwrap_or(resolved);
+        let env_overrides = self.manifest_env_overrides(&resolved);
         let loaded = (|| -> Result<Vec<Config>, SupervisorError> {
             let file = runtime::open_trusted_config(&resolved)?;
-            let configs = load_projects_from_file(file, &resolved)?;
+            let configs =
+                load_projects_from_file_with_env(file, &resolved, &env_overrides)?;
             if configs.is_empty() {
                 return Err(io::Error::new(
                     io::ErrorKind::InvalidData,
@@ -1378,6 +1657,7 @@ impl Supervisor {
                     &project_id,
                 )))
             })?;
+        self.check_supervisor_env(&new_config)?;
         Self::register_spawn_limits_for_config(&self.spawn_manager, &new_config)?;
         let old_config = daemon.config();
         let diff =
@@ -1434,9 +1714,7 @@ impl Supervisor {
         self.respawn_status_refresher()?;
         self.metrics_collector = Some(MetricsCollector::spawn(
             self.metrics_store.clone(),
-            self.daemon.config(),
-            self.daemon.pid_file_handle(),
-            self.daemon.service_state_handle(),
+            self.daemon.clone(),
         )?);
         Ok(())
     }
@@ -1536,7 +1814,10 @@ impl Supervisor {
         let mut replacement = Daemon::from_config(config, self.detach_children)?;
         replacement.set_timeouts(self.timeouts.clone());
         replacement.set_pipe_stderr(self.pipe_stderr);
+        replacement.set_parallel_start(self.parallel_start);
         replacement.set_op_slot(self.op_slot.clone());
+        replacement.set_restart_pause(Arc::clone(&self.restarts_paused));
+        replacement.carry_paused_crashes(&old_daemon);
 
         old_daemon.cancel_boot();
         old_daemon.shutdown_monitor();
@@ -1653,15 +1934,18 @@ impl Supervisor {
             detach_children,
             service_filter,
             ProjectRunMode::Daemon,
+            &BTreeMap::new(),
         )
     }
 
-    /// Creates supervisor with config and project mode.
+    /// Creates supervisor with config and project mode. `env_overrides` are
+    /// layered onto every service of every project the file declares.
     pub fn new_with_mode(
         config_path: PathBuf,
         detach_children: bool,
         service_filter: Option<String>,
         primary_project_mode: ProjectRunMode,
+        env_overrides: &BTreeMap<String, String>,
     ) -> Result<Self, SupervisorError> {
         let config_path = if config_path.is_absolute() {
             config_path
@@ -1671,7 +1955,7 @@ impl Supervisor {
         let config_path = config_path.canonicalize().unwrap_or(config_path);
         let mut projects = {
             let trusted = runtime::open_trusted_config(&config_path)?;
-            load_projects_from_file(trusted, &config_path)?
+            load_projects_from_file_with_env(trusted, &config_path, env_overrides)?
         };
         if projects.is_empty() {
             return Err(io::Error::new(
@@ -1681,6 +1965,10 @@ impl Supervisor {
             .into());
         }
         let config = projects.remove(0);
+        let applied = runtime::apply_supervisor_env(&config.supervisor.env);
+        if !applied.is_empty() {
+            info!("Applied supervisor.env: {}", applied.join(", "));
+        }
         Self::from_primary_config(
             config_path,
             config,
@@ -1705,8 +1993,10 @@ impl Supervisor {
         cron_manager.sync_from_config(&config)?;
 
         let op_slot = OpSlot::new();
+        let restarts_paused = Arc::new(AtomicBool::new(false));
         let mut daemon = Daemon::from_config(config.clone(), detach_children)?;
         daemon.set_op_slot(op_slot.clone());
+        daemon.set_restart_pause(Arc::clone(&restarts_paused));
         let config_arc = daemon.config();
         let cron_projects = Arc::new(RwLock::new(vec![CronProjectRuntime {
             project_id: config_arc.project.id.clone(),
@@ -1748,6 +2038,7 @@ impl Supervisor {
             metrics_collector: None,
             spawn_manager,
             pipe_stderr: false,
+            parallel_start: false,
             primary_project_mode,
             primary_active: true,
             extra_projects: BTreeMap::new(),
@@ -1758,6 +2049,7 @@ impl Supervisor {
             boot_projects,
             boots: Arc::new(RwLock::new(HashMap::new())),
             upgrading: Arc::new(AtomicBool::new(false)),
+            restarts_paused,
             cron_gate: Arc::new(std::sync::Mutex::new(())),
             handoff: None,
         })
@@ -1787,7 +2079,12 @@ impl Supervisor {
             .into());
         }
         let trusted = runtime::open_trusted_config(&project.config_path)?;
-        load_projects_from_file(trusted, &project.config_path)?
+        let configs = load_projects_from_file_with_env(
+            trusted,
+            &project.config_path,
+            &project.env_overrides,
+        )?;
+        configs
             .into_iter()
             .find(|config| config.project.id == project.project_id)
             .ok_or_else(|| {
@@ -1831,6 +2128,8 @@ impl Supervisor {
         supervisor.primary_active = primary.active;
         supervisor.pipe_stderr = state.pipe_stderr;
         supervisor.daemon.set_pipe_stderr(state.pipe_stderr);
+        supervisor.parallel_start = state.parallel_start;
+        supervisor.daemon.set_parallel_start(state.parallel_start);
         supervisor.daemon.adopt_handoff_state(&primary.daemon)?;
         if !primary.active
             && let Ok(mut projects) = supervisor.boot_projects.write()
@@ -1844,7 +2143,9 @@ impl Supervisor {
             let mut daemon = Daemon::from_config(config, false)?;
             daemon.set_timeouts(supervisor.timeouts.clone());
             daemon.set_op_slot(supervisor.op_slot.clone());
+            daemon.set_restart_pause(Arc::clone(&supervisor.restarts_paused));
             daemon.set_pipe_stderr(state.pipe_stderr);
+            daemon.set_parallel_start(state.parallel_start);
             daemon.adopt_handoff_state(&project.daemon)?;
             if project.active
                 && let Ok(mut projects) = supervisor.boot_projects.write()
@@ -1875,6 +2176,15 @@ impl Supervisor {
         }
     }
 
+    /// Sets whether project boots start independent services concurrently.
+    pub fn set_parallel_start(&mut self, parallel_start: bool) {
+        self.parallel_start = parallel_start;
+        self.daemon.set_parallel_start(parallel_start);
+        for project in self.extra_projects.values_mut() {
+            project.daemon.set_parallel_start(parallel_start);
+        }
+    }
+
     /// Returns the project runtimes that own cron-capable configs.
     fn cron_project_runtimes(&self) -> Vec<CronProjectRuntime> {
         let mut projects = Vec::new();
@@ -2017,7 +2327,9 @@ impl Supervisor {
         let mut daemon = Daemon::from_config(config, self.detach_children)?;
         daemon.set_timeouts(self.timeouts.clone());
         daemon.set_pipe_stderr(self.pipe_stderr);
+        daemon.set_parallel_start(self.parallel_start);
         daemon.set_op_slot(self.op_slot.clone());
+        daemon.set_restart_pause(Arc::clone(&self.restarts_paused));
         if let Ok(mut projects) = self.boot_projects.write() {
             projects.insert(project_id.clone(), daemon.clone());
         }
@@ -2105,8 +2417,8 @@ impl Supervisor {
             return;
         }
 
-        let command = match ipc::read_command(&mut stream) {
-            Ok(command) => command,
+        let request = match ipc::read_request(&mut stream) {
+            Ok(request) => request,
             Err(ipc::ControlError::Io(err))
                 if err.kind() == io::ErrorKind::UnexpectedEof =>
             {
@@ -2121,12 +2433,22 @@ impl Supervisor {
                 return;
             }
         };
+        if !request.is_authorized(&read_ctx.control_token) {
+            warn!("Rejected control request without a valid token");
+            let _ = ipc::write_response(
+                &mut stream,
+                &ControlResponse::Error("unauthorized".into()),
+            );
+            return;
+        }
+        let command = request.command;
         debug!("Supervisor received command: {:?}", command);
         match &command {
             ControlCommand::StopProject { project }
             | ControlCommand::Stop {
                 service: None,
                 project: Some(project),
+                ..
             } => {
                 if let Ok(projects) = read_ctx.boot_projects.read()
                     && let Some(daemon) = projects.get(project)
@@ -2138,6 +2460,7 @@ impl Supervisor {
             | ControlCommand::Stop {
                 service: None,
                 project: None,
+                ..
             } => {
                 if let Ok(projects) = read_ctx.boot_projects.read() {
                     for daemon in projects.values() {
@@ -2166,6 +2489,16 @@ impl Supervisor {
             return;
         }
 
+        if let ControlCommand::LogFollow { .. } = command {
+            Self::serve_log_follow(stream, command, &read_ctx);
+            return;
+        }
+
+        if let ControlCommand::Attach { .. } = command {
+            Self::serve_attach(stream, command, &read_ctx);
+            return;
+        }
+
         if let Some(response) = Self::answer_read(&command, &read_ctx) {
             let _ = ipc::write_response(&mut stream, &response);
             return;
@@ -2219,9 +2552,18 @@ impl Supervisor {
         read_ctx: &ReadContext,
     ) -> Option<ControlResponse> {
         match command {
-            ControlCommand::Status { live: false } => {
+            ControlCommand::Status {
+                live: false,
+                no_children,
+            } => {
                 let mut snapshot = read_ctx.status_cache.snapshot();
+                if *no_children {
+                    for unit in &mut snapshot.units {
+                        unit.spawned_children.clear();
+                    }
+                }
                 Self::apply_boots(&mut snapshot, &read_ctx.boots);
+                snapshot.paused = read_ctx.restarts_paused.load(Ordering::SeqCst);
                 Some(ControlResponse::Status(snapshot))
             }
             ControlCommand::Version => {
@@ -2230,6 +2572,26 @@ impl Supervisor {
             ControlCommand::CurrentOp => {
                 Some(ControlResponse::CurrentOp(read_ctx.op_slot.report()))
             }
+            ControlCommand::ServiceHashes { project } => {
+                Some(ControlResponse::ServiceHashes(Self::service_hashes(
+                    &read_ctx.boot_projects,
+                    project.as_deref(),
+                )))
+            }
+            ControlCommand::Services { project } => Some(ControlResponse::Services(
+                Self::service_selectors(&read_ctx.boot_projects, project.as_deref()),
+            )),
+            ControlCommand::Samples {
+                unit,
+                project,
+                samples,
+            } => Some(Self::unit_samples(
+                &read_ctx.status_cache.snapshot(),
+                &read_ctx.metrics_store,
+                unit,
+                project.as_deref(),
+                *samples as usize,
+            )),
             ControlCommand::Inspect {
                 unit,
                 project,
@@ -2323,48 +2685,214 @@ impl Supervisor {
         }
     }
 
-    fn serve_logs(
+    /// Streams one service's live output as [`ipc::LogFrame`]s. The service is
+    /// resolved against the status cache the same way `logs -s` resolves it; a
+    /// selector that matches nothing, or several projects, ends the stream with
+    /// an error frame.
+    fn serve_log_follow(
         mut stream: std::os::unix::net::UnixStream,
         command: ControlCommand,
         read_ctx: &ReadContext,
     ) {
-        let ControlCommand::Logs {
+        let ControlCommand::LogFollow {
             service,
             project,
             lines,
             kind,
-            follow,
-            since,
-            until,
             grep,
-            all,
-            structured,
         } = command
         else {
             return;
         };
+        let send_error = |stream: &mut std::os::unix::net::UnixStream,
+                          message: String| {
+            if let Ok(frame) = serde_json::to_string(&ipc::LogFrame::Error { message }) {
+                let _ = writeln!(stream, "{frame}");
+            }
+        };
 
         let filter = match crate::logs::LogFilter::from_parts(
-            since.as_deref(),
-            until.as_deref(),
+            None,
+            None,
             grep.as_deref(),
-            all,
+            false,
             chrono::Utc::now(),
         ) {
             Ok(filter) => filter,
-            Err(err) => {
-                let _ = writeln!(stream, "{err}");
-                return;
-            }
+            Err(err) => return send_error(&mut stream, err.to_string()),
         };
 
-        let request = SupervisorLogRequest {
-            snapshot: read_ctx.status_cache.snapshot(),
-            service,
-            project,
+        let (unit_project, unit_name) =
+            match Self::resolve_streamed_unit(read_ctx, &service, project.as_deref()) {
+                Ok(unit) => unit,
+                Err(message) => return send_error(&mut stream, message),
+            };
+
+        if let Err(err) = LogManager::new().stream_log_frames_to_socket(
+            &unit_project,
+            &unit_name,
             lines,
-            kind: kind.as_deref(),
-            follow,
+            kind.as_deref(),
+            &filter,
+            &stream,
+        ) {
+            error!("Supervisor log follow failed: {err}");
+            send_error(&mut stream, err.to_string());
+        }
+    }
+
+    /// Resolves a streamed command's service selector against the status
+    /// cache to its `(project id, service name)`, or the message an error frame
+    /// carries when it matches nothing or several projects.
+    fn resolve_streamed_unit(
+        read_ctx: &ReadContext,
+        service: &str,
+        project: Option<&str>,
+    ) -> Result<(String, String), String> {
+        let snapshot = read_ctx.status_cache.snapshot();
+        let matching_projects = snapshot
+            .units
+            .iter()
+            .filter(|unit| unit_matches_selector(unit, service, project))
+            .map(|unit| {
+                (
+                    unit.project
+                        .as_ref()
+                        .map(|project| project.id.clone())
+                        .unwrap_or_else(|| {
+                            crate::state_store::LOOSE_PROJECT_ID.to_string()
+                        }),
+                    unit.name.clone(),
+                )
+            })
+            .collect::<BTreeSet<_>>();
+        match matching_projects.len() {
+            0 => Err(format!(
+                "service '{service}' is not managed by the supervisor"
+            )),
+            1 => Ok(matching_projects.into_iter().next().unwrap_or_default()),
+            _ => Err(format!(
+                "service '{service}' exists in multiple projects ({}); pass --project to choose one",
+                matching_projects
+                    .iter()
+                    .map(|(project, _)| project.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+        }
+    }
+
+    /// Attaches a client to an interactive service: confirms with
+    /// [`ipc::LogFrame::Attached`], relays what the client sends to the
+    /// service's stdin on a separate thread, and streams its live output until
+    /// the client disconnects. The service keeps running after a detach.
+    fn serve_attach(
+        mut stream: std::os::unix::net::UnixStream,
+        command: ControlCommand,
+        read_ctx: &ReadContext,
+    ) {
+        let ControlCommand::Attach { service, project } = command else {
+            return;
+        };
+        let send_frame = |stream: &mut std::os::unix::net::UnixStream,
+                          frame: &ipc::LogFrame| {
+            if let Ok(frame) = serde_json::to_string(frame) {
+                let _ = writeln!(stream, "{frame}");
+            }
+        };
+
+        let (unit_project, unit_name) =
+            match Self::resolve_streamed_unit(read_ctx, &service, project.as_deref()) {
+                Ok(unit) => unit,
+                Err(message) => {
+                    return send_frame(&mut stream, &ipc::LogFrame::Error { message });
+                }
+            };
+        if !crate::attach::is_attachable(&unit_project, &unit_name) {
+            let message = format!(
+                "service '{unit_name}' does not accept input; set `interactive: true` and restart it to attach"
+            );
+            return send_frame(&mut stream, &ipc::LogFrame::Error { message });
+        }
+        let input = match stream.try_clone() {
+            Ok(input) => input,
+            Err(err) => {
+                let message = err.to_string();
+                return send_frame(&mut stream, &ipc::LogFrame::Error { message });
+            }
+        };
+        send_frame(&mut stream, &ipc::LogFrame::Attached);
+
+        let (relay_project, relay_name) = (unit_project.clone(), unit_name.clone());
+        let relay = thread::Builder::new()
+            .name("sysg-attach-input".to_string())
+            .spawn(move || {
+                if let Err(err) = crate::attach::relay(&relay_project, &relay_name, input)
+                {
+                    warn!("Stopped relaying input to '{relay_name}': {err}");
+                }
+            });
+        if let Err(err) = relay {
+            let message = format!("failed to relay input: {err}");
+            return send_frame(&mut stream, &ipc::LogFrame::Error { message });
+        }
+
+        if let Err(err) = LogManager::new().stream_log_frames_to_socket(
+            &unit_project,
+            &unit_name,
+            0,
+            None,
+            &crate::logs::LogFilter::default(),
+            &stream,
+        ) {
+            error!("Supervisor attach failed: {err}");
+            let message = err.to_string();
+            send_frame(&mut stream, &ipc::LogFrame::Error { message });
+        }
+    }
+
+    fn serve_logs(
+        mut stream: std::os::unix::net::UnixStream,
+        command: ControlCommand,
+        read_ctx: &ReadContext,
+    ) {
+        let ControlCommand::Logs {
+            service,
+            project,
+            lines,
+            kind,
+            follow,
+            since,
+            until,
+            grep,
+            all,
+            structured,
+        } = command
+        else {
+            return;
+        };
+
+        let filter = match crate::logs::LogFilter::from_parts(
+            since.as_deref(),
+            until.as_deref(),
+            grep.as_deref(),
+            all,
+            chrono::Utc::now(),
+        ) {
+            Ok(filter) => filter,
+            Err(err) => {
+                let _ = writeln!(stream, "{err}");
+                return;
+            }
+        };
+
+        let request = SupervisorLogRequest {
+            snapshot: read_ctx.status_cache.snapshot(),
+            service,
+            project,
+            lines,
+            kind: kind.as_deref(),
+            follow,
             filter,
             structured,
             stream: &stream,
@@ -2435,6 +2963,7 @@ impl Supervisor {
             })?,
             mode,
             active,
+            env_overrides: config.env_overrides.clone(),
             daemon: state,
         })
     }
@@ -2468,11 +2997,10 @@ impl Supervisor {
     /// complete descriptor-backed supervisor handoff.
     fn prepare_upgrade(
         &self,
-        binary: &Path,
+        target: UpgradeTarget,
         runtime_lock: &File,
         listener: &std::os::unix::net::UnixListener,
     ) -> Result<PreparedUpgrade, Box<crate::diag::Diagnostic>> {
-        let target = UpgradeTarget::inspect(binary, &LiveUpgradeInfo::current())?;
         if self.pipe_stderr {
             return Err(Box::new(crate::upgrade::environment_unsafe(
                 "service stderr is attached to supervisor stdout; restart without `--stderr` before upgrading",
@@ -2545,9 +3073,7 @@ impl Supervisor {
             Self::set_descriptor_cloexec(listener.as_raw_fd(), false).map_err(|err| {
                 Box::new(crate::upgrade::handoff_failed(err.to_string()))
             })?;
-            let source_binary = std::env::current_exe()
-                .and_then(std::fs::canonicalize)
-                .map_err(|err| {
+            let source_binary = crate::upgrade::rollback_binary().map_err(|err| {
                 Box::new(crate::upgrade::handoff_failed(format!(
                     "could not resolve the resident binary for rollback: {err}"
                 )))
@@ -2563,6 +3089,7 @@ impl Supervisor {
                 listener_fd: listener.as_raw_fd(),
                 service_filter: self.service_filter.clone(),
                 pipe_stderr: self.pipe_stderr,
+                parallel_start: self.parallel_start,
                 primary,
                 projects,
                 log_pipes,
@@ -2647,7 +3174,7 @@ impl Supervisor {
     fn run_internal(&mut self) -> Result<(), SupervisorError> {
         let loaded = self.handoff.take();
         let resumed = loaded.is_some();
-        let (runtime_lock, listener, handoff_path) = match loaded {
+        let (runtime_lock, listener, handoff_path, control_token) = match loaded {
             Some(LoadedHandoff { path, state }) => {
                 if let Some(reason) = &state.rollback_reason {
                     error!(
@@ -2661,15 +3188,25 @@ impl Supervisor {
                 };
                 Self::set_descriptor_cloexec(lock.as_raw_fd(), true)?;
                 Self::set_descriptor_cloexec(listener.as_raw_fd(), true)?;
-                (lock, listener, Some(path))
+                // Keep the predecessor's token: clients that read it moments
+                // ago may already be queued on the inherited socket.
+                let token = match ipc::read_control_token() {
+                    Some(token) => token,
+                    None => ipc::write_control_token()?,
+                };
+                (lock, listener, Some((path, state.source_binary)), token)
             }
             None => {
                 let lock = ipc::lock_supervisor_runtime()?;
                 ipc::cleanup_runtime()?;
+                // Written before the socket exists, so no client can connect
+                // in time to find the socket but not the token.
+                let token = ipc::write_control_token()?;
                 let listener = ipc::bind_control_socket()?;
-                (lock, listener, None)
+                (lock, listener, None, token)
             }
         };
+        let control_token: Arc<str> = control_token.into();
 
         // Load (or create with defaults) the supervisor's OWN config — distinct
         // from any project manifest — and apply its log-rotation defaults as the
@@ -2682,11 +3219,12 @@ impl Supervisor {
             supervisor_config.logs.max_bytes,
             supervisor_config.logs.max_files,
         );
+        crate::logs::set_max_log_reader_threads(supervisor_config.logs.reader_threads);
 
         ipc::write_config_hint(&self.config_path)?;
         ipc::write_supervisor_pid(unsafe { libc::getpid() })?;
 
-        match self.collect_aggregate_snapshot(false) {
+        match self.collect_aggregate_snapshot(false, true) {
             Ok(snapshot) => self.status_cache.replace(snapshot),
             Err(err) => error!("failed to build pre-boot status snapshot: {err}"),
         }
@@ -2699,7 +3237,10 @@ impl Supervisor {
             boot_journal: self.boot_journal.clone(),
             boot_projects: Arc::clone(&self.boot_projects),
             boots: Arc::clone(&self.boots),
+            metrics_store: self.metrics_store.clone(),
             upgrading: Arc::clone(&self.upgrading),
+            restarts_paused: Arc::clone(&self.restarts_paused),
+            control_token,
         };
         Self::spawn_acceptor(listener.try_clone()?, read_ctx, mutation_tx)?;
 
@@ -2724,13 +3265,11 @@ impl Supervisor {
         }
 
         let config_handle = self.daemon.config();
-        let pid_handle = self.daemon.pid_file_handle();
-        let state_handle = self.daemon.service_state_handle();
 
         // Seed the cache from ALL managed projects, not just the primary, so a
         // multi-project boot (which registers extra projects before this point)
         // is reflected in status from the first read.
-        match self.collect_aggregate_snapshot(false) {
+        match self.collect_aggregate_snapshot(false, true) {
             Ok(snapshot) => self.status_cache.replace(snapshot),
             Err(err) => error!("failed to build initial status snapshot: {err}"),
         }
@@ -2759,9 +3298,7 @@ impl Supervisor {
         let metrics_handle = self.metrics_store.clone();
         self.metrics_collector = Some(MetricsCollector::spawn(
             metrics_handle,
-            Arc::clone(&config_handle),
-            pid_handle,
-            state_handle,
+            self.daemon.clone(),
         )?);
 
         let cron_manager = self.cron_manager.clone();
@@ -2908,6 +3445,7 @@ impl Supervisor {
                                                             let CronCompletionOutcome {
                                                                 status,
                                                                 exit_code,
+                                                                metrics: sampled,
                                                             } = outcome;
 
                                                             match &status {
@@ -2929,11 +3467,15 @@ impl Supervisor {
                                                                             ),
                                                                         }
 
-                                                            let metrics = cron_run_metrics(
-                                                                &metrics_store_clone,
-                                                                &service_hash,
-                                                                run_started_at,
-                                                            );
+                                                            let metrics = if sampled.is_empty() {
+                                                                cron_run_metrics(
+                                                                    &metrics_store_clone,
+                                                                    &service_hash,
+                                                                    run_started_at,
+                                                                )
+                                                            } else {
+                                                                sampled
+                                                            };
                                                             let lifecycle_status = match status {
                                                                 CronExecutionStatus::Success => ServiceLifecycleStatus::ExitedSuccessfully,
                                                                 CronExecutionStatus::Failed(_) | CronExecutionStatus::OverlapError => ServiceLifecycleStatus::ExitedWithError,
@@ -3101,11 +3643,13 @@ impl Supervisor {
                 }
             })?;
 
-        if let Some(path) = handoff_path
-            && let Err(err) = std::fs::remove_file(&path)
-            && err.kind() != io::ErrorKind::NotFound
-        {
-            warn!("Failed to remove completed supervisor handoff {path:?}: {err}");
+        if let Some((path, source_binary)) = handoff_path {
+            if let Err(err) = std::fs::remove_file(&path)
+                && err.kind() != io::ErrorKind::NotFound
+            {
+                warn!("Failed to remove completed supervisor handoff {path:?}: {err}");
+            }
+            crate::upgrade::discard_rollback_binary(&source_binary);
         }
 
         loop {
@@ -3121,9 +3665,19 @@ impl Supervisor {
                 reply,
                 delivered,
             } = request;
-            if let ControlCommand::Upgrade { binary } = command {
-                let _op = self.op_slot.guard("upgrading supervisor");
-                match self.prepare_upgrade(Path::new(&binary), &runtime_lock, &listener) {
+            if let ControlCommand::Upgrade { binary }
+            | ControlCommand::Reexec { binary } = &command
+            {
+                let _op = self.op_slot.guard(Self::mutation_label(&command));
+                let current = LiveUpgradeInfo::current();
+                let target = if matches!(command, ControlCommand::Reexec { .. }) {
+                    UpgradeTarget::inspect_reexec(Path::new(binary), &current)
+                } else {
+                    UpgradeTarget::inspect(Path::new(binary), &current)
+                };
+                match target.and_then(|target| {
+                    self.prepare_upgrade(target, &runtime_lock, &listener)
+                }) {
                     Ok(prepared) => {
                         let version = prepared.target.info.version.to_string();
                         let _ = reply.send(ControlResponse::UpgradeAccepted { version });
@@ -3180,17 +3734,58 @@ impl Supervisor {
             ControlCommand::Start { service, project } => {
                 Self::target_label("starting", service.as_deref(), project.as_deref())
             }
-            ControlCommand::Stop { service, project } => {
-                Self::target_label("stopping", service.as_deref(), project.as_deref())
-            }
+            ControlCommand::Stop {
+                service, project, ..
+            } => Self::target_label("stopping", service.as_deref(), project.as_deref()),
             ControlCommand::Restart {
                 service, project, ..
             } => Self::target_label("restarting", service.as_deref(), project.as_deref()),
+            ControlCommand::Bulk {
+                action,
+                services,
+                project,
+            } => {
+                let subject = format!("{} services", services.len());
+                match project {
+                    Some(project) => format!(
+                        "{} {subject} in project '{project}'",
+                        action.progressive()
+                    ),
+                    None => format!("{} {subject}", action.progressive()),
+                }
+            }
+            ControlCommand::RestartFailed { project } => match project {
+                Some(project) => {
+                    format!("restarting failed services in project '{project}'")
+                }
+                None => "restarting failed services".to_string(),
+            },
+            ControlCommand::VerifyRestart {
+                service, project, ..
+            } => Self::target_label("verifying", service.as_deref(), project.as_deref()),
             ControlCommand::StopProject { project } => {
                 format!("stopping project '{project}'")
             }
+            ControlCommand::RunCron { hash } => {
+                format!("running cron job '{hash}'")
+            }
+            ControlCommand::Disable { service, project } => {
+                Self::target_label("disabling", Some(service), project.as_deref())
+            }
+            ControlCommand::Enable { service, project } => {
+                Self::target_label("enabling", Some(service), project.as_deref())
+            }
+            ControlCommand::StopOrphans { project } => match project {
+                Some(project) => {
+                    format!("stopping orphaned units in project '{project}'")
+                }
+                None => "stopping orphaned units".to_string(),
+            },
+            ControlCommand::Pause => "pausing automatic restarts".to_string(),
+            ControlCommand::Resume { .. } => "resuming automatic restarts".to_string(),
             ControlCommand::Spawn { name, .. } => format!("spawning '{name}'"),
             ControlCommand::Upgrade { .. } => "upgrading supervisor".to_string(),
+            ControlCommand::Reexec { .. } => "re-executing supervisor".to_string(),
             ControlCommand::Shutdown => "shutting down".to_string(),
             other => format!("{other:?}"),
         }
@@ -3481,9 +4076,10 @@ impl Supervisor {
                 config,
                 service,
                 mode,
+                env,
             } => {
                 let project_id =
-                    self.add_project_config(Path::new(&config), service, mode)?;
+                    self.add_project_config(Path::new(&config), service, mode, &env)?;
                 Ok(ControlResponse::Message(format!(
                     "Project '{project_id}' loaded"
                 )))
@@ -3495,7 +4091,11 @@ impl Supervisor {
                     "Project '{project}' stopped"
                 )))
             }
-            ControlCommand::Stop { service, project } => {
+            ControlCommand::Stop {
+                service,
+                project,
+                timeout_ms,
+            } => {
                 if service.is_none()
                     && let Some(project_id) = project.as_deref()
                 {
@@ -3506,53 +4106,289 @@ impl Supervisor {
                     )));
                 }
                 if let Some(service) = service {
-                    let (project_id, service_name) =
-                        self.stop_single_service_target(&service, project.as_deref())?;
+                    let grace = timeout_ms.map(Duration::from_millis);
+                    let (project_id, service_name, outcome) = self
+                        .stop_single_service_target(
+                            &service,
+                            project.as_deref(),
+                            grace,
+                        )?;
                     self.refresh_status_cache();
-                    if project.is_some() || split_project_selector(&service).is_some() {
-                        Ok(ControlResponse::Message(format!(
-                            "Service '{service_name}' stopped in project '{project_id}'"
-                        )))
-                    } else {
-                        Ok(ControlResponse::Message(format!(
-                            "Service '{service_name}' stopped"
-                        )))
-                    }
+                    let scoped =
+                        project.is_some() || split_project_selector(&service).is_some();
+                    let message = match (outcome, scoped) {
+                        (StopOutcome::NotRunning, true) => {
+                            format!(
+                                "Service '{service_name}' stopped in project '{project_id}'"
+                            )
+                        }
+                        (StopOutcome::NotRunning, false) => {
+                            format!("Service '{service_name}' stopped")
+                        }
+                        (outcome, true) => format!(
+                            "Service '{service_name}' in project '{project_id}' {outcome}"
+                        ),
+                        (outcome, false) => format!("Service '{service_name}' {outcome}"),
+                    };
+                    Ok(ControlResponse::Message(message))
                 } else {
                     self.stop_all_projects()?;
                     self.refresh_status_cache();
                     Ok(ControlResponse::Message("All services stopped".into()))
                 }
             }
+            ControlCommand::StopOrphans { project } => {
+                let daemons: Vec<&Daemon> = std::iter::once(&self.daemon)
+                    .chain(self.extra_projects.values().map(|runtime| &runtime.daemon))
+                    .filter(|daemon| {
+                        project
+                            .as_deref()
+                            .is_none_or(|project| daemon.config().project.id == project)
+                    })
+                    .collect();
+                if let Some(project) = project.as_deref()
+                    && daemons.is_empty()
+                {
+                    return Err(io::Error::new(
+                        io::ErrorKind::NotFound,
+                        format!("project '{project}' is not managed by this supervisor"),
+                    )
+                    .into());
+                }
+                let mut cleared = Vec::new();
+                let mut first_error = None;
+                for daemon in daemons {
+                    let project = daemon.config().project.id.clone();
+                    match daemon.stop_orphans() {
+                        Ok(services) => cleared.extend(
+                            services
+                                .into_iter()
+                                .map(|service| format!("{project}/{service}")),
+                        ),
+                        Err(err) => {
+                            error!(
+                                "Failed to stop orphans in project '{project}': {err}"
+                            );
+                            first_error.get_or_insert(err);
+                        }
+                    }
+                }
+                self.refresh_status_cache();
+                if let Some(err) = first_error {
+                    return Err(err.into());
+                }
+                let message = if cleared.is_empty() {
+                    "No orphaned units".to_string()
+                } else {
+                    format!(
+                        "Cleared {} orphaned unit(s): {}",
+                        cleared.len(),
+                        cleared.join(", ")
+                    )
+                };
+                Ok(ControlResponse::Message(message))
+            }
             ControlCommand::Restart {
                 config,
                 service,
                 project,
+                with_dependents,
+                build_first,
+                wait,
             } => {
-                if let Some(service) = service {
+                let message = if let Some(service) = service.as_deref() {
                     self.restart_single_service_target(
-                        &service,
+                        service,
                         project.as_deref(),
                         config.as_deref().map(Path::new),
+                        with_dependents,
+                        build_first,
                     )?;
-                    self.refresh_status_cache();
-                    Ok(ControlResponse::Message(format!(
-                        "Service '{service}' restarted"
-                    )))
+                    format!("Service '{service}' restarted")
                 } else if let Some(project_id) = project.as_deref() {
                     self.restart_project_target(
                         project_id,
                         config.as_deref().map(Path::new),
                     )?;
-                    self.refresh_status_cache();
-                    Ok(ControlResponse::Message(format!(
-                        "Project '{project_id}' restarted"
-                    )))
+                    format!("Project '{project_id}' restarted")
                 } else {
                     self.restart_all_targets(config.as_deref().map(Path::new))?;
-                    self.refresh_status_cache();
-                    Ok(ControlResponse::Message("All services restarted".into()))
+                    "All services restarted".to_string()
+                };
+                let verified = if wait {
+                    Some(self.verify_restart_targets(
+                        service.as_deref(),
+                        project.as_deref(),
+                        with_dependents,
+                    ))
+                } else {
+                    None
+                };
+                self.refresh_status_cache();
+                match verified {
+                    Some(services) => Ok(ControlResponse::Restarted(RestartReport {
+                        message,
+                        services: services?,
+                    })),
+                    None => Ok(ControlResponse::Message(message)),
+                }
+            }
+            ControlCommand::Bulk {
+                action,
+                services,
+                project,
+            } => {
+                // One request, applied service by service on the owner thread, so
+                // no other mutation interleaves with the batch. A failure is
+                // recorded against its service and the rest still run.
+                let mut outcomes = Vec::with_capacity(services.len());
+                for service in services {
+                    let error = match self
+                        .handle_command(action.command(&service, project.as_deref()))
+                    {
+                        Ok(ControlResponse::Error(message)) => Some(message),
+                        Ok(ControlResponse::Diag(diag)) => Some(diag.title),
+                        Ok(_) => None,
+                        Err(SupervisorError::Process(ProcessManagerError::Diag(
+                            diag,
+                        ))) => Some(diag.title),
+                        Err(err) => Some(err.to_string()),
+                    };
+                    if let Some(error) = &error {
+                        warn!(
+                            "Bulk {} of '{service}' failed: {error}",
+                            action.progressive()
+                        );
+                    }
+                    outcomes.push(BulkOutcome { service, error });
+                }
+                self.refresh_status_cache();
+                Ok(ControlResponse::Bulk(outcomes))
+            }
+            ControlCommand::RestartFailed { project } => {
+                let (restarted, errors) =
+                    self.restart_failed_targets(project.as_deref())?;
+                self.refresh_status_cache();
+                if restarted.is_empty() && errors.is_empty() {
+                    return Ok(ControlResponse::Message(
+                        "No failed services to restart".to_string(),
+                    ));
+                }
+                let mut lines = Vec::new();
+                if !restarted.is_empty() {
+                    let names = restarted
+                        .iter()
+                        .map(|service| format!("{}/{}", service.project, service.name))
+                        .collect::<Vec<_>>();
+                    lines.push(format!(
+                        "Restarted {} failed service{}: {}",
+                        names.len(),
+                        if names.len() == 1 { "" } else { "s" },
+                        names.join(", ")
+                    ));
+                }
+                if errors.is_empty() {
+                    return Ok(ControlResponse::Message(lines.join("\n")));
+                }
+                // Any service left down fails the command, but the report
+                // still names the ones that did come back.
+                lines.push(format!(
+                    "Could not restart {} failed service{}:",
+                    errors.len(),
+                    if errors.len() == 1 { "" } else { "s" }
+                ));
+                for (service, error) in &errors {
+                    lines
+                        .push(format!("  {}/{}: {error}", service.project, service.name));
+                }
+                Ok(ControlResponse::Error(lines.join("\n")))
+            }
+            ControlCommand::VerifyRestart {
+                service,
+                project,
+                with_dependents,
+            } => {
+                let services = self.verify_restart_targets(
+                    service.as_deref(),
+                    project.as_deref(),
+                    with_dependents,
+                )?;
+                Ok(ControlResponse::Restarted(RestartReport {
+                    message: "Supervisor started".to_string(),
+                    services,
+                }))
+            }
+            ControlCommand::Disable { service, project } => {
+                let (project_id, service_name) =
+                    self.set_service_disabled_target(&service, project.as_deref(), true)?;
+                self.refresh_status_cache();
+                Ok(ControlResponse::Message(format!(
+                    "Service '{service_name}' disabled in project '{project_id}'"
+                )))
+            }
+            ControlCommand::Enable { service, project } => {
+                let (project_id, service_name) = self.set_service_disabled_target(
+                    &service,
+                    project.as_deref(),
+                    false,
+                )?;
+                self.refresh_status_cache();
+                Ok(ControlResponse::Message(format!(
+                    "Service '{service_name}' enabled in project '{project_id}'; run `sysg start {service_name}` to launch it"
+                )))
+            }
+            ControlCommand::Pause => {
+                let newly_paused = self.daemon.pause_restarts();
+                self.refresh_status_cache();
+                Ok(ControlResponse::Message(if newly_paused {
+                    "Supervisor paused; crashed services will not be restarted until `sysg resume`".to_string()
+                } else {
+                    "Supervisor is already paused".to_string()
+                }))
+            }
+            ControlCommand::Resume { restart_crashed } => {
+                let was_paused = self.restarts_paused.load(Ordering::SeqCst);
+                let daemons = std::iter::once(&self.daemon)
+                    .chain(self.extra_projects.values().map(|runtime| &runtime.daemon));
+                let mut crashed = Vec::new();
+                for daemon in daemons {
+                    let project = daemon.config().project.id.clone();
+                    crashed.extend(
+                        daemon
+                            .resume_restarts(restart_crashed)
+                            .into_iter()
+                            .map(|service| format!("{project}/{service}")),
+                    );
                 }
+                self.refresh_status_cache();
+                let message = if !was_paused {
+                    "Supervisor is not paused".to_string()
+                } else if crashed.is_empty() {
+                    "Supervisor resumed; no services crashed while paused".to_string()
+                } else if restart_crashed {
+                    format!("Supervisor resumed; restarting {}", crashed.join(", "))
+                } else {
+                    format!(
+                        "Supervisor resumed; left down after crashing while paused: {} (run `sysg resume --restart` or `sysg restart --all-failed` to bring them back)",
+                        crashed.join(", ")
+                    )
+                };
+                Ok(ControlResponse::Message(message))
+            }
+            ControlCommand::RunCron { hash } => {
+                Ok(match self.cron_manager.request_manual_run(&hash) {
+                    Some(ManualRunOutcome::Queued(name)) => ControlResponse::Message(
+                        format!("Cron job '{name}' will run on the next scheduler tick"),
+                    ),
+                    Some(ManualRunOutcome::Overlapped(name)) => {
+                        ControlResponse::Error(format!(
+                            "cron job '{name}' is still running; recorded the manual run as an overlap"
+                        ))
+                    }
+                    None => ControlResponse::Error(format!(
+                        "no cron job '{hash}' is scheduled by this supervisor"
+                    )),
+                })
             }
             ControlCommand::Inspect {
                 unit,
@@ -3613,16 +4449,29 @@ impl Supervisor {
             ControlCommand::Logs { .. } => Ok(ControlResponse::Error(
                 "logs command is streamed separately".into(),
             )),
-            ControlCommand::ClearLogs { service, project } => {
-                self.clear_logs(service.as_deref(), project.as_deref())?;
-                Ok(ControlResponse::Message(match service {
-                    Some(name) => format!("Cleared logs for '{name}'"),
-                    None => "Cleared logs for all services".into(),
+            ControlCommand::ClearLogs {
+                service,
+                project,
+                keep_history,
+            } => {
+                self.clear_logs(service.as_deref(), project.as_deref(), keep_history)?;
+                Ok(ControlResponse::Message(match (service, project) {
+                    (Some(name), _) => format!("Cleared logs for '{name}'"),
+                    (None, Some(project)) => {
+                        format!("Cleared logs for project '{project}'")
+                    }
+                    (None, None) => "Cleared logs for all services".into(),
                 }))
             }
             ControlCommand::BootStream => Ok(ControlResponse::Error(
                 "boot stream is served separately".into(),
             )),
+            ControlCommand::LogFollow { .. } => Ok(ControlResponse::Error(
+                "log follow is streamed separately".into(),
+            )),
+            ControlCommand::Attach { .. } => Ok(ControlResponse::Error(
+                "attach is streamed separately".into(),
+            )),
             ControlCommand::Spawn {
                 parent_pid,
                 name,
@@ -3645,35 +4494,144 @@ impl Supervisor {
             ControlCommand::Shutdown => {
                 Ok(ControlResponse::Message("Supervisor shutting down".into()))
             }
-            ControlCommand::Status { live } => {
-                let mut snapshot = if live {
-                    self.collect_live_snapshot_for_request()?
-                } else {
-                    self.collect_configured_snapshot()?
-                };
+            ControlCommand::Status { live, no_children } => {
+                let mut snapshot = self.collect_aggregate_snapshot(live, !no_children)?;
                 Self::apply_boots(&mut snapshot, &self.boots);
-                self.status_cache.replace(snapshot.clone());
+                snapshot.paused = self.restarts_paused.load(Ordering::SeqCst);
+                // A childless snapshot would blank the trees other readers of
+                // the cache (e.g. `sysg tree`) rely on.
+                if !no_children {
+                    self.status_cache.replace(snapshot.clone());
+                }
                 Ok(ControlResponse::Status(snapshot))
             }
             ControlCommand::Version => Ok(ControlResponse::DaemonVersion(
                 env!("CARGO_PKG_VERSION").to_string(),
             )),
-            ControlCommand::Upgrade { .. } => Ok(ControlResponse::Error(
-                "upgrade command must be handled by the supervisor owner loop".into(),
-            )),
+            ControlCommand::Upgrade { .. } | ControlCommand::Reexec { .. } => {
+                Ok(ControlResponse::Error(
+                    "upgrade command must be handled by the supervisor owner loop".into(),
+                ))
+            }
             ControlCommand::CurrentOp => {
                 Ok(ControlResponse::CurrentOp(self.op_slot.report()))
             }
+            ControlCommand::Services { project } => Ok(ControlResponse::Services(
+                Self::service_selectors(&self.boot_projects, project.as_deref()),
+            )),
+            ControlCommand::Samples {
+                unit,
+                project,
+                samples,
+            } => {
+                let snapshot = self.collect_configured_snapshot()?;
+                self.status_cache.replace(snapshot.clone());
+                Ok(Self::unit_samples(
+                    &snapshot,
+                    &self.metrics_store,
+                    &unit,
+                    project.as_deref(),
+                    samples as usize,
+                ))
+            }
+            ControlCommand::ServiceHashes { project } => {
+                Ok(ControlResponse::ServiceHashes(Self::service_hashes(
+                    &self.boot_projects,
+                    project.as_deref(),
+                )))
+            }
         }
     }
 
-    /// Resolves a service configuration by name across the primary daemon and
-    /// any additional managed projects.
-    fn resolve_service_config(
-        &self,
-        service_name: &str,
-    ) -> Option<crate::config::ServiceConfig> {
-        if let Some(config) = self.daemon.config().services.get(service_name) {
+    /// Lists every loaded service as a sorted `project/service` selector.
+    fn service_selectors(
+        projects: &RwLock<HashMap<String, Daemon>>,
+        project: Option<&str>,
+    ) -> Vec<String> {
+        let projects = projects
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let mut selectors = projects
+            .iter()
+            .filter(|(id, _)| project.is_none_or(|wanted| wanted == id.as_str()))
+            .flat_map(|(id, daemon)| {
+                daemon
+                    .config()
+                    .services
+                    .keys()
+                    .map(|name| format!("{id}/{name}"))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        selectors.sort();
+        selectors
+    }
+
+    /// Answers a [`ControlCommand::Samples`] request from `snapshot`, which
+    /// resolves the unit selector to the hash its samples are stored under.
+    fn unit_samples(
+        snapshot: &StatusSnapshot,
+        metrics_store: &MetricsHandle,
+        unit: &str,
+        project: Option<&str>,
+        limit: usize,
+    ) -> ControlResponse {
+        let matching = snapshot
+            .units
+            .iter()
+            .filter(|status| unit_matches_selector(status, unit, project))
+            .collect::<Vec<_>>();
+        let projects = matching
+            .iter()
+            .filter_map(|unit| unit.project.as_ref().map(|project| project.id.clone()))
+            .collect::<BTreeSet<_>>();
+        if project.is_none() && projects.len() > 1 {
+            return ControlResponse::Diag(Box::new(start::ambiguous_service(
+                unit,
+                &projects.into_iter().collect::<Vec<_>>(),
+            )));
+        }
+        let Some(status) = matching.first() else {
+            return ControlResponse::Diag(Box::new(crate::stop::service_not_found(unit)));
+        };
+        let samples = metrics_store
+            .read()
+            .map(|store| store.latest_samples(&status.hash, limit))
+            .unwrap_or_default();
+        ControlResponse::Samples(samples)
+    }
+
+    /// Collects per-field config hashes for every loaded project, optionally
+    /// narrowed to one, so a client can preview a reload against what is live.
+    fn service_hashes(
+        projects: &RwLock<HashMap<String, Daemon>>,
+        project: Option<&str>,
+    ) -> ipc::ProjectServiceHashes {
+        let projects = projects
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        projects
+            .iter()
+            .filter(|(id, _)| project.is_none_or(|wanted| wanted == id.as_str()))
+            .map(|(id, daemon)| {
+                let services = daemon
+                    .config()
+                    .services
+                    .iter()
+                    .map(|(name, service)| (name.clone(), service.field_hashes()))
+                    .collect();
+                (id.clone(), services)
+            })
+            .collect()
+    }
+
+    /// Resolves a service configuration by name across the primary daemon and
+    /// any additional managed projects.
+    fn resolve_service_config(
+        &self,
+        service_name: &str,
+    ) -> Option<crate::config::ServiceConfig> {
+        if let Some(config) = self.daemon.config().services.get(service_name) {
             return Some(config.clone());
         }
         self.extra_projects.values().find_map(|project| {
@@ -4068,7 +5026,9 @@ impl Supervisor {
         let mut daemon = Daemon::from_config(config, self.detach_children)?;
         daemon.set_timeouts(self.timeouts.clone());
         daemon.set_pipe_stderr(self.pipe_stderr);
+        daemon.set_parallel_start(self.parallel_start);
         daemon.set_op_slot(self.op_slot.clone());
+        daemon.set_restart_pause(Arc::clone(&self.restarts_paused));
         if let Ok(mut projects) = self.boot_projects.write() {
             projects.insert(project_id.clone(), daemon.clone());
         }
@@ -4126,7 +5086,10 @@ impl Supervisor {
         let mut replacement = Daemon::from_config(config, self.detach_children)?;
         replacement.set_timeouts(self.timeouts.clone());
         replacement.set_pipe_stderr(self.pipe_stderr);
+        replacement.set_parallel_start(self.parallel_start);
         replacement.set_op_slot(self.op_slot.clone());
+        replacement.set_restart_pause(Arc::clone(&self.restarts_paused));
+        replacement.carry_paused_crashes(&old_daemon);
 
         self.stop_primary_workers();
         old_daemon.cancel_boot();
@@ -4180,6 +5143,7 @@ impl Supervisor {
         path: &Path,
         service_filter: Option<String>,
         mode: ProjectRunMode,
+        env_overrides: &BTreeMap<String, String>,
     ) -> Result<String, SupervisorError> {
         let resolved = if path.is_absolute() {
             path.to_path_buf()
@@ -4188,7 +5152,14 @@ impl Supervisor {
         };
         let resolved = resolved.canonicalize().unwrap_or(resolved);
         let trusted = runtime::open_trusted_config(&resolved)?;
-        let configs = load_projects_from_file(trusted, &resolved)?;
+        let configs =
+            load_projects_from_file_with_env(trusted, &resolved, env_overrides)?;
+        let primary_project = self.daemon.config().project.id.clone();
+        for config in &configs {
+            if config.project.id != primary_project {
+                self.check_supervisor_env(config)?;
+            }
+        }
 
         let mut last_id = None;
         for config in configs {
@@ -4208,6 +5179,25 @@ impl Supervisor {
         })
     }
 
+    /// Rejects a project from another manifest whose `supervisor.env` differs
+    /// from the primary's. The block is applied once, at boot, so taking the
+    /// project would silently drop it.
+    fn check_supervisor_env(&self, config: &Config) -> Result<(), SupervisorError> {
+        if config.supervisor.env.is_empty()
+            || config.supervisor.env == self.daemon.config().supervisor.env
+        {
+            return Ok(());
+        }
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "project '{}' sets its own supervisor.env, but supervisor.env is only honored in the primary project; move it to the manifest that starts the supervisor",
+                config.project.id
+            ),
+        )
+        .into())
+    }
+
     /// Registers and starts a single already-parsed project config, the unit of
     /// work `add_project_config` loops over once per project a file declares.
     fn register_one_project(
@@ -4281,7 +5271,9 @@ impl Supervisor {
             let mut daemon = Daemon::from_config(config.clone(), self.detach_children)?;
             daemon.set_timeouts(self.timeouts.clone());
             daemon.set_pipe_stderr(self.pipe_stderr);
+            daemon.set_parallel_start(self.parallel_start);
             daemon.set_op_slot(self.op_slot.clone());
+            daemon.set_restart_pause(Arc::clone(&self.restarts_paused));
             if let Ok(mut projects) = self.boot_projects.write() {
                 projects.insert(project_id.clone(), daemon.clone());
             }
@@ -4492,19 +5484,21 @@ impl Supervisor {
         }
     }
 
-    /// Restarts one service in the selected project without reloading unrelated projects.
-    /// Restarts `root` and its transitive dependents in dependency order.
-    /// A dependent must re-handshake the freshly-restarted dependency, so
-    /// `restart -s A` bounces A then everything that depends on A. A dependent
-    /// carrying `skip: true` is honored — it is not launched by the cascade.
+    /// Restarts `root`, and with `with_dependents` its transitive dependents in
+    /// dependency order, so `restart -s A --with-dependents` bounces A then
+    /// everything that depends on A. A dependent carrying `skip: true` is
+    /// honored — it is not launched by the cascade. With `build_first`, each
+    /// service's `pre_start` runs before its old instance is stopped.
     fn cascade_restart(
         daemon: &Daemon,
         config: &Config,
         root: &str,
         target_project: &str,
+        with_dependents: bool,
+        build_first: bool,
     ) -> Result<(), SupervisorError> {
         daemon.begin_boot();
-        for name in cascade_restart_order(config, root) {
+        for name in service_restart_order(config, root, with_dependents) {
             let Some(service_config) = config.services.get(&name) else {
                 continue;
             };
@@ -4512,22 +5506,155 @@ impl Supervisor {
                 info!("Skipping dependent '{name}' during cascade restart (skip flag)");
                 continue;
             }
+            if daemon.is_service_disabled(&name) {
+                info!("Skipping dependent '{name}' during cascade restart (disabled)");
+                continue;
+            }
             reject_direct_cron_control(
                 service_config,
                 &name,
                 target_project,
                 "restarted",
             )?;
-            daemon.restart_service(&name, service_config)?;
+            daemon.restart_service(&name, service_config, build_first)?;
         }
         Ok(())
     }
 
+    /// Restarts every service the live snapshot reports as failing (or exited
+    /// with an error), project by project in dependency order. Healthy services
+    /// and cron units are left alone.
+    ///
+    /// One service failing to come back does not stop the others: the services
+    /// that restarted are returned alongside each one that did not and why.
+    /// The project's boot epoch is left alone, so an in-flight boot of the same
+    /// project is not cancelled.
+    fn restart_failed_targets(
+        &self,
+        project: Option<&str>,
+    ) -> Result<(Vec<RestartedService>, Vec<FailedRestart>), SupervisorError> {
+        let snapshot = self.collect_live_snapshot_for_request()?;
+        let failed: HashSet<(String, String)> = snapshot
+            .units
+            .iter()
+            .filter(|unit| {
+                matches!(unit.kind, crate::status::UnitKind::Service)
+                    && project_matches(unit, project)
+                    && (matches!(unit.health, crate::status::UnitHealth::Failing)
+                        || matches!(
+                            unit.lifecycle,
+                            Some(ServiceLifecycleStatus::ExitedWithError)
+                        ))
+            })
+            .filter_map(|unit| {
+                let project = unit.project.as_ref()?;
+                Some((project.id.clone(), unit.name.clone()))
+            })
+            .collect();
+
+        let daemons = std::iter::once(&self.daemon)
+            .chain(self.extra_projects.values().map(|runtime| &runtime.daemon));
+        let mut restarted = Vec::new();
+        let mut errors = Vec::new();
+        for daemon in daemons {
+            let config = daemon.config();
+            let project_id = config.project.id.clone();
+            let targets = config
+                .service_start_order()?
+                .into_iter()
+                .filter(|name| failed.contains(&(project_id.clone(), name.clone())))
+                .collect::<Vec<_>>();
+            for name in targets {
+                let Some(service_config) = config.services.get(&name) else {
+                    continue;
+                };
+                info!("Restarting failed service '{name}' in project '{project_id}'");
+                let service = RestartedService {
+                    project: project_id.clone(),
+                    name,
+                };
+                match daemon.restart_service(&service.name, service_config, false) {
+                    Ok(()) => restarted.push(service),
+                    Err(err) => {
+                        warn!(
+                            "Failed to restart '{}' in project '{}': {err}",
+                            service.name, service.project
+                        );
+                        errors.push((service, err.to_string()));
+                    }
+                }
+            }
+        }
+        Ok((restarted, errors))
+    }
+
+    /// Re-verifies what a restart touched, for `restart --wait`: the service
+    /// (plus its dependents with `--with-dependents`), a whole project, or every
+    /// loaded project.
+    fn verify_restart_targets(
+        &self,
+        service: Option<&str>,
+        project: Option<&str>,
+        with_dependents: bool,
+    ) -> Result<Vec<RestartedService>, SupervisorError> {
+        let (selector_project, service_name) = match service {
+            Some(selector) => split_project_selector(selector)
+                .map(|(project_id, name)| (Some(project_id), Some(name)))
+                .unwrap_or((None, Some(selector))),
+            None => (None, None),
+        };
+        let requested_project = project.or(selector_project);
+
+        let daemons = std::iter::once(&self.daemon)
+            .chain(self.extra_projects.values().map(|runtime| &runtime.daemon));
+        let mut verified = Vec::new();
+        let mut failed = Vec::new();
+        for daemon in daemons {
+            let config = daemon.config();
+            let project_id = config.project.id.clone();
+            if requested_project.is_some_and(|id| id != project_id) {
+                continue;
+            }
+            let targets = match service_name {
+                Some(root) if config.services.contains_key(root) => {
+                    service_restart_order(config.as_ref(), root, with_dependents)
+                }
+                Some(_) => continue,
+                None => {
+                    let mut names = config.services.keys().cloned().collect::<Vec<_>>();
+                    names.sort();
+                    names
+                }
+            };
+            match daemon.verify_restarted(&targets) {
+                Ok(names) => {
+                    verified.extend(names.into_iter().map(|name| RestartedService {
+                        project: project_id.clone(),
+                        name,
+                    }))
+                }
+                Err(ProcessManagerError::ServicesNotRunning { services }) => {
+                    failed.extend(services);
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        if failed.is_empty() {
+            Ok(verified)
+        } else {
+            Err(ProcessManagerError::ServicesNotRunning { services: failed }.into())
+        }
+    }
+
+    /// Restarts one service in the selected project without reloading unrelated projects.
     fn restart_single_service_target(
         &mut self,
         selector: &str,
         project: Option<&str>,
         config_path: Option<&Path>,
+        with_dependents: bool,
+        build_first: bool,
     ) -> Result<(), SupervisorError> {
         let (selector_project, service_name) = split_project_selector(selector)
             .map(|(project_id, service_name)| (Some(project_id), service_name))
@@ -4635,6 +5762,8 @@ impl Supervisor {
                 live.as_ref(),
                 service_name,
                 &target_project,
+                with_dependents,
+                build_first,
             );
         }
 
@@ -4669,6 +5798,8 @@ impl Supervisor {
             live.as_ref(),
             service_name,
             &target_project,
+            with_dependents,
+            build_first,
         )
     }
 
@@ -4677,7 +5808,8 @@ impl Supervisor {
         &self,
         selector: &str,
         project: Option<&str>,
-    ) -> Result<(String, String), SupervisorError> {
+        grace: Option<Duration>,
+    ) -> Result<(String, String, StopOutcome), SupervisorError> {
         let (selector_project, service_name) = split_project_selector(selector)
             .map(|(project_id, service_name)| (Some(project_id), service_name))
             .unwrap_or((None, selector));
@@ -4712,8 +5844,9 @@ impl Supervisor {
         let primary_project = self.daemon.config().project.id.clone();
 
         if target_project == primary_project {
-            self.daemon.stop_service(service_name)?;
-            return Ok((target_project, service_name.to_string()));
+            let grace = grace.unwrap_or_else(|| self.daemon.stop_grace(service_name));
+            let outcome = self.daemon.stop_service_within(service_name, grace)?;
+            return Ok((target_project, service_name.to_string(), outcome));
         }
 
         let Some(project_runtime) = self.extra_projects.get(&target_project) else {
@@ -4737,13 +5870,64 @@ impl Supervisor {
             .into());
         }
 
-        project_runtime.daemon.stop_service(service_name)?;
-        Ok((target_project, service_name.to_string()))
+        let grace =
+            grace.unwrap_or_else(|| project_runtime.daemon.stop_grace(service_name));
+        let outcome = project_runtime
+            .daemon
+            .stop_service_within(service_name, grace)?;
+        Ok((target_project, service_name.to_string(), outcome))
+    }
+
+    /// Persists `sysg disable`/`sysg enable` for one service. Disabling stops a
+    /// running instance first, then records the service as skipped.
+    fn set_service_disabled_target(
+        &self,
+        selector: &str,
+        project: Option<&str>,
+        disabled: bool,
+    ) -> Result<(String, String), SupervisorError> {
+        let (project_id, service_name) = if disabled {
+            let (project_id, service_name, _) =
+                self.stop_single_service_target(selector, project, None)?;
+            (project_id, service_name)
+        } else {
+            let (selector_project, service_name) = split_project_selector(selector)
+                .map(|(project_id, service_name)| (Some(project_id), service_name))
+                .unwrap_or((None, selector));
+            if self.projects_containing_service(service_name).is_empty() {
+                return Err(ProcessManagerError::Diag(Box::new(
+                    crate::stop::service_not_found(service_name),
+                ))
+                .into());
+            }
+            let project_id = self.resolve_service_target_project(
+                service_name,
+                project,
+                selector_project,
+                None,
+            )?;
+            (project_id, service_name.to_string())
+        };
+
+        let daemon = if project_id == self.daemon.config().project.id {
+            &self.daemon
+        } else {
+            let Some(project_runtime) = self.extra_projects.get(&project_id) else {
+                return Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("project '{project_id}' is not managed by this supervisor"),
+                )
+                .into());
+            };
+            &project_runtime.daemon
+        };
+        daemon.set_service_disabled(&service_name, disabled)?;
+        Ok((project_id, service_name))
     }
 
     /// Handles refresh status cache.
     fn refresh_status_cache(&mut self) {
-        match self.collect_aggregate_snapshot(false) {
+        match self.collect_aggregate_snapshot(false, true) {
             Ok(snapshot) => self.status_cache.replace(snapshot),
             Err(err) => error!("failed to refresh status snapshot: {err}"),
         }
@@ -4857,14 +6041,14 @@ impl Supervisor {
 
     /// Collects a fresh status snapshot using each project's configured snapshot mode.
     fn collect_configured_snapshot(&self) -> Result<StatusSnapshot, SupervisorError> {
-        self.collect_aggregate_snapshot(false)
+        self.collect_aggregate_snapshot(false, true)
     }
 
     /// Collects a fresh status snapshot with immediate runtime collection enabled.
     fn collect_live_snapshot_for_request(
         &self,
     ) -> Result<StatusSnapshot, SupervisorError> {
-        self.collect_aggregate_snapshot(true)
+        self.collect_aggregate_snapshot(true, true)
     }
 
     /// Handles shutdown runtime.
@@ -4965,6 +6149,7 @@ impl Supervisor {
 
         let wait_pid = Pid::from_raw(pid as i32);
         let start = std::time::Instant::now();
+        let mut sampler = RunSampler::new(pid);
 
         loop {
             match waitpid(wait_pid, Some(WaitPidFlag::WNOHANG)) {
@@ -4982,9 +6167,11 @@ impl Supervisor {
                                 max_wait_time.as_secs()
                             )),
                             exit_code: None,
+                            metrics: sampler.into_samples(),
                         });
                     }
 
+                    sampler.poll();
                     thread::sleep(poll_interval);
                 }
                 Ok(WaitStatus::Exited(_, exit_code)) => {
@@ -4999,6 +6186,7 @@ impl Supervisor {
                     return Ok(CronCompletionOutcome {
                         status,
                         exit_code: Some(exit_code),
+                        metrics: sampler.into_samples(),
                     });
                 }
                 Ok(WaitStatus::Signaled(_, signal, _)) => {
@@ -5011,6 +6199,7 @@ impl Supervisor {
                             "Terminated by signal {signal}"
                         )),
                         exit_code: None,
+                        metrics: sampler.into_samples(),
                     });
                 }
                 Ok(WaitStatus::Stopped(..)) | Ok(WaitStatus::Continued(_)) => {
@@ -5029,6 +6218,7 @@ impl Supervisor {
                     return Ok(CronCompletionOutcome {
                         status: CronExecutionStatus::Success,
                         exit_code: Some(0),
+                        metrics: sampler.into_samples(),
                     });
                 }
                 Err(e) => {
@@ -5055,9 +6245,10 @@ mod tests {
     use super::*;
     use crate::{
         config::{
-            LogsConfig, MetricsConfig, ProjectConfig, ServiceConfig, StatusConfig,
-            Version,
+            LogsConfig, MetricsConfig, ProjectConfig, ProjectDeploymentConfig,
+            ServiceConfig, StatusConfig, Version,
         },
+        ipc::BulkAction,
         runtime,
         status::{
             OverallHealth, UnitHealth, UnitIntent, UnitKind, UnitState, UnitStatus,
@@ -5097,6 +6288,13 @@ mod tests {
             metrics: MetricsConfig::default(),
             logs: LogsConfig::default(),
             status: StatusConfig::default(),
+            deployment: ProjectDeploymentConfig::default(),
+            on_ready: None,
+            on_start_failure: None,
+            on_failure: Default::default(),
+            dependency_settle: None,
+            supervisor: Default::default(),
+            env_overrides: Default::default(),
         };
 
         let order = Supervisor::startup_service_order(&config, None).unwrap();
@@ -5119,6 +6317,13 @@ mod tests {
             metrics: MetricsConfig::default(),
             logs: LogsConfig::default(),
             status: StatusConfig::default(),
+            deployment: ProjectDeploymentConfig::default(),
+            on_ready: None,
+            on_start_failure: None,
+            on_failure: Default::default(),
+            dependency_settle: None,
+            supervisor: Default::default(),
+            env_overrides: Default::default(),
         };
 
         let order = Supervisor::startup_service_order(&config, Some("worker")).unwrap();
@@ -5126,6 +6331,47 @@ mod tests {
         assert_eq!(order, vec!["worker"]);
     }
 
+    #[test]
+    fn cron_completion_samples_a_short_cpu_burst() {
+        let mut child = std::process::Command::new("sh")
+            .args([
+                "-c",
+                "end=$(( $(date +%s) + 1 )); while [ $(date +%s) -le $end ]; do :; done",
+            ])
+            .spawn()
+            .expect("spawn cpu-burning cron process");
+
+        let outcome = Supervisor::wait_for_cron_completion_with_timeout(
+            child.id(),
+            "burner",
+            Duration::from_secs(30),
+            Duration::from_millis(20),
+        )
+        .expect("wait for burner");
+
+        assert!(matches!(outcome.status, CronExecutionStatus::Success));
+        assert!(
+            outcome.metrics.len() >= 2,
+            "expected a series, got {:?}",
+            outcome.metrics
+        );
+        assert!(outcome.metrics.iter().any(|sample| sample.rss_bytes > 0));
+        assert!(
+            outcome
+                .metrics
+                .iter()
+                .any(|sample| sample.cpu_percent > 0.0)
+        );
+        assert!(
+            outcome
+                .metrics
+                .windows(2)
+                .all(|pair| pair[0].timestamp <= pair[1].timestamp)
+        );
+        // Already reaped by the completion wait; this only satisfies the handle.
+        let _ = child.wait();
+    }
+
     #[test]
     fn cron_completion_timeout_terminates_process_tree() {
         let mut child = std::process::Command::new("sh")
@@ -5207,7 +6453,7 @@ services:
     }
 
     #[test]
-    fn status_and_inspect_commands_refresh_configured_snapshot() {
+    fn supervisor_env_applies_to_the_supervisor_but_not_service_env() {
         let _guard = crate::test_utils::env_lock();
 
         let base = std::env::current_dir()
@@ -5220,6 +6466,7 @@ services:
         let original_home = std::env::var("HOME").ok();
         unsafe {
             std::env::set_var("HOME", &home);
+            std::env::set_var("SYSG_TEST_SUPERVISOR_PRESET", "inherited");
         }
         runtime::init(runtime::RuntimeMode::User);
         runtime::set_drop_privileges(false);
@@ -5229,99 +6476,51 @@ services:
             &config_path,
             r#"
 version: "2"
-status:
-  snapshot_mode: summary
+supervisor:
+  env:
+    SYSG_TEST_SUPERVISOR_ONLY: "on"
+    SYSG_TEST_SUPERVISOR_PRESET: "manifest"
 services:
-  cached:
+  api:
     command: "/bin/true"
+    env:
+      vars:
+        SYSG_TEST_SERVICE_ONLY: "on"
 "#,
         )
         .expect("write config");
 
-        let mut supervisor =
+        let _supervisor =
             Supervisor::new(config_path, false, None).expect("create supervisor");
-        let cached_unit = UnitStatus {
-            name: "cached".into(),
-            hash: "cached-hash".into(),
-            project: None,
-            kind: UnitKind::Service,
-            lifecycle: None,
-            state: UnitState::Unknown,
-            intent: UnitIntent::Manual,
-            health: UnitHealth::Healthy,
-            process: None,
-            uptime: None,
-            last_exit: None,
-            cron: None,
-            metrics: None,
-            command: Some("/bin/true".into()),
-            runtime_command: None,
-            spawned_children: Vec::new(),
-        };
-        supervisor.status_cache.replace(StatusSnapshot {
-            schema_version: crate::status::STATUS_SCHEMA_VERSION.into(),
-            captured_at: Utc::now(),
-            overall_health: OverallHealth::Healthy,
-            units: vec![cached_unit],
-        });
-
-        match supervisor
-            .handle_command(ControlCommand::Status { live: false })
-            .expect("status response")
-        {
-            ControlResponse::Status(snapshot) => {
-                assert_eq!(snapshot.units.len(), 1);
-                assert_eq!(snapshot.units[0].name, "cached");
-                assert_ne!(snapshot.units[0].hash, "cached-hash");
-            }
-            other => panic!("expected status response, got {other:?}"),
-        }
-
-        match supervisor
-            .handle_command(ControlCommand::Inspect {
-                unit: "cached".into(),
-                project: None,
-                samples: 10,
-                live: false,
-            })
-            .expect("inspect response")
-        {
-            ControlResponse::Inspect(payload) => {
-                assert_eq!(
-                    payload.unit.as_ref().map(|unit| unit.name.as_str()),
-                    Some("cached")
-                );
-                assert_ne!(
-                    payload.unit.as_ref().map(|unit| unit.hash.as_str()),
-                    Some("cached-hash")
-                );
-            }
-            other => panic!("expected inspect response, got {other:?}"),
-        }
 
-        match supervisor
-            .handle_command(ControlCommand::Status { live: true })
-            .expect("live status response")
-        {
-            ControlResponse::Status(snapshot) => {
-                assert_eq!(snapshot.units.len(), 1);
-                assert_eq!(snapshot.units[0].name, "cached");
-                assert_ne!(snapshot.units[0].hash, "cached-hash");
-            }
-            other => panic!("expected status response, got {other:?}"),
-        }
+        assert_eq!(
+            std::env::var("SYSG_TEST_SUPERVISOR_ONLY").as_deref(),
+            Ok("on")
+        );
+        assert_eq!(
+            std::env::var("SYSG_TEST_SUPERVISOR_PRESET").as_deref(),
+            Ok("inherited"),
+            "the inherited environment wins over supervisor.env"
+        );
+        assert!(std::env::var_os("SYSG_TEST_SERVICE_ONLY").is_none());
+        let keys = runtime::supervisor_env_keys();
+        assert!(keys.contains(&"SYSG_TEST_SUPERVISOR_ONLY".to_string()));
+        assert!(!keys.contains(&"SYSG_TEST_SUPERVISOR_PRESET".to_string()));
 
         unsafe {
-            if let Some(home) = original_home {
-                std::env::set_var("HOME", home);
-            } else {
-                std::env::remove_var("HOME");
-            }
+            std::env::remove_var("SYSG_TEST_SUPERVISOR_ONLY");
+            std::env::remove_var("SYSG_TEST_SUPERVISOR_PRESET");
+        }
+        match original_home {
+            Some(val) => unsafe { std::env::set_var("HOME", val) },
+            None => unsafe { std::env::remove_var("HOME") },
         }
+        runtime::init(runtime::RuntimeMode::User);
+        runtime::set_drop_privileges(false);
     }
 
     #[test]
-    fn add_project_config_makes_second_project_visible_in_status() {
+    fn added_project_with_its_own_supervisor_env_is_rejected() {
         let _guard = crate::test_utils::env_lock();
 
         let base = std::env::current_dir()
@@ -5340,17 +6539,19 @@ services:
 
         let alpha_config = temp.path().join("alpha.yaml");
         let beta_config = temp.path().join("beta.yaml");
-        let beta_updated_config = temp.path().join("beta-updated.yaml");
+        let gamma_config = temp.path().join("gamma.yaml");
         fs::write(
             &alpha_config,
             r#"
 version: "2"
 project:
   id: alpha
-  name: Alpha
+supervisor:
+  env:
+    SYSG_TEST_PRIMARY_SUPERVISOR: "on"
 services:
   alpha_worker:
-    command: "/bin/sleep 31"
+    command: "/bin/true"
 "#,
         )
         .expect("write alpha config");
@@ -5360,30 +6561,318 @@ services:
 version: "2"
 project:
   id: beta
-  name: Beta
+supervisor:
+  env:
+    SYSG_TEST_ADDED_SUPERVISOR: "on"
 services:
   beta_worker:
-    command: "/bin/sleep 32"
-  beta_cron:
-    command: "/bin/echo beta"
-    cron:
-      expression: "*/30 * * * *"
+    command: "/bin/true"
 "#,
         )
         .expect("write beta config");
         fs::write(
-            &beta_updated_config,
+            &gamma_config,
             r#"
 version: "2"
 project:
-  id: beta
-  name: Beta Updated
+  id: gamma
+supervisor:
+  env:
+    SYSG_TEST_PRIMARY_SUPERVISOR: "on"
 services:
-  beta_worker:
-    command: "/bin/sleep 33"
+  gamma_worker:
+    command: "/bin/true"
 "#,
         )
-        .expect("write updated beta config");
+        .expect("write gamma config");
+
+        let mut supervisor =
+            Supervisor::new(alpha_config, false, None).expect("create supervisor");
+        let err = supervisor
+            .handle_command(ControlCommand::AddProject {
+                config: beta_config.to_string_lossy().to_string(),
+                service: None,
+                mode: ProjectRunMode::Foreground,
+                env: BTreeMap::new(),
+            })
+            .expect_err("a second supervisor.env block should be rejected");
+        assert!(
+            err.to_string()
+                .contains("supervisor.env is only honored in the primary project"),
+            "{err}"
+        );
+        assert!(!supervisor.extra_projects.contains_key("beta"));
+        assert!(std::env::var_os("SYSG_TEST_ADDED_SUPERVISOR").is_none());
+
+        supervisor
+            .handle_command(ControlCommand::AddProject {
+                config: gamma_config.to_string_lossy().to_string(),
+                service: None,
+                mode: ProjectRunMode::Foreground,
+                env: BTreeMap::new(),
+            })
+            .expect("a block matching the primary's is already honored");
+        assert!(supervisor.extra_projects.contains_key("gamma"));
+
+        supervisor
+            .shutdown_runtime()
+            .expect("shutdown test supervisor runtime");
+        unsafe {
+            std::env::remove_var("SYSG_TEST_PRIMARY_SUPERVISOR");
+        }
+        match original_home {
+            Some(val) => unsafe { std::env::set_var("HOME", val) },
+            None => unsafe { std::env::remove_var("HOME") },
+        }
+        runtime::init(runtime::RuntimeMode::User);
+        runtime::set_drop_privileges(false);
+    }
+
+    #[test]
+    fn status_and_inspect_commands_refresh_configured_snapshot() {
+        let _guard = crate::test_utils::env_lock();
+
+        let base = std::env::current_dir()
+            .expect("current_dir")
+            .join("target/tmp-home");
+        fs::create_dir_all(&base).expect("create base dir");
+        let temp = tempdir_in(&base).expect("create tempdir");
+        let home = temp.path().join("home");
+        fs::create_dir_all(&home).expect("create home");
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", &home);
+        }
+        runtime::init(runtime::RuntimeMode::User);
+        runtime::set_drop_privileges(false);
+
+        let config_path = temp.path().join("systemg.yaml");
+        fs::write(
+            &config_path,
+            r#"
+version: "2"
+status:
+  snapshot_mode: summary
+services:
+  cached:
+    command: "/bin/true"
+"#,
+        )
+        .expect("write config");
+
+        let mut supervisor =
+            Supervisor::new(config_path, false, None).expect("create supervisor");
+        let cached_unit = UnitStatus {
+            name: "cached".into(),
+            hash: "cached-hash".into(),
+            project: None,
+            kind: UnitKind::Service,
+            lifecycle: None,
+            state: UnitState::Unknown,
+            intent: UnitIntent::Manual,
+            health: UnitHealth::Healthy,
+            process: None,
+            uptime: None,
+            last_exit: None,
+            cron: None,
+            metrics: None,
+            command: Some("/bin/true".into()),
+            runtime_command: None,
+            spawned_children: Vec::new(),
+            drifted: false,
+            restarts: 0,
+            gave_up_after: None,
+        };
+        supervisor.status_cache.replace(StatusSnapshot {
+            schema_version: crate::status::STATUS_SCHEMA_VERSION.into(),
+            captured_at: Utc::now(),
+            overall_health: OverallHealth::Healthy,
+            paused: false,
+            units: vec![cached_unit],
+        });
+
+        match supervisor
+            .handle_command(ControlCommand::Status {
+                live: false,
+                no_children: false,
+            })
+            .expect("status response")
+        {
+            ControlResponse::Status(snapshot) => {
+                assert_eq!(snapshot.units.len(), 1);
+                assert_eq!(snapshot.units[0].name, "cached");
+                assert_ne!(snapshot.units[0].hash, "cached-hash");
+            }
+            other => panic!("expected status response, got {other:?}"),
+        }
+
+        match supervisor
+            .handle_command(ControlCommand::Inspect {
+                unit: "cached".into(),
+                project: None,
+                samples: 10,
+                live: false,
+            })
+            .expect("inspect response")
+        {
+            ControlResponse::Inspect(payload) => {
+                assert_eq!(
+                    payload.unit.as_ref().map(|unit| unit.name.as_str()),
+                    Some("cached")
+                );
+                assert_ne!(
+                    payload.unit.as_ref().map(|unit| unit.hash.as_str()),
+                    Some("cached-hash")
+                );
+            }
+            other => panic!("expected inspect response, got {other:?}"),
+        }
+
+        match supervisor
+            .handle_command(ControlCommand::Status {
+                live: true,
+                no_children: false,
+            })
+            .expect("live status response")
+        {
+            ControlResponse::Status(snapshot) => {
+                assert_eq!(snapshot.units.len(), 1);
+                assert_eq!(snapshot.units[0].name, "cached");
+                assert_ne!(snapshot.units[0].hash, "cached-hash");
+            }
+            other => panic!("expected status response, got {other:?}"),
+        }
+
+        let project_id = supervisor.daemon.config().project.id.clone();
+        match supervisor
+            .handle_command(ControlCommand::Services { project: None })
+            .expect("services response")
+        {
+            ControlResponse::Services(services) => {
+                assert_eq!(services, vec![format!("{project_id}/cached")]);
+            }
+            other => panic!("expected services response, got {other:?}"),
+        }
+
+        let hash = supervisor.daemon.config().state_key("cached");
+        {
+            let mut store = supervisor.metrics_store.write().expect("metrics store");
+            store.register_unit(&hash);
+            store
+                .record_sample(
+                    &hash,
+                    MetricSample {
+                        timestamp: Utc::now(),
+                        cpu_percent: 1.0,
+                        rss_bytes: 2048,
+                        io_read_bytes: 0,
+                        io_write_bytes: 0,
+                        net_rx_bytes: 0,
+                        net_tx_bytes: 0,
+                    },
+                )
+                .expect("record sample");
+        }
+        match supervisor
+            .handle_command(ControlCommand::Samples {
+                unit: "cached".into(),
+                project: None,
+                samples: 10,
+            })
+            .expect("samples response")
+        {
+            ControlResponse::Samples(samples) => {
+                assert_eq!(samples.last().map(|sample| sample.rss_bytes), Some(2048));
+            }
+            other => panic!("expected samples response, got {other:?}"),
+        }
+        match supervisor
+            .handle_command(ControlCommand::Samples {
+                unit: "ghost".into(),
+                project: None,
+                samples: 10,
+            })
+            .expect("samples response")
+        {
+            ControlResponse::Diag(diag) => {
+                assert_eq!(diag.code, crate::diag::SgCode::TargetNotFound);
+            }
+            other => panic!("expected not-found diagnostic, got {other:?}"),
+        }
+
+        unsafe {
+            if let Some(home) = original_home {
+                std::env::set_var("HOME", home);
+            } else {
+                std::env::remove_var("HOME");
+            }
+        }
+    }
+
+    #[test]
+    fn add_project_config_makes_second_project_visible_in_status() {
+        let _guard = crate::test_utils::env_lock();
+
+        let base = std::env::current_dir()
+            .expect("current_dir")
+            .join("target/tmp-home");
+        fs::create_dir_all(&base).expect("create base dir");
+        let temp = tempdir_in(&base).expect("create tempdir");
+        let home = temp.path().join("home");
+        fs::create_dir_all(&home).expect("create home");
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", &home);
+        }
+        runtime::init(runtime::RuntimeMode::User);
+        runtime::set_drop_privileges(false);
+
+        let alpha_config = temp.path().join("alpha.yaml");
+        let beta_config = temp.path().join("beta.yaml");
+        let beta_updated_config = temp.path().join("beta-updated.yaml");
+        fs::write(
+            &alpha_config,
+            r#"
+version: "2"
+project:
+  id: alpha
+  name: Alpha
+services:
+  alpha_worker:
+    command: "/bin/sleep 31"
+"#,
+        )
+        .expect("write alpha config");
+        fs::write(
+            &beta_config,
+            r#"
+version: "2"
+project:
+  id: beta
+  name: Beta
+services:
+  beta_worker:
+    command: "/bin/sleep 32"
+  beta_cron:
+    command: "/bin/echo beta"
+    cron:
+      expression: "*/30 * * * *"
+"#,
+        )
+        .expect("write beta config");
+        fs::write(
+            &beta_updated_config,
+            r#"
+version: "2"
+project:
+  id: beta
+  name: Beta Updated
+services:
+  beta_worker:
+    command: "/bin/sleep 33"
+"#,
+        )
+        .expect("write updated beta config");
 
         let mut supervisor = Supervisor::new(alpha_config.clone(), false, None)
             .expect("create supervisor");
@@ -5392,11 +6881,15 @@ services:
                 config: beta_config.to_string_lossy().to_string(),
                 service: None,
                 mode: ProjectRunMode::Foreground,
+                env: BTreeMap::new(),
             })
             .expect("add beta project");
 
         match supervisor
-            .handle_command(ControlCommand::Status { live: true })
+            .handle_command(ControlCommand::Status {
+                live: true,
+                no_children: false,
+            })
             .expect("status response")
         {
             ControlResponse::Status(snapshot) => {
@@ -5489,6 +6982,9 @@ services:
                 config: None,
                 service: Some("beta_cron".into()),
                 project: Some("beta".into()),
+                with_dependents: false,
+                build_first: false,
+                wait: false,
             })
             .expect_err("direct cron unit restart should be rejected");
         assert!(matches!(
@@ -5502,11 +6998,17 @@ services:
                 config: Some(beta_config.to_string_lossy().to_string()),
                 service: Some("beta_worker".into()),
                 project: None,
+                with_dependents: false,
+                build_first: false,
+                wait: false,
             })
             .expect("restart beta service from beta config");
 
         match supervisor
-            .handle_command(ControlCommand::Status { live: true })
+            .handle_command(ControlCommand::Status {
+                live: true,
+                no_children: false,
+            })
             .expect("status response after project-scoped restart")
         {
             ControlResponse::Status(snapshot) => {
@@ -5535,6 +7037,9 @@ services:
                 config: Some(beta_updated_config.to_string_lossy().to_string()),
                 service: None,
                 project: Some("beta".into()),
+                with_dependents: false,
+                build_first: false,
+                wait: false,
             })
             .expect("restart beta project from updated config");
 
@@ -5585,7 +7090,7 @@ services:
     }
 
     #[test]
-    fn restart_primary_project_without_config_reloads_stored_manifest() {
+    fn restart_wait_reports_the_service_and_its_dependents() {
         let _guard = crate::test_utils::env_lock();
 
         let base = std::env::current_dir()
@@ -5610,10 +7115,15 @@ version: "2"
 project:
   id: primary
 services:
-  alpha:
+  web:
     command: "/bin/sleep 45"
-  beta:
+  worker:
+    command: "/bin/sleep 45"
+    depends_on: ["web"]
+  migrate:
     command: "/bin/sleep 45"
+    restart_policy: never
+    depends_on: ["web"]
 "#,
         )
         .expect("write config");
@@ -5621,57 +7131,1066 @@ services:
         let mut supervisor =
             Supervisor::new(config_path.clone(), false, None).expect("create supervisor");
 
-        fs::write(
-            &config_path,
-            r#"
-version: "2"
-project:
-  id: primary
-services:
-  alpha:
-    command: "/bin/sleep 60"
-  gamma:
-    command: "/bin/sleep 45"
-"#,
-        )
-        .expect("rewrite config");
+        match supervisor
+            .handle_command(ControlCommand::Restart {
+                config: None,
+                service: Some("web".into()),
+                project: None,
+                with_dependents: true,
+                build_first: false,
+                wait: true,
+            })
+            .expect("restart web and wait")
+        {
+            ControlResponse::Restarted(report) => {
+                assert_eq!(report.message, "Service 'web' restarted");
+                let names = report
+                    .services
+                    .iter()
+                    .map(|service| (service.project.as_str(), service.name.as_str()))
+                    .collect::<Vec<_>>();
+                assert_eq!(names, vec![("primary", "web"), ("primary", "worker")]);
+            }
+            other => panic!("expected restarted report, got {other:?}"),
+        }
+
+        supervisor
+            .shutdown_runtime()
+            .expect("shutdown test supervisor runtime");
+
+        unsafe {
+            if let Some(home) = original_home {
+                std::env::set_var("HOME", home);
+            } else {
+                std::env::remove_var("HOME");
+            }
+        }
+    }
+
+    #[test]
+    fn restart_failed_restarts_only_the_failing_services() {
+        let _guard = crate::test_utils::env_lock();
+
+        let base = std::env::current_dir()
+            .expect("current_dir")
+            .join("target/tmp-home");
+        fs::create_dir_all(&base).expect("create base dir");
+        let temp = tempdir_in(&base).expect("create tempdir");
+        let home = temp.path().join("home");
+        fs::create_dir_all(&home).expect("create home");
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", &home);
+        }
+        runtime::init(runtime::RuntimeMode::User);
+        runtime::set_drop_privileges(false);
+
+        let config_path = temp.path().join("primary.yaml");
+        fs::write(
+            &config_path,
+            r#"
+version: "2"
+project:
+  id: primary
+services:
+  web:
+    command: "/bin/sleep 45"
+  flaky:
+    command: "if [ -f recovered ]; then exec /bin/sleep 45; fi; touch recovered; exit 1"
+    restart_policy: never
+"#,
+        )
+        .expect("write config");
+
+        let mut supervisor =
+            Supervisor::new(config_path.clone(), false, None).expect("create supervisor");
+        let config = supervisor.daemon.config();
+        supervisor
+            .daemon
+            .start_service("web", &config.services["web"])
+            .expect("start web");
+        assert!(
+            supervisor
+                .daemon
+                .start_service("flaky", &config.services["flaky"])
+                .is_err()
+        );
+        let web_pid = supervisor.daemon.running_pid("web");
+
+        match supervisor
+            .handle_command(ControlCommand::RestartFailed { project: None })
+            .expect("restart failed services")
+        {
+            ControlResponse::Message(message) => {
+                assert_eq!(message, "Restarted 1 failed service: primary/flaky");
+            }
+            other => panic!("expected message response, got {other:?}"),
+        }
+        assert!(supervisor.daemon.running_pid("flaky").is_some());
+        assert_eq!(supervisor.daemon.running_pid("web"), web_pid);
+
+        match supervisor
+            .handle_command(ControlCommand::RestartFailed { project: None })
+            .expect("restart with nothing failing")
+        {
+            ControlResponse::Message(message) => {
+                assert_eq!(message, "No failed services to restart");
+            }
+            other => panic!("expected message response, got {other:?}"),
+        }
+
+        supervisor
+            .shutdown_runtime()
+            .expect("shutdown test supervisor runtime");
+
+        unsafe {
+            if let Some(home) = original_home {
+                std::env::set_var("HOME", home);
+            } else {
+                std::env::remove_var("HOME");
+            }
+        }
+    }
+
+    #[test]
+    fn restart_failed_keeps_going_past_a_service_that_stays_down() {
+        let _guard = crate::test_utils::env_lock();
+
+        let base = std::env::current_dir()
+            .expect("current_dir")
+            .join("target/tmp-home");
+        fs::create_dir_all(&base).expect("create base dir");
+        let temp = tempdir_in(&base).expect("create tempdir");
+        let home = temp.path().join("home");
+        fs::create_dir_all(&home).expect("create home");
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", &home);
+        }
+        runtime::init(runtime::RuntimeMode::User);
+        runtime::set_drop_privileges(false);
+
+        let config_path = temp.path().join("primary.yaml");
+        fs::write(
+            &config_path,
+            r#"
+version: "2"
+project:
+  id: primary
+services:
+  broken:
+    command: "exit 1"
+    restart_policy: never
+  flaky:
+    command: "if [ -f recovered ]; then exec /bin/sleep 45; fi; touch recovered; exit 1"
+    restart_policy: never
+    after: ["broken"]
+"#,
+        )
+        .expect("write config");
+
+        let mut supervisor =
+            Supervisor::new(config_path.clone(), false, None).expect("create supervisor");
+        let config = supervisor.daemon.config();
+        for name in ["broken", "flaky"] {
+            assert!(
+                supervisor
+                    .daemon
+                    .start_service(name, &config.services[name])
+                    .is_err()
+            );
+        }
+        let epoch = supervisor.daemon.begin_boot();
+
+        let message = match supervisor
+            .handle_command(ControlCommand::RestartFailed { project: None })
+            .expect("restart failed services")
+        {
+            ControlResponse::Error(message) => message,
+            other => panic!("a service that stays down fails the command: {other:?}"),
+        };
+        assert!(
+            message.contains("Restarted 1 failed service: primary/flaky"),
+            "{message}"
+        );
+        assert!(
+            message.contains("Could not restart 1 failed service:\n  primary/broken:"),
+            "{message}"
+        );
+        assert!(supervisor.daemon.running_pid("flaky").is_some());
+        assert!(
+            supervisor.daemon.boot_active(epoch),
+            "restarting failed services must not cancel an in-flight boot"
+        );
+
+        supervisor
+            .shutdown_runtime()
+            .expect("shutdown test supervisor runtime");
+
+        unsafe {
+            if let Some(home) = original_home {
+                std::env::set_var("HOME", home);
+            } else {
+                std::env::remove_var("HOME");
+            }
+        }
+    }
+
+    #[test]
+    fn bulk_stop_reports_each_service_and_keeps_going_past_failures() {
+        let _guard = crate::test_utils::env_lock();
+
+        let base = std::env::current_dir()
+            .expect("current_dir")
+            .join("target/tmp-home");
+        fs::create_dir_all(&base).expect("create base dir");
+        let temp = tempdir_in(&base).expect("create tempdir");
+        let home = temp.path().join("home");
+        fs::create_dir_all(&home).expect("create home");
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", &home);
+        }
+        runtime::init(runtime::RuntimeMode::User);
+        runtime::set_drop_privileges(false);
+
+        let config_path = temp.path().join("primary.yaml");
+        fs::write(
+            &config_path,
+            r#"
+version: "2"
+project:
+  id: primary
+services:
+  web:
+    command: "/bin/sleep 45"
+  worker:
+    command: "/bin/sleep 45"
+  db:
+    command: "/bin/sleep 45"
+"#,
+        )
+        .expect("write config");
+
+        let mut supervisor =
+            Supervisor::new(config_path.clone(), false, None).expect("create supervisor");
+        let config = supervisor.daemon.config();
+        for name in ["web", "worker", "db"] {
+            supervisor
+                .daemon
+                .start_service(name, &config.services[name])
+                .expect("start service");
+        }
+
+        let response = supervisor
+            .handle_command(ControlCommand::Bulk {
+                action: BulkAction::Stop { timeout_ms: None },
+                services: vec!["web".into(), "ghost".into(), "worker".into()],
+                project: None,
+            })
+            .expect("bulk stop");
+        let ControlResponse::Bulk(outcomes) = response else {
+            panic!("expected bulk response, got {response:?}");
+        };
+        let services: Vec<_> = outcomes.iter().map(|o| o.service.as_str()).collect();
+        assert_eq!(services, vec!["web", "ghost", "worker"]);
+        assert!(outcomes[0].error.is_none());
+        assert!(outcomes[1].error.is_some());
+        assert!(outcomes[2].error.is_none());
+        assert!(supervisor.daemon.running_pid("web").is_none());
+        assert!(supervisor.daemon.running_pid("worker").is_none());
+        assert!(supervisor.daemon.running_pid("db").is_some());
+
+        supervisor
+            .shutdown_runtime()
+            .expect("shutdown test supervisor runtime");
+
+        unsafe {
+            if let Some(home) = original_home {
+                std::env::set_var("HOME", home);
+            } else {
+                std::env::remove_var("HOME");
+            }
+        }
+    }
+
+    #[test]
+    fn disable_stops_a_running_service_and_blocks_start_until_enabled() {
+        let _guard = crate::test_utils::env_lock();
+
+        let base = std::env::current_dir()
+            .expect("current_dir")
+            .join("target/tmp-home");
+        fs::create_dir_all(&base).expect("create base dir");
+        let temp = tempdir_in(&base).expect("create tempdir");
+        let home = temp.path().join("home");
+        fs::create_dir_all(&home).expect("create home");
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", &home);
+        }
+        runtime::init(runtime::RuntimeMode::User);
+        runtime::set_drop_privileges(false);
+
+        let config_path = temp.path().join("primary.yaml");
+        fs::write(
+            &config_path,
+            r#"
+version: "2"
+project:
+  id: primary
+services:
+  web:
+    command: "/bin/sleep 45"
+"#,
+        )
+        .expect("write config");
+
+        let mut supervisor =
+            Supervisor::new(config_path.clone(), false, None).expect("create supervisor");
+        let config = supervisor.daemon.config();
+        supervisor
+            .daemon
+            .start_service("web", &config.services["web"])
+            .expect("start web");
+        assert!(supervisor.daemon.running_pid("web").is_some());
+
+        match supervisor
+            .handle_command(ControlCommand::Disable {
+                service: "web".into(),
+                project: None,
+            })
+            .expect("disable web")
+        {
+            ControlResponse::Message(message) => {
+                assert_eq!(message, "Service 'web' disabled in project 'primary'");
+            }
+            other => panic!("expected message response, got {other:?}"),
+        }
+        assert!(supervisor.daemon.running_pid("web").is_none());
+        assert!(supervisor.daemon.is_service_disabled("web"));
+
+        let err = supervisor
+            .handle_command(ControlCommand::Start {
+                service: Some("web".into()),
+                project: None,
+            })
+            .expect_err("starting a disabled service should be refused");
+        assert!(err.to_string().contains("is disabled"));
+
+        supervisor
+            .handle_command(ControlCommand::Enable {
+                service: "web".into(),
+                project: None,
+            })
+            .expect("enable web");
+        supervisor
+            .handle_command(ControlCommand::Start {
+                service: Some("web".into()),
+                project: None,
+            })
+            .expect("start re-enabled web");
+        assert!(supervisor.daemon.running_pid("web").is_some());
+
+        supervisor
+            .shutdown_runtime()
+            .expect("shutdown test supervisor runtime");
+
+        unsafe {
+            if let Some(home) = original_home {
+                std::env::set_var("HOME", home);
+            } else {
+                std::env::remove_var("HOME");
+            }
+        }
+    }
+
+    #[test]
+    fn run_cron_queues_a_manual_run_for_a_scheduled_job() {
+        let _guard = crate::test_utils::env_lock();
+
+        let base = std::env::current_dir()
+            .expect("current_dir")
+            .join("target/tmp-home");
+        fs::create_dir_all(&base).expect("create base dir");
+        let temp = tempdir_in(&base).expect("create tempdir");
+        let home = temp.path().join("home");
+        fs::create_dir_all(&home).expect("create home");
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", &home);
+        }
+        runtime::init(runtime::RuntimeMode::User);
+        runtime::set_drop_privileges(false);
+
+        let config_path = temp.path().join("primary.yaml");
+        fs::write(
+            &config_path,
+            r#"
+version: "2"
+project:
+  id: primary
+services:
+  report:
+    command: "/bin/true"
+    cron:
+      expression: "0 0 0 1 1 *"
+"#,
+        )
+        .expect("write config");
+
+        let mut supervisor =
+            Supervisor::new(config_path.clone(), false, None).expect("create supervisor");
+        let hash = supervisor.daemon.config().state_key("report");
+
+        match supervisor
+            .handle_command(ControlCommand::RunCron { hash: hash.clone() })
+            .expect("run cron")
+        {
+            ControlResponse::Message(message) => {
+                assert_eq!(
+                    message,
+                    "Cron job 'report' will run on the next scheduler tick"
+                );
+            }
+            other => panic!("expected message response, got {other:?}"),
+        }
+        let due = supervisor.cron_manager.get_due_job_refs();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].service_hash, hash);
+
+        match supervisor
+            .handle_command(ControlCommand::RunCron {
+                hash: "v2:primary:missing".into(),
+            })
+            .expect("run unknown cron")
+        {
+            ControlResponse::Error(message) => assert!(message.contains("no cron job")),
+            other => panic!("expected error response, got {other:?}"),
+        }
+
+        supervisor
+            .shutdown_runtime()
+            .expect("shutdown test supervisor runtime");
+
+        unsafe {
+            if let Some(home) = original_home {
+                std::env::set_var("HOME", home);
+            } else {
+                std::env::remove_var("HOME");
+            }
+        }
+    }
+
+    #[test]
+    fn restart_with_dependents_bounces_the_service_then_its_dependents() {
+        let _guard = crate::test_utils::env_lock();
+
+        let base = std::env::current_dir()
+            .expect("current_dir")
+            .join("target/tmp-home");
+        fs::create_dir_all(&base).expect("create base dir");
+        let temp = tempdir_in(&base).expect("create tempdir");
+        let home = temp.path().join("home");
+        fs::create_dir_all(&home).expect("create home");
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", &home);
+        }
+        runtime::init(runtime::RuntimeMode::User);
+        runtime::set_drop_privileges(false);
+
+        let config_path = temp.path().join("primary.yaml");
+        fs::write(
+            &config_path,
+            r#"
+version: "2"
+project:
+  id: primary
+services:
+  db:
+    command: "/bin/sleep 45"
+  web:
+    command: "/bin/sleep 45"
+    depends_on: ["db"]
+"#,
+        )
+        .expect("write config");
+
+        let mut supervisor =
+            Supervisor::new(config_path.clone(), false, None).expect("create supervisor");
+        let pids = |supervisor: &Supervisor| {
+            let handle = supervisor.daemon.pid_file_handle();
+            let guard = handle.lock().expect("lock pid file");
+            (guard.pid_for("db"), guard.pid_for("web"))
+        };
+        let restart =
+            |supervisor: &mut Supervisor, with_dependents: bool| match supervisor
+                .handle_command(ControlCommand::Restart {
+                    config: None,
+                    service: Some("db".into()),
+                    project: None,
+                    with_dependents,
+                    build_first: false,
+                    wait: true,
+                })
+                .expect("restart db and wait")
+            {
+                ControlResponse::Restarted(report) => report
+                    .services
+                    .into_iter()
+                    .map(|service| service.name)
+                    .collect::<Vec<_>>(),
+                other => panic!("expected restarted report, got {other:?}"),
+            };
+
+        assert_eq!(restart(&mut supervisor, true), vec!["db", "web"]);
+        let (db_before, web_before) = pids(&supervisor);
+        assert!(db_before.is_some() && web_before.is_some());
+
+        assert_eq!(restart(&mut supervisor, true), vec!["db", "web"]);
+        let (db_after, web_after) = pids(&supervisor);
+        assert_ne!(db_after, db_before, "db was not bounced");
+        assert_ne!(web_after, web_before, "dependent web was not bounced");
+
+        assert_eq!(restart(&mut supervisor, false), vec!["db"]);
+        let (db_single, web_single) = pids(&supervisor);
+        assert_ne!(db_single, db_after, "db was not bounced");
+        assert_eq!(
+            web_single, web_after,
+            "web restarted without --with-dependents"
+        );
+
+        supervisor
+            .shutdown_runtime()
+            .expect("shutdown test supervisor runtime");
+
+        unsafe {
+            if let Some(home) = original_home {
+                std::env::set_var("HOME", home);
+            } else {
+                std::env::remove_var("HOME");
+            }
+        }
+    }
+
+    #[test]
+    fn restart_primary_project_without_config_reloads_stored_manifest() {
+        let _guard = crate::test_utils::env_lock();
+
+        let base = std::env::current_dir()
+            .expect("current_dir")
+            .join("target/tmp-home");
+        fs::create_dir_all(&base).expect("create base dir");
+        let temp = tempdir_in(&base).expect("create tempdir");
+        let home = temp.path().join("home");
+        fs::create_dir_all(&home).expect("create home");
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", &home);
+        }
+        runtime::init(runtime::RuntimeMode::User);
+        runtime::set_drop_privileges(false);
+
+        let config_path = temp.path().join("primary.yaml");
+        fs::write(
+            &config_path,
+            r#"
+version: "2"
+project:
+  id: primary
+services:
+  alpha:
+    command: "/bin/sleep 45"
+  beta:
+    command: "/bin/sleep 45"
+"#,
+        )
+        .expect("write config");
+
+        let mut supervisor =
+            Supervisor::new(config_path.clone(), false, None).expect("create supervisor");
+
+        fs::write(
+            &config_path,
+            r#"
+version: "2"
+project:
+  id: primary
+services:
+  alpha:
+    command: "/bin/sleep 60"
+  gamma:
+    command: "/bin/sleep 45"
+"#,
+        )
+        .expect("rewrite config");
+
+        supervisor
+            .handle_command(ControlCommand::Restart {
+                config: None,
+                service: None,
+                project: Some("primary".into()),
+                with_dependents: false,
+                build_first: false,
+                wait: false,
+            })
+            .expect("restart primary project without config");
+
+        match supervisor
+            .handle_command(ControlCommand::Status {
+                live: true,
+                no_children: false,
+            })
+            .expect("status after restart")
+        {
+            ControlResponse::Status(snapshot) => {
+                let names = project_service_names(&snapshot, "primary");
+                assert!(
+                    names.contains(&"gamma".to_string()),
+                    "added service missing"
+                );
+                assert!(
+                    !names.contains(&"beta".to_string()),
+                    "removed service lingered"
+                );
+                assert!(names.contains(&"alpha".to_string()), "kept service missing");
+            }
+            other => panic!("expected status response, got {other:?}"),
+        }
+
+        assert_eq!(
+            supervisor
+                .daemon
+                .config()
+                .services
+                .get("alpha")
+                .map(|service| service.command.as_str()),
+            Some("/bin/sleep 60")
+        );
+
+        supervisor
+            .shutdown_runtime()
+            .expect("shutdown test supervisor runtime");
+
+        unsafe {
+            if let Some(home) = original_home {
+                std::env::set_var("HOME", home);
+            } else {
+                std::env::remove_var("HOME");
+            }
+        }
+    }
+
+    #[test]
+    /// Verifies a failed added unit leaves unchanged primary processes intact.
+    fn primary_reconcile_failure_preserves_unchanged_processes() {
+        let _guard = crate::test_utils::env_lock();
+
+        let base = std::env::current_dir()
+            .expect("current_dir")
+            .join("target/tmp-home");
+        fs::create_dir_all(&base).expect("create base dir");
+        let temp = tempdir_in(&base).expect("create tempdir");
+        let home = temp.path().join("home");
+        fs::create_dir_all(&home).expect("create home");
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", &home);
+        }
+        runtime::init(runtime::RuntimeMode::User);
+        runtime::set_drop_privileges(false);
+
+        let config_path = temp.path().join("primary.yaml");
+        fs::write(
+            &config_path,
+            r#"
+version: "2"
+project:
+  id: primary
+services:
+  web:
+    command: "/bin/sleep 45"
+  api:
+    command: "/bin/sleep 45"
+"#,
+        )
+        .expect("write config");
+
+        let mut supervisor =
+            Supervisor::new(config_path.clone(), false, None).expect("create supervisor");
+        supervisor
+            .daemon
+            .start_services()
+            .expect("start primary services");
+        let before = supervisor
+            .daemon
+            .pid_file_handle()
+            .lock()
+            .expect("pid file lock")
+            .services()
+            .clone();
+
+        fs::write(
+            &config_path,
+            r#"
+version: "2"
+project:
+  id: primary
+services:
+  web:
+    command: "/bin/sleep 45"
+  api:
+    command: "/bin/sleep 45"
+  bad:
+    command: "/bin/sh -c 'exit 1'"
+"#,
+        )
+        .expect("rewrite config");
+
+        let error = supervisor
+            .handle_command(ControlCommand::Restart {
+                config: Some(config_path.to_string_lossy().to_string()),
+                service: None,
+                project: Some("primary".into()),
+                with_dependents: false,
+                build_first: false,
+                wait: false,
+            })
+            .expect_err("failing added service should make reconcile incomplete");
+        assert!(
+            error.to_string().contains("SG0302"),
+            "unexpected reconcile error: {error}"
+        );
+
+        let after = supervisor
+            .daemon
+            .pid_file_handle()
+            .lock()
+            .expect("pid file lock")
+            .services()
+            .clone();
+        for service in ["web", "api"] {
+            let pid = before.get(service).copied().expect("original service pid");
+            assert_eq!(after.get(service), Some(&pid));
+            assert_eq!(unsafe { libc::kill(pid as libc::pid_t, 0) }, 0);
+        }
+        assert!(supervisor.daemon.config().services.contains_key("bad"));
+
+        supervisor
+            .shutdown_runtime()
+            .expect("shutdown test supervisor runtime");
+
+        unsafe {
+            if let Some(home) = original_home {
+                std::env::set_var("HOME", home);
+            } else {
+                std::env::remove_var("HOME");
+            }
+        }
+    }
+
+    #[test]
+    fn restart_extra_project_without_config_reloads_stored_manifest() {
+        let _guard = crate::test_utils::env_lock();
+
+        let base = std::env::current_dir()
+            .expect("current_dir")
+            .join("target/tmp-home");
+        fs::create_dir_all(&base).expect("create base dir");
+        let temp = tempdir_in(&base).expect("create tempdir");
+        let home = temp.path().join("home");
+        fs::create_dir_all(&home).expect("create home");
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", &home);
+        }
+        runtime::init(runtime::RuntimeMode::User);
+        runtime::set_drop_privileges(false);
+
+        let alpha_config = temp.path().join("alpha.yaml");
+        fs::write(
+            &alpha_config,
+            r#"
+version: "2"
+project:
+  id: alpha
+services:
+  alpha_worker:
+    command: "/bin/sleep 45"
+"#,
+        )
+        .expect("write alpha config");
+
+        let beta_config = temp.path().join("beta.yaml");
+        fs::write(
+            &beta_config,
+            r#"
+version: "2"
+project:
+  id: beta
+services:
+  beta_worker:
+    command: "/bin/sleep 45"
+  beta_legacy:
+    command: "/bin/sleep 45"
+"#,
+        )
+        .expect("write beta config");
+
+        let mut supervisor =
+            Supervisor::new(alpha_config, false, None).expect("create supervisor");
+        supervisor
+            .handle_command(ControlCommand::AddProject {
+                config: beta_config.to_string_lossy().to_string(),
+                service: None,
+                mode: ProjectRunMode::Daemon,
+                env: BTreeMap::new(),
+            })
+            .expect("add beta project");
+
+        fs::write(
+            &beta_config,
+            r#"
+version: "2"
+project:
+  id: beta
+services:
+  beta_worker:
+    command: "/bin/sleep 60"
+  beta_added:
+    command: "/bin/sleep 45"
+"#,
+        )
+        .expect("rewrite beta config");
 
         supervisor
             .handle_command(ControlCommand::Restart {
                 config: None,
                 service: None,
-                project: Some("primary".into()),
+                project: Some("beta".into()),
+                with_dependents: false,
+                build_first: false,
+                wait: false,
             })
-            .expect("restart primary project without config");
+            .expect("restart beta project without config");
 
         match supervisor
-            .handle_command(ControlCommand::Status { live: true })
+            .handle_command(ControlCommand::Status {
+                live: true,
+                no_children: false,
+            })
             .expect("status after restart")
         {
             ControlResponse::Status(snapshot) => {
-                let names = project_service_names(&snapshot, "primary");
+                let names = project_service_names(&snapshot, "beta");
                 assert!(
-                    names.contains(&"gamma".to_string()),
+                    names.contains(&"beta_added".to_string()),
                     "added service missing"
                 );
                 assert!(
-                    !names.contains(&"beta".to_string()),
+                    !names.contains(&"beta_legacy".to_string()),
                     "removed service lingered"
                 );
-                assert!(names.contains(&"alpha".to_string()), "kept service missing");
+                assert!(
+                    names.contains(&"beta_worker".to_string()),
+                    "kept service missing"
+                );
             }
             other => panic!("expected status response, got {other:?}"),
         }
 
-        assert_eq!(
-            supervisor
-                .daemon
-                .config()
-                .services
-                .get("alpha")
-                .map(|service| service.command.as_str()),
-            Some("/bin/sleep 60")
-        );
+        let beta_runtime = supervisor
+            .extra_projects
+            .get("beta")
+            .expect("beta runtime after restart");
+        assert_eq!(
+            beta_runtime
+                .daemon
+                .config()
+                .services
+                .get("beta_worker")
+                .map(|service| service.command.as_str()),
+            Some("/bin/sleep 60")
+        );
+
+        supervisor
+            .shutdown_runtime()
+            .expect("shutdown test supervisor runtime");
+
+        unsafe {
+            if let Some(home) = original_home {
+                std::env::set_var("HOME", home);
+            } else {
+                std::env::remove_var("HOME");
+            }
+        }
+    }
+
+    #[test]
+    fn add_project_env_overrides_reach_the_service_process() {
+        let _guard = crate::test_utils::env_lock();
+
+        let base = std::env::current_dir()
+            .expect("current_dir")
+            .join("target/tmp-home");
+        fs::create_dir_all(&base).expect("create base dir");
+        let temp = tempdir_in(&base).expect("create tempdir");
+        let home = temp.path().join("home");
+        fs::create_dir_all(&home).expect("create home");
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", &home);
+        }
+        runtime::init(runtime::RuntimeMode::User);
+        runtime::set_drop_privileges(false);
+
+        let alpha_config = temp.path().join("alpha.yaml");
+        fs::write(
+            &alpha_config,
+            r#"
+version: "2"
+project:
+  id: alpha
+services:
+  alpha_worker:
+    command: "/bin/sleep 45"
+"#,
+        )
+        .expect("write alpha config");
+
+        let out = temp.path().join("greeting.txt");
+        let beta_config = temp.path().join("beta.yaml");
+        fs::write(
+            &beta_config,
+            format!(
+                r#"
+version: "2"
+project:
+  id: beta
+env:
+  vars:
+    GREETING: "from-config"
+services:
+  beta_worker:
+    command: "sh -c 'echo $GREETING > {}; exec sleep 45'"
+"#,
+                out.display()
+            ),
+        )
+        .expect("write beta config");
+
+        let mut supervisor =
+            Supervisor::new(alpha_config, false, None).expect("create supervisor");
+        supervisor
+            .handle_command(ControlCommand::AddProject {
+                config: beta_config.to_string_lossy().to_string(),
+                service: None,
+                mode: ProjectRunMode::Daemon,
+                env: BTreeMap::from([("GREETING".to_string(), "from-cli".to_string())]),
+            })
+            .expect("add beta project");
+
+        let deadline = std::time::Instant::now() + Duration::from_secs(10);
+        let mut greeting = String::new();
+        while std::time::Instant::now() < deadline {
+            greeting = fs::read_to_string(&out).unwrap_or_default();
+            if !greeting.is_empty() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+        assert_eq!(greeting.trim(), "from-cli");
+
+        supervisor
+            .shutdown_runtime()
+            .expect("shutdown test supervisor runtime");
+
+        unsafe {
+            if let Some(home) = original_home {
+                std::env::set_var("HOME", home);
+            } else {
+                std::env::remove_var("HOME");
+            }
+        }
+    }
+
+    #[test]
+    fn env_overrides_do_not_mark_a_service_drifted() {
+        let _guard = crate::test_utils::env_lock();
+
+        let base = std::env::current_dir()
+            .expect("current_dir")
+            .join("target/tmp-home");
+        fs::create_dir_all(&base).expect("create base dir");
+        let temp = tempdir_in(&base).expect("create tempdir");
+        let home = temp.path().join("home");
+        fs::create_dir_all(&home).expect("create home");
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", &home);
+        }
+        runtime::init(runtime::RuntimeMode::User);
+        runtime::set_drop_privileges(false);
+
+        let alpha_config = temp.path().join("alpha.yaml");
+        fs::write(
+            &alpha_config,
+            r#"
+version: "2"
+project:
+  id: alpha
+services:
+  alpha_worker:
+    command: "/bin/sleep 45"
+"#,
+        )
+        .expect("write alpha config");
+        let beta_config = temp.path().join("beta.yaml");
+        fs::write(
+            &beta_config,
+            r#"
+version: "2"
+project:
+  id: beta
+services:
+  beta_worker:
+    command: "sh -c 'exec sleep 45'"
+"#,
+        )
+        .expect("write beta config");
+
+        let mut supervisor =
+            Supervisor::new(alpha_config, false, None).expect("create supervisor");
+        supervisor
+            .handle_command(ControlCommand::AddProject {
+                config: beta_config.to_string_lossy().to_string(),
+                service: None,
+                mode: ProjectRunMode::Daemon,
+                env: BTreeMap::from([("GREETING".to_string(), "from-cli".to_string())]),
+            })
+            .expect("add beta project");
+
+        let deadline = std::time::Instant::now() + Duration::from_secs(10);
+        let worker = loop {
+            let snapshot = supervisor
+                .collect_configured_snapshot()
+                .expect("collect snapshot");
+            let worker = snapshot
+                .units
+                .into_iter()
+                .find(|unit| unit.name == "beta_worker")
+                .filter(|unit| unit.lifecycle == Some(ServiceLifecycleStatus::Running));
+            if let Some(worker) = worker {
+                break worker;
+            }
+            assert!(
+                std::time::Instant::now() < deadline,
+                "beta_worker never reported running"
+            );
+            thread::sleep(Duration::from_millis(50));
+        };
+        assert!(!worker.drifted, "--env overrides were reported as drift");
 
         supervisor
             .shutdown_runtime()
@@ -5687,8 +8206,7 @@ services:
     }
 
     #[test]
-    /// Verifies a failed added unit leaves unchanged primary processes intact.
-    fn primary_reconcile_failure_preserves_unchanged_processes() {
+    fn reload_keeps_the_env_overrides_the_project_started_with() {
         let _guard = crate::test_utils::env_lock();
 
         let base = std::env::current_dir()
@@ -5705,78 +8223,66 @@ services:
         runtime::init(runtime::RuntimeMode::User);
         runtime::set_drop_privileges(false);
 
-        let config_path = temp.path().join("primary.yaml");
+        let config_path = temp.path().join("greeter.yaml");
         fs::write(
             &config_path,
             r#"
 version: "2"
 project:
-  id: primary
+  id: greeter
+env:
+  vars:
+    GREETING: "from-config"
 services:
   web:
     command: "/bin/sleep 45"
-  api:
-    command: "/bin/sleep 45"
 "#,
         )
         .expect("write config");
+        let overrides =
+            BTreeMap::from([("GREETING".to_string(), "from-cli".to_string())]);
+        let greeting = |config: &Config| {
+            config.services["web"]
+                .env
+                .as_ref()
+                .and_then(|env| env.vars.as_ref())
+                .and_then(|vars| vars.get("GREETING"))
+                .cloned()
+        };
 
-        let mut supervisor =
-            Supervisor::new(config_path.clone(), false, None).expect("create supervisor");
-        supervisor
-            .daemon
-            .start_services()
-            .expect("start primary services");
-        let before = supervisor
-            .daemon
-            .pid_file_handle()
-            .lock()
-            .expect("pid file lock")
-            .services()
-            .clone();
-
-        fs::write(
-            &config_path,
-            r#"
-version: "2"
-project:
-  id: primary
-services:
-  web:
-    command: "/bin/sleep 45"
-  api:
-    command: "/bin/sleep 45"
-  bad:
-    command: "/bin/sh -c 'exit 1'"
-"#,
+        let mut supervisor = Supervisor::new_with_mode(
+            config_path.clone(),
+            false,
+            None,
+            ProjectRunMode::Daemon,
+            &overrides,
         )
-        .expect("rewrite config");
-
-        let error = supervisor
-            .handle_command(ControlCommand::Restart {
-                config: Some(config_path.to_string_lossy().to_string()),
-                service: None,
-                project: Some("primary".into()),
-            })
-            .expect_err("failing added service should make reconcile incomplete");
-        assert!(
-            error.to_string().contains("SG0302"),
-            "unexpected reconcile error: {error}"
-        );
-
-        let after = supervisor
-            .daemon
-            .pid_file_handle()
-            .lock()
-            .expect("pid file lock")
-            .services()
-            .clone();
-        for service in ["web", "api"] {
-            let pid = before.get(service).copied().expect("original service pid");
-            assert_eq!(after.get(service), Some(&pid));
-            assert_eq!(unsafe { libc::kill(pid as libc::pid_t, 0) }, 0);
-        }
-        assert!(supervisor.daemon.config().services.contains_key("bad"));
+        .expect("create supervisor");
+        supervisor
+            .reload_config_for_test(&config_path)
+            .expect("reload manifest");
+        let reloaded = supervisor.daemon.config();
+        assert_eq!(greeting(&reloaded).as_deref(), Some("from-cli"));
+        assert_eq!(reloaded.env_overrides, overrides);
+
+        let handoff = HandoffProject {
+            project_id: "greeter".into(),
+            config_path: config_path.canonicalize().expect("canonical config"),
+            config_hash: ipc::manifest_content_hash(&config_path).expect("hash"),
+            mode: ProjectRunMode::Daemon,
+            active: true,
+            env_overrides: overrides.clone(),
+            daemon: crate::upgrade::HandoffDaemonState {
+                processes: Vec::new(),
+                manual_stops: Vec::new(),
+                restart_suppressed: Vec::new(),
+                restart_counts: BTreeMap::new(),
+                stopped_for_dependency: BTreeMap::new(),
+            },
+        };
+        let resumed =
+            Supervisor::load_handoff_project(&handoff).expect("load handoff project");
+        assert_eq!(greeting(&resumed).as_deref(), Some("from-cli"));
 
         supervisor
             .shutdown_runtime()
@@ -5792,7 +8298,7 @@ services:
     }
 
     #[test]
-    fn restart_extra_project_without_config_reloads_stored_manifest() {
+    fn parallel_boot_isolates_failures_to_their_dependents() {
         let _guard = crate::test_utils::env_lock();
 
         let base = std::env::current_dir()
@@ -5809,108 +8315,200 @@ services:
         runtime::init(runtime::RuntimeMode::User);
         runtime::set_drop_privileges(false);
 
-        let alpha_config = temp.path().join("alpha.yaml");
+        let config_path = temp.path().join("parallel.yaml");
         fs::write(
-            &alpha_config,
+            &config_path,
             r#"
 version: "2"
 project:
-  id: alpha
+  id: parallel
 services:
-  alpha_worker:
+  db:
+    command: "sh -c 'exit 3'"
+    restart_policy: "never"
+  cache:
     command: "/bin/sleep 45"
-"#,
-        )
-        .expect("write alpha config");
-
-        let beta_config = temp.path().join("beta.yaml");
-        fs::write(
-            &beta_config,
-            r#"
-version: "2"
-project:
-  id: beta
-services:
-  beta_worker:
+  api:
     command: "/bin/sleep 45"
-  beta_legacy:
+    depends_on: ["db"]
+  worker:
     command: "/bin/sleep 45"
+    depends_on: ["cache"]
 "#,
         )
-        .expect("write beta config");
+        .expect("write parallel config");
 
-        let mut supervisor =
-            Supervisor::new(alpha_config, false, None).expect("create supervisor");
-        supervisor
-            .handle_command(ControlCommand::AddProject {
-                config: beta_config.to_string_lossy().to_string(),
-                service: None,
-                mode: ProjectRunMode::Daemon,
-            })
-            .expect("add beta project");
+        let config =
+            crate::config::load_config(Some(config_path.to_str().unwrap())).unwrap();
+        let mut daemon = Daemon::from_config(config, false).expect("create daemon");
+        daemon.set_parallel_start(true);
+        let failed = Supervisor::start_project_services(
+            &daemon,
+            daemon.config().as_ref(),
+            None,
+            &DynamicSpawnManager::new(),
+            None,
+        )
+        .expect("boot project");
 
-        fs::write(
-            &beta_config,
-            r#"
+        assert_eq!(failed.services(), ["api".to_string(), "db".to_string()]);
+        let running = daemon.pid_file_handle().lock().unwrap().services().clone();
+        assert!(running.contains_key("cache"));
+        assert!(running.contains_key("worker"));
+
+        daemon.stop_services().expect("stop services");
+        unsafe {
+            if let Some(home) = original_home {
+                std::env::set_var("HOME", home);
+            } else {
+                std::env::remove_var("HOME");
+            }
+        }
+    }
+
+    #[test]
+    fn abort_boot_launches_nothing_after_the_first_failure() {
+        let _guard = crate::test_utils::env_lock();
+
+        let base = std::env::current_dir()
+            .expect("current_dir")
+            .join("target/tmp-home");
+        fs::create_dir_all(&base).expect("create base dir");
+        let temp = tempdir_in(&base).expect("create tempdir");
+        let home = temp.path().join("home");
+        fs::create_dir_all(&home).expect("create home");
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", &home);
+        }
+        runtime::init(runtime::RuntimeMode::User);
+        runtime::set_drop_privileges(false);
+
+        let manifest = |mode: &str| {
+            format!(
+                r#"
 version: "2"
 project:
-  id: beta
+  id: {mode}
+on_failure: {mode}
 services:
-  beta_worker:
-    command: "/bin/sleep 60"
-  beta_added:
+  api:
+    command: "sh -c 'exit 3'"
+    restart_policy: "never"
+  cache:
+    command: "sh -c 'exit 4'"
+    restart_policy: "never"
+  web:
     command: "/bin/sleep 45"
-"#,
-        )
-        .expect("rewrite beta config");
+"#
+            )
+        };
+        for (mode, failed_services, web_started) in [
+            ("abort", vec!["api".to_string()], false),
+            (
+                "continue",
+                vec!["api".to_string(), "cache".to_string()],
+                true,
+            ),
+        ] {
+            let config_path = temp.path().join(format!("{mode}.yaml"));
+            fs::write(&config_path, manifest(mode)).expect("write config");
+            let config =
+                crate::config::load_config(Some(config_path.to_str().unwrap())).unwrap();
+            let daemon = Daemon::from_config(config, false).expect("create daemon");
+            let failed = Supervisor::start_project_services(
+                &daemon,
+                daemon.config().as_ref(),
+                None,
+                &DynamicSpawnManager::new(),
+                None,
+            )
+            .expect("boot project");
 
-        supervisor
-            .handle_command(ControlCommand::Restart {
-                config: None,
-                service: None,
-                project: Some("beta".into()),
-            })
-            .expect("restart beta project without config");
+            assert_eq!(failed.services(), failed_services, "{mode}");
+            let running = daemon.pid_file_handle().lock().unwrap().services().clone();
+            assert_eq!(running.contains_key("web"), web_started, "{mode}");
+            daemon.stop_services().expect("stop services");
+        }
 
-        match supervisor
-            .handle_command(ControlCommand::Status { live: true })
-            .expect("status after restart")
-        {
-            ControlResponse::Status(snapshot) => {
-                let names = project_service_names(&snapshot, "beta");
-                assert!(
-                    names.contains(&"beta_added".to_string()),
-                    "added service missing"
-                );
-                assert!(
-                    !names.contains(&"beta_legacy".to_string()),
-                    "removed service lingered"
-                );
-                assert!(
-                    names.contains(&"beta_worker".to_string()),
-                    "kept service missing"
-                );
+        unsafe {
+            if let Some(home) = original_home {
+                std::env::set_var("HOME", home);
+            } else {
+                std::env::remove_var("HOME");
             }
-            other => panic!("expected status response, got {other:?}"),
         }
+    }
 
-        let beta_runtime = supervisor
-            .extra_projects
-            .get("beta")
-            .expect("beta runtime after restart");
-        assert_eq!(
-            beta_runtime
-                .daemon
-                .config()
-                .services
-                .get("beta_worker")
-                .map(|service| service.command.as_str()),
-            Some("/bin/sleep 60")
-        );
+    #[test]
+    fn boot_waits_out_dependency_settle_before_dependents() {
+        let _guard = crate::test_utils::env_lock();
 
-        supervisor
-            .shutdown_runtime()
-            .expect("shutdown test supervisor runtime");
+        let base = std::env::current_dir()
+            .expect("current_dir")
+            .join("target/tmp-home");
+        fs::create_dir_all(&base).expect("create base dir");
+        let temp = tempdir_in(&base).expect("create tempdir");
+        let home = temp.path().join("home");
+        fs::create_dir_all(&home).expect("create home");
+        let original_home = std::env::var("HOME").ok();
+        unsafe {
+            std::env::set_var("HOME", &home);
+        }
+        runtime::init(runtime::RuntimeMode::User);
+        runtime::set_drop_privileges(false);
+
+        for parallel in [false, true] {
+            let mode = if parallel { "parallel" } else { "sequential" };
+            let config_path = temp.path().join(format!("{mode}.yaml"));
+            fs::write(
+                &config_path,
+                format!(
+                    r#"
+version: "2"
+project:
+  id: settle-{mode}
+dependency_settle: "800ms"
+services:
+  db:
+    command: "sh -c 'date +%s%N > {mode}-db.ready; echo db ready; exec sleep 45'"
+    ready_log_pattern: "db ready"
+  web:
+    command: "sh -c 'date +%s%N > {mode}-web.launched; exec sleep 45'"
+    depends_on: ["db"]
+"#
+                ),
+            )
+            .expect("write config");
+            let config =
+                crate::config::load_config(Some(config_path.to_str().unwrap())).unwrap();
+            let mut daemon = Daemon::from_config(config, false).expect("create daemon");
+            daemon.set_parallel_start(parallel);
+            let failed = Supervisor::start_project_services(
+                &daemon,
+                daemon.config().as_ref(),
+                None,
+                &DynamicSpawnManager::new(),
+                None,
+            )
+            .expect("boot project");
+            daemon.stop_services().expect("stop services");
+            assert!(failed.is_empty(), "{mode}: {:?}", failed.services());
+
+            let nanos = |file: &str| -> u128 {
+                fs::read_to_string(temp.path().join(format!("{mode}-{file}")))
+                    .unwrap()
+                    .trim()
+                    .parse()
+                    .unwrap()
+            };
+            let gap =
+                Duration::from_nanos((nanos("web.launched") - nanos("db.ready")) as u64);
+            assert!(
+                gap >= Duration::from_millis(800),
+                "{mode}: dependent launched after {gap:?}"
+            );
+        }
 
         unsafe {
             if let Some(home) = original_home {
@@ -5979,6 +8577,7 @@ services:
                 config: config_path.to_string_lossy().to_string(),
                 service: None,
                 mode: ProjectRunMode::Daemon,
+                env: BTreeMap::new(),
             })
             .expect("redundant add of same primary config");
         let pids_after: Vec<(String, u32)> = {
@@ -6048,6 +8647,7 @@ services:
             .handle_command(ControlCommand::Stop {
                 service: None,
                 project: Some("primary".into()),
+                timeout_ms: None,
             })
             .expect("stop primary project");
 
@@ -6071,11 +8671,15 @@ services:
                 config: config_path.to_string_lossy().to_string(),
                 service: None,
                 mode: ProjectRunMode::Daemon,
+                env: BTreeMap::new(),
             })
             .expect("re-add primary project");
 
         match supervisor
-            .handle_command(ControlCommand::Status { live: true })
+            .handle_command(ControlCommand::Status {
+                live: true,
+                no_children: false,
+            })
             .expect("status after re-add")
         {
             ControlResponse::Status(snapshot) => {
@@ -6169,6 +8773,7 @@ services:
                 config: beta_config.to_string_lossy().to_string(),
                 service: None,
                 mode: ProjectRunMode::Daemon,
+                env: BTreeMap::new(),
             })
             .expect("add beta project");
 
@@ -6275,6 +8880,7 @@ services:
                 config: beta_config.to_string_lossy().to_string(),
                 service: None,
                 mode: ProjectRunMode::Daemon,
+                env: BTreeMap::new(),
             })
             .expect("add beta project");
 
@@ -6306,7 +8912,10 @@ services:
         );
 
         match supervisor
-            .handle_command(ControlCommand::Status { live: true })
+            .handle_command(ControlCommand::Status {
+                live: true,
+                no_children: false,
+            })
             .expect("status response")
         {
             ControlResponse::Status(snapshot) => {
@@ -6417,6 +9026,7 @@ services:
                 config: beta_config.to_string_lossy().to_string(),
                 service: None,
                 mode: ProjectRunMode::Daemon,
+                env: BTreeMap::new(),
             })
             .expect("add beta project");
         assert!(
@@ -6435,6 +9045,7 @@ services:
             .handle_command(ControlCommand::Stop {
                 service: None,
                 project: Some("beta".into()),
+                timeout_ms: None,
             })
             .expect("stop beta project");
         match response {
@@ -6467,7 +9078,10 @@ services:
         );
 
         match supervisor
-            .handle_command(ControlCommand::Status { live: true })
+            .handle_command(ControlCommand::Status {
+                live: true,
+                no_children: false,
+            })
             .expect("status after beta stop")
         {
             ControlResponse::Status(snapshot) => {
@@ -6552,6 +9166,7 @@ services:
                 config: beta_config.to_string_lossy().to_string(),
                 service: None,
                 mode: ProjectRunMode::Daemon,
+                env: BTreeMap::new(),
             })
             .expect("add beta project");
 
@@ -6585,11 +9200,15 @@ services:
             schema_version: crate::status::STATUS_SCHEMA_VERSION.into(),
             captured_at: Utc::now(),
             overall_health: OverallHealth::Healthy,
+            paused: false,
             units: Vec::new(),
         });
 
         match supervisor
-            .handle_command(ControlCommand::Status { live: false })
+            .handle_command(ControlCommand::Status {
+                live: false,
+                no_children: false,
+            })
             .expect("status response")
         {
             ControlResponse::Status(snapshot) => {
@@ -6616,6 +9235,7 @@ services:
             schema_version: crate::status::STATUS_SCHEMA_VERSION.into(),
             captured_at: Utc::now(),
             overall_health: OverallHealth::Healthy,
+            paused: false,
             units: Vec::new(),
         });
 
@@ -6665,6 +9285,7 @@ services:
             .handle_command(ControlCommand::Stop {
                 service: None,
                 project: Some("beta".into()),
+                timeout_ms: None,
             })
             .expect("stop beta project");
 
@@ -6693,6 +9314,7 @@ services:
                 config: beta_config.to_string_lossy().to_string(),
                 service: None,
                 mode: ProjectRunMode::Daemon,
+                env: BTreeMap::new(),
             })
             .expect("re-add beta project");
 
@@ -6744,6 +9366,9 @@ services:
             command: Some("/bin/true".into()),
             runtime_command: None,
             spawned_children: Vec::new(),
+            drifted: false,
+            restarts: 0,
+            gave_up_after: None,
         }
     }
 
@@ -6814,6 +9439,7 @@ services:
             schema_version: crate::status::STATUS_SCHEMA_VERSION.into(),
             captured_at: Utc::now(),
             overall_health: OverallHealth::Healthy,
+            paused: false,
             units: vec![
                 offline_unit("arb_rs__server", "arb"),
                 offline_unit("arb_py__curator", "arb"),
@@ -6884,6 +9510,7 @@ services:
             schema_version: crate::status::STATUS_SCHEMA_VERSION.into(),
             captured_at: Utc::now(),
             overall_health: OverallHealth::Healthy,
+            paused: false,
             units: vec![offline_unit("arb_rs__server", "arb")],
         };
 
@@ -6935,6 +9562,7 @@ services:
             schema_version: crate::status::STATUS_SCHEMA_VERSION.into(),
             captured_at: Utc::now(),
             overall_health: OverallHealth::Healthy,
+            paused: false,
             units: vec![
                 offline_unit("alphasvc", "alpha"),
                 offline_unit("betasvc", "beta"),
@@ -33,6 +33,10 @@ const TARGET_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
 /// Interval between candidate process completion checks.
 const TARGET_PROBE_INTERVAL: Duration = Duration::from_millis(20);
 
+/// File-name prefix of the private copy kept when the resident binary was
+/// replaced on disk before a re-exec.
+const ROLLBACK_BINARY_PREFIX: &str = "upgrade-rollback-";
+
 /// Metadata emitted by a sysg binary for live-upgrade compatibility checks.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LiveUpgradeInfo {
@@ -129,6 +133,9 @@ pub struct HandoffProject {
     pub mode: ProjectRunMode,
     /// Whether this project remains registered with the supervisor.
     pub active: bool,
+    /// `sysg start --env` overrides the project was started with.
+    #[serde(default)]
+    pub env_overrides: BTreeMap<String, String>,
     /// Process and lifecycle bookkeeping owned by the project daemon.
     pub daemon: HandoffDaemonState,
 }
@@ -157,6 +164,9 @@ pub struct SupervisorHandoff {
     pub service_filter: Option<String>,
     /// Whether service stderr was forwarded to supervisor stdout.
     pub pipe_stderr: bool,
+    /// Whether project boots started dependency levels concurrently.
+    #[serde(default)]
+    pub parallel_start: bool,
     /// Primary project retained even when currently stopped.
     pub primary: HandoffProject,
     /// Additional registered projects keyed by stable project id.
@@ -176,8 +186,8 @@ impl SupervisorHandoff {
             .unwrap_or_default()
             .as_nanos();
         let path = directory.join(format!(
-            "upgrade-handoff-{}-{stamp}.json",
-            std::process::id()
+            "{}{stamp}.json",
+            handoff_prefix(std::process::id())
         ));
         self.write_to(&path)?;
         Ok(path)
@@ -216,6 +226,60 @@ impl SupervisorHandoff {
     }
 }
 
+/// File-name prefix shared by every handoff record written by `pid`.
+fn handoff_prefix(pid: u32) -> String {
+    format!("upgrade-handoff-{pid}-")
+}
+
+/// Reports whether the supervisor `pid` still has a handoff record on disk,
+/// meaning a replacement image has not yet finished resuming its runtime.
+pub fn handoff_pending(pid: u32) -> bool {
+    let prefix = handoff_prefix(pid);
+    fs::read_dir(runtime::state_dir())
+        .map(|entries| {
+            entries.flatten().any(|entry| {
+                entry
+                    .file_name()
+                    .to_string_lossy()
+                    .starts_with(prefix.as_str())
+            })
+        })
+        .unwrap_or(false)
+}
+
+/// Resolves the resident binary a failed replacement rolls back to. When the
+/// executable was replaced on disk since the supervisor started, the running
+/// image is preserved as a private copy in the state directory instead.
+pub fn rollback_binary() -> io::Result<PathBuf> {
+    if let Ok(path) = std::env::current_exe().and_then(fs::canonicalize) {
+        return Ok(path);
+    }
+    preserve_rollback_copy(Path::new("/proc/self/exe"))
+}
+
+/// Copies `image` into the state directory as this process's private
+/// rollback binary.
+fn preserve_rollback_copy(image: &Path) -> io::Result<PathBuf> {
+    let directory = runtime::state_dir();
+    runtime::create_private_dir(&directory)?;
+    let copy = directory.join(format!("{ROLLBACK_BINARY_PREFIX}{}", std::process::id()));
+    fs::copy(image, &copy)?;
+    fs::set_permissions(&copy, fs::Permissions::from_mode(0o700))?;
+    Ok(copy)
+}
+
+/// Removes a rollback copy made by [`rollback_binary`] once the handoff that
+/// needed it has completed. Installed binaries are never touched.
+pub fn discard_rollback_binary(path: &Path) {
+    let is_copy = path.parent() == Some(runtime::state_dir().as_path())
+        && path.file_name().is_some_and(|name| {
+            name.to_string_lossy().starts_with(ROLLBACK_BINARY_PREFIX)
+        });
+    if is_copy {
+        let _ = fs::remove_file(path);
+    }
+}
+
 /// Re-executes the previous supervisor binary after replacement initialization
 /// failed, retaining the same descriptors and handoff record.
 pub fn rollback_handoff(path: &Path, reason: impl Into<String>) -> io::Result<()> {
@@ -264,6 +328,29 @@ impl UpgradeTarget {
             info,
         })
     }
+
+    /// Validates a candidate for `sysg supervisor-reexec`. Unlike
+    /// [`UpgradeTarget::inspect`], the candidate may report the resident
+    /// version, so a rebuilt or reinstalled binary of the same release can take
+    /// over the running supervisor.
+    pub fn inspect_reexec(
+        path: &Path,
+        current: &LiveUpgradeInfo,
+    ) -> Result<Self, Box<Diagnostic>> {
+        let canonical = trusted_executable(path)?;
+        let info = probe_target(&canonical)?;
+        validate_contract(current, &info)?;
+        if info.version < current.version {
+            return Err(incompatible(format!(
+                "target {} is older than resident {}",
+                info.version, current.version
+            )));
+        }
+        Ok(Self {
+            path: canonical,
+            info,
+        })
+    }
 }
 
 /// Builds SG0503 for runtime activity that prevents a stable handoff.
@@ -324,6 +411,31 @@ pub fn validate_resident_version(
     Ok(resident)
 }
 
+/// Verifies that a resident release can be re-executed from `target`: it must
+/// support live re-execution and must not be newer than the target.
+pub fn validate_reexec_resident(
+    resident: &str,
+    target: &LiveUpgradeInfo,
+) -> Result<Version, Box<Diagnostic>> {
+    let resident = Version::parse(resident).map_err(|err| {
+        incompatible(format!(
+            "resident supervisor reported invalid version `{resident}`: {err}"
+        ))
+    })?;
+    if resident < Version::new(0, 56, 0) {
+        return Err(incompatible(format!(
+            "resident {resident} does not support live re-execution"
+        )));
+    }
+    if resident > target.version {
+        return Err(incompatible(format!(
+            "resident {resident} cannot re-execute the older {}",
+            target.version
+        )));
+    }
+    Ok(resident)
+}
+
 /// Resolves and verifies the filesystem trust boundary for a candidate binary.
 fn trusted_executable(path: &Path) -> Result<PathBuf, Box<Diagnostic>> {
     let canonical = fs::canonicalize(path).map_err(|err| {
@@ -416,6 +528,21 @@ fn probe_target(path: &Path) -> Result<LiveUpgradeInfo, Box<Diagnostic>> {
 fn validate_compatibility(
     current: &LiveUpgradeInfo,
     target: &LiveUpgradeInfo,
+) -> Result<(), Box<Diagnostic>> {
+    validate_contract(current, target)?;
+    if target.version <= current.version {
+        return Err(incompatible(format!(
+            "target {} must be newer than resident {}",
+            target.version, current.version
+        )));
+    }
+    Ok(())
+}
+
+/// Checks that both binaries speak the same re-exec protocol and handoff schema.
+fn validate_contract(
+    current: &LiveUpgradeInfo,
+    target: &LiveUpgradeInfo,
 ) -> Result<(), Box<Diagnostic>> {
     if target.protocol != current.protocol {
         return Err(incompatible(format!(
@@ -429,12 +556,6 @@ fn validate_compatibility(
             current.schema, target.schema
         )));
     }
-    if target.version <= current.version {
-        return Err(incompatible(format!(
-            "target {} must be newer than resident {}",
-            target.version, current.version
-        )));
-    }
     Ok(())
 }
 
@@ -522,6 +643,159 @@ mod tests {
         assert!(validate_resident_version("0.58.0", &target).is_err());
     }
 
+    #[test]
+    fn reexec_accepts_same_or_newer_target() {
+        let current = info("0.57.4", 1, 1);
+
+        assert!(validate_contract(&current, &info("0.57.4", 1, 1)).is_ok());
+        assert!(validate_reexec_resident("0.57.4", &current).is_ok());
+        assert!(validate_reexec_resident("0.57.1", &current).is_ok());
+        assert!(validate_reexec_resident("0.58.0", &current).is_err());
+        assert!(validate_reexec_resident("0.55.9", &current).is_err());
+        assert!(validate_contract(&current, &info("0.57.4", 2, 1)).is_err());
+    }
+
+    #[test]
+    fn reexec_rejects_a_resident_without_live_reexec() {
+        let target = info("0.57.4", 1, 1);
+
+        let err = validate_reexec_resident("0.55.9", &target).unwrap_err();
+
+        assert!(
+            err.render(false)
+                .contains("resident 0.55.9 does not support live re-execution")
+        );
+    }
+
+    #[test]
+    fn reexec_rejects_a_resident_newer_than_the_target() {
+        let target = info("0.57.4", 1, 1);
+
+        let err = validate_reexec_resident("0.58.0", &target).unwrap_err();
+
+        assert!(
+            err.render(false)
+                .contains("resident 0.58.0 cannot re-execute the older 0.57.4")
+        );
+    }
+
+    #[test]
+    fn reexec_rejects_an_unparseable_resident_version() {
+        let target = info("0.57.4", 1, 1);
+
+        let err = validate_reexec_resident("dev-build", &target).unwrap_err();
+
+        assert!(err.render(false).contains("invalid version `dev-build`"));
+    }
+
+    /// Writes an executable stand-in that reports `metadata` as its upgrade info.
+    fn fake_target(dir: &Path, metadata: &str) -> PathBuf {
+        let path = dir.join("sysg");
+        fs::write(&path, format!("#!/bin/sh\necho '{metadata}'\n")).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    #[test]
+    fn inspect_reexec_accepts_the_resident_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let binary = fake_target(
+            dir.path(),
+            r#"{"version":"0.57.4","protocol":1,"schema":1}"#,
+        );
+
+        let target =
+            UpgradeTarget::inspect_reexec(&binary, &info("0.57.4", 1, 1)).unwrap();
+
+        assert_eq!(target.info.version, Version::new(0, 57, 4));
+    }
+
+    #[test]
+    fn inspect_reexec_rejects_an_older_target() {
+        let dir = tempfile::tempdir().unwrap();
+        let binary = fake_target(
+            dir.path(),
+            r#"{"version":"0.57.1","protocol":1,"schema":1}"#,
+        );
+
+        let err = UpgradeTarget::inspect_reexec(&binary, &info("0.57.4", 1, 1))
+            .expect_err("older target is rejected");
+
+        assert!(
+            err.render(false)
+                .contains("target 0.57.1 is older than resident 0.57.4")
+        );
+    }
+
+    #[test]
+    fn inspect_reexec_rejects_a_contract_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let binary = fake_target(
+            dir.path(),
+            r#"{"version":"0.57.4","protocol":2,"schema":1}"#,
+        );
+
+        let err = UpgradeTarget::inspect_reexec(&binary, &info("0.57.4", 1, 1))
+            .expect_err("protocol mismatch is rejected");
+
+        assert!(err.render(false).contains("cannot hand off to protocol 2"));
+    }
+
+    /// Runs `test` with the runtime state directory inside a temporary home.
+    fn with_temp_state<F: FnOnce(&Path)>(test: F) {
+        let _guard = crate::test_utils::env_lock();
+        let temp = tempfile::tempdir().unwrap();
+        runtime::init_with_test_home(temp.path());
+        test(temp.path());
+        runtime::init(runtime::RuntimeMode::User);
+    }
+
+    #[test]
+    fn rollback_binary_prefers_the_installed_executable() {
+        let installed = std::env::current_exe().and_then(fs::canonicalize).unwrap();
+
+        let rollback = rollback_binary().unwrap();
+        discard_rollback_binary(&rollback);
+
+        assert_eq!(rollback, installed);
+        assert!(installed.exists());
+    }
+
+    #[test]
+    fn rollback_copy_is_private_and_discarded_after_handoff() {
+        with_temp_state(|home| {
+            let image = home.join("image");
+            fs::write(&image, b"binary").unwrap();
+
+            let copy = preserve_rollback_copy(&image).unwrap();
+
+            assert_eq!(copy.parent(), Some(runtime::state_dir().as_path()));
+            assert_eq!(fs::read(&copy).unwrap(), b"binary");
+            assert_eq!(fs::metadata(&copy).unwrap().mode() & 0o777, 0o700);
+
+            discard_rollback_binary(&copy);
+            assert!(!copy.exists());
+        });
+    }
+
+    #[test]
+    fn discard_rollback_binary_leaves_other_files_alone() {
+        with_temp_state(|home| {
+            let state = runtime::state_dir();
+            runtime::create_private_dir(&state).unwrap();
+            let unprefixed = state.join("sysg");
+            let outside = home.join(format!("{ROLLBACK_BINARY_PREFIX}1"));
+            fs::write(&unprefixed, b"binary").unwrap();
+            fs::write(&outside, b"binary").unwrap();
+
+            discard_rollback_binary(&unprefixed);
+            discard_rollback_binary(&outside);
+
+            assert!(unprefixed.exists());
+            assert!(outside.exists());
+        });
+    }
+
     #[test]
     fn reads_legacy_metadata() {
         let info: LiveUpgradeInfo =
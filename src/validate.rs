@@ -114,8 +114,20 @@ fn classify_semantic(err: &ProcessManagerError) -> Diagnostic {
         ),
         ProcessManagerError::DependencyCycle { .. } => (
             "dependency-cycle",
-            "Services depend on each other in a loop, so no valid start order exists.",
-            "Break the cycle by removing one of the `depends_on` edges in the loop.",
+            "Services depend on or are ordered after each other in a loop, so no valid start order exists.",
+            "Break the cycle by removing one of the `depends_on`, `after` or `before` edges in the loop.",
+            "/how-it-works/configuration",
+        ),
+        ProcessManagerError::UnknownOrderingTarget { .. } => (
+            "unknown-ordering-target",
+            "A service lists an `after` or `before` entry that no service in this manifest defines.",
+            "Fix the typo, or add the missing service so the ordering resolves.",
+            "/how-it-works/configuration",
+        ),
+        ProcessManagerError::OrderingConflict { .. } => (
+            "ordering-conflict",
+            "An `after` or `before` entry orders a service ahead of one it lists in `depends_on`.",
+            "Drop the ordering entry; `depends_on` already starts the dependency first.",
             "/how-it-works/configuration",
         ),
         ProcessManagerError::MissingEnvVar(_) => (
@@ -182,6 +194,92 @@ fn classify_yaml(err: &serde_yaml::Error) -> Diagnostic {
             "Add a `services:` block with at least one named service.",
             "/how-it-works/configuration",
         )
+    } else if lower.contains("duplicate service") {
+        (
+            "duplicate-service",
+            "Two entries under the same `services:` map use one name, and only one of them could run.",
+            "Rename or remove one of the definitions so every service name is unique.",
+            "/how-it-works/configuration",
+        )
+    } else if lower.contains("invalid backoff") {
+        (
+            "invalid-backoff",
+            "A restart `backoff` must be a duration systemg can parse, or the first crash would restart on an unexpected delay.",
+            "Use a whole number with an optional unit: `30s`, `5m`, or `2h`.",
+            "/how-it-works/configuration",
+        )
+    } else if lower.contains("invalid ready_stable_for") {
+        (
+            "invalid-ready-stable-for",
+            "A `ready_stable_for` window must be a duration systemg can parse, or the service could never be judged ready.",
+            "Use a whole number with a unit: `500ms`, `2s`, or `1m`.",
+            "/how-it-works/configuration",
+        )
+    } else if lower.contains("invalid start_timeout") {
+        (
+            "invalid-start-timeout",
+            "A `start_timeout` must be a duration systemg can parse, or every start of the service would fail.",
+            "Use a whole number with a unit: `500ms`, `30s`, or `2m`.",
+            "/how-it-works/configuration",
+        )
+    } else if lower.contains("invalid restart_if_rss_exceeds")
+        || lower.contains("invalid rss_window")
+    {
+        (
+            "invalid-rss-limit",
+            "A memory restart needs a positive `restart_if_rss_exceeds` size and a `rss_window` systemg can parse, or the guard never fires.",
+            "Use a size such as `512M` and a window such as `5m`.",
+            "/how-it-works/configuration",
+        )
+    } else if lower.contains("invalid umask") {
+        (
+            "invalid-umask",
+            "A service `umask` must be an octal mask, or files it creates would get unexpected permissions.",
+            "Quote an octal mask from `0000` to `0777`, such as `umask: \"0027\"`.",
+            "/how-it-works/configuration",
+        )
+    } else if lower.contains("invalid socket") {
+        (
+            "invalid-socket",
+            "A service socket must name the address systemg binds for it, or the service could not start.",
+            "Use an IP address and port, such as `listen: \"0.0.0.0:8080\"` or `listen: \"[::]:8080\"`.",
+            "/how-it-works/configuration",
+        )
+    } else if lower.contains("declare port") || lower.contains("declares invalid port") {
+        (
+            "port-conflict",
+            "Only one process can listen on a port, so two services declaring the same one could never both run.",
+            "Give each service its own `ports`, between 1 and 65535.",
+            "/how-it-works/configuration",
+        )
+    } else if lower.contains("share log file") {
+        (
+            "shared-log-file",
+            "Each service writes its own log file, so two names that sanitize to the same filename would interleave their output.",
+            "Rename one of the services so its log filename differs.",
+            "/how-it-works/configuration",
+        )
+    } else if lower.contains("invalid cron history") {
+        (
+            "invalid-cron-history",
+            "Cron history settings bound how many past runs are kept, so they must keep at least one record and use a parsable duration.",
+            "Set `history_limit` to 1 or more and `history_retention` to a duration like `72h`.",
+            "/how-it-works/configuration",
+        )
+    } else if lower.contains("invalid ready_log_pattern") {
+        (
+            "invalid-ready-log-pattern",
+            "A service `ready_log_pattern` must be a valid regular expression.",
+            "Escape regex metacharacters such as `(` or `[`, or balance the brackets.",
+            "/how-it-works/configuration",
+        )
+    } else if lower.contains("invalid shell") {
+        (
+            "invalid-shell",
+            "A service `shell` must be an existing interpreter, or `none` with a command that splits into an argv.",
+            "Point `shell` at an installed interpreter such as `/bin/bash`, or balance the quotes in the command.",
+            "/how-it-works/configuration",
+        )
     } else if lower.contains("health check requires at least one") {
         (
             "invalid-health-check",
@@ -189,6 +287,13 @@ fn classify_yaml(err: &serde_yaml::Error) -> Diagnostic {
             "Give the health_check a `url:` or a `command:` (plus optional interval/attempt_timeout/retries).",
             "/how-it-works/configuration",
         )
+    } else if lower.contains("http health checks are not available") {
+        (
+            "http-health-disabled",
+            "This systemg was built without the `http-health` feature, so it cannot probe a health_check `url`.",
+            "Rebuild with `--features http-health`, or replace the `url:` with a `command:` probe such as `curl -fsS <url>`.",
+            "/how-it-works/configuration",
+        )
     } else if lower.contains("project.id") {
         (
             "invalid-project-id",
@@ -269,6 +374,134 @@ mod tests {
         assert_eq!(report.diagnostics[0].kind, "invalid-health-check");
     }
 
+    #[test]
+    fn duplicate_service_is_classified() {
+        let (_dir, path) = write_config(
+            "version: \"2\"\nservices:\n  api:\n    command: \"echo one\"\n  api:\n    command: \"echo two\"\n",
+        );
+        let (report, _) = validate(&path);
+        assert!(!report.valid);
+        assert_eq!(report.diagnostics[0].kind, "duplicate-service");
+        assert!(report.diagnostics[0].message.contains("`api`"));
+
+        let (_dir, path) = write_config(
+            "version: \"2\"\nprojects:\n  shop:\n    services:\n      web:\n        command: \"a\"\n      web:\n        command: \"b\"\n",
+        );
+        let (report, _) = validate(&path);
+        assert_eq!(report.diagnostics[0].kind, "duplicate-service");
+    }
+
+    #[test]
+    fn invalid_backoff_is_classified() {
+        let (_dir, path) = write_config(
+            "version: \"2\"\nservices:\n  api:\n    command: \"echo ok\"\n    backoff: \"5 minutes\"\n",
+        );
+        let (report, _) = validate(&path);
+        assert!(!report.valid);
+        assert_eq!(report.diagnostics[0].kind, "invalid-backoff");
+    }
+
+    #[test]
+    fn invalid_ready_stable_for_is_classified() {
+        let (_dir, path) = write_config(
+            "version: \"2\"\nservices:\n  api:\n    command: \"echo ok\"\n    ready_stable_for: \"soon\"\n",
+        );
+        let (report, _) = validate(&path);
+        assert!(!report.valid);
+        assert_eq!(report.diagnostics[0].kind, "invalid-ready-stable-for");
+    }
+
+    #[test]
+    fn invalid_start_timeout_is_classified() {
+        let (_dir, path) = write_config(
+            "version: \"2\"\nservices:\n  api:\n    command: \"echo ok\"\n    start_timeout: \"forever\"\n",
+        );
+        let (report, _) = validate(&path);
+        assert!(!report.valid);
+        assert_eq!(report.diagnostics[0].kind, "invalid-start-timeout");
+    }
+
+    #[test]
+    fn invalid_rss_limit_is_classified() {
+        let (_dir, path) = write_config(
+            "version: \"2\"\nservices:\n  api:\n    command: \"echo ok\"\n    restart_if_rss_exceeds: \"512M\"\n    rss_window: \"5 minutes\"\n",
+        );
+        let (report, _) = validate(&path);
+        assert!(!report.valid);
+        assert_eq!(report.diagnostics[0].kind, "invalid-rss-limit");
+    }
+
+    #[test]
+    fn invalid_umask_is_classified() {
+        let (_dir, path) = write_config(
+            "version: \"2\"\nservices:\n  api:\n    command: \"echo ok\"\n    umask: \"0999\"\n",
+        );
+        let (report, _) = validate(&path);
+        assert!(!report.valid);
+        assert_eq!(report.diagnostics[0].kind, "invalid-umask");
+    }
+
+    #[test]
+    fn invalid_socket_is_classified() {
+        let (_dir, path) = write_config(
+            "version: \"2\"\nservices:\n  api:\n    command: \"echo ok\"\n    sockets:\n      - listen: \"localhost:8080\"\n",
+        );
+        let (report, _) = validate(&path);
+        assert!(!report.valid);
+        assert_eq!(report.diagnostics[0].kind, "invalid-socket");
+    }
+
+    #[test]
+    fn port_conflict_is_classified() {
+        let (_dir, path) = write_config(
+            "version: \"2\"\nservices:\n  api:\n    command: \"serve\"\n    ports: [8080]\n  web:\n    command: \"serve\"\n    ports: [8080]\n",
+        );
+        let (report, _) = validate(&path);
+        assert!(!report.valid);
+        assert_eq!(report.diagnostics[0].kind, "port-conflict");
+    }
+
+    #[test]
+    fn shared_log_file_is_classified() {
+        let stem = crate::logs::log_file_stem("web api");
+        let (_dir, path) = write_config(&format!(
+            "version: \"2\"\nservices:\n  \"web api\":\n    command: \"serve\"\n  {stem}:\n    command: \"serve\"\n"
+        ));
+        let (report, _) = validate(&path);
+        assert!(!report.valid);
+        assert_eq!(report.diagnostics[0].kind, "shared-log-file");
+    }
+
+    #[test]
+    fn invalid_cron_history_is_classified() {
+        let (_dir, path) = write_config(
+            "version: \"2\"\nservices:\n  job:\n    command: \"echo ok\"\n    cron:\n      expression: \"0 * * * * *\"\n      history_retention: \"a week\"\n",
+        );
+        let (report, _) = validate(&path);
+        assert!(!report.valid);
+        assert_eq!(report.diagnostics[0].kind, "invalid-cron-history");
+    }
+
+    #[test]
+    fn invalid_shell_is_classified() {
+        let (_dir, path) = write_config(
+            "version: \"2\"\nservices:\n  api:\n    command: \"echo ok\"\n    shell: \"/no/such/shell\"\n",
+        );
+        let (report, _) = validate(&path);
+        assert!(!report.valid);
+        assert_eq!(report.diagnostics[0].kind, "invalid-shell");
+    }
+
+    #[test]
+    fn invalid_ready_log_pattern_is_classified() {
+        let (_dir, path) = write_config(
+            "version: \"2\"\nservices:\n  api:\n    command: \"echo ok\"\n    ready_log_pattern: \"(unclosed\"\n",
+        );
+        let (report, _) = validate(&path);
+        assert!(!report.valid);
+        assert_eq!(report.diagnostics[0].kind, "invalid-ready-log-pattern");
+    }
+
     #[test]
     fn unreadable_config_is_reported() {
         let (report, content) = validate("/nonexistent/path/systemg.yaml");
@@ -0,0 +1,70 @@
+//! Typed diagnostics for the `wait` command.
+
+use std::time::Duration;
+
+use crate::{
+    cli::WaitCondition,
+    diag::{Diagnostic, SgCode},
+};
+
+/// Builds the diagnostic for a wait whose deadline passed before the service
+/// reached the requested condition. `last_seen` describes the final reading.
+pub fn timed_out(
+    service: &str,
+    condition: WaitCondition,
+    timeout: Duration,
+    last_seen: &str,
+) -> Diagnostic {
+    Diagnostic::error(
+        SgCode::Catchall,
+        format!(
+            "`{service}` was not {} after {}s",
+            condition.as_str(),
+            timeout.as_secs()
+        ),
+    )
+    .note(format!("last observed: {last_seen}"))
+    .help_cmd("see why", format!("sysg inspect -s {service}"))
+    .help_docs()
+}
+
+/// Builds the diagnostic for a wait that can no longer succeed, such as waiting
+/// for `running` on a one-shot that already completed.
+pub fn condition_unreachable(
+    service: &str,
+    condition: WaitCondition,
+    reason: &str,
+) -> Diagnostic {
+    Diagnostic::error(
+        SgCode::Catchall,
+        format!("`{service}` will not become {}", condition.as_str()),
+    )
+    .note(reason.to_string())
+    .help_cmd("check its logs", format!("sysg logs -s {service}"))
+    .help_docs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timed_out_names_service_and_condition() {
+        let diag = timed_out(
+            "web",
+            WaitCondition::Healthy,
+            Duration::from_secs(5),
+            "running",
+        );
+        let rendered = diag.render(false);
+        assert!(rendered.contains("web"));
+        assert!(rendered.contains("healthy"));
+        assert!(rendered.contains("5s"));
+    }
+
+    #[test]
+    fn unreachable_carries_reason() {
+        let diag = condition_unreachable("web", WaitCondition::Running, "it completed");
+        assert!(diag.render(false).contains("it completed"));
+    }
+}
@@ -0,0 +1,17 @@
+//! The `wait` command's plan layer.
+//!
+//! - [`crate::wait::plan`] — resolves the selector and `--for`/`--timeout` into a
+//!   [`crate::wait::WaitPlan`], and judges a status unit against the requested
+//!   condition.
+//! - [`crate::wait::diagnostics`] — typed diagnostics for wait failures.
+//!
+//! Polling the supervisor lives in the binary; this module only decides what is
+//! being waited for and whether a given reading satisfies it.
+
+pub mod diagnostics;
+pub mod plan;
+
+pub use diagnostics::{condition_unreachable, timed_out};
+pub use plan::{
+    DEFAULT_WAIT_TIMEOUT, WaitPlan, WaitPlanError, WaitVerdict, evaluate, resolve_plan,
+};
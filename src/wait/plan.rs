@@ -0,0 +1,300 @@
+//! Resolving `wait`'s selector into one plan, and judging readings against it.
+//!
+//! `wait` blocks a script until one service reaches a state, so like inspect it
+//! targets exactly one service. The plan carries the condition and the deadline;
+//! [`evaluate`] is the pure verdict the binary's poll loop applies to each status
+//! reading, so "is it there yet?" is decided in one testable place.
+//!
+//! - [`WaitPlan`] — the service, its project, the condition, and the timeout.
+//! - [`resolve_plan`] — resolves `-p`/`<service>`/`--timeout` into the plan.
+//! - [`evaluate`] — whether a unit meets, may still meet, or can no longer meet
+//!   the condition.
+
+use std::time::Duration;
+
+use crate::{
+    charting::parse_stream_duration,
+    cli::WaitCondition,
+    selector::{ProjectMismatch, Target, resolve_target},
+    status::{UnitHealth, UnitState, UnitStatus},
+};
+
+/// How long `wait` polls when no `--timeout` is given.
+pub const DEFAULT_WAIT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// The single service a `wait` invocation blocks on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WaitPlan {
+    /// The service to wait for (never carries a `project/` prefix).
+    pub service: String,
+    /// The project the service belongs to, when known from `-p` or a
+    /// `project/service` selector.
+    pub project: Option<String>,
+    /// The state the service must reach.
+    pub condition: WaitCondition,
+    /// How long to poll before giving up.
+    pub timeout: Duration,
+}
+
+/// Why a `wait` invocation could not resolve to a plan.
+#[derive(Debug, PartialEq, Eq)]
+pub enum WaitPlanError {
+    /// The selector did not name a single service.
+    NotAService,
+    /// The `-p` flag disagreed with a `project/service` selector prefix.
+    Mismatch(ProjectMismatch),
+    /// `--timeout` was not a valid duration.
+    InvalidTimeout {
+        /// The rejected value.
+        value: String,
+        /// Why it was rejected.
+        reason: String,
+    },
+}
+
+/// Resolves the selector and flags into a [`WaitPlan`].
+pub fn resolve_plan(
+    service: &str,
+    project: Option<&str>,
+    condition: WaitCondition,
+    timeout: Option<&str>,
+) -> Result<WaitPlan, WaitPlanError> {
+    let timeout = match timeout {
+        Some(raw) => parse_stream_duration(raw)
+            .map(Duration::from_secs)
+            .map_err(|reason| WaitPlanError::InvalidTimeout {
+                value: raw.to_string(),
+                reason,
+            })?,
+        None => DEFAULT_WAIT_TIMEOUT,
+    };
+    match resolve_target(Some(service), project).map_err(WaitPlanError::Mismatch)? {
+        Target::Service { service, project } => Ok(WaitPlan {
+            service,
+            project,
+            condition,
+            timeout,
+        }),
+        Target::Everything | Target::Project { .. } => Err(WaitPlanError::NotAService),
+    }
+}
+
+/// The verdict on one status reading.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WaitVerdict {
+    /// The condition holds; `wait` exits 0.
+    Met,
+    /// The condition does not hold yet but still can; keep polling.
+    Pending,
+    /// The unit settled somewhere the condition can no longer be reached from.
+    Unreachable(String),
+}
+
+/// Judges a unit against `condition`. `unit` is `None` when the reading does not
+/// contain the service yet, which is pending rather than fatal: a script may call
+/// `wait` before the supervisor has registered the project.
+///
+/// `healthy` leans on the project's boot report: the supervisor only settles a
+/// boot once each service's health check has passed, so a running unit in a
+/// still-booting project is not yet healthy, and one listed in the boot's failed
+/// set never will be.
+pub fn evaluate(condition: WaitCondition, unit: Option<&UnitStatus>) -> WaitVerdict {
+    let Some(unit) = unit else {
+        return WaitVerdict::Pending;
+    };
+    let boot = unit
+        .project
+        .as_ref()
+        .and_then(|project| project.boot.as_ref());
+    if boot.is_some_and(|boot| boot.failed.iter().any(|name| name == &unit.name)) {
+        return WaitVerdict::Unreachable(format!("`{}` failed to start", unit.name));
+    }
+
+    match (condition, unit.state) {
+        (_, UnitState::Skipped) => WaitVerdict::Unreachable(format!(
+            "`{}` is skipped by configuration",
+            unit.name
+        )),
+        (WaitCondition::Exited, UnitState::Done) => WaitVerdict::Met,
+        (WaitCondition::Exited, UnitState::Failed) => {
+            let code = unit
+                .last_exit
+                .as_ref()
+                .and_then(|exit| exit.exit_code)
+                .map(|code| format!(" with code {code}"))
+                .unwrap_or_default();
+            WaitVerdict::Unreachable(format!("`{}` exited{code}", unit.name))
+        }
+        (WaitCondition::Exited, _) => WaitVerdict::Pending,
+        (WaitCondition::Running | WaitCondition::Healthy, UnitState::Done) => {
+            WaitVerdict::Unreachable(format!(
+                "`{}` already completed and will not run again",
+                unit.name
+            ))
+        }
+        (WaitCondition::Running, UnitState::Running) => WaitVerdict::Met,
+        (WaitCondition::Healthy, UnitState::Running)
+            if unit.health == UnitHealth::Healthy
+                && boot.is_none_or(|boot| boot.settled) =>
+        {
+            WaitVerdict::Met
+        }
+        (WaitCondition::Running | WaitCondition::Healthy, _) => WaitVerdict::Pending,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::status::{
+        BootStatus, ExitMetadata, ProjectRunMode, ProjectStatus, UnitIntent, UnitKind,
+    };
+
+    fn unit(state: UnitState, health: UnitHealth) -> UnitStatus {
+        UnitStatus {
+            name: "web".into(),
+            hash: "abc".into(),
+            project: None,
+            kind: UnitKind::Service,
+            lifecycle: None,
+            state,
+            intent: UnitIntent::Serve,
+            health,
+            process: None,
+            uptime: None,
+            last_exit: None,
+            cron: None,
+            metrics: None,
+            command: None,
+            runtime_command: None,
+            spawned_children: Vec::new(),
+            drifted: false,
+            restarts: 0,
+            gave_up_after: None,
+        }
+    }
+
+    fn with_boot(mut unit: UnitStatus, boot: BootStatus) -> UnitStatus {
+        unit.project = Some(ProjectStatus {
+            id: "demo".into(),
+            name: "demo".into(),
+            mode: ProjectRunMode::Daemon,
+            config_path: None,
+            boot: Some(boot),
+        });
+        unit
+    }
+
+    #[test]
+    fn resolves_prefixed_selector_and_default_timeout() {
+        let plan = resolve_plan("demo/web", None, WaitCondition::Running, None).unwrap();
+        assert_eq!(plan.service, "web");
+        assert_eq!(plan.project.as_deref(), Some("demo"));
+        assert_eq!(plan.timeout, DEFAULT_WAIT_TIMEOUT);
+    }
+
+    #[test]
+    fn parses_timeout_units() {
+        let plan = resolve_plan("web", None, WaitCondition::Exited, Some("2m")).unwrap();
+        assert_eq!(plan.timeout, Duration::from_secs(120));
+    }
+
+    #[test]
+    fn rejects_invalid_timeout() {
+        assert!(matches!(
+            resolve_plan("web", None, WaitCondition::Running, Some("soon")),
+            Err(WaitPlanError::InvalidTimeout { .. })
+        ));
+    }
+
+    #[test]
+    fn p_flag_conflicting_with_prefix_is_a_mismatch() {
+        assert!(matches!(
+            resolve_plan("other/web", Some("demo"), WaitCondition::Running, None),
+            Err(WaitPlanError::Mismatch(_))
+        ));
+    }
+
+    #[test]
+    fn missing_unit_is_pending() {
+        assert_eq!(evaluate(WaitCondition::Running, None), WaitVerdict::Pending);
+    }
+
+    #[test]
+    fn running_unit_meets_running() {
+        let unit = unit(UnitState::Running, UnitHealth::Healthy);
+        assert_eq!(
+            evaluate(WaitCondition::Running, Some(&unit)),
+            WaitVerdict::Met
+        );
+    }
+
+    #[test]
+    fn healthy_waits_for_boot_to_settle() {
+        let booting = with_boot(
+            unit(UnitState::Running, UnitHealth::Healthy),
+            BootStatus::default(),
+        );
+        assert_eq!(
+            evaluate(WaitCondition::Healthy, Some(&booting)),
+            WaitVerdict::Pending
+        );
+
+        let settled = with_boot(
+            unit(UnitState::Running, UnitHealth::Healthy),
+            BootStatus {
+                settled: true,
+                ..BootStatus::default()
+            },
+        );
+        assert_eq!(
+            evaluate(WaitCondition::Healthy, Some(&settled)),
+            WaitVerdict::Met
+        );
+    }
+
+    #[test]
+    fn failed_boot_is_unreachable() {
+        let failed = with_boot(
+            unit(UnitState::Running, UnitHealth::Healthy),
+            BootStatus {
+                settled: true,
+                failed: vec!["web".into()],
+                cause: None,
+            },
+        );
+        assert!(matches!(
+            evaluate(WaitCondition::Healthy, Some(&failed)),
+            WaitVerdict::Unreachable(_)
+        ));
+    }
+
+    #[test]
+    fn exited_distinguishes_success_from_failure() {
+        let done = unit(UnitState::Done, UnitHealth::Healthy);
+        assert_eq!(
+            evaluate(WaitCondition::Exited, Some(&done)),
+            WaitVerdict::Met
+        );
+
+        let mut failed = unit(UnitState::Failed, UnitHealth::Failing);
+        failed.last_exit = Some(ExitMetadata {
+            exit_code: Some(3),
+            signal: None,
+            at: None,
+        });
+        assert_eq!(
+            evaluate(WaitCondition::Exited, Some(&failed)),
+            WaitVerdict::Unreachable("`web` exited with code 3".into())
+        );
+    }
+
+    #[test]
+    fn completed_unit_never_becomes_running() {
+        let done = unit(UnitState::Done, UnitHealth::Healthy);
+        assert!(matches!(
+            evaluate(WaitCondition::Running, Some(&done)),
+            WaitVerdict::Unreachable(_)
+        ));
+    }
+}
@@ -227,6 +227,81 @@ fn sys_flag_requires_root_privileges() {
     );
 }
 
+#[test]
+fn restart_build_first_refuses_a_project_restart() {
+    let temp = tempdir().expect("failed to create tempdir");
+    let home = temp.path().join("home");
+    fs::create_dir_all(&home).expect("failed to create home");
+    let _home = HomeEnvGuard::set(&home);
+    let config_path = temp.path().join("systemg.yaml");
+    fs::write(
+        &config_path,
+        "version: \"2\"\nproject:\n  id: shop\nservices:\n  api:\n    command: \"sleep 30\"\n",
+    )
+    .expect("failed to write config");
+
+    let output = Command::new(assert_cmd::cargo::cargo_bin!("sysg"))
+        .arg("restart")
+        .arg("--config")
+        .arg(&config_path)
+        .arg("-p")
+        .arg("shop")
+        .arg("--build-first")
+        .output()
+        .expect("failed to invoke sysg restart");
+
+    assert!(
+        !output.status.success(),
+        "a project restart should refuse --build-first"
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("`--build-first` only applies to restarts of named services"),
+        "stderr should explain the restriction: {stderr}"
+    );
+}
+
+#[test]
+fn offline_disable_rejects_a_project_the_config_does_not_define() {
+    let temp = tempdir().expect("failed to create tempdir");
+    let home = temp.path().join("home");
+    fs::create_dir_all(&home).expect("failed to create home");
+    let _home = HomeEnvGuard::set(&home);
+    let config_path = temp.path().join("systemg.yaml");
+    fs::write(
+        &config_path,
+        "version: \"2\"\nproject:\n  id: shop\nservices:\n  api:\n    command: \"sleep 30\"\n",
+    )
+    .expect("failed to write config");
+
+    let disable = |args: &[&str]| {
+        Command::new(assert_cmd::cargo::cargo_bin!("sysg"))
+            .arg("disable")
+            .arg("--config")
+            .arg(&config_path)
+            .args(args)
+            .output()
+            .expect("failed to invoke sysg disable")
+    };
+
+    for args in [&["other/api"][..], &["api", "-p", "other"][..]] {
+        let output = disable(args);
+        assert!(!output.status.success(), "{args:?} should be refused");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            stderr.contains("project `other` does not match the resolved project `shop`"),
+            "{args:?} should report the mismatch: {stderr}"
+        );
+    }
+
+    let output = disable(&["shop/api", "-p", "shop"]);
+    assert!(
+        output.status.success(),
+        "a matching qualifier should disable the service: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
 #[test]
 fn inspect_requires_service_flag_not_positional_arg() {
     let output = Command::new(assert_cmd::cargo::cargo_bin!("sysg"))
@@ -611,6 +686,129 @@ fn start_daemonize_accepts_unit_command_without_config() {
         .success();
 }
 
+#[test]
+fn start_follow_exit_propagates_the_service_exit_code() {
+    let temp = tempdir().expect("failed to create tempdir");
+    let dir = temp.path();
+    let home = dir.join("home");
+    fs::create_dir_all(&home).expect("failed to create home dir");
+    let _home = HomeEnvGuard::set(&home);
+
+    let config_path = dir.join("systemg.yaml");
+    fs::write(
+        &config_path,
+        r#"version: "2"
+project:
+  id: follow-exit
+services:
+  migrate:
+    command: "sh -c 'sleep 1; exit 3'"
+    restart_policy: never
+"#,
+    )
+    .expect("failed to write config");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("sysg"))
+        .arg("start")
+        .arg("--follow-exit")
+        .arg("--service")
+        .arg("migrate")
+        .arg("--config")
+        .arg(&config_path)
+        .timeout(Duration::from_secs(60))
+        .assert()
+        .code(3);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("sysg"))
+        .arg("stop")
+        .arg("--supervisor")
+        .assert()
+        .success();
+}
+
+#[test]
+fn start_follow_exit_ignores_the_previous_runs_exit() {
+    let temp = tempdir().expect("failed to create tempdir");
+    let dir = temp.path();
+    let home = dir.join("home");
+    fs::create_dir_all(&home).expect("failed to create home dir");
+    let _home = HomeEnvGuard::set(&home);
+
+    let config_path = dir.join("systemg.yaml");
+    fs::write(
+        &config_path,
+        r#"version: "2"
+project:
+  id: follow-exit-rerun
+services:
+  migrate:
+    command: "sh -c 'sleep 1; exit 0'"
+    restart_policy: never
+"#,
+    )
+    .expect("failed to write config");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("sysg"))
+        .arg("start")
+        .arg("--follow-exit")
+        .arg("--service")
+        .arg("migrate")
+        .arg("--config")
+        .arg(&config_path)
+        .timeout(Duration::from_secs(60))
+        .assert()
+        .code(0);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("sysg"))
+        .arg("stop")
+        .arg("--supervisor")
+        .assert()
+        .success();
+
+    // The re-run never launches `migrate`: a port it declares is taken. The
+    // clean exit recorded by the first run must not stand in for it.
+    let holder = std::net::TcpListener::bind("127.0.0.1:0").expect("bind port");
+    let port = holder.local_addr().expect("local addr").port();
+    fs::write(
+        &config_path,
+        format!(
+            r#"version: "2"
+project:
+  id: follow-exit-rerun
+services:
+  migrate:
+    command: "sh -c 'sleep 1; exit 0'"
+    restart_policy: never
+    ports: [{port}]
+"#
+        ),
+    )
+    .expect("failed to rewrite config");
+
+    let output = Command::new(assert_cmd::cargo::cargo_bin!("sysg"))
+        .arg("start")
+        .arg("--follow-exit")
+        .arg("--service")
+        .arg("migrate")
+        .arg("--config")
+        .arg(&config_path)
+        .timeout(Duration::from_secs(60))
+        .output()
+        .expect("failed to invoke sysg start");
+    assert!(
+        !output.status.success(),
+        "a re-run that never launched must not pass: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    drop(holder);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("sysg"))
+        .arg("stop")
+        .arg("--supervisor")
+        .assert()
+        .success();
+}
+
 #[test]
 fn drop_privileges_warns_for_non_spawn_commands() {
     let temp = tempdir().expect("failed to create tempdir");
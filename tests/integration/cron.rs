@@ -88,11 +88,80 @@ services:
         .expect("supervisor thread should shut down cleanly");
 }
 
+#[test]
+/// Verifies a failed run's record keeps the lines it printed just before exiting.
+fn cron_record_captures_output_written_right_before_exit() {
+    use systemg::supervisor::Supervisor;
+
+    let temp = tempdir().expect("failed to create tempdir");
+    let dir = temp.path();
+    let home = dir.join("home");
+    fs::create_dir_all(&home).expect("failed to create home dir");
+    let _home = HomeEnvGuard::set(&home);
+
+    let config_path = dir.join("systemg.yaml");
+    fs::write(
+        &config_path,
+        r#"version: "2"
+services:
+  noisy_cron:
+    command: "sh -c 'sleep 1; seq 1 50000; echo final words; exit 3'"
+    restart_policy: "never"
+    cron:
+      expression: "*/1 * * * * *"
+"#,
+    )
+    .expect("failed to write config");
+
+    let config =
+        load_config(Some(config_path.to_string_lossy().as_ref())).expect("load config");
+    let service_key = config.state_key("noisy_cron");
+
+    let config_for_thread = config_path.clone();
+    let supervisor_thread = thread::spawn(move || {
+        let mut supervisor =
+            Supervisor::new(config_for_thread, false, None).expect("create supervisor");
+        supervisor.run().expect("run supervisor");
+    });
+
+    wait_for_supervisor_socket();
+    let deadline = Instant::now() + Duration::from_secs(10);
+    let output = loop {
+        if let Some(state) = cron_state_with_key(&service_key)
+            && let Some(job) = state.jobs().get(&service_key)
+            && let Some(record) = job.execution_history.iter().find(|record| {
+                record.completed_at.is_some()
+                    && !matches!(record.status, Some(CronExecutionStatus::OverlapError))
+            })
+        {
+            assert_eq!(record.exit_code, Some(3), "{record:#?}");
+            break record.output.clone();
+        }
+        if Instant::now() >= deadline {
+            panic!("timed out waiting for a completed noisy_cron record");
+        }
+        thread::sleep(Duration::from_millis(50));
+    };
+
+    let _ = ipc::send_command(&ControlCommand::Shutdown);
+    supervisor_thread
+        .join()
+        .expect("supervisor thread should shut down cleanly");
+    assert_eq!(
+        output.last().map(String::as_str),
+        Some("final words"),
+        "record output should end with the run's last line: {output:?}"
+    );
+}
+
 /// Waits until the integration supervisor accepts control commands.
 fn wait_for_supervisor_socket() {
     let deadline = Instant::now() + Duration::from_secs(5);
     loop {
-        match ipc::send_command(&ControlCommand::Status { live: false }) {
+        match ipc::send_command(&ControlCommand::Status {
+            live: false,
+            no_children: false,
+        }) {
             Ok(_) => return,
             Err(ControlError::NotAvailable) | Err(ControlError::Io(_)) => {}
             Err(err) => panic!("unexpected supervisor status error: {err}"),
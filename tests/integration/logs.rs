@@ -563,3 +563,58 @@ fn logs_purge_without_service_truncates_all_logs() {
     assert_eq!(read_log(&supervisor), "");
     assert_eq!(read_log(&spawn_log), "spawn output\n");
 }
+
+#[cfg(target_os = "linux")]
+#[test]
+/// Clears only the named project's logs when `--clear-all` is scoped by `-p`.
+fn logs_clear_all_with_project_leaves_other_projects_alone() {
+    let temp = tempdir().expect("failed to create tempdir");
+    let dir = temp.path();
+    let home = dir.join("home");
+    fs::create_dir_all(&home).expect("failed to create home dir");
+    let _home = HomeEnvGuard::set(&home);
+
+    let log_dir = home.join(".local/share/systemg/logs");
+    let alpha_api = log_dir.join("alpha/api_stdout.log");
+    let alpha_backup = log_dir.join("alpha/api_stdout.log.1");
+    let beta_api = log_dir.join("beta/api_stdout.log");
+    let supervisor = log_dir.join("supervisor.log");
+
+    write_log(&alpha_api, "alpha stdout\n");
+    write_log(&alpha_backup, "alpha rotated\n");
+    write_log(&beta_api, "beta stdout\n");
+    write_log(&supervisor, "supervisor event\n");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("sysg"))
+        .current_dir(dir)
+        .arg("logs")
+        .arg("--clear-all")
+        .arg("--project")
+        .arg("alpha")
+        .arg("--yes")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "Cleared logs for project 'alpha'",
+        ));
+
+    assert_eq!(read_log(&alpha_api), "");
+    assert_eq!(read_log(&alpha_backup), "alpha rotated\n");
+    assert_eq!(read_log(&beta_api), "beta stdout\n");
+    assert_eq!(read_log(&supervisor), "supervisor event\n");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("sysg"))
+        .current_dir(dir)
+        .arg("logs")
+        .arg("--purge")
+        .arg("--project")
+        .arg("alpha")
+        .assert()
+        .success();
+
+    assert!(
+        !alpha_backup.exists(),
+        "purge -p should drop alpha's backups"
+    );
+    assert_eq!(read_log(&beta_api), "beta stdout\n");
+}
@@ -0,0 +1,101 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use chrono::{Duration as ChronoDuration, Utc};
+use systemg::metrics::{MetricSample, MetricsSettings, MetricsStore};
+
+/// Tracks live heap bytes so the test can compare the store's estimate with
+/// what it actually allocated.
+struct CountingAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            ALLOCATED.fetch_add(layout.size(), Ordering::SeqCst);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        ALLOCATED.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            ALLOCATED.fetch_add(new_size, Ordering::SeqCst);
+            ALLOCATED.fetch_sub(layout.size(), Ordering::SeqCst);
+        }
+        new_ptr
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[test]
+/// Verifies many units sampling past the budget keep the real allocation under it.
+fn real_allocation_stays_under_budget_for_many_units() {
+    let budget = 256 * 1024;
+    let baseline = ALLOCATED.load(Ordering::SeqCst);
+    let mut store = MetricsStore::new(MetricsSettings {
+        retention: Duration::from_secs(24 * 60 * 60),
+        max_memory_bytes: budget,
+        ..MetricsSettings::default()
+    })
+    .expect("create metrics store");
+
+    let units: Vec<String> = (0..200).map(|index| format!("unit-{index:04}")).collect();
+    let started = Utc::now();
+    let mut peak = 0;
+    for tick in 0..100 {
+        let timestamp = started + ChronoDuration::seconds(tick);
+        for unit in &units {
+            store
+                .record_sample(
+                    unit,
+                    MetricSample {
+                        timestamp,
+                        cpu_percent: 1.0,
+                        rss_bytes: 1024,
+                        io_read_bytes: 0,
+                        io_write_bytes: 0,
+                        net_rx_bytes: 0,
+                        net_tx_bytes: 0,
+                    },
+                )
+                .expect("record sample");
+        }
+        let usage = store.memory_usage();
+        let real = ALLOCATED.load(Ordering::SeqCst) - baseline;
+        let owned_by_test = units.capacity() * std::mem::size_of::<String>()
+            + units.iter().map(String::capacity).sum::<usize>();
+        let real = real - owned_by_test;
+        assert!(usage <= budget, "estimate {usage} exceeds budget {budget}");
+        assert!(
+            real <= usage,
+            "real allocation {real} exceeds estimate {usage}"
+        );
+        peak = peak.max(real);
+    }
+
+    assert!(
+        peak <= budget,
+        "peak allocation {peak} exceeds budget {budget}"
+    );
+    let retained: usize = units
+        .iter()
+        .map(|unit| store.latest_samples(unit, usize::MAX).len())
+        .sum();
+    assert!(
+        retained > 0 && retained < units.len() * 100,
+        "retained {retained} samples"
+    );
+}
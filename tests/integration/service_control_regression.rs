@@ -114,6 +114,8 @@ services:
         .arg("status")
         .arg("-c")
         .arg(config_path.to_str().unwrap())
+        .arg("--output")
+        .arg("wide")
         .output()
         .expect("status after purge to execute");
     assert!(